@@ -62,6 +62,7 @@ pub struct DomainCache {
     pub id_tenant: Option<u32>,
     pub catch_all: Option<Box<str>>,
     pub sub_addressing_custom: Option<Box<IfBlock>>,
+    pub locale: Locale,
     pub flags: u8,
 }
 
@@ -92,6 +93,8 @@ pub const ACCOUNT_FLAG_ENCRYPT_METHOD_PGP: u64 = 1 << 3;
 pub const ACCOUNT_FLAG_ENCRYPT_ALGO_AES256: u64 = 1 << 4;
 pub const ACCOUNT_FLAG_ENCRYPT_ALGO_AES128: u64 = 1 << 5;
 pub const ACCOUNT_FLAG_ENCRYPT_APPEND: u64 = 1 << 6;
+pub const ACCOUNT_IS_RESOURCE: u64 = 1 << 7;
+pub const ACCOUNT_IS_LOCATION: u64 = 1 << 8;
 
 #[derive(Debug, Clone)]
 pub struct RoleCache {
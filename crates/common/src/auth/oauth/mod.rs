@@ -26,6 +26,7 @@ pub enum GrantType {
     LiveMetrics,
     LiveDelivery,
     Rsvp,
+    QuarantineRelease,
 }
 
 impl GrantType {
@@ -37,6 +38,7 @@ impl GrantType {
             GrantType::LiveMetrics => "live_metrics",
             GrantType::LiveDelivery => "live_delivery",
             GrantType::Rsvp => "rsvp",
+            GrantType::QuarantineRelease => "quarantine_release",
         }
     }
 
@@ -48,6 +50,7 @@ impl GrantType {
             GrantType::LiveMetrics => 3,
             GrantType::LiveDelivery => 4,
             GrantType::Rsvp => 5,
+            GrantType::QuarantineRelease => 6,
         }
     }
 
@@ -59,6 +62,7 @@ impl GrantType {
             3 => Some(GrantType::LiveMetrics),
             4 => Some(GrantType::LiveDelivery),
             5 => Some(GrantType::Rsvp),
+            6 => Some(GrantType::QuarantineRelease),
             _ => None,
         }
     }
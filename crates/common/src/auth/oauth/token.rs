@@ -38,7 +38,7 @@ impl Server {
         // Build context
         let mut password_hash = String::new();
 
-        if !matches!(grant_type, GrantType::Rsvp) {
+        if !matches!(grant_type, GrantType::Rsvp | GrantType::QuarantineRelease) {
             if client_id.len() > CLIENT_ID_MAX_LEN {
                 return Err(trc::AuthEvent::Error
                     .into_err()
@@ -157,7 +157,9 @@ impl Server {
         }
 
         // Obtain password hash
-        let password_hash = if !matches!(grant_type, GrantType::Rsvp) && expiry - issued_at > 3600 {
+        let password_hash = if !matches!(grant_type, GrantType::Rsvp | GrantType::QuarantineRelease)
+            && expiry - issued_at > 3600
+        {
             self.password_hash(account_id)
                 .await
                 .map_err(|err| trc::AuthEvent::Error.into_err().ctx(trc::Key::Details, err))?
@@ -277,6 +277,7 @@ impl Default for DefaultPermissions {
                         || name.starts_with("sysAccountSettings")
                         || name.starts_with("sysPublicKey")
                         || (name.starts_with("sysSpamTrainingSample") && !name.contains("Create"))
+                        || (name.starts_with("sysQuarantinedMessage") && !name.contains("Create"))
                     {
                         default.user.push(permission);
                         default.group.push(permission);
@@ -285,6 +285,7 @@ impl Server {
         self.inner.cache.accounts.clear();
         self.inner.cache.roles.clear();
         self.inner.cache.lists.clear();
+        self.inner.cache.spam_models.clear();
         self.inner.data.logos.lock().clear();
     }
 
@@ -355,6 +356,9 @@ impl Server {
                         .lock()
                         .retain(|_, v| v.tenant_id != Some(*id));
                 }
+                CacheInvalidation::SpamModel(id) => {
+                    cache.spam_models.remove(id);
+                }
             }
         }
     }
@@ -9,10 +9,11 @@ use crate::{
     auth::{
         ACCOUNT_FLAG_ENCRYPT_ALGO_AES128, ACCOUNT_FLAG_ENCRYPT_ALGO_AES256,
         ACCOUNT_FLAG_ENCRYPT_APPEND, ACCOUNT_FLAG_ENCRYPT_METHOD_PGP,
-        ACCOUNT_FLAG_ENCRYPT_METHOD_SMIME, ACCOUNT_FLAG_ENCRYPT_TRAIN_SPAM_FILTER, ACCOUNT_IS_USER,
-        AccountCache, AccountInfo, AccountTenantIds, DOMAIN_FLAG_RELAY, DOMAIN_FLAG_SUB_ADDRESSING,
-        DomainCache, EmailAddress, EmailAddressRef, EmailCache, MailingListCache, PermissionsGroup,
-        RECOVERY_ADMIN_ID, RoleCache, TenantCache, permissions::BuildPermissions,
+        ACCOUNT_FLAG_ENCRYPT_METHOD_SMIME, ACCOUNT_FLAG_ENCRYPT_TRAIN_SPAM_FILTER,
+        ACCOUNT_IS_LOCATION, ACCOUNT_IS_RESOURCE, ACCOUNT_IS_USER, AccountCache, AccountInfo,
+        AccountTenantIds, DOMAIN_FLAG_RELAY, DOMAIN_FLAG_SUB_ADDRESSING, DomainCache, EmailAddress,
+        EmailAddressRef, EmailCache, MailingListCache, PermissionsGroup, RECOVERY_ADMIN_ID,
+        RoleCache, TenantCache, permissions::BuildPermissions,
     },
     config::smtp::auth::DkimSigner,
     expr::if_block::BootstrapExprExt,
@@ -24,7 +25,7 @@ use crate::{
 };
 use registry::{
     schema::{
-        enums::{DkimRotationStage, Locale, StorageQuota, TenantStorageQuota},
+        enums::{DkimRotationStage, Locale, PrincipalKind, StorageQuota, TenantStorageQuota},
         prelude::{ObjectType, Property},
         structs::{
             Account, DkimSignature, Domain, EncryptionAtRest, MailingList, MaskedEmail,
@@ -177,6 +178,7 @@ impl Server {
                     id_tenant: domain.member_tenant_id.map(|id| id.document_id()),
                     catch_all: domain.catch_all_address.map(|s| s.into_boxed_str()),
                     sub_addressing_custom,
+                    locale: domain.locale,
                     flags,
                 });
 
@@ -370,6 +372,11 @@ impl Server {
                         }
 
                         let mut flags = ACCOUNT_IS_USER;
+                        match account.principal_kind {
+                            PrincipalKind::Individual => {}
+                            PrincipalKind::Resource => flags |= ACCOUNT_IS_RESOURCE,
+                            PrincipalKind::Location => flags |= ACCOUNT_IS_LOCATION,
+                        }
                         let encryption_settings = match account.encryption_at_rest {
                             EncryptionAtRest::Disabled => None,
                             EncryptionAtRest::Aes256(settings) => {
@@ -867,6 +874,11 @@ impl AccountInfo {
         self.account.flags & ACCOUNT_IS_USER != 0
     }
 
+    #[inline(always)]
+    pub fn principal_kind(&self) -> PrincipalKind {
+        self.account.principal_kind()
+    }
+
     #[inline(always)]
     pub fn locale(&self) -> Locale {
         self.account.locale
@@ -909,6 +921,16 @@ impl AccountCache {
         self.flags & ACCOUNT_IS_USER != 0
     }
 
+    pub fn principal_kind(&self) -> PrincipalKind {
+        if self.flags & ACCOUNT_IS_RESOURCE != 0 {
+            PrincipalKind::Resource
+        } else if self.flags & ACCOUNT_IS_LOCATION != 0 {
+            PrincipalKind::Location
+        } else {
+            PrincipalKind::Individual
+        }
+    }
+
     #[inline(always)]
     pub fn disk_quota(&self) -> u64 {
         self.quota_disk
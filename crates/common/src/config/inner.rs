@@ -10,11 +10,11 @@ use crate::{
     TlsConnectors,
     auth::{AccessTokenInner, AccountCache, DomainCache, MailingListCache, RoleCache, TenantCache},
     config::{
-        mailstore::spamfilter::SpamClassifier,
+        mailstore::spamfilter::{AccountSpamClassifier, SpamClassifier},
         server::tls::parse_certificates,
         smtp::{
             auth::DkimSigner,
-            resolver::{Policy, Tlsa},
+            resolver::{BimiRecord, Policy, Tlsa},
         },
     },
     manager::application::WebApplications,
@@ -90,6 +90,8 @@ impl Data {
             applications,
             logos: Default::default(),
             smtp_connectors: TlsConnectors::try_new().failed("Failed to build TLS connectors"),
+            smtp_sessions: Default::default(),
+            relay_stats: Default::default(),
             asn_geo_data: Default::default(),
         }
     }
@@ -166,6 +168,10 @@ impl Caches {
                 cache.dkim_signatures,
                 (std::mem::size_of::<DkimSigner>() + 255) as u64,
             ),
+            spam_models: Cache::new(
+                cache.spam_models,
+                (std::mem::size_of::<AccountSpamClassifier>() + (1 << 16)) as u64,
+            ),
             dns_txt: CacheWithTtl::new(cache.dns_txt, (std::mem::size_of::<Txt>() + 255) as u64),
             dns_mx: CacheWithTtl::new(cache.dns_mx, ((std::mem::size_of::<MX>() + 255) * 2) as u64),
             dns_ptr: CacheWithTtl::new(cache.dns_ptr, (std::mem::size_of::<IpAddr>() + 255) as u64),
@@ -186,6 +192,12 @@ impl Caches {
                 cache.dns_rbl,
                 ((std::mem::size_of::<Ipv4Addr>() + 255) * 2) as u64,
             ),
+            dns_bimi: CacheWithTtl::new(
+                cache.dns_txt,
+                (std::mem::size_of::<BimiRecord>() + 255) as u64,
+            ),
+            bimi_indicators: CacheWithTtl::new(16 * 1024 * 1024, 32 * 1024),
+            relay_unhealthy: CacheWithTtl::new(1024 * 255, 255),
             negative_cache_ttl: cache.negative_ttl.into_inner(),
         }
     }
@@ -230,6 +242,8 @@ impl Default for Data {
             applications: WebApplications::new(),
             logos: Default::default(),
             smtp_connectors: TlsConnectors::try_new().unwrap(),
+            smtp_sessions: Default::default(),
+            relay_stats: Default::default(),
             asn_geo_data: Default::default(),
             lookup_stores: Default::default(),
         }
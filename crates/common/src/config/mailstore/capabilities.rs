@@ -14,7 +14,7 @@ use jmap_proto::{
         BlobCapabilities, CalendarCapabilities, Capabilities, Capability, ContactsCapabilities,
         CoreCapabilities, EmptyCapabilities, FileNodeCapabilities, MailCapabilities,
         PrincipalAvailabilityCapabilities, PrincipalCapabilities, SieveAccountCapabilities,
-        SieveSessionCapabilities, SubmissionCapabilities,
+        SieveSessionCapabilities, SubmissionCapabilities, TasksCapabilities,
     },
     types::date::UTCDate,
 };
@@ -106,6 +106,20 @@ impl JmapConfig {
             Capabilities::Empty(EmptyCapabilities::default()),
         );
 
+        // Add tasks capabilities
+        self.capabilities.session.append(
+            Capability::Tasks,
+            Capabilities::Empty(EmptyCapabilities::default()),
+        );
+        self.capabilities.account.insert(
+            Capability::Tasks,
+            Capabilities::Tasks(TasksCapabilities {
+                min_date_time: UTCDate::from_timestamp(DateTime::<Utc>::MIN_UTC.timestamp()),
+                max_date_time: UTCDate::from_timestamp(DateTime::<Utc>::MAX_UTC.timestamp()),
+                may_create_task_list: true,
+            }),
+        );
+
         // Add contacts capabilities
         self.capabilities.session.append(
             Capability::Contacts,
@@ -184,6 +198,16 @@ impl JmapConfig {
             }),
         );
 
+        // Add MDN capabilities
+        self.capabilities.session.append(
+            Capability::Mdn,
+            Capabilities::Empty(EmptyCapabilities::default()),
+        );
+        self.capabilities.account.insert(
+            Capability::Mdn,
+            Capabilities::Empty(EmptyCapabilities::default()),
+        );
+
         // Add vacation response capabilities
         self.capabilities.session.append(
             Capability::VacationResponse,
@@ -20,14 +20,14 @@ use registry::{
     },
     types::EnumImpl,
 };
-use std::time::Duration;
+use std::{str::FromStr, time::Duration};
 use store::{
     registry::bootstrap::Bootstrap,
     search::{CalendarSearchField, ContactSearchField, EmailSearchField, SearchField},
     write::SearchIndex,
 };
 use types::special_use::SpecialUse;
-use utils::cron::SimpleCron;
+use utils::{cron::SimpleCron, template::Template};
 
 use crate::storage::ObjectQuota;
 
@@ -58,15 +58,65 @@ pub struct EmailConfig {
 
     pub index_batch_size: usize,
     pub index_fields: AHashMap<SearchIndex, AHashSet<SearchField>>,
+    pub index_extract: ExtractLimits,
 
     pub max_objects: ObjectQuota,
     pub compression: CompressionAlgo,
 
+    pub quota_warn_thresholds: Vec<u64>,
+    pub quota_warn_template: Template<QuotaTemplateVariable>,
+
+    pub quarantine_hold: Duration,
+    pub quarantine_digest: Option<SimpleCron>,
+    pub quarantine_release_url: String,
+    pub quarantine_template: Template<QuarantineTemplateVariable>,
+    pub quarantine_release_template: Template<QuarantineTemplateVariable>,
+
     pub account_purge_frequency: SimpleCron,
     pub data_purge_frequency: SimpleCron,
     pub blob_purge_frequency: SimpleCron,
 }
 
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ExtractLimits {
+    pub pdf_max_size: usize,
+    pub office_max_size: usize,
+    pub archive_max_size: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, Hash)]
+pub enum QuotaTemplateVariable {
+    #[default]
+    PageTitle,
+    Header,
+    Footer,
+    Title,
+    Description,
+    Details,
+    Key,
+    Value,
+    LogoCid,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, Hash)]
+pub enum QuarantineTemplateVariable {
+    #[default]
+    PageTitle,
+    Header,
+    Footer,
+    Title,
+    Description,
+    Items,
+    From,
+    Subject,
+    Reason,
+    ReceivedAt,
+    ReleaseUrl,
+    ReleaseLabel,
+    LogoCid,
+    Token,
+}
+
 #[derive(Clone, Debug)]
 pub struct DefaultFolder {
     pub name: String,
@@ -260,12 +310,22 @@ impl EmailConfig {
                             SearchCalendarField::Attendee => CalendarSearchField::Attendee,
                             SearchCalendarField::Start => CalendarSearchField::Start,
                             SearchCalendarField::Uid => CalendarSearchField::Uid,
+                            SearchCalendarField::Due => CalendarSearchField::Due,
+                            SearchCalendarField::Completed => CalendarSearchField::Completed,
+                            SearchCalendarField::PercentComplete => {
+                                CalendarSearchField::PercentComplete
+                            }
                         })
                     })
                     .collect(),
             );
         }
 
+        // Parse quota warning thresholds
+        let mut quota_warn_thresholds = email.quota_warn_thresholds.into_inner();
+        quota_warn_thresholds.sort_unstable();
+        quota_warn_thresholds.dedup();
+
         EmailConfig {
             default_language: Language::from_iso_639(search.default_language.as_str())
                 .unwrap_or(Language::English),
@@ -284,6 +344,11 @@ impl EmailConfig {
             encrypt_append: email.encrypt_on_append,
             index_batch_size: search.index_batch_size as usize,
             index_fields,
+            index_extract: ExtractLimits {
+                pdf_max_size: search.extract_pdf_max_size as usize,
+                office_max_size: search.extract_office_max_size as usize,
+                archive_max_size: search.extract_archive_max_size as usize,
+            },
             max_objects,
             default_folders,
             shared_folder,
@@ -291,8 +356,75 @@ impl EmailConfig {
             data_purge_frequency: dr.data_cleanup_schedule.into(),
             blob_purge_frequency: dr.blob_cleanup_schedule.into(),
             compression: email.compression_algorithm,
+            quota_warn_thresholds,
+            quota_warn_template: Template::parse(include_str!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/../../resources/html-templates/quota-warning.html.min"
+            )))
+            .expect("Failed to parse quota warning template"),
+            quarantine_hold: dr.hold_quarantine_for.into_inner(),
+            quarantine_digest: dr
+                .send_quarantine_digest
+                .then(|| dr.quarantine_digest_schedule.into()),
+            quarantine_release_url: format!(
+                "https://{}/quarantine/release",
+                system.default_hostname
+            ),
+            quarantine_template: Template::parse(include_str!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/../../resources/html-templates/quarantine-digest.html.min"
+            )))
+            .expect("Failed to parse quarantine digest template"),
+            quarantine_release_template: Template::parse(include_str!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/../../resources/html-templates/quarantine-release.html.min"
+            )))
+            .expect("Failed to parse quarantine release template"),
             default_domain_id: system.default_domain_id.id() as u32,
             default_domain_name,
         }
     }
 }
+
+impl FromStr for QuotaTemplateVariable {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "page_title" => Ok(QuotaTemplateVariable::PageTitle),
+            "header" => Ok(QuotaTemplateVariable::Header),
+            "footer" => Ok(QuotaTemplateVariable::Footer),
+            "title" => Ok(QuotaTemplateVariable::Title),
+            "description" => Ok(QuotaTemplateVariable::Description),
+            "details" => Ok(QuotaTemplateVariable::Details),
+            "key" => Ok(QuotaTemplateVariable::Key),
+            "value" => Ok(QuotaTemplateVariable::Value),
+            "logo_cid" => Ok(QuotaTemplateVariable::LogoCid),
+            _ => Err(format!("Unknown quota template variable: {}", s)),
+        }
+    }
+}
+
+impl FromStr for QuarantineTemplateVariable {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "page_title" => Ok(QuarantineTemplateVariable::PageTitle),
+            "header" => Ok(QuarantineTemplateVariable::Header),
+            "footer" => Ok(QuarantineTemplateVariable::Footer),
+            "title" => Ok(QuarantineTemplateVariable::Title),
+            "description" => Ok(QuarantineTemplateVariable::Description),
+            "items" => Ok(QuarantineTemplateVariable::Items),
+            "from" => Ok(QuarantineTemplateVariable::From),
+            "subject" => Ok(QuarantineTemplateVariable::Subject),
+            "reason" => Ok(QuarantineTemplateVariable::Reason),
+            "received_at" => Ok(QuarantineTemplateVariable::ReceivedAt),
+            "release_url" => Ok(QuarantineTemplateVariable::ReleaseUrl),
+            "release_label" => Ok(QuarantineTemplateVariable::ReleaseLabel),
+            "logo_cid" => Ok(QuarantineTemplateVariable::LogoCid),
+            "token" => Ok(QuarantineTemplateVariable::Token),
+            _ => Err(format!("Unknown quarantine template variable: {}", s)),
+        }
+    }
+}
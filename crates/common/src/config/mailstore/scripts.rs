@@ -12,12 +12,12 @@ use crate::{
         plugins::RegisterSievePlugins,
     },
 };
-use ahash::AHashMap;
+use ahash::{AHashMap, AHashSet};
 use registry::{
     schema::{
         prelude::ObjectType,
         structs::{
-            SieveSystemInterpreter, SieveSystemScript, SieveUserInterpreter, SieveUserScript,
+            Rate, SieveSystemInterpreter, SieveSystemScript, SieveUserInterpreter, SieveUserScript,
             SystemSettings,
         },
     },
@@ -30,6 +30,8 @@ use store::registry::bootstrap::Bootstrap;
 pub struct Scripting {
     pub untrusted_compiler: Compiler,
     pub untrusted_runtime: Runtime,
+    pub untrusted_notify_rate: Option<Rate>,
+    pub untrusted_ext_lists: AHashSet<String>,
     pub trusted_runtime: Runtime,
     pub from_addr: IfBlock,
     pub from_name: IfBlock,
@@ -58,6 +60,12 @@ impl Scripting {
             .register_functions(&mut fnc_map_untrusted);
 
         // Parse untrusted runtime
+        let untrusted_notify_rate = untrusted.rate_limit_notify;
+        let untrusted_ext_lists = untrusted
+            .allowed_ext_lists
+            .into_inner()
+            .into_iter()
+            .collect();
         let untrusted_runtime = Runtime::new()
             .with_functions(&mut fnc_map_untrusted)
             .with_max_nested_includes(untrusted.max_nested_includes as usize)
@@ -175,6 +183,8 @@ impl Scripting {
         Scripting {
             untrusted_compiler,
             untrusted_runtime,
+            untrusted_notify_rate,
+            untrusted_ext_lists,
             trusted_runtime,
             untrusted_scripts,
             trusted_scripts,
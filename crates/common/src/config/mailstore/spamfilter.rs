@@ -42,6 +42,15 @@ pub enum SpamClassifier {
     Disabled,
 }
 
+// Classifier built from an account's personalized model, cached in memory
+// so that it is not deserialized for every message.
+#[derive(Debug, Default)]
+pub struct AccountSpamClassifier {
+    pub classifier: FhClassifier,
+    pub ham_count: u64,
+    pub spam_count: u64,
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct SpamFilterConfig {
     pub enabled: bool,
@@ -85,6 +94,7 @@ pub enum SpamFilterAction<T> {
     Allow(T),
     Discard,
     Reject,
+    Quarantine,
     Disabled,
 }
 
@@ -103,6 +113,14 @@ pub struct ClassifierConfig {
     pub train_frequency: Option<u64>,
     pub log_scale: bool,
     pub l2_normalize: bool,
+    pub account_model: Option<AccountModelConfig>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct AccountModelConfig {
+    pub weight: f32,
+    pub min_samples: u64,
+    pub max_age: u64,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -387,6 +405,9 @@ impl SpamFilterLists {
                 SpamTag::Reject(tag) => lists
                     .scores
                     .insert_pattern(&tag.tag, SpamFilterAction::Reject),
+                SpamTag::Quarantine(tag) => lists
+                    .scores
+                    .insert_pattern(&tag.tag, SpamFilterAction::Quarantine),
             }
         }
 
@@ -487,6 +508,11 @@ impl ClassifierConfig {
             train_frequency: classifier.train_frequency.map(|d| d.into_inner().as_secs()),
             log_scale,
             l2_normalize,
+            account_model: classifier.account_model.then(|| AccountModelConfig {
+                weight: classifier.account_model_weight.into_inner() as f32,
+                min_samples: classifier.account_model_min_samples,
+                max_age: classifier.account_model_max_age.into_inner().as_secs(),
+            }),
         }
         .into()
     }
@@ -609,6 +635,13 @@ impl IpResolver {
     }
 }
 
+impl CacheItemWeight for AccountSpamClassifier {
+    fn weight(&self) -> u64 {
+        (std::mem::size_of::<AccountSpamClassifier>()
+            + std::mem::size_of_val(self.classifier.parameters())) as u64
+    }
+}
+
 impl CacheItemWeight for IpResolver {
     fn weight(&self) -> u64 {
         (std::mem::size_of::<IpResolver>() + self.ip_string.len() + self.reverse.len()) as u64
@@ -57,6 +57,7 @@ pub struct Http {
     pub allowed_endpoint: IfBlock,
     pub response_headers: Vec<(hyper::header::HeaderName, hyper::header::HeaderValue)>,
     pub use_forwarded: bool,
+    pub compression_min_size: Option<usize>,
 }
 
 #[derive(Clone)]
@@ -423,6 +424,9 @@ impl Http {
             rate_anonymous: http.rate_limit_anonymous,
             response_headers: http_headers,
             use_forwarded: http.use_x_forwarded,
+            compression_min_size: http
+                .enable_compression
+                .then_some(http.compression_min_size as usize),
         }
     }
 }
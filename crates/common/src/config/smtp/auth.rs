@@ -17,11 +17,14 @@ use registry::{
     schema::{
         enums::{self, ExpressionConstant},
         prelude::ObjectType,
-        structs::{Dkim1Signature, DkimSignature, SenderAuth},
+        structs::{Dkim1Signature, DkimSignature, SecretKeyOptional, SenderAuth},
     },
     types::ObjectImpl,
 };
-use rustls_pki_types::{PrivateKeyDer, PrivatePkcs1KeyDer, PrivatePkcs8KeyDer, pem::PemObject};
+use ring::hmac;
+use rustls_pki_types::{
+    CertificateDer, PrivateKeyDer, PrivatePkcs1KeyDer, PrivatePkcs8KeyDer, pem::PemObject,
+};
 use store::registry::bootstrap::Bootstrap;
 use utils::cache::CacheItemWeight;
 
@@ -31,7 +34,9 @@ pub struct MailAuthConfig {
     pub arc: ArcAuthConfig,
     pub spf: SpfAuthConfig,
     pub dmarc: DmarcAuthConfig,
+    pub bimi: BimiAuthConfig,
     pub iprev: IpRevAuthConfig,
+    pub srs: SrsAuthConfig,
 }
 
 #[derive(Clone)]
@@ -58,11 +63,26 @@ pub struct DmarcAuthConfig {
     pub verify: IfBlock,
 }
 
+#[derive(Clone)]
+pub struct BimiAuthConfig {
+    pub verify: bool,
+    pub require_vmc: bool,
+    pub max_size: usize,
+    pub trust_anchors: Vec<CertificateDer<'static>>,
+}
+
 #[derive(Clone)]
 pub struct IpRevAuthConfig {
     pub verify: IfBlock,
 }
 
+#[derive(Clone)]
+pub struct SrsAuthConfig {
+    pub rewrite_domain: IfBlock,
+    pub keys: Vec<hmac::Key>,
+    pub max_age: u64,
+}
+
 #[derive(Debug, Clone, Copy, Default)]
 pub enum VerifyStrategy {
     #[default]
@@ -85,6 +105,26 @@ impl MailAuthConfig {
     pub async fn parse(bp: &mut Bootstrap) -> Self {
         let auth = bp.setting_infallible::<SenderAuth>().await;
 
+        // The previous key is only used for verification while rotating keys
+        let mut srs_keys = Vec::with_capacity(2);
+        if let Some(key) = srs_key(bp, &auth.srs_signing_key).await {
+            srs_keys.push(key);
+            srs_keys.extend(srs_key(bp, &auth.srs_previous_key).await);
+        }
+
+        let bimi_trust_anchors = match auth.bimi_trust_anchors.as_deref() {
+            Some(pem) => CertificateDer::pem_slice_iter(pem.as_bytes())
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap_or_else(|err| {
+                    bp.build_error(
+                        ObjectType::SenderAuth.singleton(),
+                        format!("Failed to parse BIMI trust anchors: {err}"),
+                    );
+                    vec![]
+                }),
+            None => vec![],
+        };
+
         MailAuthConfig {
             dkim: DkimAuthConfig {
                 verify: bp
@@ -113,12 +153,38 @@ impl MailAuthConfig {
                 verify: bp
                     .compile_expr(ObjectType::SenderAuth.singleton(), &auth.ctx_dmarc_verify()),
             },
+            bimi: BimiAuthConfig {
+                verify: auth.bimi_verify,
+                require_vmc: auth.bimi_require_vmc,
+                max_size: auth.bimi_max_size as usize,
+                trust_anchors: bimi_trust_anchors,
+            },
             iprev: IpRevAuthConfig {
                 verify: bp.compile_expr(
                     ObjectType::SenderAuth.singleton(),
                     &auth.ctx_reverse_ip_verify(),
                 ),
             },
+            srs: SrsAuthConfig {
+                rewrite_domain: bp
+                    .compile_expr(ObjectType::SenderAuth.singleton(), &auth.ctx_srs_domain()),
+                keys: srs_keys,
+                max_age: auth.srs_max_age.as_secs(),
+            },
+        }
+    }
+}
+
+async fn srs_key(bp: &mut Bootstrap, secret: &SecretKeyOptional) -> Option<hmac::Key> {
+    match secret.secret().await {
+        Ok(secret) => secret
+            .map(|secret| hmac::Key::new(hmac::HMAC_SHA1_FOR_LEGACY_USE_ONLY, secret.as_bytes())),
+        Err(err) => {
+            bp.build_error(
+                ObjectType::SenderAuth.singleton(),
+                format!("Unable to retrieve SRS key: {err}"),
+            );
+            None
         }
     }
 }
@@ -6,6 +6,7 @@
 
 use super::*;
 use crate::{
+    KV_IP_WARM_UP,
     config::server::ServerProtocol,
     expr::{
         if_block::{BootstrapExprExt, IfBlock},
@@ -28,8 +29,10 @@ use std::{
     fmt::Display,
     hash::{Hash, Hasher},
     net::IpAddr,
+    str::FromStr,
     time::Duration,
 };
+use utils::template::Template;
 
 #[derive(
     Debug,
@@ -92,6 +95,20 @@ pub struct Dsn {
     pub name: IfBlock,
     pub address: IfBlock,
     pub sign: IfBlock,
+    pub template: Template<DsnTemplateVariable>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, Hash)]
+pub enum DsnTemplateVariable {
+    #[default]
+    PageTitle,
+    Header,
+    Footer,
+    Title,
+    Recipients,
+    Section,
+    Address,
+    Details,
 }
 
 #[derive(Clone, Debug)]
@@ -146,12 +163,27 @@ pub struct ConnectionStrategy {
     pub timeout_mail: Duration,
     pub timeout_rcpt: Duration,
     pub timeout_data: Duration,
+
+    pub reuse_max_idle: usize,
+    pub reuse_idle_timeout: Duration,
+    pub reuse_max_messages: usize,
 }
 
 #[derive(Clone, Debug)]
 pub struct IpAndHost {
     pub ip: IpAddr,
     pub host: Option<String>,
+    pub warm_up: Option<IpWarmUp>,
+}
+
+#[derive(Clone, Debug)]
+pub struct IpWarmUp {
+    pub id: u64,
+    pub strategy_id: u64,
+    pub start: u64,
+    pub days: u64,
+    pub initial_volume: u64,
+    pub target_volume: u64,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -177,14 +209,22 @@ pub struct QueueQuota {
     pub messages: Option<u64>,
 }
 
-#[derive(Clone, Hash, PartialEq, Eq)]
+#[derive(Clone, Hash, PartialEq, Eq, Debug)]
 pub struct RelayConfig {
-    pub address: HostOrIp<Box<str>, IpStr>,
-    pub port: u16,
+    pub hosts: Vec<RelayHost>,
     pub protocol: ServerProtocol,
-    pub auth: Option<Credentials>,
     pub tls_implicit: bool,
     pub tls_allow_invalid_certs: bool,
+    pub host_cooldown: Duration,
+}
+
+#[derive(Clone, Hash, PartialEq, Eq)]
+pub struct RelayHost {
+    pub address: HostOrIp<Box<str>, IpStr>,
+    pub port: u16,
+    pub auth: Option<Credentials>,
+    pub priority: u16,
+    pub weight: u16,
 }
 
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
@@ -236,6 +276,11 @@ impl QueueConfig {
                     ObjectType::DsnReportSettings.singleton(),
                     &dsn.ctx_dkim_sign_domain(),
                 ),
+                template: Template::parse(include_str!(concat!(
+                    env!("CARGO_MANIFEST_DIR"),
+                    "/../../resources/html-templates/dsn.html.min"
+                )))
+                .expect("Failed to parse DSN template"),
             },
             inbound_limiters: QueueRateLimiters::parse_inbound(bp).await,
             outbound_limiters: QueueRateLimiters::parse_outbound(bp).await,
@@ -322,10 +367,21 @@ impl QueueConfig {
             let mut source_ipv4 = Vec::new();
             let mut source_ipv6 = Vec::new();
 
+            let strategy_id = obj.id.id().id();
             for ip_host in obj.object.source_ips {
+                let ip = ip_host.source_ip.into_inner();
+                let warm_up = ip_host.warm_up_start.map(|start| IpWarmUp {
+                    id: IpWarmUp::build_id(strategy_id, ip),
+                    strategy_id,
+                    start: start.timestamp().max(0) as u64,
+                    days: ip_host.warm_up_days.max(1),
+                    initial_volume: ip_host.warm_up_initial_volume.max(1),
+                    target_volume: ip_host.warm_up_target_volume.max(1),
+                });
                 let ip_host = IpAndHost {
-                    ip: ip_host.source_ip.into_inner(),
+                    ip,
                     host: ip_host.ehlo_hostname,
+                    warm_up,
                 };
                 if ip_host.ip.is_ipv4() {
                     source_ipv4.push(ip_host);
@@ -346,6 +402,9 @@ impl QueueConfig {
                     timeout_mail: obj.object.mail_from_timeout.into_inner(),
                     timeout_rcpt: obj.object.rcpt_to_timeout.into_inner(),
                     timeout_data: obj.object.data_timeout.into_inner(),
+                    reuse_max_idle: obj.object.reuse_max_idle as usize,
+                    reuse_idle_timeout: obj.object.reuse_idle_timeout.into_inner(),
+                    reuse_max_messages: obj.object.reuse_max_messages.max(1) as usize,
                 },
             );
         }
@@ -377,32 +436,64 @@ impl QueueConfig {
                             bp.build_error(obj.id, err);
                         })
                         .unwrap_or_default();
+                    let auth = route
+                        .auth_username
+                        .and_then(|user| secret.map(|secret| (user, secret)))
+                        .map(|(user, secret)| Credentials::Basic {
+                            username: user,
+                            secret: secret.into_owned(),
+                            mfa_token: None,
+                        });
+
+                    // The primary address has priority 0, failover hosts are tried by priority
+                    let mut hosts = Vec::with_capacity(route.hosts.len() + 1);
+                    hosts.push(RelayHost {
+                        address: HostOrIp::parse(route.address),
+                        port: route.port as u16,
+                        auth: auth.clone(),
+                        priority: 0,
+                        weight: 1,
+                    });
+                    for host in route.hosts {
+                        let auth = if let Some(username) = host.auth_username {
+                            let secret = host
+                                .auth_secret
+                                .secret()
+                                .await
+                                .map_err(|err| {
+                                    bp.build_error(obj.id, err);
+                                })
+                                .unwrap_or_default();
+                            secret.map(|secret| Credentials::Basic {
+                                username,
+                                secret: secret.into_owned(),
+                                mfa_token: None,
+                            })
+                        } else {
+                            auth.clone()
+                        };
+
+                        hosts.push(RelayHost {
+                            address: HostOrIp::parse(host.address),
+                            port: host.port as u16,
+                            auth,
+                            priority: host.priority as u16,
+                            weight: host.weight as u16,
+                        });
+                    }
+                    hosts.sort_by_key(|host| host.priority);
+
                     queue.routing_strategy.insert(
                         route.name,
                         RoutingStrategy::Relay(RelayConfig {
-                            address: if let Ok(ip) = route.address.parse() {
-                                HostOrIp::Ip(IpStr {
-                                    ip,
-                                    ip_str: route.address.into(),
-                                })
-                            } else {
-                                HostOrIp::Host(route.address.into())
-                            },
-                            port: route.port as u16,
+                            hosts,
                             protocol: match route.protocol {
                                 enums::MtaProtocol::Smtp => ServerProtocol::Smtp,
                                 enums::MtaProtocol::Lmtp => ServerProtocol::Lmtp,
                             },
-                            auth: route
-                                .auth_username
-                                .and_then(|user| secret.map(|secret| (user, secret)))
-                                .map(|(user, secret)| Credentials::Basic {
-                                    username: user,
-                                    secret: secret.into_owned(),
-                                    mfa_token: None,
-                                }),
                             tls_implicit: route.implicit_tls,
                             tls_allow_invalid_certs: route.allow_invalid_certs,
+                            host_cooldown: route.host_cooldown.into_inner(),
                         }),
                     );
                 }
@@ -627,6 +718,24 @@ impl QueueQuotas {
     }
 }
 
+impl FromStr for DsnTemplateVariable {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "page_title" => Ok(DsnTemplateVariable::PageTitle),
+            "header" => Ok(DsnTemplateVariable::Header),
+            "footer" => Ok(DsnTemplateVariable::Footer),
+            "title" => Ok(DsnTemplateVariable::Title),
+            "recipients" => Ok(DsnTemplateVariable::Recipients),
+            "section" => Ok(DsnTemplateVariable::Section),
+            "address" => Ok(DsnTemplateVariable::Address),
+            "details" => Ok(DsnTemplateVariable::Details),
+            _ => Err(format!("Unknown DSN template variable: {}", s)),
+        }
+    }
+}
+
 impl<'x> TryFrom<Variable<'x>> for RequireOptional {
     type Error = ();
 
@@ -667,18 +776,81 @@ impl<'x> TryFrom<Variable<'x>> for IpLookupStrategy {
     }
 }
 
-impl std::fmt::Debug for RelayConfig {
+impl std::fmt::Debug for RelayHost {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("RelayConfig")
+        f.debug_struct("RelayHost")
             .field("address", &self.address)
             .field("port", &self.port)
-            .field("protocol", &self.protocol)
-            .field("tls_implicit", &self.tls_implicit)
-            .field("tls_allow_invalid_certs", &self.tls_allow_invalid_certs)
+            .field("priority", &self.priority)
+            .field("weight", &self.weight)
             .finish()
     }
 }
 
+impl HostOrIp<Box<str>, IpStr> {
+    pub fn parse(address: String) -> Self {
+        if let Ok(ip) = address.parse() {
+            HostOrIp::Ip(IpStr {
+                ip,
+                ip_str: address.into(),
+            })
+        } else {
+            HostOrIp::Host(address.into())
+        }
+    }
+}
+
+impl IpWarmUp {
+    pub const DAY: u64 = 86400;
+
+    pub fn build_id(strategy_id: u64, ip: IpAddr) -> u64 {
+        let mut key = strategy_id.to_be_bytes().to_vec();
+        match ip {
+            IpAddr::V4(ip) => key.extend_from_slice(&ip.octets()),
+            IpAddr::V6(ip) => key.extend_from_slice(&ip.octets()),
+        }
+        xxhash_rust::xxh3::xxh3_64(&key)
+    }
+
+    /// Zero-based day of the warm-up schedule, or None if it has not started yet.
+    pub fn day(&self, now: u64) -> Option<u64> {
+        now.checked_sub(self.start)
+            .map(|elapsed| elapsed / Self::DAY)
+    }
+
+    /// Messages allowed per destination provider on the given day, or None once the
+    /// warm-up is complete. The volume grows geometrically from the initial volume on
+    /// the first day to the target volume on the last day.
+    pub fn daily_limit(&self, day: u64) -> Option<u64> {
+        if day >= self.days {
+            None
+        } else if self.days == 1 {
+            Some(self.initial_volume)
+        } else {
+            let ratio = self.target_volume as f64 / self.initial_volume as f64;
+            let limit =
+                self.initial_volume as f64 * ratio.powf(day as f64 / (self.days - 1) as f64);
+            Some((limit.round() as u64).max(1))
+        }
+    }
+
+    pub fn day_ends_at(&self, day: u64) -> u64 {
+        self.start + (day + 1) * Self::DAY
+    }
+
+    pub fn counter_key(&self, ip: IpAddr, day: u64, provider: &str) -> Vec<u8> {
+        let mut key = Vec::with_capacity(32 + provider.len());
+        key.push(KV_IP_WARM_UP);
+        match ip {
+            IpAddr::V4(ip) => key.extend_from_slice(&ip.octets()),
+            IpAddr::V6(ip) => key.extend_from_slice(&ip.octets()),
+        }
+        key.extend_from_slice(&day.to_be_bytes());
+        key.extend_from_slice(provider.as_bytes());
+        key
+    }
+}
+
 impl TlsStrategy {
     #[inline(always)]
     pub fn try_dane(&self) -> bool {
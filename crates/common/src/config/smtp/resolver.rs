@@ -102,6 +102,56 @@ impl CacheItemWeight for Policy {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BimiRecord {
+    pub location: Option<Box<str>>,
+    pub authority: Option<Box<str>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BimiIndicator {
+    Valid(Arc<BimiLogo>),
+    Invalid(Arc<str>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BimiLogo {
+    pub svg: Box<[u8]>,
+    pub has_vmc: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BimiResult {
+    Pass {
+        domain: String,
+        selector: String,
+        record: Arc<BimiRecord>,
+        logo: Arc<BimiLogo>,
+    },
+    Fail(String),
+    TempError(String),
+    Declined,
+    None,
+}
+
+impl CacheItemWeight for BimiRecord {
+    fn weight(&self) -> u64 {
+        (std::mem::size_of::<BimiRecord>()
+            + self.location.as_ref().map_or(0, |l| l.len())
+            + self.authority.as_ref().map_or(0, |a| a.len())) as u64
+    }
+}
+
+impl CacheItemWeight for BimiIndicator {
+    fn weight(&self) -> u64 {
+        (std::mem::size_of::<BimiIndicator>()
+            + match self {
+                BimiIndicator::Valid(logo) => logo.svg.len(),
+                BimiIndicator::Invalid(reason) => reason.len(),
+            }) as u64
+    }
+}
+
 impl Resolvers {
     pub async fn parse(bp: &mut Bootstrap) -> Self {
         let mut resolver_config: ResolverConfig;
@@ -171,6 +171,7 @@ pub enum Stage {
     Mail,
     Rcpt,
     Data,
+    Delivery,
 }
 
 impl SessionConfig {
@@ -558,6 +559,7 @@ impl From<MtaStage> for Stage {
             MtaStage::Mail => Stage::Mail,
             MtaStage::Rcpt => Stage::Rcpt,
             MtaStage::Data => Stage::Data,
+            MtaStage::Delivery => Stage::Delivery,
         }
     }
 }
@@ -51,6 +51,7 @@ pub enum PushNotification {
     StateChange(StateChange),
     CalendarAlert(CalendarAlert),
     EmailPush(EmailPush),
+    SieveNotify(SieveNotify),
 }
 
 #[derive(Debug, Clone)]
@@ -60,6 +61,13 @@ pub struct EmailPush {
     pub change_id: u64,
 }
 
+#[derive(Debug, Clone)]
+pub struct SieveNotify {
+    pub account_id: u32,
+    pub importance: u8,
+    pub message: String,
+}
+
 #[derive(Debug, Clone)]
 pub struct CalendarAlert {
     pub account_id: u32,
@@ -99,6 +107,7 @@ pub enum CacheInvalidation {
     List(u32),
     DomainLogo(u32),
     TenantLogo(u32),
+    SpamModel(u32),
 }
 
 #[derive(Debug)]
@@ -264,6 +273,7 @@ impl PushNotification {
             PushNotification::StateChange(state_change) => state_change.account_id,
             PushNotification::CalendarAlert(calendar_alert) => calendar_alert.account_id,
             PushNotification::EmailPush(email_push) => email_push.account_id,
+            PushNotification::SieveNotify(sieve_notify) => sieve_notify.account_id,
         }
     }
 
@@ -289,6 +299,13 @@ impl PushNotification {
                     None
                 }
             }
+            PushNotification::SieveNotify(_) => {
+                if types.contains(DataType::EmailDelivery) {
+                    Some(self.clone())
+                } else {
+                    None
+                }
+            }
             PushNotification::EmailPush(_) => {
                 if types.contains_any(
                     [
@@ -16,12 +16,12 @@ use crate::{
             email::EmailConfig,
             imap::ImapConfig,
             scripts::Scripting,
-            spamfilter::{IpResolver, SpamClassifier, SpamFilterConfig},
+            spamfilter::{AccountSpamClassifier, IpResolver, SpamClassifier, SpamFilterConfig},
         },
         smtp::auth::DkimSigner,
     },
     ipc::TrainTaskController,
-    network::security::BlockedIps,
+    network::{pool::SmtpSessionPool, security::BlockedIps},
 };
 use ahash::{AHashMap, AHashSet};
 use arc_swap::ArcSwap;
@@ -33,7 +33,7 @@ use config::{
     network::Network,
     smtp::{
         SmtpConfig,
-        resolver::{Policy, Tlsa},
+        resolver::{BimiIndicator, BimiRecord, Policy, Tlsa},
     },
     storage::Storage,
     telemetry::Metrics,
@@ -100,10 +100,11 @@ Schema history:
 4 - v0.14.0
 5 - v0.15.0
 6 - v0.16.0
+7 - v1.0.0
 
 */
 
-pub const DATABASE_SCHEMA_VERSION: u32 = 6;
+pub const DATABASE_SCHEMA_VERSION: u32 = 7;
 
 pub const LONG_1D_SLUMBER: Duration = Duration::from_secs(60 * 60 * 24);
 pub const LONG_1Y_SLUMBER: Duration = Duration::from_secs(60 * 60 * 24 * 365);
@@ -127,6 +128,10 @@ pub const KV_LOCK_QUEUE_MESSAGE: u8 = 21;
 pub const KV_LOCK_TASK: u8 = 23;
 pub const KV_LOCK_DAV: u8 = 25;
 pub const KV_SIEVE_ID: u8 = 26;
+pub const KV_RATE_LIMIT_NOTIFY: u8 = 27;
+pub const KV_QUOTA_WARNING: u8 = 28;
+pub const KV_QUARANTINE_DIGEST: u8 = 29;
+pub const KV_IP_WARM_UP: u8 = 30;
 
 #[derive(Clone)]
 pub struct Server {
@@ -163,6 +168,14 @@ pub struct Data {
     pub logos: Mutex<AHashMap<Box<str>, LogoCache>>,
 
     pub smtp_connectors: TlsConnectors,
+    pub smtp_sessions: SmtpSessionPool,
+    pub relay_stats: Mutex<AHashMap<Box<str>, RelayHostStats>>,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct RelayHostStats {
+    pub success: u64,
+    pub failed: u64,
 }
 
 #[derive(Clone)]
@@ -194,6 +207,7 @@ pub struct Caches {
     pub lists: Cache<u32, Arc<MailingListCache>>,
 
     pub dkim_signers: Cache<u32, Arc<[DkimSigner]>>,
+    pub spam_models: Cache<u32, Option<Arc<AccountSpamClassifier>>>,
 
     pub dns_txt: CacheWithTtl<Box<str>, Txt>,
     pub dns_mx: CacheWithTtl<Box<str>, Arc<[MX]>>,
@@ -203,6 +217,10 @@ pub struct Caches {
     pub dns_tlsa: CacheWithTtl<Box<str>, Arc<Tlsa>>,
     pub dns_mta_sts: CacheWithTtl<Box<str>, Arc<Policy>>,
     pub dns_rbl: CacheWithTtl<Box<str>, Option<Arc<IpResolver>>>,
+    pub dns_bimi: CacheWithTtl<Box<str>, Option<Arc<BimiRecord>>>,
+    pub bimi_indicators: CacheWithTtl<Box<str>, BimiIndicator>,
+
+    pub relay_unhealthy: CacheWithTtl<Box<str>, ()>,
 
     pub negative_cache_ttl: Duration,
 }
@@ -336,11 +354,13 @@ pub enum DavResourceMetadata {
         name: String,
         acls: TinyVec<[AclGrant; 2]>,
         preferences: TinyVec<[TinyCalendarPreferences; 2]>,
+        supports_tasks: bool,
     },
     CalendarEvent {
         names: TinyVec<[DavName; 2]>,
         start: i64,
         duration: u32,
+        is_task: bool,
     },
     CalendarEventNotification {
         names: TinyVec<[DavName; 2]>,
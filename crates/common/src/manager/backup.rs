@@ -322,7 +322,11 @@ impl Family {
                 SUBSPACE_DIRECTORY,
             ],
             Family::Changelog => &[SUBSPACE_LOGS],
-            Family::Queue => &[SUBSPACE_QUEUE_MESSAGE, SUBSPACE_QUEUE_EVENT],
+            Family::Queue => &[
+                SUBSPACE_QUEUE_MESSAGE,
+                SUBSPACE_QUEUE_EVENT,
+                SUBSPACE_QUARANTINE,
+            ],
             Family::Report => &[SUBSPACE_REPORT_OUT, SUBSPACE_REPORT_IN],
             Family::Telemetry => &[SUBSPACE_TELEMETRY_SPAN, SUBSPACE_TELEMETRY_METRIC],
             Family::Tasks => &[SUBSPACE_TASK_QUEUE],
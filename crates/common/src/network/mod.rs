@@ -31,9 +31,11 @@ pub mod dns;
 pub mod limiter;
 pub mod listen;
 pub mod mta;
+pub mod pool;
 pub mod security;
 pub mod stream;
 pub mod tls;
+pub mod wkd;
 
 #[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
 pub enum RcptResolution {
@@ -98,6 +100,7 @@ pub enum SessionResult {
     Continue,
     Close,
     UpgradeTls,
+    UpgradeCompression,
 }
 
 pub trait SessionManager: Sync + Send + 'static + Clone {
@@ -340,6 +340,9 @@ impl Server {
             timeout_mail: Duration::from_secs(5 * 60),
             timeout_rcpt: Duration::from_secs(5 * 60),
             timeout_data: Duration::from_secs(10 * 60),
+            reuse_max_idle: 0,
+            reuse_idle_timeout: Duration::from_secs(30),
+            reuse_max_messages: 100,
         };
 
         self.core
@@ -0,0 +1,139 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use ahash::AHashMap;
+use directory::Credentials;
+use parking_lot::Mutex;
+use smtp_proto::EhloResponse;
+use std::{net::IpAddr, time::Instant};
+use tokio::net::TcpStream;
+use tokio_rustls::client::TlsStream;
+
+/// Idle outbound SMTP sessions that can be reused to deliver subsequent messages
+/// to the same destination.
+#[derive(Default)]
+pub struct SmtpSessionPool {
+    sessions: Mutex<AHashMap<SmtpSessionKey, Vec<IdleSmtpSession>>>,
+}
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct SmtpSessionKey {
+    pub remote_ip: IpAddr,
+    pub remote_port: u16,
+    pub local_ip: IpAddr,
+    pub hostname: Box<str>,
+    pub credentials: Option<Credentials>,
+    pub is_smtp: bool,
+    pub is_strict_tls: bool,
+    pub allow_invalid_certs: bool,
+}
+
+pub struct IdleSmtpSession {
+    pub stream: IdleSmtpStream,
+    pub capabilities: EhloResponse<String>,
+    pub limits: SmtpLimits,
+    pub transactions: usize,
+    pub expires: Instant,
+}
+
+pub enum IdleSmtpStream {
+    Plain(TcpStream),
+    Tls(Box<TlsStream<TcpStream>>),
+}
+
+/// Limits advertised by the remote server using the LIMITS extension (RFC 9422).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SmtpLimits {
+    pub rcpt_max: Option<usize>,
+    pub rcpt_domain_max: Option<usize>,
+    pub mail_max: Option<usize>,
+}
+
+impl SmtpSessionPool {
+    /// Removes the most recently used idle session for the destination, if any.
+    pub fn take(&self, key: &SmtpSessionKey) -> Option<IdleSmtpSession> {
+        let mut sessions = self.sessions.lock();
+        let idle = sessions.get_mut(key)?;
+        let session = idle.pop();
+        if idle.is_empty() {
+            sessions.remove(key);
+        }
+        session
+    }
+
+    /// Adds a session to the pool, returning it back if the destination
+    /// already has `max_idle` idle sessions.
+    pub fn put(
+        &self,
+        key: SmtpSessionKey,
+        session: IdleSmtpSession,
+        max_idle: usize,
+    ) -> Option<IdleSmtpSession> {
+        let mut sessions = self.sessions.lock();
+        let idle = sessions.entry(key).or_default();
+        if idle.len() < max_idle {
+            idle.push(session);
+            None
+        } else {
+            Some(session)
+        }
+    }
+
+    /// Removes all sessions that have been idle for longer than their timeout.
+    pub fn take_expired(&self) -> Vec<IdleSmtpSession> {
+        let now = Instant::now();
+        let mut expired = Vec::new();
+        let mut sessions = self.sessions.lock();
+        for idle in sessions.values_mut() {
+            expired.extend(idle.extract_if(.., |session| session.expires <= now));
+        }
+        sessions.retain(|_, idle| !idle.is_empty());
+        expired
+    }
+
+    pub fn idle_sessions(&self) -> usize {
+        self.sessions.lock().values().map(|idle| idle.len()).sum()
+    }
+}
+
+impl SmtpLimits {
+    pub fn parse(ehlo: &[u8]) -> Self {
+        let mut limits = SmtpLimits::default();
+
+        for line in ehlo.split(|&ch| ch == b'\n') {
+            // Skip the reply code and separator, i.e. "250-LIMITS ..."
+            let Some(params) = line
+                .get(4..)
+                .filter(|line| {
+                    line.get(..7)
+                        .is_some_and(|ext| ext.eq_ignore_ascii_case(b"LIMITS "))
+                })
+                .and_then(|line| std::str::from_utf8(&line[7..]).ok())
+            else {
+                continue;
+            };
+
+            for param in params.split_ascii_whitespace() {
+                let Some((name, value)) = param.split_once('=') else {
+                    continue;
+                };
+                let Some(value) = value.parse::<usize>().ok().filter(|value| *value > 0) else {
+                    continue;
+                };
+
+                if name.eq_ignore_ascii_case("RCPTMAX") {
+                    limits.rcpt_max = Some(value);
+                } else if name.eq_ignore_ascii_case("RCPTDOMAINMAX") {
+                    limits.rcpt_domain_max = Some(value);
+                } else if name.eq_ignore_ascii_case("MAILMAX") {
+                    limits.mail_max = Some(value);
+                }
+            }
+        }
+
+        limits
+    }
+}
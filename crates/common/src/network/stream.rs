@@ -4,11 +4,12 @@
  * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
  */
 
-use std::borrow::Cow;
+use std::{borrow::Cow, pin::Pin, task::Context, task::Poll};
 
+use async_compression::tokio::{bufread::DeflateDecoder, write::DeflateEncoder};
 use proxy_header::io::ProxiedStream;
 use tokio::{
-    io::{AsyncRead, AsyncWrite},
+    io::{AsyncRead, AsyncWrite, BufReader, ReadBuf, ReadHalf, WriteHalf},
     net::TcpStream,
 };
 use tokio_rustls::server::TlsStream;
@@ -141,3 +142,71 @@ impl SessionStream for NullIo {
         )
     }
 }
+
+// Raw DEFLATE (RFC 1951) layer on top of a session stream, as used by IMAP COMPRESS (RFC 4978).
+// Every flush emits a sync flush so the peer can decode each response as soon as it is sent.
+pub struct DeflateStream<T: SessionStream> {
+    reader: DeflateDecoder<BufReader<ReadHalf<T>>>,
+    writer: DeflateEncoder<WriteHalf<T>>,
+    is_tls: bool,
+    tls_version_and_cipher: (Cow<'static, str>, Cow<'static, str>),
+}
+
+impl<T: SessionStream> DeflateStream<T> {
+    pub fn new(stream: T) -> Self {
+        let is_tls = stream.is_tls();
+        let tls_version_and_cipher = stream.tls_version_and_cipher();
+        let (stream_rx, stream_tx) = tokio::io::split(stream);
+
+        DeflateStream {
+            reader: DeflateDecoder::new(BufReader::new(stream_rx)),
+            writer: DeflateEncoder::new(stream_tx),
+            is_tls,
+            tls_version_and_cipher,
+        }
+    }
+}
+
+impl<T: SessionStream> AsyncRead for DeflateStream<T> {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<std::io::Result<()>> {
+        Pin::new(&mut self.reader).poll_read(cx, buf)
+    }
+}
+
+impl<T: SessionStream> AsyncWrite for DeflateStream<T> {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<Result<usize, std::io::Error>> {
+        Pin::new(&mut self.writer).poll_write(cx, buf)
+    }
+
+    fn poll_flush(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<(), std::io::Error>> {
+        Pin::new(&mut self.writer).poll_flush(cx)
+    }
+
+    fn poll_shutdown(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<(), std::io::Error>> {
+        Pin::new(&mut self.writer).poll_shutdown(cx)
+    }
+}
+
+impl<T: SessionStream> SessionStream for DeflateStream<T> {
+    fn is_tls(&self) -> bool {
+        self.is_tls
+    }
+
+    fn tls_version_and_cipher(&self) -> (Cow<'static, str>, Cow<'static, str>) {
+        self.tls_version_and_cipher.clone()
+    }
+}
@@ -0,0 +1,77 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::{Server, manager::application::Resource, storage::encryption::KeyDiscovery};
+use sha1::{Digest, Sha1};
+use utils::url_params::UrlParams;
+
+const ZBASE32_ALPHABET: &[u8; 32] = b"ybndrfg8ejkmcpqxot1uwisza345h769";
+
+impl Server {
+    // Handles both the direct (`/.well-known/openpgpkey/hu/<hash>`) and the
+    // advanced (`/.well-known/openpgpkey/<domain>/hu/<hash>`) WKD methods
+    pub async fn handle_wkd_request(
+        &self,
+        host: &str,
+        path: &[&str],
+        query: Option<&str>,
+    ) -> trc::Result<Resource<Vec<u8>>> {
+        let (domain, hash) = match path {
+            ["policy"] => (host, None),
+            ["hu", hash] => (host, Some(*hash)),
+            [domain, "policy"] => (*domain, None),
+            [domain, "hu", hash] => (*domain, Some(*hash)),
+            _ => return Err(trc::ResourceEvent::NotFound.into_err()),
+        };
+        let domain = domain.to_lowercase();
+        if self.domain(&domain).await?.is_none() {
+            return Err(trc::ResourceEvent::NotFound.into_err());
+        }
+
+        let Some(hash) = hash else {
+            // An empty policy file signals WKD support for the domain
+            return Ok(Resource::new("text/plain", Vec::new()));
+        };
+
+        // The local part is required to resolve the address, and must match the hash
+        let local_part = UrlParams::new(query)
+            .get("l")
+            .unwrap_or_default()
+            .to_lowercase();
+        if local_part.is_empty() || !wkd_hash(&local_part).eq_ignore_ascii_case(hash) {
+            return Err(trc::ResourceEvent::NotFound.into_err());
+        }
+
+        match self
+            .discoverable_public_key(&format!("{local_part}@{domain}"), KeyDiscovery::Wkd)
+            .await?
+        {
+            Some(key) => Ok(Resource::new("application/octet-stream", key.cert)),
+            None => Err(trc::ResourceEvent::NotFound.into_err()),
+        }
+    }
+}
+
+pub fn wkd_hash(local_part: &str) -> String {
+    let digest = Sha1::digest(local_part.as_bytes());
+    let mut result = String::with_capacity(32);
+    let mut buf = 0u32;
+    let mut bits = 0;
+
+    for &byte in digest.iter() {
+        buf = (buf << 8) | byte as u32;
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            result.push(ZBASE32_ALPHABET[((buf >> bits) & 0x1f) as usize] as char);
+        }
+    }
+    if bits > 0 {
+        result.push(ZBASE32_ALPHABET[((buf << (5 - bits)) & 0x1f) as usize] as char);
+    }
+
+    result
+}
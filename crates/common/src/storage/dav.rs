@@ -275,6 +275,23 @@ impl DavResource {
         }
     }
 
+    pub fn is_task(&self) -> bool {
+        matches!(
+            &self.data,
+            DavResourceMetadata::CalendarEvent { is_task: true, .. }
+        )
+    }
+
+    pub fn supports_tasks(&self) -> bool {
+        matches!(
+            &self.data,
+            DavResourceMetadata::Calendar {
+                supports_tasks: true,
+                ..
+            }
+        )
+    }
+
     pub fn calendar_preferences(&self, account_id: u32) -> Option<&TinyCalendarPreferences> {
         match &self.data {
             DavResourceMetadata::Calendar { preferences, .. } => preferences
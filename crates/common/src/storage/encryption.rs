@@ -4,11 +4,21 @@
  * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
  */
 
-use crate::auth::EncryptionKeys;
+use crate::{
+    Server,
+    auth::{EmailCache, EncryptionKeys},
+};
+use base64::{Engine, engine::general_purpose::STANDARD};
 use mail_parser::decoders::base64::base64_decode;
-use registry::schema::structs::PublicKey;
-use sequoia_openpgp::{Cert, parse::Parse, policy::StandardPolicy, types::KeyFlags};
+use registry::schema::{prelude::ObjectType, structs::PublicKey};
+use sequoia_openpgp::{
+    Cert, parse::Parse, policy::StandardPolicy, serialize::SerializeInto, types::KeyFlags,
+};
 use std::borrow::Cow;
+use store::{registry::RegistryQuery, write::now};
+use trc::AddContext;
+use types::id::Id;
+use utils::sanitize_email;
 
 const P: StandardPolicy<'static> = StandardPolicy::new();
 
@@ -23,6 +33,125 @@ pub struct EncryptionParams {
     pub method: EncryptionMethod,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyDiscovery {
+    Wkd,
+    Autocrypt,
+}
+
+pub struct DiscoverableKey {
+    pub account_id: u32,
+    pub cert: Vec<u8>,
+}
+
+pub struct AutocryptKey {
+    pub addr: String,
+    pub keydata: Vec<u8>,
+}
+
+impl AutocryptKey {
+    // Parses an Autocrypt header value, discarding headers with unknown
+    // critical attributes or without a usable OpenPGP key
+    pub fn parse(value: &str) -> Option<Self> {
+        let mut addr = None;
+        let mut keydata = None;
+
+        for attribute in value.split(';').filter(|attr| !attr.trim().is_empty()) {
+            let (name, value) = attribute.split_once('=')?;
+            let name = name.trim();
+            if name.eq_ignore_ascii_case("addr") {
+                addr = sanitize_email(value.trim());
+            } else if name.eq_ignore_ascii_case("keydata") {
+                keydata = base64_decode(
+                    &value
+                        .bytes()
+                        .filter(|ch| !ch.is_ascii_whitespace())
+                        .collect::<Vec<_>>(),
+                );
+            } else if !name.eq_ignore_ascii_case("prefer-encrypt") && !name.starts_with('_') {
+                return None;
+            }
+        }
+
+        let keydata = keydata?;
+        if Cert::from_bytes(&keydata).is_ok_and(has_pgp_keys) {
+            Some(AutocryptKey {
+                addr: addr?,
+                keydata,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn to_data_uri(&self) -> String {
+        format!(
+            "data:application/pgp-keys;base64,{}",
+            STANDARD.encode(&self.keydata)
+        )
+    }
+}
+
+impl Server {
+    /// Returns the binary OpenPGP certificate published for an address through
+    /// the requested discovery method, along with the account it belongs to.
+    pub async fn discoverable_public_key(
+        &self,
+        address: &str,
+        method: KeyDiscovery,
+    ) -> trc::Result<Option<DiscoverableKey>> {
+        let Some(EmailCache::Account(account_id)) = self
+            .rcpt_id_from_email(address)
+            .await
+            .caused_by(trc::location!())?
+        else {
+            return Ok(None);
+        };
+
+        let ids = self
+            .registry()
+            .query::<Vec<Id>>(RegistryQuery::new(ObjectType::PublicKey).with_account(account_id))
+            .await
+            .caused_by(trc::location!())?;
+        let now = now() as i64;
+
+        for id in ids {
+            let Some(pk) = self
+                .registry()
+                .object::<PublicKey>(id)
+                .await
+                .caused_by(trc::location!())?
+            else {
+                continue;
+            };
+
+            if !match method {
+                KeyDiscovery::Wkd => pk.publish_wkd,
+                KeyDiscovery::Autocrypt => pk.autocrypt,
+            } || pk
+                .expires_at
+                .is_some_and(|expires| expires.timestamp() <= now)
+                || (!pk.email_addresses.is_empty()
+                    && !pk
+                        .email_addresses
+                        .iter()
+                        .any(|addr| addr.eq_ignore_ascii_case(address)))
+            {
+                continue;
+            }
+
+            if let Ok(cert) = Cert::from_bytes(pk.key.as_bytes())
+                && has_pgp_keys(cert.clone())
+                && let Ok(cert) = cert.export_to_vec()
+            {
+                return Ok(Some(DiscoverableKey { account_id, cert }));
+            }
+        }
+
+        Ok(None)
+    }
+}
+
 #[allow(clippy::type_complexity)]
 pub fn parse_public_key(pk: &PublicKey) -> Result<Option<EncryptionParams>, Cow<'static, str>> {
     let bytes_ = pk.key.as_bytes();
@@ -10,12 +10,22 @@ use crate::{
     storage::{ObjectQuota, TenantQuota},
 };
 use registry::{
-    schema::enums::{StorageQuota, TenantStorageQuota},
+    schema::{
+        enums::{StorageQuota, TaskAccountMaintenanceType, TenantStorageQuota},
+        structs::{Task, TaskAccountMaintenance, TaskStatus},
+    },
     types::EnumImpl,
 };
-use store::{ValueKey, write::ValueClass};
+use store::{
+    ValueKey,
+    write::{BatchBuilder, ValueClass, now},
+};
 use trc::AddContext;
 
+// Gives the write that crossed the threshold time to be committed
+// before the warning task reads the used quota.
+const QUOTA_WARNING_DELAY: u64 = 5;
+
 impl Server {
     pub async fn get_used_quota_account(&self, account_id: u32) -> trc::Result<i64> {
         self.core
@@ -45,6 +55,8 @@ impl Server {
         account: &AccountCache,
         item_size: u64,
     ) -> trc::Result<()> {
+        let mut crossed_warning = false;
+
         if account.quota_disk != 0 {
             let used_quota = self.get_used_quota_account(account.id).await? as u64;
 
@@ -54,6 +66,9 @@ impl Server {
                     .ctx(trc::Key::Limit, account.quota_disk)
                     .ctx(trc::Key::Size, used_quota));
             }
+
+            crossed_warning = self.quota_warning_level(used_quota + item_size, account.quota_disk)
+                > self.quota_warning_level(used_quota, account.quota_disk);
         }
 
         // SPDX-SnippetBegin
@@ -75,14 +90,58 @@ impl Server {
                         .ctx(trc::Key::Limit, tenant.quota_disk)
                         .ctx(trc::Key::Size, used_quota));
                 }
+
+                crossed_warning |= self
+                    .quota_warning_level(used_quota + item_size, tenant.quota_disk)
+                    > self.quota_warning_level(used_quota, tenant.quota_disk);
             }
         }
 
         // SPDX-SnippetEnd
 
+        if crossed_warning {
+            self.schedule_quota_warning(account.id).await;
+        }
+
         Ok(())
     }
 
+    // Returns the highest warning threshold (in percent) reached by the used quota
+    pub fn quota_warning_level(&self, used_quota: u64, quota: u64) -> u64 {
+        if quota != 0 {
+            self.core
+                .email
+                .quota_warn_thresholds
+                .iter()
+                .rev()
+                .find(|threshold| used_quota as u128 * 100 >= **threshold as u128 * quota as u128)
+                .copied()
+                .unwrap_or_default()
+        } else {
+            0
+        }
+    }
+
+    async fn schedule_quota_warning(&self, account_id: u32) {
+        let mut batch = BatchBuilder::new();
+        batch.schedule_task(Task::AccountMaintenance(TaskAccountMaintenance {
+            account_id: account_id.into(),
+            maintenance_type: TaskAccountMaintenanceType::QuotaWarning,
+            status: TaskStatus::at((now() + QUOTA_WARNING_DELAY) as i64),
+        }));
+
+        match self.store().write(batch.build_all()).await {
+            Ok(_) => self.notify_task_queue(),
+            Err(err) => {
+                trc::error!(
+                    err.account_id(account_id)
+                        .caused_by(trc::location!())
+                        .details("Failed to schedule quota warning")
+                );
+            }
+        }
+    }
+
     #[inline(always)]
     pub fn object_quota(&self, user_quotas: Option<&ObjectQuota>, object: StorageQuota) -> u32 {
         user_quotas.unwrap_or(&self.core.email.max_objects).0[object as usize]
@@ -6,7 +6,7 @@
 
 use prometheus::{
     TextEncoder,
-    proto::{Bucket, Counter, Gauge, Histogram, Metric, MetricFamily, MetricType},
+    proto::{Bucket, Counter, Gauge, Histogram, LabelPair, Metric, MetricFamily, MetricType},
 };
 use trc::{Collector, atomics::histogram::AtomicHistogram};
 
@@ -26,13 +26,34 @@ impl Server {
         #[cfg(not(feature = "enterprise"))]
         let is_enterprise = false;
 
-        // Add counters
+        // Add counters, relay host counters are labelled by host
+        let relay_stats = self.inner.data.relay_stats.lock().clone();
         for counter in Collector::collect_counters(is_enterprise) {
             let mut metric = MetricFamily::default();
             metric.set_name(metric_name(counter.id().as_str()));
             metric.set_help(counter.id().description().into());
             metric.set_field_type(MetricType::COUNTER);
-            metric.set_metric(vec![new_counter(counter.value())]);
+            match counter.id() {
+                trc::MetricType::DeliveryRelayHostSuccess if !relay_stats.is_empty() => {
+                    metric.set_metric(
+                        relay_stats
+                            .iter()
+                            .map(|(host, stats)| new_host_counter(host, stats.success))
+                            .collect(),
+                    );
+                }
+                trc::MetricType::DeliveryRelayHostFailed if !relay_stats.is_empty() => {
+                    metric.set_metric(
+                        relay_stats
+                            .iter()
+                            .map(|(host, stats)| new_host_counter(host, stats.failed))
+                            .collect(),
+                    );
+                }
+                _ => {
+                    metric.set_metric(vec![new_counter(counter.value())]);
+                }
+            }
             metrics.push(metric);
         }
 
@@ -83,6 +104,15 @@ fn new_counter(value: u64) -> Metric {
     m
 }
 
+fn new_host_counter(host: &str, value: u64) -> Metric {
+    let mut m = new_counter(value);
+    let mut label = LabelPair::default();
+    label.set_name("host".into());
+    label.set_value(host.into());
+    m.set_label(vec![label]);
+    m
+}
+
 fn new_gauge(value: u64) -> Metric {
     let mut m = Metric::default();
     let mut gauge = Gauge::default();
@@ -157,6 +157,7 @@ impl StoreTracer {
                                     | QueueEvent::AutogeneratedQueued
                                     | QueueEvent::Rescheduled
                                     | QueueEvent::RateLimitExceeded
+                                    | QueueEvent::WarmUpLimitExceeded
                                     | QueueEvent::ConcurrencyLimitExceeded
                                     | QueueEvent::QuotaExceeded
                             )
@@ -4,38 +4,27 @@
  * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
  */
 
-use super::query::CalendarQueryHandler;
 use crate::{DavError, calendar::query::is_resource_in_time_range, common::uri::DavUriResource};
 use calcard::{
     common::{PartialDateTime, timezone::Tz},
     icalendar::{
-        ArchivedICalendarComponentType, ArchivedICalendarEntry, ArchivedICalendarParameterName,
-        ArchivedICalendarParameterValue, ArchivedICalendarProperty, ArchivedICalendarStatus,
-        ArchivedICalendarValue, ICalendar, ICalendarComponent, ICalendarComponentType,
-        ICalendarEntry, ICalendarFreeBusyType, ICalendarParameter, ICalendarPeriod,
-        ICalendarProperty, ICalendarTransparency, ICalendarValue,
+        ArchivedICalendarEntry, ArchivedICalendarValue, ICalendar, ICalendarComponent,
+        ICalendarComponentType, ICalendarEntry, ICalendarParameter, ICalendarPeriod,
+        ICalendarProperty, ICalendarValue,
     },
 };
 use common::{DavResourcePath, DavResources, PROD_ID, Server, auth::AccessToken};
 use dav_proto::{RequestHeaders, schema::request::FreeBusyQuery};
-use groupware::{cache::GroupwareCache, calendar::CalendarEvent};
+use groupware::{
+    cache::GroupwareCache,
+    calendar::freebusy::{CalendarFreeBusy, merge_intervals},
+};
 use http_proto::HttpResponse;
 use hyper::StatusCode;
 use std::str::FromStr;
-use store::{
-    ValueKey,
-    write::{AlignedBytes, Archive},
-};
-use store::{
-    ahash::AHashMap,
-    write::{now, serialize::rkyv_deserialize},
-};
+use store::write::{now, serialize::rkyv_deserialize};
 use trc::AddContext;
-use types::{
-    TimeRange,
-    acl::Acl,
-    collection::{Collection, SyncCollection},
-};
+use types::{TimeRange, acl::Acl, collection::SyncCollection};
 
 pub(crate) trait CalendarFreebusyRequestHandler: Sync + Send {
     fn handle_calendar_freebusy_request(
@@ -158,125 +147,19 @@ impl CalendarFreebusyRequestHandler for Server {
                 .map(|resource| resource.document_id())
                 .collect::<Vec<_>>();
 
-            let mut fb_entries: AHashMap<ICalendarFreeBusyType, Vec<(i64, i64)>> =
-                AHashMap::with_capacity(document_ids.len());
-
-            for document_id in document_ids {
-                let Some(archive) = self
-                    .store()
-                    .get_value::<Archive<AlignedBytes>>(ValueKey::archive(
-                        account_id,
-                        Collection::CalendarEvent,
-                        document_id,
-                    ))
-                    .await
-                    .caused_by(trc::location!())?
-                else {
-                    continue;
-                };
-                let event = archive
-                    .unarchive::<CalendarEvent>()
-                    .caused_by(trc::location!())?;
-
-                /*
-                   Only VEVENT components without a TRANSP property or with the TRANSP
-                   property set to OPAQUE, and VFREEBUSY components SHOULD be considered
-                   in generating the free busy time information.
-                */
-                let mut components = event
-                    .data
-                    .event
-                    .components
-                    .iter()
-                    .enumerate()
-                    .filter(|(_, comp)| {
-                        (matches!(comp.component_type, ArchivedICalendarComponentType::VEvent)
-                            && comp
-                                .transparency()
-                                .is_none_or(|t| t == &ICalendarTransparency::Opaque))
-                            || matches!(
-                                comp.component_type,
-                                ArchivedICalendarComponentType::VFreebusy
-                            )
-                    })
-                    .peekable();
-
-                if components.peek().is_none() {
-                    continue;
-                }
-
-                let events =
-                    CalendarQueryHandler::new(event, Some(range), default_tz).into_expanded_times();
-
-                if events.is_empty() {
-                    continue;
-                }
-
-                for (component_id, component) in components {
-                    let component_id = component_id as u32;
-                    match component.component_type {
-                        ArchivedICalendarComponentType::VEvent => {
-                            let fbtype = match component.status() {
-                                Some(ArchivedICalendarStatus::Cancelled) => continue,
-                                Some(ArchivedICalendarStatus::Tentative) => {
-                                    ICalendarFreeBusyType::BusyTentative
-                                }
-                                _ => ICalendarFreeBusyType::Busy,
-                            };
-
-                            let mut events_in_range = Vec::new();
-                            for event in &events {
-                                if event.comp_id == component_id
-                                    && range.is_in_range(false, event.start, event.end)
-                                {
-                                    events_in_range.push((event.start, event.end));
-                                }
-                            }
-
-                            if !events_in_range.is_empty() {
-                                fb_entries
-                                    .entry(fbtype)
-                                    .or_default()
-                                    .extend(events_in_range);
-                            }
-                        }
-                        ArchivedICalendarComponentType::VFreebusy => {
-                            for entry in component.entries.iter() {
-                                if matches!(entry.name, ArchivedICalendarProperty::Freebusy) {
-                                    let mut fb_in_range =
-                                        freebusy_in_range_utc(entry, &range, default_tz).peekable();
-                                    if fb_in_range.peek().is_some() {
-                                        let fb_type = entry
-                                            .params
-                                            .iter()
-                                            .find_map(|param| {
-                                                if let (
-                                                    ArchivedICalendarParameterName::Fbtype,
-                                                    ArchivedICalendarParameterValue::Fbtype(param),
-                                                ) = (&param.name, &param.value)
-                                                {
-                                                    rkyv_deserialize(param).ok()
-                                                } else {
-                                                    None
-                                                }
-                                            })
-                                            .unwrap_or(ICalendarFreeBusyType::Busy);
-
-                                        fb_entries.entry(fb_type).or_default().extend(fb_in_range);
-                                    }
-                                }
-                            }
-                        }
-                        _ => {}
-                    }
-                }
-            }
+            let fb_entries = self
+                .calendar_busy_periods(account_id, document_ids, range, default_tz, &[])
+                .await
+                .caused_by(trc::location!())?;
 
             for (fbtype, events_in_range) in fb_entries {
                 entries.push(ICalendarEntry {
                     name: ICalendarProperty::Freebusy,
                     params: vec![ICalendarParameter::fbtype(fbtype)],
-                    values: merge_intervals(events_in_range),
+                    values: merge_intervals(events_in_range)
+                        .into_iter()
+                        .map(|(start, end)| build_ical_value(start, end))
+                        .collect(),
                 });
             }
         }
@@ -310,34 +193,6 @@ impl CalendarFreebusyRequestHandler for Server {
     }
 }
 
-fn merge_intervals(mut intervals: Vec<(i64, i64)>) -> Vec<ICalendarValue> {
-    if intervals.len() > 1 {
-        intervals.sort_unstable_by_key(|a| a.0);
-
-        let mut unique_intervals = Vec::new();
-        let mut start_time = intervals[0].0;
-        let mut end_time = intervals[0].1;
-
-        for &(curr_start, curr_end) in intervals.iter().skip(1) {
-            if curr_start <= end_time {
-                end_time = end_time.max(curr_end);
-            } else {
-                unique_intervals.push(build_ical_value(start_time, end_time));
-                start_time = curr_start;
-                end_time = curr_end;
-            }
-        }
-
-        unique_intervals.push(build_ical_value(start_time, end_time));
-        unique_intervals
-    } else {
-        intervals
-            .into_iter()
-            .map(|(start, end)| build_ical_value(start, end))
-            .collect()
-    }
-}
-
 fn build_ical_value(from: i64, to: i64) -> ICalendarValue {
     ICalendarValue::Period(ICalendarPeriod::Range {
         start: PartialDateTime::from_utc_timestamp(from),
@@ -371,30 +226,3 @@ pub(crate) fn freebusy_in_range(
         }
     })
 }
-
-fn freebusy_in_range_utc(
-    entry: &ArchivedICalendarEntry,
-    range: &TimeRange,
-    default_tz: Tz,
-) -> impl Iterator<Item = (i64, i64)> {
-    let tz = entry
-        .tz_id()
-        .and_then(|tz_id| Tz::from_str(tz_id).ok())
-        .unwrap_or(default_tz);
-
-    entry.values.iter().filter_map(move |value| {
-        if let ArchivedICalendarValue::Period(period) = &value {
-            period.time_range(tz).and_then(|(start, end)| {
-                let start = start.timestamp();
-                let end = end.timestamp();
-                if range.is_in_range(false, start, end) {
-                    Some((start, end))
-                } else {
-                    None
-                }
-            })
-        } else {
-            None
-        }
-    })
-}
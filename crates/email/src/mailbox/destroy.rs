@@ -7,6 +7,10 @@
 use super::*;
 use crate::{
     cache::{MessageCacheFetch, email::MessageCacheAccess},
+    mailbox::{
+        metadata::{MailboxMetadata, metadata_key},
+        save_date::MailboxSaveDate,
+    },
     message::metadata::MessageData,
 };
 use common::{
@@ -18,7 +22,7 @@ use registry::schema::{
 };
 use store::{
     ValueKey,
-    write::{AlignedBytes, Archive},
+    write::{AlignedBytes, Archive, ValueClass},
 };
 use store::{roaring::RoaringBitmap, write::BatchBuilder};
 use trc::AddContext;
@@ -202,6 +206,30 @@ impl MailboxDestroy for Server {
                 .clear(MailboxField::UidCounter)
                 .custom(ObjectIndexBuilder::<_, ()>::new().with_current(mailbox))
                 .caused_by(trc::location!())?;
+
+            // Remove metadata entries
+            if let Some(metadata_) = self
+                .store()
+                .get_value::<Archive<AlignedBytes>>(metadata_key(account_id, Some(document_id)))
+                .await
+                .caused_by(trc::location!())?
+            {
+                let size = metadata_
+                    .unarchive::<MailboxMetadata>()
+                    .caused_by(trc::location!())?
+                    .size() as i64;
+                let tenant_id = self
+                    .account(account_id)
+                    .await
+                    .caused_by(trc::location!())?
+                    .id_tenant;
+                batch
+                    .clear(MailboxField::Metadata)
+                    .add(ValueClass::Quota, -size);
+                if let Some(tenant_id) = tenant_id {
+                    batch.add(ValueClass::TenantQuota(tenant_id), -size);
+                }
+            }
         } else {
             return Ok(Err(MailboxDestroyError::NotFound));
         };
@@ -213,6 +241,9 @@ impl MailboxDestroy for Server {
                 .and_then(|ids| ids.last_change_id(account_id))
             {
                 Ok(change_id) => {
+                    self.delete_save_dates(account_id, document_id)
+                        .await
+                        .caused_by(trc::location!())?;
                     self.notify_task_queue();
 
                     Ok(Ok(Some(change_id)))
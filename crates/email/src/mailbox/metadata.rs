@@ -0,0 +1,240 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use common::Server;
+use std::future::Future;
+use store::{
+    Serialize, ValueKey,
+    write::{AlignedBytes, Archive, Archiver, BatchBuilder, ValueClass},
+};
+use trc::AddContext;
+use types::{
+    collection::Collection,
+    field::{MailboxField, PrincipalField},
+};
+
+pub const MAX_METADATA_ENTRY_SIZE: usize = 64 * 1024;
+pub const MAX_METADATA_ENTRIES: usize = 100;
+
+#[derive(
+    rkyv::Archive, rkyv::Deserialize, rkyv::Serialize, Debug, Default, Clone, PartialEq, Eq,
+)]
+pub struct MailboxMetadata {
+    pub entries: Vec<MetadataEntry>,
+}
+
+#[derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct MetadataEntry {
+    pub name: String,
+    // Owner of a /private entry, None for /shared entries
+    pub account_id: Option<u32>,
+    pub value: Vec<u8>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MetadataUpdate {
+    pub name: String,
+    pub value: Option<Vec<u8>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MetadataError {
+    MaxSize(usize),
+    TooMany,
+}
+
+pub trait MailboxMetadataStore: Sync + Send {
+    fn mailbox_metadata(
+        &self,
+        account_id: u32,
+        mailbox_id: Option<u32>,
+    ) -> impl Future<Output = trc::Result<MailboxMetadata>> + Send;
+
+    fn mailbox_metadata_update(
+        &self,
+        account_id: u32,
+        mailbox_id: Option<u32>,
+        access_account_id: u32,
+        updates: Vec<MetadataUpdate>,
+    ) -> impl Future<Output = trc::Result<Result<(), MetadataError>>> + Send;
+}
+
+impl MailboxMetadataStore for Server {
+    async fn mailbox_metadata(
+        &self,
+        account_id: u32,
+        mailbox_id: Option<u32>,
+    ) -> trc::Result<MailboxMetadata> {
+        if let Some(metadata_) = self
+            .store()
+            .get_value::<Archive<AlignedBytes>>(metadata_key(account_id, mailbox_id))
+            .await
+            .caused_by(trc::location!())?
+        {
+            metadata_
+                .deserialize::<MailboxMetadata>()
+                .caused_by(trc::location!())
+        } else {
+            Ok(MailboxMetadata::default())
+        }
+    }
+
+    async fn mailbox_metadata_update(
+        &self,
+        account_id: u32,
+        mailbox_id: Option<u32>,
+        access_account_id: u32,
+        updates: Vec<MetadataUpdate>,
+    ) -> trc::Result<Result<(), MetadataError>> {
+        let (collection, document_id, field) = metadata_location(mailbox_id);
+        let current_ = self
+            .store()
+            .get_value::<Archive<AlignedBytes>>(ValueKey::property(
+                account_id,
+                collection,
+                document_id,
+                field,
+            ))
+            .await
+            .caused_by(trc::location!())?;
+        let mut metadata = if let Some(current) = &current_ {
+            current
+                .deserialize::<MailboxMetadata>()
+                .caused_by(trc::location!())?
+        } else {
+            MailboxMetadata::default()
+        };
+        let prev_size = metadata.size();
+
+        // Apply changes
+        for update in updates {
+            if update
+                .value
+                .as_ref()
+                .is_some_and(|value| value.len() > MAX_METADATA_ENTRY_SIZE)
+            {
+                return Ok(Err(MetadataError::MaxSize(MAX_METADATA_ENTRY_SIZE)));
+            }
+            metadata.set(update.name, access_account_id, update.value);
+        }
+        if metadata.entries.len() > MAX_METADATA_ENTRIES {
+            return Ok(Err(MetadataError::TooMany));
+        }
+
+        // Enforce quota
+        let account = self.account(account_id).await.caused_by(trc::location!())?;
+        let new_size = metadata.size();
+        if new_size > prev_size {
+            self.has_available_quota(&account, new_size - prev_size)
+                .await
+                .caused_by(trc::location!())?;
+        }
+
+        // Write changes
+        let mut batch = BatchBuilder::new();
+        batch
+            .with_account_id(account_id)
+            .with_collection(collection)
+            .with_document(document_id);
+        if let Some(current) = current_ {
+            batch.assert_value(ValueClass::Property(field), current);
+        }
+        if !metadata.entries.is_empty() {
+            batch.set(
+                ValueClass::Property(field),
+                Archiver::new(metadata)
+                    .serialize()
+                    .caused_by(trc::location!())?,
+            );
+        } else {
+            batch.clear(ValueClass::Property(field));
+        }
+        if new_size != prev_size {
+            let delta = new_size as i64 - prev_size as i64;
+            batch.add(ValueClass::Quota, delta);
+            if let Some(tenant_id) = account.id_tenant {
+                batch.add(ValueClass::TenantQuota(tenant_id), delta);
+            }
+        }
+
+        self.commit_batch(batch)
+            .await
+            .caused_by(trc::location!())
+            .map(|_| Ok(()))
+    }
+}
+
+impl MailboxMetadata {
+    pub fn size(&self) -> u64 {
+        self.entries
+            .iter()
+            .map(|entry| (entry.name.len() + entry.value.len()) as u64)
+            .sum()
+    }
+
+    pub fn set(&mut self, name: String, access_account_id: u32, value: Option<Vec<u8>>) {
+        let account_id = is_private_entry(&name).then_some(access_account_id);
+        let pos = self
+            .entries
+            .iter()
+            .position(|entry| entry.name == name && entry.account_id == account_id);
+        match (pos, value) {
+            (Some(pos), Some(value)) => {
+                self.entries[pos].value = value;
+            }
+            (Some(pos), None) => {
+                self.entries.swap_remove(pos);
+            }
+            (None, Some(value)) => {
+                self.entries.push(MetadataEntry {
+                    name,
+                    account_id,
+                    value,
+                });
+            }
+            (None, None) => {}
+        }
+    }
+
+    pub fn visible_entries(&self, access_account_id: u32) -> impl Iterator<Item = &MetadataEntry> {
+        self.entries.iter().filter(move |entry| {
+            entry
+                .account_id
+                .is_none_or(|account_id| account_id == access_account_id)
+        })
+    }
+}
+
+impl ArchivedMailboxMetadata {
+    pub fn size(&self) -> u64 {
+        self.entries
+            .iter()
+            .map(|entry| (entry.name.len() + entry.value.len()) as u64)
+            .sum()
+    }
+}
+
+#[inline(always)]
+pub fn is_private_entry(name: &str) -> bool {
+    name.starts_with("/private/")
+}
+
+pub fn metadata_key(account_id: u32, mailbox_id: Option<u32>) -> ValueKey<ValueClass> {
+    let (collection, document_id, field) = metadata_location(mailbox_id);
+    ValueKey::property(account_id, collection, document_id, field)
+}
+
+fn metadata_location(mailbox_id: Option<u32>) -> (Collection, u32, u8) {
+    if let Some(mailbox_id) = mailbox_id {
+        (
+            Collection::Mailbox,
+            mailbox_id,
+            MailboxField::Metadata.into(),
+        )
+    } else {
+        (Collection::Principal, 0, PrincipalField::Metadata.into())
+    }
+}
@@ -0,0 +1,155 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::cache::MessageCacheFetch;
+use common::Server;
+use std::{future::Future, ops::RangeInclusive};
+use store::{
+    IterateParams, U32_LEN, U64_LEN, ValueKey,
+    ahash::{AHashMap, AHashSet},
+    write::{BatchBuilder, IndexPropertyClass, ValueClass, key::DeserializeBigEndian},
+};
+use trc::AddContext;
+use types::{collection::Collection, field::MailboxField};
+
+pub trait MailboxSaveDate: Sync + Send {
+    fn save_dates(
+        &self,
+        account_id: u32,
+        mailbox_id: u32,
+        uids: RangeInclusive<u32>,
+    ) -> impl Future<Output = trc::Result<AHashMap<u32, u64>>> + Send;
+
+    fn delete_save_dates(
+        &self,
+        account_id: u32,
+        mailbox_id: u32,
+    ) -> impl Future<Output = trc::Result<()>> + Send;
+
+    fn purge_save_dates(&self, account_id: u32) -> impl Future<Output = trc::Result<()>> + Send;
+}
+
+// Save dates are stored under the mailbox, indexed by (mailbox_id, uid)
+pub fn save_date_class(mailbox_id: u32, uid: u32) -> ValueClass {
+    ValueClass::IndexProperty(IndexPropertyClass::Integer {
+        property: MailboxField::SaveDate.into(),
+        value: ((mailbox_id as u64) << 32) | uid as u64,
+    })
+}
+
+fn save_date_key(account_id: u32, mailbox_id: u32, uid: u32) -> ValueKey<ValueClass> {
+    ValueKey {
+        account_id,
+        collection: Collection::Mailbox.into(),
+        document_id: mailbox_id,
+        class: save_date_class(mailbox_id, uid),
+    }
+}
+
+impl MailboxSaveDate for Server {
+    async fn save_dates(
+        &self,
+        account_id: u32,
+        mailbox_id: u32,
+        uids: RangeInclusive<u32>,
+    ) -> trc::Result<AHashMap<u32, u64>> {
+        let mut save_dates = AHashMap::new();
+        self.store()
+            .iterate(
+                IterateParams::new(
+                    save_date_key(account_id, mailbox_id, *uids.start()),
+                    save_date_key(account_id, mailbox_id, *uids.end()),
+                )
+                .ascending(),
+                |key, value| {
+                    let uid = key.deserialize_be_u64(key.len() - U32_LEN - U64_LEN)? as u32;
+                    save_dates.insert(uid, value.deserialize_be_u64(0)?);
+
+                    Ok(true)
+                },
+            )
+            .await
+            .caused_by(trc::location!())
+            .map(|_| save_dates)
+    }
+
+    async fn delete_save_dates(&self, account_id: u32, mailbox_id: u32) -> trc::Result<()> {
+        self.store()
+            .delete_range(
+                save_date_key(account_id, mailbox_id, 0),
+                save_date_key(account_id, mailbox_id, u32::MAX),
+            )
+            .await
+            .caused_by(trc::location!())
+    }
+
+    async fn purge_save_dates(&self, account_id: u32) -> trc::Result<()> {
+        let cache = self
+            .get_cached_messages(account_id)
+            .await
+            .caused_by(trc::location!())?;
+        let memberships = cache
+            .emails
+            .items
+            .iter()
+            .flat_map(|item| item.mailboxes.iter())
+            .map(|mailbox| (mailbox.mailbox_id, mailbox.uid))
+            .collect::<AHashSet<_>>();
+
+        // Find save dates of messages that were removed from their mailbox
+        let mut stale = Vec::new();
+        self.store()
+            .iterate(
+                IterateParams::new(
+                    ValueKey {
+                        account_id,
+                        collection: Collection::Mailbox.into(),
+                        document_id: 0,
+                        class: save_date_class(0, 0),
+                    },
+                    ValueKey {
+                        account_id,
+                        collection: Collection::Mailbox.into(),
+                        document_id: u32::MAX,
+                        class: save_date_class(u32::MAX, u32::MAX),
+                    },
+                )
+                .ascending()
+                .no_values(),
+                |key, _| {
+                    let value = key.deserialize_be_u64(key.len() - U32_LEN - U64_LEN)?;
+                    let membership = ((value >> 32) as u32, value as u32);
+                    if !memberships.contains(&membership) {
+                        stale.push(membership);
+                    }
+
+                    Ok(true)
+                },
+            )
+            .await
+            .caused_by(trc::location!())?;
+
+        if stale.is_empty() {
+            return Ok(());
+        }
+
+        let mut batch = BatchBuilder::new();
+        batch
+            .with_account_id(account_id)
+            .with_collection(Collection::Mailbox);
+        for (mailbox_id, uid) in stale {
+            batch
+                .with_document(mailbox_id)
+                .clear(save_date_class(mailbox_id, uid))
+                .commit_point();
+        }
+
+        self.commit_batch(batch)
+            .await
+            .caused_by(trc::location!())
+            .map(|_| ())
+    }
+}
@@ -5,6 +5,7 @@
  */
 
 use super::metadata::MessageData;
+use crate::mailbox::save_date::MailboxSaveDate;
 use common::{Server, storage::index::ObjectIndexBuilder};
 use groupware::calendar::storage::ItipAutoExpunge;
 use registry::schema::enums::IndexDocumentType;
@@ -80,6 +81,7 @@ impl EmailDeletion for Server {
                             .with_current(metadata),
                     )
                     .caused_by(trc::location!())?
+                    .clear(EmailField::SnoozedUntil)
                     .schedule_task(Task::UnindexDocument(TaskIndexDocument {
                         account_id: account_id.into(),
                         document_id: document_id.into(),
@@ -127,6 +129,11 @@ impl EmailDeletion for Server {
                 .caused_by(trc::location!())?;
         }
 
+        // Purge save dates of removed messages
+        self.purge_save_dates(account_id)
+            .await
+            .caused_by(trc::location!())?;
+
         // Purge changelogs
         self.delete_changes(
             account_id,
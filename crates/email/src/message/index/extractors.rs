@@ -8,10 +8,21 @@ use crate::message::metadata::{
     ArchivedMessageMetadataContents, ArchivedMessageMetadataPart, ArchivedMetadataHeaderValue,
     MetadataHeaderName, MetadataHeaderValue,
 };
-use mail_parser::{Addr, Address, Group, HeaderValue};
+use common::config::mailstore::email::ExtractLimits;
+use mail_parser::{Addr, Address, Group, HeaderValue, decoders::html::html_to_text};
 use nlp::language::Language;
+use quick_xml::events::Event;
 use rkyv::option::ArchivedOption;
-use std::borrow::Cow;
+use std::{
+    borrow::Cow,
+    io::{Cursor, Read, Seek},
+};
+use utils::chained_bytes::ChainedBytes;
+use zip::ZipArchive;
+
+// Upper bound on the amount of text indexed for a single attachment
+const MAX_EXTRACTED_TEXT: usize = 1024 * 1024;
+const MAX_ARCHIVE_ENTRIES: usize = 256;
 
 impl ArchivedMessageMetadataContents {
     pub fn is_html_part(&self, part_id: u16) -> bool {
@@ -32,6 +43,343 @@ impl ArchivedMessageMetadataPart {
                     .into()
             })
     }
+
+    pub fn extract_text(
+        &self,
+        raw_message: &ChainedBytes<'_>,
+        limits: &ExtractLimits,
+    ) -> Option<String> {
+        let extractor = ExtractorType::detect(
+            self.content_type()
+                .map(|ct| (ct.ctype(), ct.subtype().unwrap_or_default())),
+            self.attachment_name(),
+        )?;
+        let max_size = extractor.max_size(limits);
+
+        // Skip oversized parts before decoding, base64 adds roughly a third to the size
+        if max_size == 0 || self.body_to_end().len() > max_size.saturating_mul(2) {
+            return None;
+        }
+        let contents = self.contents(raw_message);
+        if contents.len() > max_size {
+            return None;
+        }
+
+        let mut text = ExtractedText::default();
+        extractor.extract(&contents, limits, &mut text);
+        text.into_text()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExtractorType {
+    Pdf,
+    Office,
+    Archive,
+}
+
+pub trait TextExtractor: Sync + Send {
+    fn extract(&self, bytes: &[u8], limits: &ExtractLimits, text: &mut ExtractedText);
+}
+
+#[derive(Debug, Default)]
+pub struct ExtractedText {
+    text: String,
+}
+
+struct PdfExtractor;
+struct OfficeExtractor;
+struct ArchiveExtractor;
+
+impl ExtractorType {
+    pub fn detect(content_type: Option<(&str, &str)>, file_name: Option<&str>) -> Option<Self> {
+        if let Some((ctype, subtype)) = content_type
+            && ctype.eq_ignore_ascii_case("application")
+        {
+            let subtype = subtype.to_ascii_lowercase();
+            match subtype.as_str() {
+                "pdf" | "x-pdf" => return Some(ExtractorType::Pdf),
+                "zip" | "x-zip" | "x-zip-compressed" => return Some(ExtractorType::Archive),
+                _ => {
+                    if subtype.starts_with("vnd.openxmlformats-officedocument.")
+                        || subtype.starts_with("vnd.oasis.opendocument.")
+                    {
+                        return Some(ExtractorType::Office);
+                    }
+                }
+            }
+        }
+
+        // Fall back to the file extension for generic content types
+        let (_, extension) = file_name?.rsplit_once('.')?;
+        hashify::tiny_map_ignore_case!(extension.as_bytes(),
+            "pdf" => ExtractorType::Pdf,
+            "docx" => ExtractorType::Office,
+            "xlsx" => ExtractorType::Office,
+            "pptx" => ExtractorType::Office,
+            "odt" => ExtractorType::Office,
+            "ods" => ExtractorType::Office,
+            "odp" => ExtractorType::Office,
+            "zip" => ExtractorType::Archive,
+        )
+    }
+
+    pub fn max_size(&self, limits: &ExtractLimits) -> usize {
+        match self {
+            ExtractorType::Pdf => limits.pdf_max_size,
+            ExtractorType::Office => limits.office_max_size,
+            ExtractorType::Archive => limits.archive_max_size,
+        }
+    }
+
+    pub fn extractor(&self) -> &'static dyn TextExtractor {
+        match self {
+            ExtractorType::Pdf => &PdfExtractor,
+            ExtractorType::Office => &OfficeExtractor,
+            ExtractorType::Archive => &ArchiveExtractor,
+        }
+    }
+
+    pub fn extract(&self, bytes: &[u8], limits: &ExtractLimits, text: &mut ExtractedText) {
+        self.extractor().extract(bytes, limits, text);
+    }
+}
+
+impl TextExtractor for PdfExtractor {
+    fn extract(&self, bytes: &[u8], _: &ExtractLimits, text: &mut ExtractedText) {
+        let Ok(document) = lopdf::Document::load_mem(bytes) else {
+            return;
+        };
+
+        for page_number in document.get_pages().into_keys() {
+            if let Ok(page_text) = document.extract_text(&[page_number])
+                && !text.push(&page_text)
+            {
+                break;
+            }
+        }
+    }
+}
+
+impl TextExtractor for OfficeExtractor {
+    fn extract(&self, bytes: &[u8], limits: &ExtractLimits, text: &mut ExtractedText) {
+        let Ok(mut archive) = ZipArchive::new(Cursor::new(bytes)) else {
+            return;
+        };
+
+        // Decompressed contents are subject to the same limit as the document itself
+        let mut budget = limits.office_max_size;
+        let names = archive
+            .file_names()
+            .filter(|name| is_office_text_part(name))
+            .take(MAX_ARCHIVE_ENTRIES)
+            .map(String::from)
+            .collect::<Vec<_>>();
+
+        for name in names {
+            if let Some(contents) = read_archive_entry(&mut archive, &name, &mut budget)
+                && !xml_to_text(&contents, text)
+            {
+                break;
+            }
+        }
+    }
+}
+
+impl TextExtractor for ArchiveExtractor {
+    fn extract(&self, bytes: &[u8], limits: &ExtractLimits, text: &mut ExtractedText) {
+        let Ok(mut archive) = ZipArchive::new(Cursor::new(bytes)) else {
+            return;
+        };
+
+        let mut budget = limits.archive_max_size;
+        let names = archive
+            .file_names()
+            .filter(|name| !name.ends_with('/'))
+            .take(MAX_ARCHIVE_ENTRIES)
+            .map(String::from)
+            .collect::<Vec<_>>();
+
+        for name in names {
+            let extension = name
+                .rsplit_once('.')
+                .map(|(_, extension)| extension.to_ascii_lowercase())
+                .unwrap_or_default();
+            let is_html = matches!(extension.as_str(), "htm" | "html");
+            let is_text = is_html
+                || matches!(
+                    extension.as_str(),
+                    "txt"
+                        | "text"
+                        | "csv"
+                        | "tsv"
+                        | "md"
+                        | "log"
+                        | "json"
+                        | "xml"
+                        | "ics"
+                        | "vcf"
+                        | "eml"
+                );
+
+            // Nested archives are not expanded
+            let extractor = if !is_text {
+                match ExtractorType::detect(None, Some(&name)) {
+                    Some(ExtractorType::Archive) | None => continue,
+                    Some(extractor) => Some(extractor),
+                }
+            } else {
+                None
+            };
+
+            let Some(contents) = read_archive_entry(&mut archive, &name, &mut budget) else {
+                continue;
+            };
+            let has_room = match extractor {
+                Some(extractor) if contents.len() <= extractor.max_size(limits) => {
+                    extractor.extract(&contents, limits, text);
+                    !text.is_full()
+                }
+                Some(_) => true,
+                None => {
+                    let contents = String::from_utf8_lossy(&contents);
+                    if is_html {
+                        text.push(&html_to_text(&contents))
+                    } else {
+                        text.push(&contents)
+                    }
+                }
+            };
+
+            if !has_room || budget == 0 {
+                break;
+            }
+        }
+    }
+}
+
+impl ExtractedText {
+    // Returns false once the text limit has been reached
+    pub fn push(&mut self, text: &str) -> bool {
+        let text = text.trim();
+        if !text.is_empty() && !self.is_full() {
+            if !self.text.is_empty() {
+                self.text.push(' ');
+            }
+            self.text
+                .push_str(text.trim_text(MAX_EXTRACTED_TEXT.saturating_sub(self.text.len())));
+        }
+
+        !self.is_full()
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.text.len() >= MAX_EXTRACTED_TEXT
+    }
+
+    pub fn into_text(self) -> Option<String> {
+        if !self.text.is_empty() {
+            Some(self.text)
+        } else {
+            None
+        }
+    }
+}
+
+fn is_office_text_part(name: &str) -> bool {
+    // ODF documents keep their text in content.xml, OOXML splits it across several parts
+    matches!(
+        name,
+        "content.xml"
+            | "word/document.xml"
+            | "word/footnotes.xml"
+            | "word/endnotes.xml"
+            | "xl/sharedStrings.xml"
+    ) || (name.ends_with(".xml")
+        && (name.starts_with("word/header")
+            || name.starts_with("word/footer")
+            || name.starts_with("ppt/slides/slide")
+            || name.starts_with("ppt/notesSlides/notesSlide")))
+}
+
+fn read_archive_entry<R: Read + Seek>(
+    archive: &mut ZipArchive<R>,
+    name: &str,
+    budget: &mut usize,
+) -> Option<Vec<u8>> {
+    let entry = archive.by_name(name).ok()?;
+    let mut contents = Vec::with_capacity((entry.size() as usize).min(*budget));
+    entry
+        .take(*budget as u64 + 1)
+        .read_to_end(&mut contents)
+        .ok()?;
+
+    if contents.len() <= *budget {
+        *budget -= contents.len();
+        Some(contents)
+    } else {
+        *budget = 0;
+        None
+    }
+}
+
+fn xml_to_text(xml: &[u8], text: &mut ExtractedText) -> bool {
+    let mut reader = quick_xml::Reader::from_reader(xml);
+    let mut buf = Vec::new();
+    let mut paragraph = String::new();
+
+    loop {
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Text(value)) => {
+                if let Ok(value) = value.xml_content() {
+                    paragraph.push_str(&value);
+                }
+            }
+            Ok(Event::GeneralRef(entity)) => {
+                let value = match entity.as_ref() {
+                    b"lt" => Some('<'),
+                    b"gt" => Some('>'),
+                    b"amp" => Some('&'),
+                    b"apos" => Some('\''),
+                    b"quot" => Some('"'),
+                    _ => entity.resolve_char_ref().ok().flatten(),
+                };
+                if let Some(value) = value {
+                    paragraph.push(value);
+                }
+            }
+            Ok(Event::CData(value)) => {
+                paragraph.push_str(&String::from_utf8_lossy(&value));
+            }
+            Ok(Event::Empty(element)) => {
+                // Tabs, line breaks and ODF spacing elements
+                if matches!(
+                    element.local_name().as_ref(),
+                    b"tab" | b"br" | b"cr" | b"s" | b"line-break"
+                ) {
+                    paragraph.push(' ');
+                }
+            }
+            Ok(Event::End(element)) => {
+                // Paragraphs, headings, table cells and shared strings
+                if matches!(
+                    element.local_name().as_ref(),
+                    b"p" | b"h" | b"si" | b"tc" | b"table-cell" | b"list-item"
+                ) && !paragraph.is_empty()
+                {
+                    if !text.push(&paragraph) {
+                        return false;
+                    }
+                    paragraph.clear();
+                }
+            }
+            Ok(Event::Eof) | Err(_) => break,
+            _ => (),
+        }
+        buf.clear();
+    }
+
+    text.push(&paragraph)
 }
 
 #[derive(Debug, PartialEq, Eq)]
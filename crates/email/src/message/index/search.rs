@@ -12,6 +12,7 @@ use crate::message::{
         MESSAGE_RECEIVED_MASK, MetadataHeaderName,
     },
 };
+use common::config::mailstore::email::ExtractLimits;
 use mail_parser::{DateTime, decoders::html::html_to_text, parsers::fields::thread::thread_name};
 use nlp::{
     language::{
@@ -35,6 +36,7 @@ impl ArchivedMessageMetadata {
         document_id: u32,
         raw_message: &[u8],
         index_fields: &AHashSet<SearchField>,
+        extract_limits: &ExtractLimits,
         default_language: Language,
     ) -> IndexDocument {
         let mut detector = LanguageDetector::new();
@@ -322,10 +324,43 @@ impl ArchivedMessageMetadata {
                                     language,
                                 );
                             }
+                            ArchivedMetadataPartType::Binary
+                            | ArchivedMetadataPartType::InlineBinary => {
+                                if let Some(text) =
+                                    sub_part.extract_text(&raw_message, extract_limits)
+                                {
+                                    if language.is_unknown() {
+                                        detector.detect(&text, MIN_LANGUAGE_SCORE);
+                                    }
+
+                                    document.index_text(
+                                        SearchField::Email(EmailSearchField::Attachment),
+                                        &text,
+                                        language,
+                                    );
+                                }
+                            }
                             _ => (),
                         }
                     }
                 }
+                ArchivedMetadataPartType::Binary | ArchivedMetadataPartType::InlineBinary
+                    if index_fields.is_empty()
+                        || index_fields
+                            .contains(&SearchField::Email(EmailSearchField::Attachment)) =>
+                {
+                    if let Some(text) = part.extract_text(&raw_message, extract_limits) {
+                        if part_language.is_unknown() {
+                            detector.detect(&text, MIN_LANGUAGE_SCORE);
+                        }
+
+                        document.index_text(
+                            SearchField::Email(EmailSearchField::Attachment),
+                            &text,
+                            part_language,
+                        );
+                    }
+                }
                 _ => {}
             }
         }
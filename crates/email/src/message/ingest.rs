@@ -7,16 +7,17 @@
 use super::crypto::{EncryptMessage, EncryptMessageError};
 use crate::{
     cache::{MessageCacheFetch, email::MessageCacheAccess, mailbox::MailboxCacheAccess},
-    mailbox::{INBOX_ID, JUNK_ID, SENT_ID, TRASH_ID, UidMailbox},
+    mailbox::{INBOX_ID, JUNK_ID, SENT_ID, TRASH_ID, UidMailbox, save_date::save_date_class},
     message::{
         crypto::EncryptionFlags,
         index::{IndexMessage, extractors::VisitText},
         metadata::{MessageData, MessageMetadata},
     },
 };
-use common::{Server, auth::AccessToken};
+use common::{Server, auth::AccessToken, storage::encryption::AutocryptKey};
 use groupware::{
     calendar::itip::{ItipIngest, ItipIngestError},
+    contact::autocrypt::AutocryptIngest,
     scheduling::{ItipError, ItipMessages},
 };
 use mail_parser::{
@@ -399,10 +400,8 @@ impl EmailIngest for Server {
                                         )
                                         .await
                                     {
-                                        Ok(message) => {
-                                            if let Some(message) = message {
-                                                itip_messages.push(message);
-                                            }
+                                        Ok(messages) => {
+                                            itip_messages.extend(messages);
                                             trc::event!(
                                                 Calendar(trc::CalendarEvent::ItipMessageReceived),
                                                 SpanId = params.session_id,
@@ -451,6 +450,46 @@ impl EmailIngest for Server {
                     }
                 }
 
+                // Autocrypt key collection
+                if !is_spam
+                    && is_sender_authenticated
+                    && let Some(sender) = message
+                        .from()
+                        .and_then(|s| s.first())
+                        .and_then(|s| s.address())
+                        .and_then(sanitize_email)
+                    && sender != deliver_to
+                    && let Some(key) = message
+                        .headers()
+                        .iter()
+                        .find(|header| {
+                            matches!(&header.name, HeaderName::Other(name)
+                                if name.eq_ignore_ascii_case("Autocrypt"))
+                        })
+                        .and_then(|header| header.value.as_text())
+                        .and_then(AutocryptKey::parse)
+                    && key.addr == sender
+                {
+                    let account_info = self
+                        .build_account_info(account.clone())
+                        .await
+                        .caused_by(trc::location!())?;
+                    match self.autocrypt_ingest(&account_info, &key).await {
+                        Ok(true) => {
+                            trc::event!(
+                                MessageIngest(MessageIngestEvent::AutocryptKeyCollected),
+                                SpanId = params.session_id,
+                                From = sender,
+                                AccountId = account_id,
+                            );
+                        }
+                        Ok(false) => {}
+                        Err(err) => {
+                            trc::error!(err.caused_by(trc::location!()));
+                        }
+                    }
+                }
+
                 is_spam
             }
             IngestSource::Jmap { train_classifier } | IngestSource::Imap { train_classifier } => {
@@ -841,9 +880,10 @@ impl EmailIngest for Server {
 
         batch.with_collection(Collection::Mailbox);
 
-        for mailbox_id in mailbox_ids {
+        let mailbox_ids = mailbox_ids.into_iter().collect::<Vec<_>>();
+        for mailbox_id in &mailbox_ids {
             batch
-                .with_document(mailbox_id)
+                .with_document(*mailbox_id)
                 .add_and_get(MailboxField::UidCounter, 1);
             expected_ids += 1;
         }
@@ -854,10 +894,32 @@ impl EmailIngest for Server {
             AssignedIds::default()
         };
         if ids.ids.len() == expected_ids {
-            Ok(ids.ids.into_iter().map(|id| match id {
-                AssignedId::Counter(id) => id as u32,
-                AssignedId::ChangeId(_) => unreachable!(),
-            }))
+            let ids = ids
+                .ids
+                .into_iter()
+                .map(|id| match id {
+                    AssignedId::Counter(id) => id as u32,
+                    AssignedId::ChangeId(_) => unreachable!(),
+                })
+                .collect::<Vec<_>>();
+
+            // Record the date each message was saved to the mailbox
+            if !mailbox_ids.is_empty() {
+                let save_date = now().serialize();
+                let uids = &ids[ids.len() - mailbox_ids.len()..];
+                let mut batch = BatchBuilder::new();
+                batch
+                    .with_account_id(account_id)
+                    .with_collection(Collection::Mailbox);
+                for (mailbox_id, uid) in mailbox_ids.into_iter().zip(uids) {
+                    batch
+                        .with_document(mailbox_id)
+                        .set(save_date_class(mailbox_id, *uid), save_date.clone());
+                }
+                self.core.storage.data.write(batch.build_all()).await?;
+            }
+
+            Ok(ids.into_iter())
         } else {
             Err(trc::StoreEvent::UnexpectedError
                 .caused_by(trc::location!())
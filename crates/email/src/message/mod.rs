@@ -11,3 +11,4 @@ pub mod delivery;
 pub mod index;
 pub mod ingest;
 pub mod metadata;
+pub mod snooze;
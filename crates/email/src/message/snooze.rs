@@ -0,0 +1,201 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::{
+    cache::{MessageCacheFetch, mailbox::MailboxCacheAccess},
+    mailbox::INBOX_ID,
+};
+use common::Server;
+use registry::schema::{
+    enums::TaskAccountMaintenanceType,
+    structs::{Task, TaskAccountMaintenance, TaskStatus},
+};
+use std::future::Future;
+use store::{
+    Deserialize, IterateParams, SerializeInfallible, U32_LEN, U64_LEN, ValueKey,
+    write::{BatchBuilder, ValueClass, key::DeserializeBigEndian, key::KeySerializer},
+};
+use trc::AddContext;
+use types::{collection::Collection, field::EmailField, special_use::SpecialUse};
+
+// IMAP keyword used to snooze a message, followed by an RFC 3339 date,
+// for example "$SnoozedUntil:2026-10-20T08:00:00Z".
+pub const SNOOZE_KEYWORD_PREFIX: &str = "$snoozeduntil:";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SnoozedEmail {
+    pub until: u64,
+    pub mailbox_id: u32,
+}
+
+pub trait EmailSnooze: Sync + Send {
+    fn email_snoozed(
+        &self,
+        account_id: u32,
+        document_id: u32,
+    ) -> impl Future<Output = trc::Result<Option<SnoozedEmail>>> + Send;
+
+    fn email_snooze_origin(
+        &self,
+        account_id: u32,
+        document_id: u32,
+        mailbox_ids: &[u32],
+    ) -> impl Future<Output = trc::Result<u32>> + Send;
+
+    fn emails_snoozed_due(
+        &self,
+        account_id: u32,
+        due: u64,
+    ) -> impl Future<Output = trc::Result<Vec<(u32, SnoozedEmail)>>> + Send;
+}
+
+impl EmailSnooze for Server {
+    async fn email_snoozed(
+        &self,
+        account_id: u32,
+        document_id: u32,
+    ) -> trc::Result<Option<SnoozedEmail>> {
+        self.store()
+            .get_value::<SnoozedEmail>(ValueKey::property(
+                account_id,
+                Collection::Email,
+                document_id,
+                EmailField::SnoozedUntil,
+            ))
+            .await
+            .caused_by(trc::location!())
+    }
+
+    async fn email_snooze_origin(
+        &self,
+        account_id: u32,
+        document_id: u32,
+        mailbox_ids: &[u32],
+    ) -> trc::Result<u32> {
+        // Keep the original mailbox when the message is snoozed again
+        if let Some(snoozed) = self.email_snoozed(account_id, document_id).await? {
+            return Ok(snoozed.mailbox_id);
+        }
+
+        let snoozed_mailbox_id = self
+            .get_cached_messages(account_id)
+            .await
+            .caused_by(trc::location!())?
+            .mailbox_by_role(&SpecialUse::Snoozed)
+            .map(|m| m.document_id);
+
+        Ok(mailbox_ids
+            .iter()
+            .copied()
+            .find(|mailbox_id| Some(*mailbox_id) != snoozed_mailbox_id)
+            .unwrap_or(INBOX_ID))
+    }
+
+    async fn emails_snoozed_due(
+        &self,
+        account_id: u32,
+        due: u64,
+    ) -> trc::Result<Vec<(u32, SnoozedEmail)>> {
+        let mut results = Vec::new();
+        self.store()
+            .iterate(
+                IterateParams::new(
+                    ValueKey {
+                        account_id,
+                        collection: Collection::Email.into(),
+                        document_id: 0,
+                        class: ValueClass::Property(EmailField::SnoozedUntil.into()),
+                    },
+                    ValueKey {
+                        account_id,
+                        collection: Collection::Email.into(),
+                        document_id: u32::MAX,
+                        class: ValueClass::Property(EmailField::SnoozedUntil.into()),
+                    },
+                )
+                .ascending(),
+                |key, value| {
+                    let snoozed = SnoozedEmail::deserialize(value)?;
+                    if snoozed.until <= due {
+                        results.push((key.deserialize_be_u32(key.len() - U32_LEN)?, snoozed));
+                    }
+
+                    Ok(true)
+                },
+            )
+            .await
+            .caused_by(trc::location!())
+            .map(|_| results)
+    }
+}
+
+impl SnoozedEmail {
+    pub fn new(until: u64, mailbox_id: u32) -> Self {
+        SnoozedEmail { until, mailbox_id }
+    }
+
+    // Stores the snooze date and schedules the wake-up task at the due time.
+    pub fn write(&self, batch: &mut BatchBuilder, account_id: u32, document_id: u32) {
+        batch
+            .with_account_id(account_id)
+            .with_collection(Collection::Email)
+            .with_document(document_id)
+            .set(EmailField::SnoozedUntil, self.serialize())
+            .schedule_task(Task::AccountMaintenance(TaskAccountMaintenance {
+                account_id: account_id.into(),
+                maintenance_type: TaskAccountMaintenanceType::EmailSnooze,
+                status: TaskStatus::at(self.until as i64),
+            }));
+    }
+
+    pub fn clear(batch: &mut BatchBuilder, account_id: u32, document_id: u32) {
+        batch
+            .with_account_id(account_id)
+            .with_collection(Collection::Email)
+            .with_document(document_id)
+            .clear(EmailField::SnoozedUntil);
+    }
+
+    pub fn is_snooze_keyword(keyword: &str) -> bool {
+        keyword
+            .get(..SNOOZE_KEYWORD_PREFIX.len())
+            .is_some_and(|prefix| prefix.eq_ignore_ascii_case(SNOOZE_KEYWORD_PREFIX))
+    }
+
+    pub fn keyword(until: u64) -> String {
+        format!(
+            "$SnoozedUntil:{}",
+            mail_parser::DateTime::from_timestamp(until as i64).to_rfc3339()
+        )
+    }
+
+    pub fn parse_keyword(keyword: &str) -> Option<u64> {
+        keyword
+            .get(SNOOZE_KEYWORD_PREFIX.len()..)
+            .filter(|_| Self::is_snooze_keyword(keyword))
+            .and_then(mail_parser::DateTime::parse_rfc3339)
+            .filter(|date| date.is_valid())
+            .map(|date| date.to_timestamp().max(0) as u64)
+    }
+}
+
+impl SerializeInfallible for SnoozedEmail {
+    fn serialize(&self) -> Vec<u8> {
+        KeySerializer::new(U64_LEN + U32_LEN)
+            .write(self.until)
+            .write(self.mailbox_id)
+            .finalize()
+    }
+}
+
+impl Deserialize for SnoozedEmail {
+    fn deserialize(bytes: &[u8]) -> trc::Result<Self> {
+        Ok(SnoozedEmail {
+            until: bytes.deserialize_be_u64(0)?,
+            mailbox_id: bytes.deserialize_be_u32(U64_LEN)?,
+        })
+    }
+}
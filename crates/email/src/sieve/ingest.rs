@@ -13,9 +13,15 @@ use crate::{
         ingest::{EmailIngest, IngestEmail, IngestSource, IngestedEmail},
     },
 };
-use common::{Server, auth::AccessToken, scripts::plugins::PluginContext};
+use common::{
+    KV_RATE_LIMIT_NOTIFY, Server,
+    auth::AccessToken,
+    ipc::{PushNotification, SieveNotify},
+    scripts::plugins::PluginContext,
+};
+use groupware::cache::GroupwareCache;
 use mail_parser::MessageParser;
-use sieve::{Envelope, Event, Input, Mailbox, Recipient, Sieve, SpamStatus};
+use sieve::{Envelope, Event, Importance, Input, Mailbox, MatchAs, Recipient, Sieve, SpamStatus};
 use std::{borrow::Cow, sync::Arc};
 use std::{future::Future, str::FromStr};
 use store::{
@@ -29,12 +35,13 @@ use store::{
 use trc::{AddContext, SieveEvent};
 use types::{
     blob_hash::BlobHash,
-    collection::Collection,
-    field::{PrincipalField, SieveField},
+    collection::{Collection, SyncCollection},
+    field::{ContactField, PrincipalField, SieveField},
     id::Id,
     keyword::Keyword,
     special_use::SpecialUse,
 };
+use utils::sanitize_email;
 
 struct SieveMessage<'x> {
     pub raw_message: Cow<'x, [u8]>,
@@ -392,6 +399,24 @@ impl SieveScriptIngest for Server {
                                 }
                             };
 
+                            // Notifications sent to mailto: URIs are subject to rate limiting
+                            if is_notification(&message.raw_message)
+                                && !is_notify_allowed(self, account_id)
+                                    .await
+                                    .caused_by(trc::location!())?
+                            {
+                                trc::event!(
+                                    Sieve(SieveEvent::NotifyRateLimit),
+                                    AccountId = account_id,
+                                    To = recipients
+                                        .iter()
+                                        .map(|r| trc::Value::String(r.as_str().into()))
+                                        .collect::<Vec<_>>(),
+                                    SpanId = session_id
+                                );
+                                continue;
+                            }
+
                             if message.raw_message.len() <= self.core.email.mail_max_size {
                                 trc::event!(
                                     Sieve(SieveEvent::SendMessage),
@@ -433,9 +458,105 @@ impl SieveScriptIngest for Server {
                             continue;
                         }
                     }
-                    Event::ListContains { .. }
-                    | Event::Notify { .. }
-                    | Event::SetEnvelope { .. } => {
+                    Event::Notify {
+                        importance,
+                        message,
+                        method,
+                        ..
+                    } => {
+                        input = true.into();
+
+                        // mailto: notifications are delivered as SendMessage events
+                        if !method
+                            .split_once(':')
+                            .is_some_and(|(scheme, _)| scheme.eq_ignore_ascii_case("push"))
+                        {
+                            trc::event!(
+                                Sieve(SieveEvent::NotSupported),
+                                Details = "Unsupported notification method",
+                                Url = method,
+                                SpanId = session_id
+                            );
+                            continue;
+                        }
+
+                        if !is_notify_allowed(self, account_id)
+                            .await
+                            .caused_by(trc::location!())?
+                        {
+                            trc::event!(
+                                Sieve(SieveEvent::NotifyRateLimit),
+                                AccountId = account_id,
+                                Url = method,
+                                SpanId = session_id
+                            );
+                            continue;
+                        }
+
+                        let message = if !message.is_empty() {
+                            message
+                        } else {
+                            let message = instance.message();
+                            format!(
+                                "{}: {}",
+                                message
+                                    .from()
+                                    .and_then(|from| from.first())
+                                    .and_then(|from| from.address())
+                                    .unwrap_or(envelope_from),
+                                message.subject().unwrap_or_default()
+                            )
+                        };
+
+                        trc::event!(
+                            Sieve(SieveEvent::Notify),
+                            AccountId = account_id,
+                            Url = method,
+                            Details = message.clone(),
+                            SpanId = session_id
+                        );
+
+                        self.broadcast_push_notification(PushNotification::SieveNotify(
+                            SieveNotify {
+                                account_id,
+                                importance: match importance {
+                                    Importance::High => 1,
+                                    Importance::Normal => 2,
+                                    Importance::Low => 3,
+                                },
+                                message,
+                            },
+                        ))
+                        .await;
+                    }
+                    Event::ListContains {
+                        lists,
+                        values,
+                        match_as,
+                    } => {
+                        input = false.into();
+                        for list in lists {
+                            match list_contains(self, account_id, &list, &values, match_as)
+                                .await
+                                .caused_by(trc::location!())?
+                            {
+                                Some(true) => {
+                                    input = true.into();
+                                    break;
+                                }
+                                Some(false) => {}
+                                None => {
+                                    trc::event!(
+                                        Sieve(SieveEvent::ListNotFound),
+                                        AccountId = account_id,
+                                        SpanId = session_id,
+                                        Details = list
+                                    );
+                                }
+                            }
+                        }
+                    }
+                    Event::SetEnvelope { .. } => {
                         // Not allowed
                         input = false.into();
                     }
@@ -750,3 +871,114 @@ pub struct CompiledScript {
     pub name: String,
     pub version: ArchiveVersion,
 }
+
+async fn is_notify_allowed(server: &Server, account_id: u32) -> trc::Result<bool> {
+    if let Some(rate) = &server.core.sieve.untrusted_notify_rate {
+        server
+            .in_memory_store()
+            .is_rate_allowed(KV_RATE_LIMIT_NOTIFY, &account_id.to_be_bytes(), rate, false)
+            .await
+            .map(|result| result.is_none())
+    } else {
+        Ok(true)
+    }
+}
+
+// Returns None if the list does not exist
+async fn list_contains(
+    server: &Server,
+    account_id: u32,
+    list: &str,
+    values: &[String],
+    match_as: MatchAs,
+) -> trc::Result<Option<bool>> {
+    if let Some(name) = list.strip_prefix("tag:addressbook:") {
+        let resources = server
+            .fetch_dav_resources(account_id, account_id, SyncCollection::AddressBook)
+            .await
+            .caused_by(trc::location!())?;
+        let address_book_id = if name == "default" {
+            server
+                .store()
+                .get_value::<u32>(ValueKey {
+                    account_id,
+                    collection: Collection::Principal.into(),
+                    document_id: 0,
+                    class: ValueClass::Property(PrincipalField::DefaultAddressBookId.into()),
+                })
+                .await
+                .caused_by(trc::location!())?
+                .or_else(|| resources.document_ids(true).next())
+        } else {
+            resources
+                .by_path(name)
+                .filter(|resource| resource.is_container())
+                .map(|resource| resource.document_id())
+        };
+        let Some(address_book_id) = address_book_id else {
+            return Ok(None);
+        };
+
+        for email in values.iter().filter_map(|value| sanitize_email(value)) {
+            let card_ids = server
+                .document_ids_matching(
+                    account_id,
+                    Collection::ContactCard,
+                    ContactField::Email,
+                    email.as_bytes(),
+                )
+                .await
+                .caused_by(trc::location!())?;
+            if resources.resources.iter().any(|resource| {
+                !resource.is_container()
+                    && card_ids.contains(resource.document_id)
+                    && resource.is_child_of(address_book_id)
+            }) {
+                return Ok(Some(true));
+            }
+        }
+
+        Ok(Some(false))
+    } else if server.core.sieve.untrusted_ext_lists.contains(list)
+        && !list.is_empty()
+        && list != "*"
+        && let Some(store) = server.get_lookup_store(list)
+    {
+        // Only lookup stores explicitly exposed to user scripts can be queried, the
+        // default in-memory store ("" or "*") holds internal data and is never exposed
+        for value in values {
+            let exists = store
+                .key_exists(if matches!(match_as, MatchAs::Lowercase) {
+                    value.to_lowercase()
+                } else {
+                    value.clone()
+                })
+                .await
+                .caused_by(trc::location!())?;
+            if exists {
+                return Ok(Some(true));
+            }
+        }
+
+        Ok(Some(false))
+    } else {
+        Ok(None)
+    }
+}
+
+fn is_notification(raw_message: &[u8]) -> bool {
+    let headers = raw_message
+        .windows(4)
+        .position(|window| window == b"\r\n\r\n")
+        .map_or(raw_message, |pos| &raw_message[..pos]);
+
+    String::from_utf8_lossy(headers).lines().any(|line| {
+        line.split_once(':').is_some_and(|(name, value)| {
+            name.trim().eq_ignore_ascii_case("Auto-Submitted")
+                && value
+                    .trim_start()
+                    .get(..13)
+                    .is_some_and(|value| value.eq_ignore_ascii_case("auto-notified"))
+        })
+    })
+}
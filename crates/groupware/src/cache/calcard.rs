@@ -291,6 +291,7 @@ pub(super) fn resource_from_calendar(calendar: &ArchivedCalendar, document_id: u
                     tz: pref.time_zone.tz().unwrap_or(Tz::UTC),
                 })
                 .collect(),
+            supports_tasks: calendar.supports_tasks(),
         },
     }
 }
@@ -310,6 +311,7 @@ pub(super) fn resource_from_event(event: &ArchivedCalendarEvent, document_id: u3
                 .collect(),
             start,
             duration,
+            is_task: event.data.is_task(),
         },
     }
 }
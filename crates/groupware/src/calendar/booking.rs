@@ -0,0 +1,251 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::{
+    cache::GroupwareCache,
+    calendar::{CalendarEventData, freebusy::CalendarFreeBusy, itip::ItipIngestError},
+    scheduling::{
+        ItipError, ItipMessage,
+        event_update::itip_update,
+        inbound::{BookingDecision, itip_apply_booking},
+    },
+};
+use calcard::{
+    common::timezone::Tz,
+    icalendar::{ICalendar, ICalendarFreeBusyType},
+};
+use common::{Server, auth::AccountInfo};
+use registry::schema::{
+    enums::{BookingPolicy, PrincipalKind},
+    structs::Account,
+};
+use store::write::now;
+use trc::AddContext;
+use types::{TimeRange, collection::SyncCollection};
+
+pub trait ItipBooking: Sync + Send {
+    fn itip_booking_decision(
+        &self,
+        account_info: &AccountInfo,
+        sender: &str,
+        data: &CalendarEventData,
+        document_id: Option<u32>,
+    ) -> impl Future<Output = trc::Result<Option<BookingDecision>>> + Send;
+
+    fn itip_booking_apply(
+        &self,
+        account_info: &AccountInfo,
+        sender: &str,
+        data: &mut CalendarEventData,
+        document_id: Option<u32>,
+    ) -> impl Future<Output = Result<Vec<ItipMessage<ICalendar>>, ItipIngestError>> + Send;
+}
+
+impl ItipBooking for Server {
+    async fn itip_booking_decision(
+        &self,
+        account_info: &AccountInfo,
+        sender: &str,
+        data: &CalendarEventData,
+        document_id: Option<u32>,
+    ) -> trc::Result<Option<BookingDecision>> {
+        if account_info.principal_kind() == PrincipalKind::Individual {
+            return Ok(None);
+        }
+
+        let account_id = account_info.account_id();
+        let Some(Account::User(account)) = self
+            .registry()
+            .object::<Account>(account_id.into())
+            .await
+            .caused_by(trc::location!())?
+        else {
+            return Ok(None);
+        };
+        if account.booking_policy == BookingPolicy::Manual {
+            return Ok(None);
+        }
+
+        // Expand all instances of the requested event
+        let instances = data.expand_all(Tz::UTC).unwrap_or_default();
+        let (Some(range_start), Some(range_end)) = (
+            instances.iter().map(|instance| instance.start).min(),
+            instances.iter().map(|instance| instance.end).max(),
+        ) else {
+            return Ok(None);
+        };
+
+        // Validate the booking rules
+        let now = now() as i64;
+        let reason = if !account.booking_allowed_requesters.is_empty()
+            && !account
+                .booking_allowed_requesters
+                .iter()
+                .any(|requester| is_allowed_requester(sender, requester))
+        {
+            Some("Requester not allowed")
+        } else if account
+            .booking_max_duration
+            .as_ref()
+            .is_some_and(|max_duration| {
+                let max_duration = max_duration.as_secs() as i64;
+                instances
+                    .iter()
+                    .any(|instance| instance.end - instance.start > max_duration)
+            })
+        {
+            Some("Maximum duration exceeded")
+        } else if account
+            .booking_window
+            .as_ref()
+            .is_some_and(|window| range_end > now + window.as_secs() as i64)
+        {
+            Some("Outside booking window")
+        } else if account.booking_policy != BookingPolicy::AutoAccept {
+            // Look for conflicting events, ignoring those already declined
+            let range = TimeRange {
+                start: range_start,
+                end: range_end,
+            };
+            let document_ids = self
+                .fetch_dav_resources(account_id, account_id, SyncCollection::Calendar)
+                .await
+                .caused_by(trc::location!())?
+                .resources
+                .iter()
+                .filter(|resource| {
+                    Some(resource.document_id) != document_id
+                        && resource
+                            .event_time_range()
+                            .is_some_and(|(start, end)| range.is_in_range(false, start, end))
+                })
+                .map(|resource| resource.document_id)
+                .collect::<Vec<_>>();
+            let busy_periods = if !document_ids.is_empty() {
+                self.calendar_busy_periods(
+                    account_id,
+                    document_ids,
+                    range,
+                    Tz::UTC,
+                    account_info.addresses(),
+                )
+                .await
+                .caused_by(trc::location!())?
+            } else {
+                Default::default()
+            };
+
+            if busy_periods
+                .iter()
+                .filter(|(fb_type, _)| **fb_type != ICalendarFreeBusyType::Free)
+                .flat_map(|(_, periods)| periods.iter())
+                .any(|(start, end)| {
+                    instances
+                        .iter()
+                        .any(|instance| instance.start < *end && instance.end > *start)
+                })
+            {
+                Some("Conflicts with an existing booking")
+            } else {
+                None
+            }
+        } else {
+            None
+        };
+
+        if let Some(reason) = reason {
+            trc::event!(
+                Calendar(trc::CalendarEvent::BookingDeclined),
+                AccountId = account_id,
+                From = sender.to_string(),
+                Reason = reason,
+            );
+
+            return Ok(Some(BookingDecision::Decline));
+        }
+
+        match account.booking_policy {
+            BookingPolicy::Delegate => {
+                let mut delegates = Vec::with_capacity(account.booking_delegate_ids.len());
+                for delegate_id in account.booking_delegate_ids.iter() {
+                    let Some(delegate) = self
+                        .try_account(delegate_id.document_id())
+                        .await
+                        .caused_by(trc::location!())?
+                    else {
+                        continue;
+                    };
+                    if let Some(address) = self
+                        .build_account_info(delegate)
+                        .await
+                        .caused_by(trc::location!())?
+                        .addresses
+                        .into_iter()
+                        .next()
+                    {
+                        delegates.push(address);
+                    }
+                }
+
+                if !delegates.is_empty() {
+                    trc::event!(
+                        Calendar(trc::CalendarEvent::BookingDelegated),
+                        AccountId = account_id,
+                        From = sender.to_string(),
+                        To = delegates.clone(),
+                    );
+
+                    Ok(Some(BookingDecision::Delegate(delegates)))
+                } else {
+                    Ok(None)
+                }
+            }
+            _ => {
+                trc::event!(
+                    Calendar(trc::CalendarEvent::BookingAccepted),
+                    AccountId = account_id,
+                    From = sender.to_string(),
+                );
+
+                Ok(Some(BookingDecision::Accept))
+            }
+        }
+    }
+
+    async fn itip_booking_apply(
+        &self,
+        account_info: &AccountInfo,
+        sender: &str,
+        data: &mut CalendarEventData,
+        document_id: Option<u32>,
+    ) -> Result<Vec<ItipMessage<ICalendar>>, ItipIngestError> {
+        let Some(decision) = self
+            .itip_booking_decision(account_info, sender, data, document_id)
+            .await?
+        else {
+            return Ok(vec![]);
+        };
+
+        // Update the participation status and reply to the organizer
+        let old_ical = data.event.clone();
+        itip_apply_booking(&mut data.event, account_info.addresses(), &decision);
+        match itip_update(&mut data.event, &old_ical, account_info.addresses()) {
+            Ok(messages) => Ok(messages),
+            Err(ItipError::NothingToSend) => Ok(vec![]),
+            Err(err) => Err(ItipIngestError::Message(err)),
+        }
+    }
+}
+
+fn is_allowed_requester(sender: &str, requester: &str) -> bool {
+    if requester.contains('@') {
+        sender.eq_ignore_ascii_case(requester)
+    } else {
+        sender
+            .rsplit_once('@')
+            .is_some_and(|(_, domain)| domain.eq_ignore_ascii_case(requester))
+    }
+}
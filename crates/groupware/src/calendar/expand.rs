@@ -239,6 +239,23 @@ impl CalendarEventData {
         Some(expansion)
     }
 
+    pub fn expand_all(&self, default_tz: Tz) -> Option<Vec<CalendarEventExpansion>> {
+        let mut expansion_ids = AHashSet::new();
+        for range in self.time_ranges.iter() {
+            let instances = range.instances.as_ref();
+            let (offset_or_count, bytes_read) = instances.read_leb128::<u32>()?;
+            let count = if instances.len() > bytes_read {
+                offset_or_count
+            } else {
+                1
+            };
+            let base_expansion_id = expansion_ids.len() as u32;
+            expansion_ids.extend(base_expansion_id..base_expansion_id + count);
+        }
+
+        self.expand_from_ids(&mut expansion_ids, default_tz)
+    }
+
     pub fn expand_single(&self, comp_id: u32, default_tz: Tz) -> Option<CalendarEventExpansion> {
         let range = self.time_ranges.iter().find(|r| r.id as u32 == comp_id)?;
         let instances = range.instances.as_ref();
@@ -0,0 +1,258 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::calendar::CalendarEvent;
+use ahash::AHashMap;
+use calcard::{
+    common::timezone::Tz,
+    icalendar::{
+        ArchivedICalendarComponentType, ArchivedICalendarEntry, ArchivedICalendarParameterName,
+        ArchivedICalendarParameterValue, ArchivedICalendarParticipationStatus,
+        ArchivedICalendarProperty, ArchivedICalendarStatus, ArchivedICalendarValue,
+        ICalendarFreeBusyType, ICalendarParameterName, ICalendarTransparency,
+    },
+};
+use common::Server;
+use std::str::FromStr;
+use store::{
+    ValueKey,
+    write::{AlignedBytes, Archive, serialize::rkyv_deserialize},
+};
+use trc::AddContext;
+use types::{TimeRange, collection::Collection};
+use utils::sanitize_email;
+
+pub type BusyPeriods = AHashMap<ICalendarFreeBusyType, Vec<(i64, i64)>>;
+
+pub trait CalendarFreeBusy: Sync + Send {
+    fn calendar_busy_periods(
+        &self,
+        account_id: u32,
+        document_ids: Vec<u32>,
+        range: TimeRange,
+        default_tz: Tz,
+        declined_by: &[String],
+    ) -> impl Future<Output = trc::Result<BusyPeriods>> + Send;
+}
+
+impl CalendarFreeBusy for Server {
+    async fn calendar_busy_periods(
+        &self,
+        account_id: u32,
+        document_ids: Vec<u32>,
+        range: TimeRange,
+        default_tz: Tz,
+        declined_by: &[String],
+    ) -> trc::Result<BusyPeriods> {
+        let mut fb_entries: BusyPeriods = AHashMap::with_capacity(document_ids.len());
+
+        for document_id in document_ids {
+            let Some(archive) = self
+                .store()
+                .get_value::<Archive<AlignedBytes>>(ValueKey::archive(
+                    account_id,
+                    Collection::CalendarEvent,
+                    document_id,
+                ))
+                .await
+                .caused_by(trc::location!())?
+            else {
+                continue;
+            };
+            let event = archive
+                .unarchive::<CalendarEvent>()
+                .caused_by(trc::location!())?;
+
+            /*
+               Only VEVENT components without a TRANSP property or with the TRANSP
+               property set to OPAQUE, and VFREEBUSY components SHOULD be considered
+               in generating the free busy time information.
+            */
+            let mut components = event
+                .data
+                .event
+                .components
+                .iter()
+                .enumerate()
+                .filter(|(_, comp)| {
+                    (matches!(comp.component_type, ArchivedICalendarComponentType::VEvent)
+                        && comp
+                            .transparency()
+                            .is_none_or(|t| t == &ICalendarTransparency::Opaque))
+                        || matches!(
+                            comp.component_type,
+                            ArchivedICalendarComponentType::VFreebusy
+                        )
+                })
+                .peekable();
+
+            if components.peek().is_none() {
+                continue;
+            }
+
+            let events = event.data.expand(default_tz, range).unwrap_or_else(|| {
+                trc::event!(
+                    Calendar(trc::CalendarEvent::RuleExpansionError),
+                    Reason = "chrono error",
+                    Details = event.data.event.to_string(),
+                );
+                vec![]
+            });
+
+            if events.is_empty() {
+                continue;
+            }
+
+            for (component_id, component) in components {
+                let component_id = component_id as u32;
+                match component.component_type {
+                    ArchivedICalendarComponentType::VEvent => {
+                        let fbtype = match component.status() {
+                            Some(ArchivedICalendarStatus::Cancelled) => continue,
+                            Some(ArchivedICalendarStatus::Tentative) => {
+                                ICalendarFreeBusyType::BusyTentative
+                            }
+                            _ => ICalendarFreeBusyType::Busy,
+                        };
+
+                        if !declined_by.is_empty()
+                            && is_declined_by(component.entries.iter(), declined_by)
+                        {
+                            continue;
+                        }
+
+                        let mut events_in_range = Vec::new();
+                        for event in &events {
+                            if event.comp_id == component_id
+                                && range.is_in_range(false, event.start, event.end)
+                            {
+                                events_in_range.push((event.start, event.end));
+                            }
+                        }
+
+                        if !events_in_range.is_empty() {
+                            fb_entries
+                                .entry(fbtype)
+                                .or_default()
+                                .extend(events_in_range);
+                        }
+                    }
+                    ArchivedICalendarComponentType::VFreebusy => {
+                        for entry in component.entries.iter() {
+                            if matches!(entry.name, ArchivedICalendarProperty::Freebusy) {
+                                let mut fb_in_range =
+                                    freebusy_in_range_utc(entry, &range, default_tz).peekable();
+                                if fb_in_range.peek().is_some() {
+                                    let fb_type = entry
+                                        .params
+                                        .iter()
+                                        .find_map(|param| {
+                                            if let (
+                                                ArchivedICalendarParameterName::Fbtype,
+                                                ArchivedICalendarParameterValue::Fbtype(param),
+                                            ) = (&param.name, &param.value)
+                                            {
+                                                rkyv_deserialize(param).ok()
+                                            } else {
+                                                None
+                                            }
+                                        })
+                                        .unwrap_or(ICalendarFreeBusyType::Busy);
+
+                                    fb_entries.entry(fb_type).or_default().extend(fb_in_range);
+                                }
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        Ok(fb_entries)
+    }
+}
+
+// Merges overlapping busy periods into sorted, non-overlapping intervals
+pub fn merge_intervals(mut intervals: Vec<(i64, i64)>) -> Vec<(i64, i64)> {
+    if intervals.len() > 1 {
+        intervals.sort_unstable_by_key(|a| a.0);
+
+        let mut unique_intervals = Vec::new();
+        let mut start_time = intervals[0].0;
+        let mut end_time = intervals[0].1;
+
+        for &(curr_start, curr_end) in intervals.iter().skip(1) {
+            if curr_start <= end_time {
+                end_time = end_time.max(curr_end);
+            } else {
+                unique_intervals.push((start_time, end_time));
+                start_time = curr_start;
+                end_time = curr_end;
+            }
+        }
+
+        unique_intervals.push((start_time, end_time));
+        unique_intervals
+    } else {
+        intervals
+    }
+}
+
+// Whether any of the given calendar addresses declined the component
+fn is_declined_by<'x>(
+    mut entries: impl Iterator<Item = &'x ArchivedICalendarEntry>,
+    addresses: &[String],
+) -> bool {
+    entries.any(|entry| {
+        matches!(entry.name, ArchivedICalendarProperty::Attendee)
+            && entry
+                .values
+                .first()
+                .and_then(|value| value.as_text())
+                .and_then(|attendee| {
+                    sanitize_email(attendee.strip_prefix("mailto:").unwrap_or(attendee))
+                })
+                .is_some_and(|attendee| addresses.contains(&attendee))
+            && entry
+                .parameters(&ICalendarParameterName::Partstat)
+                .any(|value| {
+                    matches!(
+                        value,
+                        ArchivedICalendarParameterValue::Partstat(
+                            ArchivedICalendarParticipationStatus::Declined
+                        )
+                    )
+                })
+    })
+}
+
+fn freebusy_in_range_utc(
+    entry: &ArchivedICalendarEntry,
+    range: &TimeRange,
+    default_tz: Tz,
+) -> impl Iterator<Item = (i64, i64)> {
+    let tz = entry
+        .tz_id()
+        .and_then(|tz_id| Tz::from_str(tz_id).ok())
+        .unwrap_or(default_tz);
+
+    entry.values.iter().filter_map(move |value| {
+        if let ArchivedICalendarValue::Period(period) = &value {
+            period.time_range(tz).and_then(|(start, end)| {
+                let start = start.timestamp();
+                let end = end.timestamp();
+                if range.is_in_range(false, start, end) {
+                    Some((start, end))
+                } else {
+                    None
+                }
+            })
+        } else {
+            None
+        }
+    })
+}
@@ -13,15 +13,20 @@ use crate::calendar::{
     CalendarEventNotification, ChangedBy, EventPreferences,
 };
 use ahash::AHashSet;
-use calcard::icalendar::{
-    ArchivedICalendarParameterValue, ArchivedICalendarProperty, ArchivedICalendarValue,
-    ICalendarParameterValue, ICalendarProperty, ICalendarValue,
+use calcard::{
+    common::timezone::Tz,
+    icalendar::{
+        ArchivedICalendarComponentType, ArchivedICalendarParameterValue, ArchivedICalendarProperty,
+        ArchivedICalendarValue, ICalendarComponentType, ICalendarParameterValue, ICalendarProperty,
+        ICalendarValue,
+    },
 };
 use common::storage::index::{IndexValue, IndexableAndSerializableObject, IndexableObject};
 use nlp::language::{
     Language,
     detect::{LanguageDetector, MIN_LANGUAGE_SCORE},
 };
+use std::str::FromStr;
 use store::{
     U32_LEN,
     search::{CalendarSearchField, IndexDocument, SearchField},
@@ -359,6 +364,25 @@ impl CalendarEvent {
                     }))
             })
             .map(|v| xxh3::xxh3_64(v.as_bytes()))
+            .chain(
+                self.data
+                    .event
+                    .components
+                    .iter()
+                    .filter(|e| e.component_type == ICalendarComponentType::VTodo)
+                    .flat_map(|e| e.entries.iter())
+                    .filter_map(|e| match (&e.name, e.values.first()) {
+                        (
+                            ICalendarProperty::Due | ICalendarProperty::Completed,
+                            Some(ICalendarValue::PartialDateTime(date)),
+                        ) => date.to_timestamp(),
+                        (ICalendarProperty::PercentComplete, Some(ICalendarValue::Integer(v))) => {
+                            Some(*v)
+                        }
+                        _ => None,
+                    })
+                    .map(|v| xxh3::xxh3_64(&v.to_be_bytes())),
+            )
     }
 }
 
@@ -399,6 +423,26 @@ impl ArchivedCalendarEvent {
                     }))
             })
             .map(|v| xxh3::xxh3_64(v.as_bytes()))
+            .chain(
+                self.data
+                    .event
+                    .components
+                    .iter()
+                    .filter(|e| matches!(e.component_type, ArchivedICalendarComponentType::VTodo))
+                    .flat_map(|e| e.entries.iter())
+                    .filter_map(|e| match (&e.name, e.values.first()) {
+                        (
+                            ArchivedICalendarProperty::Due | ArchivedICalendarProperty::Completed,
+                            Some(ArchivedICalendarValue::PartialDateTime(date)),
+                        ) => date.to_date_time().and_then(|date| date.to_timestamp()),
+                        (
+                            ArchivedICalendarProperty::PercentComplete,
+                            Some(ArchivedICalendarValue::Integer(v)),
+                        ) => Some(v.to_native()),
+                        _ => None,
+                    })
+                    .map(|v| xxh3::xxh3_64(&v.to_be_bytes())),
+            )
     }
 }
 
@@ -420,6 +464,50 @@ impl ArchivedCalendarEvent {
             document.index_integer(CalendarSearchField::Start, self.data.event_range_start());
         }
 
+        // Index task due date, completion date and progress
+        if let Some(todo) = self
+            .data
+            .event
+            .components
+            .iter()
+            .find(|e| matches!(e.component_type, ArchivedICalendarComponentType::VTodo))
+        {
+            for entry in todo.entries.iter() {
+                let field = match entry.name {
+                    ArchivedICalendarProperty::Due => CalendarSearchField::Due,
+                    ArchivedICalendarProperty::Completed => CalendarSearchField::Completed,
+                    ArchivedICalendarProperty::PercentComplete => {
+                        CalendarSearchField::PercentComplete
+                    }
+                    _ => continue,
+                };
+
+                if !index_fields.is_empty() && !index_fields.contains(&SearchField::Calendar(field))
+                {
+                    continue;
+                }
+
+                match entry.values.first() {
+                    Some(ArchivedICalendarValue::PartialDateTime(date)) => {
+                        let tz = entry
+                            .tz_id()
+                            .and_then(|tz_id| Tz::from_str(tz_id).ok())
+                            .unwrap_or(Tz::UTC);
+                        if let Some(date) = date
+                            .to_date_time()
+                            .and_then(|date| date.to_date_time_with_tz(tz))
+                        {
+                            document.index_integer(field, date.timestamp());
+                        }
+                    }
+                    Some(ArchivedICalendarValue::Integer(value)) => {
+                        document.index_integer(field, value.to_native());
+                    }
+                    _ => {}
+                }
+            }
+        }
+
         let mut detector = LanguageDetector::new();
         for component in self
             .data
@@ -9,7 +9,7 @@ use crate::{
     cache::GroupwareCache,
     calendar::{
         CalendarEvent, CalendarEventData, CalendarEventNotification, ChangedBy,
-        EVENT_NOTIFICATION_IS_CHANGE,
+        EVENT_NOTIFICATION_IS_CHANGE, booking::ItipBooking,
     },
     scheduling::{
         ItipError, ItipMessage,
@@ -33,6 +33,7 @@ use common::{
     config::groupware::CalendarTemplateVariable,
     i18n,
 };
+use registry::schema::enums::PrincipalKind;
 use store::{
     ValueKey, rand,
     write::{AlignedBytes, Archive, BatchBuilder, now},
@@ -59,7 +60,7 @@ pub trait ItipIngest: Sync + Send {
         sender: &str,
         recipient: &str,
         itip_message: &str,
-    ) -> impl Future<Output = Result<Option<ItipMessage<ICalendar>>, ItipIngestError>> + Send;
+    ) -> impl Future<Output = Result<Vec<ItipMessage<ICalendar>>, ItipIngestError>> + Send;
 
     fn http_rsvp_url(
         &self,
@@ -82,7 +83,7 @@ impl ItipIngest for Server {
         sender: &str,
         recipient: &str,
         itip_message: &str,
-    ) -> Result<Option<ItipMessage<ICalendar>>, ItipIngestError> {
+    ) -> Result<Vec<ItipMessage<ICalendar>>, ItipIngestError> {
         // Parse and validate the iTIP message
         let mut itip = ICalendar::parse(itip_message)
             .map_err(|_| ItipIngestError::Message(ItipError::ICalendarParseError))
@@ -192,6 +193,30 @@ impl ItipIngest for Server {
                         // Merge changes
                         itip_merge_changes(&mut event.data.event, changes);
 
+                        // Build event
+                        let now = now() as i64;
+                        let prev_email_alarm = event_.inner.data.next_alarm(now, Tz::Floating);
+                        let mut next_email_alarm = None;
+                        event.data = CalendarEventData::new(
+                            event.data.event,
+                            Tz::Floating,
+                            self.core.groupware.max_ical_instances,
+                            &mut next_email_alarm,
+                        );
+
+                        // Apply the booking policy of resources and locations
+                        let itip_messages = if is_organizer_update {
+                            self.itip_booking_apply(
+                                account_info,
+                                sender,
+                                &mut event.data,
+                                Some(document_id),
+                            )
+                            .await?
+                        } else {
+                            vec![]
+                        };
+
                         // Calculate the new ical size
                         event.size = event.data.event.to_string().len() as u32;
                         if event.size > self.core.groupware.max_ical_size as u32 {
@@ -213,16 +238,6 @@ impl ItipIngest for Server {
                             return Err(ItipIngestError::Message(ItipError::QuotaExceeded));
                         }
 
-                        // Build event
-                        let now = now() as i64;
-                        let prev_email_alarm = event_.inner.data.next_alarm(now, Tz::Floating);
-                        let mut next_email_alarm = None;
-                        event.data = CalendarEventData::new(
-                            event.data.event,
-                            Tz::Floating,
-                            self.core.groupware.max_ical_instances,
-                            &mut next_email_alarm,
-                        );
                         if is_organizer_update {
                             if let Some(schedule_tag) = &mut event.schedule_tag {
                                 *schedule_tag += 1;
@@ -279,10 +294,10 @@ impl ItipIngest for Server {
                             .caused_by(trc::location!())?;
                         self.commit_batch(batch).await.caused_by(trc::location!())?;
 
-                        Ok(None)
+                        Ok(itip_messages)
                     }
-                    MergeResult::Message(itip_message) => Ok(Some(itip_message)),
-                    MergeResult::None => Ok(None),
+                    MergeResult::Message(itip_message) => Ok(vec![itip_message]),
+                    MergeResult::None => Ok(vec![]),
                 }
             } else {
                 Err(ItipIngestError::Message(ItipError::EventNotFound))
@@ -290,6 +305,7 @@ impl ItipIngest for Server {
         } else {
             // Verify that auto-adding invitations is allowed
             if !self.core.groupware.itip_auto_add
+                && account_info.principal_kind() == PrincipalKind::Individual
                 && !matches!(changed_by, ChangedBy::PrincipalId(_))
                 && !self
                     .document_exists(
@@ -334,7 +350,7 @@ impl ItipIngest for Server {
             // Build event
             let mut next_email_alarm = None;
             let now = now() as i64;
-            let event = CalendarEvent {
+            let mut event = CalendarEvent {
                 names: vec![DavName {
                     name: format!("{}_{}.ics", now, rand::random::<u64>()),
                     parent_id,
@@ -350,6 +366,11 @@ impl ItipIngest for Server {
                 ..Default::default()
             };
 
+            // Apply the booking policy of resources and locations
+            let itip_messages = self
+                .itip_booking_apply(account_info, sender, &mut event.data, None)
+                .await?;
+
             // Obtain document ids
             let document_id = self
                 .store()
@@ -390,7 +411,7 @@ impl ItipIngest for Server {
                 .caused_by(trc::location!())?;
             self.commit_batch(batch).await.caused_by(trc::location!())?;
 
-            Ok(None)
+            Ok(itip_messages)
         }
     }
 
@@ -5,18 +5,22 @@
  */
 
 pub mod alarm;
+pub mod booking;
 pub mod dates;
 pub mod expand;
+pub mod freebusy;
 pub mod index;
 pub mod itip;
+pub mod publish;
 pub mod storage;
 
 use calcard::icalendar::{
-    ICalendar, ICalendarComponent, ICalendarComponentType, ICalendarDuration, ICalendarEntry,
+    ArchivedICalendarComponentType, ICalendar, ICalendarComponent, ICalendarComponentType,
+    ICalendarDuration, ICalendarEntry,
 };
 use common::{DavName, auth::AccessToken};
 use types::{acl::AclGrant, dead_property::DeadProperty};
-use utils::map::bitmap::BitmapItem;
+use utils::map::bitmap::{Bitmap, BitmapItem};
 
 #[derive(
     rkyv::Archive, rkyv::Deserialize, rkyv::Serialize, Debug, Default, Clone, PartialEq, Eq,
@@ -242,9 +246,22 @@ impl Calendar {
 
         &mut self.preferences[idx]
     }
+
+    pub fn supports_tasks(&self) -> bool {
+        self.supported_components == 0
+            || Bitmap::<SupportedComponent>::from(self.supported_components)
+                .contains(SupportedComponent::VTodo)
+    }
 }
 
 impl ArchivedCalendar {
+    pub fn supports_tasks(&self) -> bool {
+        let supported_components = self.supported_components.to_native();
+        supported_components == 0
+            || Bitmap::<SupportedComponent>::from(supported_components)
+                .contains(SupportedComponent::VTodo)
+    }
+
     pub fn default_alerts(
         &self,
         access_token: &AccessToken,
@@ -338,6 +355,24 @@ impl ArchivedCalendarEvent {
     }
 }
 
+impl CalendarEventData {
+    pub fn is_task(&self) -> bool {
+        self.event
+            .components
+            .iter()
+            .any(|c| c.component_type == ICalendarComponentType::VTodo)
+    }
+}
+
+impl ArchivedCalendarEventData {
+    pub fn is_task(&self) -> bool {
+        self.event
+            .components
+            .iter()
+            .any(|c| matches!(c.component_type, ArchivedICalendarComponentType::VTodo))
+    }
+}
+
 impl Default for ChangedBy {
     fn default() -> Self {
         ChangedBy::CalendarAddress("".into())
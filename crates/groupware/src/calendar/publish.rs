@@ -0,0 +1,434 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::{
+    cache::GroupwareCache,
+    calendar::{
+        CalendarEvent,
+        freebusy::{CalendarFreeBusy, merge_intervals},
+    },
+};
+use ahash::AHashSet;
+use calcard::{
+    common::{PartialDateTime, timezone::Tz},
+    icalendar::{
+        ICalendar, ICalendarClassification, ICalendarComponent, ICalendarComponentType,
+        ICalendarEntry, ICalendarFreeBusyType, ICalendarProperty, ICalendarValue,
+    },
+};
+use common::{PROD_ID, Server};
+use std::str::FromStr;
+use store::{
+    Deserialize, SerializeInfallible, ValueKey,
+    rand::{self, Rng, distr::Alphanumeric},
+    write::{AlignedBytes, Archive, BatchBuilder, now},
+};
+use trc::AddContext;
+use types::{
+    TimeRange,
+    collection::{Collection, SyncCollection},
+    field::CalendarField,
+    id::Id,
+};
+
+// Free/busy feeds cover a rolling window starting at the beginning of the current day
+const FREEBUSY_WINDOW_PAST: i64 = 30 * 86400;
+const FREEBUSY_WINDOW_FUTURE: i64 = 365 * 86400;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CalendarPublish {
+    pub secret: String,
+    pub free_busy_only: bool,
+}
+
+pub enum CalendarFeed {
+    NotModified { etag: String },
+    Feed { etag: String, ical: String },
+}
+
+pub trait CalendarPublishing: Sync + Send {
+    fn calendar_publish_settings(
+        &self,
+        account_id: u32,
+        document_id: u32,
+    ) -> impl Future<Output = trc::Result<Option<CalendarPublish>>> + Send;
+
+    fn calendar_feed(
+        &self,
+        token: &str,
+        if_none_match: Option<&str>,
+    ) -> impl Future<Output = trc::Result<Option<CalendarFeed>>> + Send;
+}
+
+impl CalendarPublishing for Server {
+    async fn calendar_publish_settings(
+        &self,
+        account_id: u32,
+        document_id: u32,
+    ) -> trc::Result<Option<CalendarPublish>> {
+        self.store()
+            .get_value::<CalendarPublish>(ValueKey::property(
+                account_id,
+                Collection::Calendar,
+                document_id,
+                CalendarField::Publish,
+            ))
+            .await
+            .caused_by(trc::location!())
+    }
+
+    async fn calendar_feed(
+        &self,
+        token: &str,
+        if_none_match: Option<&str>,
+    ) -> trc::Result<Option<CalendarFeed>> {
+        // Decode token
+        let Some((id, secret)) = token
+            .strip_suffix(".ics")
+            .unwrap_or(token)
+            .split_once('-')
+            .and_then(|(id, secret)| Id::from_str(id).ok().map(|id| (id, secret)))
+        else {
+            return Ok(None);
+        };
+        let account_id = id.prefix_id();
+        let calendar_id = id.document_id();
+        let Some(publish) = self
+            .calendar_publish_settings(account_id, calendar_id)
+            .await?
+            .filter(|publish| publish.matches_secret(secret))
+        else {
+            return Ok(None);
+        };
+        let resources = self
+            .fetch_dav_resources(account_id, account_id, SyncCollection::Calendar)
+            .await
+            .caused_by(trc::location!())?;
+        let Some(calendar) = resources.container_resource_by_id(calendar_id) else {
+            return Ok(None);
+        };
+
+        // Free/busy feeds change daily as the window moves forward
+        let day_start = (now() as i64 / 86400) * 86400;
+        let etag = if publish.free_busy_only {
+            format!("\"{}-{}\"", resources.highest_change_id, day_start / 86400)
+        } else {
+            format!("\"{}\"", resources.highest_change_id)
+        };
+        if if_none_match.is_some_and(|value| {
+            value.split(',').any(|value| {
+                let value = value.trim();
+                value == "*" || value.strip_prefix("W/").unwrap_or(value) == etag
+            })
+        }) {
+            return Ok(Some(CalendarFeed::NotModified { etag }));
+        }
+
+        let mut ical = ICalendar {
+            components: vec![ICalendarComponent {
+                component_type: ICalendarComponentType::VCalendar,
+                entries: vec![
+                    ICalendarEntry {
+                        name: ICalendarProperty::Version,
+                        params: vec![],
+                        values: vec![ICalendarValue::Text("2.0".to_string())],
+                    },
+                    ICalendarEntry {
+                        name: ICalendarProperty::Prodid,
+                        params: vec![],
+                        values: vec![ICalendarValue::Text(PROD_ID.to_string())],
+                    },
+                ],
+                component_ids: vec![],
+            }],
+        };
+
+        if publish.free_busy_only {
+            // Expand busy periods within the window, honoring the instance limits
+            let range = TimeRange {
+                start: day_start - FREEBUSY_WINDOW_PAST,
+                end: day_start + FREEBUSY_WINDOW_FUTURE,
+            };
+            let default_tz = calendar
+                .calendar_preferences(account_id)
+                .map(|p| p.tz)
+                .unwrap_or(Tz::UTC);
+            let document_ids = resources
+                .children(calendar_id)
+                .filter(|resource| {
+                    resource
+                        .resource
+                        .event_time_range()
+                        .is_some_and(|(start, end)| range.is_in_range(false, start, end))
+                })
+                .map(|resource| resource.document_id())
+                .collect::<Vec<_>>();
+            let mut periods = Vec::new();
+            for (fbtype, events_in_range) in self
+                .calendar_busy_periods(account_id, document_ids, range, default_tz, &[])
+                .await
+                .caused_by(trc::location!())?
+            {
+                if fbtype != ICalendarFreeBusyType::Free {
+                    let is_tentative = fbtype == ICalendarFreeBusyType::BusyTentative;
+                    periods.extend(
+                        merge_intervals(events_in_range)
+                            .into_iter()
+                            .map(|(start, end)| (start, end, is_tentative)),
+                    );
+                }
+            }
+            periods.sort_unstable();
+            periods.truncate(self.core.groupware.max_ical_instances);
+
+            let dtstamp = now() as i64;
+            for (start, end, is_tentative) in periods {
+                let component_id = ical.components.len() as u32;
+                ical.components[0].component_ids.push(component_id);
+                ical.components.push(ICalendarComponent {
+                    component_type: ICalendarComponentType::VEvent,
+                    entries: vec![
+                        ICalendarEntry {
+                            name: ICalendarProperty::Uid,
+                            params: vec![],
+                            values: vec![ICalendarValue::Text(format!("{id}-{start}-{end}"))],
+                        },
+                        build_date_entry(ICalendarProperty::Dtstamp, dtstamp),
+                        build_date_entry(ICalendarProperty::Dtstart, start),
+                        build_date_entry(ICalendarProperty::Dtend, end),
+                        ICalendarEntry {
+                            name: ICalendarProperty::Summary,
+                            params: vec![],
+                            values: vec![ICalendarValue::Text(
+                                if is_tentative { "Tentative" } else { "Busy" }.to_string(),
+                            )],
+                        },
+                    ],
+                    component_ids: vec![],
+                });
+            }
+        } else {
+            // Merge all events, leaving out alarms, participants and duplicate time zones
+            let mut tz_ids = AHashSet::new();
+            for document_id in resources.children_ids(calendar_id) {
+                let Some(archive) = self
+                    .store()
+                    .get_value::<Archive<AlignedBytes>>(ValueKey::archive(
+                        account_id,
+                        Collection::CalendarEvent,
+                        document_id,
+                    ))
+                    .await
+                    .caused_by(trc::location!())?
+                else {
+                    continue;
+                };
+                let event = archive
+                    .deserialize::<CalendarEvent>()
+                    .caused_by(trc::location!())?
+                    .data
+                    .event;
+                let Some(root) = event.components.first() else {
+                    continue;
+                };
+                for component_id in root.component_ids.clone() {
+                    if let Some(component_id) =
+                        copy_component(&mut ical, &event, component_id, &mut tz_ids)
+                    {
+                        ical.components[0].component_ids.push(component_id);
+                    }
+                }
+            }
+        }
+
+        Ok(Some(CalendarFeed::Feed {
+            etag,
+            ical: ical.to_string(),
+        }))
+    }
+}
+
+fn copy_component(
+    ical: &mut ICalendar,
+    source: &ICalendar,
+    component_id: u32,
+    tz_ids: &mut AHashSet<String>,
+) -> Option<u32> {
+    let component = source.components.get(component_id as usize)?;
+    match component.component_type {
+        ICalendarComponentType::VAlarm => return None,
+        ICalendarComponentType::VTimezone => {
+            let tz_id = component
+                .property(&ICalendarProperty::Tzid)
+                .and_then(|entry| entry.values.first())
+                .and_then(|value| value.as_text())
+                .unwrap_or_default();
+            if !tz_ids.insert(tz_id.to_string()) {
+                return None;
+            }
+        }
+        _ => {}
+    }
+
+    // Private and confidential items are reduced to busy blocks, and participant
+    // addresses are never exposed in the anonymous feed
+    let is_private = component.entries.iter().any(|entry| {
+        entry.name == ICalendarProperty::Class
+            && matches!(
+                entry.values.first(),
+                Some(ICalendarValue::Classification(
+                    ICalendarClassification::Private | ICalendarClassification::Confidential
+                ))
+            )
+    });
+    let mut entries = component
+        .entries
+        .iter()
+        .filter(|entry| {
+            if is_private {
+                matches!(
+                    entry.name,
+                    ICalendarProperty::Uid
+                        | ICalendarProperty::Dtstamp
+                        | ICalendarProperty::Dtstart
+                        | ICalendarProperty::Dtend
+                        | ICalendarProperty::Duration
+                        | ICalendarProperty::Due
+                        | ICalendarProperty::Rrule
+                        | ICalendarProperty::Rdate
+                        | ICalendarProperty::Exdate
+                        | ICalendarProperty::Exrule
+                        | ICalendarProperty::RecurrenceId
+                        | ICalendarProperty::Sequence
+                        | ICalendarProperty::Status
+                        | ICalendarProperty::Transp
+                        | ICalendarProperty::Class
+                )
+            } else {
+                !matches!(
+                    entry.name,
+                    ICalendarProperty::Attendee | ICalendarProperty::Organizer
+                )
+            }
+        })
+        .cloned()
+        .collect::<Vec<_>>();
+    if is_private {
+        entries.push(ICalendarEntry {
+            name: ICalendarProperty::Summary,
+            params: vec![],
+            values: vec![ICalendarValue::Text("Busy".to_string())],
+        });
+    }
+
+    let new_component_id = ical.components.len() as u32;
+    ical.components.push(ICalendarComponent {
+        component_type: component.component_type.clone(),
+        entries,
+        component_ids: vec![],
+    });
+    if is_private {
+        return Some(new_component_id);
+    }
+    for child_id in &component.component_ids {
+        if let Some(child_id) = copy_component(ical, source, *child_id, tz_ids) {
+            ical.components[new_component_id as usize]
+                .component_ids
+                .push(child_id);
+        }
+    }
+
+    Some(new_component_id)
+}
+
+fn build_date_entry(name: ICalendarProperty, timestamp: i64) -> ICalendarEntry {
+    ICalendarEntry {
+        name,
+        params: vec![],
+        values: vec![ICalendarValue::PartialDateTime(Box::new(
+            PartialDateTime::from_utc_timestamp(timestamp),
+        ))],
+    }
+}
+
+impl CalendarPublish {
+    pub fn new(free_busy_only: bool) -> Self {
+        CalendarPublish {
+            secret: rand::rng()
+                .sample_iter(Alphanumeric)
+                .take(32)
+                .map(char::from)
+                .collect::<String>(),
+            free_busy_only,
+        }
+    }
+
+    pub fn token(&self, account_id: u32, document_id: u32) -> String {
+        format!(
+            "{}-{}",
+            Id::from_parts(account_id, document_id),
+            self.secret
+        )
+    }
+
+    pub fn url(&self, server: &Server, account_id: u32, document_id: u32) -> String {
+        format!(
+            "{}/calendar/feed/{}.ics",
+            server.core.network.http.url_https,
+            self.token(account_id, document_id)
+        )
+    }
+
+    pub fn write(&self, batch: &mut BatchBuilder, account_id: u32, document_id: u32) {
+        batch
+            .with_account_id(account_id)
+            .with_collection(Collection::Calendar)
+            .with_document(document_id)
+            .set(CalendarField::Publish, self.serialize());
+    }
+
+    pub fn clear(batch: &mut BatchBuilder, account_id: u32, document_id: u32) {
+        batch
+            .with_account_id(account_id)
+            .with_collection(Collection::Calendar)
+            .with_document(document_id)
+            .clear(CalendarField::Publish);
+    }
+
+    fn matches_secret(&self, secret: &str) -> bool {
+        // Constant time comparison
+        self.secret.len() == secret.len()
+            && self
+                .secret
+                .bytes()
+                .zip(secret.bytes())
+                .fold(0, |diff, (a, b)| diff | (a ^ b))
+                == 0
+    }
+}
+
+impl SerializeInfallible for CalendarPublish {
+    fn serialize(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(self.secret.len() + 1);
+        bytes.push(self.free_busy_only as u8);
+        bytes.extend_from_slice(self.secret.as_bytes());
+        bytes
+    }
+}
+
+impl Deserialize for CalendarPublish {
+    fn deserialize(bytes: &[u8]) -> trc::Result<Self> {
+        bytes
+            .split_first()
+            .and_then(|(free_busy_only, secret)| {
+                Some(CalendarPublish {
+                    secret: std::str::from_utf8(secret).ok()?.to_string(),
+                    free_busy_only: *free_busy_only != 0,
+                })
+            })
+            .ok_or_else(|| trc::StoreEvent::DataCorruption.caused_by(trc::location!()))
+    }
+}
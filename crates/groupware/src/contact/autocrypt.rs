@@ -0,0 +1,196 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use super::ContactCard;
+use crate::cache::GroupwareCache;
+use calcard::vcard::{VCard, VCardEntry, VCardProperty};
+use common::{DavName, Server, auth::AccountInfo, storage::encryption::AutocryptKey};
+use store::{
+    ValueKey,
+    write::{AlignedBytes, Archive, BatchBuilder, ValueClass},
+};
+use trc::AddContext;
+use types::{
+    collection::{Collection, SyncCollection},
+    field::{ContactField, PrincipalField},
+};
+
+pub trait AutocryptIngest: Sync + Send {
+    fn autocrypt_ingest(
+        &self,
+        account_info: &AccountInfo,
+        key: &AutocryptKey,
+    ) -> impl Future<Output = trc::Result<bool>> + Send;
+}
+
+impl AutocryptIngest for Server {
+    // Stores the sender's key in the contacts matching its address, or in a new
+    // contact in the default address book. Returns whether any card was changed.
+    async fn autocrypt_ingest(
+        &self,
+        account_info: &AccountInfo,
+        key: &AutocryptKey,
+    ) -> trc::Result<bool> {
+        let account_id = account_info.account_id();
+        let key_uri = key.to_data_uri();
+        let Ok(key_card) = VCard::parse(&format!(
+            "BEGIN:VCARD\r\nVERSION:4.0\r\nKEY:{key_uri}\r\nEND:VCARD\r\n"
+        )) else {
+            return Ok(false);
+        };
+        let Some(key_entry) = key_card
+            .entries
+            .into_iter()
+            .find(|entry| entry.name == VCardProperty::Key)
+        else {
+            return Ok(false);
+        };
+
+        let card_ids = self
+            .document_ids_matching(
+                account_id,
+                Collection::ContactCard,
+                ContactField::Email,
+                key.addr.as_bytes(),
+            )
+            .await
+            .caused_by(trc::location!())?;
+        let mut batch = BatchBuilder::new();
+
+        if !card_ids.is_empty() {
+            for document_id in card_ids {
+                let Some(card_) = self
+                    .store()
+                    .get_value::<Archive<AlignedBytes>>(ValueKey::archive(
+                        account_id,
+                        Collection::ContactCard,
+                        document_id,
+                    ))
+                    .await
+                    .caused_by(trc::location!())?
+                else {
+                    continue;
+                };
+                let card = card_
+                    .to_unarchived::<ContactCard>()
+                    .caused_by(trc::location!())?;
+                let mut new_card = card
+                    .deserialize::<ContactCard>()
+                    .caused_by(trc::location!())?;
+
+                // Replace any previously collected OpenPGP key
+                if new_card.card.entries.contains(&key_entry) {
+                    continue;
+                }
+                new_card.card.entries.retain(|entry| !is_pgp_key(entry));
+                new_card.card.entries.push(key_entry.clone());
+                new_card.size = new_card.card.size() as u32;
+                new_card
+                    .update(
+                        account_info.account_tenant_ids(),
+                        card,
+                        account_id,
+                        document_id,
+                        &mut batch,
+                    )
+                    .caused_by(trc::location!())?;
+            }
+        } else {
+            // Add the sender to the default address book
+            let default_address_book_id = self
+                .store()
+                .get_value::<u32>(ValueKey {
+                    account_id,
+                    collection: Collection::Principal.into(),
+                    document_id: 0,
+                    class: ValueClass::Property(PrincipalField::DefaultAddressBookId.into()),
+                })
+                .await
+                .caused_by(trc::location!())?;
+            let Some(parent_id) = (match default_address_book_id {
+                Some(parent_id) => Some(parent_id),
+                None => self
+                    .fetch_dav_resources(account_id, account_id, SyncCollection::AddressBook)
+                    .await
+                    .caused_by(trc::location!())?
+                    .document_ids(true)
+                    .next(),
+            }) else {
+                return Ok(false);
+            };
+
+            let Ok(mut vcard) = VCard::parse(&format!(
+                "BEGIN:VCARD\r\nVERSION:4.0\r\nUID:urn:uuid:{}\r\nFN:{}\r\nEMAIL:{}\r\nEND:VCARD\r\n",
+                uuid_from_addr(&key.addr),
+                key.addr,
+                key.addr
+            )) else {
+                return Ok(false);
+            };
+            vcard.entries.push(key_entry);
+            let size = vcard.size();
+            if self
+                .has_available_quota(account_info.account.as_ref(), size as u64)
+                .await
+                .is_err()
+            {
+                return Ok(false);
+            }
+
+            let document_id = self
+                .store()
+                .assign_document_ids(account_id, Collection::ContactCard, 1)
+                .await
+                .caused_by(trc::location!())?;
+            ContactCard {
+                names: vec![DavName::new(
+                    format!("{}.vcf", uuid_from_addr(&key.addr)),
+                    parent_id,
+                )],
+                card: vcard,
+                size: size as u32,
+                ..Default::default()
+            }
+            .insert(
+                account_info.account_tenant_ids(),
+                account_id,
+                document_id,
+                &mut batch,
+            )
+            .caused_by(trc::location!())?;
+        }
+
+        if !batch.is_empty() {
+            self.commit_batch(batch).await.caused_by(trc::location!())?;
+            self.notify_task_queue();
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+}
+
+fn is_pgp_key(entry: &VCardEntry) -> bool {
+    entry.name == VCardProperty::Key && {
+        let mut card = VCard::default();
+        card.entries.push(entry.clone());
+        card.to_string().contains("data:application/pgp-keys")
+    }
+}
+
+fn uuid_from_addr(addr: &str) -> String {
+    let hash = store::xxhash_rust::xxh3::xxh3_128(addr.as_bytes()).to_be_bytes();
+    format!(
+        "{:08x}-{:04x}-{:04x}-{:04x}-{:012x}",
+        u32::from_be_bytes(hash[0..4].try_into().unwrap()),
+        u16::from_be_bytes(hash[4..6].try_into().unwrap()),
+        u16::from_be_bytes(hash[6..8].try_into().unwrap()),
+        u16::from_be_bytes(hash[8..10].try_into().unwrap()),
+        u64::from_be_bytes([
+            0, 0, hash[10], hash[11], hash[12], hash[13], hash[14], hash[15]
+        ])
+    )
+}
@@ -10,10 +10,17 @@ use crate::scheduling::{
 use ahash::AHashSet;
 use calcard::icalendar::{
     ICalendar, ICalendarComponent, ICalendarComponentType, ICalendarEntry, ICalendarMethod,
-    ICalendarParameter, ICalendarParameterName, ICalendarProperty, ICalendarStatus, ICalendarValue,
-    Uri,
+    ICalendarParameter, ICalendarParameterName, ICalendarParameterValue,
+    ICalendarParticipationStatus, ICalendarProperty, ICalendarStatus, ICalendarValue, Uri,
 };
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BookingDecision {
+    Accept,
+    Decline,
+    Delegate(Vec<String>),
+}
+
 #[derive(Debug)]
 pub enum MergeAction {
     AddEntries {
@@ -599,6 +606,95 @@ pub fn itip_merge_changes(ical: &mut ICalendar, changes: Vec<MergeAction>) {
     }
 }
 
+pub fn itip_apply_booking(
+    ical: &mut ICalendar,
+    local_addresses: &[String],
+    decision: &BookingDecision,
+) {
+    for comp in ical
+        .components
+        .iter_mut()
+        .filter(|comp| comp.component_type.is_scheduling_object())
+    {
+        let mut delegator = None;
+
+        for entry in comp.entries.iter_mut() {
+            if entry.name != ICalendarProperty::Attendee
+                || !entry.calendar_address().is_some_and(|address| {
+                    local_addresses
+                        .iter()
+                        .any(|local| local.eq_ignore_ascii_case(address))
+                })
+            {
+                continue;
+            }
+
+            entry.params.retain(|param| {
+                !matches!(
+                    param.name,
+                    ICalendarParameterName::Partstat | ICalendarParameterName::DelegatedTo
+                )
+            });
+
+            match decision {
+                BookingDecision::Accept => {
+                    entry.params.push(ICalendarParameter::partstat(
+                        ICalendarParticipationStatus::Accepted,
+                    ));
+                }
+                BookingDecision::Decline => {
+                    entry.params.push(ICalendarParameter::partstat(
+                        ICalendarParticipationStatus::Declined,
+                    ));
+                }
+                BookingDecision::Delegate(delegates) => {
+                    entry.params.push(ICalendarParameter::partstat(
+                        ICalendarParticipationStatus::Delegated,
+                    ));
+                    entry.params.extend(delegates.iter().map(|delegate| {
+                        ICalendarParameter::delegated_to(Uri::Location(format!(
+                            "mailto:{delegate}"
+                        )))
+                    }));
+                    if delegator.is_none() {
+                        delegator = entry.calendar_address().map(|address| address.to_string());
+                    }
+                }
+            }
+        }
+
+        if let (BookingDecision::Delegate(delegates), Some(delegator)) = (decision, delegator) {
+            for delegate in delegates {
+                if comp.entries.iter().any(|entry| {
+                    entry.name == ICalendarProperty::Attendee
+                        && entry
+                            .calendar_address()
+                            .is_some_and(|address| address.eq_ignore_ascii_case(delegate))
+                }) {
+                    continue;
+                }
+
+                comp.entries.push(ICalendarEntry {
+                    name: ICalendarProperty::Attendee,
+                    params: vec![
+                        ICalendarParameter {
+                            name: ICalendarParameterName::DelegatedFrom,
+                            value: ICalendarParameterValue::Uri(Uri::Location(format!(
+                                "mailto:{delegator}"
+                            ))),
+                        },
+                        ICalendarParameter::partstat(ICalendarParticipationStatus::NeedsAction),
+                        ICalendarParameter::rsvp(true),
+                    ],
+                    values: vec![ICalendarValue::Uri(Uri::Location(format!(
+                        "mailto:{delegate}"
+                    )))],
+                });
+            }
+        }
+    }
+}
+
 pub fn itip_method(ical: &ICalendar) -> Result<&ICalendarMethod, ItipError> {
     ical.components
         .first()
@@ -0,0 +1,210 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use std::io::{Read, Write};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContentEncoding {
+    Zstd,
+    Brotli,
+    Gzip,
+}
+
+const BROTLI_QUALITY: u32 = 5;
+const BROTLI_WINDOW: u32 = 22;
+const ZSTD_LEVEL: i32 = 3;
+
+impl ContentEncoding {
+    pub fn parse(value: &str) -> Option<Self> {
+        let value = value.trim();
+        if value.eq_ignore_ascii_case("zstd") {
+            Some(ContentEncoding::Zstd)
+        } else if value.eq_ignore_ascii_case("br") {
+            Some(ContentEncoding::Brotli)
+        } else if value.eq_ignore_ascii_case("gzip") || value.eq_ignore_ascii_case("x-gzip") {
+            Some(ContentEncoding::Gzip)
+        } else {
+            None
+        }
+    }
+
+    // Picks the encoding with the highest q-value, ties are broken
+    // by server preference (zstd, br, gzip).
+    pub fn negotiate(accept_encoding: &str) -> Option<Self> {
+        let mut result: Option<(Self, f32)> = None;
+
+        for item in accept_encoding.split(',') {
+            let mut parts = item.split(';');
+            let Some(encoding) = parts.next().and_then(ContentEncoding::parse) else {
+                continue;
+            };
+            let q = parts
+                .find_map(|param| {
+                    param
+                        .trim()
+                        .strip_prefix("q=")
+                        .and_then(|q| q.trim().parse::<f32>().ok())
+                })
+                .unwrap_or(1.0);
+
+            if q > 0.0
+                && result.is_none_or(|(current, current_q)| {
+                    q > current_q
+                        || (q == current_q && encoding.preference() < current.preference())
+                })
+            {
+                result = Some((encoding, q));
+            }
+        }
+
+        result.map(|(encoding, _)| encoding)
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ContentEncoding::Zstd => "zstd",
+            ContentEncoding::Brotli => "br",
+            ContentEncoding::Gzip => "gzip",
+        }
+    }
+
+    pub fn encode(&self, bytes: &[u8]) -> std::io::Result<Vec<u8>> {
+        match self {
+            ContentEncoding::Zstd => zstd::bulk::compress(bytes, ZSTD_LEVEL),
+            ContentEncoding::Brotli => {
+                let mut encoder = brotli::CompressorWriter::new(
+                    Vec::with_capacity(bytes.len() / 2),
+                    4096,
+                    BROTLI_QUALITY,
+                    BROTLI_WINDOW,
+                );
+                encoder.write_all(bytes)?;
+                encoder.flush()?;
+                Ok(encoder.into_inner())
+            }
+            ContentEncoding::Gzip => {
+                let mut encoder = flate2::write::GzEncoder::new(
+                    Vec::with_capacity(bytes.len() / 2),
+                    flate2::Compression::default(),
+                );
+                encoder.write_all(bytes)?;
+                encoder.finish()
+            }
+        }
+    }
+
+    // Returns None if the decoded contents exceed max_size (0 means unlimited)
+    pub fn decode(&self, bytes: &[u8], max_size: usize) -> std::io::Result<Option<Vec<u8>>> {
+        let limit = if max_size > 0 {
+            max_size as u64 + 1
+        } else {
+            u64::MAX
+        };
+        let mut decoded = Vec::with_capacity(bytes.len() * 2);
+
+        match self {
+            ContentEncoding::Zstd => {
+                zstd::stream::read::Decoder::new(bytes)?
+                    .take(limit)
+                    .read_to_end(&mut decoded)?;
+            }
+            ContentEncoding::Brotli => {
+                brotli::Decompressor::new(bytes, 4096)
+                    .take(limit)
+                    .read_to_end(&mut decoded)?;
+            }
+            ContentEncoding::Gzip => {
+                flate2::read::GzDecoder::new(bytes)
+                    .take(limit)
+                    .read_to_end(&mut decoded)?;
+            }
+        }
+
+        Ok(if max_size == 0 || decoded.len() <= max_size {
+            Some(decoded)
+        } else {
+            None
+        })
+    }
+
+    fn preference(&self) -> u8 {
+        match self {
+            ContentEncoding::Zstd => 0,
+            ContentEncoding::Brotli => 1,
+            ContentEncoding::Gzip => 2,
+        }
+    }
+}
+
+pub fn is_compressible(content_type: &str) -> bool {
+    let content_type = content_type
+        .split_once(';')
+        .map_or(content_type, |(content_type, _)| content_type)
+        .trim();
+
+    // Media, archives and opaque blobs are either already compressed or unknown
+    content_type.starts_with("text/")
+        || content_type.ends_with("+json")
+        || content_type.ends_with("+xml")
+        || matches!(
+            content_type,
+            "application/json"
+                | "application/xml"
+                | "application/javascript"
+                | "application/ecmascript"
+                | "application/wasm"
+                | "message/rfc822"
+                | "message/global"
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ContentEncoding;
+
+    #[test]
+    fn negotiate_encoding() {
+        for (header, expected) in [
+            ("gzip, deflate, br, zstd", Some(ContentEncoding::Zstd)),
+            ("gzip, deflate, br", Some(ContentEncoding::Brotli)),
+            ("gzip;q=1.0, br;q=0.5", Some(ContentEncoding::Gzip)),
+            ("br;q=0, gzip;q=0.1", Some(ContentEncoding::Gzip)),
+            ("deflate, identity", None),
+            ("*", None),
+            ("", None),
+        ] {
+            assert_eq!(ContentEncoding::negotiate(header), expected, "{header}");
+        }
+    }
+
+    #[test]
+    fn encode_decode() {
+        let text = "The quick brown fox jumps over the lazy dog. ".repeat(100);
+
+        for encoding in [
+            ContentEncoding::Zstd,
+            ContentEncoding::Brotli,
+            ContentEncoding::Gzip,
+        ] {
+            let encoded = encoding.encode(text.as_bytes()).unwrap();
+            assert!(encoded.len() < text.len(), "{encoding:?}");
+            assert_eq!(
+                encoding.decode(&encoded, 0).unwrap().unwrap(),
+                text.as_bytes(),
+                "{encoding:?}"
+            );
+            assert_eq!(
+                encoding.decode(&encoded, text.len()).unwrap().unwrap(),
+                text.as_bytes(),
+                "{encoding:?}"
+            );
+            assert!(
+                encoding.decode(&encoded, 100).unwrap().is_none(),
+                "{encoding:?}"
+            );
+        }
+    }
+}
@@ -9,7 +9,7 @@ use std::borrow::Cow;
 use compact_str::ToCompactString;
 use http_body_util::BodyExt;
 
-use crate::HttpRequest;
+use crate::{HttpRequest, compression::ContentEncoding};
 
 #[inline]
 pub fn decode_path_element(item: &str) -> Cow<'_, str> {
@@ -71,3 +71,36 @@ pub async fn fetch_body(
 
     bytes.into()
 }
+
+pub async fn fetch_encoded_body(
+    req: &mut HttpRequest,
+    max_size: usize,
+    session_id: u64,
+) -> trc::Result<Option<Vec<u8>>> {
+    let encoding = match req
+        .headers()
+        .get(hyper::header::CONTENT_ENCODING)
+        .and_then(|value| value.to_str().ok())
+        .map(str::trim)
+    {
+        Some(value) if !value.is_empty() && !value.eq_ignore_ascii_case("identity") => {
+            Some(ContentEncoding::parse(value).ok_or_else(|| {
+                trc::ResourceEvent::BadParameters
+                    .into_err()
+                    .details("Unsupported Content-Encoding")
+                    .ctx(trc::Key::Value, value.to_string())
+            })?)
+        }
+        _ => None,
+    };
+
+    match (fetch_body(req, max_size, session_id).await, encoding) {
+        (Some(bytes), Some(encoding)) => encoding.decode(&bytes, max_size).map_err(|err| {
+            trc::ResourceEvent::BadParameters
+                .into_err()
+                .details("Failed to decode request body")
+                .reason(err)
+        }),
+        (bytes, _) => Ok(bytes),
+    }
+}
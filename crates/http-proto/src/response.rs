@@ -16,6 +16,7 @@ use serde_json::json;
 use crate::{
     DownloadResponse, HtmlResponse, HttpResponse, HttpResponseBody, JsonProblemResponse,
     JsonResponse, ToHttpResponse,
+    compression::{ContentEncoding, is_compressible},
 };
 
 impl HttpResponse {
@@ -187,6 +188,71 @@ impl HttpResponse {
         }
     }
 
+    pub fn compress(mut self, encoding: Option<ContentEncoding>, min_size: usize) -> Self {
+        if self.size() < min_size.max(1)
+            || matches!(
+                self.status,
+                StatusCode::NO_CONTENT | StatusCode::PARTIAL_CONTENT | StatusCode::NOT_MODIFIED
+            )
+        {
+            return self;
+        }
+
+        let Some(headers) = self.builder.headers_mut() else {
+            return self;
+        };
+        if headers.contains_key(header::CONTENT_ENCODING)
+            || headers.contains_key(header::CONTENT_RANGE)
+            || !headers
+                .get(header::CONTENT_TYPE)
+                .and_then(|value| value.to_str().ok())
+                .is_some_and(is_compressible)
+        {
+            return self;
+        }
+
+        let bytes = match &self.body {
+            HttpResponseBody::Text(value) => value.as_bytes(),
+            HttpResponseBody::Binary(value) => value.as_slice(),
+            _ => return self,
+        };
+
+        // The representation depends on Accept-Encoding even when sent uncompressed
+        headers.append(header::VARY, HeaderValue::from_static("Accept-Encoding"));
+        let Some(encoding) = encoding else {
+            return self;
+        };
+        let compressed = match encoding.encode(bytes) {
+            Ok(compressed) if compressed.len() < bytes.len() => compressed,
+            Ok(_) => return self,
+            Err(err) => {
+                trc::event!(
+                    Http(trc::HttpEvent::Error),
+                    Details = "Failed to compress response",
+                    Reason = err.to_string(),
+                );
+                return self;
+            }
+        };
+
+        // Compressed bodies are not byte-for-byte identical, so strong ETags become weak
+        if let Some(etag) = headers
+            .get(header::ETAG)
+            .and_then(|value| value.to_str().ok())
+            .filter(|value| !value.starts_with("W/"))
+            .and_then(|value| HeaderValue::from_str(&format!("W/{value}")).ok())
+        {
+            headers.insert(header::ETAG, etag);
+        }
+        headers.insert(header::CONTENT_LENGTH, HeaderValue::from(compressed.len()));
+        headers.insert(
+            header::CONTENT_ENCODING,
+            HeaderValue::from_static(encoding.as_str()),
+        );
+        self.body = HttpResponseBody::Binary(compressed);
+        self
+    }
+
     pub fn build(
         self,
     ) -> hyper::Response<http_body_util::combinators::BoxBody<hyper::body::Bytes, hyper::Error>>
@@ -81,6 +81,9 @@ impl AccountApiHandler for Server {
                 Permission::SysClusterNodeCreate,
                 Permission::SysClusterNodeUpdate,
                 Permission::SysClusterNodeDestroy,
+                Permission::SysMtaIpWarmUpCreate,
+                Permission::SysMtaIpWarmUpUpdate,
+                Permission::SysMtaIpWarmUpDestroy,
                 Permission::SysBootstrapGet,
                 Permission::SysBootstrapUpdate,
             ] {
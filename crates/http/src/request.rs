@@ -23,10 +23,19 @@ use common::{
     network::{SessionData, SessionManager, SessionStream},
 };
 use dav::{DavMethod, request::DavRequestHandler};
-use groupware::{DavResourceName, calendar::itip::ItipIngest};
+use groupware::{
+    DavResourceName,
+    calendar::{
+        itip::ItipIngest,
+        publish::{CalendarFeed, CalendarPublishing},
+    },
+};
 use http_proto::{
     DownloadResponse, HtmlResponse, HttpContext, HttpRequest, HttpResponse, HttpResponseBody,
-    HttpSessionData, JsonProblemResponse, ToHttpResponse, form_urlencoded, request::fetch_body,
+    HttpSessionData, JsonProblemResponse, ToHttpResponse,
+    compression::ContentEncoding,
+    form_urlencoded,
+    request::{fetch_body, fetch_encoded_body},
 };
 use hyper::{
     Method, StatusCode, body,
@@ -45,6 +54,7 @@ use jmap::{
 };
 use jmap_proto::request::{Request, capability::Session};
 use registry::schema::enums::Permission;
+use smtp::queue::quarantine::SmtpQuarantine;
 use std::{net::IpAddr, str::FromStr, sync::Arc};
 use store::dispatch::lookup::KeyValue;
 use trc::SecurityEvent;
@@ -87,7 +97,7 @@ impl ParseHttp for Server {
                         let (_in_flight, access_token) =
                             self.authenticate_headers(&req, &session).await?;
 
-                        let bytes = fetch_body(
+                        let bytes = fetch_encoded_body(
                             &mut req,
                             if !access_token.has_permission(Permission::UnlimitedUploads) {
                                 self.core.jmap.upload_max_size
@@ -96,7 +106,7 @@ impl ParseHttp for Server {
                             },
                             session.session_id,
                         )
-                        .await
+                        .await?
                         .ok_or_else(|| trc::LimitEvent::SizeRequest.into_err())?;
 
                         return Ok(self
@@ -147,7 +157,7 @@ impl ParseHttp for Server {
                             self.authenticate_headers(&req, &session).await?;
 
                         if let Some(account_id) = path.next().and_then(|p| Id::from_str(p).ok()) {
-                            return match fetch_body(
+                            return match fetch_encoded_body(
                                 &mut req,
                                 if !access_token.has_permission(Permission::UnlimitedUploads) {
                                     self.core.jmap.upload_max_size
@@ -156,7 +166,7 @@ impl ParseHttp for Server {
                                 },
                                 session.session_id,
                             )
-                            .await
+                            .await?
                             {
                                 Some(bytes) => Ok(self
                                     .blob_upload(
@@ -338,6 +348,25 @@ impl ParseHttp for Server {
                         .await
                         .map(|resource| resource.into_http_response());
                 }
+                ("openpgpkey", &Method::GET) => {
+                    // Limit anonymous requests
+                    self.is_http_anonymous_request_allowed(session.remote_ip)
+                        .await?;
+
+                    return self
+                        .handle_wkd_request(
+                            req.headers()
+                                .get(header::HOST)
+                                .and_then(|h| h.to_str().ok())
+                                .map(|h| h.rsplit_once(':').map_or(h, |(h, _)| h))
+                                .or_else(|| req.uri().host())
+                                .unwrap_or_default(),
+                            &path.collect::<Vec<_>>(),
+                            req.uri().query(),
+                        )
+                        .await
+                        .map(|resource| resource.into_http_response());
+                }
                 (_, &Method::OPTIONS) => {
                     return Ok(HttpResponse::new(StatusCode::NO_CONTENT));
                 }
@@ -418,28 +447,101 @@ impl ParseHttp for Server {
                 self.is_http_anonymous_request_allowed(session.remote_ip)
                     .await?;
 
-                if self.core.groupware.itip_http_rsvp_url.is_some()
-                    && req.method() == Method::GET
-                    && path.next().unwrap_or_default() == "rsvp"
-                {
-                    return self
-                        .http_rsvp_handle(
-                            req.uri().query().unwrap_or_default(),
-                            req.headers()
-                                .get(header::ACCEPT_LANGUAGE)
-                                .and_then(|v| v.to_str().ok())
-                                .map(|lang| {
-                                    let lang = lang.split_once(',').map_or(lang, |(l, _)| l);
-                                    lang.split_once(';').map_or(lang, |(l, _)| l)
-                                })
-                                .unwrap_or("en"),
-                        )
-                        .await
-                        .map(|response| {
-                            HtmlResponse::new(response)
-                                .into_http_response()
-                                .with_no_store()
-                        });
+                match (path.next().unwrap_or_default(), req.method()) {
+                    ("rsvp", &Method::GET) if self.core.groupware.itip_http_rsvp_url.is_some() => {
+                        return self
+                            .http_rsvp_handle(
+                                req.uri().query().unwrap_or_default(),
+                                req.headers()
+                                    .get(header::ACCEPT_LANGUAGE)
+                                    .and_then(|v| v.to_str().ok())
+                                    .map(|lang| {
+                                        let lang = lang.split_once(',').map_or(lang, |(l, _)| l);
+                                        lang.split_once(';').map_or(lang, |(l, _)| l)
+                                    })
+                                    .unwrap_or("en"),
+                            )
+                            .await
+                            .map(|response| {
+                                HtmlResponse::new(response)
+                                    .into_http_response()
+                                    .with_no_store()
+                            });
+                    }
+                    ("feed", &Method::GET) => {
+                        if let Some(token) = path.next() {
+                            return match self
+                                .calendar_feed(
+                                    token,
+                                    req.headers()
+                                        .get(header::IF_NONE_MATCH)
+                                        .and_then(|v| v.to_str().ok()),
+                                )
+                                .await?
+                            {
+                                Some(CalendarFeed::Feed { etag, ical }) => {
+                                    Ok(HttpResponse::new(StatusCode::OK)
+                                        .with_content_type("text/calendar; charset=utf-8")
+                                        .with_etag(etag)
+                                        .with_no_cache()
+                                        .with_text_body(ical))
+                                }
+                                Some(CalendarFeed::NotModified { etag }) => {
+                                    Ok(HttpResponse::new(StatusCode::NOT_MODIFIED)
+                                        .with_etag(etag)
+                                        .with_no_cache())
+                                }
+                                None => Err(trc::ResourceEvent::NotFound.into_err()),
+                            };
+                        }
+                    }
+                    _ => (),
+                }
+            }
+            "quarantine" => {
+                // Limit anonymous requests
+                self.is_http_anonymous_request_allowed(session.remote_ip)
+                    .await?;
+
+                if path.next().unwrap_or_default() == "release" {
+                    let language = req
+                        .headers()
+                        .get(header::ACCEPT_LANGUAGE)
+                        .and_then(|v| v.to_str().ok())
+                        .map(|lang| {
+                            let lang = lang.split_once(',').map_or(lang, |(l, _)| l);
+                            lang.split_once(';').map_or(lang, |(l, _)| l)
+                        })
+                        .unwrap_or("en")
+                        .to_string();
+
+                    // GET renders a confirmation page, the release happens on POST
+                    let response = match *req.method() {
+                        Method::GET => {
+                            self.http_quarantine_release(
+                                req.uri().query().unwrap_or_default(),
+                                &language,
+                                false,
+                            )
+                            .await?
+                        }
+                        Method::POST => {
+                            let body = fetch_body(&mut req, 8192, session.session_id)
+                                .await
+                                .unwrap_or_default();
+                            self.http_quarantine_release(
+                                std::str::from_utf8(&body).unwrap_or_default(),
+                                &language,
+                                true,
+                            )
+                            .await?
+                        }
+                        _ => return Err(trc::ResourceEvent::NotFound.into_err()),
+                    };
+
+                    return Ok(HtmlResponse::new(response)
+                        .into_http_response()
+                        .with_no_store());
                 }
             }
             "autodiscover" | "Autodiscover" | "AutoDiscover" => {
@@ -729,6 +831,22 @@ async fn handle_session<T: SessionStream>(inner: Arc<Inner>, session: SessionDat
                         session.remote_ip
                     };
 
+                    // Negotiate response compression
+                    let encoding = server
+                        .core
+                        .network
+                        .http
+                        .compression_min_size
+                        .map(|min_size| {
+                            (
+                                req.headers()
+                                    .get(header::ACCEPT_ENCODING)
+                                    .and_then(|h| h.to_str().ok())
+                                    .and_then(ContentEncoding::negotiate),
+                                min_size,
+                            )
+                        });
+
                     // Parse HTTP request
                     let response = match Box::pin(server.parse_http_request(
                         req,
@@ -767,8 +885,13 @@ async fn handle_session<T: SessionStream>(inner: Arc<Inner>, session: SessionDat
                         Size = response.size(),
                     );
 
-                    // Build response
-                    let mut response = response.build();
+                    // Compress and build response
+                    let mut response = if let Some((encoding, min_size)) = encoding {
+                        response.compress(encoding, min_size)
+                    } else {
+                        response
+                    }
+                    .build();
 
                     // Add custom headers
                     if !server.core.network.http.response_headers.is_empty() {
@@ -74,6 +74,16 @@ pub enum Command {
     // RFC 9208
     GetQuota,
     GetQuotaRoot,
+
+    // RFC 4978
+    Compress,
+
+    // RFC 5464
+    GetMetadata,
+    SetMetadata,
+
+    // RFC 5465
+    Notify,
 }
 
 impl Command {
@@ -108,6 +118,7 @@ pub enum ResponseCode {
         capabilities: Vec<Capability>,
     },
     ClientBug,
+    CompressionActive,
     Closed,
     ContactAdmin,
     CopyUid {
@@ -152,6 +163,25 @@ pub enum ResponseCode {
 
     // USEATTR
     UseAttr,
+
+    // METADATA
+    Metadata {
+        code: MetadataCode,
+    },
+
+    // UIDONLY
+    UidRequired,
+
+    // NOTIFY
+    BadEvent,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MetadataCode {
+    LongEntries(u32),
+    MaxSize(u32),
+    TooMany,
+    NoPrivate,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
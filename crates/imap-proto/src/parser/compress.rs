@@ -0,0 +1,72 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use compact_str::ToCompactString;
+
+use crate::{
+    Command,
+    protocol::compress::{self, CompressionAlgorithm},
+    receiver::{Request, bad},
+};
+
+impl Request<Command> {
+    pub fn parse_compress(self) -> trc::Result<compress::Arguments> {
+        match self.tokens.len() {
+            1 => {
+                let algorithm = self.tokens.into_iter().next().unwrap().unwrap_bytes();
+                if algorithm.eq_ignore_ascii_case(b"DEFLATE") {
+                    Ok(compress::Arguments {
+                        tag: self.tag,
+                        algorithm: CompressionAlgorithm::Deflate,
+                    })
+                } else {
+                    Err(bad(
+                        self.tag.to_compact_string(),
+                        format!(
+                            "Unsupported compression algorithm '{}'.",
+                            String::from_utf8_lossy(&algorithm)
+                        ),
+                    ))
+                }
+            }
+            0 => Err(self.into_error("Missing compression algorithm.")),
+            _ => Err(self.into_error("Too many arguments.")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        protocol::compress::{self, CompressionAlgorithm},
+        receiver::Receiver,
+    };
+
+    #[test]
+    fn parse_compress() {
+        let mut receiver = Receiver::new();
+
+        assert_eq!(
+            receiver
+                .parse(&mut "t1 COMPRESS DEFLATE\r\n".as_bytes().iter())
+                .unwrap()
+                .parse_compress()
+                .unwrap(),
+            compress::Arguments {
+                tag: "t1".into(),
+                algorithm: CompressionAlgorithm::Deflate,
+            }
+        );
+
+        assert!(
+            receiver
+                .parse(&mut "t2 COMPRESS GZIP\r\n".as_bytes().iter())
+                .unwrap()
+                .parse_compress()
+                .is_err()
+        );
+    }
+}
@@ -42,6 +42,7 @@ impl Capability {
             "CONDSTORE" => Self::CondStore,
             "QRESYNC" => Self::QResync,
             "UTF8=ACCEPT" => Self::Utf8Accept,
+            "UIDONLY" => Self::UidOnly,
         )
         .ok_or_else(|| {
             format!(
@@ -16,7 +16,7 @@ use crate::{
     receiver::{Request, Token, bad},
 };
 
-use super::{PushUnique, parse_number, parse_sequence_set};
+use super::{PushUnique, parse_number, parse_partial_range, parse_sequence_set};
 
 impl Request<Command> {
     #[allow(clippy::while_let_on_iterator)]
@@ -334,6 +334,9 @@ impl Request<Command> {
                         "THREADID" => {
                             attributes.push_unique(Attribute::ThreadId);
                         },
+                        "SAVEDATE" => {
+                            attributes.push_unique(Attribute::SaveDate);
+                        },
                         _ => {
                             return Err(bad(
                                 CompactString::from_string_buffer(self.tag),
@@ -375,9 +378,10 @@ impl Request<Command> {
             }
         }
 
-        // CONDSTORE parameters
+        // CONDSTORE and PARTIAL parameters
         let mut changed_since = None;
         let mut include_vanished = false;
+        let mut partial = None;
         if let Some(Token::ParenthesisOpen) = tokens.peek() {
             tokens.next();
             while let Some(token) = tokens.next() {
@@ -400,6 +404,18 @@ impl Request<Command> {
                     Token::Argument(param) if param.eq_ignore_ascii_case(b"VANISHED") => {
                         include_vanished = true;
                     }
+                    Token::Argument(param) if param.eq_ignore_ascii_case(b"PARTIAL") => {
+                        partial = parse_partial_range(
+                            &tokens
+                                .next()
+                                .ok_or_else(|| {
+                                    bad(self.tag.to_compact_string(), "Missing PARTIAL range.")
+                                })?
+                                .unwrap_bytes(),
+                        )
+                        .map_err(|v| bad(self.tag.to_compact_string(), v))?
+                        .into();
+                    }
                     Token::ParenthesisClose => {
                         break;
                     }
@@ -420,6 +436,7 @@ impl Request<Command> {
                 attributes,
                 changed_since,
                 include_vanished,
+                partial,
             })
         } else {
             Err(bad(
@@ -511,7 +528,7 @@ pub fn parse_partial(tokens: &mut Peekable<IntoIter<Token>>) -> super::Result<Op
 mod tests {
     use crate::{
         protocol::{
-            Sequence,
+            PartialRange, Sequence,
             fetch::{self, Attribute, Section},
         },
         receiver::Receiver,
@@ -540,6 +557,7 @@ mod tests {
                     ],
                     changed_since: None,
                     include_vanished: false,
+                    partial: None,
                 },
             ),
             (
@@ -554,6 +572,7 @@ mod tests {
                     }],
                     changed_since: None,
                     include_vanished: false,
+                    partial: None,
                 },
             ),
             (
@@ -568,6 +587,7 @@ mod tests {
                     }],
                     changed_since: None,
                     include_vanished: false,
+                    partial: None,
                 },
             ),
             (
@@ -588,6 +608,7 @@ mod tests {
                     ],
                     changed_since: None,
                     include_vanished: false,
+                    partial: None,
                 },
             ),
             (
@@ -605,6 +626,7 @@ mod tests {
                     }],
                     changed_since: None,
                     include_vanished: false,
+                    partial: None,
                 },
             ),
             (
@@ -627,6 +649,7 @@ mod tests {
                     ],
                     changed_since: None,
                     include_vanished: false,
+                    partial: None,
                 },
             ),
             (
@@ -643,6 +666,7 @@ mod tests {
                     ],
                     changed_since: None,
                     include_vanished: false,
+                    partial: None,
                 },
             ),
             (
@@ -658,6 +682,7 @@ mod tests {
                     ],
                     changed_since: None,
                     include_vanished: false,
+                    partial: None,
                 },
             ),
             (
@@ -731,6 +756,7 @@ mod tests {
                     ],
                     changed_since: None,
                     include_vanished: false,
+                    partial: None,
                 },
             ),
             (
@@ -746,6 +772,7 @@ mod tests {
                     ],
                     changed_since: None,
                     include_vanished: false,
+                    partial: None,
                 },
             ),
             (
@@ -762,6 +789,7 @@ mod tests {
                     ],
                     changed_since: None,
                     include_vanished: false,
+                    partial: None,
                 },
             ),
             (
@@ -776,6 +804,7 @@ mod tests {
                     ],
                     changed_since: None,
                     include_vanished: false,
+                    partial: None,
                 },
             ),
             (
@@ -786,6 +815,7 @@ mod tests {
                     attributes: vec![Attribute::Flags, Attribute::ModSeq],
                     changed_since: 12345.into(),
                     include_vanished: true,
+                    partial: None,
                 },
             ),
             (
@@ -796,6 +826,18 @@ mod tests {
                     attributes: vec![Attribute::Uid],
                     changed_since: 1.into(),
                     include_vanished: true,
+                    partial: None,
+                },
+            ),
+            (
+                "10 UID FETCH 1:* (UID SAVEDATE) (PARTIAL -1:-30)\r\n",
+                fetch::Arguments {
+                    tag: "10".into(),
+                    sequence_set: Sequence::range(1.into(), None),
+                    attributes: vec![Attribute::Uid, Attribute::SaveDate],
+                    changed_since: None,
+                    include_vanished: false,
+                    partial: PartialRange::Last { start: 1, end: 30 }.into(),
                 },
             ),
         ] {
@@ -0,0 +1,318 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use compact_str::ToCompactString;
+
+use crate::{
+    Command,
+    protocol::metadata::{self, Depth},
+    receiver::{Request, Token, bad},
+    utf7::utf7_maybe_decode,
+};
+
+use super::parse_number;
+
+impl Request<Command> {
+    pub fn parse_get_metadata(self, is_utf8: bool) -> trc::Result<metadata::GetArguments> {
+        if self.tokens.len() < 2 {
+            return Err(self.into_error("Missing arguments."));
+        }
+
+        let mut tokens = self.tokens.into_iter().peekable();
+        let mut max_size = None;
+        let mut depth = Depth::Zero;
+
+        // Parse options
+        if tokens
+            .peek()
+            .is_some_and(|token| token.is_parenthesis_open())
+        {
+            tokens.next();
+            loop {
+                match tokens.next() {
+                    Some(Token::ParenthesisClose) => break,
+                    Some(Token::Argument(option)) if option.eq_ignore_ascii_case(b"MAXSIZE") => {
+                        max_size = parse_number::<u32>(
+                            &tokens
+                                .next()
+                                .ok_or_else(|| {
+                                    bad(self.tag.to_compact_string(), "Missing MAXSIZE value.")
+                                })?
+                                .unwrap_bytes(),
+                        )
+                        .map_err(|v| bad(self.tag.to_compact_string(), v))?
+                        .into();
+                    }
+                    Some(Token::Argument(option)) if option.eq_ignore_ascii_case(b"DEPTH") => {
+                        depth = match tokens.next() {
+                            Some(Token::Argument(value)) => {
+                                hashify::tiny_map_ignore_case!(value.as_slice(),
+                                    "0" => Depth::Zero,
+                                    "1" => Depth::One,
+                                    "infinity" => Depth::Infinity,
+                                )
+                                .ok_or_else(|| {
+                                    bad(self.tag.to_compact_string(), "Invalid DEPTH value.")
+                                })?
+                            }
+                            _ => {
+                                return Err(bad(
+                                    self.tag.to_compact_string(),
+                                    "Missing DEPTH value.",
+                                ));
+                            }
+                        };
+                    }
+                    _ => {
+                        return Err(bad(
+                            self.tag.to_compact_string(),
+                            "Invalid GETMETADATA option.",
+                        ));
+                    }
+                }
+            }
+        }
+
+        // Parse mailbox name
+        let mailbox_name = utf7_maybe_decode(
+            tokens
+                .next()
+                .ok_or_else(|| bad(self.tag.to_compact_string(), "Missing mailbox name."))?
+                .unwrap_string()
+                .map_err(|v| bad(self.tag.to_compact_string(), v))?,
+            is_utf8,
+        );
+
+        // Parse entries
+        let mut entries = Vec::new();
+        let in_parenthesis = tokens
+            .peek()
+            .is_some_and(|token| token.is_parenthesis_open());
+        if in_parenthesis {
+            tokens.next();
+        }
+        for token in tokens {
+            match token {
+                Token::ParenthesisClose if in_parenthesis => break,
+                Token::Argument(value) => {
+                    entries.push(
+                        parse_entry_name(value, true)
+                            .map_err(|v| bad(self.tag.to_compact_string(), v))?,
+                    );
+                    if !in_parenthesis {
+                        break;
+                    }
+                }
+                _ => {
+                    return Err(bad(self.tag.to_compact_string(), "Invalid entry name."));
+                }
+            }
+        }
+
+        if !entries.is_empty() {
+            Ok(metadata::GetArguments {
+                tag: self.tag,
+                mailbox_name,
+                entries,
+                max_size,
+                depth,
+            })
+        } else {
+            Err(bad(self.tag.to_compact_string(), "Missing entry names."))
+        }
+    }
+
+    pub fn parse_set_metadata(self, is_utf8: bool) -> trc::Result<metadata::SetArguments> {
+        if self.tokens.len() < 4 {
+            return Err(self.into_error("Missing arguments."));
+        }
+
+        let mut tokens = self.tokens.into_iter();
+        let mailbox_name = utf7_maybe_decode(
+            tokens
+                .next()
+                .unwrap()
+                .unwrap_string()
+                .map_err(|v| bad(self.tag.to_compact_string(), v))?,
+            is_utf8,
+        );
+
+        if tokens
+            .next()
+            .is_none_or(|token| !token.is_parenthesis_open())
+        {
+            return Err(bad(
+                self.tag.to_compact_string(),
+                "Expected parenthesis after mailbox name.",
+            ));
+        }
+
+        let mut entries = Vec::new();
+        loop {
+            match tokens.next() {
+                Some(Token::ParenthesisClose) => break,
+                Some(Token::Argument(name)) => {
+                    let name = parse_entry_name(name, false)
+                        .map_err(|v| bad(self.tag.to_compact_string(), v))?;
+                    let value = match tokens.next() {
+                        Some(Token::Argument(value)) if value.eq_ignore_ascii_case(b"NIL") => None,
+                        Some(Token::Argument(value)) => Some(value),
+                        Some(Token::Nil) => Some(vec![]),
+                        _ => {
+                            return Err(bad(self.tag.to_compact_string(), "Missing entry value."));
+                        }
+                    };
+                    entries.push((name, value));
+                }
+                _ => {
+                    return Err(bad(
+                        self.tag.to_compact_string(),
+                        "Expected entry name and value pairs.",
+                    ));
+                }
+            }
+        }
+
+        if !entries.is_empty() {
+            Ok(metadata::SetArguments {
+                tag: self.tag,
+                mailbox_name,
+                entries,
+            })
+        } else {
+            Err(bad(self.tag.to_compact_string(), "Missing entry names."))
+        }
+    }
+}
+
+fn parse_entry_name(value: Vec<u8>, allow_root: bool) -> super::Result<String> {
+    let name = String::from_utf8(value)
+        .map_err(|_| "Invalid UTF-8 in entry name.")?
+        .to_ascii_lowercase();
+
+    if (name.starts_with("/private/")
+        || name.starts_with("/shared/")
+        || (allow_root && (name == "/private" || name == "/shared")))
+        && !name.ends_with('/')
+        && !name.contains("//")
+        && name
+            .bytes()
+            .all(|ch| ch.is_ascii_graphic() && ch != b'*' && ch != b'%')
+    {
+        Ok(name)
+    } else {
+        Err(format!("Invalid entry name '{name}'.").into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        protocol::metadata::{self, Depth},
+        receiver::Receiver,
+    };
+
+    #[test]
+    fn parse_get_metadata() {
+        let mut receiver = Receiver::new();
+
+        for (command, arguments) in [
+            (
+                "a GETMETADATA \"\" /private/vendor/vendor.dovecot/webmail-user\r\n",
+                metadata::GetArguments {
+                    tag: "a".into(),
+                    mailbox_name: "".into(),
+                    entries: vec!["/private/vendor/vendor.dovecot/webmail-user".into()],
+                    max_size: None,
+                    depth: Depth::Zero,
+                },
+            ),
+            (
+                "a GETMETADATA INBOX (/shared/Comment /private/comment)\r\n",
+                metadata::GetArguments {
+                    tag: "a".into(),
+                    mailbox_name: "INBOX".into(),
+                    entries: vec!["/shared/comment".into(), "/private/comment".into()],
+                    max_size: None,
+                    depth: Depth::Zero,
+                },
+            ),
+            (
+                "a GETMETADATA (MAXSIZE 1024 DEPTH infinity) \"Sent Items\" /shared\r\n",
+                metadata::GetArguments {
+                    tag: "a".into(),
+                    mailbox_name: "Sent Items".into(),
+                    entries: vec!["/shared".into()],
+                    max_size: Some(1024),
+                    depth: Depth::Infinity,
+                },
+            ),
+        ] {
+            assert_eq!(
+                receiver
+                    .parse(&mut command.as_bytes().iter())
+                    .unwrap()
+                    .parse_get_metadata(true)
+                    .expect(command),
+                arguments,
+                "{command}"
+            );
+        }
+
+        for command in [
+            "a GETMETADATA INBOX /comment\r\n",
+            "a GETMETADATA INBOX /shared/\r\n",
+            "a GETMETADATA (DEPTH 2) INBOX /shared/comment\r\n",
+            "a GETMETADATA INBOX\r\n",
+        ] {
+            assert!(
+                receiver
+                    .parse(&mut command.as_bytes().iter())
+                    .unwrap()
+                    .parse_get_metadata(true)
+                    .is_err(),
+                "{command}"
+            );
+        }
+    }
+
+    #[test]
+    fn parse_set_metadata() {
+        let mut receiver = Receiver::new();
+
+        for (command, arguments) in [
+            (
+                "a SETMETADATA INBOX (/private/comment {14+}\r\nMy own comment)\r\n",
+                metadata::SetArguments {
+                    tag: "a".into(),
+                    mailbox_name: "INBOX".into(),
+                    entries: vec![("/private/comment".into(), Some(b"My own comment".to_vec()))],
+                },
+            ),
+            (
+                "a SETMETADATA \"\" (/shared/comment NIL /private/color \"\")\r\n",
+                metadata::SetArguments {
+                    tag: "a".into(),
+                    mailbox_name: "".into(),
+                    entries: vec![
+                        ("/shared/comment".into(), None),
+                        ("/private/color".into(), Some(vec![])),
+                    ],
+                },
+            ),
+        ] {
+            assert_eq!(
+                receiver
+                    .parse(&mut command.as_bytes().iter())
+                    .unwrap()
+                    .parse_set_metadata(true)
+                    .expect(command),
+                arguments,
+                "{command}"
+            );
+        }
+    }
+}
@@ -7,6 +7,7 @@
 pub mod acl;
 pub mod append;
 pub mod authenticate;
+pub mod compress;
 pub mod copy_move;
 pub mod create;
 pub mod delete;
@@ -15,6 +16,8 @@ pub mod fetch;
 pub mod list;
 pub mod login;
 pub mod lsub;
+pub mod metadata;
+pub mod notify;
 pub mod quota;
 pub mod rename;
 pub mod search;
@@ -31,7 +34,7 @@ use chrono::{DateTime, NaiveDate};
 
 use crate::{
     Command,
-    protocol::{Flag, Sequence},
+    protocol::{Flag, PartialRange, Sequence},
     receiver::CommandParser,
 };
 
@@ -80,6 +83,10 @@ impl CommandParser for Command {
             "ID" => Command::Id,
             "GETQUOTA" => Command::GetQuota,
             "GETQUOTAROOT" => Command::GetQuotaRoot,
+            "COMPRESS" => Command::Compress,
+            "GETMETADATA" => Command::GetMetadata,
+            "SETMETADATA" => Command::SetMetadata,
+            "NOTIFY" => Command::Notify,
         )
     }
 
@@ -399,6 +406,46 @@ pub fn parse_sequence_set(value: &[u8]) -> Result<Sequence> {
     }
 }
 
+pub fn parse_partial_range(value: &[u8]) -> Result<PartialRange> {
+    let invalid = || {
+        Cow::from(format!(
+            "Invalid partial range {:?}.",
+            String::from_utf8_lossy(value)
+        ))
+    };
+    let (start, end) = value
+        .iter()
+        .position(|&ch| ch == b':')
+        .map(|pos| (&value[..pos], &value[pos + 1..]))
+        .ok_or_else(invalid)?;
+
+    match (start.strip_prefix(b"-"), end.strip_prefix(b"-")) {
+        (Some(start), Some(end)) => {
+            let (start, end) = (parse_number::<u32>(start)?, parse_number::<u32>(end)?);
+            if start != 0 && end != 0 {
+                Ok(PartialRange::Last {
+                    start: start.min(end),
+                    end: start.max(end),
+                })
+            } else {
+                Err(invalid())
+            }
+        }
+        (None, None) => {
+            let (start, end) = (parse_number::<u32>(start)?, parse_number::<u32>(end)?);
+            if start != 0 && end != 0 {
+                Ok(PartialRange::First {
+                    start: start.min(end),
+                    end: start.max(end),
+                })
+            } else {
+                Err(invalid())
+            }
+        }
+        _ => Err(invalid()),
+    }
+}
+
 pub trait PushUnique<T> {
     fn push_unique(&mut self, value: T);
 }
@@ -413,7 +460,7 @@ impl<T: PartialEq> PushUnique<T> for Vec<T> {
 
 #[cfg(test)]
 mod tests {
-    use crate::protocol::Sequence;
+    use crate::protocol::{PartialRange, Sequence};
 
     #[test]
     fn parse_sequence_set() {
@@ -487,4 +534,35 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn parse_partial_range() {
+        for (range, expected_result) in [
+            ("1:100", Some(PartialRange::First { start: 1, end: 100 })),
+            ("50:10", Some(PartialRange::First { start: 10, end: 50 })),
+            ("-1:-100", Some(PartialRange::Last { start: 1, end: 100 })),
+            ("0:10", None),
+            ("-1:10", None),
+            ("1", None),
+            ("1:*", None),
+        ] {
+            assert_eq!(
+                super::parse_partial_range(range.as_bytes()).ok(),
+                expected_result,
+                "{range}"
+            );
+        }
+
+        let items = (1..=10).collect::<Vec<u32>>();
+        for (range, expected_result) in [
+            (PartialRange::First { start: 1, end: 3 }, vec![1, 2, 3]),
+            (PartialRange::First { start: 9, end: 20 }, vec![9, 10]),
+            (PartialRange::First { start: 11, end: 20 }, vec![]),
+            (PartialRange::Last { start: 1, end: 3 }, vec![8, 9, 10]),
+            (PartialRange::Last { start: 9, end: 20 }, vec![1, 2]),
+            (PartialRange::Last { start: 11, end: 20 }, vec![]),
+        ] {
+            assert_eq!(range.apply(&items), expected_result.as_slice(), "{range:?}");
+        }
+    }
 }
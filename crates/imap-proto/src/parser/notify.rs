@@ -0,0 +1,364 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use std::iter::Peekable;
+use std::vec::IntoIter;
+
+use compact_str::ToCompactString;
+
+use crate::{
+    Command,
+    protocol::{
+        fetch::Attribute,
+        notify::{self, Event, EventGroup, Filter, Settings},
+    },
+    receiver::{Request, Token, bad},
+    utf7::utf7_maybe_decode,
+};
+
+use super::PushUnique;
+
+impl Request<Command> {
+    pub fn parse_notify(self, is_utf8: bool) -> trc::Result<notify::Arguments> {
+        let mut tokens = self.tokens.into_iter().peekable();
+
+        match tokens.next() {
+            Some(Token::Argument(value)) if value.eq_ignore_ascii_case(b"NONE") => {
+                if tokens.next().is_none() {
+                    Ok(notify::Arguments {
+                        tag: self.tag,
+                        settings: None,
+                    })
+                } else {
+                    Err(bad(
+                        self.tag.to_compact_string(),
+                        "Unexpected arguments after NONE.",
+                    ))
+                }
+            }
+            Some(Token::Argument(value)) if value.eq_ignore_ascii_case(b"SET") => {
+                parse_settings(&mut tokens, is_utf8)
+                    .map(|settings| notify::Arguments {
+                        tag: self.tag.clone(),
+                        settings: Some(settings),
+                    })
+                    .map_err(|v| bad(self.tag.to_compact_string(), v))
+            }
+            _ => Err(bad(self.tag.to_compact_string(), "Expected SET or NONE.")),
+        }
+    }
+}
+
+fn parse_settings(
+    tokens: &mut Peekable<IntoIter<Token>>,
+    is_utf8: bool,
+) -> super::Result<Settings> {
+    let mut settings = Settings::default();
+
+    while let Some(token) = tokens.next() {
+        if !token.is_parenthesis_open() {
+            return Err("Expected event group.".into());
+        }
+
+        // Status indicator
+        if settings.groups.is_empty()
+            && !settings.status
+            && tokens
+                .peek()
+                .is_some_and(|token| token.eq_ignore_ascii_case(b"STATUS"))
+        {
+            tokens.next();
+            if tokens
+                .next()
+                .is_none_or(|token| !token.is_parenthesis_close())
+            {
+                return Err("Expected ')' after STATUS.".into());
+            }
+            settings.status = true;
+            continue;
+        }
+
+        // Parse filter
+        let filter = match tokens.next() {
+            Some(Token::Argument(value)) => hashify::tiny_map_ignore_case!(value.as_slice(),
+                "SELECTED" => Filter::Selected,
+                "SELECTED-DELAYED" => Filter::SelectedDelayed,
+                "INBOXES" => Filter::Inboxes,
+                "PERSONAL" => Filter::Personal,
+                "SUBSCRIBED" => Filter::Subscribed,
+                "SUBTREE" => Filter::Subtree(vec![]),
+                "MAILBOXES" => Filter::Mailboxes(vec![]),
+            )
+            .ok_or_else(|| {
+                format!(
+                    "Invalid mailbox filter {:?}.",
+                    String::from_utf8_lossy(&value)
+                )
+            })?,
+            _ => return Err("Expected mailbox filter.".into()),
+        };
+        let filter = match filter {
+            Filter::Subtree(_) => Filter::Subtree(parse_mailboxes(tokens, is_utf8)?),
+            Filter::Mailboxes(_) => Filter::Mailboxes(parse_mailboxes(tokens, is_utf8)?),
+            filter => filter,
+        };
+        if settings.groups.iter().any(|group| {
+            group.filter == filter || (group.filter.is_selected() && filter.is_selected())
+        }) {
+            return Err("Duplicate mailbox filter.".into());
+        }
+
+        // Parse events
+        let mut events = Vec::new();
+        match tokens.next() {
+            Some(Token::Argument(value)) if value.eq_ignore_ascii_case(b"NONE") => {}
+            Some(Token::ParenthesisOpen) => loop {
+                match tokens.next() {
+                    Some(Token::ParenthesisClose) => break,
+                    Some(Token::Argument(value)) => {
+                        let event = hashify::tiny_map_ignore_case!(value.as_slice(),
+                            "MESSAGENEW" => Event::MessageNew { attributes: vec![] },
+                            "MESSAGEEXPUNGE" => Event::MessageExpunge,
+                            "FLAGCHANGE" => Event::FlagChange,
+                            "ANNOTATIONCHANGE" => Event::AnnotationChange,
+                            "MAILBOXNAME" => Event::MailboxName,
+                            "SUBSCRIPTIONCHANGE" => Event::SubscriptionChange,
+                            "MAILBOXMETADATACHANGE" => Event::MailboxMetadataChange,
+                            "SERVERMETADATACHANGE" => Event::ServerMetadataChange,
+                        )
+                        .ok_or_else(|| {
+                            format!("Invalid event {:?}.", String::from_utf8_lossy(&value))
+                        })?;
+                        let event = if matches!(event, Event::MessageNew { .. })
+                            && tokens
+                                .peek()
+                                .is_some_and(|token| token.is_parenthesis_open())
+                        {
+                            if !filter.is_selected() {
+                                return Err(
+                                    "Fetch attributes are only allowed for the selected mailbox."
+                                        .into(),
+                                );
+                            }
+                            tokens.next();
+                            Event::MessageNew {
+                                attributes: parse_attributes(tokens)?,
+                            }
+                        } else {
+                            event
+                        };
+                        events.push_unique(event);
+                    }
+                    _ => return Err("Expected event name.".into()),
+                }
+            },
+            _ => return Err("Expected event list or NONE.".into()),
+        }
+
+        // MessageNew and MessageExpunge must be requested together, FlagChange requires both
+        let has_new = events
+            .iter()
+            .any(|event| matches!(event, Event::MessageNew { .. }));
+        let has_expunge = events.contains(&Event::MessageExpunge);
+        if has_new != has_expunge {
+            return Err("MessageNew and MessageExpunge must be specified together.".into());
+        } else if !has_new
+            && (events.contains(&Event::FlagChange) || events.contains(&Event::AnnotationChange))
+        {
+            return Err("FlagChange requires MessageNew and MessageExpunge.".into());
+        }
+
+        if tokens
+            .next()
+            .is_none_or(|token| !token.is_parenthesis_close())
+        {
+            return Err("Expected ')' after event group.".into());
+        }
+
+        settings.groups.push(EventGroup { filter, events });
+    }
+
+    if !settings.groups.is_empty() {
+        Ok(settings)
+    } else {
+        Err("Missing event groups.".into())
+    }
+}
+
+fn parse_mailboxes(
+    tokens: &mut Peekable<IntoIter<Token>>,
+    is_utf8: bool,
+) -> super::Result<Vec<String>> {
+    let mut mailboxes = Vec::new();
+    match tokens.next() {
+        Some(Token::ParenthesisOpen) => loop {
+            match tokens.next() {
+                Some(Token::ParenthesisClose) => break,
+                Some(token @ (Token::Argument(_) | Token::Nil)) => {
+                    mailboxes.push(utf7_maybe_decode(token.unwrap_string()?, is_utf8));
+                }
+                _ => return Err("Expected mailbox name.".into()),
+            }
+        },
+        Some(token @ (Token::Argument(_) | Token::Nil)) => {
+            mailboxes.push(utf7_maybe_decode(token.unwrap_string()?, is_utf8));
+        }
+        _ => return Err("Expected mailbox name.".into()),
+    }
+
+    if !mailboxes.is_empty() {
+        Ok(mailboxes)
+    } else {
+        Err("Missing mailbox names.".into())
+    }
+}
+
+fn parse_attributes(tokens: &mut Peekable<IntoIter<Token>>) -> super::Result<Vec<Attribute>> {
+    let mut attributes = Vec::new();
+    loop {
+        match tokens.next() {
+            Some(Token::ParenthesisClose) => break,
+            Some(Token::Argument(value)) => {
+                attributes.push_unique(
+                    hashify::tiny_map_ignore_case!(value.as_slice(),
+                        "ENVELOPE" => Attribute::Envelope,
+                        "FLAGS" => Attribute::Flags,
+                        "INTERNALDATE" => Attribute::InternalDate,
+                        "RFC822.SIZE" => Attribute::Rfc822Size,
+                        "BODYSTRUCTURE" => Attribute::BodyStructure,
+                        "UID" => Attribute::Uid,
+                        "MODSEQ" => Attribute::ModSeq,
+                        "EMAILID" => Attribute::EmailId,
+                        "THREADID" => Attribute::ThreadId,
+                        "SAVEDATE" => Attribute::SaveDate,
+                    )
+                    .ok_or_else(|| {
+                        format!(
+                            "Unsupported fetch attribute {:?}.",
+                            String::from_utf8_lossy(&value)
+                        )
+                    })?,
+                );
+            }
+            _ => return Err("Expected fetch attribute.".into()),
+        }
+    }
+
+    if !attributes.is_empty() {
+        Ok(attributes)
+    } else {
+        Err("Missing fetch attributes.".into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        protocol::{
+            fetch::Attribute,
+            notify::{self, Event, EventGroup, Filter, Settings},
+        },
+        receiver::Receiver,
+    };
+
+    #[test]
+    fn parse_notify() {
+        let mut receiver = Receiver::new();
+
+        for (command, arguments) in [
+            (
+                "A1 NOTIFY NONE\r\n",
+                notify::Arguments {
+                    tag: "A1".into(),
+                    settings: None,
+                },
+            ),
+            (
+                concat!(
+                    "A2 NOTIFY SET (STATUS) (selected (MessageNew (UID BODYSTRUCTURE) ",
+                    "MessageExpunge FlagChange)) (subtree (Lists \"Other Users\") ",
+                    "(MessageNew MessageExpunge MailboxName)) (personal NONE)\r\n"
+                ),
+                notify::Arguments {
+                    tag: "A2".into(),
+                    settings: Some(Settings {
+                        status: true,
+                        groups: vec![
+                            EventGroup {
+                                filter: Filter::Selected,
+                                events: vec![
+                                    Event::MessageNew {
+                                        attributes: vec![Attribute::Uid, Attribute::BodyStructure],
+                                    },
+                                    Event::MessageExpunge,
+                                    Event::FlagChange,
+                                ],
+                            },
+                            EventGroup {
+                                filter: Filter::Subtree(vec!["Lists".into(), "Other Users".into()]),
+                                events: vec![
+                                    Event::MessageNew { attributes: vec![] },
+                                    Event::MessageExpunge,
+                                    Event::MailboxName,
+                                ],
+                            },
+                            EventGroup {
+                                filter: Filter::Personal,
+                                events: vec![],
+                            },
+                        ],
+                    }),
+                },
+            ),
+            (
+                "A3 NOTIFY SET (mailboxes INBOX (MessageNew MessageExpunge SubscriptionChange))\r\n",
+                notify::Arguments {
+                    tag: "A3".into(),
+                    settings: Some(Settings {
+                        status: false,
+                        groups: vec![EventGroup {
+                            filter: Filter::Mailboxes(vec!["INBOX".into()]),
+                            events: vec![
+                                Event::MessageNew { attributes: vec![] },
+                                Event::MessageExpunge,
+                                Event::SubscriptionChange,
+                            ],
+                        }],
+                    }),
+                },
+            ),
+        ] {
+            assert_eq!(
+                receiver
+                    .parse(&mut command.as_bytes().iter())
+                    .unwrap()
+                    .parse_notify(true)
+                    .expect(command),
+                arguments,
+                "{command}"
+            );
+        }
+
+        for command in [
+            "A1 NOTIFY\r\n",
+            "A2 NOTIFY SET (inboxes (MessageNew))\r\n",
+            "A3 NOTIFY SET (inboxes (FlagChange))\r\n",
+            "A4 NOTIFY SET (inboxes (MessageNew (UID) MessageExpunge))\r\n",
+            "A5 NOTIFY SET (selected (MessageNew MessageExpunge)) (selected-delayed NONE)\r\n",
+            "A6 NOTIFY SET (unknown NONE)\r\n",
+            "A7 NOTIFY SET (subtree (MailboxName))\r\n",
+        ] {
+            assert!(
+                receiver
+                    .parse(&mut command.as_bytes().iter())
+                    .unwrap()
+                    .parse_notify(true)
+                    .is_err(),
+                "{command}"
+            );
+        }
+    }
+}
@@ -18,7 +18,7 @@ use crate::protocol::search::{ModSeqEntry, ResultOption};
 use crate::protocol::{Flag, ProtocolVersion};
 use crate::receiver::{Request, Token, bad};
 
-use super::{parse_date, parse_number, parse_sequence_set};
+use super::{parse_date, parse_number, parse_partial_range, parse_sequence_set};
 
 impl Request<Command> {
     #[allow(clippy::while_let_on_iterator)]
@@ -83,9 +83,17 @@ pub fn parse_result_options(
         return Err(Cow::from("Invalid result option, expected parenthesis."));
     }
 
-    for token in tokens {
+    while let Some(token) = tokens.next() {
         match token {
             Token::ParenthesisClose => break,
+            Token::Argument(value) if value.eq_ignore_ascii_case(b"partial") => {
+                result_options.push(ResultOption::Partial(parse_partial_range(
+                    &tokens
+                        .next()
+                        .ok_or_else(|| Cow::from("Expected partial range."))?
+                        .unwrap_bytes(),
+                )?));
+            }
             Token::Argument(value) => {
                 result_options.push(ResultOption::parse(&value)?);
             }
@@ -93,6 +101,14 @@ pub fn parse_result_options(
         }
     }
 
+    if result_options.contains(&ResultOption::All)
+        && result_options
+            .iter()
+            .any(|option| matches!(option, ResultOption::Partial(_)))
+    {
+        return Err(Cow::from("PARTIAL and ALL are mutually exclusive."));
+    }
+
     Ok(result_options)
 }
 
@@ -420,6 +436,37 @@ pub fn parse_filters(
                                 .unwrap_string()?,
                         ));
 
+                    },
+                    "SAVEDBEFORE" => {
+                        filters.push(Filter::SavedBefore(parse_date(
+                            &tokens
+                                .next()
+                                .ok_or_else(|| Cow::from("Expected date"))?
+                                .unwrap_bytes(),
+                        )?));
+
+                    },
+                    "SAVEDON" => {
+                        filters.push(Filter::SavedOn(parse_date(
+                            &tokens
+                                .next()
+                                .ok_or_else(|| Cow::from("Expected date"))?
+                                .unwrap_bytes(),
+                        )?));
+
+                    },
+                    "SAVEDSINCE" => {
+                        filters.push(Filter::SavedSince(parse_date(
+                            &tokens
+                                .next()
+                                .ok_or_else(|| Cow::from("Expected date"))?
+                                .unwrap_bytes(),
+                        )?));
+
+                    },
+                    "SAVEDATESUPPORTED" => {
+                        filters.push(Filter::SaveDateSupported);
+
                     },
                     "OR" => {
                         if filters_stack.len() > 10 {
@@ -541,7 +588,7 @@ impl ResultOption {
 mod tests {
     use crate::{
         protocol::{
-            Flag, ProtocolVersion, Sequence,
+            Flag, PartialRange, ProtocolVersion, Sequence,
             search::{self, Filter, ModSeqEntry, ResultOption},
         },
         receiver::Receiver,
@@ -631,6 +678,19 @@ mod tests {
                     sort: None,
                 },
             ),
+            (
+                b"F283 SEARCH RETURN (COUNT PARTIAL -1:-100) SAVEDSINCE 1-Feb-1994\r\n".to_vec(),
+                search::Arguments {
+                    tag: "F283".into(),
+                    result_options: vec![
+                        ResultOption::Count,
+                        ResultOption::Partial(PartialRange::Last { start: 1, end: 100 }),
+                    ],
+                    filter: vec![Filter::SavedSince(760060800)],
+                    is_esearch: true,
+                    sort: None,
+                },
+            ),
             (
                 [
                     b"F282 SEARCH OR OR FROM hello@world.com TO ".to_vec(),
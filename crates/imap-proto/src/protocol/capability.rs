@@ -54,6 +54,13 @@ pub enum Capability {
     QuotaResource(QuotaResourceName),
     QuotaSet,
     JmapAccess,
+    CompressDeflate, //COMPRESS=DEFLATE
+    Metadata,
+    MetadataServer, //METADATA-SERVER
+    Notify,
+    UidOnly,
+    Partial,
+    SaveDate,
 }
 
 /*
@@ -130,6 +137,13 @@ impl Capability {
             }
             Capability::QuotaSet => b"QUOTA=SET",
             Capability::JmapAccess => b"JMAPACCESS",
+            Capability::CompressDeflate => b"COMPRESS=DEFLATE",
+            Capability::Metadata => b"METADATA",
+            Capability::MetadataServer => b"METADATA-SERVER",
+            Capability::Notify => b"NOTIFY",
+            Capability::UidOnly => b"UIDONLY",
+            Capability::Partial => b"PARTIAL",
+            Capability::SaveDate => b"SAVEDATE",
         });
     }
 
@@ -176,6 +190,13 @@ impl Capability {
                 Capability::Rights,
                 Capability::Quota,
                 Capability::QuotaResource(QuotaResourceName::Storage),
+                Capability::CompressDeflate,
+                Capability::Metadata,
+                Capability::MetadataServer,
+                Capability::Notify,
+                Capability::UidOnly,
+                Capability::Partial,
+                Capability::SaveDate,
             ]);
         } else {
             capabilities.extend([
@@ -12,7 +12,7 @@ use utils::chained_bytes::SliceRange;
 use crate::protocol::literal_string_slice;
 
 use super::{
-    Flag, ImapResponse, Sequence, literal_string, quoted_or_literal_string,
+    Flag, ImapResponse, PartialRange, Sequence, literal_string, quoted_or_literal_string,
     quoted_or_literal_string_or_nil, quoted_rfc2822_or_nil, quoted_timestamp,
 };
 
@@ -23,6 +23,7 @@ pub struct Arguments {
     pub attributes: Vec<Attribute>,
     pub changed_since: Option<u64>,
     pub include_vanished: bool,
+    pub partial: Option<PartialRange>,
 }
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Response<'x> {
@@ -67,6 +68,7 @@ pub enum Attribute {
     ModSeq,
     EmailId,
     ThreadId,
+    SaveDate,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -136,6 +138,9 @@ pub enum DataItem<'x> {
     ThreadId {
         thread_id: String,
     },
+    SaveDate {
+        date: Option<i64>,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -847,6 +852,14 @@ impl DataItem<'_> {
                 buf.extend_from_slice(thread_id.as_bytes());
                 buf.push(b')');
             }
+            DataItem::SaveDate { date } => {
+                buf.extend_from_slice(b"SAVEDATE ");
+                if let Some(date) = date {
+                    quoted_timestamp(buf, *date);
+                } else {
+                    buf.extend_from_slice(b"NIL");
+                }
+            }
         }
     }
 }
@@ -864,6 +877,25 @@ impl FetchItem<'_> {
         }
         buf.extend_from_slice(b")\r\n");
     }
+
+    // RFC 9586 - UIDONLY replaces FETCH with UIDFETCH, the id being the message UID
+    pub fn serialize_uid_only(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(b"* ");
+        buf.extend_from_slice(self.id.to_string().as_bytes());
+        buf.extend_from_slice(b" UIDFETCH (");
+        for (pos, item) in self
+            .items
+            .iter()
+            .filter(|item| !matches!(item, DataItem::Uid { .. }))
+            .enumerate()
+        {
+            if pos > 0 {
+                buf.push(b' ');
+            }
+            item.serialize(buf);
+        }
+        buf.extend_from_slice(b")\r\n");
+    }
 }
 
 impl ImapResponse for Response<'_> {
@@ -1362,6 +1394,13 @@ mod tests {
                 super::DataItem::InternalDate { date: 482374938 },
                 "INTERNALDATE \"15-Apr-1985 01:02:18 +0000\"",
             ),
+            (
+                super::DataItem::SaveDate {
+                    date: 482374938.into(),
+                },
+                "SAVEDATE \"15-Apr-1985 01:02:18 +0000\"",
+            ),
+            (super::DataItem::SaveDate { date: None }, "SAVEDATE NIL"),
         ] {
             let mut buf = Vec::with_capacity(100);
 
@@ -1406,4 +1445,25 @@ mod tests {
             )
         );
     }
+
+    #[test]
+    fn serialize_uid_fetch() {
+        let mut buf = Vec::with_capacity(64);
+        FetchItem {
+            id: 983,
+            items: vec![
+                super::DataItem::Uid { uid: 983 },
+                super::DataItem::Flags {
+                    flags: vec![Flag::Seen],
+                },
+                super::DataItem::ModSeq { modseq: 65402 },
+            ],
+        }
+        .serialize_uid_only(&mut buf);
+
+        assert_eq!(
+            String::from_utf8(buf).unwrap(),
+            "* 983 UIDFETCH (FLAGS (\\Seen) MODSEQ (65402))\r\n"
+        );
+    }
 }
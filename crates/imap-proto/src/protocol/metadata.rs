@@ -0,0 +1,126 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use super::{ImapResponse, literal_string, quoted_string};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GetArguments {
+    pub tag: String,
+    pub mailbox_name: String,
+    pub entries: Vec<String>,
+    pub max_size: Option<u32>,
+    pub depth: Depth,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SetArguments {
+    pub tag: String,
+    pub mailbox_name: String,
+    pub entries: Vec<(String, Option<Vec<u8>>)>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Depth {
+    #[default]
+    Zero,
+    One,
+    Infinity,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub mailbox_name: String,
+    pub entries: Vec<(String, Option<Vec<u8>>)>,
+}
+
+impl ImapResponse for Response {
+    fn serialize(self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(64);
+        if !self.entries.is_empty() {
+            buf.extend_from_slice(b"* METADATA ");
+            quoted_string(&mut buf, &self.mailbox_name);
+            buf.extend_from_slice(b" (");
+            for (pos, (name, value)) in self.entries.iter().enumerate() {
+                if pos > 0 {
+                    buf.push(b' ');
+                }
+                quoted_string(&mut buf, name);
+                buf.push(b' ');
+                if let Some(value) = value {
+                    literal_string(&mut buf, value);
+                } else {
+                    buf.extend_from_slice(b"NIL");
+                }
+            }
+            buf.extend_from_slice(b")\r\n");
+        }
+        buf
+    }
+}
+
+impl Depth {
+    pub fn matches(&self, requested: &str, entry: &str) -> bool {
+        if let Some(suffix) = entry.strip_prefix(requested).filter(|suffix| {
+            requested.ends_with('/') || suffix.is_empty() || suffix.starts_with('/')
+        }) {
+            let suffix = suffix.trim_start_matches('/');
+            match self {
+                Depth::Zero => suffix.is_empty(),
+                Depth::One => !suffix.contains('/'),
+                Depth::Infinity => true,
+            }
+        } else {
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::protocol::ImapResponse;
+
+    use super::Depth;
+
+    #[test]
+    fn serialize_metadata() {
+        assert_eq!(
+            String::from_utf8(
+                super::Response {
+                    mailbox_name: "INBOX".into(),
+                    entries: vec![
+                        ("/private/comment".into(), Some(b"My own comment".to_vec())),
+                        ("/shared/comment".into(), None),
+                    ],
+                }
+                .serialize()
+            )
+            .unwrap(),
+            concat!(
+                "* METADATA \"INBOX\" (\"/private/comment\" {14}\r\nMy own comment ",
+                "\"/shared/comment\" NIL)\r\n"
+            )
+        );
+    }
+
+    #[test]
+    fn metadata_depth() {
+        for (depth, requested, entry, expected) in [
+            (Depth::Zero, "/shared/comment", "/shared/comment", true),
+            (Depth::Zero, "/shared/comment", "/shared/comment/a", false),
+            (Depth::One, "/shared/comment", "/shared/comment/a", true),
+            (Depth::One, "/shared/comment", "/shared/comment/a/b", false),
+            (Depth::One, "/shared/comment", "/shared/commentary", false),
+            (Depth::Infinity, "/shared", "/shared/comment/a/b", true),
+            (Depth::Infinity, "/shared", "/private/comment", false),
+        ] {
+            assert_eq!(
+                depth.matches(requested, entry),
+                expected,
+                "{depth:?} {requested} {entry}"
+            );
+        }
+    }
+}
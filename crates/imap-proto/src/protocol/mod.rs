@@ -4,7 +4,7 @@
  * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
  */
 
-use crate::{Command, ResponseCode, ResponseType, StatusResponse};
+use crate::{Command, MetadataCode, ResponseCode, ResponseType, StatusResponse};
 use ahash::AHashSet;
 use chrono::{DateTime, Utc};
 use compact_str::CompactString;
@@ -16,6 +16,7 @@ pub mod acl;
 pub mod append;
 pub mod authenticate;
 pub mod capability;
+pub mod compress;
 pub mod copy_move;
 pub mod create;
 pub mod delete;
@@ -24,7 +25,9 @@ pub mod expunge;
 pub mod fetch;
 pub mod list;
 pub mod login;
+pub mod metadata;
 pub mod namespace;
+pub mod notify;
 pub mod quota;
 pub mod rename;
 pub mod search;
@@ -148,6 +151,41 @@ impl Sequence {
     }
 }
 
+// RFC 9394 - Positions are 1-based and counted from the end for `Last`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PartialRange {
+    First { start: u32, end: u32 },
+    Last { start: u32, end: u32 },
+}
+
+impl PartialRange {
+    pub fn apply<'x, T>(&self, items: &'x [T]) -> &'x [T] {
+        let len = items.len();
+        let (from, to) = match self {
+            PartialRange::First { start, end } => {
+                ((*start as usize - 1).min(len), (*end as usize).min(len))
+            }
+            PartialRange::Last { start, end } => (
+                len.saturating_sub(*end as usize),
+                len.saturating_sub(*start as usize - 1),
+            ),
+        };
+        &items[from..to]
+    }
+
+    pub fn serialize(&self, buf: &mut Vec<u8>) {
+        let (start, end, prefix) = match self {
+            PartialRange::First { start, end } => (start, end, &b""[..]),
+            PartialRange::Last { start, end } => (start, end, &b"-"[..]),
+        };
+        buf.extend_from_slice(prefix);
+        buf.extend_from_slice(start.to_string().as_bytes());
+        buf.push(b':');
+        buf.extend_from_slice(prefix);
+        buf.extend_from_slice(end.to_string().as_bytes());
+    }
+}
+
 pub trait ImapResponse {
     fn serialize(self) -> Vec<u8>;
 }
@@ -439,6 +477,7 @@ impl ResponseCode {
                 return;
             }
             ResponseCode::ClientBug => b"CLIENTBUG",
+            ResponseCode::CompressionActive => b"COMPRESSIONACTIVE",
             ResponseCode::Closed => b"CLOSED",
             ResponseCode::ContactAdmin => b"CONTACTADMIN",
             ResponseCode::CopyUid {
@@ -492,6 +531,34 @@ impl ResponseCode {
                 return;
             }
             ResponseCode::UseAttr => b"USEATTR",
+            ResponseCode::Metadata { code } => {
+                buf.extend_from_slice(b"METADATA ");
+                match code {
+                    MetadataCode::LongEntries(size) => {
+                        buf.extend_from_slice(b"LONGENTRIES ");
+                        buf.extend_from_slice(size.to_string().as_bytes());
+                    }
+                    MetadataCode::MaxSize(size) => {
+                        buf.extend_from_slice(b"MAXSIZE ");
+                        buf.extend_from_slice(size.to_string().as_bytes());
+                    }
+                    MetadataCode::TooMany => buf.extend_from_slice(b"TOOMANY"),
+                    MetadataCode::NoPrivate => buf.extend_from_slice(b"NOPRIVATE"),
+                }
+                return;
+            }
+            ResponseCode::UidRequired => b"UIDREQUIRED",
+            ResponseCode::BadEvent => {
+                buf.extend_from_slice(b"BADEVENT (");
+                for (pos, event) in notify::SUPPORTED_EVENTS.iter().enumerate() {
+                    if pos > 0 {
+                        buf.push(b' ');
+                    }
+                    buf.extend_from_slice(event.as_bytes());
+                }
+                buf.push(b')');
+                return;
+            }
         });
     }
 
@@ -507,6 +574,7 @@ impl ResponseCode {
             ResponseCode::Cannot => "CANNOT",
             ResponseCode::Capability { .. } => "CAPABILITY",
             ResponseCode::ClientBug => "CLIENTBUG",
+            ResponseCode::CompressionActive => "COMPRESSIONACTIVE",
             ResponseCode::Closed => "CLOSED",
             ResponseCode::ContactAdmin => "CONTACTADMIN",
             ResponseCode::CopyUid { .. } => "COPYUID",
@@ -535,6 +603,9 @@ impl ResponseCode {
             ResponseCode::MailboxId { .. } => "MAILBOXID",
             ResponseCode::HighestModseq { .. } => "HIGHESTMODSEQ",
             ResponseCode::UseAttr => "USEATTR",
+            ResponseCode::Metadata { .. } => "METADATA",
+            ResponseCode::UidRequired => "UIDREQUIRED",
+            ResponseCode::BadEvent => "BADEVENT",
         }
     }
 }
@@ -557,7 +628,14 @@ impl ResponseType {
 
 impl From<ResponseCode> for trc::Value {
     fn from(value: ResponseCode) -> Self {
-        trc::Value::String(CompactString::const_new(value.as_str()))
+        match value {
+            ResponseCode::Metadata { .. } => {
+                let mut buf = Vec::with_capacity(24);
+                value.serialize(&mut buf);
+                trc::Value::String(CompactString::from_utf8_lossy(&buf))
+            }
+            _ => trc::Value::String(CompactString::const_new(value.as_str())),
+        }
     }
 }
 
@@ -726,6 +804,10 @@ impl Display for Command {
             Command::Id => write!(f, "ID"),
             Command::GetQuota => write!(f, "GETQUOTA"),
             Command::GetQuotaRoot => write!(f, "GETQUOTAROOT"),
+            Command::Compress => write!(f, "COMPRESS"),
+            Command::GetMetadata => write!(f, "GETMETADATA"),
+            Command::SetMetadata => write!(f, "SETMETADATA"),
+            Command::Notify => write!(f, "NOTIFY"),
         }
     }
 }
@@ -0,0 +1,106 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use super::fetch;
+
+pub const SUPPORTED_EVENTS: &[&str] =
+    &["MessageNew", "MessageExpunge", "FlagChange", "MailboxName"];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Arguments {
+    pub tag: String,
+    // None when the client issued NOTIFY NONE
+    pub settings: Option<Settings>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Settings {
+    pub status: bool,
+    pub groups: Vec<EventGroup>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EventGroup {
+    pub filter: Filter,
+    pub events: Vec<Event>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Filter {
+    Selected,
+    SelectedDelayed,
+    Inboxes,
+    Personal,
+    Subscribed,
+    Subtree(Vec<String>),
+    Mailboxes(Vec<String>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Event {
+    MessageNew { attributes: Vec<fetch::Attribute> },
+    MessageExpunge,
+    FlagChange,
+    AnnotationChange,
+    MailboxName,
+    SubscriptionChange,
+    MailboxMetadataChange,
+    ServerMetadataChange,
+}
+
+impl Settings {
+    pub fn selected(&self) -> Option<&EventGroup> {
+        self.groups.iter().find(|group| group.filter.is_selected())
+    }
+
+    pub fn has_mailbox_filters(&self) -> bool {
+        self.groups
+            .iter()
+            .any(|group| !group.filter.is_selected() && !group.events.is_empty())
+    }
+}
+
+impl EventGroup {
+    pub fn has_message_events(&self) -> bool {
+        self.events.iter().any(|event| {
+            matches!(
+                event,
+                Event::MessageNew { .. } | Event::MessageExpunge | Event::FlagChange
+            )
+        })
+    }
+
+    pub fn has_mailbox_name_events(&self) -> bool {
+        self.events.contains(&Event::MailboxName)
+    }
+
+    pub fn message_new_attributes(&self) -> Option<&[fetch::Attribute]> {
+        self.events.iter().find_map(|event| match event {
+            Event::MessageNew { attributes } if !attributes.is_empty() => {
+                Some(attributes.as_slice())
+            }
+            _ => None,
+        })
+    }
+}
+
+impl Filter {
+    pub fn is_selected(&self) -> bool {
+        matches!(self, Filter::Selected | Filter::SelectedDelayed)
+    }
+}
+
+impl Event {
+    pub fn is_supported(&self) -> bool {
+        matches!(
+            self,
+            Event::MessageNew { .. }
+                | Event::MessageExpunge
+                | Event::FlagChange
+                | Event::MailboxName
+        )
+    }
+}
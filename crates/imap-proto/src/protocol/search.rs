@@ -4,7 +4,7 @@
  * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
  */
 
-use super::{Flag, Sequence, quoted_string, serialize_sequence};
+use super::{Flag, PartialRange, Sequence, quoted_string, serialize_sequence};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Arguments {
@@ -43,6 +43,7 @@ pub struct Response {
     pub min: Option<u32>,
     pub max: Option<u32>,
     pub count: Option<u32>,
+    pub partial: Option<PartialRange>,
     pub highest_modseq: Option<u64>,
 }
 
@@ -54,6 +55,7 @@ pub enum ResultOption {
     Count,
     Save,
     Context,
+    Partial(PartialRange),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -110,6 +112,12 @@ pub enum Filter {
     // RFC 8474 - ObjectID
     EmailId(String),
     ThreadId(String),
+
+    // RFC 8514 - SAVEDATE
+    SavedBefore(i64),
+    SavedOn(i64),
+    SavedSince(i64),
+    SaveDateSupported,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -152,7 +160,17 @@ impl Response {
                 buf.extend_from_slice(b" MAX ");
                 buf.extend_from_slice(max.to_string().as_bytes());
             }
-            if !self.ids.is_empty() {
+            if let Some(partial) = &self.partial {
+                buf.extend_from_slice(b" PARTIAL (");
+                partial.serialize(&mut buf);
+                if !self.ids.is_empty() {
+                    buf.push(b' ');
+                    serialize_sequence(&mut buf, &self.ids);
+                } else {
+                    buf.extend_from_slice(b" NIL");
+                }
+                buf.push(b')');
+            } else if !self.ids.is_empty() {
                 buf.extend_from_slice(b" ALL ");
                 serialize_sequence(&mut buf, &self.ids);
             }
@@ -198,6 +216,7 @@ mod tests {
                     min: 2.into(),
                     max: 11.into(),
                     count: 3.into(),
+                    partial: None,
                     highest_modseq: None,
                 },
                 "A283",
@@ -215,6 +234,7 @@ mod tests {
                     min: None,
                     max: None,
                     count: None,
+                    partial: None,
                     highest_modseq: None,
                 },
                 "A283",
@@ -230,6 +250,7 @@ mod tests {
                     min: None,
                     max: None,
                     count: None,
+                    partial: None,
                     highest_modseq: None,
                 },
                 "A283",
@@ -245,12 +266,45 @@ mod tests {
                     min: None,
                     max: None,
                     count: None,
+                    partial: None,
                     highest_modseq: 12345.into(),
                 },
                 "A283",
                 "* ESEARCH (TAG \"A283\") ALL 10:13,21 MODSEQ 12345\r\n",
                 "* SEARCH 10 11 12 13 21 (MODSEQ 12345)\r\n",
             ),
+            (
+                super::Response {
+                    is_uid: true,
+                    is_esearch: true,
+                    is_sort: false,
+                    ids: vec![200, 201, 202, 250],
+                    min: None,
+                    max: None,
+                    count: 23765.into(),
+                    partial: super::PartialRange::Last { start: 1, end: 4 }.into(),
+                    highest_modseq: None,
+                },
+                "A284",
+                "* ESEARCH (TAG \"A284\") UID COUNT 23765 PARTIAL (-1:-4 200:202,250)\r\n",
+                "* SEARCH 200 201 202 250\r\n",
+            ),
+            (
+                super::Response {
+                    is_uid: true,
+                    is_esearch: true,
+                    is_sort: false,
+                    ids: vec![],
+                    min: None,
+                    max: None,
+                    count: None,
+                    partial: super::PartialRange::First { start: 1, end: 100 }.into(),
+                    highest_modseq: None,
+                },
+                "A285",
+                "* ESEARCH (TAG \"A285\") UID PARTIAL (1:100 NIL)\r\n",
+                "* SEARCH\r\n",
+            ),
         ] {
             let response_v2 = String::from_utf8(response.clone().serialize(tag)).unwrap();
             response.is_esearch = false;
@@ -26,13 +26,18 @@ pub enum Operation {
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Response<'x> {
     pub items: Vec<FetchItem<'x>>,
+    pub is_uid_only: bool,
 }
 
 impl ImapResponse for Response<'_> {
     fn serialize(self) -> Vec<u8> {
         let mut buf = Vec::with_capacity(64);
         for item in &self.items {
-            item.serialize(&mut buf);
+            if self.is_uid_only {
+                item.serialize_uid_only(&mut buf);
+            } else {
+                item.serialize(&mut buf);
+            }
         }
         buf
     }
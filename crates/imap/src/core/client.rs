@@ -11,7 +11,7 @@ use common::{
     network::{SessionResult, SessionStream},
 };
 use imap_proto::{
-    Command, ResponseType, StatusResponse,
+    Command, ResponseCode, ResponseType, StatusResponse,
     receiver::{self, Request},
 };
 use trc::SecurityEvent;
@@ -239,6 +239,18 @@ impl<T: SessionStream> Session<T> {
                     .handle_get_quota_root(request)
                     .await
                     .map(|_| SessionResult::Continue),
+                Command::GetMetadata => self
+                    .handle_get_metadata(request)
+                    .await
+                    .map(|_| SessionResult::Continue),
+                Command::SetMetadata => self
+                    .handle_set_metadata(request)
+                    .await
+                    .map(|_| SessionResult::Continue),
+                Command::Notify => self
+                    .handle_notify(request)
+                    .await
+                    .map(|_| SessionResult::Continue),
                 Command::Unauthenticate => self
                     .handle_unauthenticate(request)
                     .await
@@ -247,6 +259,10 @@ impl<T: SessionStream> Session<T> {
                     .handle_id(request)
                     .await
                     .map(|_| SessionResult::Continue),
+                Command::Compress => self
+                    .handle_compress(request)
+                    .await
+                    .map(|_| SessionResult::UpgradeCompression),
             };
 
             match result {
@@ -313,7 +329,12 @@ impl<T: SessionStream> Session<T> {
         match &request.command {
             Command::Capability | Command::Noop | Command::Logout | Command::Id => Ok(request),
             Command::StartTls => {
-                if !self.is_tls {
+                if self.is_compressed {
+                    Err(trc::ImapEvent::Error
+                        .into_err()
+                        .details("STARTTLS is not allowed after COMPRESS.")
+                        .id(request.tag))
+                } else if !self.is_tls {
                     if self.instance.acceptor.is_tls() {
                         Ok(request)
                     } else {
@@ -356,6 +377,22 @@ impl<T: SessionStream> Session<T> {
                         .id(request.tag))
                 }
             }
+            Command::Compress => {
+                if !state.is_authenticated() {
+                    Err(trc::ImapEvent::Error
+                        .into_err()
+                        .details("Not authenticated.")
+                        .id(request.tag))
+                } else if self.is_compressed {
+                    Err(trc::ImapEvent::Error
+                        .into_err()
+                        .details("DEFLATE active via COMPRESS.")
+                        .code(ResponseCode::CompressionActive)
+                        .id(request.tag))
+                } else {
+                    Ok(request)
+                }
+            }
             Command::Enable
             | Command::Select
             | Command::Examine
@@ -377,7 +414,10 @@ impl<T: SessionStream> Session<T> {
             | Command::MyRights
             | Command::Unauthenticate
             | Command::GetQuota
-            | Command::GetQuotaRoot => {
+            | Command::GetQuotaRoot
+            | Command::GetMetadata
+            | Command::SetMetadata
+            | Command::Notify => {
                 if let State::Authenticated { .. } | State::Selected { .. } = state {
                     Ok(request)
                 } else {
@@ -399,7 +439,25 @@ impl<T: SessionStream> Session<T> {
             | Command::Sort(_)
             | Command::Thread(_) => match state {
                 State::Selected { mailbox, .. } => {
-                    if mailbox.is_select
+                    if mailbox.is_uid_only
+                        && matches!(
+                            request.command,
+                            Command::Search(false)
+                                | Command::Fetch(false)
+                                | Command::Store(false)
+                                | Command::Copy(false)
+                                | Command::Move(false)
+                                | Command::Sort(false)
+                                | Command::Thread(false)
+                        )
+                    {
+                        Err(trc::ImapEvent::Error
+                            .into_err()
+                            .details("Message sequence numbers are not allowed in UIDONLY mode.")
+                            .code(ResponseCode::UidRequired)
+                            .ctx(trc::Key::Type, ResponseType::Bad)
+                            .id(request.tag))
+                    } else if mailbox.is_select
                         || !matches!(
                             request.command,
                             Command::Store(_) | Command::Expunge(_) | Command::Move(_),
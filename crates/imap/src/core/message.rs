@@ -125,6 +125,7 @@ impl<T: SessionStream> SessionData<T> {
     ) -> trc::Result<u64> {
         // Resync mailbox
         let modseq = self.synchronize_messages(mailbox).await?;
+        let is_qresync = is_qresync || mailbox.is_uid_only;
         let mut buf = Vec::new();
         {
             let mut current_state = mailbox.state.lock();
@@ -8,11 +8,12 @@ use ahash::AHashMap;
 use common::{
     Inner, Server,
     auth::AccessToken,
+    ipc::PushNotification,
     network::{ServerInstance, SessionStream, limiter::InFlight},
 };
 use imap_proto::{
     Command,
-    protocol::{ProtocolVersion, list::Attribute},
+    protocol::{ProtocolVersion, list::Attribute, notify},
     receiver::Receiver,
 };
 use std::{
@@ -22,7 +23,7 @@ use std::{
 };
 use tokio::{
     io::{ReadHalf, WriteHalf},
-    sync::watch,
+    sync::{mpsc, watch},
 };
 use trc::AddContext;
 
@@ -49,9 +50,12 @@ pub struct Session<T: SessionStream> {
     pub version: ProtocolVersion,
     pub state: State<T>,
     pub is_tls: bool,
+    pub is_compressed: bool,
     pub is_condstore: bool,
     pub is_qresync: bool,
     pub is_utf8: bool,
+    pub is_uid_only: bool,
+    pub notify: Option<NotifySubscription>,
     pub stream_rx: ReadHalf<T>,
     pub stream_tx: Arc<tokio::sync::Mutex<WriteHalf<T>>>,
     pub in_flight: InFlight,
@@ -71,12 +75,18 @@ pub struct SessionData<T: SessionStream> {
     pub in_flight: Option<InFlight>,
 }
 
+pub struct NotifySubscription {
+    pub settings: Arc<notify::Settings>,
+    pub push_rx: mpsc::Receiver<PushNotification>,
+}
+
 pub struct SelectedMailbox {
     pub id: MailboxId,
     pub state: parking_lot::Mutex<MailboxState>,
     pub saved_search: parking_lot::Mutex<SavedSearch>,
     pub is_select: bool,
     pub is_condstore: bool,
+    pub is_uid_only: bool,
 }
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
@@ -4,11 +4,15 @@
  * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
  */
 
-use super::{ImapSessionManager, Session, State};
+use super::{ImapSessionManager, NotifySubscription, Session, State};
 use crate::{GREETING_WITH_TLS, GREETING_WITHOUT_TLS};
 use common::{
     BuildServer,
-    network::{SessionData, SessionManager, SessionResult, SessionStream, stream::NullIo},
+    ipc::PushNotification,
+    network::{
+        SessionData, SessionManager, SessionResult, SessionStream,
+        stream::{DeflateStream, NullIo},
+    },
 };
 use imap_proto::{
     protocol::{ProtocolVersion, SerializeResponse},
@@ -25,12 +29,23 @@ impl SessionManager for ImapSessionManager {
         session: SessionData<T>,
     ) -> impl std::future::Future<Output = ()> + Send {
         async move {
-            if let Ok(mut session) = Session::new(session, self).await
-                && session.handle_conn().await
-                && session.instance.acceptor.is_tls()
-                && let Ok(mut session) = session.into_tls().await
-            {
-                session.handle_conn().await;
+            if let Ok(mut session) = Session::new(session, self).await {
+                match session.handle_conn().await {
+                    SessionResult::UpgradeTls if session.instance.acceptor.is_tls() => {
+                        if let Ok(mut session) = session.into_tls().await
+                            && session.handle_conn().await == SessionResult::UpgradeCompression
+                            && let Ok(mut session) = session.into_compressed().await
+                        {
+                            session.handle_conn().await;
+                        }
+                    }
+                    SessionResult::UpgradeCompression => {
+                        if let Ok(mut session) = session.into_compressed().await {
+                            session.handle_conn().await;
+                        }
+                    }
+                    _ => (),
+                }
             }
         }
     }
@@ -42,7 +57,7 @@ impl SessionManager for ImapSessionManager {
 }
 
 impl<T: SessionStream> Session<T> {
-    pub async fn handle_conn(&mut self) -> bool {
+    pub async fn handle_conn(&mut self) -> SessionResult {
         let mut buf = vec![0; 8192];
         let mut shutdown_rx = self.instance.shutdown_rx.clone();
 
@@ -61,7 +76,10 @@ impl<T: SessionStream> Session<T> {
                                 match self.ingest(&buf[..bytes_read]).await {
                                     SessionResult::Continue => (),
                                     SessionResult::UpgradeTls => {
-                                        return true;
+                                        return SessionResult::UpgradeTls;
+                                    }
+                                    SessionResult::UpgradeCompression => {
+                                        return SessionResult::UpgradeCompression;
                                     }
                                     SessionResult::Close => {
                                         break;
@@ -96,6 +114,18 @@ impl<T: SessionStream> Session<T> {
                         }
                     }
                 },
+                push_notification = next_notification(&mut self.notify) => {
+                    if let Some(push_notification) = push_notification {
+                        if let Err(err) = self.handle_notification(push_notification).await
+                            && !self.write_error(err).await
+                        {
+                            break;
+                        }
+                    } else {
+                        // Push manager went away, stop delivering notifications
+                        self.notify = None;
+                    }
+                },
                 _ = shutdown_rx.changed() => {
                     trc::event!(
                         Network(trc::NetworkEvent::Closed),
@@ -109,7 +139,7 @@ impl<T: SessionStream> Session<T> {
             };
         }
 
-        false
+        SessionResult::Close
     }
 
     pub async fn new(
@@ -144,9 +174,12 @@ impl<T: SessionStream> Session<T> {
             version: ProtocolVersion::Rev1,
             state: State::NotAuthenticated { auth_failures: 0 },
             is_tls,
+            is_compressed: false,
             is_condstore: false,
             is_qresync: false,
             is_utf8: false,
+            is_uid_only: false,
+            notify: None,
             server,
             instance: session.instance,
             session_id: session.session_id,
@@ -201,9 +234,69 @@ impl<T: SessionStream> Session<T> {
             version: self.version,
             state: state.try_replace_stream_tx(stream_tx.clone()).unwrap(),
             is_tls: true,
+            is_compressed: false,
+            is_condstore: self.is_condstore,
+            is_qresync: self.is_qresync,
+            is_utf8: self.is_utf8,
+            is_uid_only: self.is_uid_only,
+            notify: self.notify,
+            session_id: self.session_id,
+            in_flight: self.in_flight,
+            remote_addr: self.remote_addr,
+            stream_rx,
+            stream_tx,
+        })
+    }
+
+    pub async fn into_compressed(self) -> Result<Session<DeflateStream<T>>, ()> {
+        // Drop references to write half from state
+        let state = if let Some(state) =
+            self.state
+                .try_replace_stream_tx(Arc::new(tokio::sync::Mutex::new(
+                    tokio::io::split(NullIo::default()).1,
+                ))) {
+            state
+        } else {
+            trc::event!(
+                Network(trc::NetworkEvent::SplitError),
+                SpanId = self.session_id,
+                Details = "Failed to obtain write half state"
+            );
+            return Err(());
+        };
+
+        // Take ownership of WriteHalf and unsplit it from ReadHalf
+        let stream = if let Ok(stream_tx) =
+            Arc::try_unwrap(self.stream_tx).map(|mutex| mutex.into_inner())
+        {
+            self.stream_rx.unsplit(stream_tx)
+        } else {
+            trc::event!(
+                Network(trc::NetworkEvent::SplitError),
+                SpanId = self.session_id,
+                Details = "Failed to take ownership of write half"
+            );
+
+            return Err(());
+        };
+
+        // Wrap stream in a DEFLATE layer
+        let (stream_rx, stream_tx) = tokio::io::split(DeflateStream::new(stream));
+        let stream_tx = Arc::new(tokio::sync::Mutex::new(stream_tx));
+
+        Ok(Session {
+            server: self.server,
+            instance: self.instance,
+            receiver: self.receiver,
+            version: self.version,
+            state: state.try_replace_stream_tx(stream_tx.clone()).unwrap(),
+            is_tls: self.is_tls,
+            is_compressed: true,
             is_condstore: self.is_condstore,
             is_qresync: self.is_qresync,
             is_utf8: self.is_utf8,
+            is_uid_only: self.is_uid_only,
+            notify: self.notify,
             session_id: self.session_id,
             in_flight: self.in_flight,
             remote_addr: self.remote_addr,
@@ -213,6 +306,14 @@ impl<T: SessionStream> Session<T> {
     }
 }
 
+async fn next_notification(notify: &mut Option<NotifySubscription>) -> Option<PushNotification> {
+    if let Some(notify) = notify {
+        notify.push_rx.recv().await
+    } else {
+        std::future::pending().await
+    }
+}
+
 impl<T: SessionStream> Session<T> {
     pub async fn write_bytes(&self, bytes: impl AsRef<[u8]>) -> trc::Result<()> {
         let bytes = bytes.as_ref();
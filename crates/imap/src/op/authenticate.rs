@@ -127,6 +127,7 @@ impl<T: SessionStream> Session<T> {
 
     pub async fn handle_unauthenticate(&mut self, request: Request<Command>) -> trc::Result<()> {
         self.state = State::NotAuthenticated { auth_failures: 0 };
+        self.notify = None;
 
         self.write_bytes(
             StatusResponse::completed(Command::Unauthenticate)
@@ -0,0 +1,41 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::core::{Session, State};
+use common::network::SessionStream;
+use imap_proto::{Command, StatusResponse, receiver::Request};
+use std::{sync::Arc, time::Instant};
+
+impl<T: SessionStream> Session<T> {
+    pub async fn handle_compress(&mut self, request: Request<Command>) -> trc::Result<()> {
+        let op_start = Instant::now();
+        let arguments = request.parse_compress()?;
+
+        // The write half can only be replaced once no other task holds the session data
+        if let State::Authenticated { data } | State::Selected { data, .. } = &self.state
+            && Arc::strong_count(data) > 1
+        {
+            return Err(trc::ImapEvent::Error
+                .into_err()
+                .details("Wait for pending commands to complete before enabling compression.")
+                .id(arguments.tag));
+        }
+
+        trc::event!(
+            Imap(trc::ImapEvent::Compress),
+            SpanId = self.session_id,
+            Type = format!("{:?}", arguments.algorithm),
+            Elapsed = op_start.elapsed()
+        );
+
+        self.write_bytes(
+            StatusResponse::ok("DEFLATE active")
+                .with_tag(arguments.tag)
+                .into_bytes(),
+        )
+        .await
+    }
+}
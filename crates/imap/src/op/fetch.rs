@@ -13,10 +13,14 @@ use ahash::AHashMap;
 use common::{network::SessionStream, storage::index::ObjectIndexBuilder};
 use email::{
     cache::{MessageCacheFetch, email::MessageCacheAccess},
-    message::metadata::{
-        ArchivedMessageMetadata, ArchivedMessageMetadataContents, ArchivedMetadataHeaderValue,
-        ArchivedMetadataPartType, DecodedParts, MESSAGE_RECEIVED_MASK, MessageData,
-        MessageMetadata, MetadataHeaderName, PART_ENCODING_PROBLEM,
+    mailbox::save_date::MailboxSaveDate,
+    message::{
+        metadata::{
+            ArchivedMessageMetadata, ArchivedMessageMetadataContents, ArchivedMetadataHeaderValue,
+            ArchivedMetadataPartType, DecodedParts, MESSAGE_RECEIVED_MASK, MessageData,
+            MessageMetadata, MetadataHeaderName, PART_ENCODING_PROBLEM,
+        },
+        snooze::{EmailSnooze, SnoozedEmail},
     },
 };
 use imap_proto::{
@@ -306,6 +310,19 @@ impl<T: SessionStream> SessionData<T> {
             }
         }
 
+        // Obtain snooze dates, which are returned as a pseudo-keyword
+        let snoozed = if set_seen_flags || arguments.attributes.contains(&Attribute::Flags) {
+            self.server
+                .emails_snoozed_due(account_id, u64::MAX)
+                .await
+                .imap_ctx(&arguments.tag, trc::location!())?
+                .into_iter()
+                .map(|(document_id, snoozed)| (document_id, snoozed.until))
+                .collect::<AHashMap<_, _>>()
+        } else {
+            AHashMap::new()
+        };
+
         // Process each message
         let mut batch = BatchBuilder::new();
         let mut ids = ids
@@ -313,6 +330,9 @@ impl<T: SessionStream> SessionData<T> {
             .map(|(id, imap_id)| (imap_id.seqnum, imap_id.uid, id))
             .collect::<Vec<_>>();
         ids.sort_unstable_by_key(|(seqnum, _, _)| *seqnum);
+        if let Some(partial) = &arguments.partial {
+            ids = partial.apply(&ids).to_vec();
+        }
         let fetched_ids = ids
             .iter()
             .map(|id| trc::Value::from(id.2))
@@ -323,6 +343,21 @@ impl<T: SessionStream> SessionData<T> {
             .await
             .imap_ctx(&arguments.tag, trc::location!())?;
 
+        // Obtain the dates messages were saved to this mailbox
+        let save_dates = if arguments.attributes.contains(&Attribute::SaveDate) && !ids.is_empty() {
+            let (first_uid, last_uid) = ids
+                .iter()
+                .fold((u32::MAX, 0), |(first, last), (_, uid, _)| {
+                    (first.min(*uid), last.max(*uid))
+                });
+            self.server
+                .save_dates(account_id, mailbox.id.mailbox_id, first_uid..=last_uid)
+                .await
+                .imap_ctx(&arguments.tag, trc::location!())?
+        } else {
+            AHashMap::new()
+        };
+
         for (seqnum, uid, id) in ids {
             // Obtain attributes and keywords
             let (metadata_, data) = if let (Some(email), Some(data)) = (
@@ -409,6 +444,9 @@ impl<T: SessionStream> SessionData<T> {
                         if set_seen_flag {
                             flags.push(Flag::Seen);
                         }
+                        if let Some(until) = snoozed.get(&id) {
+                            flags.push(Flag::Keyword(SnoozedEmail::keyword(*until).into()));
+                        }
                         items.push(DataItem::Flags { flags });
                     }
                     Attribute::InternalDate => {
@@ -528,6 +566,12 @@ impl<T: SessionStream> SessionData<T> {
                             thread_id: Id::from_parts(account_id, data.thread_id).to_string(),
                         });
                     }
+                    Attribute::SaveDate => {
+                        // Messages saved before save dates were tracked return NIL
+                        items.push(DataItem::SaveDate {
+                            date: save_dates.get(&uid).map(|date| *date as i64),
+                        });
+                    }
                 }
             }
 
@@ -538,12 +582,19 @@ impl<T: SessionStream> SessionData<T> {
                     .map(Flag::from)
                     .collect::<Vec<_>>();
                 flags.push(Flag::Seen);
+                if let Some(until) = snoozed.get(&id) {
+                    flags.push(Flag::Keyword(SnoozedEmail::keyword(*until).into()));
+                }
                 items.push(DataItem::Flags { flags });
             }
 
             // Serialize fetch item
             let mut buf = Vec::with_capacity(128);
-            FetchItem { id: seqnum, items }.serialize(&mut buf);
+            if mailbox.is_uid_only {
+                FetchItem { id: uid, items }.serialize_uid_only(&mut buf);
+            } else {
+                FetchItem { id: seqnum, items }.serialize(&mut buf);
+            }
             self.write_bytes(buf).await?;
 
             // Add to set flags
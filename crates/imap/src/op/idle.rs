@@ -115,7 +115,8 @@ impl<T: SessionStream> Session<T> {
                                 has_email_changes = true;
                                 has_mailbox_changes = true;
                             },
-                            PushNotification::CalendarAlert(_) => (),
+                            PushNotification::CalendarAlert(_)
+                            | PushNotification::SieveNotify(_) => (),
                         }
 
                         if has_mailbox_changes || has_email_changes {
@@ -249,6 +250,7 @@ impl<T: SessionStream> SessionData<T> {
                                 attributes: vec![fetch::Attribute::Flags, fetch::Attribute::Uid],
                                 changed_since: None,
                                 include_vanished: false,
+                                partial: None,
                             },
                             mailbox.clone(),
                             true,
@@ -0,0 +1,278 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::{
+    core::{MailboxId, Session, SessionData},
+    op::ImapContext,
+    spawn_op,
+};
+use common::network::SessionStream;
+use email::mailbox::metadata::{
+    MailboxMetadataStore, MetadataError, MetadataUpdate, is_private_entry,
+};
+use imap_proto::{
+    Command, MetadataCode, ResponseCode, StatusResponse,
+    protocol::{
+        ImapResponse,
+        metadata::{GetArguments, Response, SetArguments},
+    },
+    receiver::Request,
+};
+use registry::schema::enums::Permission;
+use std::time::Instant;
+use types::acl::Acl;
+
+const SPECIAL_USE_ENTRY: &str = "/private/specialuse";
+
+impl<T: SessionStream> Session<T> {
+    pub async fn handle_get_metadata(&mut self, request: Request<Command>) -> trc::Result<()> {
+        // Validate access
+        self.assert_has_permission(Permission::ImapGetMetadata)?;
+
+        let arguments = request.parse_get_metadata(self.is_utf8)?;
+        let data = self.state.session_data();
+
+        spawn_op!(data, {
+            let response = data.get_metadata(arguments).await?;
+            data.write_bytes(response).await
+        })
+    }
+
+    pub async fn handle_set_metadata(&mut self, request: Request<Command>) -> trc::Result<()> {
+        // Validate access
+        self.assert_has_permission(Permission::ImapSetMetadata)?;
+
+        let arguments = request.parse_set_metadata(self.is_utf8)?;
+        let data = self.state.session_data();
+
+        spawn_op!(data, {
+            let response = data.set_metadata(arguments).await?;
+            data.write_bytes(response).await
+        })
+    }
+}
+
+impl<T: SessionStream> SessionData<T> {
+    pub async fn get_metadata(&self, arguments: GetArguments) -> trc::Result<Vec<u8>> {
+        let op_start = Instant::now();
+
+        // Resolve mailbox, an empty name refers to server annotations
+        let mailbox = self
+            .metadata_mailbox(&arguments.tag, &arguments.mailbox_name)
+            .await?;
+        let (account_id, mailbox_id) = if let Some(mailbox) = &mailbox {
+            if !self
+                .check_mailbox_acl(mailbox.account_id, mailbox.mailbox_id, Acl::Read)
+                .await
+                .imap_ctx(&arguments.tag, trc::location!())?
+            {
+                return Err(trc::ImapEvent::Error
+                    .into_err()
+                    .details("You do not have enough permissions to view this mailbox.")
+                    .code(ResponseCode::NoPerm)
+                    .id(arguments.tag));
+            }
+            (mailbox.account_id, Some(mailbox.mailbox_id))
+        } else {
+            (self.account_id, None)
+        };
+        let can_read_shared = match &mailbox {
+            Some(mailbox) => self
+                .check_mailbox_acl(mailbox.account_id, mailbox.mailbox_id, Acl::ReadItems)
+                .await
+                .imap_ctx(&arguments.tag, trc::location!())?,
+            None => true,
+        };
+
+        // Obtain entries
+        let metadata = self
+            .server
+            .mailbox_metadata(account_id, mailbox_id)
+            .await
+            .imap_ctx(&arguments.tag, trc::location!())?;
+        let special_use = mailbox
+            .as_ref()
+            .and_then(|mailbox| self.mailbox_state(mailbox))
+            .and_then(|mailbox| mailbox.special_use)
+            .map(|attribute| {
+                let mut value = Vec::with_capacity(10);
+                attribute.serialize(&mut value);
+                value
+            });
+        let mut entries = Vec::new();
+        let mut long_entries = 0;
+
+        for requested in &arguments.entries {
+            let mut found = false;
+            let stored = metadata
+                .visible_entries(self.account_id)
+                .filter(|entry| can_read_shared || is_private_entry(&entry.name))
+                .map(|entry| (entry.name.as_str(), entry.value.as_slice()));
+            let synthetic = special_use
+                .as_deref()
+                .map(|value| (SPECIAL_USE_ENTRY, value));
+
+            for (name, value) in stored.chain(synthetic) {
+                if arguments.depth.matches(requested, name)
+                    && !entries.iter().any(|(entry, _)| entry == name)
+                {
+                    found = true;
+                    if arguments
+                        .max_size
+                        .is_some_and(|max_size| value.len() > max_size as usize)
+                    {
+                        long_entries = long_entries.max(value.len());
+                    } else {
+                        entries.push((name.to_string(), Some(value.to_vec())));
+                    }
+                }
+            }
+
+            if !found && !entries.iter().any(|(entry, _)| entry == requested) {
+                entries.push((requested.clone(), None));
+            }
+        }
+
+        trc::event!(
+            Imap(trc::ImapEvent::GetMetadata),
+            SpanId = self.session_id,
+            MailboxName = arguments.mailbox_name.clone(),
+            Total = entries.len(),
+            Elapsed = op_start.elapsed()
+        );
+
+        let response = Response {
+            mailbox_name: arguments.mailbox_name,
+            entries,
+        }
+        .serialize();
+        let status = StatusResponse::ok("GETMETADATA successful.").with_tag(arguments.tag);
+
+        Ok(if long_entries > 0 {
+            status.with_code(ResponseCode::Metadata {
+                code: MetadataCode::LongEntries(long_entries as u32),
+            })
+        } else {
+            status
+        }
+        .serialize(response))
+    }
+
+    pub async fn set_metadata(&self, arguments: SetArguments) -> trc::Result<Vec<u8>> {
+        let op_start = Instant::now();
+
+        // Resolve mailbox, an empty name refers to server annotations
+        let mailbox = self
+            .metadata_mailbox(&arguments.tag, &arguments.mailbox_name)
+            .await?;
+        let (account_id, mailbox_id) = if let Some(mailbox) = &mailbox {
+            for (acl, is_private) in [(Acl::Read, true), (Acl::ModifyItems, false)] {
+                if arguments
+                    .entries
+                    .iter()
+                    .any(|(name, _)| is_private_entry(name) == is_private)
+                    && !self
+                        .check_mailbox_acl(mailbox.account_id, mailbox.mailbox_id, acl)
+                        .await
+                        .imap_ctx(&arguments.tag, trc::location!())?
+                {
+                    return Err(trc::ImapEvent::Error
+                        .into_err()
+                        .details("You do not have enough permissions to annotate this mailbox.")
+                        .code(ResponseCode::NoPerm)
+                        .id(arguments.tag));
+                }
+            }
+            (mailbox.account_id, Some(mailbox.mailbox_id))
+        } else {
+            (self.account_id, None)
+        };
+
+        // Special use attributes are managed through CREATE and JMAP
+        if mailbox_id.is_some()
+            && arguments
+                .entries
+                .iter()
+                .any(|(name, _)| name == SPECIAL_USE_ENTRY)
+        {
+            return Err(trc::ImapEvent::Error
+                .into_err()
+                .details("The special-use attribute of a mailbox cannot be modified.")
+                .code(ResponseCode::Cannot)
+                .id(arguments.tag));
+        }
+
+        let total = arguments.entries.len();
+        match self
+            .server
+            .mailbox_metadata_update(
+                account_id,
+                mailbox_id,
+                self.account_id,
+                arguments
+                    .entries
+                    .into_iter()
+                    .map(|(name, value)| MetadataUpdate { name, value })
+                    .collect(),
+            )
+            .await
+            .imap_ctx(&arguments.tag, trc::location!())?
+        {
+            Ok(()) => {
+                trc::event!(
+                    Imap(trc::ImapEvent::SetMetadata),
+                    SpanId = self.session_id,
+                    MailboxName = arguments.mailbox_name,
+                    Total = total,
+                    Elapsed = op_start.elapsed()
+                );
+
+                Ok(StatusResponse::ok("SETMETADATA successful.")
+                    .with_tag(arguments.tag)
+                    .into_bytes())
+            }
+            Err(MetadataError::MaxSize(max_size)) => Err(trc::ImapEvent::Error
+                .into_err()
+                .details("Annotation value is too large.")
+                .code(ResponseCode::Metadata {
+                    code: MetadataCode::MaxSize(max_size as u32),
+                })
+                .id(arguments.tag)),
+            Err(MetadataError::TooMany) => Err(trc::ImapEvent::Error
+                .into_err()
+                .details("Too many annotations.")
+                .code(ResponseCode::Metadata {
+                    code: MetadataCode::TooMany,
+                })
+                .id(arguments.tag)),
+        }
+    }
+
+    async fn metadata_mailbox(
+        &self,
+        tag: &str,
+        mailbox_name: &str,
+    ) -> trc::Result<Option<MailboxId>> {
+        if mailbox_name.is_empty() {
+            return Ok(None);
+        }
+
+        // Refresh mailboxes
+        self.synchronize_mailboxes(false)
+            .await
+            .imap_ctx(tag, trc::location!())?;
+
+        self.get_mailbox_by_name(mailbox_name)
+            .map(Some)
+            .ok_or_else(|| {
+                trc::ImapEvent::Error
+                    .into_err()
+                    .details("Mailbox does not exist.")
+                    .code(ResponseCode::NonExistent)
+                    .id(tag.to_string())
+            })
+    }
+}
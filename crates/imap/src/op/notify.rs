@@ -0,0 +1,332 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::{
+    core::{MailboxId, NotifySubscription, SelectedMailbox, Session, SessionData},
+    op::ImapContext,
+    spawn_op,
+};
+use common::{ipc::PushNotification, network::SessionStream};
+use email::mailbox::INBOX_ID;
+use imap_proto::{
+    Command, ResponseCode, StatusResponse,
+    protocol::{
+        Sequence, fetch,
+        list::{Attribute, ListItem},
+        notify::{EventGroup, Filter, Settings},
+        status::Status,
+    },
+    receiver::Request,
+};
+use registry::schema::enums::Permission;
+use std::{sync::Arc, time::Instant};
+use trc::AddContext;
+use types::type_state::DataType;
+use utils::map::bitmap::Bitmap;
+
+const STATUS_ITEMS: &[Status] = &[
+    Status::Messages,
+    Status::Unseen,
+    Status::UidNext,
+    Status::UidValidity,
+];
+
+impl<T: SessionStream> Session<T> {
+    pub async fn handle_notify(&mut self, request: Request<Command>) -> trc::Result<()> {
+        // Validate access
+        self.assert_has_permission(Permission::ImapNotify)?;
+
+        let op_start = Instant::now();
+        let arguments = request.parse_notify(self.is_utf8)?;
+        let (data, mailbox) = self.state.session_mailbox_state();
+
+        // NOTIFY NONE disables all notifications
+        let Some(settings) = arguments.settings else {
+            self.notify = None;
+
+            trc::event!(
+                Imap(trc::ImapEvent::Notify),
+                SpanId = self.session_id,
+                Total = 0,
+                Elapsed = op_start.elapsed()
+            );
+
+            return self
+                .write_bytes(
+                    StatusResponse::completed(Command::Notify)
+                        .with_tag(arguments.tag)
+                        .into_bytes(),
+                )
+                .await;
+        };
+
+        if settings
+            .groups
+            .iter()
+            .flat_map(|group| group.events.iter())
+            .any(|event| !event.is_supported())
+        {
+            return Err(trc::ImapEvent::Error
+                .into_err()
+                .details("One or more requested events are not supported.")
+                .code(ResponseCode::BadEvent)
+                .id(arguments.tag));
+        }
+
+        // Register with push manager
+        let push_rx = self
+            .server
+            .subscribe_push_manager(
+                &data.access_token,
+                Bitmap::from_iter([DataType::Email, DataType::Mailbox, DataType::EmailDelivery]),
+            )
+            .await
+            .imap_ctx(&arguments.tag, trc::location!())?;
+        let settings = Arc::new(settings);
+        self.notify = Some(NotifySubscription {
+            settings: settings.clone(),
+            push_rx,
+        });
+        let is_utf8 = self.is_utf8;
+
+        spawn_op!(data, {
+            let mut buf = Vec::with_capacity(64);
+
+            // Send the current status of all monitored mailboxes
+            if settings.status {
+                data.synchronize_mailboxes(false)
+                    .await
+                    .imap_ctx(&arguments.tag, trc::location!())?;
+
+                let selected_id = mailbox.as_ref().map(|mailbox| mailbox.id);
+                let mailbox_names = data
+                    .mailboxes
+                    .lock()
+                    .iter()
+                    .flat_map(|account| account.mailbox_names.keys().cloned())
+                    .collect::<Vec<_>>();
+                for mailbox_name in mailbox_names {
+                    if data
+                        .notify_group(&settings, &mailbox_name, selected_id)
+                        .is_some_and(|group| group.has_message_events())
+                        && let Ok(status) = data.status(mailbox_name, STATUS_ITEMS).await
+                    {
+                        status.serialize(&mut buf, is_utf8);
+                    }
+                }
+            }
+
+            trc::event!(
+                Imap(trc::ImapEvent::Notify),
+                SpanId = data.session_id,
+                Total = settings.groups.len(),
+                Elapsed = op_start.elapsed()
+            );
+
+            data.write_bytes(
+                StatusResponse::completed(Command::Notify)
+                    .with_tag(arguments.tag)
+                    .serialize(buf),
+            )
+            .await
+        })
+    }
+
+    pub async fn handle_notification(
+        &mut self,
+        push_notification: PushNotification,
+    ) -> trc::Result<()> {
+        let Some(settings) = self
+            .notify
+            .as_ref()
+            .map(|notify| notify.settings.clone())
+            .filter(|_| self.state.is_authenticated())
+        else {
+            return Ok(());
+        };
+
+        let mut has_mailbox_changes = false;
+        let mut has_email_changes = false;
+        match push_notification {
+            PushNotification::StateChange(state_change) => {
+                for type_state in state_change.types {
+                    match type_state {
+                        DataType::Email | DataType::EmailDelivery => {
+                            has_email_changes = true;
+                        }
+                        DataType::Mailbox => {
+                            has_mailbox_changes = true;
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            PushNotification::EmailPush(_) => {
+                has_email_changes = true;
+                has_mailbox_changes = true;
+            }
+            PushNotification::CalendarAlert(_) | PushNotification::SieveNotify(_) => (),
+        }
+
+        if has_mailbox_changes || has_email_changes {
+            let (data, mailbox) = self.state.session_mailbox_state();
+            data.write_notifications(
+                &settings,
+                &mailbox,
+                has_email_changes,
+                self.is_qresync,
+                self.version.is_rev2(),
+                self.is_utf8,
+            )
+            .await
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl<T: SessionStream> SessionData<T> {
+    pub async fn write_notifications(
+        &self,
+        settings: &Settings,
+        mailbox: &Option<Arc<SelectedMailbox>>,
+        has_email_changes: bool,
+        is_qresync: bool,
+        is_rev2: bool,
+        is_utf8: bool,
+    ) -> trc::Result<()> {
+        // Changes to the selected mailbox are reported as EXPUNGE, EXISTS and FETCH responses.
+        // SELECTED-DELAYED is not reported until the client issues a command.
+        if has_email_changes
+            && let Some(mailbox) = mailbox
+            && let Some(group) = settings
+                .selected()
+                .filter(|group| group.filter == Filter::Selected && group.has_message_events())
+        {
+            let uid_max = mailbox.state.lock().uid_max;
+            self.write_changes(
+                &Some(mailbox.clone()),
+                false,
+                true,
+                is_qresync,
+                is_rev2,
+                is_utf8,
+            )
+            .await?;
+
+            // Send the attributes requested for new messages
+            if let Some(attributes) = group.message_new_attributes()
+                && mailbox.state.lock().uid_max > uid_max
+            {
+                self.fetch(
+                    fetch::Arguments {
+                        tag: "".into(),
+                        sequence_set: Sequence::Range {
+                            start: Some(uid_max + 1),
+                            end: None,
+                        },
+                        attributes: attributes.to_vec(),
+                        changed_since: None,
+                        include_vanished: false,
+                        partial: None,
+                    },
+                    mailbox.clone(),
+                    true,
+                    is_qresync,
+                    false,
+                    Instant::now(),
+                )
+                .await
+                .caused_by(trc::location!())?;
+            }
+        }
+
+        // Changes to other mailboxes are reported as STATUS and LIST responses
+        if !settings.has_mailbox_filters() {
+            return Ok(());
+        }
+        let changes = self
+            .synchronize_mailboxes(true)
+            .await
+            .caused_by(trc::location!())?
+            .unwrap();
+        let selected_id = mailbox.as_ref().map(|mailbox| mailbox.id);
+        let mut buf = Vec::with_capacity(64);
+
+        for (mailbox_names, attributes) in [
+            (changes.deleted, vec![Attribute::NonExistent]),
+            (changes.added, vec![]),
+        ] {
+            for mailbox_name in mailbox_names {
+                if self
+                    .notify_group(settings, &mailbox_name, selected_id)
+                    .is_some_and(|group| group.has_mailbox_name_events())
+                {
+                    ListItem {
+                        mailbox_name,
+                        attributes: attributes.clone(),
+                        tags: vec![],
+                    }
+                    .serialize(&mut buf, is_rev2, is_utf8, false);
+                }
+            }
+        }
+
+        for mailbox_name in changes.changed {
+            if self
+                .notify_group(settings, &mailbox_name, selected_id)
+                .is_some_and(|group| group.has_message_events())
+                && let Ok(status) = self.status(mailbox_name, STATUS_ITEMS).await
+            {
+                status.serialize(&mut buf, is_utf8);
+            }
+        }
+
+        if !buf.is_empty() {
+            self.write_bytes(buf).await
+        } else {
+            Ok(())
+        }
+    }
+
+    fn notify_group<'x>(
+        &self,
+        settings: &'x Settings,
+        mailbox_name: &str,
+        selected_id: Option<MailboxId>,
+    ) -> Option<&'x EventGroup> {
+        let mailbox_id = self.get_mailbox_by_name(mailbox_name);
+
+        // The selected mailbox is only reported through the SELECTED filters
+        if mailbox_id.is_some() && mailbox_id == selected_id {
+            return None;
+        }
+
+        let shared_folder = &self.server.core.email.shared_folder;
+        let is_personal = mailbox_name != shared_folder
+            && !mailbox_name
+                .strip_prefix(shared_folder.as_str())
+                .is_some_and(|name| name.starts_with('/'));
+
+        settings.groups.iter().find(|group| match &group.filter {
+            Filter::Selected | Filter::SelectedDelayed => false,
+            Filter::Inboxes => mailbox_id.is_some_and(|mailbox_id| {
+                mailbox_id.account_id == self.account_id && mailbox_id.mailbox_id == INBOX_ID
+            }),
+            Filter::Personal => is_personal,
+            Filter::Subscribed => mailbox_id
+                .and_then(|mailbox_id| self.mailbox_state(&mailbox_id))
+                .is_some_and(|state| state.is_subscribed),
+            Filter::Subtree(names) => names.iter().any(|name| {
+                mailbox_name == name
+                    || mailbox_name
+                        .strip_prefix(name.as_str())
+                        .is_some_and(|name| name.starts_with('/'))
+            }),
+            Filter::Mailboxes(names) => names.iter().any(|name| name == mailbox_name),
+        })
+    }
+}
@@ -9,8 +9,12 @@ use crate::{
     core::{ImapId, SavedSearch, SelectedMailbox, Session, SessionData},
     spawn_op,
 };
-use common::network::SessionStream;
-use email::cache::{MessageCacheFetch, email::MessageCacheAccess};
+use ahash::AHashMap;
+use common::{MessageStoreCache, network::SessionStream};
+use email::{
+    cache::{MessageCacheFetch, email::MessageCacheAccess},
+    mailbox::save_date::MailboxSaveDate,
+};
 use imap_proto::{
     Command, StatusResponse,
     protocol::{
@@ -185,6 +189,13 @@ impl<T: SessionStream> SessionData<T> {
         );
 
         // Build response
+        let partial = arguments
+            .result_options
+            .iter()
+            .find_map(|option| match option {
+                ResultOption::Partial(partial) => Some(*partial),
+                _ => None,
+            });
         Ok(Response {
             is_uid,
             min: min.map(|(id, _)| id),
@@ -194,13 +205,16 @@ impl<T: SessionStream> SessionData<T> {
             } else {
                 None
             },
-            ids: if arguments.result_options.is_empty()
+            ids: if let Some(partial) = &partial {
+                partial.apply(&imap_ids).to_vec()
+            } else if arguments.result_options.is_empty()
                 || arguments.result_options.contains(&ResultOption::All)
             {
                 imap_ids
             } else {
                 vec![]
             },
+            partial,
             is_sort,
             is_esearch: arguments.is_esearch,
             highest_modseq,
@@ -229,6 +243,7 @@ impl<T: SessionStream> SessionData<T> {
 
         // Convert query
         let mut include_highest_modseq = false;
+        let mut save_dates = None;
         for filter in imap_filter {
             match filter {
                 Filter::Sequence(sequence, uid_filter) => {
@@ -438,6 +453,39 @@ impl<T: SessionStream> SessionData<T> {
                             .details(format!("Failed to parse thread id '{id}'.",)));
                     }
                 }
+                Filter::SavedBefore(date) => {
+                    self.save_date_filter(
+                        &mut filters,
+                        &mut save_dates,
+                        &cache,
+                        mailbox,
+                        (None, Some(date)),
+                    )
+                    .await?;
+                }
+                Filter::SavedOn(date) => {
+                    self.save_date_filter(
+                        &mut filters,
+                        &mut save_dates,
+                        &cache,
+                        mailbox,
+                        (Some(date), Some(date + 86400)),
+                    )
+                    .await?;
+                }
+                Filter::SavedSince(date) => {
+                    self.save_date_filter(
+                        &mut filters,
+                        &mut save_dates,
+                        &cache,
+                        mailbox,
+                        (Some(date), None),
+                    )
+                    .await?;
+                }
+                Filter::SaveDateSupported => {
+                    filters.push(SearchFilter::is_in_set(message_ids.clone()));
+                }
                 Filter::Bcc(text) => {
                     filters.push(SearchFilter::has_text(
                         EmailSearchField::Bcc,
@@ -633,6 +681,58 @@ impl<T: SessionStream> SessionData<T> {
             .map(|res| (res, include_highest_modseq))
             .caused_by(trc::location!())
     }
+
+    async fn save_date_filter(
+        &self,
+        filters: &mut Vec<SearchFilter>,
+        save_dates: &mut Option<AHashMap<u32, u64>>,
+        cache: &MessageStoreCache,
+        mailbox: &SelectedMailbox,
+        (from, to): (Option<i64>, Option<i64>),
+    ) -> trc::Result<()> {
+        let save_dates = match save_dates {
+            Some(save_dates) => save_dates,
+            None => save_dates.insert(
+                self.server
+                    .save_dates(mailbox.id.account_id, mailbox.id.mailbox_id, 0..=u32::MAX)
+                    .await
+                    .caused_by(trc::location!())?,
+            ),
+        };
+
+        // Messages without a save date are matched by their internal date
+        let mut saved_ids = RoaringBitmap::new();
+        let mut undated_ids = RoaringBitmap::new();
+        for message in cache.in_mailbox(mailbox.id.mailbox_id) {
+            let save_date = message
+                .mailboxes
+                .iter()
+                .find(|m| m.mailbox_id == mailbox.id.mailbox_id)
+                .and_then(|m| save_dates.get(&m.uid));
+            if let Some(save_date) = save_date.map(|date| *date as i64) {
+                if from.is_none_or(|from| save_date >= from) && to.is_none_or(|to| save_date < to) {
+                    saved_ids.insert(message.document_id);
+                }
+            } else {
+                undated_ids.insert(message.document_id);
+            }
+        }
+
+        filters.push(SearchFilter::Or);
+        filters.push(SearchFilter::is_in_set(saved_ids));
+        filters.push(SearchFilter::And);
+        filters.push(SearchFilter::is_in_set(undated_ids));
+        if let Some(from) = from {
+            filters.push(SearchFilter::ge(EmailSearchField::ReceivedAt, from));
+        }
+        if let Some(to) = to {
+            filters.push(SearchFilter::lt(EmailSearchField::ReceivedAt, to));
+        }
+        filters.push(SearchFilter::End);
+        filters.push(SearchFilter::End);
+
+        Ok(())
+    }
 }
 
 impl SelectedMailbox {
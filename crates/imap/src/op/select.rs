@@ -68,6 +68,7 @@ impl<T: SessionStream> Session<T> {
                 saved_search: parking_lot::Mutex::new(SavedSearch::None),
                 is_select,
                 is_condstore,
+                is_uid_only: self.is_uid_only,
             });
 
             // Validate QRESYNC arguments
@@ -93,6 +94,7 @@ impl<T: SessionStream> Session<T> {
                             attributes: vec![fetch::Attribute::Flags],
                             changed_since: qresync.modseq.into(),
                             include_vanished: true,
+                            partial: None,
                         },
                         mailbox.clone(),
                         true,
@@ -13,7 +13,11 @@ use ahash::AHashSet;
 use common::{network::SessionStream, storage::index::ObjectIndexBuilder};
 use email::{
     mailbox::TRASH_ID,
-    message::{ingest::EmailIngest, metadata::MessageData},
+    message::{
+        ingest::EmailIngest,
+        metadata::MessageData,
+        snooze::{EmailSnooze, SnoozedEmail},
+    },
 };
 use imap_proto::{
     Command, ResponseCode, ResponseType, StatusResponse,
@@ -184,16 +188,40 @@ impl<T: SessionStream> SessionData<T> {
         }
         let mut items = Response {
             items: Vec::with_capacity(ids.len()),
+            is_uid_only: mailbox.is_uid_only,
         };
 
+        // Snooze keywords are not stored, they set or clear the snooze date instead
+        let mut set_keywords = Vec::with_capacity(arguments.keywords.len());
+        let mut snoozed_until = None;
+        for keyword in arguments.keywords.iter().map(|k| Keyword::from(k.clone())) {
+            match &keyword {
+                Keyword::Other(name) if SnoozedEmail::is_snooze_keyword(name) => {
+                    snoozed_until = match arguments.operation {
+                        Operation::Set | Operation::Add => Some(
+                            SnoozedEmail::parse_keyword(name)
+                                .ok_or_else(|| {
+                                    trc::ImapEvent::Error
+                                        .into_err()
+                                        .details("Invalid snooze date.")
+                                        .code(ResponseCode::Parse)
+                                })
+                                .imap_ctx(response.tag.as_ref().unwrap(), trc::location!())?,
+                        ),
+                        Operation::Clear => None,
+                    }
+                    .into();
+                }
+                _ => {
+                    set_keywords.push(keyword);
+                }
+            }
+        }
+
         // Process each change
-        let set_keywords = arguments
-            .keywords
-            .iter()
-            .map(|k| Keyword::from(k.clone()))
-            .collect::<Vec<_>>();
         let mut changed_mailboxes = AHashSet::new();
         let mut batch = BatchBuilder::new();
+        let mut has_snoozed = false;
 
         for (id, imap_id) in &ids {
             // Obtain message data
@@ -243,7 +271,7 @@ impl<T: SessionStream> SessionData<T> {
                 }
             }
 
-            if !new_data.has_keyword_changes(data.inner) {
+            if !new_data.has_keyword_changes(data.inner) && snoozed_until.is_none() {
                 continue;
             }
 
@@ -270,14 +298,27 @@ impl<T: SessionStream> SessionData<T> {
                 }
             }
 
-            // Convert keywords to flags
+            // Convert keywords to flags, including the snooze pseudo-keyword
             let flags = if !arguments.is_silent {
-                new_data
+                let mut flags = new_data
                     .keywords
                     .iter()
                     .cloned()
                     .map(Flag::from)
-                    .collect::<Vec<_>>()
+                    .collect::<Vec<_>>();
+                let snoozed = match snoozed_until {
+                    Some(until) => until,
+                    None => self
+                        .server
+                        .email_snoozed(account_id, *id)
+                        .await
+                        .imap_ctx(response.tag.as_ref().unwrap(), trc::location!())?
+                        .map(|snoozed| snoozed.until),
+                };
+                if let Some(until) = snoozed {
+                    flags.push(Flag::Keyword(SnoozedEmail::keyword(until).into()));
+                }
+                flags
             } else {
                 vec![]
             };
@@ -289,6 +330,31 @@ impl<T: SessionStream> SessionData<T> {
                 }
             }
 
+            // Set or clear the snooze date
+            if let Some(snoozed_until) = snoozed_until {
+                if let Some(until) = snoozed_until {
+                    let mailbox_ids = [mailbox.id.mailbox_id]
+                        .into_iter()
+                        .chain(
+                            data.inner
+                                .mailboxes
+                                .iter()
+                                .map(|m| m.mailbox_id.to_native()),
+                        )
+                        .collect::<Vec<_>>();
+                    let mailbox_id = self
+                        .server
+                        .email_snooze_origin(account_id, *id, &mailbox_ids)
+                        .await
+                        .imap_ctx(response.tag.as_ref().unwrap(), trc::location!())?;
+
+                    SnoozedEmail::new(until, mailbox_id).write(&mut batch, account_id, *id);
+                    has_snoozed = true;
+                } else {
+                    SnoozedEmail::clear(&mut batch, account_id, *id);
+                }
+            }
+
             // Write changes
             batch
                 .with_account_id(account_id)
@@ -325,12 +391,20 @@ impl<T: SessionStream> SessionData<T> {
                     data_items.push(DataItem::Uid { uid: imap_id.uid });
                 }
                 items.items.push(FetchItem {
-                    id: imap_id.seqnum,
+                    id: if mailbox.is_uid_only {
+                        imap_id.uid
+                    } else {
+                        imap_id.seqnum
+                    },
                     items: data_items,
                 });
             } else if is_condstore {
                 items.items.push(FetchItem {
-                    id: imap_id.seqnum,
+                    id: if mailbox.is_uid_only {
+                        imap_id.uid
+                    } else {
+                        imap_id.seqnum
+                    },
                     items: if is_uid {
                         vec![DataItem::Uid { uid: imap_id.uid }]
                     } else {
@@ -357,6 +431,9 @@ impl<T: SessionStream> SessionData<T> {
                 .caused_by(trc::location!())
             {
                 Ok(change_id) => {
+                    if has_snoozed {
+                        self.server.notify_task_queue();
+                    }
                     if is_condstore {
                         let modseq = change_id + 1;
                         for item in items.items.iter_mut() {
@@ -22,6 +22,7 @@ pub mod parse;
 pub mod query;
 pub mod query_changes;
 pub mod search_snippet;
+pub mod send;
 pub mod set;
 pub mod upload;
 pub mod validate;
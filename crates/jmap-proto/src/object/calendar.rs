@@ -39,6 +39,8 @@ pub enum CalendarProperty {
     TimeZone,
     ShareWith,
     MyRights,
+    Publish,
+    PublishUrl,
 
     // Alert object properties
     When,
@@ -118,6 +120,8 @@ impl Property for CalendarProperty {
             CalendarProperty::TimeZone => "timeZone",
             CalendarProperty::ShareWith => "shareWith",
             CalendarProperty::MyRights => "myRights",
+            CalendarProperty::Publish => "publish",
+            CalendarProperty::PublishUrl => "publishUrl",
             CalendarProperty::When => "when",
             CalendarProperty::Trigger => "trigger",
             CalendarProperty::Offset => "offset",
@@ -229,6 +233,8 @@ impl CalendarProperty {
             b"timeZone" => CalendarProperty::TimeZone,
             b"shareWith" => CalendarProperty::ShareWith,
             b"myRights" => CalendarProperty::MyRights,
+            b"publish" => CalendarProperty::Publish,
+            b"publishUrl" => CalendarProperty::PublishUrl,
             b"mayReadFreeBusy" => CalendarProperty::Rights(CalendarRight::MayReadFreeBusy),
             b"mayReadItems" => CalendarProperty::Rights(CalendarRight::MayReadItems),
             b"mayWriteAll" => CalendarProperty::Rights(CalendarRight::MayWriteAll),
@@ -29,6 +29,7 @@ pub enum EmailProperty {
     Keywords,
     Size,
     ReceivedAt,
+    SnoozedUntil,
 
     // Address
     Name,
@@ -156,6 +157,7 @@ impl Property for EmailProperty {
             EmailProperty::ReplyTo => "replyTo",
             EmailProperty::Sender => "sender",
             EmailProperty::SentAt => "sentAt",
+            EmailProperty::SnoozedUntil => "snoozedUntil",
             EmailProperty::Size => "size",
             EmailProperty::Subject => "subject",
             EmailProperty::SubParts => "subParts",
@@ -200,7 +202,10 @@ impl Element for EmailValue {
                     ..
                 })
                 | EmailProperty::ReceivedAt
-                | EmailProperty::SentAt => UTCDate::from_str(value).ok().map(EmailValue::Date),
+                | EmailProperty::SentAt
+                | EmailProperty::SnoozedUntil => {
+                    UTCDate::from_str(value).ok().map(EmailValue::Date)
+                }
                 _ => None,
             }
         } else {
@@ -228,6 +233,7 @@ impl EmailProperty {
                 "keywords" => EmailProperty::Keywords,
                 "size" => EmailProperty::Size,
                 "receivedAt" => EmailProperty::ReceivedAt,
+                "snoozedUntil" => EmailProperty::SnoozedUntil,
                 "name" => EmailProperty::Name,
                 "email" => EmailProperty::Email,
                 "addresses" => EmailProperty::Addresses,
@@ -0,0 +1,196 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::object::{AnyId, JmapObject, JmapObjectId, MaybeReference, parse_ref};
+use jmap_tools::{Element, Key, Property};
+use std::{borrow::Cow, str::FromStr};
+use types::id::Id;
+
+#[derive(Debug, Clone, Default)]
+pub struct Mdn;
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MdnProperty {
+    ForEmailId,
+    Subject,
+    TextBody,
+    IncludeOriginalMessage,
+    ReportingUa,
+    Disposition,
+    ActionMode,
+    SendingMode,
+    Type,
+    MdnGateway,
+    OriginalRecipient,
+    FinalRecipient,
+    OriginalMessageId,
+    Error,
+    ExtensionFields,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MdnValue {
+    Id(Id),
+    IdReference(String),
+}
+
+impl Property for MdnProperty {
+    fn try_parse(_: Option<&Key<'_, Self>>, value: &str) -> Option<Self> {
+        MdnProperty::parse(value)
+    }
+
+    fn to_cow(&self) -> Cow<'static, str> {
+        match self {
+            MdnProperty::ForEmailId => "forEmailId",
+            MdnProperty::Subject => "subject",
+            MdnProperty::TextBody => "textBody",
+            MdnProperty::IncludeOriginalMessage => "includeOriginalMessage",
+            MdnProperty::ReportingUa => "reportingUA",
+            MdnProperty::Disposition => "disposition",
+            MdnProperty::ActionMode => "actionMode",
+            MdnProperty::SendingMode => "sendingMode",
+            MdnProperty::Type => "type",
+            MdnProperty::MdnGateway => "mdnGateway",
+            MdnProperty::OriginalRecipient => "originalRecipient",
+            MdnProperty::FinalRecipient => "finalRecipient",
+            MdnProperty::OriginalMessageId => "originalMessageId",
+            MdnProperty::Error => "error",
+            MdnProperty::ExtensionFields => "extensionFields",
+        }
+        .into()
+    }
+}
+
+impl Element for MdnValue {
+    type Property = MdnProperty;
+
+    fn try_parse<P>(key: &Key<'_, Self::Property>, value: &str) -> Option<Self> {
+        if let Key::Property(MdnProperty::ForEmailId) = key {
+            match parse_ref(value) {
+                MaybeReference::Value(v) => Some(MdnValue::Id(v)),
+                MaybeReference::Reference(v) => Some(MdnValue::IdReference(v)),
+                MaybeReference::ParseError => None,
+            }
+        } else {
+            None
+        }
+    }
+
+    fn to_cow(&self) -> Cow<'static, str> {
+        match self {
+            MdnValue::Id(id) => id.to_string().into(),
+            MdnValue::IdReference(r) => format!("#{r}").into(),
+        }
+    }
+}
+
+impl MdnProperty {
+    fn parse(value: &str) -> Option<Self> {
+        hashify::tiny_map!(value.as_bytes(),
+            b"forEmailId" => MdnProperty::ForEmailId,
+            b"subject" => MdnProperty::Subject,
+            b"textBody" => MdnProperty::TextBody,
+            b"includeOriginalMessage" => MdnProperty::IncludeOriginalMessage,
+            b"reportingUA" => MdnProperty::ReportingUa,
+            b"disposition" => MdnProperty::Disposition,
+            b"actionMode" => MdnProperty::ActionMode,
+            b"sendingMode" => MdnProperty::SendingMode,
+            b"type" => MdnProperty::Type,
+            b"mdnGateway" => MdnProperty::MdnGateway,
+            b"originalRecipient" => MdnProperty::OriginalRecipient,
+            b"finalRecipient" => MdnProperty::FinalRecipient,
+            b"originalMessageId" => MdnProperty::OriginalMessageId,
+            b"error" => MdnProperty::Error,
+            b"extensionFields" => MdnProperty::ExtensionFields,
+        )
+    }
+}
+
+impl FromStr for MdnProperty {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        MdnProperty::parse(s).ok_or(())
+    }
+}
+
+impl JmapObject for Mdn {
+    type Property = MdnProperty;
+
+    type Element = MdnValue;
+
+    type Id = Id;
+
+    type Filter = ();
+
+    type Comparator = ();
+
+    type GetArguments = ();
+
+    type SetArguments<'de> = ();
+
+    type QueryArguments = ();
+
+    type CopyArguments = ();
+
+    type ParseArguments = ();
+
+    const ID_PROPERTY: Self::Property = MdnProperty::ForEmailId;
+}
+
+impl From<Id> for MdnValue {
+    fn from(id: Id) -> Self {
+        MdnValue::Id(id)
+    }
+}
+
+impl JmapObjectId for MdnValue {
+    fn as_id(&self) -> Option<Id> {
+        match self {
+            MdnValue::Id(id) => Some(*id),
+            MdnValue::IdReference(_) => None,
+        }
+    }
+
+    fn as_any_id(&self) -> Option<AnyId> {
+        self.as_id().map(AnyId::Id)
+    }
+
+    fn as_id_ref(&self) -> Option<&str> {
+        if let MdnValue::IdReference(r) = self {
+            Some(r)
+        } else {
+            None
+        }
+    }
+
+    fn try_set_id(&mut self, new_id: AnyId) -> bool {
+        if let AnyId::Id(id) = new_id {
+            *self = MdnValue::Id(id);
+            true
+        } else {
+            false
+        }
+    }
+}
+
+impl JmapObjectId for MdnProperty {
+    fn as_id(&self) -> Option<Id> {
+        None
+    }
+
+    fn as_any_id(&self) -> Option<AnyId> {
+        None
+    }
+
+    fn as_id_ref(&self) -> Option<&str> {
+        None
+    }
+
+    fn try_set_id(&mut self, _: AnyId) -> bool {
+        false
+    }
+}
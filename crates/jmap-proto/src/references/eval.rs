@@ -81,6 +81,10 @@ impl Response<'_> {
                         GetResponseMethod::ShareNotification(response) => {
                             response.eval_jptr(path, &mut results)
                         }
+                        GetResponseMethod::TaskList(response) => {
+                            response.eval_jptr(path, &mut results)
+                        }
+                        GetResponseMethod::Task(response) => response.eval_jptr(path, &mut results),
                         GetResponseMethod::PrincipalAvailability(response) => {
                             response.eval_jptr(path, &mut results)
                         }
@@ -128,6 +132,12 @@ impl Response<'_> {
                         ChangesResponseMethod::ShareNotification(response) => {
                             response.eval_jptr(path, &mut results)
                         }
+                        ChangesResponseMethod::TaskList(response) => {
+                            response.eval_jptr(path, &mut results)
+                        }
+                        ChangesResponseMethod::Task(response) => {
+                            response.eval_jptr(path, &mut results)
+                        }
                     },
                     ResponseMethod::Query(response) => response.eval_jptr(path, &mut results),
                     ResponseMethod::QueryChanges(response) => {
@@ -12,6 +12,7 @@ use crate::{
         import::ImportEmailRequest,
         parse::ParseRequest,
         search_snippet::GetSearchSnippetRequest,
+        send::MdnSendRequest,
         set::{SetRequest, SetResponse},
         upload::{BlobUploadRequest, DataSourceObject},
     },
@@ -56,6 +57,8 @@ impl Response<'_> {
                 GetRequestMethod::ParticipantIdentity(request) => {
                     request.resolve_references(self)?
                 }
+                GetRequestMethod::TaskList(request) => request.resolve_references(self)?,
+                GetRequestMethod::Task(request) => request.resolve_references(self)?,
                 GetRequestMethod::PrincipalAvailability(_) => (),
                 GetRequestMethod::Registry(request) => request.resolve_references(self)?,
             },
@@ -99,6 +102,10 @@ impl Response<'_> {
                 SetRequestMethod::ParticipantIdentity(request) => {
                     request.resolve_references(self, 1, false)?
                 }
+                SetRequestMethod::TaskList(request) => {
+                    request.resolve_references(self, 1, false)?
+                }
+                SetRequestMethod::Task(request) => request.resolve_references(self, 1, false)?,
                 SetRequestMethod::Registry(request) => request.resolve_references(self, 5, true)?,
             },
             RequestMethod::Copy(request) => match request {
@@ -114,10 +121,12 @@ impl Response<'_> {
             RequestMethod::ImportEmail(request) => request.resolve_references(self)?,
             RequestMethod::SearchSnippet(request) => request.resolve_references(self)?,
             RequestMethod::UploadBlob(request) => request.resolve_references(self)?,
+            RequestMethod::SendMdn(request) => request.resolve_references(self)?,
             RequestMethod::Parse(request) => match request {
                 ParseRequestMethod::Email(request) => request.resolve_references(self)?,
                 ParseRequestMethod::ContactCard(request) => request.resolve_references(self)?,
                 ParseRequestMethod::CalendarEvent(request) => request.resolve_references(self)?,
+                ParseRequestMethod::Mdn(request) => request.resolve_references(self)?,
             },
             _ => {}
         }
@@ -359,6 +368,24 @@ impl ResolveReference for ImportEmailRequest {
     }
 }
 
+impl ResolveReference for MdnSendRequest<'_> {
+    fn resolve_references(&mut self, response: &Response<'_>) -> trc::Result<()> {
+        // Resolve forEmailId references
+        for obj in self.send.values_mut() {
+            obj.eval_object_references(response, &mut Graph::None, 0, 1, false)?;
+        }
+
+        // Resolve email patch references
+        if let Some(update) = &mut self.on_success_update_email {
+            for obj in update.values_mut() {
+                obj.eval_object_references(response, &mut Graph::None, 0, 1, false)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
 impl ResolveReference for GetSearchSnippetRequest {
     fn resolve_references(&mut self, response: &Response<'_>) -> trc::Result<()> {
         // Resolve emailIds references
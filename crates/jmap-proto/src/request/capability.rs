@@ -91,6 +91,10 @@ pub enum Capability {
     MailShare = 1 << 16,
     #[serde(rename(serialize = "urn:stalwart:jmap"))]
     Stalwart = 1 << 17,
+    #[serde(rename(serialize = "urn:ietf:params:jmap:mdn"))]
+    Mdn = 1 << 18,
+    #[serde(rename(serialize = "urn:ietf:params:jmap:tasks"))]
+    Tasks = 1 << 19,
 }
 
 #[derive(Debug, Clone, Copy, Default)]
@@ -112,6 +116,7 @@ pub enum Capabilities {
     Principals(PrincipalCapabilities),
     PrincipalsAvailability(PrincipalAvailabilityCapabilities),
     Calendar(CalendarCapabilities),
+    Tasks(TasksCapabilities),
     FileNode(FileNodeCapabilities),
     Empty(EmptyCapabilities),
 }
@@ -220,6 +225,16 @@ pub struct CalendarCapabilities {
     pub may_create_calendar: bool,
 }
 
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct TasksCapabilities {
+    #[serde(rename(serialize = "minDateTime"))]
+    pub min_date_time: UTCDate,
+    #[serde(rename(serialize = "maxDateTime"))]
+    pub max_date_time: UTCDate,
+    #[serde(rename(serialize = "mayCreateTaskList"))]
+    pub may_create_task_list: bool,
+}
+
 #[derive(Debug, Clone, serde::Serialize)]
 pub struct ContactsCapabilities {
     #[serde(rename(serialize = "maxAddressBooksPerCard"))]
@@ -303,6 +318,8 @@ impl Capability {
             Capability::FileNode => "urn:ietf:params:jmap:filenode",
             Capability::MailShare => "urn:ietf:params:jmap:mail:share",
             Capability::Stalwart => "urn:stalwart:jmap",
+            Capability::Mdn => "urn:ietf:params:jmap:mdn",
+            Capability::Tasks => "urn:ietf:params:jmap:tasks",
         }
     }
 
@@ -325,6 +342,8 @@ impl Capability {
             Capability::FileNode,
             Capability::MailShare,
             Capability::Stalwart,
+            Capability::Mdn,
+            Capability::Tasks,
         ]
     }
 }
@@ -413,6 +432,10 @@ impl Capabilities {
                     ..calendar_capabilities.clone()
                 })
             }
+            Capabilities::Tasks(tasks_capabilities) => Capabilities::Tasks(TasksCapabilities {
+                may_create_task_list: may_create,
+                ..tasks_capabilities.clone()
+            }),
             Capabilities::FileNode(file_node_capabilities) => {
                 Capabilities::FileNode(FileNodeCapabilities {
                     may_create_top_level_file_node: may_create,
@@ -445,6 +468,8 @@ impl Capability {
             "urn:ietf:params:jmap:calendars:parse" => Capability::CalendarsParse,
             "urn:ietf:params:jmap:mail:share" => Capability::MailShare,
             "urn:stalwart:jmap" => Capability::Stalwart,
+            "urn:ietf:params:jmap:mdn" => Capability::Mdn,
+            "urn:ietf:params:jmap:tasks" => Capability::Tasks,
         )
     }
 }
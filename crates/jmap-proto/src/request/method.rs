@@ -39,6 +39,9 @@ pub enum MethodObject {
     FileNode,
     ParticipantIdentity,
     ShareNotification,
+    Mdn,
+    TaskList,
+    Task,
     Registry(ObjectType),
 }
 
@@ -57,6 +60,7 @@ pub enum MethodFunction {
     Upload,
     Echo,
     GetAvailability,
+    Send,
 }
 
 impl Display for MethodName {
@@ -185,6 +189,16 @@ impl MethodName {
             (MethodFunction::Copy, MethodObject::CalendarEvent) => "CalendarEvent/copy",
             (MethodFunction::Parse, MethodObject::CalendarEvent) => "CalendarEvent/parse",
 
+            (MethodFunction::Get, MethodObject::TaskList) => "TaskList/get",
+            (MethodFunction::Changes, MethodObject::TaskList) => "TaskList/changes",
+            (MethodFunction::Set, MethodObject::TaskList) => "TaskList/set",
+
+            (MethodFunction::Get, MethodObject::Task) => "Task/get",
+            (MethodFunction::Changes, MethodObject::Task) => "Task/changes",
+            (MethodFunction::Query, MethodObject::Task) => "Task/query",
+            (MethodFunction::QueryChanges, MethodObject::Task) => "Task/queryChanges",
+            (MethodFunction::Set, MethodObject::Task) => "Task/set",
+
             (MethodFunction::Get, MethodObject::CalendarEventNotification) => {
                 "CalendarEventNotification/get"
             }
@@ -207,6 +221,9 @@ impl MethodName {
             }
             (MethodFunction::Set, MethodObject::ParticipantIdentity) => "ParticipantIdentity/set",
 
+            (MethodFunction::Send, MethodObject::Mdn) => "MDN/send",
+            (MethodFunction::Parse, MethodObject::Mdn) => "MDN/parse",
+
             (MethodFunction::Echo, MethodObject::Core) => "Core/echo",
             (method, MethodObject::Registry(obj)) => {
                 return Cow::Owned(format!("x:{}/{}", obj.as_str(), method.as_str()));
@@ -314,6 +331,16 @@ impl MethodName {
             "CalendarEvent/copy" => (MethodObject::CalendarEvent, MethodFunction::Copy),
             "CalendarEvent/parse" => (MethodObject::CalendarEvent, MethodFunction::Parse),
 
+            "TaskList/get" => (MethodObject::TaskList, MethodFunction::Get),
+            "TaskList/changes" => (MethodObject::TaskList, MethodFunction::Changes),
+            "TaskList/set" => (MethodObject::TaskList, MethodFunction::Set),
+
+            "Task/get" => (MethodObject::Task, MethodFunction::Get),
+            "Task/changes" => (MethodObject::Task, MethodFunction::Changes),
+            "Task/query" => (MethodObject::Task, MethodFunction::Query),
+            "Task/queryChanges" => (MethodObject::Task, MethodFunction::QueryChanges),
+            "Task/set" => (MethodObject::Task, MethodFunction::Set),
+
             "CalendarEventNotification/get" => (MethodObject::CalendarEventNotification, MethodFunction::Get),
             "CalendarEventNotification/changes" => (MethodObject::CalendarEventNotification, MethodFunction::Changes),
             "CalendarEventNotification/set" => (MethodObject::CalendarEventNotification, MethodFunction::Set),
@@ -324,6 +351,9 @@ impl MethodName {
             "ParticipantIdentity/changes" => (MethodObject::ParticipantIdentity, MethodFunction::Changes),
             "ParticipantIdentity/set" => (MethodObject::ParticipantIdentity, MethodFunction::Set),
 
+            "MDN/send" => (MethodObject::Mdn, MethodFunction::Send),
+            "MDN/parse" => (MethodObject::Mdn, MethodFunction::Parse),
+
             "Core/echo" => (MethodObject::Core, MethodFunction::Echo),
 
         ).or_else(|| {
@@ -368,6 +398,9 @@ impl Display for MethodObject {
             MethodObject::CalendarEvent => "CalendarEvent",
             MethodObject::CalendarEventNotification => "CalendarEventNotification",
             MethodObject::ShareNotification => "ShareNotification",
+            MethodObject::Mdn => "MDN",
+            MethodObject::TaskList => "TaskList",
+            MethodObject::Task => "Task",
             MethodObject::Registry(obj) => {
                 f.write_str("x:")?;
                 return f.write_str(obj.as_str());
@@ -392,6 +425,7 @@ impl MethodFunction {
             MethodFunction::Upload => "upload",
             MethodFunction::Echo => "echo",
             MethodFunction::GetAvailability => "getAvailability",
+            MethodFunction::Send => "send",
         }
     }
 }
@@ -24,6 +24,7 @@ use crate::{
         query::QueryRequest,
         query_changes::QueryChangesRequest,
         search_snippet::GetSearchSnippetRequest,
+        send::MdnSendRequest,
         set::SetRequest,
         upload::BlobUploadRequest,
         validate::ValidateSieveScriptRequest,
@@ -32,10 +33,10 @@ use crate::{
         AnyId, addressbook::AddressBook, blob::Blob, calendar::Calendar,
         calendar_event::CalendarEvent, calendar_event_notification::CalendarEventNotification,
         contact::ContactCard, email::Email, email_submission::EmailSubmission, file_node::FileNode,
-        identity::Identity, mailbox::Mailbox, participant_identity::ParticipantIdentity,
+        identity::Identity, mailbox::Mailbox, mdn::Mdn, participant_identity::ParticipantIdentity,
         principal::Principal, push_subscription::PushSubscription, quota::Quota,
-        registry::Registry, share_notification::ShareNotification, sieve::Sieve, thread::Thread,
-        vacation_response::VacationResponse,
+        registry::Registry, share_notification::ShareNotification, sieve::Sieve, task::Task,
+        task_list::TaskList, thread::Thread, vacation_response::VacationResponse,
     },
     request::{capability::CapabilityIds, reference::MaybeIdReference},
 };
@@ -71,6 +72,7 @@ pub enum RequestMethod<'x> {
     ValidateScript(ValidateSieveScriptRequest),
     LookupBlob(BlobLookupRequest),
     UploadBlob(BlobUploadRequest),
+    SendMdn(MdnSendRequest<'x>),
     Echo(Value<'x, Null, Null>),
     Error(trc::Error),
 }
@@ -97,6 +99,8 @@ pub enum GetRequestMethod {
     CalendarEventNotification(GetRequest<CalendarEventNotification>),
     ParticipantIdentity(GetRequest<ParticipantIdentity>),
     ShareNotification(GetRequest<ShareNotification>),
+    TaskList(GetRequest<TaskList>),
+    Task(GetRequest<Task>),
     Registry(GetRequest<Registry>),
 }
 
@@ -117,6 +121,8 @@ pub enum SetRequestMethod<'x> {
     CalendarEvent(SetRequest<'x, CalendarEvent>),
     CalendarEventNotification(SetRequest<'x, CalendarEventNotification>),
     ParticipantIdentity(SetRequest<'x, ParticipantIdentity>),
+    TaskList(SetRequest<'x, TaskList>),
+    Task(SetRequest<'x, Task>),
     Registry(SetRequest<'x, Registry>),
 }
 
@@ -143,6 +149,7 @@ pub enum QueryRequestMethod {
     CalendarEvent(QueryRequest<CalendarEvent>),
     CalendarEventNotification(QueryRequest<CalendarEventNotification>),
     ShareNotification(QueryRequest<ShareNotification>),
+    Task(QueryRequest<Task>),
     Registry(QueryRequest<Registry>),
 }
 
@@ -158,6 +165,7 @@ pub enum QueryChangesRequestMethod {
     CalendarEvent(QueryChangesRequest<CalendarEvent>),
     CalendarEventNotification(QueryChangesRequest<CalendarEventNotification>),
     ShareNotification(QueryChangesRequest<ShareNotification>),
+    Task(QueryChangesRequest<Task>),
 }
 
 #[derive(Debug)]
@@ -165,6 +173,7 @@ pub enum ParseRequestMethod {
     Email(ParseRequest<Email>),
     ContactCard(ParseRequest<ContactCard>),
     CalendarEvent(ParseRequest<CalendarEvent>),
+    Mdn(ParseRequest<Mdn>),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -21,6 +21,7 @@ use crate::{
         query::QueryResponse,
         query_changes::QueryChangesResponse,
         search_snippet::GetSearchSnippetResponse,
+        send::MdnSendResponse,
         set::SetResponse,
         upload::BlobUploadResponse,
         validate::ValidateSieveScriptResponse,
@@ -40,6 +41,7 @@ use crate::{
         file_node::FileNode,
         identity::Identity,
         mailbox::Mailbox,
+        mdn::Mdn,
         participant_identity::ParticipantIdentity,
         principal::Principal,
         push_subscription::PushSubscription,
@@ -47,6 +49,8 @@ use crate::{
         registry::Registry,
         share_notification::ShareNotification,
         sieve::Sieve,
+        task::Task,
+        task_list::TaskList,
         thread::Thread,
         vacation_response::VacationResponse,
     },
@@ -70,6 +74,7 @@ pub enum ResponseMethod<'x> {
     ValidateScript(ValidateSieveScriptResponse),
     LookupBlob(BlobLookupResponse),
     UploadBlob(BlobUploadResponse),
+    SendMdn(MdnSendResponse),
     Echo(Value<'x, Null, Null>),
     Error(MethodErrorWrapper),
 }
@@ -97,6 +102,8 @@ pub enum GetResponseMethod {
     CalendarEventNotification(CalendarEventNotificationGetResponse),
     ParticipantIdentity(GetResponse<ParticipantIdentity>),
     ShareNotification(GetResponse<ShareNotification>),
+    TaskList(GetResponse<TaskList>),
+    Task(GetResponse<Task>),
     Registry(GetResponse<Registry>),
 }
 
@@ -118,6 +125,8 @@ pub enum SetResponseMethod {
     CalendarEvent(SetResponse<CalendarEvent>),
     CalendarEventNotification(SetResponse<CalendarEventNotification>),
     ParticipantIdentity(SetResponse<ParticipantIdentity>),
+    TaskList(SetResponse<TaskList>),
+    Task(SetResponse<Task>),
     Registry(SetResponse<Registry>),
 }
 
@@ -137,6 +146,8 @@ pub enum ChangesResponseMethod {
     CalendarEvent(ChangesResponse<CalendarEvent>),
     CalendarEventNotification(ChangesResponse<CalendarEventNotification>),
     ShareNotification(ChangesResponse<ShareNotification>),
+    TaskList(ChangesResponse<TaskList>),
+    Task(ChangesResponse<Task>),
 }
 
 #[derive(Debug, serde::Serialize)]
@@ -154,6 +165,7 @@ pub enum ParseResponseMethod {
     Email(ParseResponse<Email>),
     ContactCard(ParseResponse<ContactCard>),
     CalendarEvent(ParseResponse<CalendarEvent>),
+    Mdn(ParseResponse<Mdn>),
 }
 
 #[derive(Debug, serde::Serialize)]
@@ -478,6 +490,12 @@ impl<'x> From<BlobUploadResponse> for ResponseMethod<'x> {
     }
 }
 
+impl<'x> From<MdnSendResponse> for ResponseMethod<'x> {
+    fn from(value: MdnSendResponse) -> Self {
+        ResponseMethod::SendMdn(value)
+    }
+}
+
 impl<'x> From<Value<'x, Null, Null>> for ResponseMethod<'x> {
     fn from(value: Value<'x, Null, Null>) -> Self {
         ResponseMethod::Echo(value)
@@ -603,3 +621,33 @@ impl From<SetResponse<CalendarEventNotification>> for ResponseMethod<'_> {
         ResponseMethod::Set(SetResponseMethod::CalendarEventNotification(value))
     }
 }
+
+impl From<ParseResponse<Mdn>> for ResponseMethod<'_> {
+    fn from(value: ParseResponse<Mdn>) -> Self {
+        ResponseMethod::Parse(ParseResponseMethod::Mdn(value))
+    }
+}
+
+impl From<GetResponse<TaskList>> for ResponseMethod<'_> {
+    fn from(response: GetResponse<TaskList>) -> Self {
+        ResponseMethod::Get(GetResponseMethod::TaskList(response))
+    }
+}
+
+impl From<SetResponse<TaskList>> for ResponseMethod<'_> {
+    fn from(response: SetResponse<TaskList>) -> Self {
+        ResponseMethod::Set(SetResponseMethod::TaskList(response))
+    }
+}
+
+impl From<GetResponse<Task>> for ResponseMethod<'_> {
+    fn from(response: GetResponse<Task>) -> Self {
+        ResponseMethod::Get(GetResponseMethod::Task(response))
+    }
+}
+
+impl From<SetResponse<Task>> for ResponseMethod<'_> {
+    fn from(response: SetResponse<Task>) -> Self {
+        ResponseMethod::Set(SetResponseMethod::Task(response))
+    }
+}
@@ -30,6 +30,12 @@ pub enum PushObject {
         #[serde(rename = "alertId")]
         alert_id: String,
     },
+    SieveNotification {
+        #[serde(rename = "accountId")]
+        account_id: Id,
+        importance: u8,
+        message: String,
+    },
     Group {
         entries: Vec<PushObject>,
     },
@@ -80,6 +80,8 @@ impl JmapAuthorization for AccessToken {
                 }
                 GetRequestMethod::Calendar(_) => Permission::JmapCalendarGet,
                 GetRequestMethod::CalendarEvent(_) => Permission::JmapCalendarEventGet,
+                GetRequestMethod::TaskList(_) => Permission::JmapTaskListGet,
+                GetRequestMethod::Task(_) => Permission::JmapTaskGet,
                 GetRequestMethod::CalendarEventNotification(_) => {
                     Permission::JmapCalendarEventNotificationGet
                 }
@@ -185,6 +187,20 @@ impl JmapAuthorization for AccessToken {
                         Permission::JmapCalendarEventUpdate,
                         Permission::JmapCalendarEventDestroy,
                     ),
+                    SetRequestMethod::TaskList(s) => validate_set(
+                        s,
+                        self,
+                        Permission::JmapTaskListCreate,
+                        Permission::JmapTaskListUpdate,
+                        Permission::JmapTaskListDestroy,
+                    ),
+                    SetRequestMethod::Task(s) => validate_set(
+                        s,
+                        self,
+                        Permission::JmapTaskCreate,
+                        Permission::JmapTaskUpdate,
+                        Permission::JmapTaskDestroy,
+                    ),
                     SetRequestMethod::CalendarEventNotification(s) => validate_set(
                         s,
                         self,
@@ -225,6 +241,8 @@ impl JmapAuthorization for AccessToken {
                 MethodObject::FileNode => Permission::JmapFileNodeChanges,
                 MethodObject::Calendar => Permission::JmapCalendarChanges,
                 MethodObject::CalendarEvent => Permission::JmapCalendarEventChanges,
+                MethodObject::TaskList => Permission::JmapTaskListChanges,
+                MethodObject::Task => Permission::JmapTaskChanges,
                 MethodObject::CalendarEventNotification => {
                     Permission::JmapCalendarEventNotificationChanges
                 }
@@ -238,6 +256,7 @@ impl JmapAuthorization for AccessToken {
                 | MethodObject::SearchSnippet
                 | MethodObject::VacationResponse
                 | MethodObject::SieveScript
+                | MethodObject::Mdn
                 | MethodObject::Registry(_) => Permission::JmapEmailChanges,
             },
             RequestMethod::Copy(m) => match &m {
@@ -251,6 +270,7 @@ impl JmapAuthorization for AccessToken {
                 ParseRequestMethod::Email(_) => Permission::JmapEmailParse,
                 ParseRequestMethod::ContactCard(_) => Permission::JmapContactCardParse,
                 ParseRequestMethod::CalendarEvent(_) => Permission::JmapCalendarEventParse,
+                ParseRequestMethod::Mdn(_) => Permission::JmapMdnParse,
             },
             RequestMethod::QueryChanges(m) => match m {
                 QueryChangesRequestMethod::Email(_) => Permission::JmapEmailQueryChanges,
@@ -267,6 +287,7 @@ impl JmapAuthorization for AccessToken {
                 QueryChangesRequestMethod::CalendarEvent(_) => {
                     Permission::JmapCalendarEventQueryChanges
                 }
+                QueryChangesRequestMethod::Task(_) => Permission::JmapTaskQueryChanges,
                 QueryChangesRequestMethod::CalendarEventNotification(_) => {
                     Permission::JmapCalendarEventNotificationQueryChanges
                 }
@@ -286,6 +307,7 @@ impl JmapAuthorization for AccessToken {
                 QueryRequestMethod::FileNode(_) => Permission::JmapFileNodeQuery,
                 QueryRequestMethod::Calendar(_) => Permission::JmapCalendarGet,
                 QueryRequestMethod::CalendarEvent(_) => Permission::JmapCalendarEventQuery,
+                QueryRequestMethod::Task(_) => Permission::JmapTaskQuery,
                 QueryRequestMethod::CalendarEventNotification(_) => {
                     Permission::JmapCalendarEventNotificationQuery
                 }
@@ -301,6 +323,7 @@ impl JmapAuthorization for AccessToken {
             RequestMethod::ValidateScript(_) => Permission::JmapSieveScriptValidate,
             RequestMethod::LookupBlob(_) => Permission::JmapBlobLookup,
             RequestMethod::UploadBlob(_) => Permission::JmapBlobUpload,
+            RequestMethod::SendMdn(_) => Permission::JmapMdnSend,
             RequestMethod::Echo(_) => Permission::JmapCoreEcho,
             RequestMethod::Error(_) => return Ok(()),
         };
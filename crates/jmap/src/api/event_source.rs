@@ -125,6 +125,12 @@ impl EventSourceHandler for Server {
                                         serde_json::to_string(&calendar_alert.into_push_object()).unwrap()
                                     ))));
                                 }
+                                PushNotification::SieveNotify(sieve_notify) => {
+                                    yield Ok(Frame::data(Bytes::from(format!(
+                                        "event: sieveNotification\ndata: {}\n\n",
+                                        serde_json::to_string(&sieve_notify.into_push_object()).unwrap()
+                                    ))));
+                                }
                                 PushNotification::EmailPush(email_push) => {
                                     let state_change = email_push.to_state_change();
                                     for type_state in state_change.types {
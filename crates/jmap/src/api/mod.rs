@@ -6,7 +6,7 @@
 
 use crate::blob::UploadResponse;
 use calcard::jscalendar::JSCalendarDateTime;
-use common::ipc::{CalendarAlert, PushNotification};
+use common::ipc::{CalendarAlert, PushNotification, SieveNotify};
 use http_proto::{HttpResponse, JsonResponse, ToHttpResponse};
 use hyper::StatusCode;
 use jmap_proto::{
@@ -151,6 +151,9 @@ impl IntoPushObject for Vec<PushNotification> {
                 PushNotification::CalendarAlert(calendar_alert) => {
                     objects.push(calendar_alert.into_push_object());
                 }
+                PushNotification::SieveNotify(sieve_notify) => {
+                    objects.push(sieve_notify.into_push_object());
+                }
                 PushNotification::EmailPush(email_push) => {
                     let state_change = email_push.to_state_change();
                     for type_state in state_change.types {
@@ -163,7 +166,7 @@ impl IntoPushObject for Vec<PushNotification> {
         }
 
         if !objects.is_empty() {
-            if changed.is_empty() {
+            if !changed.is_empty() {
                 objects.push(PushObject::StateChange { changed });
             }
             if objects.len() > 1 {
@@ -190,3 +193,13 @@ impl IntoPushObject for CalendarAlert {
         }
     }
 }
+
+impl IntoPushObject for SieveNotify {
+    fn into_push_object(self) -> PushObject {
+        PushObject::SieveNotification {
+            account_id: self.account_id.into(),
+            importance: self.importance,
+            message: self.message,
+        }
+    }
+}
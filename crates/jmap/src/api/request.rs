@@ -29,6 +29,7 @@ use crate::{
     file::{get::FileNodeGet, query::FileNodeQuery, set::FileNodeSet},
     identity::{get::IdentityGet, set::IdentitySet},
     mailbox::{get::MailboxGet, query::MailboxQuery, set::MailboxSet},
+    mdn::{parse::MdnParse, send::MdnSend},
     participant_identity::{get::ParticipantIdentityGet, set::ParticipantIdentitySet},
     principal::{availability::PrincipalGetAvailability, get::PrincipalGet, query::PrincipalQuery},
     push::{get::PushSubscriptionFetch, set::PushSubscriptionSet},
@@ -42,6 +43,8 @@ use crate::{
         validate::SieveScriptValidate,
     },
     submission::{get::EmailSubmissionGet, query::EmailSubmissionQuery, set::EmailSubmissionSet},
+    task::{get::TaskGet, query::TaskQuery, set::TaskSet},
+    task_list::{get::TaskListGet, set::TaskListSet},
     thread::get::ThreadGet,
     vacation::{get::VacationResponseGet, set::VacationResponseSet},
 };
@@ -162,6 +165,12 @@ impl RequestHandler for Server {
                                     SetResponseMethod::CalendarEvent(set_response) => {
                                         set_response.update_created_ids(&mut response);
                                     }
+                                    SetResponseMethod::TaskList(set_response) => {
+                                        set_response.update_created_ids(&mut response);
+                                    }
+                                    SetResponseMethod::Task(set_response) => {
+                                        set_response.update_created_ids(&mut response);
+                                    }
                                     SetResponseMethod::ParticipantIdentity(set_response) => {
                                         set_response.update_created_ids(&mut response);
                                     }
@@ -327,6 +336,18 @@ impl RequestHandler for Server {
 
                     self.calendar_event_get(req, access_token).await?.into()
                 }
+                GetRequestMethod::TaskList(mut req) => {
+                    set_account_id_if_missing(&mut req.account_id, access_token);
+                    access_token.assert_has_access(req.account_id, Collection::Calendar)?;
+
+                    self.task_list_get(req, access_token).await?.into()
+                }
+                GetRequestMethod::Task(mut req) => {
+                    set_account_id_if_missing(&mut req.account_id, access_token);
+                    access_token.assert_has_access(req.account_id, Collection::CalendarEvent)?;
+
+                    self.task_get(req, access_token).await?.into()
+                }
                 GetRequestMethod::CalendarEventNotification(mut req) => {
                     set_account_id_if_missing(&mut req.account_id, access_token);
                     access_token.assert_is_member(req.account_id)?;
@@ -424,6 +445,12 @@ impl RequestHandler for Server {
 
                     self.calendar_event_query(req, access_token).await?.into()
                 }
+                QueryRequestMethod::Task(mut req) => {
+                    set_account_id_if_missing(&mut req.account_id, access_token);
+                    access_token.assert_has_access(req.account_id, Collection::CalendarEvent)?;
+
+                    self.task_query(req, access_token).await?.into()
+                }
                 QueryRequestMethod::CalendarEventNotification(mut req) => {
                     set_account_id_if_missing(&mut req.account_id, access_token);
                     access_token.assert_is_member(req.account_id)?;
@@ -538,6 +565,18 @@ impl RequestHandler for Server {
                         .await?
                         .into()
                 }
+                SetRequestMethod::TaskList(mut req) => {
+                    set_account_id_if_missing(&mut req.account_id, access_token);
+                    access_token.assert_has_access(req.account_id, Collection::Calendar)?;
+
+                    self.task_list_set(req, access_token, session).await?.into()
+                }
+                SetRequestMethod::Task(mut req) => {
+                    set_account_id_if_missing(&mut req.account_id, access_token);
+                    access_token.assert_has_access(req.account_id, Collection::CalendarEvent)?;
+
+                    self.task_set(req, access_token, session).await?.into()
+                }
                 SetRequestMethod::CalendarEventNotification(mut req) => {
                     set_account_id_if_missing(&mut req.account_id, access_token);
                     access_token.assert_is_member(req.account_id)?;
@@ -642,6 +681,12 @@ impl RequestHandler for Server {
 
                     self.calendar_event_parse(req, access_token).await?.into()
                 }
+                ParseRequestMethod::Mdn(mut req) => {
+                    set_account_id_if_missing(&mut req.account_id, access_token);
+                    access_token.assert_has_access(req.account_id, Collection::Email)?;
+
+                    self.mdn_parse(req, access_token).await?.into()
+                }
             },
             RequestMethod::QueryChanges(req) => self.query_changes(req, access_token).await?.into(),
             RequestMethod::SearchSnippet(mut req) => {
@@ -668,6 +713,14 @@ impl RequestHandler for Server {
 
                 self.blob_upload_many(req, access_token).await?.into()
             }
+            RequestMethod::SendMdn(mut req) => {
+                set_account_id_if_missing(&mut req.account_id, access_token);
+                access_token.assert_is_member(req.account_id)?;
+
+                self.mdn_send(req, access_token, &session.instance, next_call)
+                    .await?
+                    .into()
+            }
             RequestMethod::Echo(req) => req.into(),
             RequestMethod::Error(error) => return Err(error),
         };
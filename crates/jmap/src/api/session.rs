@@ -102,10 +102,12 @@ impl AccountCapabilities for AccessToken {
                     Capability::ContactsParse => Permission::JmapContactCardParse,
                     Capability::Calendars => Permission::JmapCalendarEventGet,
                     Capability::CalendarsParse => Permission::JmapCalendarEventParse,
+                    Capability::Tasks => Permission::JmapTaskGet,
                     Capability::Sieve => Permission::JmapSieveScriptGet,
                     Capability::Blob => Permission::JmapBlobGet,
                     Capability::Quota => Permission::JmapQuotaGet,
                     Capability::FileNode => Permission::JmapFileNodeGet,
+                    Capability::Mdn => Permission::JmapMdnSend,
                     Capability::WebSocket
                     | Capability::Principals
                     | Capability::PrincipalsAvailability
@@ -11,7 +11,7 @@ use groupware::{
     cache::GroupwareCache,
     calendar::{
         ALERT_EMAIL, ALERT_RELATIVE_TO_END, ArchivedDefaultAlert, CALENDAR_INVISIBLE,
-        CALENDAR_SUBSCRIBED, Calendar,
+        CALENDAR_SUBSCRIBED, Calendar, publish::CalendarPublishing,
     },
 };
 use jmap_proto::{
@@ -66,6 +66,12 @@ impl CalendarGet for Server {
             )
             .await?;
         let is_owner = access_token.is_member(account_id);
+        let fetch_publish = properties.iter().any(|property| {
+            matches!(
+                property,
+                CalendarProperty::Publish | CalendarProperty::PublishUrl
+            )
+        });
         let calendar_ids = if is_owner {
             cache.document_ids(true).collect::<RoaringBitmap>()
         } else {
@@ -129,6 +135,13 @@ impl CalendarGet for Server {
             let calendar = _calendar
                 .unarchive::<Calendar>()
                 .caused_by(trc::location!())?;
+            let publish = if is_owner && fetch_publish {
+                self.calendar_publish_settings(account_id, document_id)
+                    .await
+                    .caused_by(trc::location!())?
+            } else {
+                None
+            };
             let mut result = Map::with_capacity(properties.len());
             for property in &properties {
                 match property {
@@ -257,6 +270,26 @@ impl CalendarGet for Server {
                             },
                         );
                     }
+                    CalendarProperty::Publish => {
+                        result.insert_unchecked(
+                            CalendarProperty::Publish,
+                            publish.as_ref().map(|publish| {
+                                if publish.free_busy_only {
+                                    "freeBusy".to_string()
+                                } else {
+                                    "full".to_string()
+                                }
+                            }),
+                        );
+                    }
+                    CalendarProperty::PublishUrl => {
+                        result.insert_unchecked(
+                            CalendarProperty::PublishUrl,
+                            publish
+                                .as_ref()
+                                .map(|publish| publish.url(self, account_id, document_id)),
+                        );
+                    }
                     property => {
                         result.insert_unchecked(property.clone(), Value::Null);
                     }
@@ -14,6 +14,7 @@ use groupware::{
         ALERT_EMAIL, ALERT_RELATIVE_TO_END, ALERT_WITH_TIME, CALENDAR_AVAILABILITY_ALL,
         CALENDAR_AVAILABILITY_ATTENDING, CALENDAR_AVAILABILITY_NONE, CALENDAR_INVISIBLE,
         CALENDAR_SUBSCRIBED, Calendar, CalendarEvent, CalendarPreferences, DefaultAlert, Timezone,
+        publish::{CalendarPublish, CalendarPublishing},
     },
 };
 use http_proto::HttpSessionData;
@@ -94,10 +95,19 @@ impl CalendarSet for Server {
             };
 
             // Process changes
-            if let Err(err) = update_calendar(object, &mut calendar, access_token) {
+            let mut publish = None;
+            if let Err(err) = update_calendar(object, &mut calendar, access_token, &mut publish) {
                 response.not_created.append(id, err);
                 continue 'create;
             }
+            if publish.is_some() && !access_token.is_member(account_id) {
+                response.not_created.append(
+                    id,
+                    SetError::forbidden()
+                        .with_description("You are not allowed to publish this calendar."),
+                );
+                continue 'create;
+            }
 
             // Validate ACLs
             if !calendar.acls.is_empty() {
@@ -125,6 +135,9 @@ impl CalendarSet for Server {
                     &mut batch,
                 )
                 .caused_by(trc::location!())?;
+            if let Some(Some(free_busy_only)) = publish {
+                CalendarPublish::new(free_busy_only).write(&mut batch, account_id, document_id);
+            }
 
             if let Some(MaybeIdReference::Reference(id_ref)) =
                 &request.arguments.on_success_set_is_default
@@ -168,13 +181,15 @@ impl CalendarSet for Server {
                 .caused_by(trc::location!())?;
 
             // Apply changes
-            let has_acl_changes = match update_calendar(object, &mut new_calendar, access_token) {
-                Ok(has_acl_changes_) => has_acl_changes_,
-                Err(err) => {
-                    response.not_updated.append(id, err);
-                    continue 'update;
-                }
-            };
+            let mut publish = None;
+            let has_acl_changes =
+                match update_calendar(object, &mut new_calendar, access_token, &mut publish) {
+                    Ok(has_acl_changes_) => has_acl_changes_,
+                    Err(err) => {
+                        response.not_updated.append(id, err);
+                        continue 'update;
+                    }
+                };
 
             // Validate ACL
             if is_shared {
@@ -188,6 +203,14 @@ impl CalendarSet for Server {
                     continue 'update;
                 }
             }
+            if publish.is_some() && !access_token.is_member(account_id) {
+                response.not_updated.append(
+                    id,
+                    SetError::forbidden()
+                        .with_description("You are not allowed to publish this calendar."),
+                );
+                continue 'update;
+            }
             if has_acl_changes {
                 if let Err(err) = self.acl_validate(&new_calendar.acls).await {
                     response.not_updated.append(id, err.into());
@@ -208,6 +231,23 @@ impl CalendarSet for Server {
                     &mut batch,
                 )
                 .caused_by(trc::location!())?;
+
+            // Publish or unpublish the calendar, keeping the existing token
+            match publish {
+                Some(Some(free_busy_only)) => {
+                    let mut publish = self
+                        .calendar_publish_settings(account_id, document_id)
+                        .await
+                        .caused_by(trc::location!())?
+                        .unwrap_or_else(|| CalendarPublish::new(free_busy_only));
+                    publish.free_busy_only = free_busy_only;
+                    publish.write(&mut batch, account_id, document_id);
+                }
+                Some(None) => {
+                    CalendarPublish::clear(&mut batch, account_id, document_id);
+                }
+                None => {}
+            }
             response.updated.append(id, None);
         }
 
@@ -400,6 +440,7 @@ fn update_calendar(
     updates: Value<'_, CalendarProperty, CalendarValue>,
     calendar: &mut Calendar,
     access_token: &AccessToken,
+    publish: &mut Option<Option<bool>>,
 ) -> Result<bool, SetError<CalendarProperty>> {
     let mut has_acl_changes = false;
 
@@ -490,6 +531,14 @@ fn update_calendar(
                     }
                 }
             }
+            (CalendarProperty::Publish, Value::Str(value))
+                if matches!(value.as_ref(), "full" | "freeBusy") =>
+            {
+                *publish = Some(Some(value == "freeBusy"));
+            }
+            (CalendarProperty::Publish, Value::Null) => {
+                *publish = Some(None);
+            }
             (CalendarProperty::ShareWith, value) => {
                 calendar.acls = JmapRights::acl_set::<calendar::Calendar>(value)?;
                 has_acl_changes = true;
@@ -93,6 +93,16 @@ impl ChangesLookup for Server {
 
                 (SyncCollection::Calendar, false)
             }
+            MethodObject::TaskList => {
+                access_token.assert_has_access(request.account_id, Collection::Calendar)?;
+
+                (SyncCollection::Calendar, true)
+            }
+            MethodObject::Task => {
+                access_token.assert_has_access(request.account_id, Collection::CalendarEvent)?;
+
+                (SyncCollection::Calendar, false)
+            }
             MethodObject::CalendarEventNotification => {
                 access_token.assert_is_member(request.account_id)?;
 
@@ -315,6 +325,10 @@ impl IntermediateChangesResponse {
             MethodObject::CalendarEvent => {
                 ChangesResponseMethod::CalendarEvent(transmute_response(self.response))
             }
+            MethodObject::TaskList => {
+                ChangesResponseMethod::TaskList(transmute_response(self.response))
+            }
+            MethodObject::Task => ChangesResponseMethod::Task(transmute_response(self.response)),
             MethodObject::CalendarEventNotification => {
                 ChangesResponseMethod::CalendarEventNotification(transmute_response(self.response))
             }
@@ -328,6 +342,7 @@ impl IntermediateChangesResponse {
             | MethodObject::SearchSnippet
             | MethodObject::VacationResponse
             | MethodObject::SieveScript
+            | MethodObject::Mdn
             | MethodObject::Principal
             | MethodObject::Quota
             | MethodObject::Registry(_) => unreachable!(),
@@ -12,9 +12,12 @@ use crate::{changes::state::JmapCacheState, email::headers::HeaderToValue};
 use common::{Server, auth::AccessToken};
 use email::{
     cache::{MessageCacheFetch, email::MessageCacheAccess},
-    message::metadata::{
-        ArchivedMetadataPartType, MESSAGE_HAS_ATTACHMENT, MESSAGE_RECEIVED_MASK, MessageMetadata,
-        MetadataHeaderName, PART_ENCODING_PROBLEM,
+    message::{
+        metadata::{
+            ArchivedMetadataPartType, MESSAGE_HAS_ATTACHMENT, MESSAGE_RECEIVED_MASK,
+            MessageMetadata, MetadataHeaderName, PART_ENCODING_PROBLEM,
+        },
+        snooze::EmailSnooze,
     },
 };
 use jmap_proto::{
@@ -274,6 +277,20 @@ impl EmailGet for Server {
                             )),
                         );
                     }
+                    EmailProperty::SnoozedUntil => {
+                        email.insert_unchecked(
+                            EmailProperty::SnoozedUntil,
+                            self.email_snoozed(account_id, id.document_id())
+                                .await
+                                .caused_by(trc::location!())?
+                                .map(|snoozed| {
+                                    Value::Element(EmailValue::Date(UTCDate::from_timestamp(
+                                        snoozed.until as i64,
+                                    )))
+                                })
+                                .unwrap_or(Value::Null),
+                        );
+                    }
                     EmailProperty::Preview => {
                         if !metadata.preview.is_empty() {
                             email.insert_unchecked(
@@ -20,6 +20,7 @@ use email::{
         delete::EmailDeletion,
         ingest::{EmailIngest, IngestEmail, IngestSource},
         metadata::MessageData,
+        snooze::{EmailSnooze, SnoozedEmail},
     },
 };
 use http_proto::HttpSessionData;
@@ -792,6 +793,7 @@ impl EmailSet for Server {
         let mut batch = BatchBuilder::new();
         let mut changed_mailboxes: AHashMap<u32, Vec<u32>> = AHashMap::new();
         let mut will_update = Vec::with_capacity(request.update.as_ref().map_or(0, |u| u.len()));
+        let mut has_snoozed = false;
         'update: for (id, object) in request.unwrap_update().into_valid() {
             // Make sure id won't be destroyed
             if will_destroy.contains(&id) {
@@ -820,6 +822,7 @@ impl EmailSet for Server {
                 .to_unarchived::<MessageData>()
                 .caused_by(trc::location!())?;
             let mut new_data = data.inner.to_builder();
+            let mut snoozed_until = None;
 
             for (property, mut value) in object.into_expanded_object() {
                 if let Err(err) = response.resolve_self_references(&mut value, 0, false) {
@@ -850,6 +853,15 @@ impl EmailSet for Server {
                                 .collect(),
                         );
                     }
+                    (
+                        Key::Property(EmailProperty::SnoozedUntil),
+                        Value::Element(EmailValue::Date(date)),
+                    ) => {
+                        snoozed_until = Some(Some(date.timestamp().max(0) as u64));
+                    }
+                    (Key::Property(EmailProperty::SnoozedUntil), Value::Null) => {
+                        snoozed_until = Some(None);
+                    }
                     (Key::Property(EmailProperty::Pointer(pointer)), value) => {
                         match handle_email_patch(&pointer, value) {
                             PatchResult::SetKeyword(keyword) => {
@@ -879,7 +891,7 @@ impl EmailSet for Server {
 
             let has_keyword_changes = new_data.has_keyword_changes(data.inner);
             let has_mailbox_changes = new_data.has_mailbox_changes(data.inner);
-            if !has_keyword_changes && !has_mailbox_changes {
+            if !has_keyword_changes && !has_mailbox_changes && snoozed_until.is_none() {
                 response.updated.append(id, None);
                 continue 'update;
             }
@@ -1043,6 +1055,42 @@ impl EmailSet for Server {
                 }
             }
 
+            // Process snooze
+            if let Some(snoozed_until) = snoozed_until {
+                // Verify permissions on shared accounts
+                if can_modify_mailbox_ids.as_ref().is_some_and(|ids| {
+                    !new_data
+                        .mailboxes
+                        .iter()
+                        .any(|mb| ids.contains(mb.mailbox_id))
+                }) {
+                    response.not_updated.append(
+                        id,
+                        SetError::forbidden()
+                            .with_description("You are not allowed to snooze this message."),
+                    );
+                    continue 'update;
+                }
+
+                if let Some(until) = snoozed_until {
+                    let mailbox_ids = data
+                        .inner
+                        .mailboxes
+                        .iter()
+                        .map(|m| m.mailbox_id.to_native())
+                        .collect::<Vec<_>>();
+                    let mailbox_id = self
+                        .email_snooze_origin(account_id, document_id, &mailbox_ids)
+                        .await
+                        .caused_by(trc::location!())?;
+
+                    SnoozedEmail::new(until, mailbox_id).write(&mut batch, account_id, document_id);
+                    has_snoozed = true;
+                } else {
+                    SnoozedEmail::clear(&mut batch, account_id, document_id);
+                }
+            }
+
             // Write changes
             batch
                 .with_account_id(account_id)
@@ -1088,6 +1136,10 @@ impl EmailSet for Server {
                 Ok(change_id) => {
                     last_change_id = change_id.into();
 
+                    if has_snoozed {
+                        self.notify_task_queue();
+                    }
+
                     // Add to updated list
                     for id in will_update {
                         response.updated.append(id, None);
@@ -18,6 +18,7 @@ pub mod email;
 pub mod file;
 pub mod identity;
 pub mod mailbox;
+pub mod mdn;
 pub mod participant_identity;
 pub mod principal;
 pub mod push;
@@ -26,6 +27,8 @@ pub mod registry;
 pub mod share_notification;
 pub mod sieve;
 pub mod submission;
+pub mod task;
+pub mod task_list;
 pub mod thread;
 pub mod vacation;
 pub mod websocket;
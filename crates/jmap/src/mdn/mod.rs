@@ -0,0 +1,28 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+pub mod parse;
+pub mod send;
+
+pub(crate) fn is_valid_field_value(value: &str) -> bool {
+    !value.contains(['\r', '\n'])
+}
+
+pub(crate) fn is_valid_field_name(name: &str) -> bool {
+    !name.is_empty() && name.bytes().all(|ch| ch.is_ascii_graphic() && ch != b':')
+}
+
+// Extracts the first e-mail address from a raw address list header
+pub(crate) fn parse_header_address(value: &str) -> Option<String> {
+    let value = value.trim();
+    let address = if let Some((_, addr)) = value.split_once('<') {
+        addr.split_once('>').map(|(addr, _)| addr)?
+    } else {
+        value.split(',').next()?
+    };
+
+    utils::sanitize_email(address.trim())
+}
@@ -0,0 +1,296 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::blob::download::BlobDownload;
+use common::{Server, auth::AccessToken};
+use email::cache::{MessageCacheFetch, email::MessageCacheAccess};
+use jmap_proto::{
+    method::parse::{ParseRequest, ParseResponse},
+    object::mdn::{Mdn, MdnProperty, MdnValue},
+    request::IntoValid,
+};
+use jmap_tools::{Key, Map, Value};
+use mail_parser::{MessageParser, MimeHeaders, PartType};
+use std::future::Future;
+use store::search::{EmailSearchField, SearchFilter, SearchOperator, SearchQuery, SearchValue};
+use store::write::SearchIndex;
+use trc::AddContext;
+use types::{acl::Acl, id::Id};
+use utils::map::vec_map::VecMap;
+
+pub trait MdnParse: Sync + Send {
+    fn mdn_parse(
+        &self,
+        request: ParseRequest<Mdn>,
+        access_token: &AccessToken,
+    ) -> impl Future<Output = trc::Result<ParseResponse<Mdn>>> + Send;
+
+    fn mdn_email_id(
+        &self,
+        account_id: u32,
+        message_id: &str,
+        access_token: &AccessToken,
+    ) -> impl Future<Output = trc::Result<Option<Id>>> + Send;
+}
+
+impl MdnParse for Server {
+    async fn mdn_parse(
+        &self,
+        request: ParseRequest<Mdn>,
+        access_token: &AccessToken,
+    ) -> trc::Result<ParseResponse<Mdn>> {
+        if request.blob_ids.len() > self.core.jmap.mail_parse_max_items {
+            return Err(trc::JmapEvent::RequestTooLarge.into_err());
+        }
+        let account_id = request.account_id.document_id();
+        let properties = request
+            .properties
+            .map(|v| v.into_valid().collect::<Vec<_>>());
+
+        let mut response = ParseResponse {
+            account_id: request.account_id,
+            parsed: VecMap::with_capacity(request.blob_ids.len()),
+            not_parsable: vec![],
+            not_found: vec![],
+        };
+
+        for blob_id in request.blob_ids.into_valid() {
+            // Fetch raw message to parse
+            let raw_message = match self.blob_download(&blob_id, access_token).await? {
+                Some(raw_message) => raw_message,
+                None => {
+                    response.not_found.push(blob_id);
+                    continue;
+                }
+            };
+            let Some(message) = MessageParser::new().parse(&raw_message) else {
+                response.not_parsable.push(blob_id);
+                continue;
+            };
+
+            // Locate the disposition notification part
+            let Some(report) = message
+                .parts
+                .iter()
+                .find(|part| {
+                    part.content_type().is_some_and(|ct| {
+                        ct.ctype().eq_ignore_ascii_case("message")
+                            && ct.subtype().is_some_and(|st| {
+                                st.eq_ignore_ascii_case("disposition-notification")
+                            })
+                    })
+                })
+                .and_then(|part| {
+                    raw_message.get(part.raw_body_offset() as usize..part.raw_end_offset() as usize)
+                })
+                .map(parse_report_fields)
+            else {
+                response.not_parsable.push(blob_id);
+                continue;
+            };
+
+            let mut mdn = Map::with_capacity(12);
+            let mut disposition = None;
+            let mut errors = Vec::new();
+            let mut extension_fields = Map::with_capacity(0);
+            for (name, value) in report {
+                let property = match name.to_ascii_lowercase().as_str() {
+                    "reporting-ua" => MdnProperty::ReportingUa,
+                    "mdn-gateway" => MdnProperty::MdnGateway,
+                    "original-recipient" => MdnProperty::OriginalRecipient,
+                    "final-recipient" => MdnProperty::FinalRecipient,
+                    "original-message-id" => MdnProperty::OriginalMessageId,
+                    "disposition" => {
+                        disposition = parse_disposition(&value);
+                        continue;
+                    }
+                    "error" => {
+                        errors.push(Value::Str(value.into()));
+                        continue;
+                    }
+                    _ => {
+                        extension_fields
+                            .insert_unchecked(Key::Owned(name), Value::Str(value.into()));
+                        continue;
+                    }
+                };
+                mdn.insert_unchecked(property, value);
+            }
+            let Some(disposition) = disposition else {
+                response.not_parsable.push(blob_id);
+                continue;
+            };
+
+            let for_email_id = if let Some(message_id) = mdn
+                .get(&Key::Property(MdnProperty::OriginalMessageId))
+                .and_then(|v| v.as_str())
+            {
+                self.mdn_email_id(account_id, message_id.as_ref(), access_token)
+                    .await?
+                    .map(|id| Value::Element(MdnValue::Id(id)))
+                    .unwrap_or(Value::Null)
+            } else {
+                Value::Null
+            };
+            mdn.insert_unchecked(MdnProperty::ForEmailId, for_email_id);
+            mdn.insert_unchecked(MdnProperty::Disposition, disposition);
+            mdn.insert_unchecked(
+                MdnProperty::Subject,
+                message
+                    .subject()
+                    .map(|v| Value::Str(v.to_string().into()))
+                    .unwrap_or(Value::Null),
+            );
+            mdn.insert_unchecked(
+                MdnProperty::TextBody,
+                message
+                    .body_text(0)
+                    .map(|v| Value::Str(v.into_owned().into()))
+                    .unwrap_or(Value::Null),
+            );
+            mdn.insert_unchecked(
+                MdnProperty::IncludeOriginalMessage,
+                message
+                    .parts
+                    .iter()
+                    .any(|part| matches!(part.body, PartType::Message(_))),
+            );
+            mdn.insert_unchecked(
+                MdnProperty::Error,
+                if !errors.is_empty() {
+                    Value::Array(errors)
+                } else {
+                    Value::Null
+                },
+            );
+            mdn.insert_unchecked(
+                MdnProperty::ExtensionFields,
+                if !extension_fields.is_empty() {
+                    Value::Object(extension_fields)
+                } else {
+                    Value::Null
+                },
+            );
+            for property in [
+                MdnProperty::ReportingUa,
+                MdnProperty::MdnGateway,
+                MdnProperty::OriginalRecipient,
+                MdnProperty::FinalRecipient,
+                MdnProperty::OriginalMessageId,
+            ] {
+                if !mdn.contains_key(&Key::Property(property.clone())) {
+                    mdn.insert_unchecked(property, Value::Null);
+                }
+            }
+
+            // Retain requested properties
+            if let Some(properties) = &properties {
+                mdn.as_mut_vec().retain(|(key, _)| {
+                    matches!(key, Key::Property(property) if properties.contains(property))
+                });
+            }
+
+            response.parsed.append(blob_id, mdn.into());
+        }
+
+        Ok(response)
+    }
+
+    async fn mdn_email_id(
+        &self,
+        account_id: u32,
+        message_id: &str,
+        access_token: &AccessToken,
+    ) -> trc::Result<Option<Id>> {
+        let message_id = message_id
+            .trim()
+            .trim_start_matches('<')
+            .trim_end_matches('>');
+        if message_id.is_empty() {
+            return Ok(None);
+        }
+
+        let cached_messages = self
+            .get_cached_messages(account_id)
+            .await
+            .caused_by(trc::location!())?;
+        let document_ids = self
+            .search_store()
+            .query_account(
+                SearchQuery::new(SearchIndex::Email)
+                    .with_filters(vec![SearchFilter::cond(
+                        EmailSearchField::Headers,
+                        SearchOperator::Equal,
+                        SearchValue::KeyValues(
+                            VecMap::with_capacity(1)
+                                .with_append("message-id".to_string(), message_id.to_string()),
+                        ),
+                    )])
+                    .with_account_id(account_id)
+                    .with_mask(if access_token.is_shared(account_id) {
+                        cached_messages.shared_messages(access_token, Acl::ReadItems)
+                    } else {
+                        cached_messages.email_document_ids()
+                    }),
+            )
+            .await
+            .caused_by(trc::location!())?;
+
+        Ok(document_ids.into_iter().find_map(|document_id| {
+            cached_messages
+                .email_by_id(&document_id)
+                .map(|item| Id::from_parts(item.thread_id, item.document_id))
+        }))
+    }
+}
+
+// Parses the header block of a message/disposition-notification body part
+fn parse_report_fields(body: &[u8]) -> Vec<(String, String)> {
+    let mut fields: Vec<(String, String)> = Vec::new();
+
+    for line in String::from_utf8_lossy(body).lines() {
+        if line.starts_with([' ', '\t']) {
+            // Unfold continuation lines
+            if let Some((_, value)) = fields.last_mut() {
+                value.push(' ');
+                value.push_str(line.trim());
+            }
+        } else if let Some((name, value)) = line.split_once(':') {
+            let name = name.trim();
+            if !name.is_empty() {
+                fields.push((name.to_string(), value.trim().to_string()));
+            }
+        }
+    }
+
+    fields
+}
+
+// Parses "action-mode/sending-mode; disposition-type[/modifiers]"
+fn parse_disposition(value: &str) -> Option<Value<'static, MdnProperty, MdnValue>> {
+    let (modes, disposition_type) = value.split_once(';')?;
+    let (action_mode, sending_mode) = modes.split_once('/')?;
+    let disposition_type = disposition_type
+        .split('/')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase();
+
+    Some(
+        Map::with_capacity(3)
+            .with_key_value(
+                MdnProperty::ActionMode,
+                action_mode.trim().to_ascii_lowercase(),
+            )
+            .with_key_value(
+                MdnProperty::SendingMode,
+                sending_mode.trim().to_ascii_lowercase(),
+            )
+            .with_key_value(MdnProperty::Type, disposition_type)
+            .into(),
+    )
+}
@@ -0,0 +1,596 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use super::{is_valid_field_name, is_valid_field_value, parse_header_address};
+use common::{
+    MessageStoreCache, Server,
+    auth::AccessToken,
+    network::{ServerInstance, stream::NullIo},
+};
+use email::{
+    cache::{MessageCacheFetch, email::MessageCacheAccess},
+    identity::Identity,
+    message::metadata::MessageMetadata,
+};
+use jmap_proto::{
+    error::set::{SetError, SetErrorType},
+    method::{
+        send::{MdnSendRequest, MdnSendResponse},
+        set::SetRequest,
+    },
+    object::{
+        email::{EmailProperty, EmailValue},
+        mdn::{MdnProperty, MdnValue},
+    },
+    request::{
+        Call, MaybeInvalid, RequestMethod, SetRequestMethod,
+        method::{MethodFunction, MethodName, MethodObject},
+        reference::MaybeIdReference,
+    },
+};
+use jmap_tools::{JsonPointer, JsonPointerItem, Key, Map, Value};
+use mail_builder::{
+    MessageBuilder,
+    headers::{HeaderType, content_type::ContentType},
+    mime::{BodyPart, MimePart, make_boundary},
+};
+use mail_parser::MessageParser;
+use smtp::core::{Session, SessionData};
+use smtp_proto::{MailFrom, RcptTo};
+use std::{borrow::Cow, fmt::Write, future::Future, sync::Arc, time::Duration};
+use store::{
+    ValueKey,
+    write::{AlignedBytes, Archive},
+};
+use trc::AddContext;
+use types::{collection::Collection, field::EmailField, id::Id, keyword::Keyword};
+use utils::map::vec_map::VecMap;
+
+pub trait MdnSend: Sync + Send {
+    fn mdn_send<'x>(
+        &self,
+        request: MdnSendRequest<'x>,
+        access_token: &AccessToken,
+        instance: &Arc<ServerInstance>,
+        next_call: &mut Option<Call<RequestMethod<'x>>>,
+    ) -> impl Future<Output = trc::Result<MdnSendResponse>> + Send;
+
+    fn send_mdn(
+        &self,
+        account_id: u32,
+        identity_email: &str,
+        cached_messages: &MessageStoreCache,
+        instance: &Arc<ServerInstance>,
+        object: Value<'_, MdnProperty, MdnValue>,
+    ) -> impl Future<
+        Output = trc::Result<
+            Result<(Id, Value<'static, MdnProperty, MdnValue>), SetError<MdnProperty>>,
+        >,
+    > + Send;
+}
+
+impl MdnSend for Server {
+    async fn mdn_send<'x>(
+        &self,
+        request: MdnSendRequest<'x>,
+        _access_token: &AccessToken,
+        instance: &Arc<ServerInstance>,
+        next_call: &mut Option<Call<RequestMethod<'x>>>,
+    ) -> trc::Result<MdnSendResponse> {
+        if request.send.len() > self.core.jmap.set_max_objects {
+            return Err(trc::JmapEvent::RequestTooLarge.into_err());
+        }
+        let account_id = request.account_id.document_id();
+        let mut response = MdnSendResponse {
+            account_id: request.account_id,
+            sent: VecMap::with_capacity(request.send.len()),
+            not_sent: VecMap::new(),
+        };
+
+        // Fetch identity's address
+        let identity_email = if let MaybeInvalid::Value(identity_id) = request.identity_id
+            && let Some(identity) = self
+                .store()
+                .get_value::<Archive<AlignedBytes>>(ValueKey::archive(
+                    account_id,
+                    Collection::Identity,
+                    identity_id.document_id(),
+                ))
+                .await?
+        {
+            identity
+                .unarchive::<Identity>()
+                .caused_by(trc::location!())?
+                .email
+                .to_string()
+        } else {
+            return Err(trc::JmapEvent::InvalidArguments
+                .into_err()
+                .details("Identity not found."));
+        };
+
+        // Send MDNs
+        let cached_messages = self
+            .get_cached_messages(account_id)
+            .await
+            .caused_by(trc::location!())?;
+        let mut sent_email_ids: VecMap<String, Id> = VecMap::with_capacity(request.send.len());
+        for (id, object) in request.send {
+            // Make sure the same email is not acknowledged twice
+            if let Some(MdnValue::Id(email_id)) = object
+                .as_object()
+                .and_then(|obj| obj.get(&Key::Property(MdnProperty::ForEmailId)))
+                .and_then(|value| value.as_element())
+                && sent_email_ids.values().any(|id| id == email_id)
+            {
+                response.not_sent.append(
+                    id,
+                    SetError::new(SetErrorType::MdnAlreadySent)
+                        .with_description("An MDN has already been sent for this email."),
+                );
+                continue;
+            }
+
+            match self
+                .send_mdn(
+                    account_id,
+                    &identity_email,
+                    &cached_messages,
+                    instance,
+                    object,
+                )
+                .await?
+            {
+                Ok((email_id, mdn)) => {
+                    sent_email_ids.append(id.clone(), email_id);
+                    response.sent.append(id, mdn);
+                }
+                Err(err) => {
+                    response.not_sent.append(id, err);
+                }
+            }
+        }
+
+        // Flag acknowledged emails with $mdnsent and apply any requested updates
+        if !sent_email_ids.is_empty() {
+            let mdn_sent = EmailProperty::Pointer(JsonPointer::new(vec![
+                JsonPointerItem::Key(Key::Property(EmailProperty::Keywords)),
+                JsonPointerItem::Key(Key::Property(EmailProperty::Keyword(Keyword::MdnSent))),
+            ]));
+            let mut update: VecMap<MaybeInvalid<Id>, Value<'x, EmailProperty, EmailValue>> =
+                VecMap::with_capacity(sent_email_ids.len());
+            for email_id in sent_email_ids.values() {
+                update.set(
+                    MaybeInvalid::Value(*email_id),
+                    Map::with_capacity(1)
+                        .with_key_value(mdn_sent.clone(), true)
+                        .into(),
+                );
+            }
+
+            for (id, value) in request.on_success_update_email.unwrap_or_default() {
+                let id = match id {
+                    MaybeIdReference::Id(id) => MaybeInvalid::Value(id),
+                    MaybeIdReference::Reference(id_ref) => {
+                        if let Some(email_id) = sent_email_ids.get(&id_ref) {
+                            MaybeInvalid::Value(*email_id)
+                        } else {
+                            continue;
+                        }
+                    }
+                    MaybeIdReference::Invalid(id) => MaybeInvalid::Invalid(id),
+                };
+
+                match (update.get_mut(&id), value) {
+                    (Some(Value::Object(patch)), Value::Object(value)) => {
+                        for (key, value) in value.into_vec() {
+                            patch.insert_unchecked(key, value);
+                        }
+                    }
+                    (_, value) => {
+                        update.set(id, value);
+                    }
+                }
+            }
+
+            *next_call = Call {
+                id: String::new(),
+                name: MethodName::new(MethodObject::Email, MethodFunction::Set),
+                method: RequestMethod::Set(SetRequestMethod::Email(SetRequest {
+                    account_id: request.account_id,
+                    if_in_state: None,
+                    create: None,
+                    update: Some(update),
+                    destroy: None,
+                    arguments: Default::default(),
+                })),
+            }
+            .into();
+        }
+
+        Ok(response)
+    }
+
+    async fn send_mdn(
+        &self,
+        account_id: u32,
+        identity_email: &str,
+        cached_messages: &MessageStoreCache,
+        instance: &Arc<ServerInstance>,
+        object: Value<'_, MdnProperty, MdnValue>,
+    ) -> trc::Result<Result<(Id, Value<'static, MdnProperty, MdnValue>), SetError<MdnProperty>>>
+    {
+        let mut for_email_id = None;
+        let mut subject = None;
+        let mut text_body = None;
+        let mut include_original_message = false;
+        let mut reporting_ua = None;
+        let mut final_recipient = None;
+        let mut action_mode = None;
+        let mut sending_mode = None;
+        let mut disposition_type = None;
+        let mut errors = Vec::new();
+        let mut extension_fields = Vec::new();
+
+        for (property, value) in object.into_expanded_object() {
+            match (&property, value) {
+                (Key::Property(MdnProperty::ForEmailId), Value::Element(MdnValue::Id(id))) => {
+                    for_email_id = Some(id);
+                }
+                (Key::Property(MdnProperty::Subject), Value::Str(value)) => {
+                    subject = Some(value.into_owned());
+                }
+                (Key::Property(MdnProperty::TextBody), Value::Str(value)) => {
+                    text_body = Some(value.into_owned());
+                }
+                (Key::Property(MdnProperty::IncludeOriginalMessage), Value::Bool(value)) => {
+                    include_original_message = value;
+                }
+                (Key::Property(MdnProperty::ReportingUa), Value::Str(value))
+                    if is_valid_field_value(&value) =>
+                {
+                    reporting_ua = Some(value.into_owned());
+                }
+                (Key::Property(MdnProperty::FinalRecipient), Value::Str(value))
+                    if is_valid_field_value(&value) =>
+                {
+                    final_recipient = Some(value.into_owned());
+                }
+                (Key::Property(MdnProperty::Disposition), Value::Object(disposition)) => {
+                    for (key, value) in disposition.into_vec() {
+                        match (key, value) {
+                            (Key::Property(MdnProperty::ActionMode), Value::Str(value))
+                                if matches!(
+                                    value.as_ref(),
+                                    "manual-action" | "automatic-action"
+                                ) =>
+                            {
+                                action_mode = Some(value.into_owned());
+                            }
+                            (Key::Property(MdnProperty::SendingMode), Value::Str(value))
+                                if matches!(
+                                    value.as_ref(),
+                                    "mdn-sent-manually" | "mdn-sent-automatically"
+                                ) =>
+                            {
+                                sending_mode = Some(value.into_owned());
+                            }
+                            (Key::Property(MdnProperty::Type), Value::Str(value))
+                                if matches!(
+                                    value.as_ref(),
+                                    "deleted" | "dispatched" | "displayed" | "processed"
+                                ) =>
+                            {
+                                disposition_type = Some(value.into_owned());
+                            }
+                            (key, _) => {
+                                return Ok(Err(SetError::invalid_properties()
+                                    .with_property(MdnProperty::Disposition)
+                                    .with_description(format!(
+                                        "Invalid disposition field {:?}.",
+                                        key.into_string()
+                                    ))));
+                            }
+                        }
+                    }
+                }
+                (Key::Property(MdnProperty::Error), Value::Array(values)) => {
+                    for value in values {
+                        if let Value::Str(value) = value
+                            && is_valid_field_value(&value)
+                        {
+                            errors.push(value.into_owned());
+                        } else {
+                            return Ok(Err(SetError::invalid_properties()
+                                .with_property(MdnProperty::Error)
+                                .with_description("Invalid error value.")));
+                        }
+                    }
+                }
+                (Key::Property(MdnProperty::ExtensionFields), Value::Object(fields)) => {
+                    for (key, value) in fields.into_vec() {
+                        let key = key.into_string();
+                        if let Value::Str(value) = value
+                            && is_valid_field_name(&key)
+                            && is_valid_field_value(&value)
+                        {
+                            extension_fields.push((key, value.into_owned()));
+                        } else {
+                            return Ok(Err(SetError::invalid_properties()
+                                .with_property(MdnProperty::ExtensionFields)
+                                .with_description(format!("Invalid extension field {key:?}."))));
+                        }
+                    }
+                }
+                (
+                    Key::Property(
+                        MdnProperty::Subject
+                        | MdnProperty::TextBody
+                        | MdnProperty::ReportingUa
+                        | MdnProperty::FinalRecipient
+                        | MdnProperty::Error
+                        | MdnProperty::ExtensionFields
+                        | MdnProperty::MdnGateway
+                        | MdnProperty::OriginalRecipient
+                        | MdnProperty::OriginalMessageId,
+                    ),
+                    Value::Null,
+                ) => {}
+                _ => {
+                    return Ok(Err(SetError::invalid_properties()
+                        .with_property(property.into_owned())
+                        .with_description("Field could not be set.")));
+                }
+            }
+        }
+
+        // Make sure we have all required fields
+        let Some(for_email_id) = for_email_id else {
+            return Ok(Err(SetError::invalid_properties()
+                .with_property(MdnProperty::ForEmailId)
+                .with_description("forEmailId property is required.")));
+        };
+        let (Some(action_mode), Some(sending_mode), Some(disposition_type)) =
+            (action_mode, sending_mode, disposition_type)
+        else {
+            return Ok(Err(SetError::invalid_properties()
+                .with_property(MdnProperty::Disposition)
+                .with_description(
+                    "Disposition actionMode, sendingMode and type are required.",
+                )));
+        };
+
+        // Obtain the message being acknowledged
+        let Some(message_cache) = cached_messages.email_by_id(&for_email_id.document_id()) else {
+            return Ok(Err(SetError::invalid_properties()
+                .with_property(MdnProperty::ForEmailId)
+                .with_description("Email not found.")));
+        };
+        if cached_messages.has_keyword(message_cache, &Keyword::MdnSent) {
+            return Ok(Err(SetError::new(SetErrorType::MdnAlreadySent)
+                .with_description("An MDN has already been sent for this email.")));
+        }
+        let email_id = Id::from_parts(message_cache.thread_id, message_cache.document_id);
+        let Some(metadata_) = self
+            .store()
+            .get_value::<Archive<AlignedBytes>>(ValueKey::property(
+                account_id,
+                Collection::Email,
+                message_cache.document_id,
+                EmailField::Metadata,
+            ))
+            .await?
+        else {
+            return Ok(Err(SetError::invalid_properties()
+                .with_property(MdnProperty::ForEmailId)
+                .with_description("Email not found.")));
+        };
+        let metadata = metadata_
+            .unarchive::<MessageMetadata>()
+            .caused_by(trc::location!())?;
+        let Some(raw_message) = self
+            .blob_store()
+            .get_blob(metadata.blob_hash.0.as_slice(), 0..usize::MAX)
+            .await?
+        else {
+            return Ok(Err(SetError::invalid_properties()
+                .with_property(MdnProperty::ForEmailId)
+                .with_description("Blob for email not found.")));
+        };
+        let Some(message) = MessageParser::new().parse(&raw_message) else {
+            return Ok(Err(SetError::invalid_properties()
+                .with_property(MdnProperty::ForEmailId)
+                .with_description("Email could not be parsed.")));
+        };
+
+        // MDNs are only sent to the address requested by the original sender
+        let Some(rcpt_to) = message
+            .header_raw("Disposition-Notification-To")
+            .and_then(parse_header_address)
+        else {
+            return Ok(Err(SetError::new(SetErrorType::InvalidRecipients)
+                .with_description(
+                    "Email does not request a disposition notification.",
+                )));
+        };
+
+        // Build disposition notification
+        let server_name = &self.core.network.server_name;
+        let reporting_ua = reporting_ua.unwrap_or_else(|| format!("{server_name}; Stalwart"));
+        let final_recipient =
+            final_recipient.unwrap_or_else(|| format!("rfc822; {identity_email}"));
+        let original_message_id = message.message_id().map(|id| format!("<{id}>"));
+        let original_recipient = message
+            .header_raw("Original-Recipient")
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty());
+        let original_subject = message.subject().unwrap_or_default();
+
+        let mut report = String::with_capacity(256);
+        let _ = write!(report, "Reporting-UA: {reporting_ua}\r\n");
+        if let Some(original_recipient) = &original_recipient {
+            let _ = write!(report, "Original-Recipient: {original_recipient}\r\n");
+        }
+        let _ = write!(report, "Final-Recipient: {final_recipient}\r\n");
+        if let Some(original_message_id) = &original_message_id {
+            let _ = write!(report, "Original-Message-ID: {original_message_id}\r\n");
+        }
+        let _ = write!(
+            report,
+            "Disposition: {action_mode}/{sending_mode}; {disposition_type}\r\n"
+        );
+        for error in &errors {
+            let _ = write!(report, "Error: {error}\r\n");
+        }
+        for (name, value) in &extension_fields {
+            let _ = write!(report, "{name}: {value}\r\n");
+        }
+
+        let original_part = if include_original_message {
+            if raw_message.len() > self.core.email.mail_max_size {
+                return Ok(Err(SetError::new(SetErrorType::TooLarge).with_description(
+                    format!(
+                        "Message exceeds maximum size of {} bytes.",
+                        self.core.email.mail_max_size
+                    ),
+                )));
+            }
+            MimePart::new(
+                ContentType::new("message/rfc822"),
+                BodyPart::Text(String::from_utf8_lossy(&raw_message).into_owned().into()),
+            )
+        } else {
+            MimePart::new(
+                ContentType::new("text/rfc822-headers"),
+                BodyPart::Text(
+                    String::from_utf8_lossy(
+                        raw_message
+                            .get(..message.root_part().raw_body_offset() as usize)
+                            .unwrap_or_default(),
+                    )
+                    .into_owned()
+                    .into(),
+                ),
+            )
+        };
+
+        let mut builder = MessageBuilder::new()
+            .from(identity_email)
+            .to(rcpt_to.as_str())
+            .message_id(format!("<{}@{}>", make_boundary("."), server_name))
+            .subject(subject.unwrap_or_else(|| {
+                format!("Disposition notification ({disposition_type}): {original_subject}")
+            }));
+        if sending_mode == "mdn-sent-automatically" {
+            builder = builder.header("Auto-Submitted", HeaderType::Text("auto-replied".into()));
+        }
+        let mdn_message = builder
+            .body(MimePart::new(
+                ContentType::new("multipart/report")
+                    .attribute("report-type", "disposition-notification"),
+                BodyPart::Multipart(vec![
+                    MimePart::new(
+                        ContentType::new("text/plain"),
+                        BodyPart::Text(
+                            text_body
+                                .unwrap_or_else(|| {
+                                    format!(
+                                        "The message sent to {identity_email} with subject \"{original_subject}\" has been {disposition_type}.\r\n"
+                                    )
+                                })
+                                .into(),
+                        ),
+                    ),
+                    MimePart::new(
+                        ContentType::new("message/disposition-notification"),
+                        BodyPart::Text(report.into()),
+                    ),
+                    original_part,
+                ]),
+            ))
+            .write_to_vec()
+            .unwrap_or_default();
+
+        // Begin local SMTP session
+        let mut session = Session::<NullIo>::local(
+            self.clone(),
+            instance.clone(),
+            SessionData::local(
+                self.account_info(account_id)
+                    .await
+                    .caused_by(trc::location!())?,
+                None,
+                vec![],
+                vec![],
+                0,
+            ),
+        );
+        session.data.is_internal = true;
+
+        // MDNs are sent with a null reverse-path (RFC 8098, Section 2.1)
+        let mail_from = MailFrom {
+            address: Cow::Borrowed(""),
+            ..Default::default()
+        };
+        let rcpt_to = RcptTo {
+            address: Cow::Owned(rcpt_to),
+            ..Default::default()
+        };
+
+        // Spawn SMTP session to avoid overflowing the stack
+        let handle = tokio::spawn(async move {
+            // MAIL FROM
+            let _ = session.handle_mail_from(mail_from).await;
+            if let Some(error) = session.has_failed() {
+                return Err(SetError::new(SetErrorType::ForbiddenFrom)
+                    .with_description(format!("Server rejected MAIL-FROM: {}", error.trim())));
+            }
+
+            // RCPT TO
+            session.params.rcpt_errors_wait = Duration::from_secs(0);
+            let _ = session.handle_rcpt_to(rcpt_to).await;
+            if let Some(error) = session.has_failed() {
+                return Err(SetError::new(SetErrorType::ForbiddenToSend)
+                    .with_description(format!("Server rejected RCPT-TO: {}", error.trim())));
+            }
+
+            // DATA
+            session.data.message = mdn_message;
+            let response = session.queue_message().await;
+            if let smtp::core::State::Accepted(_) = session.state {
+                Ok(())
+            } else {
+                Err(
+                    SetError::new(SetErrorType::ForbiddenToSend).with_description(format!(
+                        "Server rejected DATA: {}",
+                        std::str::from_utf8(&response).unwrap_or_default().trim()
+                    )),
+                )
+            }
+        });
+
+        match handle.await {
+            Ok(Ok(())) => {
+                let mut mdn = Map::with_capacity(4)
+                    .with_key_value(MdnProperty::ReportingUa, reporting_ua)
+                    .with_key_value(MdnProperty::FinalRecipient, final_recipient);
+                if let Some(original_recipient) = original_recipient {
+                    mdn.insert_unchecked(MdnProperty::OriginalRecipient, original_recipient);
+                }
+                if let Some(original_message_id) = original_message_id {
+                    mdn.insert_unchecked(MdnProperty::OriginalMessageId, original_message_id);
+                }
+
+                Ok(Ok((email_id, mdn.into())))
+            }
+            Ok(Err(err)) => Ok(Err(err)),
+            Err(err) => Err(trc::EventType::Server(trc::ServerEvent::ThreadError)
+                .reason(err)
+                .caused_by(trc::location!())
+                .details("Join Error")),
+        }
+    }
+}
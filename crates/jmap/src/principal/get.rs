@@ -12,7 +12,7 @@ use jmap_proto::{
     types::state::State,
 };
 use jmap_tools::{Key, Map, Value};
-use registry::schema::prelude::{ObjectType, Permission};
+use registry::schema::prelude::{ObjectType, Permission, PrincipalKind};
 use std::future::Future;
 use store::{registry::RegistryQuery, roaring::RoaringBitmap};
 use trc::AddContext;
@@ -91,7 +91,11 @@ impl PrincipalGet for Server {
                     PrincipalProperty::Id => Value::Element(PrincipalValue::Id(id)),
                     PrincipalProperty::Type => {
                         Value::Element(PrincipalValue::Type(if principal.is_user_account() {
-                            PrincipalType::Individual
+                            match principal.principal_kind() {
+                                PrincipalKind::Individual => PrincipalType::Individual,
+                                PrincipalKind::Resource => PrincipalType::Resource,
+                                PrincipalKind::Location => PrincipalType::Location,
+                            }
                         } else {
                             PrincipalType::Group
                         }))
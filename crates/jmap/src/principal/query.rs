@@ -13,7 +13,7 @@ use jmap_proto::{
 };
 use registry::{
     schema::{
-        enums::AccountType,
+        enums::{AccountType, PrincipalKind},
         prelude::{ObjectType, Permission, Property},
     },
     types::EnumImpl,
@@ -95,25 +95,49 @@ impl PrincipalQuery for Server {
                         ));
                     }
                     PrincipalFilter::Type(principal_type) => {
-                        let typ = match principal_type {
-                            PrincipalType::Individual => AccountType::User,
-                            PrincipalType::Group => AccountType::Group,
-                            _ => {
+                        let (typ, kind) = match principal_type {
+                            PrincipalType::Individual => {
+                                (AccountType::User, PrincipalKind::Individual)
+                            }
+                            PrincipalType::Resource => (AccountType::User, PrincipalKind::Resource),
+                            PrincipalType::Location => (AccountType::User, PrincipalKind::Location),
+                            PrincipalType::Group => (AccountType::Group, PrincipalKind::Individual),
+                            PrincipalType::Other => {
                                 filters.push(SearchFilter::is_in_set(Default::default()));
                                 continue;
                             }
                         };
 
-                        filters.push(SearchFilter::is_in_set(
-                            self.registry()
-                                .query::<RoaringBitmap>(
-                                    RegistryQuery::new(ObjectType::Account)
-                                        .equal(Property::Type, typ.to_id())
-                                        .with_tenant(access_token.tenant_id()),
-                                )
-                                .await
-                                .caused_by(trc::location!())?,
-                        ));
+                        let query = if kind == PrincipalKind::Individual {
+                            RegistryQuery::new(ObjectType::Account)
+                                .equal(Property::Type, typ.to_id())
+                        } else {
+                            RegistryQuery::new(ObjectType::Account)
+                                .equal(Property::PrincipalKind, kind.to_id())
+                        };
+                        let mut account_ids = self
+                            .registry()
+                            .query::<RoaringBitmap>(query.with_tenant(access_token.tenant_id()))
+                            .await
+                            .caused_by(trc::location!())?;
+
+                        // Accounts indexed before principal kinds were introduced carry no
+                        // kind, so individuals are obtained by excluding resources and locations
+                        if principal_type == PrincipalType::Individual {
+                            for kind in [PrincipalKind::Resource, PrincipalKind::Location] {
+                                account_ids -= self
+                                    .registry()
+                                    .query::<RoaringBitmap>(
+                                        RegistryQuery::new(ObjectType::Account)
+                                            .equal(Property::PrincipalKind, kind.to_id())
+                                            .with_tenant(access_token.tenant_id()),
+                                    )
+                                    .await
+                                    .caused_by(trc::location!())?;
+                            }
+                        }
+
+                        filters.push(SearchFilter::is_in_set(account_ids));
                     }
                     other => {
                         return Err(trc::JmapEvent::UnsupportedFilter
@@ -83,6 +83,13 @@ impl QuotaGet for Server {
                     QuotaProperty::Used => {
                         (self.get_used_quota_account(account_id).await? as u64).into()
                     }
+                    QuotaProperty::WarnLimit => self
+                        .core
+                        .email
+                        .quota_warn_thresholds
+                        .first()
+                        .map(|threshold| account.as_ref().disk_quota() * threshold / 100)
+                        .into(),
                     QuotaProperty::HardLimit => account.as_ref().disk_quota().into(),
                     QuotaProperty::Scope => "account".to_string().into(),
                     QuotaProperty::Name => account.as_ref().name().to_string().into(),
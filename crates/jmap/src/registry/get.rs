@@ -8,8 +8,9 @@ use crate::registry::{
     EnterpriseRegistry,
     mapping::{
         RegistryGetResponse, account::account_get, bootstrap::bootstrap_get,
-        cluster::cluster_node_get, log::log_get, queued_message::queued_message_get,
-        report::report_get, spam_sample::spam_sample_get, task::task_get,
+        cluster::cluster_node_get, log::log_get, quarantine::quarantine_get,
+        queued_message::queued_message_get, report::report_get, spam_sample::spam_sample_get,
+        task::task_get, warm_up::ip_warm_up_get,
     },
 };
 use common::{Server, auth::AccessToken, network::dkim::generate_dkim_public_key};
@@ -321,8 +322,12 @@ impl RegistryGet for Server {
             ObjectType::QueuedMessage => {
                 queued_message_get(get).await.map(|get| get.into_response())
             }
+            ObjectType::QuarantinedMessage => {
+                quarantine_get(get).await.map(|get| get.into_response())
+            }
             ObjectType::Task => task_get(get).await.map(|get| get.into_response()),
             ObjectType::ClusterNode => cluster_node_get(get).await.map(|get| get.into_response()),
+            ObjectType::MtaIpWarmUp => ip_warm_up_get(get).await.map(|get| get.into_response()),
             ObjectType::ArfExternalReport
             | ObjectType::DmarcExternalReport
             | ObjectType::TlsExternalReport
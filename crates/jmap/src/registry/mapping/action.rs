@@ -357,6 +357,7 @@ async fn classify_spam(server: &Server, mut request: SpamClassify) -> Option<Spa
         dkim_result: dkim_output.as_slice(),
         dmarc_result: Some(&dmarc_result),
         dmarc_policy: Some(&dmarc_policy),
+        bimi_result: None,
         iprev_result: Some(&iprev_result),
         remote_ip,
         ehlo_domain: Some(ehlo_domain.as_str()),
@@ -385,13 +386,16 @@ async fn classify_spam(server: &Server, mut request: SpamClassify) -> Option<Spa
     request.result = match result {
         SpamFilterAction::Allow(result) => {
             request.score = (result.score as f64).into();
-            if result.is_spam {
+            if result.quarantine.is_some() {
+                SpamClassifyResult::Quarantine
+            } else if result.is_spam {
                 SpamClassifyResult::Spam
             } else {
                 SpamClassifyResult::Ham
             }
         }
         SpamFilterAction::Discard => SpamClassifyResult::Discard,
+        SpamFilterAction::Quarantine => SpamClassifyResult::Quarantine,
         SpamFilterAction::Reject | SpamFilterAction::Disabled => SpamClassifyResult::Reject,
     };
 
@@ -400,6 +404,7 @@ async fn classify_spam(server: &Server, mut request: SpamClassify) -> Option<Spa
         let (score, disposition) = match server.core.spam.lists.scores.get(&tag) {
             Some(SpamFilterAction::Allow(score)) => (*score, SpamClassifyTagDisposition::Score),
             Some(SpamFilterAction::Discard) => (0.0, SpamClassifyTagDisposition::Discard),
+            Some(SpamFilterAction::Quarantine) => (0.0, SpamClassifyTagDisposition::Quarantine),
             _ => (0.0, SpamClassifyTagDisposition::Reject),
         };
         request.tags.append(
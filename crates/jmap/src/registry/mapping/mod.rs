@@ -30,11 +30,13 @@ pub mod domain;
 pub mod log;
 pub mod principal;
 pub mod public_key;
+pub mod quarantine;
 pub mod queued_message;
 pub mod report;
 pub mod spam_sample;
 pub mod task;
 pub mod tls;
+pub mod warm_up;
 
 // SPDX-SnippetBegin
 // SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
@@ -0,0 +1,225 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::{
+    api::query::QueryResponseBuilder,
+    registry::{
+        mapping::{RegistryGetResponse, RegistryQueryResponse, RegistrySetResponse},
+        query::RegistryQueryFilters,
+    },
+};
+use jmap_proto::{error::set::SetError, types::state::State};
+use jmap_tools::{Key, Value};
+use registry::{
+    jmap::IntoValue,
+    schema::{
+        enums::{Permission, QuarantineStatus},
+        prelude::Property,
+        structs::QuarantinedMessage,
+    },
+    types::EnumImpl,
+};
+use smtp::queue::quarantine::SmtpQuarantine;
+use std::str::FromStr;
+use store::{
+    ValueKey,
+    registry::RegistryQuery,
+    write::{RegistryClass, ValueClass},
+};
+use types::{blob::BlobClass, id::Id};
+
+pub(crate) async fn quarantine_set(
+    mut set: RegistrySetResponse<'_>,
+) -> trc::Result<RegistrySetResponse<'_>> {
+    // Quarantined messages are only created by the SMTP server
+    set.fail_all_create("Quarantined messages cannot be created");
+
+    let account_id = set.is_account_filtered.then_some(set.account_id);
+    'outer: for (id, value) in set.update.drain(..) {
+        let mut status = QuarantineStatus::Held;
+        for (key, value) in value.into_expanded_object() {
+            match (key, value) {
+                (Key::Property(Property::Status), Value::Str(status_)) => {
+                    let Some(status_) = QuarantineStatus::parse(&status_) else {
+                        set.response.not_updated.append(
+                            id,
+                            SetError::invalid_patch()
+                                .with_property(Property::Status)
+                                .with_description("Invalid value for property"),
+                        );
+                        continue 'outer;
+                    };
+                    status = status_;
+                }
+                (Key::Property(Property::Id), _) => {}
+                (key, _) => {
+                    set.response.not_updated.append(
+                        id,
+                        SetError::invalid_properties().with_property(key.into_owned()),
+                    );
+                    continue 'outer;
+                }
+            }
+        }
+
+        if status == QuarantineStatus::RequestRelease {
+            if set
+                .server
+                .quarantine_release(id.id(), account_id, 0)
+                .await?
+            {
+                set.response.updated.append(id, None);
+            } else {
+                set.response.not_updated.append(id, SetError::not_found());
+            }
+        } else {
+            set.response.not_updated.append(
+                id,
+                SetError::invalid_properties()
+                    .with_property(Property::Status)
+                    .with_description(
+                        "Quarantined messages can only be updated to request a release",
+                    ),
+            );
+        }
+    }
+
+    // Process items to destroy
+    for id in set.destroy.drain(..) {
+        if set.server.quarantine_delete(id.id(), account_id).await? {
+            set.response.destroyed.push(id);
+        } else {
+            set.response.not_destroyed.append(id, SetError::not_found());
+        }
+    }
+
+    Ok(set)
+}
+
+pub(crate) async fn quarantine_get(
+    mut get: RegistryGetResponse<'_>,
+) -> trc::Result<RegistryGetResponse<'_>> {
+    let object_id = get.object_type.to_id();
+    let ids = if let Some(ids) = get.ids.take() {
+        ids
+    } else {
+        let query = if !get.is_account_filtered {
+            RegistryQuery::new(get.object_type).greater_than_or_equal(Property::AccountId, 0u64)
+        } else {
+            RegistryQuery::new(get.object_type).with_account(get.account_id)
+        }
+        .with_limit(get.server.core.jmap.get_max_objects);
+
+        get.server.registry().query::<Vec<Id>>(query).await?
+    };
+
+    for id in ids {
+        if let Some(mut item) = get
+            .server
+            .store()
+            .get_value::<QuarantinedMessage>(ValueKey::from(ValueClass::Registry(
+                RegistryClass::Item {
+                    object_id,
+                    item_id: id.id(),
+                },
+            )))
+            .await?
+            .filter(|item| {
+                !get.is_account_filtered
+                    || item
+                        .account_id
+                        .is_some_and(|account_id| account_id.document_id() == get.account_id)
+            })
+        {
+            if get.is_account_filtered {
+                item.blob_id.class = BlobClass::Reserved {
+                    account_id: get.account_id,
+                    expires: item.expires_at.timestamp() as u64,
+                };
+            }
+
+            get.insert(id, item.into_value());
+        } else {
+            get.not_found(id);
+        }
+    }
+
+    Ok(get)
+}
+
+pub(crate) async fn quarantine_query(
+    mut req: RegistryQueryResponse<'_>,
+) -> trc::Result<QueryResponseBuilder> {
+    let can_impersonate = req.access_token.has_permission(Permission::Impersonate);
+    let mut account_id = None;
+
+    req.request
+        .extract_filters(|property, _, value| match property {
+            Property::AccountId if can_impersonate => {
+                if let Some(id) = value.as_str().and_then(|s| Id::from_str(s).ok()) {
+                    account_id = Some(id);
+                    true
+                } else {
+                    false
+                }
+            }
+
+            _ => false,
+        })?;
+
+    let mut query = if let Some(account_id) = account_id {
+        RegistryQuery::new(req.object_type).with_account(account_id.document_id())
+    } else if !can_impersonate {
+        RegistryQuery::new(req.object_type).with_account(req.request.account_id.document_id())
+    } else {
+        RegistryQuery::new(req.object_type).greater_than_or_equal(Property::AccountId, 0u64)
+    };
+
+    let params = req
+        .request
+        .extract_parameters(req.server.core.jmap.query_max_results, Some(Property::Id))?;
+
+    if let Some(limit) = params.limit {
+        query = query.with_limit(limit);
+        if let Some(anchor) = params.anchor {
+            query = query.with_anchor(anchor);
+        } else if let Some(position) = params.position {
+            query = query.with_index_start(position);
+        }
+    }
+
+    let mut results = req.server.registry().query::<Vec<Id>>(query).await?;
+
+    match params.sort_by {
+        Property::Id => {
+            if !params.sort_ascending {
+                results.sort_unstable_by(|a, b| b.cmp(a));
+            }
+        }
+        property => {
+            return Err(trc::JmapEvent::UnsupportedSort.into_err().details(format!(
+                "Property {} is not supported for sorting",
+                property
+            )));
+        }
+    }
+
+    // Build response
+    let mut response = QueryResponseBuilder::new(
+        results.len(),
+        req.server.core.jmap.query_max_results,
+        State::Initial,
+        &req.request,
+    );
+
+    for id in results {
+        if !response.add_id(id) {
+            break;
+        }
+    }
+
+    Ok(response)
+}
@@ -0,0 +1,123 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::{
+    api::query::QueryResponseBuilder,
+    registry::mapping::{RegistryGetResponse, RegistryQueryResponse},
+};
+use common::Server;
+use jmap_proto::types::state::State;
+use registry::{
+    jmap::IntoValue,
+    schema::{enums::MtaIpWarmUpStatus, structs::MtaIpWarmUp},
+    types::{datetime::UTCDateTime, ipaddr::IpAddr},
+};
+use store::{ahash::AHashMap, write::now};
+use trc::AddContext;
+use types::id::Id;
+
+pub(crate) async fn ip_warm_up_get(
+    mut get: RegistryGetResponse<'_>,
+) -> trc::Result<RegistryGetResponse<'_>> {
+    let items = ip_warm_up_list(get.server).await?;
+
+    if let Some(ids) = get.ids.take() {
+        let mut items = items.into_iter().collect::<AHashMap<_, _>>();
+        for id in ids {
+            if let Some(item) = items.remove(&id.id()) {
+                get.insert(id, item.into_value());
+            } else {
+                get.not_found(id);
+            }
+        }
+    } else {
+        for (id, item) in items {
+            get.insert(id.into(), item.into_value());
+        }
+    }
+
+    Ok(get)
+}
+
+pub(crate) async fn ip_warm_up_query(
+    req: RegistryQueryResponse<'_>,
+) -> trc::Result<QueryResponseBuilder> {
+    let items = ip_warm_up_list(req.server).await?;
+
+    // Build response
+    let mut response = QueryResponseBuilder::new(
+        items.len(),
+        req.server.core.jmap.query_max_results,
+        State::Initial,
+        &req.request,
+    );
+
+    for (id, _) in items {
+        if !response.add_id(id.into()) {
+            break;
+        }
+    }
+
+    Ok(response)
+}
+
+async fn ip_warm_up_list(server: &Server) -> trc::Result<Vec<(u64, MtaIpWarmUp)>> {
+    let mut ips = server
+        .core
+        .smtp
+        .queue
+        .connection_strategy
+        .values()
+        .flat_map(|strategy| strategy.source_ipv4.iter().chain(&strategy.source_ipv6))
+        .filter_map(|ip_host| {
+            ip_host
+                .warm_up
+                .as_ref()
+                .map(|warm_up| (ip_host.ip, warm_up))
+        })
+        .collect::<Vec<_>>();
+    ips.sort_unstable_by(|(ip_a, warm_up_a), (ip_b, warm_up_b)| {
+        (warm_up_a.strategy_id, ip_a).cmp(&(warm_up_b.strategy_id, ip_b))
+    });
+
+    let now = now();
+    let mut items = Vec::with_capacity(ips.len());
+    for (ip, warm_up) in ips {
+        let mut item = MtaIpWarmUp {
+            connection_strategy_id: Id::new(warm_up.strategy_id),
+            source_ip: IpAddr(ip),
+            warm_up_start: UTCDateTime::from(warm_up.start),
+            warm_up_days: warm_up.days,
+            ..Default::default()
+        };
+
+        match warm_up.day(now) {
+            Some(day) => {
+                if let Some(limit) = warm_up.daily_limit(day) {
+                    item.status = MtaIpWarmUpStatus::WarmingUp;
+                    item.day = day + 1;
+                    item.daily_limit = limit;
+                    item.sent_today = server
+                        .in_memory_store()
+                        .counter_get(warm_up.counter_key(ip, day, "").as_slice())
+                        .await
+                        .caused_by(trc::location!())?
+                        .max(0) as u64;
+                } else {
+                    item.status = MtaIpWarmUpStatus::Complete;
+                    item.day = warm_up.days;
+                }
+            }
+            None => {
+                item.status = MtaIpWarmUpStatus::Scheduled;
+            }
+        }
+
+        items.push((warm_up.id, item));
+    }
+
+    Ok(items)
+}
@@ -10,8 +10,9 @@ use crate::{
         EnterpriseRegistry,
         mapping::{
             RegistryQueryResponse, account::credential_query, cluster::cluster_node_query,
-            log::log_query, queued_message::queued_message_query, report::report_query,
-            spam_sample::spam_sample_query, task::task_query,
+            log::log_query, quarantine::quarantine_query, queued_message::queued_message_query,
+            report::report_query, spam_sample::spam_sample_query, task::task_query,
+            warm_up::ip_warm_up_query,
         },
     },
 };
@@ -23,7 +24,7 @@ use jmap_proto::{
 };
 use registry::{
     schema::{
-        enums::{AccountType, Permission},
+        enums::{AccountType, Permission, PrincipalKind},
         prelude::{ObjectType, Property},
     },
     types::{
@@ -119,6 +120,15 @@ impl RegistryQuery for Server {
             .await
             .and_then(|response| response.build()),
 
+            ObjectType::QuarantinedMessage => quarantine_query(RegistryQueryResponse {
+                server: self,
+                access_token,
+                object_type,
+                request,
+            })
+            .await
+            .and_then(|response| response.build()),
+
             ObjectType::QueuedMessage => queued_message_query(RegistryQueryResponse {
                 server: self,
                 access_token,
@@ -137,6 +147,15 @@ impl RegistryQuery for Server {
             .await
             .and_then(|response| response.build()),
 
+            ObjectType::MtaIpWarmUp => ip_warm_up_query(RegistryQueryResponse {
+                server: self,
+                access_token,
+                object_type,
+                request,
+            })
+            .await
+            .and_then(|response| response.build()),
+
             ObjectType::ApiKey | ObjectType::AppPassword => {
                 credential_query(RegistryQueryResponse {
                     server: self,
@@ -207,6 +226,13 @@ impl RegistryQuery for Server {
                                 AccountType::parse(&value)
                                     .map(|id| RegistryFilterValue::from(id.to_id()))
                             }
+                            (IndexSchemaValueType::Enum, serde_json::Value::String(value))
+                                if (property == Property::PrincipalKind
+                                    && object_type == ObjectType::Account) =>
+                            {
+                                PrincipalKind::parse(&value)
+                                    .map(|id| RegistryFilterValue::from(id.to_id()))
+                            }
                             (IndexSchemaValueType::Boolean, serde_json::Value::Bool(value)) => {
                                 Some(RegistryFilterValue::from(value))
                             }
@@ -19,6 +19,7 @@ use crate::registry::{
             validate_tenant_quota,
         },
         public_key::validate_public_key,
+        quarantine::quarantine_set,
         queued_message::queued_message_set,
         report::report_set,
         spam_sample::spam_sample_set,
@@ -672,6 +673,10 @@ impl RegistrySet for Server {
                 queued_message_set(set).await.map(|set| set.into_response())
             }
 
+            ObjectType::QuarantinedMessage => {
+                quarantine_set(set).await.map(|set| set.into_response())
+            }
+
             ObjectType::Task => task_set(set).await.map(|set| set.into_response()),
 
             ObjectType::Action => action_set(set).await.map(|set| set.into_response()),
@@ -686,6 +691,13 @@ impl RegistrySet for Server {
                 set.fail_all_destroy("Telemetry objects cannot be deleted");
                 Ok(set.into_response())
             }
+
+            ObjectType::MtaIpWarmUp => {
+                set.fail_all_create("IP warm-up progress is derived from connection strategies");
+                set.fail_all_update("IP warm-up progress is derived from connection strategies");
+                set.fail_all_destroy("IP warm-up progress is derived from connection strategies");
+                Ok(set.into_response())
+            }
         }
     }
 }
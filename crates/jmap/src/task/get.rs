@@ -0,0 +1,102 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::{
+    calendar_event::get::CalendarEventGet,
+    task::{transmute_get_request, transmute_get_response},
+};
+use calcard::jscalendar::{JSCalendarProperty, JSCalendarValue};
+use common::{Server, auth::AccessToken};
+use groupware::cache::GroupwareCache;
+use jmap_proto::{
+    method::get::{GetRequest, GetResponse},
+    object::task,
+};
+use jmap_tools::{Key, Value};
+use store::ahash::AHashMap;
+use types::{collection::SyncCollection, id::Id};
+
+pub trait TaskGet: Sync + Send {
+    fn task_get(
+        &self,
+        request: GetRequest<task::Task>,
+        access_token: &AccessToken,
+    ) -> impl Future<Output = trc::Result<GetResponse<task::Task>>> + Send;
+}
+
+impl TaskGet for Server {
+    async fn task_get(
+        &self,
+        mut request: GetRequest<task::Task>,
+        access_token: &AccessToken,
+    ) -> trc::Result<GetResponse<task::Task>> {
+        let account_id = request.account_id.document_id();
+        let cache = self
+            .fetch_dav_resources(
+                access_token.account_id(),
+                account_id,
+                SyncCollection::Calendar,
+            )
+            .await?;
+        let task_lists = cache
+            .resources
+            .iter()
+            .filter(|resource| resource.is_task())
+            .filter_map(|resource| Some((resource.document_id, resource.parent_id()?)))
+            .collect::<AHashMap<_, _>>();
+
+        // Only calendar resources containing a VTODO component are tasks
+        let mut not_found = vec![];
+        let ids = if let Some(ids) = request.unwrap_ids(self.core.jmap.get_max_objects)? {
+            ids.into_iter()
+                .filter(|id| {
+                    if task_lists.contains_key(&id.document_id()) {
+                        true
+                    } else {
+                        not_found.push(*id);
+                        false
+                    }
+                })
+                .collect()
+        } else {
+            let mut ids = task_lists.keys().copied().collect::<Vec<_>>();
+            ids.sort_unstable();
+            ids.into_iter()
+                .take(self.core.jmap.get_max_objects)
+                .map(Id::from)
+                .collect()
+        };
+        let arguments = std::mem::take(&mut request.arguments);
+
+        let mut response: GetResponse<task::Task> = self
+            .calendar_event_get(transmute_get_request(request, ids, arguments), access_token)
+            .await
+            .map(|response| transmute_get_response(response, not_found))?;
+
+        // Add taskListId
+        for item in response.list.iter_mut() {
+            let Some(item) = item.as_object_mut() else {
+                continue;
+            };
+            if let Some(task_list_id) =
+                item.get(&Key::Property(JSCalendarProperty::Id))
+                    .and_then(|id| match id {
+                        Value::Element(JSCalendarValue::Id(id)) => {
+                            task_lists.get(&id.document_id()).copied()
+                        }
+                        _ => None,
+                    })
+            {
+                item.insert_unchecked(
+                    Key::Borrowed("taskListId"),
+                    Value::Element(JSCalendarValue::Id(Id::from(task_list_id))),
+                );
+            }
+        }
+
+        Ok(response)
+    }
+}
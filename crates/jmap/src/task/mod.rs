@@ -0,0 +1,96 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use jmap_proto::{
+    method::{
+        get::{GetRequest, GetResponse},
+        set::{SetRequest, SetResponse},
+    },
+    object::JmapObject,
+    request::reference::{MaybeIdReference, MaybeResultReference},
+};
+use types::id::Id;
+
+pub mod get;
+pub mod query;
+pub mod set;
+
+// Tasks and task lists are stored as calendar events and calendars, so the
+// Task/* and TaskList/* methods are implemented on top of their calendar
+// counterparts after restricting the ids to VTODO resources.
+
+pub(crate) fn transmute_get_request<F, T>(
+    request: GetRequest<F>,
+    ids: Vec<Id>,
+    arguments: T::GetArguments,
+) -> GetRequest<T>
+where
+    F: JmapObject,
+    T: JmapObject<Property = F::Property, Id = Id>,
+{
+    GetRequest {
+        account_id: request.account_id,
+        ids: Some(MaybeResultReference::Value(
+            ids.into_iter().map(MaybeIdReference::Id).collect(),
+        )),
+        properties: request.properties,
+        arguments,
+    }
+}
+
+pub(crate) fn transmute_get_response<F, T>(
+    response: GetResponse<F>,
+    mut not_found: Vec<Id>,
+) -> GetResponse<T>
+where
+    F: JmapObject<Id = Id>,
+    T: JmapObject<Property = F::Property, Element = F::Element, Id = Id>,
+{
+    not_found.extend(response.not_found);
+
+    GetResponse {
+        account_id: response.account_id,
+        state: response.state,
+        list: response.list,
+        not_found,
+    }
+}
+
+pub(crate) fn transmute_set_request<'x, F, T>(
+    request: SetRequest<'x, F>,
+    arguments: T::SetArguments<'x>,
+) -> SetRequest<'x, T>
+where
+    F: JmapObject,
+    T: JmapObject<Property = F::Property, Element = F::Element>,
+{
+    SetRequest {
+        account_id: request.account_id,
+        if_in_state: request.if_in_state,
+        create: request.create,
+        update: request.update,
+        destroy: request.destroy,
+        arguments,
+    }
+}
+
+pub(crate) fn transmute_set_response<F, T>(response: SetResponse<F>) -> SetResponse<T>
+where
+    F: JmapObject,
+    T: JmapObject<Property = F::Property, Element = F::Element>,
+{
+    SetResponse {
+        account_id: response.account_id,
+        old_state: response.old_state,
+        new_state: response.new_state,
+        created: response.created,
+        updated: response.updated,
+        destroyed: response.destroyed,
+        not_created: response.not_created,
+        not_updated: response.not_updated,
+        not_destroyed: response.not_destroyed,
+    }
+}
@@ -0,0 +1,196 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::{
+    api::query::QueryResponseBuilder, calendar_event::query::local_timestamp,
+    changes::state::JmapCacheState,
+};
+use calcard::common::timezone::Tz;
+use common::{Server, auth::AccessToken};
+use groupware::cache::GroupwareCache;
+use jmap_proto::{
+    method::query::{Filter, QueryRequest, QueryResponse},
+    object::task::{self, TaskComparator, TaskFilter},
+    request::MaybeInvalid,
+};
+use nlp::language::Language;
+use store::{
+    roaring::RoaringBitmap,
+    search::{CalendarSearchField, SearchComparator, SearchFilter, SearchQuery},
+    write::SearchIndex,
+};
+use types::{acl::Acl, collection::SyncCollection};
+
+pub trait TaskQuery: Sync + Send {
+    fn task_query(
+        &self,
+        request: QueryRequest<task::Task>,
+        access_token: &AccessToken,
+    ) -> impl Future<Output = trc::Result<QueryResponse>> + Send;
+}
+
+impl TaskQuery for Server {
+    async fn task_query(
+        &self,
+        mut request: QueryRequest<task::Task>,
+        access_token: &AccessToken,
+    ) -> trc::Result<QueryResponse> {
+        let account_id = request.account_id.document_id();
+        let mut filters = Vec::with_capacity(request.filter.len());
+        let cache = self
+            .fetch_dav_resources(
+                access_token.account_id(),
+                account_id,
+                SyncCollection::Calendar,
+            )
+            .await?;
+
+        for cond in std::mem::take(&mut request.filter) {
+            match cond {
+                Filter::Property(cond) => match cond {
+                    TaskFilter::InTaskList(MaybeInvalid::Value(id)) => {
+                        filters.push(SearchFilter::is_in_set(RoaringBitmap::from_iter(
+                            cache.children_ids(id.document_id()),
+                        )))
+                    }
+                    TaskFilter::Uid(uid) => {
+                        filters.push(SearchFilter::eq(CalendarSearchField::Uid, uid));
+                    }
+                    TaskFilter::Text(value) => {
+                        let (text, language) =
+                            Language::detect(value, self.core.email.default_language);
+                        filters.push(SearchFilter::Or);
+                        filters.push(SearchFilter::has_text(
+                            CalendarSearchField::Title,
+                            text.clone(),
+                            language,
+                        ));
+                        filters.push(SearchFilter::has_text(
+                            CalendarSearchField::Description,
+                            text,
+                            language,
+                        ));
+                        filters.push(SearchFilter::End);
+                    }
+                    TaskFilter::Title(title) => {
+                        filters.push(SearchFilter::has_text_detect(
+                            CalendarSearchField::Title,
+                            title,
+                            self.core.email.default_language,
+                        ));
+                    }
+                    TaskFilter::Description(description) => {
+                        filters.push(SearchFilter::has_text_detect(
+                            CalendarSearchField::Description,
+                            description,
+                            self.core.email.default_language,
+                        ));
+                    }
+                    TaskFilter::After(after) => {
+                        if let Some(after) = local_timestamp(&after, Tz::UTC) {
+                            filters.push(SearchFilter::gt(CalendarSearchField::Due, after));
+                        }
+                    }
+                    TaskFilter::Before(before) => {
+                        if let Some(before) = local_timestamp(&before, Tz::UTC) {
+                            filters.push(SearchFilter::lt(CalendarSearchField::Due, before));
+                        }
+                    }
+                    TaskFilter::IsCompleted(is_completed) => {
+                        if !is_completed {
+                            filters.push(SearchFilter::Not);
+                        }
+                        filters.push(SearchFilter::gt(CalendarSearchField::Completed, 0i64));
+                        if !is_completed {
+                            filters.push(SearchFilter::End);
+                        }
+                    }
+                    unsupported => {
+                        return Err(trc::JmapEvent::UnsupportedFilter
+                            .into_err()
+                            .details(unsupported.into_string()));
+                    }
+                },
+                Filter::And => {
+                    filters.push(SearchFilter::And);
+                }
+                Filter::Or => {
+                    filters.push(SearchFilter::Or);
+                }
+                Filter::Not => {
+                    filters.push(SearchFilter::Not);
+                }
+                Filter::Close => {
+                    filters.push(SearchFilter::End);
+                }
+            }
+        }
+
+        let comparators = request
+            .sort
+            .take()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|comparator| {
+                let field = match comparator.property {
+                    TaskComparator::Due => CalendarSearchField::Due,
+                    TaskComparator::Completed => CalendarSearchField::Completed,
+                    TaskComparator::PercentComplete => CalendarSearchField::PercentComplete,
+                    TaskComparator::Start => CalendarSearchField::Start,
+                    TaskComparator::Uid => CalendarSearchField::Uid,
+                    TaskComparator::Created | TaskComparator::Updated => {
+                        return Err(trc::JmapEvent::UnsupportedSort
+                            .into_err()
+                            .details(comparator.property.into_string().into_owned()));
+                    }
+                    TaskComparator::_T(other) => {
+                        return Err(trc::JmapEvent::UnsupportedSort
+                            .into_err()
+                            .details(other.to_string()));
+                    }
+                };
+                Ok(SearchComparator::field(field, comparator.is_ascending))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // Restrict results to calendar resources containing a VTODO component
+        let mut mask = if access_token.is_shared(account_id) {
+            cache.shared_items(access_token, [Acl::ReadItems], true)
+        } else {
+            cache.document_ids(false).collect()
+        };
+        mask &= cache
+            .resources
+            .iter()
+            .filter(|resource| resource.is_task())
+            .map(|resource| resource.document_id)
+            .collect::<RoaringBitmap>();
+
+        let results = self
+            .search_store()
+            .query_account(
+                SearchQuery::new(SearchIndex::Calendar)
+                    .with_filters(filters)
+                    .with_comparators(comparators)
+                    .with_account_id(account_id)
+                    .with_mask(mask),
+            )
+            .await?;
+
+        let mut response = QueryResponseBuilder::new(
+            results.len(),
+            self.core.jmap.query_max_results,
+            cache.get_state(false),
+            &request,
+        );
+        for document_id in results {
+            if !response.add(0, document_id) {
+                break;
+            }
+        }
+        response.build()
+    }
+}
@@ -0,0 +1,218 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::{
+    calendar_event::set::CalendarEventSet,
+    task::{transmute_set_request, transmute_set_response},
+};
+use calcard::jscalendar::{JSCalendarProperty, JSCalendarType, JSCalendarValue};
+use common::{DavResources, Server, auth::AccessToken};
+use groupware::cache::GroupwareCache;
+use http_proto::HttpSessionData;
+use jmap_proto::{
+    error::set::SetError,
+    method::set::{SetRequest, SetResponse},
+    object::task,
+    request::{MaybeInvalid, reference::MaybeResultReference},
+};
+use jmap_tools::{Key, Map, Value};
+use std::str::FromStr;
+use types::{blob::BlobId, collection::SyncCollection, id::Id};
+
+type TaskValue<'x> = Value<'x, JSCalendarProperty<Id>, JSCalendarValue<Id, BlobId>>;
+
+pub trait TaskSet: Sync + Send {
+    fn task_set(
+        &self,
+        request: SetRequest<'_, task::Task>,
+        access_token: &AccessToken,
+        session: &HttpSessionData,
+    ) -> impl Future<Output = trc::Result<SetResponse<task::Task>>> + Send;
+}
+
+impl TaskSet for Server {
+    async fn task_set(
+        &self,
+        mut request: SetRequest<'_, task::Task>,
+        access_token: &AccessToken,
+        session: &HttpSessionData,
+    ) -> trc::Result<SetResponse<task::Task>> {
+        let account_id = request.account_id.document_id();
+        let cache = self
+            .fetch_dav_resources(
+                access_token.account_id(),
+                account_id,
+                SyncCollection::Calendar,
+            )
+            .await?;
+        let is_task = |id: &Id| {
+            cache
+                .resources
+                .iter()
+                .find(|resource| resource.document_id == id.document_id())
+                .is_none_or(|resource| resource.is_task())
+        };
+
+        // Map taskListId to calendarIds and make sure new objects are tasks
+        let mut not_created = vec![];
+        let mut not_updated = vec![];
+        let mut not_destroyed = vec![];
+        if let Some(create) = request.create.take() {
+            request.create = Some(
+                create
+                    .into_iter()
+                    .filter_map(|(id, object)| match map_task(&cache, object, true) {
+                        Ok(object) => Some((id, object)),
+                        Err(err) => {
+                            not_created.push((id, err));
+                            None
+                        }
+                    })
+                    .collect(),
+            );
+        }
+        if let Some(update) = request.update.take() {
+            request.update = Some(
+                update
+                    .into_iter()
+                    .filter_map(|(id, object)| match &id {
+                        MaybeInvalid::Value(task_id) if !is_task(task_id) => {
+                            not_updated.push((*task_id, SetError::not_found()));
+                            None
+                        }
+                        MaybeInvalid::Value(task_id) => match map_task(&cache, object, false) {
+                            Ok(object) => Some((id, object)),
+                            Err(err) => {
+                                not_updated.push((*task_id, err));
+                                None
+                            }
+                        },
+                        MaybeInvalid::Invalid(_) => Some((id, object)),
+                    })
+                    .collect(),
+            );
+        }
+        if request.destroy.is_some() {
+            request.destroy = Some(MaybeResultReference::Value(
+                request
+                    .unwrap_destroy()
+                    .into_iter()
+                    .filter(|id| match id {
+                        MaybeInvalid::Value(id) if !is_task(id) => {
+                            not_destroyed.push(*id);
+                            false
+                        }
+                        _ => true,
+                    })
+                    .collect(),
+            ));
+        }
+
+        let arguments = std::mem::take(&mut request.arguments);
+        let mut response = transmute_set_response(
+            self.calendar_event_set(
+                transmute_set_request(request, arguments),
+                access_token,
+                session,
+            )
+            .await?,
+        );
+        for (id, err) in not_created {
+            response.not_created.append(id, err);
+        }
+        for (id, err) in not_updated {
+            response.not_updated.append(id, err);
+        }
+        for id in not_destroyed {
+            response.not_destroyed.append(id, SetError::not_found());
+        }
+
+        Ok(response)
+    }
+}
+
+fn map_task<'x>(
+    cache: &DavResources,
+    object: TaskValue<'x>,
+    is_create: bool,
+) -> Result<TaskValue<'x>, SetError<JSCalendarProperty<Id>>> {
+    let mut has_type = false;
+    let mut task = Map::with_capacity(4);
+
+    for (key, value) in object.into_expanded_object() {
+        match (key, value) {
+            (Key::Borrowed("taskListId"), value) => {
+                task.insert_unchecked(
+                    JSCalendarProperty::CalendarIds,
+                    task_list_ids(cache, value)?,
+                );
+            }
+            (Key::Owned(key), value) if key == "taskListId" => {
+                task.insert_unchecked(
+                    JSCalendarProperty::CalendarIds,
+                    task_list_ids(cache, value)?,
+                );
+            }
+            (
+                Key::Property(JSCalendarProperty::Type),
+                Value::Element(JSCalendarValue::Type(JSCalendarType::Task)),
+            ) => {
+                has_type = true;
+                task.insert_unchecked(
+                    JSCalendarProperty::Type,
+                    Value::Element(JSCalendarValue::Type(JSCalendarType::Task)),
+                );
+            }
+            (Key::Property(JSCalendarProperty::Type), _) => {
+                return Err(SetError::invalid_properties()
+                    .with_property(JSCalendarProperty::Type)
+                    .with_description("Only objects of type Task are allowed."));
+            }
+            (key, value) => {
+                task.insert_unchecked(key, value);
+            }
+        }
+    }
+
+    if is_create && !has_type {
+        task.insert_unchecked(
+            JSCalendarProperty::Type,
+            Value::Element(JSCalendarValue::Type(JSCalendarType::Task)),
+        );
+    }
+
+    Ok(Value::Object(task))
+}
+
+fn task_list_ids<'x>(
+    cache: &DavResources,
+    value: TaskValue<'x>,
+) -> Result<TaskValue<'x>, SetError<JSCalendarProperty<Id>>> {
+    let task_list_id = match value {
+        Value::Element(JSCalendarValue::Id(id)) => Some(id),
+        Value::Str(id) => Id::from_str(id.as_ref()).ok(),
+        _ => None,
+    }
+    .ok_or_else(|| {
+        SetError::invalid_properties()
+            .with_property(JSCalendarProperty::CalendarIds)
+            .with_description("Invalid taskListId.")
+    })?;
+
+    if cache
+        .container_resource_by_id(task_list_id.document_id())
+        .is_some_and(|resource| resource.supports_tasks())
+    {
+        Ok(Value::Object(Map::from(vec![(
+            Key::Property(JSCalendarProperty::IdValue(task_list_id)),
+            Value::Bool(true),
+        )])))
+    } else {
+        Err(SetError::invalid_properties()
+            .with_property(JSCalendarProperty::CalendarIds)
+            .with_description(format!("taskListId {task_list_id} is not a task list.")))
+    }
+}
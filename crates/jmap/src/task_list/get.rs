@@ -0,0 +1,74 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::{
+    calendar::get::CalendarGet,
+    task::{transmute_get_request, transmute_get_response},
+};
+use common::{Server, auth::AccessToken};
+use groupware::cache::GroupwareCache;
+use jmap_proto::{
+    method::get::{GetRequest, GetResponse},
+    object::task_list,
+};
+use store::roaring::RoaringBitmap;
+use types::collection::SyncCollection;
+
+pub trait TaskListGet: Sync + Send {
+    fn task_list_get(
+        &self,
+        request: GetRequest<task_list::TaskList>,
+        access_token: &AccessToken,
+    ) -> impl Future<Output = trc::Result<GetResponse<task_list::TaskList>>> + Send;
+}
+
+impl TaskListGet for Server {
+    async fn task_list_get(
+        &self,
+        mut request: GetRequest<task_list::TaskList>,
+        access_token: &AccessToken,
+    ) -> trc::Result<GetResponse<task_list::TaskList>> {
+        let account_id = request.account_id.document_id();
+        let cache = self
+            .fetch_dav_resources(
+                access_token.account_id(),
+                account_id,
+                SyncCollection::Calendar,
+            )
+            .await?;
+        let task_list_ids = cache
+            .resources
+            .iter()
+            .filter(|resource| resource.supports_tasks())
+            .map(|resource| resource.document_id)
+            .collect::<RoaringBitmap>();
+
+        // Only calendars accepting VTODO components are task lists
+        let mut not_found = vec![];
+        let ids = if let Some(ids) = request.unwrap_ids(self.core.jmap.get_max_objects)? {
+            ids.into_iter()
+                .filter(|id| {
+                    if task_list_ids.contains(id.document_id()) {
+                        true
+                    } else {
+                        not_found.push(*id);
+                        false
+                    }
+                })
+                .collect()
+        } else {
+            task_list_ids
+                .iter()
+                .take(self.core.jmap.get_max_objects)
+                .map(Into::into)
+                .collect()
+        };
+
+        self.calendar_get(transmute_get_request(request, ids, ()), access_token)
+            .await
+            .map(|response| transmute_get_response(response, not_found))
+    }
+}
@@ -0,0 +1,106 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::{
+    calendar::set::CalendarSet,
+    task::{transmute_set_request, transmute_set_response},
+};
+use common::{Server, auth::AccessToken};
+use groupware::cache::GroupwareCache;
+use http_proto::HttpSessionData;
+use jmap_proto::{
+    error::set::SetError,
+    method::set::{SetRequest, SetResponse},
+    object::{calendar::CalendarSetArguments, task_list},
+    request::{MaybeInvalid, reference::MaybeResultReference},
+};
+use types::{collection::SyncCollection, id::Id};
+
+pub trait TaskListSet: Sync + Send {
+    fn task_list_set(
+        &self,
+        request: SetRequest<'_, task_list::TaskList>,
+        access_token: &AccessToken,
+        session: &HttpSessionData,
+    ) -> impl Future<Output = trc::Result<SetResponse<task_list::TaskList>>> + Send;
+}
+
+impl TaskListSet for Server {
+    async fn task_list_set(
+        &self,
+        mut request: SetRequest<'_, task_list::TaskList>,
+        access_token: &AccessToken,
+        session: &HttpSessionData,
+    ) -> trc::Result<SetResponse<task_list::TaskList>> {
+        let account_id = request.account_id.document_id();
+        let cache = self
+            .fetch_dav_resources(
+                access_token.account_id(),
+                account_id,
+                SyncCollection::Calendar,
+            )
+            .await?;
+        let is_task_list = |id: &Id| {
+            cache
+                .container_resource_by_id(id.document_id())
+                .is_none_or(|resource| resource.supports_tasks())
+        };
+
+        // Calendars that do not accept VTODO components are not visible as task lists
+        let mut not_updated = vec![];
+        let mut not_destroyed = vec![];
+        if let Some(update) = request.update.take() {
+            request.update = Some(
+                update
+                    .into_iter()
+                    .filter(|(id, _)| match id {
+                        MaybeInvalid::Value(id) if !is_task_list(id) => {
+                            not_updated.push(*id);
+                            false
+                        }
+                        _ => true,
+                    })
+                    .collect(),
+            );
+        }
+        if request.destroy.is_some() {
+            request.destroy = Some(MaybeResultReference::Value(
+                request
+                    .unwrap_destroy()
+                    .into_iter()
+                    .filter(|id| match id {
+                        MaybeInvalid::Value(id) if !is_task_list(id) => {
+                            not_destroyed.push(*id);
+                            false
+                        }
+                        _ => true,
+                    })
+                    .collect(),
+            ));
+        }
+
+        let arguments = CalendarSetArguments {
+            on_destroy_remove_events: request.arguments.on_destroy_remove_tasks,
+            on_success_set_is_default: None,
+        };
+        let mut response = transmute_set_response(
+            self.calendar_set(
+                transmute_set_request(request, arguments),
+                access_token,
+                session,
+            )
+            .await?,
+        );
+        for id in not_updated {
+            response.not_updated.append(id, SetError::not_found());
+        }
+        for id in not_destroyed {
+            response.not_destroyed.append(id, SetError::not_found());
+        }
+
+        Ok(response)
+    }
+}
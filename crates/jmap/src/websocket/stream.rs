@@ -212,6 +212,13 @@ impl WebSocketHandler for Server {
                                     ));
                                 }
                             },
+                            PushNotification::SieveNotify(sieve_notify) => {
+                                if change_types.contains(DataType::EmailDelivery) {
+                                    notifications.push(PushNotification::SieveNotify(
+                                        sieve_notify
+                                    ));
+                                }
+                            },
                         }
 
                     } else {
@@ -4,7 +4,7 @@
  * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
  */
 
-use crate::v016::migrate_v0_16;
+use crate::{v016::migrate_v0_16, v100::migrate_v1_0};
 use common::{DATABASE_SCHEMA_VERSION, Server};
 use store::{
     IterateParams, SUBSPACE_PROPERTY, SUBSPACE_QUEUE_MESSAGE, SUBSPACE_REPORT_IN,
@@ -15,22 +15,25 @@ use trc::AddContext;
 
 pub mod destroy;
 pub mod v016;
+pub mod v100;
 
 pub async fn try_migrate(server: &Server) -> trc::Result<()> {
-    match server
+    let version = server
         .store()
         .get_value::<u32>(AnyKey {
             subspace: SUBSPACE_PROPERTY,
             key: vec![0u8],
         })
         .await
-        .caused_by(trc::location!())?
-    {
+        .caused_by(trc::location!())?;
+
+    match version {
         Some(DATABASE_SCHEMA_VERSION) => {
             if !std::env::var("DANGER_FORCE_MIGRATE").is_ok_and(|v| v == "1") {
                 return Ok(());
             }
         }
+        Some(6) => {}
         Some(0..=4) => {
             abort(concat!(
                 "You must first upgrade to version 0.15, please read ",
@@ -64,7 +67,10 @@ pub async fn try_migrate(server: &Server) -> trc::Result<()> {
         }
     }
 
-    migrate_v0_16(server).await?;
+    if version != Some(6) {
+        migrate_v0_16(server).await?;
+    }
+    migrate_v1_0(server).await?;
 
     let mut batch = BatchBuilder::new();
     batch.set(
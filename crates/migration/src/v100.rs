@@ -0,0 +1,31 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use common::Server;
+use registry::schema::{
+    enums::TaskStoreMaintenanceType,
+    structs::{Task, TaskStatus, TaskStoreMaintenance},
+};
+use store::write::BatchBuilder;
+use trc::AddContext;
+
+pub async fn migrate_v1_0(server: &Server) -> trc::Result<()> {
+    // Reindex calendars to populate the task columns added to the calendar search index
+    let mut batch = BatchBuilder::new();
+    batch.schedule_task(Task::StoreMaintenance(TaskStoreMaintenance {
+        maintenance_type: TaskStoreMaintenanceType::ReindexCalendar,
+        status: TaskStatus::now(),
+        shard_index: None,
+    }));
+
+    server
+        .store()
+        .write(batch.build_all())
+        .await
+        .caused_by(trc::location!())?;
+
+    Ok(())
+}
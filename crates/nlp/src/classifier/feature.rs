@@ -47,6 +47,14 @@ pub trait UnprocessedFeature {
     fn value(&self) -> &[u8];
 }
 
+impl FhFeatureBuilder {
+    pub fn new(num_features: usize) -> Self {
+        FhFeatureBuilder {
+            weight_mask: (num_features - 1) as u64,
+        }
+    }
+}
+
 impl FeatureWeight for FhFeature {
     fn weight(&self) -> f32 {
         self.weight
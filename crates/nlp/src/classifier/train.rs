@@ -9,6 +9,7 @@ use crate::classifier::{
     feature::{CcfhFeature, CcfhFeatureBuilder, FhFeature, FhFeatureBuilder, Sample},
     gradient,
     model::{CcfhClassifier, FhClassifier},
+    sigmoid,
 };
 use rand::{SeedableRng, rngs::StdRng, seq::SliceRandom};
 
@@ -62,6 +63,16 @@ impl<T: Optimizer> FhTrainer<T> {
         self.optimizer.build_classifier()
     }
 
+    pub fn predict_proba_sample(&self, features: &[FhFeature]) -> f32 {
+        let mut z: f32 = 0.0;
+
+        for f in features {
+            z += self.optimizer.get_param(f.idx) * f.weight;
+        }
+
+        sigmoid(z + self.optimizer.get_bias())
+    }
+
     pub fn optimizer(&self) -> &T {
         &self.optimizer
     }
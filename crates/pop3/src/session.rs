@@ -81,7 +81,7 @@ impl<T: SessionStream> Session<T> {
                                     SessionResult::UpgradeTls => {
                                         return true;
                                     }
-                                    SessionResult::Close => {
+                                    SessionResult::Close | SessionResult::UpgradeCompression => {
                                         break;
                                     }
                                 }
@@ -189,6 +189,16 @@ pub enum BlockReason {
     Other = 5,
 }
 
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[repr(u16)]
+pub enum BookingPolicy {
+    #[default]
+    Manual = 0,
+    AutoAccept = 1,
+    AutoAcceptIfFree = 2,
+    Delegate = 3,
+}
+
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
 #[repr(u16)]
 pub enum CertificateManagementType {
@@ -1249,6 +1259,15 @@ pub enum MtaIpStrategy {
     V6Only = 3,
 }
 
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[repr(u16)]
+pub enum MtaIpWarmUpStatus {
+    #[default]
+    Scheduled = 0,
+    WarmingUp = 1,
+    Complete = 2,
+}
+
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
 #[repr(u16)]
 pub enum MtaOutboundThrottleKey {
@@ -1307,6 +1326,7 @@ pub enum MtaStage {
     Mail = 3,
     Rcpt = 4,
     Data = 5,
+    Delivery = 6,
 }
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
@@ -2017,6 +2037,33 @@ pub enum Permission {
     SysWebHookUpdate = 656,
     SysWebHookDestroy = 657,
     SysWebHookQuery = 658,
+    ImapGetMetadata = 659,
+    ImapSetMetadata = 660,
+    ImapNotify = 661,
+    SysQuarantinedMessageGet = 662,
+    SysQuarantinedMessageCreate = 663,
+    SysQuarantinedMessageUpdate = 664,
+    SysQuarantinedMessageDestroy = 665,
+    SysQuarantinedMessageQuery = 666,
+    SysMtaIpWarmUpGet = 667,
+    SysMtaIpWarmUpCreate = 668,
+    SysMtaIpWarmUpUpdate = 669,
+    SysMtaIpWarmUpDestroy = 670,
+    SysMtaIpWarmUpQuery = 671,
+    JmapMdnSend = 672,
+    JmapMdnParse = 673,
+    JmapTaskListGet = 674,
+    JmapTaskListChanges = 675,
+    JmapTaskListCreate = 676,
+    JmapTaskListUpdate = 677,
+    JmapTaskListDestroy = 678,
+    JmapTaskGet = 679,
+    JmapTaskChanges = 680,
+    JmapTaskQuery = 681,
+    JmapTaskQueryChanges = 682,
+    JmapTaskCreate = 683,
+    JmapTaskUpdate = 684,
+    JmapTaskDestroy = 685,
 }
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
@@ -2046,6 +2093,15 @@ pub enum PostgreSqlRecyclingMethod {
     Clean = 2,
 }
 
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[repr(u16)]
+pub enum PrincipalKind {
+    #[default]
+    Individual = 0,
+    Resource = 1,
+    Location = 2,
+}
+
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
 #[repr(u16)]
 pub enum ProviderInfo {
@@ -2069,6 +2125,14 @@ pub enum PublicTextType {
     File = 2,
 }
 
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[repr(u16)]
+pub enum QuarantineStatus {
+    #[default]
+    Held = 0,
+    RequestRelease = 1,
+}
+
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
 #[repr(u16)]
 pub enum QueueExpiryType {
@@ -2171,6 +2235,9 @@ pub enum SearchCalendarField {
     Attendee = 4,
     Start = 5,
     Uid = 6,
+    Due = 7,
+    Completed = 8,
+    PercentComplete = 9,
 }
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
@@ -2380,6 +2447,7 @@ pub enum SpamClassifyResult {
     Ham = 1,
     Reject = 2,
     Discard = 3,
+    Quarantine = 4,
 }
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
@@ -2389,6 +2457,7 @@ pub enum SpamClassifyTagDisposition {
     Score = 0,
     Reject = 1,
     Discard = 2,
+    Quarantine = 3,
 }
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
@@ -2432,6 +2501,7 @@ pub enum SpamTagType {
     Score = 0,
     Discard = 1,
     Reject = 2,
+    Quarantine = 3,
 }
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
@@ -2525,6 +2595,11 @@ pub enum TaskAccountMaintenanceType {
     Reindex = 1,
     RecalculateImapUid = 2,
     RecalculateQuota = 3,
+    ReindexEmail = 4,
+    QuotaWarning = 5,
+    QuarantineDigest = 6,
+    EmailSnooze = 7,
+    ReindexCalendar = 8,
 }
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
@@ -2544,6 +2619,7 @@ pub enum TaskSpamFilterMaintenanceType {
     Abort = 2,
     Reset = 3,
     UpdateRules = 4,
+    PruneAccountModels = 5,
 }
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
@@ -2574,6 +2650,9 @@ pub enum TaskStoreMaintenanceType {
     RemoveLockDav = 12,
     RemoveSieveId = 13,
     RemoveGreylist = 14,
+    ReindexEmail = 15,
+    SendQuarantineDigests = 16,
+    ReindexCalendar = 17,
 }
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
@@ -999,6 +999,62 @@ impl<'de> serde::Deserialize<'de> for BlockReason {
     }
 }
 
+impl EnumImpl for BookingPolicy {
+    fn parse(value: &str) -> Option<Self> {
+        hashify::tiny_map! {
+            value.as_bytes(),
+            b"manual" => BookingPolicy::Manual,
+            b"autoAccept" => BookingPolicy::AutoAccept,
+            b"autoAcceptIfFree" => BookingPolicy::AutoAcceptIfFree,
+            b"delegate" => BookingPolicy::Delegate,
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            BookingPolicy::Manual => "manual",
+            BookingPolicy::AutoAccept => "autoAccept",
+            BookingPolicy::AutoAcceptIfFree => "autoAcceptIfFree",
+            BookingPolicy::Delegate => "delegate",
+        }
+    }
+
+    fn to_id(&self) -> u16 {
+        *self as u16
+    }
+
+    fn from_id(id: u16) -> Option<Self> {
+        match id {
+            0 => Some(BookingPolicy::Manual),
+            1 => Some(BookingPolicy::AutoAccept),
+            2 => Some(BookingPolicy::AutoAcceptIfFree),
+            3 => Some(BookingPolicy::Delegate),
+            _ => None,
+        }
+    }
+
+    const COUNT: usize = 4;
+}
+
+impl serde::Serialize for BookingPolicy {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for BookingPolicy {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = Cow::<str>::deserialize(deserializer)?;
+        Self::parse(&s).ok_or_else(|| serde::de::Error::unknown_variant(&s, &[]))
+    }
+}
+
 impl EnumImpl for CertificateManagementType {
     fn parse(value: &str) -> Option<Self> {
         hashify::tiny_map! {
@@ -5717,6 +5773,59 @@ impl<'de> serde::Deserialize<'de> for MtaIpStrategy {
     }
 }
 
+impl EnumImpl for MtaIpWarmUpStatus {
+    fn parse(value: &str) -> Option<Self> {
+        hashify::tiny_map! {
+            value.as_bytes(),
+            b"scheduled" => MtaIpWarmUpStatus::Scheduled,
+            b"warmingUp" => MtaIpWarmUpStatus::WarmingUp,
+            b"complete" => MtaIpWarmUpStatus::Complete,
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            MtaIpWarmUpStatus::Scheduled => "scheduled",
+            MtaIpWarmUpStatus::WarmingUp => "warmingUp",
+            MtaIpWarmUpStatus::Complete => "complete",
+        }
+    }
+
+    fn to_id(&self) -> u16 {
+        *self as u16
+    }
+
+    fn from_id(id: u16) -> Option<Self> {
+        match id {
+            0 => Some(MtaIpWarmUpStatus::Scheduled),
+            1 => Some(MtaIpWarmUpStatus::WarmingUp),
+            2 => Some(MtaIpWarmUpStatus::Complete),
+            _ => None,
+        }
+    }
+
+    const COUNT: usize = 3;
+}
+
+impl serde::Serialize for MtaIpWarmUpStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for MtaIpWarmUpStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = Cow::<str>::deserialize(deserializer)?;
+        Self::parse(&s).ok_or_else(|| serde::de::Error::unknown_variant(&s, &[]))
+    }
+}
+
 impl EnumImpl for MtaOutboundThrottleKey {
     fn parse(value: &str) -> Option<Self> {
         hashify::tiny_map! {
@@ -6001,6 +6110,7 @@ impl EnumImpl for MtaStage {
             b"mail" => MtaStage::Mail,
             b"rcpt" => MtaStage::Rcpt,
             b"data" => MtaStage::Data,
+            b"delivery" => MtaStage::Delivery,
         }
     }
 
@@ -6012,6 +6122,7 @@ impl EnumImpl for MtaStage {
             MtaStage::Mail => "mail",
             MtaStage::Rcpt => "rcpt",
             MtaStage::Data => "data",
+            MtaStage::Delivery => "delivery",
         }
     }
 
@@ -6027,11 +6138,12 @@ impl EnumImpl for MtaStage {
             3 => Some(MtaStage::Mail),
             4 => Some(MtaStage::Rcpt),
             5 => Some(MtaStage::Data),
+            6 => Some(MtaStage::Delivery),
             _ => None,
         }
     }
 
-    const COUNT: usize = 6;
+    const COUNT: usize = 7;
 }
 
 impl serde::Serialize for MtaStage {
@@ -6956,6 +7068,33 @@ impl EnumImpl for Permission {
             b"sysWebHookUpdate" => Permission::SysWebHookUpdate,
             b"sysWebHookDestroy" => Permission::SysWebHookDestroy,
             b"sysWebHookQuery" => Permission::SysWebHookQuery,
+            b"imapGetMetadata" => Permission::ImapGetMetadata,
+            b"imapSetMetadata" => Permission::ImapSetMetadata,
+            b"imapNotify" => Permission::ImapNotify,
+            b"sysQuarantinedMessageGet" => Permission::SysQuarantinedMessageGet,
+            b"sysQuarantinedMessageCreate" => Permission::SysQuarantinedMessageCreate,
+            b"sysQuarantinedMessageUpdate" => Permission::SysQuarantinedMessageUpdate,
+            b"sysQuarantinedMessageDestroy" => Permission::SysQuarantinedMessageDestroy,
+            b"sysQuarantinedMessageQuery" => Permission::SysQuarantinedMessageQuery,
+            b"sysMtaIpWarmUpGet" => Permission::SysMtaIpWarmUpGet,
+            b"sysMtaIpWarmUpCreate" => Permission::SysMtaIpWarmUpCreate,
+            b"sysMtaIpWarmUpUpdate" => Permission::SysMtaIpWarmUpUpdate,
+            b"sysMtaIpWarmUpDestroy" => Permission::SysMtaIpWarmUpDestroy,
+            b"sysMtaIpWarmUpQuery" => Permission::SysMtaIpWarmUpQuery,
+            b"jmapMdnSend" => Permission::JmapMdnSend,
+            b"jmapMdnParse" => Permission::JmapMdnParse,
+            b"jmapTaskListGet" => Permission::JmapTaskListGet,
+            b"jmapTaskListChanges" => Permission::JmapTaskListChanges,
+            b"jmapTaskListCreate" => Permission::JmapTaskListCreate,
+            b"jmapTaskListUpdate" => Permission::JmapTaskListUpdate,
+            b"jmapTaskListDestroy" => Permission::JmapTaskListDestroy,
+            b"jmapTaskGet" => Permission::JmapTaskGet,
+            b"jmapTaskChanges" => Permission::JmapTaskChanges,
+            b"jmapTaskQuery" => Permission::JmapTaskQuery,
+            b"jmapTaskQueryChanges" => Permission::JmapTaskQueryChanges,
+            b"jmapTaskCreate" => Permission::JmapTaskCreate,
+            b"jmapTaskUpdate" => Permission::JmapTaskUpdate,
+            b"jmapTaskDestroy" => Permission::JmapTaskDestroy,
         }
         .copied()
     }
@@ -7631,6 +7770,33 @@ impl EnumImpl for Permission {
             Permission::SysWebHookUpdate => "sysWebHookUpdate",
             Permission::SysWebHookDestroy => "sysWebHookDestroy",
             Permission::SysWebHookQuery => "sysWebHookQuery",
+            Permission::ImapGetMetadata => "imapGetMetadata",
+            Permission::ImapSetMetadata => "imapSetMetadata",
+            Permission::ImapNotify => "imapNotify",
+            Permission::SysQuarantinedMessageGet => "sysQuarantinedMessageGet",
+            Permission::SysQuarantinedMessageCreate => "sysQuarantinedMessageCreate",
+            Permission::SysQuarantinedMessageUpdate => "sysQuarantinedMessageUpdate",
+            Permission::SysQuarantinedMessageDestroy => "sysQuarantinedMessageDestroy",
+            Permission::SysQuarantinedMessageQuery => "sysQuarantinedMessageQuery",
+            Permission::SysMtaIpWarmUpGet => "sysMtaIpWarmUpGet",
+            Permission::SysMtaIpWarmUpCreate => "sysMtaIpWarmUpCreate",
+            Permission::SysMtaIpWarmUpUpdate => "sysMtaIpWarmUpUpdate",
+            Permission::SysMtaIpWarmUpDestroy => "sysMtaIpWarmUpDestroy",
+            Permission::SysMtaIpWarmUpQuery => "sysMtaIpWarmUpQuery",
+            Permission::JmapMdnSend => "jmapMdnSend",
+            Permission::JmapMdnParse => "jmapMdnParse",
+            Permission::JmapTaskListGet => "jmapTaskListGet",
+            Permission::JmapTaskListChanges => "jmapTaskListChanges",
+            Permission::JmapTaskListCreate => "jmapTaskListCreate",
+            Permission::JmapTaskListUpdate => "jmapTaskListUpdate",
+            Permission::JmapTaskListDestroy => "jmapTaskListDestroy",
+            Permission::JmapTaskGet => "jmapTaskGet",
+            Permission::JmapTaskChanges => "jmapTaskChanges",
+            Permission::JmapTaskQuery => "jmapTaskQuery",
+            Permission::JmapTaskQueryChanges => "jmapTaskQueryChanges",
+            Permission::JmapTaskCreate => "jmapTaskCreate",
+            Permission::JmapTaskUpdate => "jmapTaskUpdate",
+            Permission::JmapTaskDestroy => "jmapTaskDestroy",
         }
     }
 
@@ -8299,11 +8465,38 @@ impl EnumImpl for Permission {
             656 => Some(Permission::SysWebHookUpdate),
             657 => Some(Permission::SysWebHookDestroy),
             658 => Some(Permission::SysWebHookQuery),
-            _ => None,
-        }
-    }
-
-    const COUNT: usize = 659;
+            659 => Some(Permission::ImapGetMetadata),
+            660 => Some(Permission::ImapSetMetadata),
+            661 => Some(Permission::ImapNotify),
+            662 => Some(Permission::SysQuarantinedMessageGet),
+            663 => Some(Permission::SysQuarantinedMessageCreate),
+            664 => Some(Permission::SysQuarantinedMessageUpdate),
+            665 => Some(Permission::SysQuarantinedMessageDestroy),
+            666 => Some(Permission::SysQuarantinedMessageQuery),
+            667 => Some(Permission::SysMtaIpWarmUpGet),
+            668 => Some(Permission::SysMtaIpWarmUpCreate),
+            669 => Some(Permission::SysMtaIpWarmUpUpdate),
+            670 => Some(Permission::SysMtaIpWarmUpDestroy),
+            671 => Some(Permission::SysMtaIpWarmUpQuery),
+            672 => Some(Permission::JmapMdnSend),
+            673 => Some(Permission::JmapMdnParse),
+            674 => Some(Permission::JmapTaskListGet),
+            675 => Some(Permission::JmapTaskListChanges),
+            676 => Some(Permission::JmapTaskListCreate),
+            677 => Some(Permission::JmapTaskListUpdate),
+            678 => Some(Permission::JmapTaskListDestroy),
+            679 => Some(Permission::JmapTaskGet),
+            680 => Some(Permission::JmapTaskChanges),
+            681 => Some(Permission::JmapTaskQuery),
+            682 => Some(Permission::JmapTaskQueryChanges),
+            683 => Some(Permission::JmapTaskCreate),
+            684 => Some(Permission::JmapTaskUpdate),
+            685 => Some(Permission::JmapTaskDestroy),
+            _ => None,
+        }
+    }
+
+    const COUNT: usize = 686;
 }
 
 impl serde::Serialize for Permission {
@@ -8484,6 +8677,59 @@ impl<'de> serde::Deserialize<'de> for PostgreSqlRecyclingMethod {
     }
 }
 
+impl EnumImpl for PrincipalKind {
+    fn parse(value: &str) -> Option<Self> {
+        hashify::tiny_map! {
+            value.as_bytes(),
+            b"individual" => PrincipalKind::Individual,
+            b"resource" => PrincipalKind::Resource,
+            b"location" => PrincipalKind::Location,
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            PrincipalKind::Individual => "individual",
+            PrincipalKind::Resource => "resource",
+            PrincipalKind::Location => "location",
+        }
+    }
+
+    fn to_id(&self) -> u16 {
+        *self as u16
+    }
+
+    fn from_id(id: u16) -> Option<Self> {
+        match id {
+            0 => Some(PrincipalKind::Individual),
+            1 => Some(PrincipalKind::Resource),
+            2 => Some(PrincipalKind::Location),
+            _ => None,
+        }
+    }
+
+    const COUNT: usize = 3;
+}
+
+impl serde::Serialize for PrincipalKind {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for PrincipalKind {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = Cow::<str>::deserialize(deserializer)?;
+        Self::parse(&s).ok_or_else(|| serde::de::Error::unknown_variant(&s, &[]))
+    }
+}
+
 impl EnumImpl for ProviderInfo {
     fn parse(value: &str) -> Option<Self> {
         hashify::tiny_map! {
@@ -8605,6 +8851,56 @@ impl<'de> serde::Deserialize<'de> for PublicTextType {
     }
 }
 
+impl EnumImpl for QuarantineStatus {
+    fn parse(value: &str) -> Option<Self> {
+        hashify::tiny_map! {
+            value.as_bytes(),
+            b"held" => QuarantineStatus::Held,
+            b"requestRelease" => QuarantineStatus::RequestRelease,
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            QuarantineStatus::Held => "held",
+            QuarantineStatus::RequestRelease => "requestRelease",
+        }
+    }
+
+    fn to_id(&self) -> u16 {
+        *self as u16
+    }
+
+    fn from_id(id: u16) -> Option<Self> {
+        match id {
+            0 => Some(QuarantineStatus::Held),
+            1 => Some(QuarantineStatus::RequestRelease),
+            _ => None,
+        }
+    }
+
+    const COUNT: usize = 2;
+}
+
+impl serde::Serialize for QuarantineStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for QuarantineStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = Cow::<str>::deserialize(deserializer)?;
+        Self::parse(&s).ok_or_else(|| serde::de::Error::unknown_variant(&s, &[]))
+    }
+}
+
 impl EnumImpl for QueueExpiryType {
     fn parse(value: &str) -> Option<Self> {
         hashify::tiny_map! {
@@ -9045,6 +9341,9 @@ impl EnumImpl for SearchCalendarField {
             b"attendee" => SearchCalendarField::Attendee,
             b"start" => SearchCalendarField::Start,
             b"uid" => SearchCalendarField::Uid,
+            b"due" => SearchCalendarField::Due,
+            b"completed" => SearchCalendarField::Completed,
+            b"percentComplete" => SearchCalendarField::PercentComplete,
         }
     }
 
@@ -9057,6 +9356,9 @@ impl EnumImpl for SearchCalendarField {
             SearchCalendarField::Attendee => "attendee",
             SearchCalendarField::Start => "start",
             SearchCalendarField::Uid => "uid",
+            SearchCalendarField::Due => "due",
+            SearchCalendarField::Completed => "completed",
+            SearchCalendarField::PercentComplete => "percentComplete",
         }
     }
 
@@ -9073,11 +9375,14 @@ impl EnumImpl for SearchCalendarField {
             4 => Some(SearchCalendarField::Attendee),
             5 => Some(SearchCalendarField::Start),
             6 => Some(SearchCalendarField::Uid),
+            7 => Some(SearchCalendarField::Due),
+            8 => Some(SearchCalendarField::Completed),
+            9 => Some(SearchCalendarField::PercentComplete),
             _ => None,
         }
     }
 
-    const COUNT: usize = 7;
+    const COUNT: usize = 10;
 }
 
 impl serde::Serialize for SearchCalendarField {
@@ -10070,6 +10375,7 @@ impl EnumImpl for SpamClassifyResult {
             b"ham" => SpamClassifyResult::Ham,
             b"reject" => SpamClassifyResult::Reject,
             b"discard" => SpamClassifyResult::Discard,
+            b"quarantine" => SpamClassifyResult::Quarantine,
         }
     }
 
@@ -10079,6 +10385,7 @@ impl EnumImpl for SpamClassifyResult {
             SpamClassifyResult::Ham => "ham",
             SpamClassifyResult::Reject => "reject",
             SpamClassifyResult::Discard => "discard",
+            SpamClassifyResult::Quarantine => "quarantine",
         }
     }
 
@@ -10092,11 +10399,12 @@ impl EnumImpl for SpamClassifyResult {
             1 => Some(SpamClassifyResult::Ham),
             2 => Some(SpamClassifyResult::Reject),
             3 => Some(SpamClassifyResult::Discard),
+            4 => Some(SpamClassifyResult::Quarantine),
             _ => None,
         }
     }
 
-    const COUNT: usize = 4;
+    const COUNT: usize = 5;
 }
 
 impl serde::Serialize for SpamClassifyResult {
@@ -10125,6 +10433,7 @@ impl EnumImpl for SpamClassifyTagDisposition {
             b"score" => SpamClassifyTagDisposition::Score,
             b"reject" => SpamClassifyTagDisposition::Reject,
             b"discard" => SpamClassifyTagDisposition::Discard,
+            b"quarantine" => SpamClassifyTagDisposition::Quarantine,
         }
     }
 
@@ -10133,6 +10442,7 @@ impl EnumImpl for SpamClassifyTagDisposition {
             SpamClassifyTagDisposition::Score => "score",
             SpamClassifyTagDisposition::Reject => "reject",
             SpamClassifyTagDisposition::Discard => "discard",
+            SpamClassifyTagDisposition::Quarantine => "quarantine",
         }
     }
 
@@ -10145,11 +10455,12 @@ impl EnumImpl for SpamClassifyTagDisposition {
             0 => Some(SpamClassifyTagDisposition::Score),
             1 => Some(SpamClassifyTagDisposition::Reject),
             2 => Some(SpamClassifyTagDisposition::Discard),
+            3 => Some(SpamClassifyTagDisposition::Quarantine),
             _ => None,
         }
     }
 
-    const COUNT: usize = 3;
+    const COUNT: usize = 4;
 }
 
 impl serde::Serialize for SpamClassifyTagDisposition {
@@ -10358,6 +10669,7 @@ impl EnumImpl for SpamTagType {
             b"Score" => SpamTagType::Score,
             b"Discard" => SpamTagType::Discard,
             b"Reject" => SpamTagType::Reject,
+            b"Quarantine" => SpamTagType::Quarantine,
         }
     }
 
@@ -10366,6 +10678,7 @@ impl EnumImpl for SpamTagType {
             SpamTagType::Score => "Score",
             SpamTagType::Discard => "Discard",
             SpamTagType::Reject => "Reject",
+            SpamTagType::Quarantine => "Quarantine",
         }
     }
 
@@ -10378,11 +10691,12 @@ impl EnumImpl for SpamTagType {
             0 => Some(SpamTagType::Score),
             1 => Some(SpamTagType::Discard),
             2 => Some(SpamTagType::Reject),
+            3 => Some(SpamTagType::Quarantine),
             _ => None,
         }
     }
 
-    const COUNT: usize = 3;
+    const COUNT: usize = 4;
 }
 
 impl serde::Serialize for SpamTagType {
@@ -10817,6 +11131,11 @@ impl EnumImpl for TaskAccountMaintenanceType {
             b"reindex" => TaskAccountMaintenanceType::Reindex,
             b"recalculateImapUid" => TaskAccountMaintenanceType::RecalculateImapUid,
             b"recalculateQuota" => TaskAccountMaintenanceType::RecalculateQuota,
+            b"reindexEmail" => TaskAccountMaintenanceType::ReindexEmail,
+            b"quotaWarning" => TaskAccountMaintenanceType::QuotaWarning,
+            b"quarantineDigest" => TaskAccountMaintenanceType::QuarantineDigest,
+            b"emailSnooze" => TaskAccountMaintenanceType::EmailSnooze,
+            b"reindexCalendar" => TaskAccountMaintenanceType::ReindexCalendar,
         }
     }
 
@@ -10826,6 +11145,11 @@ impl EnumImpl for TaskAccountMaintenanceType {
             TaskAccountMaintenanceType::Reindex => "reindex",
             TaskAccountMaintenanceType::RecalculateImapUid => "recalculateImapUid",
             TaskAccountMaintenanceType::RecalculateQuota => "recalculateQuota",
+            TaskAccountMaintenanceType::ReindexEmail => "reindexEmail",
+            TaskAccountMaintenanceType::QuotaWarning => "quotaWarning",
+            TaskAccountMaintenanceType::QuarantineDigest => "quarantineDigest",
+            TaskAccountMaintenanceType::EmailSnooze => "emailSnooze",
+            TaskAccountMaintenanceType::ReindexCalendar => "reindexCalendar",
         }
     }
 
@@ -10839,11 +11163,16 @@ impl EnumImpl for TaskAccountMaintenanceType {
             1 => Some(TaskAccountMaintenanceType::Reindex),
             2 => Some(TaskAccountMaintenanceType::RecalculateImapUid),
             3 => Some(TaskAccountMaintenanceType::RecalculateQuota),
+            4 => Some(TaskAccountMaintenanceType::ReindexEmail),
+            5 => Some(TaskAccountMaintenanceType::QuotaWarning),
+            6 => Some(TaskAccountMaintenanceType::QuarantineDigest),
+            7 => Some(TaskAccountMaintenanceType::EmailSnooze),
+            8 => Some(TaskAccountMaintenanceType::ReindexCalendar),
             _ => None,
         }
     }
 
-    const COUNT: usize = 4;
+    const COUNT: usize = 9;
 }
 
 impl serde::Serialize for TaskAccountMaintenanceType {
@@ -10924,6 +11253,7 @@ impl EnumImpl for TaskSpamFilterMaintenanceType {
             b"abort" => TaskSpamFilterMaintenanceType::Abort,
             b"reset" => TaskSpamFilterMaintenanceType::Reset,
             b"updateRules" => TaskSpamFilterMaintenanceType::UpdateRules,
+            b"pruneAccountModels" => TaskSpamFilterMaintenanceType::PruneAccountModels,
         }
     }
 
@@ -10934,6 +11264,7 @@ impl EnumImpl for TaskSpamFilterMaintenanceType {
             TaskSpamFilterMaintenanceType::Abort => "abort",
             TaskSpamFilterMaintenanceType::Reset => "reset",
             TaskSpamFilterMaintenanceType::UpdateRules => "updateRules",
+            TaskSpamFilterMaintenanceType::PruneAccountModels => "pruneAccountModels",
         }
     }
 
@@ -10948,11 +11279,12 @@ impl EnumImpl for TaskSpamFilterMaintenanceType {
             2 => Some(TaskSpamFilterMaintenanceType::Abort),
             3 => Some(TaskSpamFilterMaintenanceType::Reset),
             4 => Some(TaskSpamFilterMaintenanceType::UpdateRules),
+            5 => Some(TaskSpamFilterMaintenanceType::PruneAccountModels),
             _ => None,
         }
     }
 
-    const COUNT: usize = 5;
+    const COUNT: usize = 6;
 }
 
 impl serde::Serialize for TaskSpamFilterMaintenanceType {
@@ -11046,6 +11378,9 @@ impl EnumImpl for TaskStoreMaintenanceType {
             b"removeLockDav" => TaskStoreMaintenanceType::RemoveLockDav,
             b"removeSieveId" => TaskStoreMaintenanceType::RemoveSieveId,
             b"removeGreylist" => TaskStoreMaintenanceType::RemoveGreylist,
+            b"reindexEmail" => TaskStoreMaintenanceType::ReindexEmail,
+            b"sendQuarantineDigests" => TaskStoreMaintenanceType::SendQuarantineDigests,
+            b"reindexCalendar" => TaskStoreMaintenanceType::ReindexCalendar,
         }
     }
 
@@ -11066,6 +11401,9 @@ impl EnumImpl for TaskStoreMaintenanceType {
             TaskStoreMaintenanceType::RemoveLockDav => "removeLockDav",
             TaskStoreMaintenanceType::RemoveSieveId => "removeSieveId",
             TaskStoreMaintenanceType::RemoveGreylist => "removeGreylist",
+            TaskStoreMaintenanceType::ReindexEmail => "reindexEmail",
+            TaskStoreMaintenanceType::SendQuarantineDigests => "sendQuarantineDigests",
+            TaskStoreMaintenanceType::ReindexCalendar => "reindexCalendar",
         }
     }
 
@@ -11090,11 +11428,14 @@ impl EnumImpl for TaskStoreMaintenanceType {
             12 => Some(TaskStoreMaintenanceType::RemoveLockDav),
             13 => Some(TaskStoreMaintenanceType::RemoveSieveId),
             14 => Some(TaskStoreMaintenanceType::RemoveGreylist),
+            15 => Some(TaskStoreMaintenanceType::ReindexEmail),
+            16 => Some(TaskStoreMaintenanceType::SendQuarantineDigests),
+            17 => Some(TaskStoreMaintenanceType::ReindexCalendar),
             _ => None,
         }
     }
 
-    const COUNT: usize = 15;
+    const COUNT: usize = 18;
 }
 
 impl serde::Serialize for TaskStoreMaintenanceType {
@@ -34,6 +34,7 @@ pub enum ObjectInner {
     CalendarAlarm(CalendarAlarm),
     CalendarScheduling(CalendarScheduling),
     Certificate(Certificate),
+    MtaIpWarmUp(MtaIpWarmUp),
     ClusterNode(ClusterNode),
     ClusterRole(ClusterRole),
     Coordinator(Coordinator),
@@ -91,6 +92,7 @@ pub enum ObjectInner {
     OAuthClient(OAuthClient),
     OidcProvider(OidcProvider),
     PublicKey(PublicKey),
+    QuarantinedMessage(QuarantinedMessage),
     QueuedMessage(QueuedMessage),
     ReportSettings(ReportSettings),
     Role(Role),
@@ -156,6 +158,7 @@ pub enum ObjectType {
     CalendarAlarm = 21,
     CalendarScheduling = 22,
     Certificate = 23,
+    MtaIpWarmUp = 118,
     ClusterNode = 24,
     ClusterRole = 25,
     Coordinator = 26,
@@ -213,6 +216,7 @@ pub enum ObjectType {
     OAuthClient = 78,
     OidcProvider = 79,
     PublicKey = 80,
+    QuarantinedMessage = 117,
     QueuedMessage = 81,
     ReportSettings = 82,
     Role = 83,
@@ -265,6 +269,10 @@ pub enum Property {
     AccountId = 57,
     AccountIdentifier = 315,
     AccountKey = 15,
+    AccountModel = 880,
+    AccountModelMaxAge = 883,
+    AccountModelMinSamples = 882,
+    AccountModelWeight = 881,
     AccountName = 809,
     AccountType = 811,
     AccountUri = 16,
@@ -299,6 +307,7 @@ pub enum Property {
     AllowRelaying = 348,
     AllowSpamTraining = 369,
     AllowedEndpoints = 398,
+    AllowedExtLists = 917,
     AllowedIps = 49,
     AllowedNotifyUris = 712,
     Alpha = 388,
@@ -338,9 +347,14 @@ pub enum Property {
     AuthenticationResults = 69,
     AutoAddInvitations = 171,
     AutoUpdateFrequency = 53,
+    Autocrypt = 910,
     BaseDn = 463,
     BearerToken = 403,
     Beta = 389,
+    BimiMaxSize = 908,
+    BimiRequireVmc = 907,
+    BimiTrustAnchors = 919,
+    BimiVerify = 906,
     Bind = 589,
     BindAuthentication = 466,
     BindDn = 464,
@@ -351,6 +365,11 @@ pub enum Property {
     BlobStore = 126,
     BlockCount = 766,
     Body = 38,
+    BookingAllowedRequesters = 915,
+    BookingDelegateIds = 916,
+    BookingMaxDuration = 913,
+    BookingPolicy = 912,
+    BookingWindow = 914,
     Brokers = 459,
     Bucket = 658,
     BufferSize = 656,
@@ -378,12 +397,14 @@ pub enum Property {
     ColumnSecret = 780,
     Comment = 240,
     CompressionAlgorithm = 359,
+    CompressionMinSize = 875,
     Concurrency = 304,
     Condition = 34,
     Confidence = 760,
     Config = 873,
     ConnectTimeout = 505,
     Connection = 539,
+    ConnectionStrategyId = 903,
     ConsumerKey = 323,
     Contact = 11,
     ContactInfo = 844,
@@ -402,6 +423,7 @@ pub enum Property {
     CustomEndpoint = 662,
     CustomRegion = 663,
     CustomRule = 787,
+    DailyLimit = 904,
     Dane = 569,
     DataCleanupSchedule = 199,
     DataStore = 125,
@@ -511,6 +533,7 @@ pub enum Property {
     EmailTemplate = 174,
     Enable = 37,
     EnableAssistedDiscovery = 865,
+    EnableCompression = 874,
     EnableEdns = 305,
     EnableHsts = 399,
     EnableLogExporter = 860,
@@ -561,6 +584,9 @@ pub enum Property {
     Extension = 754,
     Extensions = 257,
     ExtraContactInfo = 243,
+    ExtractArchiveMaxSize = 879,
+    ExtractOfficeMaxSize = 878,
+    ExtractPdfMaxSize = 877,
     Factor = 821,
     FailOnTimeout = 490,
     FailedAt = 826,
@@ -607,11 +633,14 @@ pub enum Property {
     Headers = 93,
     HoldMetricsFor = 206,
     HoldMtaReportsFor = 204,
+    HoldQuarantineFor = 886,
     HoldSamplesFor = 730,
     HoldTracesFor = 205,
     Host = 333,
+    HostCooldown = 895,
     HostedZoneId = 331,
     Hostname = 185,
+    Hosts = 894,
     Hour = 190,
     HttpAuth = 32,
     HttpHeaders = 33,
@@ -877,6 +906,8 @@ pub enum Property {
     Port = 299,
     Prefix = 856,
     PreserveIntermediates = 306,
+    Preview = 885,
+    PrincipalKind = 911,
     Priority = 483,
     PrivateKey = 177,
     PrivateZone = 319,
@@ -894,6 +925,7 @@ pub enum Property {
     ProxyTrustedNetworks = 792,
     PublicKey = 218,
     PublishRecords = 302,
+    PublishWkd = 909,
     PushAttemptWait = 448,
     PushMaxAttempts = 449,
     PushRequestTimeout = 452,
@@ -901,6 +933,7 @@ pub enum Property {
     PushShardsTotal = 454,
     PushThrottle = 451,
     PushVerifyTimeout = 453,
+    QuarantineDigestSchedule = 888,
     QueryEmailAliases = 786,
     QueryLogin = 783,
     QueryMaxResults = 437,
@@ -908,11 +941,13 @@ pub enum Property {
     QueryRecipient = 784,
     QueueId = 514,
     QueueName = 644,
+    QuotaWarnThresholds = 884,
     Quotas = 394,
     Rate = 532,
     RateLimit = 410,
     RateLimitAnonymous = 397,
     RateLimitAuthenticated = 396,
+    RateLimitNotify = 876,
     Ratio = 767,
     RcptToTimeout = 510,
     ReadFromReplicas = 650,
@@ -965,6 +1000,9 @@ pub enum Property {
     RetryCount = 640,
     RetryDue = 641,
     ReturnPath = 635,
+    ReuseIdleTimeout = 897,
+    ReuseMaxIdle = 896,
+    ReuseMaxMessages = 898,
     ReverseIpVerify = 692,
     Rewrite = 565,
     RoleIds = 193,
@@ -995,7 +1033,9 @@ pub enum Property {
     Selector = 222,
     SelectorTemplate = 226,
     SendFrequency = 230,
+    SendQuarantineDigest = 887,
     SendingMtaIp = 833,
+    SentToday = 905,
     Separator = 97,
     ServerHostname = 121,
     Servers = 308,
@@ -1024,6 +1064,7 @@ pub enum Property {
     SourceIps = 504,
     SourcePort = 78,
     SpamFilterRulesUrl = 775,
+    SpamModels = 918,
     SpfDns = 90,
     SpfEhloDomain = 285,
     SpfEhloResult = 286,
@@ -1032,6 +1073,10 @@ pub enum Property {
     SpfMailFromDomain = 287,
     SpfMailFromResult = 288,
     SpfResults = 267,
+    SrsDomain = 889,
+    SrsMaxAge = 892,
+    SrsPreviousKey = 891,
+    SrsSigningKey = 890,
     Stage = 224,
     Stages = 529,
     StartTime = 56,
@@ -1122,9 +1167,14 @@ pub enum Property {
     Version = 80,
     Vrfy = 526,
     WaitOnFail = 548,
+    WarmUpDays = 900,
+    WarmUpInitialVolume = 901,
+    WarmUpStart = 899,
+    WarmUpTargetVolume = 902,
     WebsocketHeartbeat = 455,
     WebsocketThrottle = 456,
     WebsocketTimeout = 457,
+    Weight = 893,
     Zone = 749,
     ZoneIpV4 = 98,
     ZoneIpV6 = 99,
@@ -36,6 +36,7 @@ impl EnumImpl for ObjectType {
             b"CalendarAlarm" => ObjectType::CalendarAlarm,
             b"CalendarScheduling" => ObjectType::CalendarScheduling,
             b"Certificate" => ObjectType::Certificate,
+            b"MtaIpWarmUp" => ObjectType::MtaIpWarmUp,
             b"ClusterNode" => ObjectType::ClusterNode,
             b"ClusterRole" => ObjectType::ClusterRole,
             b"Coordinator" => ObjectType::Coordinator,
@@ -93,6 +94,7 @@ impl EnumImpl for ObjectType {
             b"OAuthClient" => ObjectType::OAuthClient,
             b"OidcProvider" => ObjectType::OidcProvider,
             b"PublicKey" => ObjectType::PublicKey,
+            b"QuarantinedMessage" => ObjectType::QuarantinedMessage,
             b"QueuedMessage" => ObjectType::QueuedMessage,
             b"ReportSettings" => ObjectType::ReportSettings,
             b"Role" => ObjectType::Role,
@@ -158,6 +160,7 @@ impl EnumImpl for ObjectType {
             ObjectType::CalendarAlarm => "CalendarAlarm",
             ObjectType::CalendarScheduling => "CalendarScheduling",
             ObjectType::Certificate => "Certificate",
+            ObjectType::MtaIpWarmUp => "MtaIpWarmUp",
             ObjectType::ClusterNode => "ClusterNode",
             ObjectType::ClusterRole => "ClusterRole",
             ObjectType::Coordinator => "Coordinator",
@@ -215,6 +218,7 @@ impl EnumImpl for ObjectType {
             ObjectType::OAuthClient => "OAuthClient",
             ObjectType::OidcProvider => "OidcProvider",
             ObjectType::PublicKey => "PublicKey",
+            ObjectType::QuarantinedMessage => "QuarantinedMessage",
             ObjectType::QueuedMessage => "QueuedMessage",
             ObjectType::ReportSettings => "ReportSettings",
             ObjectType::Role => "Role",
@@ -284,6 +288,7 @@ impl EnumImpl for ObjectType {
             21 => Some(ObjectType::CalendarAlarm),
             22 => Some(ObjectType::CalendarScheduling),
             23 => Some(ObjectType::Certificate),
+            118 => Some(ObjectType::MtaIpWarmUp),
             24 => Some(ObjectType::ClusterNode),
             25 => Some(ObjectType::ClusterRole),
             26 => Some(ObjectType::Coordinator),
@@ -341,6 +346,7 @@ impl EnumImpl for ObjectType {
             78 => Some(ObjectType::OAuthClient),
             79 => Some(ObjectType::OidcProvider),
             80 => Some(ObjectType::PublicKey),
+            117 => Some(ObjectType::QuarantinedMessage),
             81 => Some(ObjectType::QueuedMessage),
             82 => Some(ObjectType::ReportSettings),
             83 => Some(ObjectType::Role),
@@ -381,7 +387,7 @@ impl EnumImpl for ObjectType {
         }
     }
 
-    const COUNT: usize = 117;
+    const COUNT: usize = 119;
 }
 
 impl serde::Serialize for ObjectType {
@@ -418,6 +424,10 @@ impl EnumImpl for Property {
             b"accountId" => Property::AccountId,
             b"accountIdentifier" => Property::AccountIdentifier,
             b"accountKey" => Property::AccountKey,
+            b"accountModel" => Property::AccountModel,
+            b"accountModelMaxAge" => Property::AccountModelMaxAge,
+            b"accountModelMinSamples" => Property::AccountModelMinSamples,
+            b"accountModelWeight" => Property::AccountModelWeight,
             b"accountName" => Property::AccountName,
             b"accountType" => Property::AccountType,
             b"accountUri" => Property::AccountUri,
@@ -452,6 +462,7 @@ impl EnumImpl for Property {
             b"allowRelaying" => Property::AllowRelaying,
             b"allowSpamTraining" => Property::AllowSpamTraining,
             b"allowedEndpoints" => Property::AllowedEndpoints,
+            b"allowedExtLists" => Property::AllowedExtLists,
             b"allowedIps" => Property::AllowedIps,
             b"allowedNotifyUris" => Property::AllowedNotifyUris,
             b"alpha" => Property::Alpha,
@@ -491,9 +502,14 @@ impl EnumImpl for Property {
             b"authenticationResults" => Property::AuthenticationResults,
             b"autoAddInvitations" => Property::AutoAddInvitations,
             b"autoUpdateFrequency" => Property::AutoUpdateFrequency,
+            b"autocrypt" => Property::Autocrypt,
             b"baseDn" => Property::BaseDn,
             b"bearerToken" => Property::BearerToken,
             b"beta" => Property::Beta,
+            b"bimiMaxSize" => Property::BimiMaxSize,
+            b"bimiRequireVmc" => Property::BimiRequireVmc,
+            b"bimiTrustAnchors" => Property::BimiTrustAnchors,
+            b"bimiVerify" => Property::BimiVerify,
             b"bind" => Property::Bind,
             b"bindAuthentication" => Property::BindAuthentication,
             b"bindDn" => Property::BindDn,
@@ -504,6 +520,11 @@ impl EnumImpl for Property {
             b"blobStore" => Property::BlobStore,
             b"blockCount" => Property::BlockCount,
             b"body" => Property::Body,
+            b"bookingAllowedRequesters" => Property::BookingAllowedRequesters,
+            b"bookingDelegateIds" => Property::BookingDelegateIds,
+            b"bookingMaxDuration" => Property::BookingMaxDuration,
+            b"bookingPolicy" => Property::BookingPolicy,
+            b"bookingWindow" => Property::BookingWindow,
             b"brokers" => Property::Brokers,
             b"bucket" => Property::Bucket,
             b"bufferSize" => Property::BufferSize,
@@ -531,12 +552,14 @@ impl EnumImpl for Property {
             b"columnSecret" => Property::ColumnSecret,
             b"comment" => Property::Comment,
             b"compressionAlgorithm" => Property::CompressionAlgorithm,
+            b"compressionMinSize" => Property::CompressionMinSize,
             b"concurrency" => Property::Concurrency,
             b"condition" => Property::Condition,
             b"confidence" => Property::Confidence,
             b"config" => Property::Config,
             b"connectTimeout" => Property::ConnectTimeout,
             b"connection" => Property::Connection,
+            b"connectionStrategyId" => Property::ConnectionStrategyId,
             b"consumerKey" => Property::ConsumerKey,
             b"contact" => Property::Contact,
             b"contactInfo" => Property::ContactInfo,
@@ -555,6 +578,7 @@ impl EnumImpl for Property {
             b"customEndpoint" => Property::CustomEndpoint,
             b"customRegion" => Property::CustomRegion,
             b"customRule" => Property::CustomRule,
+            b"dailyLimit" => Property::DailyLimit,
             b"dane" => Property::Dane,
             b"dataCleanupSchedule" => Property::DataCleanupSchedule,
             b"dataStore" => Property::DataStore,
@@ -664,6 +688,7 @@ impl EnumImpl for Property {
             b"emailTemplate" => Property::EmailTemplate,
             b"enable" => Property::Enable,
             b"enableAssistedDiscovery" => Property::EnableAssistedDiscovery,
+            b"enableCompression" => Property::EnableCompression,
             b"enableEdns" => Property::EnableEdns,
             b"enableHsts" => Property::EnableHsts,
             b"enableLogExporter" => Property::EnableLogExporter,
@@ -714,6 +739,9 @@ impl EnumImpl for Property {
             b"extension" => Property::Extension,
             b"extensions" => Property::Extensions,
             b"extraContactInfo" => Property::ExtraContactInfo,
+            b"extractArchiveMaxSize" => Property::ExtractArchiveMaxSize,
+            b"extractOfficeMaxSize" => Property::ExtractOfficeMaxSize,
+            b"extractPdfMaxSize" => Property::ExtractPdfMaxSize,
             b"factor" => Property::Factor,
             b"failOnTimeout" => Property::FailOnTimeout,
             b"failedAt" => Property::FailedAt,
@@ -760,11 +788,14 @@ impl EnumImpl for Property {
             b"headers" => Property::Headers,
             b"holdMetricsFor" => Property::HoldMetricsFor,
             b"holdMtaReportsFor" => Property::HoldMtaReportsFor,
+            b"holdQuarantineFor" => Property::HoldQuarantineFor,
             b"holdSamplesFor" => Property::HoldSamplesFor,
             b"holdTracesFor" => Property::HoldTracesFor,
             b"host" => Property::Host,
+            b"hostCooldown" => Property::HostCooldown,
             b"hostedZoneId" => Property::HostedZoneId,
             b"hostname" => Property::Hostname,
+            b"hosts" => Property::Hosts,
             b"hour" => Property::Hour,
             b"httpAuth" => Property::HttpAuth,
             b"httpHeaders" => Property::HttpHeaders,
@@ -1030,6 +1061,8 @@ impl EnumImpl for Property {
             b"port" => Property::Port,
             b"prefix" => Property::Prefix,
             b"preserveIntermediates" => Property::PreserveIntermediates,
+            b"preview" => Property::Preview,
+            b"principalKind" => Property::PrincipalKind,
             b"priority" => Property::Priority,
             b"privateKey" => Property::PrivateKey,
             b"privateZone" => Property::PrivateZone,
@@ -1047,6 +1080,7 @@ impl EnumImpl for Property {
             b"proxyTrustedNetworks" => Property::ProxyTrustedNetworks,
             b"publicKey" => Property::PublicKey,
             b"publishRecords" => Property::PublishRecords,
+            b"publishWkd" => Property::PublishWkd,
             b"pushAttemptWait" => Property::PushAttemptWait,
             b"pushMaxAttempts" => Property::PushMaxAttempts,
             b"pushRequestTimeout" => Property::PushRequestTimeout,
@@ -1054,6 +1088,7 @@ impl EnumImpl for Property {
             b"pushShardsTotal" => Property::PushShardsTotal,
             b"pushThrottle" => Property::PushThrottle,
             b"pushVerifyTimeout" => Property::PushVerifyTimeout,
+            b"quarantineDigestSchedule" => Property::QuarantineDigestSchedule,
             b"queryEmailAliases" => Property::QueryEmailAliases,
             b"queryLogin" => Property::QueryLogin,
             b"queryMaxResults" => Property::QueryMaxResults,
@@ -1061,11 +1096,13 @@ impl EnumImpl for Property {
             b"queryRecipient" => Property::QueryRecipient,
             b"queueId" => Property::QueueId,
             b"queueName" => Property::QueueName,
+            b"quotaWarnThresholds" => Property::QuotaWarnThresholds,
             b"quotas" => Property::Quotas,
             b"rate" => Property::Rate,
             b"rateLimit" => Property::RateLimit,
             b"rateLimitAnonymous" => Property::RateLimitAnonymous,
             b"rateLimitAuthenticated" => Property::RateLimitAuthenticated,
+            b"rateLimitNotify" => Property::RateLimitNotify,
             b"ratio" => Property::Ratio,
             b"rcptToTimeout" => Property::RcptToTimeout,
             b"readFromReplicas" => Property::ReadFromReplicas,
@@ -1118,6 +1155,9 @@ impl EnumImpl for Property {
             b"retryCount" => Property::RetryCount,
             b"retryDue" => Property::RetryDue,
             b"returnPath" => Property::ReturnPath,
+            b"reuseIdleTimeout" => Property::ReuseIdleTimeout,
+            b"reuseMaxIdle" => Property::ReuseMaxIdle,
+            b"reuseMaxMessages" => Property::ReuseMaxMessages,
             b"reverseIpVerify" => Property::ReverseIpVerify,
             b"rewrite" => Property::Rewrite,
             b"roleIds" => Property::RoleIds,
@@ -1148,7 +1188,9 @@ impl EnumImpl for Property {
             b"selector" => Property::Selector,
             b"selectorTemplate" => Property::SelectorTemplate,
             b"sendFrequency" => Property::SendFrequency,
+            b"sendQuarantineDigest" => Property::SendQuarantineDigest,
             b"sendingMtaIp" => Property::SendingMtaIp,
+            b"sentToday" => Property::SentToday,
             b"separator" => Property::Separator,
             b"serverHostname" => Property::ServerHostname,
             b"servers" => Property::Servers,
@@ -1177,6 +1219,7 @@ impl EnumImpl for Property {
             b"sourceIps" => Property::SourceIps,
             b"sourcePort" => Property::SourcePort,
             b"spamFilterRulesUrl" => Property::SpamFilterRulesUrl,
+            b"spamModels" => Property::SpamModels,
             b"spfDns" => Property::SpfDns,
             b"spfEhloDomain" => Property::SpfEhloDomain,
             b"spfEhloResult" => Property::SpfEhloResult,
@@ -1185,6 +1228,10 @@ impl EnumImpl for Property {
             b"spfMailFromDomain" => Property::SpfMailFromDomain,
             b"spfMailFromResult" => Property::SpfMailFromResult,
             b"spfResults" => Property::SpfResults,
+            b"srsDomain" => Property::SrsDomain,
+            b"srsMaxAge" => Property::SrsMaxAge,
+            b"srsPreviousKey" => Property::SrsPreviousKey,
+            b"srsSigningKey" => Property::SrsSigningKey,
             b"stage" => Property::Stage,
             b"stages" => Property::Stages,
             b"startTime" => Property::StartTime,
@@ -1275,9 +1322,14 @@ impl EnumImpl for Property {
             b"version" => Property::Version,
             b"vrfy" => Property::Vrfy,
             b"waitOnFail" => Property::WaitOnFail,
+            b"warmUpDays" => Property::WarmUpDays,
+            b"warmUpInitialVolume" => Property::WarmUpInitialVolume,
+            b"warmUpStart" => Property::WarmUpStart,
+            b"warmUpTargetVolume" => Property::WarmUpTargetVolume,
             b"websocketHeartbeat" => Property::WebsocketHeartbeat,
             b"websocketThrottle" => Property::WebsocketThrottle,
             b"websocketTimeout" => Property::WebsocketTimeout,
+            b"weight" => Property::Weight,
             b"zone" => Property::Zone,
             b"zoneIpV4" => Property::ZoneIpV4,
             b"zoneIpV6" => Property::ZoneIpV6,
@@ -1297,6 +1349,10 @@ impl EnumImpl for Property {
             Property::AccountId => "accountId",
             Property::AccountIdentifier => "accountIdentifier",
             Property::AccountKey => "accountKey",
+            Property::AccountModel => "accountModel",
+            Property::AccountModelMaxAge => "accountModelMaxAge",
+            Property::AccountModelMinSamples => "accountModelMinSamples",
+            Property::AccountModelWeight => "accountModelWeight",
             Property::AccountName => "accountName",
             Property::AccountType => "accountType",
             Property::AccountUri => "accountUri",
@@ -1331,6 +1387,7 @@ impl EnumImpl for Property {
             Property::AllowRelaying => "allowRelaying",
             Property::AllowSpamTraining => "allowSpamTraining",
             Property::AllowedEndpoints => "allowedEndpoints",
+            Property::AllowedExtLists => "allowedExtLists",
             Property::AllowedIps => "allowedIps",
             Property::AllowedNotifyUris => "allowedNotifyUris",
             Property::Alpha => "alpha",
@@ -1370,9 +1427,14 @@ impl EnumImpl for Property {
             Property::AuthenticationResults => "authenticationResults",
             Property::AutoAddInvitations => "autoAddInvitations",
             Property::AutoUpdateFrequency => "autoUpdateFrequency",
+            Property::Autocrypt => "autocrypt",
             Property::BaseDn => "baseDn",
             Property::BearerToken => "bearerToken",
             Property::Beta => "beta",
+            Property::BimiMaxSize => "bimiMaxSize",
+            Property::BimiRequireVmc => "bimiRequireVmc",
+            Property::BimiTrustAnchors => "bimiTrustAnchors",
+            Property::BimiVerify => "bimiVerify",
             Property::Bind => "bind",
             Property::BindAuthentication => "bindAuthentication",
             Property::BindDn => "bindDn",
@@ -1383,6 +1445,11 @@ impl EnumImpl for Property {
             Property::BlobStore => "blobStore",
             Property::BlockCount => "blockCount",
             Property::Body => "body",
+            Property::BookingAllowedRequesters => "bookingAllowedRequesters",
+            Property::BookingDelegateIds => "bookingDelegateIds",
+            Property::BookingMaxDuration => "bookingMaxDuration",
+            Property::BookingPolicy => "bookingPolicy",
+            Property::BookingWindow => "bookingWindow",
             Property::Brokers => "brokers",
             Property::Bucket => "bucket",
             Property::BufferSize => "bufferSize",
@@ -1410,12 +1477,14 @@ impl EnumImpl for Property {
             Property::ColumnSecret => "columnSecret",
             Property::Comment => "comment",
             Property::CompressionAlgorithm => "compressionAlgorithm",
+            Property::CompressionMinSize => "compressionMinSize",
             Property::Concurrency => "concurrency",
             Property::Condition => "condition",
             Property::Confidence => "confidence",
             Property::Config => "config",
             Property::ConnectTimeout => "connectTimeout",
             Property::Connection => "connection",
+            Property::ConnectionStrategyId => "connectionStrategyId",
             Property::ConsumerKey => "consumerKey",
             Property::Contact => "contact",
             Property::ContactInfo => "contactInfo",
@@ -1434,6 +1503,7 @@ impl EnumImpl for Property {
             Property::CustomEndpoint => "customEndpoint",
             Property::CustomRegion => "customRegion",
             Property::CustomRule => "customRule",
+            Property::DailyLimit => "dailyLimit",
             Property::Dane => "dane",
             Property::DataCleanupSchedule => "dataCleanupSchedule",
             Property::DataStore => "dataStore",
@@ -1543,6 +1613,7 @@ impl EnumImpl for Property {
             Property::EmailTemplate => "emailTemplate",
             Property::Enable => "enable",
             Property::EnableAssistedDiscovery => "enableAssistedDiscovery",
+            Property::EnableCompression => "enableCompression",
             Property::EnableEdns => "enableEdns",
             Property::EnableHsts => "enableHsts",
             Property::EnableLogExporter => "enableLogExporter",
@@ -1593,6 +1664,9 @@ impl EnumImpl for Property {
             Property::Extension => "extension",
             Property::Extensions => "extensions",
             Property::ExtraContactInfo => "extraContactInfo",
+            Property::ExtractArchiveMaxSize => "extractArchiveMaxSize",
+            Property::ExtractOfficeMaxSize => "extractOfficeMaxSize",
+            Property::ExtractPdfMaxSize => "extractPdfMaxSize",
             Property::Factor => "factor",
             Property::FailOnTimeout => "failOnTimeout",
             Property::FailedAt => "failedAt",
@@ -1639,11 +1713,14 @@ impl EnumImpl for Property {
             Property::Headers => "headers",
             Property::HoldMetricsFor => "holdMetricsFor",
             Property::HoldMtaReportsFor => "holdMtaReportsFor",
+            Property::HoldQuarantineFor => "holdQuarantineFor",
             Property::HoldSamplesFor => "holdSamplesFor",
             Property::HoldTracesFor => "holdTracesFor",
             Property::Host => "host",
+            Property::HostCooldown => "hostCooldown",
             Property::HostedZoneId => "hostedZoneId",
             Property::Hostname => "hostname",
+            Property::Hosts => "hosts",
             Property::Hour => "hour",
             Property::HttpAuth => "httpAuth",
             Property::HttpHeaders => "httpHeaders",
@@ -1909,6 +1986,8 @@ impl EnumImpl for Property {
             Property::Port => "port",
             Property::Prefix => "prefix",
             Property::PreserveIntermediates => "preserveIntermediates",
+            Property::Preview => "preview",
+            Property::PrincipalKind => "principalKind",
             Property::Priority => "priority",
             Property::PrivateKey => "privateKey",
             Property::PrivateZone => "privateZone",
@@ -1926,6 +2005,7 @@ impl EnumImpl for Property {
             Property::ProxyTrustedNetworks => "proxyTrustedNetworks",
             Property::PublicKey => "publicKey",
             Property::PublishRecords => "publishRecords",
+            Property::PublishWkd => "publishWkd",
             Property::PushAttemptWait => "pushAttemptWait",
             Property::PushMaxAttempts => "pushMaxAttempts",
             Property::PushRequestTimeout => "pushRequestTimeout",
@@ -1933,6 +2013,7 @@ impl EnumImpl for Property {
             Property::PushShardsTotal => "pushShardsTotal",
             Property::PushThrottle => "pushThrottle",
             Property::PushVerifyTimeout => "pushVerifyTimeout",
+            Property::QuarantineDigestSchedule => "quarantineDigestSchedule",
             Property::QueryEmailAliases => "queryEmailAliases",
             Property::QueryLogin => "queryLogin",
             Property::QueryMaxResults => "queryMaxResults",
@@ -1940,11 +2021,13 @@ impl EnumImpl for Property {
             Property::QueryRecipient => "queryRecipient",
             Property::QueueId => "queueId",
             Property::QueueName => "queueName",
+            Property::QuotaWarnThresholds => "quotaWarnThresholds",
             Property::Quotas => "quotas",
             Property::Rate => "rate",
             Property::RateLimit => "rateLimit",
             Property::RateLimitAnonymous => "rateLimitAnonymous",
             Property::RateLimitAuthenticated => "rateLimitAuthenticated",
+            Property::RateLimitNotify => "rateLimitNotify",
             Property::Ratio => "ratio",
             Property::RcptToTimeout => "rcptToTimeout",
             Property::ReadFromReplicas => "readFromReplicas",
@@ -1997,6 +2080,9 @@ impl EnumImpl for Property {
             Property::RetryCount => "retryCount",
             Property::RetryDue => "retryDue",
             Property::ReturnPath => "returnPath",
+            Property::ReuseIdleTimeout => "reuseIdleTimeout",
+            Property::ReuseMaxIdle => "reuseMaxIdle",
+            Property::ReuseMaxMessages => "reuseMaxMessages",
             Property::ReverseIpVerify => "reverseIpVerify",
             Property::Rewrite => "rewrite",
             Property::RoleIds => "roleIds",
@@ -2027,7 +2113,9 @@ impl EnumImpl for Property {
             Property::Selector => "selector",
             Property::SelectorTemplate => "selectorTemplate",
             Property::SendFrequency => "sendFrequency",
+            Property::SendQuarantineDigest => "sendQuarantineDigest",
             Property::SendingMtaIp => "sendingMtaIp",
+            Property::SentToday => "sentToday",
             Property::Separator => "separator",
             Property::ServerHostname => "serverHostname",
             Property::Servers => "servers",
@@ -2056,6 +2144,7 @@ impl EnumImpl for Property {
             Property::SourceIps => "sourceIps",
             Property::SourcePort => "sourcePort",
             Property::SpamFilterRulesUrl => "spamFilterRulesUrl",
+            Property::SpamModels => "spamModels",
             Property::SpfDns => "spfDns",
             Property::SpfEhloDomain => "spfEhloDomain",
             Property::SpfEhloResult => "spfEhloResult",
@@ -2064,6 +2153,10 @@ impl EnumImpl for Property {
             Property::SpfMailFromDomain => "spfMailFromDomain",
             Property::SpfMailFromResult => "spfMailFromResult",
             Property::SpfResults => "spfResults",
+            Property::SrsDomain => "srsDomain",
+            Property::SrsMaxAge => "srsMaxAge",
+            Property::SrsPreviousKey => "srsPreviousKey",
+            Property::SrsSigningKey => "srsSigningKey",
             Property::Stage => "stage",
             Property::Stages => "stages",
             Property::StartTime => "startTime",
@@ -2154,9 +2247,14 @@ impl EnumImpl for Property {
             Property::Version => "version",
             Property::Vrfy => "vrfy",
             Property::WaitOnFail => "waitOnFail",
+            Property::WarmUpDays => "warmUpDays",
+            Property::WarmUpInitialVolume => "warmUpInitialVolume",
+            Property::WarmUpStart => "warmUpStart",
+            Property::WarmUpTargetVolume => "warmUpTargetVolume",
             Property::WebsocketHeartbeat => "websocketHeartbeat",
             Property::WebsocketThrottle => "websocketThrottle",
             Property::WebsocketTimeout => "websocketTimeout",
+            Property::Weight => "weight",
             Property::Zone => "zone",
             Property::ZoneIpV4 => "zoneIpV4",
             Property::ZoneIpV6 => "zoneIpV6",
@@ -2180,6 +2278,10 @@ impl EnumImpl for Property {
             57 => Some(Property::AccountId),
             315 => Some(Property::AccountIdentifier),
             15 => Some(Property::AccountKey),
+            880 => Some(Property::AccountModel),
+            883 => Some(Property::AccountModelMaxAge),
+            882 => Some(Property::AccountModelMinSamples),
+            881 => Some(Property::AccountModelWeight),
             809 => Some(Property::AccountName),
             811 => Some(Property::AccountType),
             16 => Some(Property::AccountUri),
@@ -2214,6 +2316,7 @@ impl EnumImpl for Property {
             348 => Some(Property::AllowRelaying),
             369 => Some(Property::AllowSpamTraining),
             398 => Some(Property::AllowedEndpoints),
+            917 => Some(Property::AllowedExtLists),
             49 => Some(Property::AllowedIps),
             712 => Some(Property::AllowedNotifyUris),
             388 => Some(Property::Alpha),
@@ -2253,9 +2356,14 @@ impl EnumImpl for Property {
             69 => Some(Property::AuthenticationResults),
             171 => Some(Property::AutoAddInvitations),
             53 => Some(Property::AutoUpdateFrequency),
+            910 => Some(Property::Autocrypt),
             463 => Some(Property::BaseDn),
             403 => Some(Property::BearerToken),
             389 => Some(Property::Beta),
+            908 => Some(Property::BimiMaxSize),
+            907 => Some(Property::BimiRequireVmc),
+            919 => Some(Property::BimiTrustAnchors),
+            906 => Some(Property::BimiVerify),
             589 => Some(Property::Bind),
             466 => Some(Property::BindAuthentication),
             464 => Some(Property::BindDn),
@@ -2266,6 +2374,11 @@ impl EnumImpl for Property {
             126 => Some(Property::BlobStore),
             766 => Some(Property::BlockCount),
             38 => Some(Property::Body),
+            915 => Some(Property::BookingAllowedRequesters),
+            916 => Some(Property::BookingDelegateIds),
+            913 => Some(Property::BookingMaxDuration),
+            912 => Some(Property::BookingPolicy),
+            914 => Some(Property::BookingWindow),
             459 => Some(Property::Brokers),
             658 => Some(Property::Bucket),
             656 => Some(Property::BufferSize),
@@ -2293,12 +2406,14 @@ impl EnumImpl for Property {
             780 => Some(Property::ColumnSecret),
             240 => Some(Property::Comment),
             359 => Some(Property::CompressionAlgorithm),
+            875 => Some(Property::CompressionMinSize),
             304 => Some(Property::Concurrency),
             34 => Some(Property::Condition),
             760 => Some(Property::Confidence),
             873 => Some(Property::Config),
             505 => Some(Property::ConnectTimeout),
             539 => Some(Property::Connection),
+            903 => Some(Property::ConnectionStrategyId),
             323 => Some(Property::ConsumerKey),
             11 => Some(Property::Contact),
             844 => Some(Property::ContactInfo),
@@ -2317,6 +2432,7 @@ impl EnumImpl for Property {
             662 => Some(Property::CustomEndpoint),
             663 => Some(Property::CustomRegion),
             787 => Some(Property::CustomRule),
+            904 => Some(Property::DailyLimit),
             569 => Some(Property::Dane),
             199 => Some(Property::DataCleanupSchedule),
             125 => Some(Property::DataStore),
@@ -2426,6 +2542,7 @@ impl EnumImpl for Property {
             174 => Some(Property::EmailTemplate),
             37 => Some(Property::Enable),
             865 => Some(Property::EnableAssistedDiscovery),
+            874 => Some(Property::EnableCompression),
             305 => Some(Property::EnableEdns),
             399 => Some(Property::EnableHsts),
             860 => Some(Property::EnableLogExporter),
@@ -2476,6 +2593,9 @@ impl EnumImpl for Property {
             754 => Some(Property::Extension),
             257 => Some(Property::Extensions),
             243 => Some(Property::ExtraContactInfo),
+            879 => Some(Property::ExtractArchiveMaxSize),
+            878 => Some(Property::ExtractOfficeMaxSize),
+            877 => Some(Property::ExtractPdfMaxSize),
             821 => Some(Property::Factor),
             490 => Some(Property::FailOnTimeout),
             826 => Some(Property::FailedAt),
@@ -2522,11 +2642,14 @@ impl EnumImpl for Property {
             93 => Some(Property::Headers),
             206 => Some(Property::HoldMetricsFor),
             204 => Some(Property::HoldMtaReportsFor),
+            886 => Some(Property::HoldQuarantineFor),
             730 => Some(Property::HoldSamplesFor),
             205 => Some(Property::HoldTracesFor),
             333 => Some(Property::Host),
+            895 => Some(Property::HostCooldown),
             331 => Some(Property::HostedZoneId),
             185 => Some(Property::Hostname),
+            894 => Some(Property::Hosts),
             190 => Some(Property::Hour),
             32 => Some(Property::HttpAuth),
             33 => Some(Property::HttpHeaders),
@@ -2792,6 +2915,8 @@ impl EnumImpl for Property {
             299 => Some(Property::Port),
             856 => Some(Property::Prefix),
             306 => Some(Property::PreserveIntermediates),
+            885 => Some(Property::Preview),
+            911 => Some(Property::PrincipalKind),
             483 => Some(Property::Priority),
             177 => Some(Property::PrivateKey),
             319 => Some(Property::PrivateZone),
@@ -2809,6 +2934,7 @@ impl EnumImpl for Property {
             792 => Some(Property::ProxyTrustedNetworks),
             218 => Some(Property::PublicKey),
             302 => Some(Property::PublishRecords),
+            909 => Some(Property::PublishWkd),
             448 => Some(Property::PushAttemptWait),
             449 => Some(Property::PushMaxAttempts),
             452 => Some(Property::PushRequestTimeout),
@@ -2816,6 +2942,7 @@ impl EnumImpl for Property {
             454 => Some(Property::PushShardsTotal),
             451 => Some(Property::PushThrottle),
             453 => Some(Property::PushVerifyTimeout),
+            888 => Some(Property::QuarantineDigestSchedule),
             786 => Some(Property::QueryEmailAliases),
             783 => Some(Property::QueryLogin),
             437 => Some(Property::QueryMaxResults),
@@ -2823,11 +2950,13 @@ impl EnumImpl for Property {
             784 => Some(Property::QueryRecipient),
             514 => Some(Property::QueueId),
             644 => Some(Property::QueueName),
+            884 => Some(Property::QuotaWarnThresholds),
             394 => Some(Property::Quotas),
             532 => Some(Property::Rate),
             410 => Some(Property::RateLimit),
             397 => Some(Property::RateLimitAnonymous),
             396 => Some(Property::RateLimitAuthenticated),
+            876 => Some(Property::RateLimitNotify),
             767 => Some(Property::Ratio),
             510 => Some(Property::RcptToTimeout),
             650 => Some(Property::ReadFromReplicas),
@@ -2880,6 +3009,9 @@ impl EnumImpl for Property {
             640 => Some(Property::RetryCount),
             641 => Some(Property::RetryDue),
             635 => Some(Property::ReturnPath),
+            897 => Some(Property::ReuseIdleTimeout),
+            896 => Some(Property::ReuseMaxIdle),
+            898 => Some(Property::ReuseMaxMessages),
             692 => Some(Property::ReverseIpVerify),
             565 => Some(Property::Rewrite),
             193 => Some(Property::RoleIds),
@@ -2910,7 +3042,9 @@ impl EnumImpl for Property {
             222 => Some(Property::Selector),
             226 => Some(Property::SelectorTemplate),
             230 => Some(Property::SendFrequency),
+            887 => Some(Property::SendQuarantineDigest),
             833 => Some(Property::SendingMtaIp),
+            905 => Some(Property::SentToday),
             97 => Some(Property::Separator),
             121 => Some(Property::ServerHostname),
             308 => Some(Property::Servers),
@@ -2939,6 +3073,7 @@ impl EnumImpl for Property {
             504 => Some(Property::SourceIps),
             78 => Some(Property::SourcePort),
             775 => Some(Property::SpamFilterRulesUrl),
+            918 => Some(Property::SpamModels),
             90 => Some(Property::SpfDns),
             285 => Some(Property::SpfEhloDomain),
             286 => Some(Property::SpfEhloResult),
@@ -2947,6 +3082,10 @@ impl EnumImpl for Property {
             287 => Some(Property::SpfMailFromDomain),
             288 => Some(Property::SpfMailFromResult),
             267 => Some(Property::SpfResults),
+            889 => Some(Property::SrsDomain),
+            892 => Some(Property::SrsMaxAge),
+            891 => Some(Property::SrsPreviousKey),
+            890 => Some(Property::SrsSigningKey),
             224 => Some(Property::Stage),
             529 => Some(Property::Stages),
             56 => Some(Property::StartTime),
@@ -3037,9 +3176,14 @@ impl EnumImpl for Property {
             80 => Some(Property::Version),
             526 => Some(Property::Vrfy),
             548 => Some(Property::WaitOnFail),
+            900 => Some(Property::WarmUpDays),
+            901 => Some(Property::WarmUpInitialVolume),
+            899 => Some(Property::WarmUpStart),
+            902 => Some(Property::WarmUpTargetVolume),
             455 => Some(Property::WebsocketHeartbeat),
             456 => Some(Property::WebsocketThrottle),
             457 => Some(Property::WebsocketTimeout),
+            893 => Some(Property::Weight),
             749 => Some(Property::Zone),
             98 => Some(Property::ZoneIpV4),
             99 => Some(Property::ZoneIpV6),
@@ -3096,6 +3240,7 @@ impl ObjectType {
             ObjectType::CalendarAlarm => CalendarAlarm::FLAGS,
             ObjectType::CalendarScheduling => CalendarScheduling::FLAGS,
             ObjectType::Certificate => Certificate::FLAGS,
+            ObjectType::MtaIpWarmUp => MtaIpWarmUp::FLAGS,
             ObjectType::ClusterNode => ClusterNode::FLAGS,
             ObjectType::ClusterRole => ClusterRole::FLAGS,
             ObjectType::Coordinator => Coordinator::FLAGS,
@@ -3153,6 +3298,7 @@ impl ObjectType {
             ObjectType::OAuthClient => OAuthClient::FLAGS,
             ObjectType::OidcProvider => OidcProvider::FLAGS,
             ObjectType::PublicKey => PublicKey::FLAGS,
+            ObjectType::QuarantinedMessage => QuarantinedMessage::FLAGS,
             ObjectType::QueuedMessage => QueuedMessage::FLAGS,
             ObjectType::ReportSettings => ReportSettings::FLAGS,
             ObjectType::Role => Role::FLAGS,
@@ -3225,6 +3371,11 @@ impl ObjectType {
                     IndexSchemaType::Search,
                     IndexSchemaValueType::Keyword,
                 ),
+                IndexSchema::new(
+                    Property::PrincipalKind,
+                    IndexSchemaType::Search,
+                    IndexSchemaValueType::Enum,
+                ),
             ],
             ObjectType::AcmeProvider => vec![
                 IndexSchema::new(
@@ -3381,6 +3532,11 @@ impl ObjectType {
                 IndexSchemaType::Search,
                 IndexSchemaValueType::Id,
             )],
+            ObjectType::QuarantinedMessage => vec![IndexSchema::new(
+                Property::AccountId,
+                IndexSchemaType::Search,
+                IndexSchemaValueType::Id,
+            )],
             ObjectType::Role => vec![
                 IndexSchema::new(
                     Property::Description,
@@ -3463,6 +3619,7 @@ impl ObjectType {
             ObjectType::CalendarAlarm => Permission::SysCalendarAlarmGet,
             ObjectType::CalendarScheduling => Permission::SysCalendarSchedulingGet,
             ObjectType::Certificate => Permission::SysCertificateGet,
+            ObjectType::MtaIpWarmUp => Permission::SysMtaIpWarmUpGet,
             ObjectType::ClusterNode => Permission::SysClusterNodeGet,
             ObjectType::ClusterRole => Permission::SysClusterRoleGet,
             ObjectType::Coordinator => Permission::SysCoordinatorGet,
@@ -3520,6 +3677,7 @@ impl ObjectType {
             ObjectType::OAuthClient => Permission::SysOAuthClientGet,
             ObjectType::OidcProvider => Permission::SysOidcProviderGet,
             ObjectType::PublicKey => Permission::SysPublicKeyGet,
+            ObjectType::QuarantinedMessage => Permission::SysQuarantinedMessageGet,
             ObjectType::QueuedMessage => Permission::SysQueuedMessageGet,
             ObjectType::ReportSettings => Permission::SysReportSettingsGet,
             ObjectType::Role => Permission::SysRoleGet,
@@ -3574,6 +3732,7 @@ impl ObjectType {
             ObjectType::ArfExternalReport => Permission::SysArfExternalReportQuery,
             ObjectType::BlockedIp => Permission::SysBlockedIpQuery,
             ObjectType::Certificate => Permission::SysCertificateQuery,
+            ObjectType::MtaIpWarmUp => Permission::SysMtaIpWarmUpQuery,
             ObjectType::ClusterNode => Permission::SysClusterNodeQuery,
             ObjectType::ClusterRole => Permission::SysClusterRoleQuery,
             ObjectType::Directory => Permission::SysDirectoryQuery,
@@ -3603,6 +3762,7 @@ impl ObjectType {
             ObjectType::NetworkListener => Permission::SysNetworkListenerQuery,
             ObjectType::OAuthClient => Permission::SysOAuthClientQuery,
             ObjectType::PublicKey => Permission::SysPublicKeyQuery,
+            ObjectType::QuarantinedMessage => Permission::SysQuarantinedMessageQuery,
             ObjectType::QueuedMessage => Permission::SysQueuedMessageQuery,
             ObjectType::Role => Permission::SysRoleQuery,
             ObjectType::SieveSystemScript => Permission::SysSieveSystemScriptQuery,
@@ -3746,6 +3906,11 @@ impl ObjectType {
                 Permission::SysCertificateUpdate,
                 Permission::SysCertificateDestroy,
             ],
+            ObjectType::MtaIpWarmUp => [
+                Permission::SysMtaIpWarmUpCreate,
+                Permission::SysMtaIpWarmUpUpdate,
+                Permission::SysMtaIpWarmUpDestroy,
+            ],
             ObjectType::ClusterNode => [
                 Permission::SysClusterNodeCreate,
                 Permission::SysClusterNodeUpdate,
@@ -4031,6 +4196,11 @@ impl ObjectType {
                 Permission::SysPublicKeyUpdate,
                 Permission::SysPublicKeyDestroy,
             ],
+            ObjectType::QuarantinedMessage => [
+                Permission::SysQuarantinedMessageCreate,
+                Permission::SysQuarantinedMessageUpdate,
+                Permission::SysQuarantinedMessageDestroy,
+            ],
             ObjectType::QueuedMessage => [
                 Permission::SysQueuedMessageCreate,
                 Permission::SysQueuedMessageUpdate,
@@ -4299,6 +4469,7 @@ impl ObjectInner {
             ObjectInner::ArchivedItem(ArchivedItem::SieveScript(obj)) => Some(obj.account_id),
             ObjectInner::MaskedEmail(obj) => Some(obj.account_id),
             ObjectInner::PublicKey(obj) => Some(obj.account_id),
+            ObjectInner::QuarantinedMessage(obj) => obj.account_id,
             ObjectInner::SpamTrainingSample(obj) => obj.account_id,
             ObjectInner::Task(Task::IndexDocument(obj)) => Some(obj.account_id),
             ObjectInner::Task(Task::UnindexDocument(obj)) => Some(obj.account_id),
@@ -4322,6 +4493,7 @@ impl ObjectInner {
             ObjectInner::ArchivedItem(ArchivedItem::SieveScript(obj)) => obj.account_id = id,
             ObjectInner::MaskedEmail(obj) => obj.account_id = id,
             ObjectInner::PublicKey(obj) => obj.account_id = id,
+            ObjectInner::QuarantinedMessage(obj) => obj.account_id = Some(id),
             ObjectInner::SpamTrainingSample(obj) => obj.account_id = Some(id),
             ObjectInner::Task(Task::IndexDocument(obj)) => obj.account_id = id,
             ObjectInner::Task(Task::UnindexDocument(obj)) => obj.account_id = id,
@@ -4362,6 +4534,7 @@ impl ObjectInner {
             ObjectInner::CalendarAlarm(obj) => obj.to_pickled_vec(),
             ObjectInner::CalendarScheduling(obj) => obj.to_pickled_vec(),
             ObjectInner::Certificate(obj) => obj.to_pickled_vec(),
+            ObjectInner::MtaIpWarmUp(obj) => obj.to_pickled_vec(),
             ObjectInner::ClusterNode(obj) => obj.to_pickled_vec(),
             ObjectInner::ClusterRole(obj) => obj.to_pickled_vec(),
             ObjectInner::Coordinator(obj) => obj.to_pickled_vec(),
@@ -4419,6 +4592,7 @@ impl ObjectInner {
             ObjectInner::OAuthClient(obj) => obj.to_pickled_vec(),
             ObjectInner::OidcProvider(obj) => obj.to_pickled_vec(),
             ObjectInner::PublicKey(obj) => obj.to_pickled_vec(),
+            ObjectInner::QuarantinedMessage(obj) => obj.to_pickled_vec(),
             ObjectInner::QueuedMessage(obj) => obj.to_pickled_vec(),
             ObjectInner::ReportSettings(obj) => obj.to_pickled_vec(),
             ObjectInner::Role(obj) => obj.to_pickled_vec(),
@@ -4495,6 +4669,7 @@ impl ObjectInner {
                 Pickle::unpickle(stream).map(ObjectInner::CalendarScheduling)
             }
             ObjectType::Certificate => Pickle::unpickle(stream).map(ObjectInner::Certificate),
+            ObjectType::MtaIpWarmUp => Pickle::unpickle(stream).map(ObjectInner::MtaIpWarmUp),
             ObjectType::ClusterNode => Pickle::unpickle(stream).map(ObjectInner::ClusterNode),
             ObjectType::ClusterRole => Pickle::unpickle(stream).map(ObjectInner::ClusterRole),
             ObjectType::Coordinator => Pickle::unpickle(stream).map(ObjectInner::Coordinator),
@@ -4586,6 +4761,9 @@ impl ObjectInner {
             ObjectType::OAuthClient => Pickle::unpickle(stream).map(ObjectInner::OAuthClient),
             ObjectType::OidcProvider => Pickle::unpickle(stream).map(ObjectInner::OidcProvider),
             ObjectType::PublicKey => Pickle::unpickle(stream).map(ObjectInner::PublicKey),
+            ObjectType::QuarantinedMessage => {
+                Pickle::unpickle(stream).map(ObjectInner::QuarantinedMessage)
+            }
             ObjectType::QueuedMessage => Pickle::unpickle(stream).map(ObjectInner::QueuedMessage),
             ObjectType::ReportSettings => Pickle::unpickle(stream).map(ObjectInner::ReportSettings),
             ObjectType::Role => Pickle::unpickle(stream).map(ObjectInner::Role),
@@ -4710,6 +4888,9 @@ impl ObjectInner {
             ObjectType::Certificate => {
                 Certificate::deserialize(deserializer).map(ObjectInner::Certificate)
             }
+            ObjectType::MtaIpWarmUp => {
+                MtaIpWarmUp::deserialize(deserializer).map(ObjectInner::MtaIpWarmUp)
+            }
             ObjectType::ClusterNode => {
                 ClusterNode::deserialize(deserializer).map(ObjectInner::ClusterNode)
             }
@@ -4855,6 +5036,9 @@ impl ObjectInner {
             ObjectType::PublicKey => {
                 PublicKey::deserialize(deserializer).map(ObjectInner::PublicKey)
             }
+            ObjectType::QuarantinedMessage => {
+                QuarantinedMessage::deserialize(deserializer).map(ObjectInner::QuarantinedMessage)
+            }
             ObjectType::QueuedMessage => {
                 QueuedMessage::deserialize(deserializer).map(ObjectInner::QueuedMessage)
             }
@@ -4998,6 +5182,7 @@ impl Object {
             ObjectInner::CalendarAlarm(_) => CalendarAlarm::FLAGS,
             ObjectInner::CalendarScheduling(_) => CalendarScheduling::FLAGS,
             ObjectInner::Certificate(_) => Certificate::FLAGS,
+            ObjectInner::MtaIpWarmUp(_) => MtaIpWarmUp::FLAGS,
             ObjectInner::ClusterNode(_) => ClusterNode::FLAGS,
             ObjectInner::ClusterRole(_) => ClusterRole::FLAGS,
             ObjectInner::Coordinator(_) => Coordinator::FLAGS,
@@ -5055,6 +5240,7 @@ impl Object {
             ObjectInner::OAuthClient(_) => OAuthClient::FLAGS,
             ObjectInner::OidcProvider(_) => OidcProvider::FLAGS,
             ObjectInner::PublicKey(_) => PublicKey::FLAGS,
+            ObjectInner::QuarantinedMessage(_) => QuarantinedMessage::FLAGS,
             ObjectInner::QueuedMessage(_) => QueuedMessage::FLAGS,
             ObjectInner::ReportSettings(_) => ReportSettings::FLAGS,
             ObjectInner::Role(_) => Role::FLAGS,
@@ -5120,6 +5306,7 @@ impl Object {
             ObjectInner::CalendarAlarm(_) => ObjectType::CalendarAlarm,
             ObjectInner::CalendarScheduling(_) => ObjectType::CalendarScheduling,
             ObjectInner::Certificate(_) => ObjectType::Certificate,
+            ObjectInner::MtaIpWarmUp(_) => ObjectType::MtaIpWarmUp,
             ObjectInner::ClusterNode(_) => ObjectType::ClusterNode,
             ObjectInner::ClusterRole(_) => ObjectType::ClusterRole,
             ObjectInner::Coordinator(_) => ObjectType::Coordinator,
@@ -5177,6 +5364,7 @@ impl Object {
             ObjectInner::OAuthClient(_) => ObjectType::OAuthClient,
             ObjectInner::OidcProvider(_) => ObjectType::OidcProvider,
             ObjectInner::PublicKey(_) => ObjectType::PublicKey,
+            ObjectInner::QuarantinedMessage(_) => ObjectType::QuarantinedMessage,
             ObjectInner::QueuedMessage(_) => ObjectType::QueuedMessage,
             ObjectInner::ReportSettings(_) => ObjectType::ReportSettings,
             ObjectInner::Role(_) => ObjectType::Role,
@@ -5242,6 +5430,7 @@ impl Object {
             ObjectInner::CalendarAlarm(obj) => obj.validate(errors),
             ObjectInner::CalendarScheduling(obj) => obj.validate(errors),
             ObjectInner::Certificate(obj) => obj.validate(errors),
+            ObjectInner::MtaIpWarmUp(obj) => obj.validate(errors),
             ObjectInner::ClusterNode(obj) => obj.validate(errors),
             ObjectInner::ClusterRole(obj) => obj.validate(errors),
             ObjectInner::Coordinator(obj) => obj.validate(errors),
@@ -5299,6 +5488,7 @@ impl Object {
             ObjectInner::OAuthClient(obj) => obj.validate(errors),
             ObjectInner::OidcProvider(obj) => obj.validate(errors),
             ObjectInner::PublicKey(obj) => obj.validate(errors),
+            ObjectInner::QuarantinedMessage(obj) => obj.validate(errors),
             ObjectInner::QueuedMessage(obj) => obj.validate(errors),
             ObjectInner::ReportSettings(obj) => obj.validate(errors),
             ObjectInner::Role(obj) => obj.validate(errors),
@@ -5364,6 +5554,7 @@ impl Object {
             ObjectInner::CalendarAlarm(obj) => obj.index(i),
             ObjectInner::CalendarScheduling(obj) => obj.index(i),
             ObjectInner::Certificate(obj) => obj.index(i),
+            ObjectInner::MtaIpWarmUp(obj) => obj.index(i),
             ObjectInner::ClusterNode(obj) => obj.index(i),
             ObjectInner::ClusterRole(obj) => obj.index(i),
             ObjectInner::Coordinator(obj) => obj.index(i),
@@ -5421,6 +5612,7 @@ impl Object {
             ObjectInner::OAuthClient(obj) => obj.index(i),
             ObjectInner::OidcProvider(obj) => obj.index(i),
             ObjectInner::PublicKey(obj) => obj.index(i),
+            ObjectInner::QuarantinedMessage(obj) => obj.index(i),
             ObjectInner::QueuedMessage(obj) => obj.index(i),
             ObjectInner::ReportSettings(obj) => obj.index(i),
             ObjectInner::Role(obj) => obj.index(i),
@@ -5490,6 +5682,7 @@ impl Object {
             ObjectInner::CalendarAlarm(obj) => obj.patch(pointer, value),
             ObjectInner::CalendarScheduling(obj) => obj.patch(pointer, value),
             ObjectInner::Certificate(obj) => obj.patch(pointer, value),
+            ObjectInner::MtaIpWarmUp(obj) => obj.patch(pointer, value),
             ObjectInner::ClusterNode(obj) => obj.patch(pointer, value),
             ObjectInner::ClusterRole(obj) => obj.patch(pointer, value),
             ObjectInner::Coordinator(obj) => obj.patch(pointer, value),
@@ -5547,6 +5740,7 @@ impl Object {
             ObjectInner::OAuthClient(obj) => obj.patch(pointer, value),
             ObjectInner::OidcProvider(obj) => obj.patch(pointer, value),
             ObjectInner::PublicKey(obj) => obj.patch(pointer, value),
+            ObjectInner::QuarantinedMessage(obj) => obj.patch(pointer, value),
             ObjectInner::QueuedMessage(obj) => obj.patch(pointer, value),
             ObjectInner::ReportSettings(obj) => obj.patch(pointer, value),
             ObjectInner::Role(obj) => obj.patch(pointer, value),
@@ -5614,6 +5808,7 @@ impl IntoValue for Object {
             ObjectInner::CalendarAlarm(obj) => obj.into_value(),
             ObjectInner::CalendarScheduling(obj) => obj.into_value(),
             ObjectInner::Certificate(obj) => obj.into_value(),
+            ObjectInner::MtaIpWarmUp(obj) => obj.into_value(),
             ObjectInner::ClusterNode(obj) => obj.into_value(),
             ObjectInner::ClusterRole(obj) => obj.into_value(),
             ObjectInner::Coordinator(obj) => obj.into_value(),
@@ -5671,6 +5866,7 @@ impl IntoValue for Object {
             ObjectInner::OAuthClient(obj) => obj.into_value(),
             ObjectInner::OidcProvider(obj) => obj.into_value(),
             ObjectInner::PublicKey(obj) => obj.into_value(),
+            ObjectInner::QuarantinedMessage(obj) => obj.into_value(),
             ObjectInner::QueuedMessage(obj) => obj.into_value(),
             ObjectInner::ReportSettings(obj) => obj.into_value(),
             ObjectInner::Role(obj) => obj.into_value(),
@@ -5738,6 +5934,7 @@ impl From<ObjectType> for ObjectInner {
             ObjectType::CalendarAlarm => ObjectInner::CalendarAlarm(Default::default()),
             ObjectType::CalendarScheduling => ObjectInner::CalendarScheduling(Default::default()),
             ObjectType::Certificate => ObjectInner::Certificate(Default::default()),
+            ObjectType::MtaIpWarmUp => ObjectInner::MtaIpWarmUp(Default::default()),
             ObjectType::ClusterNode => ObjectInner::ClusterNode(Default::default()),
             ObjectType::ClusterRole => ObjectInner::ClusterRole(Default::default()),
             ObjectType::Coordinator => ObjectInner::Coordinator(Default::default()),
@@ -5799,6 +5996,7 @@ impl From<ObjectType> for ObjectInner {
             ObjectType::OAuthClient => ObjectInner::OAuthClient(Default::default()),
             ObjectType::OidcProvider => ObjectInner::OidcProvider(Default::default()),
             ObjectType::PublicKey => ObjectInner::PublicKey(Default::default()),
+            ObjectType::QuarantinedMessage => ObjectInner::QuarantinedMessage(Default::default()),
             ObjectType::QueuedMessage => ObjectInner::QueuedMessage(Default::default()),
             ObjectType::ReportSettings => ObjectInner::ReportSettings(Default::default()),
             ObjectType::Role => ObjectInner::Role(Default::default()),
@@ -6788,6 +6986,21 @@ impl From<Object> for MtaInboundThrottle {
     }
 }
 
+impl From<MtaIpWarmUp> for ObjectInner {
+    fn from(value: MtaIpWarmUp) -> Self {
+        ObjectInner::MtaIpWarmUp(value)
+    }
+}
+
+impl From<Object> for MtaIpWarmUp {
+    fn from(obj: Object) -> Self {
+        match obj.inner {
+            ObjectInner::MtaIpWarmUp(obj) => obj,
+            _ => unreachable!(),
+        }
+    }
+}
+
 impl From<MtaMilter> for ObjectInner {
     fn from(value: MtaMilter) -> Self {
         ObjectInner::MtaMilter(value)
@@ -7058,6 +7271,21 @@ impl From<Object> for PublicKey {
     }
 }
 
+impl From<QuarantinedMessage> for ObjectInner {
+    fn from(value: QuarantinedMessage) -> Self {
+        ObjectInner::QuarantinedMessage(value)
+    }
+}
+
+impl From<Object> for QuarantinedMessage {
+    fn from(obj: Object) -> Self {
+        match obj.inner {
+            ObjectInner::QuarantinedMessage(obj) => obj,
+            _ => unreachable!(),
+        }
+    }
+}
+
 impl From<QueuedMessage> for ObjectInner {
     fn from(value: QueuedMessage) -> Self {
         ObjectInner::QueuedMessage(value)
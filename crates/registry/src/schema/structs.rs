@@ -618,6 +618,8 @@ pub struct Cache {
     pub dkim_signatures: u64,
     #[serde(rename = "negativeTtl")]
     pub negative_ttl: Duration,
+    #[serde(rename = "spamModels")]
+    pub spam_models: u64,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -882,6 +884,12 @@ pub struct DataRetention {
     pub hold_metrics_for: Option<Duration>,
     #[serde(rename = "metricsCollectionInterval")]
     pub metrics_collection_interval: Cron,
+    #[serde(rename = "holdQuarantineFor")]
+    pub hold_quarantine_for: Duration,
+    #[serde(rename = "sendQuarantineDigest")]
+    pub send_quarantine_digest: bool,
+    #[serde(rename = "quarantineDigestSchedule")]
+    pub quarantine_digest_schedule: Cron,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -1676,6 +1684,8 @@ pub struct Domain {
     pub allow_relaying: bool,
     #[serde(rename = "reportAddressUri")]
     pub report_address_uri: Option<String>,
+    #[serde(rename = "locale")]
+    pub locale: Locale,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -1741,6 +1751,8 @@ pub struct Email {
     pub max_masked_addresses: Option<u64>,
     #[serde(rename = "maxPublicKeys")]
     pub max_public_keys: Option<u64>,
+    #[serde(rename = "quotaWarnThresholds")]
+    pub quota_warn_thresholds: Map<u64>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -1922,6 +1934,10 @@ pub struct Http {
     pub response_headers: VecMap<String, String>,
     #[serde(rename = "useXForwarded")]
     pub use_x_forwarded: bool,
+    #[serde(rename = "enableCompression")]
+    pub enable_compression: bool,
+    #[serde(rename = "compressionMinSize")]
+    pub compression_min_size: u64,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -2416,6 +2432,14 @@ pub struct MtaConnectionIpHost {
     pub ehlo_hostname: Option<String>,
     #[serde(rename = "sourceIp")]
     pub source_ip: IpAddr,
+    #[serde(rename = "warmUpStart")]
+    pub warm_up_start: Option<UTCDateTime>,
+    #[serde(rename = "warmUpDays")]
+    pub warm_up_days: u64,
+    #[serde(rename = "warmUpInitialVolume")]
+    pub warm_up_initial_volume: u64,
+    #[serde(rename = "warmUpTargetVolume")]
+    pub warm_up_target_volume: u64,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -2441,6 +2465,12 @@ pub struct MtaConnectionStrategy {
     pub mail_from_timeout: Duration,
     #[serde(rename = "rcptToTimeout")]
     pub rcpt_to_timeout: Duration,
+    #[serde(rename = "reuseMaxIdle")]
+    pub reuse_max_idle: u64,
+    #[serde(rename = "reuseIdleTimeout")]
+    pub reuse_idle_timeout: Duration,
+    #[serde(rename = "reuseMaxMessages")]
+    pub reuse_max_messages: u64,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -2576,6 +2606,27 @@ pub struct MtaInboundThrottle {
     pub rate: Rate,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MtaIpWarmUp {
+    #[serde(rename = "sourceIp")]
+    pub source_ip: IpAddr,
+    #[serde(rename = "connectionStrategyId")]
+    pub connection_strategy_id: Id,
+    #[serde(rename = "status")]
+    pub status: MtaIpWarmUpStatus,
+    #[serde(rename = "warmUpStart")]
+    pub warm_up_start: UTCDateTime,
+    #[serde(rename = "day")]
+    pub day: u64,
+    #[serde(rename = "warmUpDays")]
+    pub warm_up_days: u64,
+    #[serde(rename = "dailyLimit")]
+    pub daily_limit: u64,
+    #[serde(rename = "sentToday")]
+    pub sent_today: u64,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(default)]
 pub struct MtaMilter {
@@ -2654,6 +2705,23 @@ pub struct MtaQueueQuota {
     pub size: Option<u64>,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MtaRelayHost {
+    #[serde(rename = "address")]
+    pub address: String,
+    #[serde(rename = "port")]
+    pub port: u64,
+    #[serde(rename = "priority")]
+    pub priority: u64,
+    #[serde(rename = "authUsername")]
+    pub auth_username: Option<String>,
+    #[serde(rename = "authSecret")]
+    pub auth_secret: SecretKeyOptional,
+    #[serde(rename = "weight")]
+    pub weight: u64,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(tag = "@type")]
 pub enum MtaRoute {
@@ -2707,6 +2775,10 @@ pub struct MtaRouteRelay {
     pub name: String,
     #[serde(rename = "description")]
     pub description: Option<String>,
+    #[serde(rename = "hosts")]
+    pub hosts: List<MtaRelayHost>,
+    #[serde(rename = "hostCooldown")]
+    pub host_cooldown: Duration,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -3141,6 +3213,10 @@ pub struct PublicKey {
     pub expires_at: Option<UTCDateTime>,
     #[serde(rename = "emailAddresses")]
     pub email_addresses: Map<String>,
+    #[serde(rename = "publishWkd")]
+    pub publish_wkd: bool,
+    #[serde(rename = "autocrypt")]
+    pub autocrypt: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -3179,6 +3255,31 @@ pub struct QueueExpiryTtl {
     pub expires_at: UTCDateTime,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct QuarantinedMessage {
+    #[serde(rename = "from")]
+    pub from: String,
+    #[serde(rename = "recipients")]
+    pub recipients: Map<String>,
+    #[serde(rename = "subject")]
+    pub subject: String,
+    #[serde(rename = "reason")]
+    pub reason: String,
+    #[serde(rename = "size")]
+    pub size: u64,
+    #[serde(rename = "accountId")]
+    pub account_id: Option<Id>,
+    #[serde(rename = "receivedAt")]
+    pub received_at: UTCDateTime,
+    #[serde(rename = "expiresAt")]
+    pub expires_at: UTCDateTime,
+    #[serde(rename = "blobId")]
+    pub blob_id: BlobId,
+    #[serde(rename = "preview")]
+    pub preview: String,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(default)]
 pub struct QueuedMessage {
@@ -3454,6 +3555,12 @@ pub struct Search {
     pub index_telemetry: bool,
     #[serde(rename = "indexTracingFields")]
     pub index_tracing_fields: Map<SearchTracingField>,
+    #[serde(rename = "extractPdfMaxSize")]
+    pub extract_pdf_max_size: u64,
+    #[serde(rename = "extractOfficeMaxSize")]
+    pub extract_office_max_size: u64,
+    #[serde(rename = "extractArchiveMaxSize")]
+    pub extract_archive_max_size: u64,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -3590,6 +3697,22 @@ pub struct SenderAuth {
     pub dmarc_verify: Expression,
     #[serde(rename = "reverseIpVerify")]
     pub reverse_ip_verify: Expression,
+    #[serde(rename = "srsDomain")]
+    pub srs_domain: Expression,
+    #[serde(rename = "srsSigningKey")]
+    pub srs_signing_key: SecretKeyOptional,
+    #[serde(rename = "srsPreviousKey")]
+    pub srs_previous_key: SecretKeyOptional,
+    #[serde(rename = "srsMaxAge")]
+    pub srs_max_age: Duration,
+    #[serde(rename = "bimiVerify")]
+    pub bimi_verify: bool,
+    #[serde(rename = "bimiRequireVmc")]
+    pub bimi_require_vmc: bool,
+    #[serde(rename = "bimiMaxSize")]
+    pub bimi_max_size: u64,
+    #[serde(rename = "bimiTrustAnchors")]
+    pub bimi_trust_anchors: Option<String>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -3734,6 +3857,10 @@ pub struct SieveUserInterpreter {
     pub max_var_size: u64,
     #[serde(rename = "maxScripts")]
     pub max_scripts: Option<u64>,
+    #[serde(rename = "rateLimitNotify")]
+    pub rate_limit_notify: Option<Rate>,
+    #[serde(rename = "allowedExtLists")]
+    pub allowed_ext_lists: Map<String>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -3772,6 +3899,14 @@ pub struct SpamClassifier {
     pub train_frequency: Option<Duration>,
     #[serde(rename = "learnHamFromReply")]
     pub learn_ham_from_reply: bool,
+    #[serde(rename = "accountModel")]
+    pub account_model: bool,
+    #[serde(rename = "accountModelWeight")]
+    pub account_model_weight: Float,
+    #[serde(rename = "accountModelMinSamples")]
+    pub account_model_min_samples: u64,
+    #[serde(rename = "accountModelMaxAge")]
+    pub account_model_max_age: Duration,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -4180,6 +4315,7 @@ pub enum SpamTag {
     Score(SpamTagScore),
     Discard(SpamTagAction),
     Reject(SpamTagAction),
+    Quarantine(SpamTagAction),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -5065,6 +5201,18 @@ pub struct UserAccount {
     pub time_zone: Option<TimeZone>,
     #[serde(rename = "encryptionAtRest")]
     pub encryption_at_rest: EncryptionAtRest,
+    #[serde(rename = "principalKind")]
+    pub principal_kind: PrincipalKind,
+    #[serde(rename = "bookingPolicy")]
+    pub booking_policy: BookingPolicy,
+    #[serde(rename = "bookingMaxDuration")]
+    pub booking_max_duration: Option<Duration>,
+    #[serde(rename = "bookingWindow")]
+    pub booking_window: Option<Duration>,
+    #[serde(rename = "bookingAllowedRequesters")]
+    pub booking_allowed_requesters: Map<String>,
+    #[serde(rename = "bookingDelegateIds")]
+    pub booking_delegate_ids: Map<Id>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -4283,6 +4283,10 @@ impl ObjectImpl for Cache {
         if *value < 2048 {
             errors.push(ValidationError::min_value(Property::DkimSignatures, 2048));
         }
+        let value = &self.spam_models;
+        if *value < 2048 {
+            errors.push(ValidationError::min_value(Property::SpamModels, 2048));
+        }
         errors.len() == neb
     }
 
@@ -4317,6 +4321,7 @@ impl Pickle for Cache {
         self.mailing_lists.pickle(out);
         self.dkim_signatures.pickle(out);
         self.negative_ttl.pickle(out);
+        self.spam_models.pickle(out);
     }
 
     fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
@@ -4347,6 +4352,7 @@ impl Pickle for Cache {
         this.mailing_lists = Pickle::unpickle(stream)?;
         this.dkim_signatures = Pickle::unpickle(stream)?;
         this.negative_ttl = Pickle::unpickle(stream)?;
+        this.spam_models = Pickle::unpickle(stream)?;
         Some(this)
     }
 }
@@ -4380,13 +4386,14 @@ impl Default for Cache {
             mailing_lists: 2097152,
             dkim_signatures: 10485760,
             negative_ttl: Duration::from_millis(3600000),
+            spam_models: 20971520,
         }
     }
 }
 
 impl IntoValue for Cache {
     fn into_value(self) -> JmapValue<'static> {
-        let mut map = jmap_tools::Map::with_capacity(28);
+        let mut map = jmap_tools::Map::with_capacity(29);
         map.insert_unchecked(Property::AccessTokens, self.access_tokens.into_value());
         map.insert_unchecked(Property::Contacts, self.contacts.into_value());
         map.insert_unchecked(Property::DnsIpv4, self.dns_ipv4.into_value());
@@ -4419,6 +4426,7 @@ impl IntoValue for Cache {
         map.insert_unchecked(Property::MailingLists, self.mailing_lists.into_value());
         map.insert_unchecked(Property::DkimSignatures, self.dkim_signatures.into_value());
         map.insert_unchecked(Property::NegativeTtl, self.negative_ttl.into_value());
+        map.insert_unchecked(Property::SpamModels, self.spam_models.into_value());
         JmapValue::Object(map)
     }
 }
@@ -4458,6 +4466,7 @@ impl RegistryJsonPropertyPatch for Cache {
             Some(Property::MailingLists) => self.mailing_lists.patch(pointer, value),
             Some(Property::DkimSignatures) => self.dkim_signatures.patch(pointer, value),
             Some(Property::NegativeTtl) => self.negative_ttl.patch(pointer, value),
+            Some(Property::SpamModels) => self.spam_models.patch(pointer, value),
             Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
                 property: Property::Type,
                 value,
@@ -6489,6 +6498,8 @@ impl ObjectImpl for DataRetention {
         value.validate(errors);
         let value = &self.metrics_collection_interval;
         value.validate(errors);
+        let value = &self.quarantine_digest_schedule;
+        value.validate(errors);
         errors.len() == neb
     }
 
@@ -6511,6 +6522,9 @@ impl Pickle for DataRetention {
         self.hold_traces_for.pickle(out);
         self.hold_metrics_for.pickle(out);
         self.metrics_collection_interval.pickle(out);
+        self.hold_quarantine_for.pickle(out);
+        self.send_quarantine_digest.pickle(out);
+        self.quarantine_digest_schedule.pickle(out);
     }
 
     fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
@@ -6529,6 +6543,9 @@ impl Pickle for DataRetention {
         this.hold_traces_for = Pickle::unpickle(stream)?;
         this.hold_metrics_for = Pickle::unpickle(stream)?;
         this.metrics_collection_interval = Pickle::unpickle(stream)?;
+        this.hold_quarantine_for = Pickle::unpickle(stream)?;
+        this.send_quarantine_digest = Pickle::unpickle(stream)?;
+        this.quarantine_digest_schedule = Pickle::unpickle(stream)?;
         Some(this)
     }
 }
@@ -6559,13 +6576,19 @@ impl Default for DataRetention {
             hold_traces_for: Some(Duration::from_millis(2592000000)),
             hold_metrics_for: Some(Duration::from_millis(7776000000)),
             metrics_collection_interval: Cron::Hourly(CronHourly { minute: 0u64 }),
+            hold_quarantine_for: Duration::from_millis(2592000000),
+            send_quarantine_digest: true,
+            quarantine_digest_schedule: Cron::Daily(CronDaily {
+                hour: 9u64,
+                minute: 0u64,
+            }),
         }
     }
 }
 
 impl IntoValue for DataRetention {
     fn into_value(self) -> JmapValue<'static> {
-        let mut map = jmap_tools::Map::with_capacity(16);
+        let mut map = jmap_tools::Map::with_capacity(19);
         map.insert_unchecked(
             Property::ExpungeTrashAfter,
             self.expunge_trash_after.into_value(),
@@ -6616,6 +6639,18 @@ impl IntoValue for DataRetention {
             Property::MetricsCollectionInterval,
             self.metrics_collection_interval.into_value(),
         );
+        map.insert_unchecked(
+            Property::HoldQuarantineFor,
+            self.hold_quarantine_for.into_value(),
+        );
+        map.insert_unchecked(
+            Property::SendQuarantineDigest,
+            self.send_quarantine_digest.into_value(),
+        );
+        map.insert_unchecked(
+            Property::QuarantineDigestSchedule,
+            self.quarantine_digest_schedule.into_value(),
+        );
         JmapValue::Object(map)
     }
 }
@@ -6653,6 +6688,13 @@ impl RegistryJsonPropertyPatch for DataRetention {
             Some(Property::MetricsCollectionInterval) => {
                 self.metrics_collection_interval.patch(pointer, value)
             }
+            Some(Property::HoldQuarantineFor) => self.hold_quarantine_for.patch(pointer, value),
+            Some(Property::SendQuarantineDigest) => {
+                self.send_quarantine_digest.patch(pointer, value)
+            }
+            Some(Property::QuarantineDigestSchedule) => {
+                self.quarantine_digest_schedule.patch(pointer, value)
+            }
             Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
                 property: Property::Type,
                 value,
@@ -12333,6 +12375,7 @@ impl Pickle for Domain {
         self.sub_addressing.pickle(out);
         self.allow_relaying.pickle(out);
         self.report_address_uri.pickle(out);
+        self.locale.pickle(out);
     }
 
     fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
@@ -12352,6 +12395,7 @@ impl Pickle for Domain {
         this.sub_addressing = Pickle::unpickle(stream)?;
         this.allow_relaying = Pickle::unpickle(stream)?;
         this.report_address_uri = Pickle::unpickle(stream)?;
+        this.locale = Pickle::unpickle(stream)?;
         Some(this)
     }
 }
@@ -12374,13 +12418,14 @@ impl Default for Domain {
             sub_addressing: Default::default(),
             allow_relaying: false,
             report_address_uri: Some("mailto:postmaster".to_string()),
+            locale: Locale::EnUS,
         }
     }
 }
 
 impl IntoValue for Domain {
     fn into_value(self) -> JmapValue<'static> {
-        let mut map = jmap_tools::Map::with_capacity(17);
+        let mut map = jmap_tools::Map::with_capacity(18);
         map.insert_unchecked(Property::Name, self.name.into_value());
         map.insert_unchecked(Property::Aliases, self.aliases.into_value());
         map.insert_unchecked(Property::IsEnabled, self.is_enabled.into_value());
@@ -12405,6 +12450,7 @@ impl IntoValue for Domain {
             Property::ReportAddressUri,
             self.report_address_uri.into_value(),
         );
+        map.insert_unchecked(Property::Locale, self.locale.into_value());
         JmapValue::Object(map)
     }
 }
@@ -12444,6 +12490,7 @@ impl RegistryJsonPropertyPatch for Domain {
             Some(Property::ReportAddressUri) => self
                 .report_address_uri
                 .patch(pointer.with_validators(&[StringValidator::Trim]), value),
+            Some(Property::Locale) => self.locale.patch(pointer, value),
             Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
                 property: Property::Type,
                 value,
@@ -12762,6 +12809,17 @@ impl ObjectImpl for Email {
                 errors.push(ValidationError::min_value(Property::MaxPublicKeys, 1));
             }
         }
+        for value in self.quota_warn_thresholds.iter() {
+            if *value < 1 {
+                errors.push(ValidationError::min_value(Property::QuotaWarnThresholds, 1));
+            }
+            if *value > 99 {
+                errors.push(ValidationError::max_value(
+                    Property::QuotaWarnThresholds,
+                    99,
+                ));
+            }
+        }
         errors.len() == neb
     }
 
@@ -12784,6 +12842,7 @@ impl Pickle for Email {
         self.max_mailboxes.pickle(out);
         self.max_masked_addresses.pickle(out);
         self.max_public_keys.pickle(out);
+        self.quota_warn_thresholds.pickle(out);
     }
 
     fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
@@ -12802,6 +12861,7 @@ impl Pickle for Email {
         this.max_mailboxes = Pickle::unpickle(stream)?;
         this.max_masked_addresses = Pickle::unpickle(stream)?;
         this.max_public_keys = Pickle::unpickle(stream)?;
+        this.quota_warn_thresholds = Pickle::unpickle(stream)?;
         Some(this)
     }
 }
@@ -12823,13 +12883,14 @@ impl Default for Email {
             max_mailboxes: Some(250u64),
             max_masked_addresses: Some(5u64),
             max_public_keys: Some(5u64),
+            quota_warn_thresholds: Default::default(),
         }
     }
 }
 
 impl IntoValue for Email {
     fn into_value(self) -> JmapValue<'static> {
-        let mut map = jmap_tools::Map::with_capacity(16);
+        let mut map = jmap_tools::Map::with_capacity(17);
         map.insert_unchecked(
             Property::MaxAttachmentSize,
             self.max_attachment_size.into_value(),
@@ -12862,6 +12923,10 @@ impl IntoValue for Email {
             self.max_masked_addresses.into_value(),
         );
         map.insert_unchecked(Property::MaxPublicKeys, self.max_public_keys.into_value());
+        map.insert_unchecked(
+            Property::QuotaWarnThresholds,
+            self.quota_warn_thresholds.into_value(),
+        );
         JmapValue::Object(map)
     }
 }
@@ -12891,6 +12956,7 @@ impl RegistryJsonPropertyPatch for Email {
             Some(Property::MaxMailboxes) => self.max_mailboxes.patch(pointer, value),
             Some(Property::MaxMaskedAddresses) => self.max_masked_addresses.patch(pointer, value),
             Some(Property::MaxPublicKeys) => self.max_public_keys.patch(pointer, value),
+            Some(Property::QuotaWarnThresholds) => self.quota_warn_thresholds.patch(pointer, value),
             Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
                 property: Property::Type,
                 value,
@@ -14106,6 +14172,8 @@ impl Pickle for Http {
         self.use_permissive_cors.pickle(out);
         self.response_headers.pickle(out);
         self.use_x_forwarded.pickle(out);
+        self.enable_compression.pickle(out);
+        self.compression_min_size.pickle(out);
     }
 
     fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
@@ -14117,6 +14185,8 @@ impl Pickle for Http {
         this.use_permissive_cors = Pickle::unpickle(stream)?;
         this.response_headers = Pickle::unpickle(stream)?;
         this.use_x_forwarded = Pickle::unpickle(stream)?;
+        this.enable_compression = Pickle::unpickle(stream)?;
+        this.compression_min_size = Pickle::unpickle(stream)?;
         Some(this)
     }
 }
@@ -14140,13 +14210,15 @@ impl Default for Http {
             use_permissive_cors: false,
             response_headers: Default::default(),
             use_x_forwarded: false,
+            enable_compression: true,
+            compression_min_size: 1024,
         }
     }
 }
 
 impl IntoValue for Http {
     fn into_value(self) -> JmapValue<'static> {
-        let mut map = jmap_tools::Map::with_capacity(9);
+        let mut map = jmap_tools::Map::with_capacity(11);
         map.insert_unchecked(
             Property::RateLimitAuthenticated,
             self.rate_limit_authenticated.into_value(),
@@ -14169,6 +14241,14 @@ impl IntoValue for Http {
             self.response_headers.into_value(),
         );
         map.insert_unchecked(Property::UseXForwarded, self.use_x_forwarded.into_value());
+        map.insert_unchecked(
+            Property::EnableCompression,
+            self.enable_compression.into_value(),
+        );
+        map.insert_unchecked(
+            Property::CompressionMinSize,
+            self.compression_min_size.into_value(),
+        );
         JmapValue::Object(map)
     }
 }
@@ -14191,6 +14271,8 @@ impl RegistryJsonPropertyPatch for Http {
                 .response_headers
                 .patch(pointer.with_validators(&[StringValidator::Trim]), value),
             Some(Property::UseXForwarded) => self.use_x_forwarded.patch(pointer, value),
+            Some(Property::EnableCompression) => self.enable_compression.patch(pointer, value),
+            Some(Property::CompressionMinSize) => self.compression_min_size.patch(pointer, value),
             Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
                 property: Property::Type,
                 value,
@@ -17718,6 +17800,26 @@ impl MtaConnectionIpHost {
         if !value.is_valid() {
             errors.push(ValidationError::invalid(Property::SourceIp, value));
         }
+        if let Some(value) = &self.warm_up_start {
+            if !value.is_valid() {
+                errors.push(ValidationError::invalid(Property::WarmUpStart, value));
+            }
+        }
+        let value = &self.warm_up_days;
+        if *value > 365 {
+            errors.push(ValidationError::max_value(Property::WarmUpDays, 365));
+        }
+        if *value < 1 {
+            errors.push(ValidationError::min_value(Property::WarmUpDays, 1));
+        }
+        let value = &self.warm_up_initial_volume;
+        if *value < 1 {
+            errors.push(ValidationError::min_value(Property::WarmUpInitialVolume, 1));
+        }
+        let value = &self.warm_up_target_volume;
+        if *value < 1 {
+            errors.push(ValidationError::min_value(Property::WarmUpTargetVolume, 1));
+        }
         errors.len() == neb
     }
 }
@@ -17726,12 +17828,20 @@ impl Pickle for MtaConnectionIpHost {
     fn pickle(&self, out: &mut Vec<u8>) {
         self.ehlo_hostname.pickle(out);
         self.source_ip.pickle(out);
+        self.warm_up_start.pickle(out);
+        self.warm_up_days.pickle(out);
+        self.warm_up_initial_volume.pickle(out);
+        self.warm_up_target_volume.pickle(out);
     }
 
     fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
         let mut this = Self::default();
         this.ehlo_hostname = Pickle::unpickle(stream)?;
         this.source_ip = Pickle::unpickle(stream)?;
+        this.warm_up_start = Pickle::unpickle(stream)?;
+        this.warm_up_days = Pickle::unpickle(stream)?;
+        this.warm_up_initial_volume = Pickle::unpickle(stream)?;
+        this.warm_up_target_volume = Pickle::unpickle(stream)?;
         Some(this)
     }
 }
@@ -17741,15 +17851,29 @@ impl Default for MtaConnectionIpHost {
         Self {
             ehlo_hostname: Default::default(),
             source_ip: Default::default(),
+            warm_up_start: Default::default(),
+            warm_up_days: 30u64,
+            warm_up_initial_volume: 50u64,
+            warm_up_target_volume: 50000u64,
         }
     }
 }
 
 impl IntoValue for MtaConnectionIpHost {
     fn into_value(self) -> JmapValue<'static> {
-        let mut map = jmap_tools::Map::with_capacity(4);
+        let mut map = jmap_tools::Map::with_capacity(8);
         map.insert_unchecked(Property::EhloHostname, self.ehlo_hostname.into_value());
         map.insert_unchecked(Property::SourceIp, self.source_ip.into_value());
+        map.insert_unchecked(Property::WarmUpStart, self.warm_up_start.into_value());
+        map.insert_unchecked(Property::WarmUpDays, self.warm_up_days.into_value());
+        map.insert_unchecked(
+            Property::WarmUpInitialVolume,
+            self.warm_up_initial_volume.into_value(),
+        );
+        map.insert_unchecked(
+            Property::WarmUpTargetVolume,
+            self.warm_up_target_volume.into_value(),
+        );
         JmapValue::Object(map)
     }
 }
@@ -17765,6 +17889,12 @@ impl RegistryJsonPropertyPatch for MtaConnectionIpHost {
                 .ehlo_hostname
                 .patch(pointer.with_validators(&[StringValidator::Hostname]), value),
             Some(Property::SourceIp) => self.source_ip.patch(pointer, value),
+            Some(Property::WarmUpStart) => self.warm_up_start.patch(pointer, value),
+            Some(Property::WarmUpDays) => self.warm_up_days.patch(pointer, value),
+            Some(Property::WarmUpInitialVolume) => {
+                self.warm_up_initial_volume.patch(pointer, value)
+            }
+            Some(Property::WarmUpTargetVolume) => self.warm_up_target_volume.patch(pointer, value),
             Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
                 property: Property::Type,
                 value,
@@ -17799,6 +17929,14 @@ impl ObjectImpl for MtaConnectionStrategy {
         for value in value.values() {
             value.validate(errors);
         }
+        let value = &self.reuse_max_idle;
+        if *value > 1024 {
+            errors.push(ValidationError::max_value(Property::ReuseMaxIdle, 1024));
+        }
+        let value = &self.reuse_max_messages;
+        if *value < 1 {
+            errors.push(ValidationError::min_value(Property::ReuseMaxMessages, 1));
+        }
         errors.len() == neb
     }
 
@@ -17819,6 +17957,9 @@ impl Pickle for MtaConnectionStrategy {
         self.greeting_timeout.pickle(out);
         self.mail_from_timeout.pickle(out);
         self.rcpt_to_timeout.pickle(out);
+        self.reuse_max_idle.pickle(out);
+        self.reuse_idle_timeout.pickle(out);
+        self.reuse_max_messages.pickle(out);
     }
 
     fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
@@ -17833,6 +17974,9 @@ impl Pickle for MtaConnectionStrategy {
         this.greeting_timeout = Pickle::unpickle(stream)?;
         this.mail_from_timeout = Pickle::unpickle(stream)?;
         this.rcpt_to_timeout = Pickle::unpickle(stream)?;
+        this.reuse_max_idle = Pickle::unpickle(stream)?;
+        this.reuse_idle_timeout = Pickle::unpickle(stream)?;
+        this.reuse_max_messages = Pickle::unpickle(stream)?;
         Some(this)
     }
 }
@@ -17850,13 +17994,16 @@ impl Default for MtaConnectionStrategy {
             greeting_timeout: Duration::from_millis(300000),
             mail_from_timeout: Duration::from_millis(300000),
             rcpt_to_timeout: Duration::from_millis(300000),
+            reuse_max_idle: 0u64,
+            reuse_idle_timeout: Duration::from_millis(30000),
+            reuse_max_messages: 100u64,
         }
     }
 }
 
 impl IntoValue for MtaConnectionStrategy {
     fn into_value(self) -> JmapValue<'static> {
-        let mut map = jmap_tools::Map::with_capacity(12);
+        let mut map = jmap_tools::Map::with_capacity(15);
         map.insert_unchecked(Property::Name, self.name.into_value());
         map.insert_unchecked(Property::Description, self.description.into_value());
         map.insert_unchecked(Property::EhloHostname, self.ehlo_hostname.into_value());
@@ -17873,6 +18020,15 @@ impl IntoValue for MtaConnectionStrategy {
             self.mail_from_timeout.into_value(),
         );
         map.insert_unchecked(Property::RcptToTimeout, self.rcpt_to_timeout.into_value());
+        map.insert_unchecked(Property::ReuseMaxIdle, self.reuse_max_idle.into_value());
+        map.insert_unchecked(
+            Property::ReuseIdleTimeout,
+            self.reuse_idle_timeout.into_value(),
+        );
+        map.insert_unchecked(
+            Property::ReuseMaxMessages,
+            self.reuse_max_messages.into_value(),
+        );
         JmapValue::Object(map)
     }
 }
@@ -17896,6 +18052,9 @@ impl RegistryJsonPropertyPatch for MtaConnectionStrategy {
             Some(Property::GreetingTimeout) => self.greeting_timeout.patch(pointer, value),
             Some(Property::MailFromTimeout) => self.mail_from_timeout.patch(pointer, value),
             Some(Property::RcptToTimeout) => self.rcpt_to_timeout.patch(pointer, value),
+            Some(Property::ReuseMaxIdle) => self.reuse_max_idle.patch(pointer, value),
+            Some(Property::ReuseIdleTimeout) => self.reuse_idle_timeout.patch(pointer, value),
+            Some(Property::ReuseMaxMessages) => self.reuse_max_messages.patch(pointer, value),
             Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
                 property: Property::Type,
                 value,
@@ -19133,6 +19292,112 @@ impl RegistryJsonPropertyPatch for MtaInboundThrottle {
     }
 }
 
+impl ObjectImpl for MtaIpWarmUp {
+    const FLAGS: u64 = 0;
+    const VERSION: u8 = 0;
+    const OBJECT: ObjectType = ObjectType::MtaIpWarmUp;
+
+    fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
+        let neb = errors.len();
+        let value = &self.source_ip;
+        if !value.is_valid() {
+            errors.push(ValidationError::invalid(Property::SourceIp, value));
+        }
+        let value = &self.warm_up_start;
+        if !value.is_valid() {
+            errors.push(ValidationError::invalid(Property::WarmUpStart, value));
+        }
+        errors.len() == neb
+    }
+
+    fn index<'x>(&'x self, _: &mut IndexBuilder<'x>) {}
+}
+
+impl Pickle for MtaIpWarmUp {
+    fn pickle(&self, out: &mut Vec<u8>) {
+        self.source_ip.pickle(out);
+        self.connection_strategy_id.pickle(out);
+        self.status.pickle(out);
+        self.warm_up_start.pickle(out);
+        self.day.pickle(out);
+        self.warm_up_days.pickle(out);
+        self.daily_limit.pickle(out);
+        self.sent_today.pickle(out);
+    }
+
+    fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
+        let mut this = Self::default();
+        this.source_ip = Pickle::unpickle(stream)?;
+        this.connection_strategy_id = Pickle::unpickle(stream)?;
+        this.status = Pickle::unpickle(stream)?;
+        this.warm_up_start = Pickle::unpickle(stream)?;
+        this.day = Pickle::unpickle(stream)?;
+        this.warm_up_days = Pickle::unpickle(stream)?;
+        this.daily_limit = Pickle::unpickle(stream)?;
+        this.sent_today = Pickle::unpickle(stream)?;
+        Some(this)
+    }
+}
+
+impl Default for MtaIpWarmUp {
+    fn default() -> Self {
+        Self {
+            source_ip: Default::default(),
+            connection_strategy_id: Default::default(),
+            status: Default::default(),
+            warm_up_start: Default::default(),
+            day: 0u64,
+            warm_up_days: 0u64,
+            daily_limit: 0u64,
+            sent_today: 0u64,
+        }
+    }
+}
+
+impl IntoValue for MtaIpWarmUp {
+    fn into_value(self) -> JmapValue<'static> {
+        let mut map = jmap_tools::Map::with_capacity(9);
+        map.insert_unchecked(Property::SourceIp, self.source_ip.into_value());
+        map.insert_unchecked(
+            Property::ConnectionStrategyId,
+            self.connection_strategy_id.into_value(),
+        );
+        map.insert_unchecked(Property::Status, self.status.into_value());
+        map.insert_unchecked(Property::WarmUpStart, self.warm_up_start.into_value());
+        map.insert_unchecked(Property::Day, self.day.into_value());
+        map.insert_unchecked(Property::WarmUpDays, self.warm_up_days.into_value());
+        map.insert_unchecked(Property::DailyLimit, self.daily_limit.into_value());
+        map.insert_unchecked(Property::SentToday, self.sent_today.into_value());
+        JmapValue::Object(map)
+    }
+}
+
+impl RegistryJsonPropertyPatch for MtaIpWarmUp {
+    fn patch_property<'x>(
+        &mut self,
+        mut pointer: JsonPointerPatch<'_>,
+        value: JmapValue<'x>,
+    ) -> PatchResult<'x> {
+        match pointer.next_property() {
+            Some(Property::SourceIp) => self.source_ip.patch(pointer, value),
+            Some(Property::ConnectionStrategyId) => {
+                self.connection_strategy_id.patch(pointer, value)
+            }
+            Some(Property::Status) => self.status.patch(pointer, value),
+            Some(Property::WarmUpStart) => self.warm_up_start.patch(pointer, value),
+            Some(Property::Day) => self.day.patch(pointer, value),
+            Some(Property::WarmUpDays) => self.warm_up_days.patch(pointer, value),
+            Some(Property::DailyLimit) => self.daily_limit.patch(pointer, value),
+            Some(Property::SentToday) => self.sent_today.patch(pointer, value),
+            Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
+                property: Property::Type,
+                value,
+            }),
+            _ => Err(PatchError::new(pointer, "Invalid property")),
+        }
+    }
+}
+
 impl ObjectImpl for MtaMilter {
     const FLAGS: u64 = 0;
     const VERSION: u8 = 0;
@@ -19728,6 +19993,114 @@ impl RegistryJsonPropertyPatch for MtaQueueQuota {
     }
 }
 
+impl MtaRelayHost {
+    fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
+        let neb = errors.len();
+        let value = &self.address;
+        if value.is_empty() {
+            errors.push(ValidationError::required(Property::Address));
+        }
+        let value = &self.port;
+        if *value > 65535 {
+            errors.push(ValidationError::max_value(Property::Port, 65535));
+        }
+        if *value < 1 {
+            errors.push(ValidationError::min_value(Property::Port, 1));
+        }
+        let value = &self.priority;
+        if *value > 65535 {
+            errors.push(ValidationError::max_value(Property::Priority, 65535));
+        }
+        if let Some(value) = &self.auth_username {
+            if value.is_empty() {
+                errors.push(ValidationError::required(Property::AuthUsername));
+            }
+        }
+        let value = &self.auth_secret;
+        value.validate(errors);
+        let value = &self.weight;
+        if *value > 65535 {
+            errors.push(ValidationError::max_value(Property::Weight, 65535));
+        }
+        if *value < 1 {
+            errors.push(ValidationError::min_value(Property::Weight, 1));
+        }
+        errors.len() == neb
+    }
+}
+
+impl Pickle for MtaRelayHost {
+    fn pickle(&self, out: &mut Vec<u8>) {
+        self.address.pickle(out);
+        self.port.pickle(out);
+        self.priority.pickle(out);
+        self.auth_username.pickle(out);
+        self.auth_secret.pickle(out);
+        self.weight.pickle(out);
+    }
+
+    fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
+        let mut this = Self::default();
+        this.address = Pickle::unpickle(stream)?;
+        this.port = Pickle::unpickle(stream)?;
+        this.priority = Pickle::unpickle(stream)?;
+        this.auth_username = Pickle::unpickle(stream)?;
+        this.auth_secret = Pickle::unpickle(stream)?;
+        this.weight = Pickle::unpickle(stream)?;
+        Some(this)
+    }
+}
+
+impl Default for MtaRelayHost {
+    fn default() -> Self {
+        Self {
+            address: Default::default(),
+            port: 25u64,
+            priority: 1u64,
+            auth_username: Default::default(),
+            auth_secret: Default::default(),
+            weight: 1u64,
+        }
+    }
+}
+
+impl IntoValue for MtaRelayHost {
+    fn into_value(self) -> JmapValue<'static> {
+        let mut map = jmap_tools::Map::with_capacity(6);
+        map.insert_unchecked(Property::Address, self.address.into_value());
+        map.insert_unchecked(Property::Port, self.port.into_value());
+        map.insert_unchecked(Property::Priority, self.priority.into_value());
+        map.insert_unchecked(Property::AuthUsername, self.auth_username.into_value());
+        map.insert_unchecked(Property::AuthSecret, self.auth_secret.into_value());
+        map.insert_unchecked(Property::Weight, self.weight.into_value());
+        JmapValue::Object(map)
+    }
+}
+
+impl RegistryJsonPropertyPatch for MtaRelayHost {
+    fn patch_property<'x>(
+        &mut self,
+        mut pointer: JsonPointerPatch<'_>,
+        value: JmapValue<'x>,
+    ) -> PatchResult<'x> {
+        match pointer.next_property() {
+            Some(Property::Address) => self
+                .address
+                .patch(pointer.with_validators(&[StringValidator::Hostname]), value),
+            Some(Property::Port) => self.port.patch(pointer, value),
+            Some(Property::Priority) => self.priority.patch(pointer, value),
+            Some(Property::AuthUsername) => self.auth_username.patch(pointer, value),
+            Some(Property::AuthSecret) => self.auth_secret.patch(pointer, value),
+            Some(Property::Weight) => self.weight.patch(pointer, value),
+            Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
+                property: Property::Type,
+                value,
+            }),
+            _ => Err(PatchError::new(pointer, "Invalid property")),
+        }
+    }
+}
+
 impl ObjectImpl for MtaRoute {
     const FLAGS: u64 = 0;
     const VERSION: u8 = 0;
@@ -20045,6 +20418,10 @@ impl MtaRouteRelay {
                 errors.push(ValidationError::required(Property::Description));
             }
         }
+        let value = &self.hosts;
+        for value in value.values() {
+            value.validate(errors);
+        }
         errors.len() == neb
     }
 
@@ -20064,6 +20441,8 @@ impl Pickle for MtaRouteRelay {
         self.implicit_tls.pickle(out);
         self.name.pickle(out);
         self.description.pickle(out);
+        self.hosts.pickle(out);
+        self.host_cooldown.pickle(out);
     }
 
     fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
@@ -20077,6 +20456,8 @@ impl Pickle for MtaRouteRelay {
         this.implicit_tls = Pickle::unpickle(stream)?;
         this.name = Pickle::unpickle(stream)?;
         this.description = Pickle::unpickle(stream)?;
+        this.hosts = Pickle::unpickle(stream)?;
+        this.host_cooldown = Pickle::unpickle(stream)?;
         Some(this)
     }
 }
@@ -20093,13 +20474,15 @@ impl Default for MtaRouteRelay {
             implicit_tls: false,
             name: Default::default(),
             description: Default::default(),
+            hosts: Default::default(),
+            host_cooldown: Duration::from_millis(300000),
         }
     }
 }
 
 impl IntoValue for MtaRouteRelay {
     fn into_value(self) -> JmapValue<'static> {
-        let mut map = jmap_tools::Map::with_capacity(11);
+        let mut map = jmap_tools::Map::with_capacity(13);
         map.insert_unchecked(Property::Address, self.address.into_value());
         map.insert_unchecked(Property::AuthSecret, self.auth_secret.into_value());
         map.insert_unchecked(Property::AuthUsername, self.auth_username.into_value());
@@ -20112,6 +20495,8 @@ impl IntoValue for MtaRouteRelay {
         map.insert_unchecked(Property::ImplicitTls, self.implicit_tls.into_value());
         map.insert_unchecked(Property::Name, self.name.into_value());
         map.insert_unchecked(Property::Description, self.description.into_value());
+        map.insert_unchecked(Property::Hosts, self.hosts.into_value());
+        map.insert_unchecked(Property::HostCooldown, self.host_cooldown.into_value());
         JmapValue::Object(map)
     }
 }
@@ -20134,6 +20519,8 @@ impl RegistryJsonPropertyPatch for MtaRouteRelay {
             Some(Property::ImplicitTls) => self.implicit_tls.patch(pointer, value),
             Some(Property::Name) => self.name.patch(pointer.assert_read_only()?, value),
             Some(Property::Description) => self.description.patch(pointer, value),
+            Some(Property::Hosts) => self.hosts.patch(pointer, value),
+            Some(Property::HostCooldown) => self.host_cooldown.patch(pointer, value),
             Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
                 property: Property::Type,
                 value,
@@ -23448,6 +23835,8 @@ impl Pickle for PublicKey {
         self.created_at.pickle(out);
         self.expires_at.pickle(out);
         self.email_addresses.pickle(out);
+        self.publish_wkd.pickle(out);
+        self.autocrypt.pickle(out);
     }
 
     fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
@@ -23458,6 +23847,8 @@ impl Pickle for PublicKey {
         this.created_at = Pickle::unpickle(stream)?;
         this.expires_at = Pickle::unpickle(stream)?;
         this.email_addresses = Pickle::unpickle(stream)?;
+        this.publish_wkd = Pickle::unpickle(stream)?;
+        this.autocrypt = Pickle::unpickle(stream)?;
         Some(this)
     }
 }
@@ -23471,19 +23862,23 @@ impl Default for PublicKey {
             created_at: Default::default(),
             expires_at: Default::default(),
             email_addresses: Default::default(),
+            publish_wkd: false,
+            autocrypt: false,
         }
     }
 }
 
 impl IntoValue for PublicKey {
     fn into_value(self) -> JmapValue<'static> {
-        let mut map = jmap_tools::Map::with_capacity(8);
+        let mut map = jmap_tools::Map::with_capacity(10);
         map.insert_unchecked(Property::AccountId, self.account_id.into_value());
         map.insert_unchecked(Property::Key, self.key.into_value());
         map.insert_unchecked(Property::Description, self.description.into_value());
         map.insert_unchecked(Property::CreatedAt, self.created_at.into_value());
         map.insert_unchecked(Property::ExpiresAt, self.expires_at.into_value());
         map.insert_unchecked(Property::EmailAddresses, self.email_addresses.into_value());
+        map.insert_unchecked(Property::PublishWkd, self.publish_wkd.into_value());
+        map.insert_unchecked(Property::Autocrypt, self.autocrypt.into_value());
         JmapValue::Object(map)
     }
 }
@@ -23507,6 +23902,8 @@ impl RegistryJsonPropertyPatch for PublicKey {
             Some(Property::EmailAddresses) => self
                 .email_addresses
                 .patch(pointer.with_validators(&[StringValidator::Email]), value),
+            Some(Property::PublishWkd) => self.publish_wkd.patch(pointer, value),
+            Some(Property::Autocrypt) => self.autocrypt.patch(pointer, value),
             Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
                 property: Property::Type,
                 value,
@@ -23874,6 +24271,144 @@ impl RegistryJsonPropertyPatch for QueueExpiryTtl {
     }
 }
 
+impl ObjectImpl for QuarantinedMessage {
+    const FLAGS: u64 = OBJ_FILTER_ACCOUNT;
+    const VERSION: u8 = 0;
+    const OBJECT: ObjectType = ObjectType::QuarantinedMessage;
+
+    fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
+        let neb = errors.len();
+        let value = &self.from;
+        if value.is_empty() {
+            errors.push(ValidationError::required(Property::From));
+        }
+        let value = &self.recipients;
+        for value in value.iter() {
+            if value.is_empty() {
+                errors.push(ValidationError::required(Property::Recipients));
+            }
+        }
+        let value = &self.blob_id;
+        if value.is_empty() {
+            errors.push(ValidationError::required(Property::BlobId));
+        }
+        if let Some(value) = &self.account_id {
+            if !value.is_valid() {
+                errors.push(ValidationError::required(Property::AccountId));
+            }
+        }
+        let value = &self.received_at;
+        if !value.is_valid() {
+            errors.push(ValidationError::invalid(Property::ReceivedAt, value));
+        }
+        let value = &self.expires_at;
+        if !value.is_valid() {
+            errors.push(ValidationError::invalid(Property::ExpiresAt, value));
+        }
+        errors.len() == neb
+    }
+
+    fn index<'x>(&'x self, i: &mut IndexBuilder<'x>) {
+        i.foreign_key(ObjectType::Account, self.account_id, None);
+        if let Some(value) = &self.account_id {
+            i.search(Property::AccountId, value);
+        }
+    }
+}
+
+impl Pickle for QuarantinedMessage {
+    fn pickle(&self, out: &mut Vec<u8>) {
+        self.from.pickle(out);
+        self.recipients.pickle(out);
+        self.subject.pickle(out);
+        self.reason.pickle(out);
+        self.size.pickle(out);
+        self.account_id.pickle(out);
+        self.received_at.pickle(out);
+        self.expires_at.pickle(out);
+        self.blob_id.pickle(out);
+        self.preview.pickle(out);
+    }
+
+    fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
+        let mut this = Self::default();
+        this.from = Pickle::unpickle(stream)?;
+        this.recipients = Pickle::unpickle(stream)?;
+        this.subject = Pickle::unpickle(stream)?;
+        this.reason = Pickle::unpickle(stream)?;
+        this.size = Pickle::unpickle(stream)?;
+        this.account_id = Pickle::unpickle(stream)?;
+        this.received_at = Pickle::unpickle(stream)?;
+        this.expires_at = Pickle::unpickle(stream)?;
+        this.blob_id = Pickle::unpickle(stream)?;
+        this.preview = Pickle::unpickle(stream)?;
+        Some(this)
+    }
+}
+
+impl Default for QuarantinedMessage {
+    fn default() -> Self {
+        Self {
+            from: Default::default(),
+            recipients: Default::default(),
+            subject: Default::default(),
+            reason: Default::default(),
+            size: 0u64,
+            account_id: Default::default(),
+            received_at: Default::default(),
+            expires_at: Default::default(),
+            blob_id: Default::default(),
+            preview: Default::default(),
+        }
+    }
+}
+
+impl IntoValue for QuarantinedMessage {
+    fn into_value(self) -> JmapValue<'static> {
+        let mut map = jmap_tools::Map::with_capacity(12);
+        map.insert_unchecked(Property::From, self.from.into_value());
+        map.insert_unchecked(Property::Recipients, self.recipients.into_value());
+        map.insert_unchecked(Property::Subject, self.subject.into_value());
+        map.insert_unchecked(Property::Reason, self.reason.into_value());
+        map.insert_unchecked(Property::Size, self.size.into_value());
+        map.insert_unchecked(Property::AccountId, self.account_id.into_value());
+        map.insert_unchecked(Property::ReceivedAt, self.received_at.into_value());
+        map.insert_unchecked(Property::ExpiresAt, self.expires_at.into_value());
+        map.insert_unchecked(Property::BlobId, self.blob_id.into_value());
+        map.insert_unchecked(Property::Preview, self.preview.into_value());
+        JmapValue::Object(map)
+    }
+}
+
+impl RegistryJsonPropertyPatch for QuarantinedMessage {
+    fn patch_property<'x>(
+        &mut self,
+        mut pointer: JsonPointerPatch<'_>,
+        value: JmapValue<'x>,
+    ) -> PatchResult<'x> {
+        match pointer.next_property() {
+            Some(Property::From) => pointer.assert_server_set(),
+            Some(Property::Recipients) => pointer.assert_server_set(),
+            Some(Property::Subject) => pointer.assert_server_set(),
+            Some(Property::Reason) => pointer.assert_server_set(),
+            Some(Property::Size) => pointer.assert_server_set(),
+            Some(Property::AccountId) => self
+                .account_id
+                .patch(pointer.assert_read_only()?.assert_can_set_account()?, value),
+            Some(Property::ReceivedAt) => pointer.assert_server_set(),
+            Some(Property::ExpiresAt) => pointer.assert_server_set(),
+            Some(Property::BlobId) => self.blob_id.patch(pointer.assert_read_only()?, value),
+            Some(property @ Property::Status) => Ok(MaybeUnpatched::Unpatched { property, value }),
+            Some(Property::Preview) => pointer.assert_server_set(),
+            Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
+                property: Property::Type,
+                value,
+            }),
+            _ => Err(PatchError::new(pointer, "Invalid property")),
+        }
+    }
+}
+
 impl ObjectImpl for QueuedMessage {
     const FLAGS: u64 = 0;
     const VERSION: u8 = 0;
@@ -25929,6 +26464,9 @@ impl Pickle for Search {
         self.index_email_fields.pickle(out);
         self.index_telemetry.pickle(out);
         self.index_tracing_fields.pickle(out);
+        self.extract_pdf_max_size.pickle(out);
+        self.extract_office_max_size.pickle(out);
+        self.extract_archive_max_size.pickle(out);
     }
 
     fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
@@ -25944,6 +26482,9 @@ impl Pickle for Search {
         this.index_email_fields = Pickle::unpickle(stream)?;
         this.index_telemetry = Pickle::unpickle(stream)?;
         this.index_tracing_fields = Pickle::unpickle(stream)?;
+        this.extract_pdf_max_size = Pickle::unpickle(stream)?;
+        this.extract_office_max_size = Pickle::unpickle(stream)?;
+        this.extract_archive_max_size = Pickle::unpickle(stream)?;
         Some(this)
     }
 }
@@ -25963,6 +26504,9 @@ impl Default for Search {
                 SearchCalendarField::Attendee,
                 SearchCalendarField::Start,
                 SearchCalendarField::Uid,
+                SearchCalendarField::Due,
+                SearchCalendarField::Completed,
+                SearchCalendarField::PercentComplete,
             ]),
             index_contacts: true,
             index_contact_fields: Map::new(vec![
@@ -25998,13 +26542,16 @@ impl Default for Search {
                 SearchTracingField::QueueId,
                 SearchTracingField::Keywords,
             ]),
+            extract_pdf_max_size: 10485760,
+            extract_office_max_size: 10485760,
+            extract_archive_max_size: 5242880,
         }
     }
 }
 
 impl IntoValue for Search {
     fn into_value(self) -> JmapValue<'static> {
-        let mut map = jmap_tools::Map::with_capacity(13);
+        let mut map = jmap_tools::Map::with_capacity(16);
         map.insert_unchecked(Property::IndexBatchSize, self.index_batch_size.into_value());
         map.insert_unchecked(
             Property::DefaultLanguage,
@@ -26034,6 +26581,18 @@ impl IntoValue for Search {
             Property::IndexTracingFields,
             self.index_tracing_fields.into_value(),
         );
+        map.insert_unchecked(
+            Property::ExtractPdfMaxSize,
+            self.extract_pdf_max_size.into_value(),
+        );
+        map.insert_unchecked(
+            Property::ExtractOfficeMaxSize,
+            self.extract_office_max_size.into_value(),
+        );
+        map.insert_unchecked(
+            Property::ExtractArchiveMaxSize,
+            self.extract_archive_max_size.into_value(),
+        );
         JmapValue::Object(map)
     }
 }
@@ -26056,6 +26615,13 @@ impl RegistryJsonPropertyPatch for Search {
             Some(Property::IndexEmailFields) => self.index_email_fields.patch(pointer, value),
             Some(Property::IndexTelemetry) => self.index_telemetry.patch(pointer, value),
             Some(Property::IndexTracingFields) => self.index_tracing_fields.patch(pointer, value),
+            Some(Property::ExtractPdfMaxSize) => self.extract_pdf_max_size.patch(pointer, value),
+            Some(Property::ExtractOfficeMaxSize) => {
+                self.extract_office_max_size.patch(pointer, value)
+            }
+            Some(Property::ExtractArchiveMaxSize) => {
+                self.extract_archive_max_size.patch(pointer, value)
+            }
             Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
                 property: Property::Type,
                 value,
@@ -27181,6 +27747,12 @@ impl ObjectImpl for SenderAuth {
         value.validate(errors);
         let value = &self.reverse_ip_verify;
         value.validate(errors);
+        let value = &self.srs_domain;
+        value.validate(errors);
+        let value = &self.srs_signing_key;
+        value.validate(errors);
+        let value = &self.srs_previous_key;
+        value.validate(errors);
         errors.len() == neb
     }
 
@@ -27295,6 +27867,22 @@ impl SenderAuth {
         }
     }
 
+    pub fn ctx_srs_domain(&self) -> ExpressionContext<'_> {
+        ExpressionContext {
+            expr: &self.srs_domain,
+            default: Some(Expression {
+                else_: "false".to_string(),
+                match_: List::from_iter([ExpressionMatch {
+                    if_: "(source == 'unauthenticated' || source == 'dmarc_pass' || source == 'autogenerated') && !is_empty(sender_domain) && !is_local_domain(sender_domain)".to_string(),
+                    then: "system('domain')".to_string(),
+                }]),
+            }),
+            property: Property::SrsDomain,
+            allowed_variables: MTA_QUEUE_RCPT_VARIABLE,
+            allowed_constants: &[],
+        }
+    }
+
     pub fn expression_ctxs(&self) -> Vec<ExpressionContext<'_>> {
         vec![
             self.ctx_dkim_sign_domain(),
@@ -27304,6 +27892,7 @@ impl SenderAuth {
             self.ctx_arc_verify(),
             self.ctx_dmarc_verify(),
             self.ctx_reverse_ip_verify(),
+            self.ctx_srs_domain(),
         ]
     }
 }
@@ -27318,6 +27907,14 @@ impl Pickle for SenderAuth {
         self.arc_verify.pickle(out);
         self.dmarc_verify.pickle(out);
         self.reverse_ip_verify.pickle(out);
+        self.srs_domain.pickle(out);
+        self.srs_signing_key.pickle(out);
+        self.srs_previous_key.pickle(out);
+        self.srs_max_age.pickle(out);
+        self.bimi_verify.pickle(out);
+        self.bimi_require_vmc.pickle(out);
+        self.bimi_max_size.pickle(out);
+        self.bimi_trust_anchors.pickle(out);
     }
 
     fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
@@ -27330,6 +27927,14 @@ impl Pickle for SenderAuth {
         this.arc_verify = Pickle::unpickle(stream)?;
         this.dmarc_verify = Pickle::unpickle(stream)?;
         this.reverse_ip_verify = Pickle::unpickle(stream)?;
+        this.srs_domain = Pickle::unpickle(stream)?;
+        this.srs_signing_key = Pickle::unpickle(stream)?;
+        this.srs_previous_key = Pickle::unpickle(stream)?;
+        this.srs_max_age = Pickle::unpickle(stream)?;
+        this.bimi_verify = Pickle::unpickle(stream)?;
+        this.bimi_require_vmc = Pickle::unpickle(stream)?;
+        this.bimi_max_size = Pickle::unpickle(stream)?;
+        this.bimi_trust_anchors = Pickle::unpickle(stream)?;
         Some(this)
     }
 }
@@ -27382,13 +27987,27 @@ impl Default for SenderAuth {
                     then: "relaxed".to_string(),
                 }]),
             },
+            srs_domain: Expression {
+                else_: "false".to_string(),
+                match_: List::from_iter([ExpressionMatch {
+                    if_: "(source == 'unauthenticated' || source == 'dmarc_pass' || source == 'autogenerated') && !is_empty(sender_domain) && !is_local_domain(sender_domain)".to_string(),
+                    then: "system('domain')".to_string(),
+                }]),
+            },
+            srs_signing_key: Default::default(),
+            srs_previous_key: Default::default(),
+            srs_max_age: Duration::from_millis(1814400000),
+            bimi_verify: false,
+            bimi_require_vmc: false,
+            bimi_max_size: 32768,
+            bimi_trust_anchors: None,
         }
     }
 }
 
 impl IntoValue for SenderAuth {
     fn into_value(self) -> JmapValue<'static> {
-        let mut map = jmap_tools::Map::with_capacity(10);
+        let mut map = jmap_tools::Map::with_capacity(18);
         map.insert_unchecked(Property::DkimSignDomain, self.dkim_sign_domain.into_value());
         map.insert_unchecked(Property::DkimStrict, self.dkim_strict.into_value());
         map.insert_unchecked(Property::DkimVerify, self.dkim_verify.into_value());
@@ -27400,6 +28019,17 @@ impl IntoValue for SenderAuth {
             Property::ReverseIpVerify,
             self.reverse_ip_verify.into_value(),
         );
+        map.insert_unchecked(Property::SrsDomain, self.srs_domain.into_value());
+        map.insert_unchecked(Property::SrsSigningKey, self.srs_signing_key.into_value());
+        map.insert_unchecked(Property::SrsPreviousKey, self.srs_previous_key.into_value());
+        map.insert_unchecked(Property::SrsMaxAge, self.srs_max_age.into_value());
+        map.insert_unchecked(Property::BimiVerify, self.bimi_verify.into_value());
+        map.insert_unchecked(Property::BimiRequireVmc, self.bimi_require_vmc.into_value());
+        map.insert_unchecked(Property::BimiMaxSize, self.bimi_max_size.into_value());
+        map.insert_unchecked(
+            Property::BimiTrustAnchors,
+            self.bimi_trust_anchors.into_value(),
+        );
         JmapValue::Object(map)
     }
 }
@@ -27419,6 +28049,14 @@ impl RegistryJsonPropertyPatch for SenderAuth {
             Some(Property::ArcVerify) => self.arc_verify.patch(pointer, value),
             Some(Property::DmarcVerify) => self.dmarc_verify.patch(pointer, value),
             Some(Property::ReverseIpVerify) => self.reverse_ip_verify.patch(pointer, value),
+            Some(Property::SrsDomain) => self.srs_domain.patch(pointer, value),
+            Some(Property::SrsSigningKey) => self.srs_signing_key.patch(pointer, value),
+            Some(Property::SrsPreviousKey) => self.srs_previous_key.patch(pointer, value),
+            Some(Property::SrsMaxAge) => self.srs_max_age.patch(pointer, value),
+            Some(Property::BimiVerify) => self.bimi_verify.patch(pointer, value),
+            Some(Property::BimiRequireVmc) => self.bimi_require_vmc.patch(pointer, value),
+            Some(Property::BimiMaxSize) => self.bimi_max_size.patch(pointer, value),
+            Some(Property::BimiTrustAnchors) => self.bimi_trust_anchors.patch(pointer, value),
             Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
                 property: Property::Type,
                 value,
@@ -28209,6 +28847,9 @@ impl ObjectImpl for SieveUserInterpreter {
                 errors.push(ValidationError::min_value(Property::MaxScripts, 1));
             }
         }
+        if let Some(value) = &self.rate_limit_notify {
+            value.validate(errors);
+        }
         errors.len() == neb
     }
 
@@ -28242,6 +28883,8 @@ impl Pickle for SieveUserInterpreter {
         self.max_var_name_length.pickle(out);
         self.max_var_size.pickle(out);
         self.max_scripts.pickle(out);
+        self.rate_limit_notify.pickle(out);
+        self.allowed_ext_lists.pickle(out);
     }
 
     fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
@@ -28271,6 +28914,8 @@ impl Pickle for SieveUserInterpreter {
         this.max_var_name_length = Pickle::unpickle(stream)?;
         this.max_var_size = Pickle::unpickle(stream)?;
         this.max_scripts = Pickle::unpickle(stream)?;
+        this.rate_limit_notify = Pickle::unpickle(stream)?;
+        this.allowed_ext_lists = Pickle::unpickle(stream)?;
         Some(this)
     }
 }
@@ -28281,7 +28926,7 @@ impl Default for SieveUserInterpreter {
             default_expiry_duplicate: Duration::from_millis(604800000),
             default_expiry_vacation: Duration::from_millis(2592000000),
             disable_capabilities: Default::default(),
-            allowed_notify_uris: Map::new(vec!["mailto".to_string()]),
+            allowed_notify_uris: Map::new(vec!["mailto".to_string(), "push".to_string()]),
             protected_headers: Map::new(vec![
                 "Original-Subject".to_string(),
                 "Original-From".to_string(),
@@ -28308,13 +28953,18 @@ impl Default for SieveUserInterpreter {
             max_var_name_length: 32u64,
             max_var_size: 4096u64,
             max_scripts: Some(100u64),
+            rate_limit_notify: Some(Rate {
+                count: 10u64,
+                period: Duration::from_millis(3600000),
+            }),
+            allowed_ext_lists: Default::default(),
         }
     }
 }
 
 impl IntoValue for SieveUserInterpreter {
     fn into_value(self) -> JmapValue<'static> {
-        let mut map = jmap_tools::Map::with_capacity(27);
+        let mut map = jmap_tools::Map::with_capacity(29);
         map.insert_unchecked(
             Property::DefaultExpiryDuplicate,
             self.default_expiry_duplicate.into_value(),
@@ -28379,6 +29029,14 @@ impl IntoValue for SieveUserInterpreter {
         );
         map.insert_unchecked(Property::MaxVarSize, self.max_var_size.into_value());
         map.insert_unchecked(Property::MaxScripts, self.max_scripts.into_value());
+        map.insert_unchecked(
+            Property::RateLimitNotify,
+            self.rate_limit_notify.into_value(),
+        );
+        map.insert_unchecked(
+            Property::AllowedExtLists,
+            self.allowed_ext_lists.into_value(),
+        );
         JmapValue::Object(map)
     }
 }
@@ -28429,6 +29087,8 @@ impl RegistryJsonPropertyPatch for SieveUserInterpreter {
             Some(Property::MaxVarNameLength) => self.max_var_name_length.patch(pointer, value),
             Some(Property::MaxVarSize) => self.max_var_size.patch(pointer, value),
             Some(Property::MaxScripts) => self.max_scripts.patch(pointer, value),
+            Some(Property::RateLimitNotify) => self.rate_limit_notify.patch(pointer, value),
+            Some(Property::AllowedExtLists) => self.allowed_ext_lists.patch(pointer, value),
             Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
                 property: Property::Type,
                 value,
@@ -28572,6 +29232,26 @@ impl ObjectImpl for SpamClassifier {
         if *value < 100 {
             errors.push(ValidationError::min_value(Property::ReservoirCapacity, 100));
         }
+        let value = &self.account_model_weight;
+        if *value < Float::new(0.0) {
+            errors.push(ValidationError::min_value(Property::AccountModelWeight, 0));
+        }
+        if *value > Float::new(1.0) {
+            errors.push(ValidationError::max_value(Property::AccountModelWeight, 1));
+        }
+        let value = &self.account_model_min_samples;
+        if *value > 10000 {
+            errors.push(ValidationError::max_value(
+                Property::AccountModelMinSamples,
+                10000,
+            ));
+        }
+        if *value < 1 {
+            errors.push(ValidationError::min_value(
+                Property::AccountModelMinSamples,
+                1,
+            ));
+        }
         errors.len() == neb
     }
 
@@ -28590,6 +29270,10 @@ impl Pickle for SpamClassifier {
         self.reservoir_capacity.pickle(out);
         self.train_frequency.pickle(out);
         self.learn_ham_from_reply.pickle(out);
+        self.account_model.pickle(out);
+        self.account_model_weight.pickle(out);
+        self.account_model_min_samples.pickle(out);
+        self.account_model_max_age.pickle(out);
     }
 
     fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
@@ -28604,6 +29288,10 @@ impl Pickle for SpamClassifier {
         this.reservoir_capacity = Pickle::unpickle(stream)?;
         this.train_frequency = Pickle::unpickle(stream)?;
         this.learn_ham_from_reply = Pickle::unpickle(stream)?;
+        this.account_model = Pickle::unpickle(stream)?;
+        this.account_model_weight = Pickle::unpickle(stream)?;
+        this.account_model_min_samples = Pickle::unpickle(stream)?;
+        this.account_model_max_age = Pickle::unpickle(stream)?;
         Some(this)
     }
 }
@@ -28621,13 +29309,17 @@ impl Default for SpamClassifier {
             reservoir_capacity: 1024u64,
             train_frequency: Some(Duration::from_millis(43200000)),
             learn_ham_from_reply: true,
+            account_model: false,
+            account_model_weight: Float::new(0.5f64),
+            account_model_min_samples: 10u64,
+            account_model_max_age: Duration::from_millis(7776000000),
         }
     }
 }
 
 impl IntoValue for SpamClassifier {
     fn into_value(self) -> JmapValue<'static> {
-        let mut map = jmap_tools::Map::with_capacity(12);
+        let mut map = jmap_tools::Map::with_capacity(16);
         map.insert_unchecked(Property::Model, self.model.into_value());
         map.insert_unchecked(
             Property::LearnHamFromCard,
@@ -28653,6 +29345,19 @@ impl IntoValue for SpamClassifier {
             Property::LearnHamFromReply,
             self.learn_ham_from_reply.into_value(),
         );
+        map.insert_unchecked(Property::AccountModel, self.account_model.into_value());
+        map.insert_unchecked(
+            Property::AccountModelWeight,
+            self.account_model_weight.into_value(),
+        );
+        map.insert_unchecked(
+            Property::AccountModelMinSamples,
+            self.account_model_min_samples.into_value(),
+        );
+        map.insert_unchecked(
+            Property::AccountModelMaxAge,
+            self.account_model_max_age.into_value(),
+        );
         JmapValue::Object(map)
     }
 }
@@ -28676,6 +29381,12 @@ impl RegistryJsonPropertyPatch for SpamClassifier {
             Some(Property::ReservoirCapacity) => self.reservoir_capacity.patch(pointer, value),
             Some(Property::TrainFrequency) => self.train_frequency.patch(pointer, value),
             Some(Property::LearnHamFromReply) => self.learn_ham_from_reply.patch(pointer, value),
+            Some(Property::AccountModel) => self.account_model.patch(pointer, value),
+            Some(Property::AccountModelWeight) => self.account_model_weight.patch(pointer, value),
+            Some(Property::AccountModelMinSamples) => {
+                self.account_model_min_samples.patch(pointer, value)
+            }
+            Some(Property::AccountModelMaxAge) => self.account_model_max_age.patch(pointer, value),
             Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
                 property: Property::Type,
                 value,
@@ -31903,6 +32614,7 @@ impl ObjectImpl for SpamTag {
             SpamTag::Score(inner) => inner.validate(errors),
             SpamTag::Discard(inner) => inner.validate(errors),
             SpamTag::Reject(inner) => inner.validate(errors),
+            SpamTag::Quarantine(inner) => inner.validate(errors),
         }
     }
 
@@ -31917,6 +32629,9 @@ impl ObjectImpl for SpamTag {
             SpamTag::Reject(object) => {
                 object.index(i);
             }
+            SpamTag::Quarantine(object) => {
+                object.index(i);
+            }
         }
     }
 }
@@ -31942,6 +32657,10 @@ impl Pickle for SpamTag {
                 2u16.pickle(out);
                 inner.pickle(out);
             }
+            SpamTag::Quarantine(inner) => {
+                3u16.pickle(out);
+                inner.pickle(out);
+            }
         }
     }
 
@@ -31950,6 +32669,7 @@ impl Pickle for SpamTag {
             0 => Pickle::unpickle(stream).map(SpamTag::Score),
             1 => Pickle::unpickle(stream).map(SpamTag::Discard),
             2 => Pickle::unpickle(stream).map(SpamTag::Reject),
+            3 => Pickle::unpickle(stream).map(SpamTag::Quarantine),
             _ => None,
         }
     }
@@ -31979,6 +32699,13 @@ impl IntoValue for SpamTag {
                     .insert_unchecked(Property::Type, JmapValue::Str("Reject".into()));
                 obj
             }
+            SpamTag::Quarantine(obj) => {
+                let mut obj = obj.into_value();
+                obj.as_object_mut()
+                    .unwrap()
+                    .insert_unchecked(Property::Type, JmapValue::Str("Quarantine".into()));
+                obj
+            }
         }
     }
 }
@@ -31994,12 +32721,14 @@ impl RegistryJsonPatch for SpamTag {
                 SpamTagType::Score => *self = SpamTag::Score(Default::default()),
                 SpamTagType::Discard => *self = SpamTag::Discard(Default::default()),
                 SpamTagType::Reject => *self = SpamTag::Reject(Default::default()),
+                SpamTagType::Quarantine => *self = SpamTag::Quarantine(Default::default()),
             }
         }
         match self {
             SpamTag::Score(inner) => inner.patch(pointer, value),
             SpamTag::Discard(inner) => inner.patch(pointer, value),
             SpamTag::Reject(inner) => inner.patch(pointer, value),
+            SpamTag::Quarantine(inner) => inner.patch(pointer, value),
         }
     }
 }
@@ -32010,6 +32739,7 @@ impl SpamTag {
             SpamTag::Score(_) => SpamTagType::Score,
             SpamTag::Discard(_) => SpamTagType::Discard,
             SpamTag::Reject(_) => SpamTagType::Reject,
+            SpamTag::Quarantine(_) => SpamTagType::Quarantine,
         }
     }
 }
@@ -38526,6 +39256,12 @@ impl UserAccount {
         }
         let value = &self.encryption_at_rest;
         value.validate(errors);
+        let value = &self.booking_delegate_ids;
+        for value in value.iter() {
+            if !value.is_valid() {
+                errors.push(ValidationError::required(Property::BookingDelegateIds));
+            }
+        }
         errors.len() == neb
     }
 
@@ -38557,6 +39293,14 @@ impl UserAccount {
             i.text(Property::Text, value);
         }
         self.encryption_at_rest.index(i);
+        i.search(Property::PrincipalKind, self.principal_kind.to_id());
+        for id in self.booking_delegate_ids.iter() {
+            i.foreign_key(
+                ObjectType::Account,
+                Some(*id),
+                Some(AccountType::User.to_id()),
+            );
+        }
     }
 }
 
@@ -38576,6 +39320,12 @@ impl Pickle for UserAccount {
         self.locale.pickle(out);
         self.time_zone.pickle(out);
         self.encryption_at_rest.pickle(out);
+        self.principal_kind.pickle(out);
+        self.booking_policy.pickle(out);
+        self.booking_max_duration.pickle(out);
+        self.booking_window.pickle(out);
+        self.booking_allowed_requesters.pickle(out);
+        self.booking_delegate_ids.pickle(out);
     }
 
     fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
@@ -38594,6 +39344,12 @@ impl Pickle for UserAccount {
         this.locale = Pickle::unpickle(stream)?;
         this.time_zone = Pickle::unpickle(stream)?;
         this.encryption_at_rest = Pickle::unpickle(stream)?;
+        this.principal_kind = Pickle::unpickle(stream)?;
+        this.booking_policy = Pickle::unpickle(stream)?;
+        this.booking_max_duration = Pickle::unpickle(stream)?;
+        this.booking_window = Pickle::unpickle(stream)?;
+        this.booking_allowed_requesters = Pickle::unpickle(stream)?;
+        this.booking_delegate_ids = Pickle::unpickle(stream)?;
         Some(this)
     }
 }
@@ -38615,13 +39371,19 @@ impl Default for UserAccount {
             locale: Locale::EnUS,
             time_zone: Default::default(),
             encryption_at_rest: Default::default(),
+            principal_kind: PrincipalKind::Individual,
+            booking_policy: BookingPolicy::AutoAcceptIfFree,
+            booking_max_duration: Default::default(),
+            booking_window: Default::default(),
+            booking_allowed_requesters: Default::default(),
+            booking_delegate_ids: Default::default(),
         }
     }
 }
 
 impl IntoValue for UserAccount {
     fn into_value(self) -> JmapValue<'static> {
-        let mut map = jmap_tools::Map::with_capacity(16);
+        let mut map = jmap_tools::Map::with_capacity(22);
         map.insert_unchecked(Property::Name, self.name.into_value());
         map.insert_unchecked(Property::DomainId, self.domain_id.into_value());
         map.insert_unchecked(Property::Credentials, self.credentials.into_value());
@@ -38639,6 +39401,21 @@ impl IntoValue for UserAccount {
             Property::EncryptionAtRest,
             self.encryption_at_rest.into_value(),
         );
+        map.insert_unchecked(Property::PrincipalKind, self.principal_kind.into_value());
+        map.insert_unchecked(Property::BookingPolicy, self.booking_policy.into_value());
+        map.insert_unchecked(
+            Property::BookingMaxDuration,
+            self.booking_max_duration.into_value(),
+        );
+        map.insert_unchecked(Property::BookingWindow, self.booking_window.into_value());
+        map.insert_unchecked(
+            Property::BookingAllowedRequesters,
+            self.booking_allowed_requesters.into_value(),
+        );
+        map.insert_unchecked(
+            Property::BookingDelegateIds,
+            self.booking_delegate_ids.into_value(),
+        );
         JmapValue::Object(map)
     }
 }
@@ -38671,6 +39448,14 @@ impl RegistryJsonPropertyPatch for UserAccount {
             Some(Property::Locale) => self.locale.patch(pointer, value),
             Some(Property::TimeZone) => self.time_zone.patch(pointer, value),
             Some(Property::EncryptionAtRest) => self.encryption_at_rest.patch(pointer, value),
+            Some(Property::PrincipalKind) => self.principal_kind.patch(pointer, value),
+            Some(Property::BookingPolicy) => self.booking_policy.patch(pointer, value),
+            Some(Property::BookingMaxDuration) => self.booking_max_duration.patch(pointer, value),
+            Some(Property::BookingWindow) => self.booking_window.patch(pointer, value),
+            Some(Property::BookingAllowedRequesters) => {
+                self.booking_allowed_requesters.patch(pointer, value)
+            }
+            Some(Property::BookingDelegateIds) => self.booking_delegate_ids.patch(pointer, value),
             Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
                 property: Property::Type,
                 value,
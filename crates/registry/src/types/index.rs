@@ -167,6 +167,12 @@ impl IndexSchema {
     }
 }
 
+impl From<u16> for IndexValue<'_> {
+    fn from(value: u16) -> Self {
+        IndexValue::U16(value)
+    }
+}
+
 impl From<u64> for IndexValue<'_> {
     fn from(value: u64) -> Self {
         IndexValue::U64(value)
@@ -6,6 +6,7 @@
 
 use common::ipc::{
     BroadcastEvent, CacheInvalidation, CalendarAlert, EmailPush, PushNotification, RegistryChange,
+    SieveNotify,
 };
 use registry::{
     schema::prelude::ObjectType,
@@ -71,6 +72,13 @@ impl BroadcastBatch<Vec<BroadcastEvent>> {
                         let _ = serialized.write_leb128(email_push.email_id);
                         let _ = serialized.write_leb128(email_push.change_id);
                     }
+                    PushNotification::SieveNotify(sieve_notify) => {
+                        serialized.push(12u8);
+                        let _ = serialized.write_leb128(sieve_notify.account_id);
+                        serialized.push(sieve_notify.importance);
+                        let _ = serialized.write_leb128(sieve_notify.message.len());
+                        let _ = serialized.write(sieve_notify.message.as_bytes());
+                    }
                 },
                 BroadcastEvent::PushServerUpdate(account_id) => {
                     serialized.push(3u8);
@@ -107,6 +115,7 @@ impl BroadcastBatch<Vec<BroadcastEvent>> {
                             CacheInvalidation::List(id) => (7u8, *id),
                             CacheInvalidation::DomainLogo(id) => (8u8, *id),
                             CacheInvalidation::TenantLogo(id) => (9u8, *id),
+                            CacheInvalidation::SpamModel(id) => (10u8, *id),
                         };
 
                         serialized.push(marker);
@@ -240,6 +249,7 @@ where
                             7 => CacheInvalidation::List(id),
                             8 => CacheInvalidation::DomainLogo(id),
                             9 => CacheInvalidation::TenantLogo(id),
+                            10 => CacheInvalidation::SpamModel(id),
                             _ => return Err(()),
                         });
                     }
@@ -249,6 +259,23 @@ where
                 9 => Ok(Some(BroadcastEvent::CacheInvalidateNegative)),
                 10 => Ok(Some(BroadcastEvent::MtaQueueStatus { is_running: true })),
                 11 => Ok(Some(BroadcastEvent::MtaQueueStatus { is_running: false })),
+                12 => {
+                    let account_id = self.messages.next_leb128().ok_or(())?;
+                    let importance = self.messages.next().ok_or(())?.borrow().to_owned();
+                    let message_len = self.messages.next_leb128::<usize>().ok_or(())?;
+                    let mut message_bytes = vec![0u8; message_len];
+                    for byte in message_bytes.iter_mut() {
+                        *byte = self.messages.next().ok_or(())?.borrow().to_owned();
+                    }
+                    let message = String::from_utf8(message_bytes).map_err(|_| ())?;
+                    Ok(Some(BroadcastEvent::PushNotification(
+                        PushNotification::SieveNotify(SieveNotify {
+                            account_id,
+                            importance,
+                            message,
+                        }),
+                    )))
+                }
                 _ => Err(()),
             }
         } else {
@@ -219,6 +219,12 @@ fn log_event(event: &BroadcastEvent) -> trc::Value {
                 email_push.email_id.into(),
                 email_push.change_id.into(),
             ]),
+            PushNotification::SieveNotify(sieve_notify) => trc::Value::Array(vec![
+                "SieveNotify".into(),
+                sieve_notify.account_id.into(),
+                (sieve_notify.importance as u32).into(),
+                sieve_notify.message.clone().into(),
+            ]),
         },
         BroadcastEvent::PushServerUpdate(account_id) => {
             trc::Value::Array(vec!["PushServerUpdate".into(), (*account_id).into()])
@@ -52,6 +52,13 @@ impl PushRegistration {
                             alert_id: calendar_alert.alert_id.clone(),
                         });
                     }
+                    PushNotification::SieveNotify(sieve_notify) => {
+                        objects.push(PushObject::SieveNotification {
+                            account_id: sieve_notify.account_id.into(),
+                            importance: sieve_notify.importance,
+                            message: sieve_notify.message.clone(),
+                        });
+                    }
                     PushNotification::EmailPush(email_push) => {
                         objects.push(PushObject::EmailPush {
                             account_id: email_push.account_id.into(),
@@ -64,7 +71,7 @@ impl PushRegistration {
             }
 
             let response = if !objects.is_empty() {
-                if changed.is_empty() {
+                if !changed.is_empty() {
                     objects.push(PushObject::StateChange { changed });
                 }
                 if objects.len() > 1 {
@@ -4,17 +4,21 @@
  * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
  */
 
-use crate::task_manager::TaskResult;
-use common::Server;
+use crate::task_manager::{
+    TaskResult, quarantine_digest::remove_quarantine_digest, quota_warning::remove_quota_warnings,
+};
+use common::{Server, ipc::CacheInvalidation};
 use email::{message::metadata::MessageMetadata, sieve::SieveScript};
 use groupware::file::FileNode;
 use registry::{
     schema::{
         prelude::{ObjectType, Property},
-        structs::{ArchivedItem, TaskDestroyAccount},
+        structs::{ArchivedItem, QuarantinedMessage, TaskDestroyAccount},
     },
     types::EnumImpl,
 };
+use smtp::queue::quarantine::QuarantineIndex;
+use spam_filter::modules::classifier::remove_account_model;
 use store::{
     SerializeInfallible, ValueKey,
     registry::RegistryQuery,
@@ -134,6 +138,43 @@ async fn destroy_account(server: &Server, task: &TaskDestroyAccount) -> trc::Res
         server.store().write(batch.build_all()).await?;
     }
 
+    // Remove quarantined messages
+    let mut batch = BatchBuilder::new();
+    let object_id = ObjectType::QuarantinedMessage.to_id();
+    for id in server
+        .registry()
+        .query::<Vec<Id>>(
+            RegistryQuery::new(ObjectType::QuarantinedMessage).with_account(account_id),
+        )
+        .await?
+    {
+        let item_id = id.id();
+        if let Some(item) = server
+            .store()
+            .get_value::<QuarantinedMessage>(ValueKey::from(ValueClass::Registry(
+                RegistryClass::Item { object_id, item_id },
+            )))
+            .await?
+        {
+            item.write_ops(&mut batch, item_id, false);
+        }
+    }
+    if !batch.is_empty() {
+        server.store().write(batch.build_all()).await?;
+    }
+    remove_quarantine_digest(server, account_id).await?;
+
+    // Remove per-account spam model
+    let mut batch = BatchBuilder::new();
+    remove_account_model(&mut batch, account_id);
+    server.store().write(batch.build_all()).await?;
+    server
+        .invalidate_caches(CacheInvalidation::SpamModel(account_id).into())
+        .await?;
+
+    // Remove quota warning state
+    remove_quota_warnings(server, account_id).await?;
+
     // Remove search index
     for index in [
         SearchIndex::Email,
@@ -328,6 +328,61 @@ pub(crate) async fn reindex_telemetry(server: &Server) -> trc::Result<()> {
 }
 
 pub(crate) async fn reindex_account(server: &Server, account_id: u32) -> trc::Result<()> {
+    reindex_email(server, account_id).await?;
+    reindex_dav_resources(server, account_id, IndexDocumentType::Calendar).await?;
+    reindex_dav_resources(server, account_id, IndexDocumentType::Contacts).await
+}
+
+pub(crate) async fn reindex_calendar(server: &Server, account_id: u32) -> trc::Result<()> {
+    reindex_dav_resources(server, account_id, IndexDocumentType::Calendar).await
+}
+
+async fn reindex_dav_resources(
+    server: &Server,
+    account_id: u32,
+    document_type: IndexDocumentType,
+) -> trc::Result<()> {
+    let now = now() as i64;
+
+    let cache = server
+        .fetch_dav_resources(
+            account_id,
+            account_id,
+            if document_type == IndexDocumentType::Calendar {
+                SyncCollection::Calendar
+            } else {
+                SyncCollection::AddressBook
+            },
+        )
+        .await
+        .caused_by(trc::location!())?;
+
+    let mut batch = BatchBuilder::new();
+    for document_id in cache.document_ids(false) {
+        batch.schedule_task(Task::IndexDocument(TaskIndexDocument {
+            account_id: account_id.into(),
+            document_id: document_id.into(),
+            document_type,
+            status: TaskStatus::at(now + rand::rng().random_range(0..=300)),
+        }));
+
+        if batch.len() >= 2000 {
+            server.core.storage.data.write(batch.build_all()).await?;
+            batch = BatchBuilder::new();
+        }
+    }
+
+    if !batch.is_empty() {
+        server.core.storage.data.write(batch.build_all()).await?;
+    }
+
+    // Request indexing
+    server.notify_task_queue();
+
+    Ok(())
+}
+
+pub(crate) async fn reindex_email(server: &Server, account_id: u32) -> trc::Result<()> {
     let now = now() as i64;
 
     let mut batch = BatchBuilder::new();
@@ -354,36 +409,6 @@ pub(crate) async fn reindex_account(server: &Server, account_id: u32) -> trc::Re
         }
     }
 
-    for document_type in [IndexDocumentType::Calendar, IndexDocumentType::Contacts] {
-        let cache = server
-            .fetch_dav_resources(
-                account_id,
-                account_id,
-                if document_type == IndexDocumentType::Calendar {
-                    SyncCollection::Calendar
-                } else {
-                    SyncCollection::AddressBook
-                },
-            )
-            .await
-            .caused_by(trc::location!())?;
-        let mut batch = BatchBuilder::new();
-
-        for document_id in cache.document_ids(false) {
-            batch.schedule_task(Task::IndexDocument(TaskIndexDocument {
-                account_id: account_id.into(),
-                document_id: document_id.into(),
-                document_type,
-                status: TaskStatus::at(now + rand::rng().random_range(0..=300)),
-            }));
-
-            if batch.len() >= 2000 {
-                server.core.storage.data.write(batch.build_all()).await?;
-                batch = BatchBuilder::new();
-            }
-        }
-    }
-
     if !batch.is_empty() {
         server.core.storage.data.write(batch.build_all()).await?;
     }
@@ -434,6 +459,7 @@ async fn build_email_document(
                 document_id,
                 &raw_message,
                 index_fields,
+                &server.core.email.index_extract,
                 server.core.email.default_language,
             )))
         }
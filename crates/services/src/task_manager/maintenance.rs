@@ -8,17 +8,20 @@ use std::time::Instant;
 
 use crate::task_manager::{
     TaskResult,
-    index::{reindex_account, reindex_telemetry},
+    index::{reindex_account, reindex_calendar, reindex_email, reindex_telemetry},
+    quota_warning::reset_quota_warnings,
+    snooze::wake_snoozed_emails,
 };
 use common::{
     KV_ACME, KV_GREYLIST, KV_LOCK_DAV, KV_LOCK_QUEUE_MESSAGE, KV_LOCK_TASK, KV_OAUTH,
     KV_QUOTA_BLOB, KV_RATE_LIMIT_AUTH, KV_RATE_LIMIT_CONTACT, KV_RATE_LIMIT_HTTP_ANONYMOUS,
-    KV_RATE_LIMIT_HTTP_AUTHENTICATED, KV_RATE_LIMIT_IMAP, KV_RATE_LIMIT_LOITER, KV_RATE_LIMIT_RCPT,
-    KV_RATE_LIMIT_SCAN, KV_RATE_LIMIT_SMTP, KV_SIEVE_ID, Server,
-    storage::index::ObjectIndexBuilder,
+    KV_RATE_LIMIT_HTTP_AUTHENTICATED, KV_RATE_LIMIT_IMAP, KV_RATE_LIMIT_LOITER,
+    KV_RATE_LIMIT_NOTIFY, KV_RATE_LIMIT_RCPT, KV_RATE_LIMIT_SCAN, KV_RATE_LIMIT_SMTP, KV_SIEVE_ID,
+    Server, storage::index::ObjectIndexBuilder,
 };
 use email::{
     cache::MessageCacheFetch,
+    mailbox::save_date::{MailboxSaveDate, save_date_class},
     message::{delete::EmailDeletion, ingest::EmailIngest, metadata::MessageData},
 };
 use groupware::{
@@ -36,9 +39,10 @@ use registry::{
     },
     types::EnumImpl,
 };
-use smtp::reporting::index::ExternalReportIndex;
+use smtp::{queue::quarantine::QuarantineIndex, reporting::index::ExternalReportIndex};
 use store::{
-    Serialize, ValueKey,
+    Serialize, SerializeInfallible, ValueKey,
+    ahash::AHashMap,
     rand::{self},
     registry::{RegistryFilter, RegistryQuery},
     roaring::RoaringBitmap,
@@ -110,16 +114,26 @@ async fn store_maintenance(
 ) -> trc::Result<TaskResult> {
     match task.maintenance_type {
         TaskStoreMaintenanceType::ReindexAccounts
+        | TaskStoreMaintenanceType::ReindexEmail
+        | TaskStoreMaintenanceType::ReindexCalendar
         | TaskStoreMaintenanceType::PurgeAccounts
-        | TaskStoreMaintenanceType::ResetUserQuotas => {
+        | TaskStoreMaintenanceType::ResetUserQuotas
+        | TaskStoreMaintenanceType::SendQuarantineDigests => {
             let mut batch = BatchBuilder::new();
             let now = now() as i64;
             let maintenance_type = match task.maintenance_type {
                 TaskStoreMaintenanceType::ReindexAccounts => TaskAccountMaintenanceType::Reindex,
+                TaskStoreMaintenanceType::ReindexEmail => TaskAccountMaintenanceType::ReindexEmail,
+                TaskStoreMaintenanceType::ReindexCalendar => {
+                    TaskAccountMaintenanceType::ReindexCalendar
+                }
                 TaskStoreMaintenanceType::PurgeAccounts => TaskAccountMaintenanceType::Purge,
                 TaskStoreMaintenanceType::ResetUserQuotas => {
                     TaskAccountMaintenanceType::RecalculateQuota
                 }
+                TaskStoreMaintenanceType::SendQuarantineDigests => {
+                    TaskAccountMaintenanceType::QuarantineDigest
+                }
                 _ => unreachable!(),
             };
             for account_id in server
@@ -156,13 +170,14 @@ async fn store_maintenance(
             reindex_telemetry(server).await?;
         }
         TaskStoreMaintenanceType::PurgeData => {
-            // Delete expired external reports
+            // Delete expired external reports and quarantined messages
             let now = now();
             let mut batch = BatchBuilder::new();
             for object in [
                 ObjectType::DmarcExternalReport,
                 ObjectType::TlsExternalReport,
                 ObjectType::ArfExternalReport,
+                ObjectType::QuarantinedMessage,
             ] {
                 let ids = server
                     .registry()
@@ -192,6 +207,9 @@ async fn store_maintenance(
                             ObjectInner::ArfExternalReport(report) => {
                                 report.write_ops(&mut batch, item_id, false);
                             }
+                            ObjectInner::QuarantinedMessage(message) => {
+                                message.write_ops(&mut batch, item_id, false);
+                            }
                             _ => {}
                         }
 
@@ -345,6 +363,7 @@ async fn store_maintenance(
                     KV_RATE_LIMIT_HTTP_AUTHENTICATED,
                     KV_RATE_LIMIT_HTTP_ANONYMOUS,
                     KV_RATE_LIMIT_IMAP,
+                    KV_RATE_LIMIT_NOTIFY,
                 ][..],
                 TaskStoreMaintenanceType::ResetBlobQuotas => &[KV_QUOTA_BLOB][..],
                 TaskStoreMaintenanceType::RemoveAuthTokens => &[KV_ACME, KV_OAUTH][..],
@@ -404,16 +423,36 @@ async fn account_maintenance(
     match task.maintenance_type {
         TaskAccountMaintenanceType::Purge => {
             server.purge_account(task.account_id.document_id()).await?;
+            reset_quota_warnings(server, task.account_id.document_id()).await?;
         }
         TaskAccountMaintenanceType::Reindex => {
             reindex_account(server, task.account_id.document_id()).await?;
         }
+        TaskAccountMaintenanceType::ReindexEmail => {
+            reindex_email(server, task.account_id.document_id()).await?;
+        }
+        TaskAccountMaintenanceType::ReindexCalendar => {
+            reindex_calendar(server, task.account_id.document_id()).await?;
+        }
         TaskAccountMaintenanceType::RecalculateImapUid => {
             reset_imap_uids(server, task.account_id.document_id()).await?;
         }
         TaskAccountMaintenanceType::RecalculateQuota => {
             recalculate_quota(server, task.account_id.document_id()).await?;
         }
+        TaskAccountMaintenanceType::EmailSnooze => {
+            wake_snoozed_emails(server, task.account_id.document_id()).await?;
+        }
+        TaskAccountMaintenanceType::QuotaWarning | TaskAccountMaintenanceType::QuarantineDigest => {
+            // These tasks send messages and are dispatched by the task manager
+            trc::event!(
+                TaskManager(trc::TaskManagerEvent::TaskIgnored),
+                Type = task.maintenance_type.as_str(),
+                Reason = "Maintenance type not handled by this task",
+                AccountId = task.account_id.document_id(),
+            );
+            return Ok(TaskResult::Ignored);
+        }
     }
 
     Ok(TaskResult::Success(vec![]))
@@ -525,6 +564,7 @@ async fn reset_imap_uids(server: &Server, account_id: u32) -> trc::Result<(u32,
         .await
         .caused_by(trc::location!())?;
 
+    let mut save_dates = AHashMap::with_capacity(cache.mailboxes.index.len());
     for &mailbox_id in cache.mailboxes.index.keys() {
         let mailbox = server
             .store()
@@ -557,6 +597,19 @@ async fn reset_imap_uids(server: &Server, account_id: u32) -> trc::Result<(u32,
             .write(batch.build_all())
             .await
             .caused_by(trc::location!())?;
+
+        // Save dates are keyed by UID, keep them to re-add them under the new UIDs
+        save_dates.insert(
+            mailbox_id,
+            server
+                .save_dates(account_id, mailbox_id, 0..=u32::MAX)
+                .await
+                .caused_by(trc::location!())?,
+        );
+        server
+            .delete_save_dates(account_id, mailbox_id)
+            .await
+            .caused_by(trc::location!())?;
         mailbox_count += 1;
     }
 
@@ -592,14 +645,25 @@ async fn reset_imap_uids(server: &Server, account_id: u32) -> trc::Result<(u32,
             .await
             .caused_by(trc::location!())?;
 
+        let mut batch = BatchBuilder::new();
+        batch
+            .with_account_id(account_id)
+            .with_collection(Collection::Mailbox);
         for (uid_mailbox, uid) in new_data.mailboxes.iter_mut().zip(ids) {
+            if let Some(save_date) = save_dates
+                .get(&uid_mailbox.mailbox_id)
+                .and_then(|dates| dates.get(&uid_mailbox.uid))
+            {
+                batch.with_document(uid_mailbox.mailbox_id).set(
+                    save_date_class(uid_mailbox.mailbox_id, uid),
+                    save_date.serialize(),
+                );
+            }
             uid_mailbox.uid = uid;
         }
 
         // Prepare write batch
-        let mut batch = BatchBuilder::new();
         batch
-            .with_account_id(account_id)
             .with_collection(Collection::Email)
             .with_document(message_id)
             .assert_value(ValueClass::Property(EmailField::Archive.into()), &data)
@@ -14,6 +14,8 @@ use crate::task_manager::index::SearchIndexTask;
 use crate::task_manager::lock::TaskLockManager;
 use crate::task_manager::maintenance::MaintenanceTask;
 use crate::task_manager::merge_threads::MergeThreadsTask;
+use crate::task_manager::quarantine_digest::QuarantineDigestTask;
+use crate::task_manager::quota_warning::QuotaWarningTask;
 use crate::task_manager::report::{self, SubmitReportTask};
 use crate::task_manager::restore_item::RestoreItemTask;
 use crate::task_manager::spam_classifier::SpamFilterMaintenanceTask;
@@ -26,7 +28,7 @@ use common::config::server::ServerProtocol;
 use common::network::limiter::ConcurrencyLimiter;
 use common::network::{ServerInstance, TcpAcceptor};
 use common::{Inner, Server};
-use registry::schema::enums::TaskType;
+use registry::schema::enums::{TaskAccountMaintenanceType, TaskType};
 use registry::schema::structs::{
     Task, TaskManager, TaskRetryStrategy, TaskStatus, TaskStatusFailed, TaskStatusRetry,
 };
@@ -231,6 +233,22 @@ pub fn spawn_task_manager(inner: Arc<Inner>) {
                                 }
                                 Task::RestoreArchivedItem(task) => server.restore_item(task).await,
                                 Task::DestroyAccount(task) => server.destroy_account(task).await,
+                                Task::AccountMaintenance(task)
+                                    if task.maintenance_type
+                                        == TaskAccountMaintenanceType::QuotaWarning =>
+                                {
+                                    server
+                                        .send_quota_warning(task, server_instance.clone())
+                                        .await
+                                }
+                                Task::AccountMaintenance(task)
+                                    if task.maintenance_type
+                                        == TaskAccountMaintenanceType::QuarantineDigest =>
+                                {
+                                    server
+                                        .send_quarantine_digest(task, server_instance.clone())
+                                        .await
+                                }
                                 Task::AccountMaintenance(task) => {
                                     server.account_maintenance(task).await
                                 }
@@ -26,9 +26,13 @@ pub mod lock;
 pub mod maintenance;
 pub mod manager;
 pub mod merge_threads;
+pub mod notification;
+pub mod quarantine_digest;
+pub mod quota_warning;
 pub mod report;
 pub mod restore_item;
 pub mod scheduler;
+pub mod snooze;
 pub mod spam_classifier;
 
 const QUEUE_REFRESH_INTERVAL: u64 = 60 * 5; // 5 minutes
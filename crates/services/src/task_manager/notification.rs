@@ -0,0 +1,158 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use common::{
+    DEFAULT_LOGO_BASE64, Server,
+    auth::AccountInfo,
+    network::{ServerInstance, stream::NullIo},
+};
+use mail_builder::{
+    MessageBuilder,
+    headers::{HeaderType, content_type::ContentType},
+    mime::{BodyPart, MimePart},
+};
+use mail_parser::decoders::html::html_to_text;
+use smtp::{
+    core::{Session, SessionData},
+    queue::QueueId,
+};
+use smtp_proto::{MailFrom, RcptTo};
+use std::{sync::Arc, time::Duration};
+use store::write::now;
+use tokio::task::JoinError;
+
+// Notifications sent by the postmaster to an account's main address
+pub(crate) struct Notification {
+    to: String,
+    domain: String,
+    pub logo_cid: String,
+}
+
+impl Notification {
+    pub fn new(account_info: &AccountInfo) -> Self {
+        let to = account_info.name().to_string();
+        let domain = to.rsplit('@').next().unwrap_or("localhost").to_string();
+
+        Notification {
+            logo_cid: format!("logo.{}@{domain}", now()),
+            to,
+            domain,
+        }
+    }
+
+    pub async fn build(&self, server: &Server, subject: String, html_body: String) -> Vec<u8> {
+        let txt_body = html_to_text(&html_body);
+
+        // Obtain logo image
+        let logo = match server.logo_resource(&self.domain).await {
+            Ok(logo) => logo,
+            Err(err) => {
+                trc::error!(
+                    err.caused_by(trc::location!())
+                        .details("Failed to fetch logo image")
+                );
+                None
+            }
+        };
+        let logo = if let Some(logo) = &logo {
+            MimePart::new(
+                ContentType::new(logo.content_type.as_ref()),
+                BodyPart::Binary(logo.contents.as_slice().into()),
+            )
+        } else {
+            MimePart::new(
+                ContentType::new("image/png"),
+                BodyPart::Binary(DEFAULT_LOGO_BASE64.as_bytes().into()),
+            )
+            .transfer_encoding("base64")
+        }
+        .inline()
+        .cid(&self.logo_cid);
+
+        // Build message
+        let mail_from = format!("postmaster@{}", self.domain);
+        MessageBuilder::new()
+            .from(("Postmaster", mail_from.as_str()))
+            .header("To", HeaderType::Text(self.to.as_str().into()))
+            .header("Auto-Submitted", HeaderType::Text("auto-generated".into()))
+            .subject(subject)
+            .body(MimePart::new(
+                ContentType::new("multipart/related"),
+                BodyPart::Multipart(vec![
+                    MimePart::new(
+                        ContentType::new("multipart/alternative"),
+                        BodyPart::Multipart(vec![
+                            MimePart::new(
+                                ContentType::new("text/plain"),
+                                BodyPart::Text(txt_body.into()),
+                            ),
+                            MimePart::new(
+                                ContentType::new("text/html"),
+                                BodyPart::Text(html_body.into()),
+                            ),
+                        ]),
+                    ),
+                    logo,
+                ]),
+            ))
+            .write_to_vec()
+            .unwrap_or_default()
+    }
+
+    pub async fn send(
+        self,
+        server: &Server,
+        server_instance: Arc<ServerInstance>,
+        account_info: AccountInfo,
+        message: Vec<u8>,
+    ) -> Result<Result<QueueId, String>, JoinError> {
+        let server = server.clone();
+        let to = self.to;
+        tokio::spawn(async move {
+            let mut session = Session::<NullIo>::local(
+                server,
+                server_instance,
+                SessionData::local(account_info, None, vec![], vec![], 0),
+            );
+
+            // MAIL FROM
+            let _ = session
+                .handle_mail_from(MailFrom {
+                    address: to.clone().into(),
+                    ..Default::default()
+                })
+                .await;
+            if let Some(error) = session.has_failed() {
+                return Err(format!("Server rejected MAIL-FROM: {}", error.trim()));
+            }
+
+            // RCPT TO
+            session.params.rcpt_errors_wait = Duration::from_secs(0);
+            let _ = session
+                .handle_rcpt_to(RcptTo {
+                    address: to.into(),
+                    ..Default::default()
+                })
+                .await;
+            if let Some(error) = session.has_failed() {
+                return Err(format!("Server rejected RCPT-TO: {}", error.trim()));
+            }
+
+            // DATA
+            session.data.message = message;
+            let response = session.queue_message().await;
+            if let smtp::core::State::Accepted(queue_id) = session.state {
+                Ok(queue_id)
+            } else {
+                Err(format!(
+                    "Server rejected DATA: {}",
+                    std::str::from_utf8(&response).unwrap().trim()
+                ))
+            }
+        })
+        .await
+    }
+}
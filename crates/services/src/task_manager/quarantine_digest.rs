@@ -0,0 +1,264 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::task_manager::{TaskResult, notification::Notification};
+use common::{
+    KV_QUARANTINE_DIGEST, Server, auth::AccountInfo,
+    config::mailstore::email::QuarantineTemplateVariable, i18n, network::ServerInstance,
+};
+use mail_builder::headers::date::Date;
+use registry::{
+    schema::{
+        prelude::{ObjectType, Property},
+        structs::{QuarantinedMessage, TaskAccountMaintenance},
+    },
+    types::EnumImpl,
+};
+use smtp::queue::quarantine::SmtpQuarantine;
+use std::sync::Arc;
+use store::{
+    ValueKey,
+    dispatch::lookup::KeyValue,
+    registry::RegistryQuery,
+    write::{RegistryClass, ValueClass, now},
+};
+use trc::AddContext;
+use types::id::Id;
+use utils::template::Variables;
+
+pub(crate) trait QuarantineDigestTask: Sync + Send {
+    fn send_quarantine_digest(
+        &self,
+        task: &TaskAccountMaintenance,
+        server_instance: Arc<ServerInstance>,
+    ) -> impl Future<Output = TaskResult> + Send;
+}
+
+struct DigestItem {
+    item_id: u64,
+    item: QuarantinedMessage,
+}
+
+impl QuarantineDigestTask for Server {
+    async fn send_quarantine_digest(
+        &self,
+        task: &TaskAccountMaintenance,
+        server_instance: Arc<ServerInstance>,
+    ) -> TaskResult {
+        match send_quarantine_digest(self, task.account_id.document_id(), server_instance).await {
+            Ok(result) => result,
+            Err(err) => {
+                let result = TaskResult::temporary(err.to_string());
+                trc::error!(
+                    err.account_id(task.account_id.document_id())
+                        .caused_by(trc::location!())
+                        .details("Failed to send quarantine digest")
+                );
+                result
+            }
+        }
+    }
+}
+
+async fn send_quarantine_digest(
+    server: &Server,
+    account_id: u32,
+    server_instance: Arc<ServerInstance>,
+) -> trc::Result<TaskResult> {
+    let Some(account) = server
+        .try_account(account_id)
+        .await
+        .caused_by(trc::location!())?
+    else {
+        return Ok(TaskResult::Success(vec![]));
+    };
+
+    // Only include messages received since the last digest
+    let now = now();
+    let last_digest = server
+        .in_memory_store()
+        .key_get::<String>(KeyValue::<()>::build_key(
+            KV_QUARANTINE_DIGEST,
+            account_id.to_be_bytes(),
+        ))
+        .await
+        .caused_by(trc::location!())?
+        .and_then(|value| value.parse::<u64>().ok())
+        .unwrap_or_default();
+    let object_id = ObjectType::QuarantinedMessage.to_id();
+    let mut items = Vec::new();
+    for id in server
+        .registry()
+        .query::<Vec<Id>>(
+            RegistryQuery::new(ObjectType::QuarantinedMessage).with_account(account_id),
+        )
+        .await?
+    {
+        let item_id = id.id();
+        if let Some(item) = server
+            .store()
+            .get_value::<QuarantinedMessage>(ValueKey::from(ValueClass::Registry(
+                RegistryClass::Item { object_id, item_id },
+            )))
+            .await
+            .caused_by(trc::location!())?
+            .filter(|item| {
+                item.received_at.timestamp() as u64 > last_digest
+                    && item.expires_at.timestamp() as u64 > now
+            })
+        {
+            items.push(DigestItem { item_id, item });
+        }
+    }
+    if items.is_empty() {
+        return Ok(TaskResult::Success(vec![]));
+    }
+
+    let account_info = server
+        .build_account_info(account)
+        .await
+        .caused_by(trc::location!())?;
+    if account_info.name().is_empty() {
+        trc::event!(
+            Queue(trc::QueueEvent::QuarantineDigestFailed),
+            Reason = "Account does not have any email addresses",
+            AccountId = account_id,
+        );
+        return Ok(TaskResult::Success(vec![]));
+    }
+
+    // Build message
+    let notification = Notification::new(&account_info);
+    let (subject, html_body) = build_template(
+        server,
+        &account_info,
+        account_id,
+        &items,
+        &notification.logo_cid,
+    )
+    .await;
+    let message = notification.build(server, subject, html_body).await;
+
+    // Send message
+    let result = notification
+        .send(server, server_instance, account_info, message)
+        .await;
+
+    match result {
+        Ok(Ok(queue_id)) => {
+            server
+                .in_memory_store()
+                .key_set(
+                    KeyValue::with_prefix(
+                        KV_QUARANTINE_DIGEST,
+                        account_id.to_be_bytes(),
+                        now.to_string().into_bytes(),
+                    )
+                    .expires(server.core.email.quarantine_hold.as_secs()),
+                )
+                .await
+                .caused_by(trc::location!())?;
+
+            trc::event!(
+                Queue(trc::QueueEvent::QuarantineDigestSent),
+                AccountId = account_id,
+                Total = items.len(),
+                QueueId = queue_id,
+            );
+        }
+        Ok(Err(err)) => {
+            trc::event!(
+                Queue(trc::QueueEvent::QuarantineDigestFailed),
+                AccountId = account_id,
+                Total = items.len(),
+                Reason = err,
+            );
+        }
+        Err(_) => {
+            trc::event!(
+                Server(trc::ServerEvent::ThreadError),
+                Details = "Join Error",
+                AccountId = account_id,
+                CausedBy = trc::location!(),
+            );
+            return Ok(TaskResult::temporary("Thread join error"));
+        }
+    }
+
+    Ok(TaskResult::Success(vec![]))
+}
+
+pub(crate) async fn remove_quarantine_digest(server: &Server, account_id: u32) -> trc::Result<()> {
+    server
+        .in_memory_store()
+        .key_delete(KeyValue::<()>::build_key(
+            KV_QUARANTINE_DIGEST,
+            account_id.to_be_bytes(),
+        ))
+        .await
+        .caused_by(trc::location!())
+}
+
+async fn build_template(
+    server: &Server,
+    account_info: &AccountInfo,
+    account_id: u32,
+    items: &[DigestItem],
+    logo_cid: &str,
+) -> (String, String) {
+    let locale = i18n::locale_or_default(account_info.locale().as_str());
+    let count = items.len().to_string();
+    let subject = locale.quarantine_digest_subject.replace("$count", &count);
+
+    let mut entries = Vec::with_capacity(items.len());
+    for DigestItem { item_id, item } in items {
+        let release_url = server
+            .quarantine_release_url(account_id, *item_id, item.expires_at.timestamp() as u64)
+            .await
+            .unwrap_or_default();
+
+        entries.push([
+            (QuarantineTemplateVariable::From, item.from.clone()),
+            (QuarantineTemplateVariable::Subject, item.subject.clone()),
+            (QuarantineTemplateVariable::Reason, item.reason.clone()),
+            (
+                QuarantineTemplateVariable::ReceivedAt,
+                Date::new(item.received_at.timestamp()).to_rfc822(),
+            ),
+            (QuarantineTemplateVariable::ReleaseUrl, release_url),
+            (
+                QuarantineTemplateVariable::ReleaseLabel,
+                locale.quarantine_release.to_string(),
+            ),
+        ]);
+    }
+
+    let mut variables = Variables::new();
+    variables.insert_single(QuarantineTemplateVariable::PageTitle, subject.clone());
+    variables.insert_single(
+        QuarantineTemplateVariable::Header,
+        locale.quarantine_digest_header.to_string(),
+    );
+    variables.insert_single(
+        QuarantineTemplateVariable::Footer,
+        locale.quarantine_digest_footer.to_string(),
+    );
+    variables.insert_single(
+        QuarantineTemplateVariable::Title,
+        locale.quarantine_digest_title.replace("$count", &count),
+    );
+    variables.insert_single(
+        QuarantineTemplateVariable::Description,
+        locale.quarantine_digest_description.to_string(),
+    );
+    variables.insert_single(QuarantineTemplateVariable::LogoCid, logo_cid.to_string());
+    variables.insert_block(QuarantineTemplateVariable::Items, entries);
+
+    (
+        subject,
+        server.core.email.quarantine_template.eval(&variables),
+    )
+}
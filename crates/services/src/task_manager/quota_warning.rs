@@ -0,0 +1,338 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::task_manager::{TaskResult, notification::Notification};
+use common::{
+    KV_QUOTA_WARNING, Server, auth::AccountInfo, config::mailstore::email::QuotaTemplateVariable,
+    i18n, network::ServerInstance,
+};
+use registry::schema::structs::TaskAccountMaintenance;
+use std::sync::Arc;
+use store::dispatch::lookup::KeyValue;
+use trc::AddContext;
+use utils::template::Variables;
+
+pub(crate) trait QuotaWarningTask: Sync + Send {
+    fn send_quota_warning(
+        &self,
+        task: &TaskAccountMaintenance,
+        server_instance: Arc<ServerInstance>,
+    ) -> impl Future<Output = TaskResult> + Send;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum QuotaScope {
+    Account(u32),
+    Tenant(u32),
+}
+
+struct QuotaUsage {
+    scope: QuotaScope,
+    used: u64,
+    quota: u64,
+    level: u64,
+}
+
+impl QuotaWarningTask for Server {
+    async fn send_quota_warning(
+        &self,
+        task: &TaskAccountMaintenance,
+        server_instance: Arc<ServerInstance>,
+    ) -> TaskResult {
+        match send_quota_warning(self, task.account_id.document_id(), server_instance).await {
+            Ok(result) => result,
+            Err(err) => {
+                let result = TaskResult::temporary(err.to_string());
+                trc::error!(
+                    err.account_id(task.account_id.document_id())
+                        .caused_by(trc::location!())
+                        .details("Failed to send quota warning")
+                );
+                result
+            }
+        }
+    }
+}
+
+async fn send_quota_warning(
+    server: &Server,
+    account_id: u32,
+    server_instance: Arc<ServerInstance>,
+) -> trc::Result<TaskResult> {
+    let Some(account) = server
+        .try_account(account_id)
+        .await
+        .caused_by(trc::location!())?
+    else {
+        return Ok(TaskResult::Success(vec![]));
+    };
+
+    // Only notify once per threshold crossing
+    let mut warnings = Vec::with_capacity(2);
+    for usage in quota_usage(server, account_id).await? {
+        let warned_level = warned_level(server, usage.scope).await?;
+        if usage.level > warned_level {
+            warnings.push(usage);
+        }
+    }
+    if warnings.is_empty() {
+        return Ok(TaskResult::Success(vec![]));
+    }
+
+    let account_info = server
+        .build_account_info(account)
+        .await
+        .caused_by(trc::location!())?;
+    if account_info.name().is_empty() {
+        trc::event!(
+            Limit(trc::LimitEvent::QuotaWarningFailed),
+            Reason = "Account does not have any email addresses",
+            AccountId = account_id,
+        );
+        return Ok(TaskResult::Success(vec![]));
+    }
+
+    // Build message
+    let notification = Notification::new(&account_info);
+    let (subject, html_body) =
+        build_template(server, &account_info, &warnings, &notification.logo_cid);
+    let message = notification.build(server, subject, html_body).await;
+
+    // Send message
+    let result = notification
+        .send(server, server_instance, account_info, message)
+        .await;
+
+    let level = warnings
+        .iter()
+        .map(|usage| usage.level)
+        .max()
+        .unwrap_or_default();
+    match result {
+        Ok(Ok(queue_id)) => {
+            trc::event!(
+                Limit(trc::LimitEvent::QuotaWarningSent),
+                AccountId = account_id,
+                Details = level,
+                QueueId = queue_id,
+            );
+
+            // Store the warned levels only once the message has been queued,
+            // so that a failed attempt is retried on the next quota change
+            for usage in &warnings {
+                set_warned_level(server, usage.scope, usage.level).await?;
+            }
+        }
+        Ok(Err(err)) => {
+            trc::event!(
+                Limit(trc::LimitEvent::QuotaWarningFailed),
+                AccountId = account_id,
+                Details = level,
+                Reason = err,
+            );
+        }
+        Err(_) => {
+            trc::event!(
+                Server(trc::ServerEvent::ThreadError),
+                Details = "Join Error",
+                AccountId = account_id,
+                CausedBy = trc::location!(),
+            );
+            return Ok(TaskResult::temporary("Thread join error"));
+        }
+    }
+
+    Ok(TaskResult::Success(vec![]))
+}
+
+// Lowers the stored warning levels once usage drops, so that crossing
+// a threshold again triggers a new warning.
+pub(crate) async fn reset_quota_warnings(server: &Server, account_id: u32) -> trc::Result<()> {
+    for usage in quota_usage(server, account_id).await? {
+        if usage.level < warned_level(server, usage.scope).await? {
+            set_warned_level(server, usage.scope, usage.level).await?;
+        }
+    }
+
+    Ok(())
+}
+
+pub(crate) async fn remove_quota_warnings(server: &Server, account_id: u32) -> trc::Result<()> {
+    set_warned_level(server, QuotaScope::Account(account_id), 0).await
+}
+
+async fn quota_usage(server: &Server, account_id: u32) -> trc::Result<Vec<QuotaUsage>> {
+    let Some(account) = server
+        .try_account(account_id)
+        .await
+        .caused_by(trc::location!())?
+    else {
+        return Ok(vec![]);
+    };
+    let mut usage = Vec::with_capacity(2);
+
+    if account.quota_disk != 0 {
+        let used = server.get_used_quota_account(account_id).await?.max(0) as u64;
+        usage.push(QuotaUsage {
+            scope: QuotaScope::Account(account_id),
+            used,
+            quota: account.quota_disk,
+            level: server.quota_warning_level(used, account.quota_disk),
+        });
+    }
+
+    // SPDX-SnippetBegin
+    // SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+    // SPDX-License-Identifier: LicenseRef-SEL
+
+    #[cfg(feature = "enterprise")]
+    if server.core.is_enterprise_edition()
+        && let Some(tenant_id) = account.id_tenant
+    {
+        let tenant = server.tenant(tenant_id).await.caused_by(trc::location!())?;
+
+        if tenant.quota_disk != 0 {
+            let used = server.get_used_quota_tenant(tenant_id).await?.max(0) as u64;
+            usage.push(QuotaUsage {
+                scope: QuotaScope::Tenant(tenant_id),
+                used,
+                quota: tenant.quota_disk,
+                level: server.quota_warning_level(used, tenant.quota_disk),
+            });
+        }
+    }
+
+    // SPDX-SnippetEnd
+
+    Ok(usage)
+}
+
+async fn warned_level(server: &Server, scope: QuotaScope) -> trc::Result<u64> {
+    server
+        .in_memory_store()
+        .key_get::<String>(KeyValue::<()>::build_key(KV_QUOTA_WARNING, scope.key()))
+        .await
+        .map(|level| {
+            level
+                .and_then(|level| level.parse().ok())
+                .unwrap_or_default()
+        })
+        .caused_by(trc::location!())
+}
+
+async fn set_warned_level(server: &Server, scope: QuotaScope, level: u64) -> trc::Result<()> {
+    if level != 0 {
+        server
+            .in_memory_store()
+            .key_set(KeyValue::with_prefix(
+                KV_QUOTA_WARNING,
+                scope.key(),
+                level.to_string().into_bytes(),
+            ))
+            .await
+    } else {
+        server
+            .in_memory_store()
+            .key_delete(KeyValue::<()>::build_key(KV_QUOTA_WARNING, scope.key()))
+            .await
+    }
+    .caused_by(trc::location!())
+}
+
+fn build_template(
+    server: &Server,
+    account_info: &AccountInfo,
+    warnings: &[QuotaUsage],
+    logo_cid: &str,
+) -> (String, String) {
+    let locale = i18n::locale_or_default(account_info.locale().as_str());
+    let level = warnings
+        .iter()
+        .map(|usage| usage.level)
+        .max()
+        .unwrap_or_default()
+        .to_string();
+    let subject = locale.quota_warning_subject.replace("$percent", &level);
+    let title = warnings
+        .iter()
+        .map(|usage| {
+            let percent = (usage.used as u128 * 100 / usage.quota as u128).to_string();
+            match usage.scope {
+                QuotaScope::Account(_) => locale.quota_account_usage.replace("$percent", &percent),
+                QuotaScope::Tenant(_) => locale.quota_tenant_usage.replace("$percent", &percent),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+    let details = warnings
+        .iter()
+        .flat_map(|usage| {
+            [
+                [
+                    (QuotaTemplateVariable::Key, locale.quota_used.to_string()),
+                    (QuotaTemplateVariable::Value, format_size(usage.used)),
+                ],
+                [
+                    (QuotaTemplateVariable::Key, locale.quota_limit.to_string()),
+                    (QuotaTemplateVariable::Value, format_size(usage.quota)),
+                ],
+            ]
+        })
+        .collect::<Vec<_>>();
+
+    let mut variables = Variables::new();
+    variables.insert_single(QuotaTemplateVariable::PageTitle, subject.clone());
+    variables.insert_single(
+        QuotaTemplateVariable::Header,
+        locale.quota_warning_header.to_string(),
+    );
+    variables.insert_single(
+        QuotaTemplateVariable::Footer,
+        locale.quota_warning_footer.to_string(),
+    );
+    variables.insert_single(QuotaTemplateVariable::Title, title);
+    variables.insert_single(
+        QuotaTemplateVariable::Description,
+        locale.quota_warning_advice.to_string(),
+    );
+    variables.insert_single(QuotaTemplateVariable::LogoCid, logo_cid.to_string());
+    variables.insert_block(QuotaTemplateVariable::Details, details);
+
+    (
+        subject,
+        server.core.email.quota_warn_template.eval(&variables),
+    )
+}
+
+fn format_size(size: u64) -> String {
+    const UNITS: [&str; 5] = ["B", "KB", "MB", "GB", "TB"];
+
+    let mut value = size as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    if unit == 0 {
+        format!("{size} {}", UNITS[0])
+    } else {
+        format!("{value:.1} {}", UNITS[unit])
+    }
+}
+
+impl QuotaScope {
+    fn key(&self) -> [u8; 5] {
+        let (typ, id) = match self {
+            QuotaScope::Account(id) => (0, *id),
+            QuotaScope::Tenant(id) => (1, *id),
+        };
+        let mut key = [0u8; 5];
+        key[0] = typ;
+        key[1..].copy_from_slice(&id.to_be_bytes());
+        key
+    }
+}
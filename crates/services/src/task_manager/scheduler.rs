@@ -37,6 +37,7 @@ enum Event {
     PurgeAccount,
     PurgeDataStore,
     PurgeBlobStore,
+    QuarantineDigest,
     OtelMetrics,
     CalculateMetrics,
     TrainSpamClassifier,
@@ -89,6 +90,14 @@ pub fn spawn_task_scheduler(inner: Arc<Inner>) {
                 Event::PurgeBlobStore,
             );
 
+            // Quarantine digests
+            if let Some(digest) = &server.core.email.quarantine_digest {
+                queue.schedule(
+                    Instant::now() + digest.time_to_next(),
+                    Event::QuarantineDigest,
+                );
+            }
+
             // Node ID lease renewal
             if server.core.storage.coordinator.is_enabled() {
                 queue.schedule(
@@ -230,6 +239,28 @@ pub fn spawn_task_scheduler(inner: Arc<Inner>) {
                             }));
                         }
                     }
+                    Event::QuarantineDigest => {
+                        if let Some(digest) = &server.core.email.quarantine_digest {
+                            queue.schedule(
+                                Instant::now() + digest.time_to_next(),
+                                Event::QuarantineDigest,
+                            );
+
+                            if let Some(batch) = batch.as_mut() {
+                                trc::event!(
+                                    TaskManager(TaskManagerEvent::TaskQueued),
+                                    Type = TaskStoreMaintenanceType::SendQuarantineDigests.as_str()
+                                );
+
+                                batch.schedule_task(Task::StoreMaintenance(TaskStoreMaintenance {
+                                    maintenance_type:
+                                        TaskStoreMaintenanceType::SendQuarantineDigests,
+                                    status: TaskStatus::now(),
+                                    shard_index: None,
+                                }));
+                            }
+                        }
+                    }
                     Event::RenewNodeIdLease => {
                         queue.schedule(
                             Instant::now() + server.registry().refresh_node_id_interval(),
@@ -393,6 +424,22 @@ pub fn spawn_task_scheduler(inner: Arc<Inner>) {
                                         status: TaskStatus::now(),
                                     },
                                 ));
+
+                                if server
+                                    .core
+                                    .spam
+                                    .classifier
+                                    .as_ref()
+                                    .is_some_and(|c| c.account_model.is_some())
+                                {
+                                    batch.schedule_task(Task::SpamFilterMaintenance(
+                                        TaskSpamFilterMaintenance {
+                                            maintenance_type:
+                                                TaskSpamFilterMaintenanceType::PruneAccountModels,
+                                            status: TaskStatus::now(),
+                                        },
+                                    ));
+                                }
                             }
                         }
                     }
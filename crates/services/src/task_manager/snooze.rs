@@ -0,0 +1,141 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use common::{Server, storage::index::ObjectIndexBuilder};
+use email::{
+    cache::{MessageCacheFetch, mailbox::MailboxCacheAccess},
+    mailbox::{INBOX_ID, UidMailbox},
+    message::{
+        ingest::EmailIngest,
+        metadata::MessageData,
+        snooze::{EmailSnooze, SnoozedEmail},
+    },
+};
+use store::{
+    ValueKey,
+    ahash::AHashSet,
+    write::{AlignedBytes, Archive, BatchBuilder, now},
+};
+use trc::AddContext;
+use types::{
+    collection::{Collection, SyncCollection, VanishedCollection},
+    keyword::Keyword,
+    special_use::SpecialUse,
+};
+
+pub(crate) async fn wake_snoozed_emails(server: &Server, account_id: u32) -> trc::Result<()> {
+    let snoozed_emails = server
+        .emails_snoozed_due(account_id, now())
+        .await
+        .caused_by(trc::location!())?;
+    if snoozed_emails.is_empty() {
+        return Ok(());
+    }
+
+    let cache = server
+        .get_cached_messages(account_id)
+        .await
+        .caused_by(trc::location!())?;
+    let snoozed_mailbox_id = cache
+        .mailbox_by_role(&SpecialUse::Snoozed)
+        .map(|m| m.document_id);
+
+    let mut batch = BatchBuilder::new();
+    let mut changed_mailboxes = AHashSet::new();
+    let mut vanished_items = Vec::new();
+    let mut total = 0;
+
+    for (document_id, snoozed) in snoozed_emails {
+        SnoozedEmail::clear(&mut batch, account_id, document_id);
+
+        let Some(data_) = server
+            .store()
+            .get_value::<Archive<AlignedBytes>>(ValueKey::archive(
+                account_id,
+                Collection::Email,
+                document_id,
+            ))
+            .await
+            .caused_by(trc::location!())?
+        else {
+            continue;
+        };
+        let data = data_
+            .to_unarchived::<MessageData>()
+            .caused_by(trc::location!())?;
+        let mut new_data = data.inner.to_builder();
+
+        // Move the message out of the Snoozed folder, falling back to the
+        // Inbox if the original mailbox no longer exists
+        if let Some(snoozed_mailbox_id) = snoozed_mailbox_id
+            && new_data.has_mailbox_id(snoozed_mailbox_id)
+        {
+            let mailbox_id = if snoozed.mailbox_id != snoozed_mailbox_id
+                && cache.has_mailbox_id(&snoozed.mailbox_id)
+            {
+                snoozed.mailbox_id
+            } else {
+                INBOX_ID
+            };
+
+            new_data.remove_mailbox(snoozed_mailbox_id);
+            if !new_data.has_mailbox_id(mailbox_id) {
+                let uid = server
+                    .assign_email_ids(account_id, [mailbox_id], false)
+                    .await
+                    .caused_by(trc::location!())?
+                    .next()
+                    .unwrap_or_default();
+                new_data.add_mailbox(UidMailbox::new(mailbox_id, uid));
+            }
+        }
+
+        // Mark the message as unread
+        new_data.remove_keyword(&Keyword::Seen);
+
+        for mailbox in new_data.removed_mailboxes(data.inner) {
+            vanished_items.push((mailbox.mailbox_id.to_native(), mailbox.uid.to_native()));
+        }
+        changed_mailboxes.extend(
+            data.inner
+                .mailboxes
+                .iter()
+                .map(|m| m.mailbox_id.to_native())
+                .chain(new_data.mailboxes.iter().map(|m| m.mailbox_id)),
+        );
+
+        batch
+            .custom(
+                ObjectIndexBuilder::new()
+                    .with_current(data)
+                    .with_changes(new_data.seal()),
+            )
+            .caused_by(trc::location!())?
+            .commit_point();
+        total += 1;
+    }
+
+    // Log mailbox changes
+    for mailbox_id in changed_mailboxes {
+        batch.log_container_property_change(SyncCollection::Email, mailbox_id);
+    }
+    for vanished_item in vanished_items {
+        batch.log_vanished_item(VanishedCollection::Email, vanished_item);
+    }
+
+    server
+        .commit_batch(batch)
+        .await
+        .caused_by(trc::location!())?;
+
+    trc::event!(
+        Store(trc::StoreEvent::SnoozeWakeUp),
+        AccountId = account_id,
+        Total = total,
+    );
+
+    Ok(())
+}
@@ -68,6 +68,7 @@ async fn spam_filter_maintenance(
             for key in [SPAM_CLASSIFIER_KEY, SPAM_TRAINER_KEY] {
                 server.blob_store().delete_blob(key).await?;
             }
+            server.spam_prune_account_models(true).await?;
         }
         TaskSpamFilterMaintenanceType::Abort => {
             if server.inner.ipc.train_task_controller.is_running() {
@@ -77,6 +78,9 @@ async fn spam_filter_maintenance(
         TaskSpamFilterMaintenanceType::UpdateRules => {
             return update_spam_rules(server).await;
         }
+        TaskSpamFilterMaintenanceType::PruneAccountModels => {
+            server.spam_prune_account_models(false).await?;
+        }
     }
 
     Ok(TaskResult::Success(vec![]))
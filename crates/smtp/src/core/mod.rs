@@ -25,6 +25,7 @@ use tokio::io::{AsyncRead, AsyncWrite};
 use utils::DomainPart;
 
 pub mod params;
+pub mod srs;
 pub mod throttle;
 
 #[derive(Clone)]
@@ -91,6 +92,9 @@ pub struct SessionData {
     pub spf_ehlo: Option<SpfOutput>,
     pub spf_mail_from: Option<SpfOutput>,
     pub dnsbl_error: Option<Vec<u8>>,
+
+    // Set on local sessions used by the server to send its own messages
+    pub is_internal: bool,
 }
 
 #[derive(Clone, Debug)]
@@ -167,6 +171,7 @@ impl SessionData {
             spf_ehlo: None,
             spf_mail_from: None,
             dnsbl_error: None,
+            is_internal: false,
         }
     }
 }
@@ -290,6 +295,7 @@ impl SessionData {
             spf_ehlo: None,
             spf_mail_from: None,
             dnsbl_error: None,
+            is_internal: false,
         }
     }
 }
@@ -0,0 +1,196 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::queue::QueueEnvelope;
+use base64::{Engine, engine::general_purpose::STANDARD};
+use common::{Server, config::smtp::auth::SrsAuthConfig};
+use ring::hmac;
+use store::write::now;
+use trc::DeliveryEvent;
+
+const SRS0_TAG: &str = "SRS0";
+const SRS1_TAG: &str = "SRS1";
+// Separators accepted after the SRS tag, the remaining fields are always separated by '='
+const SRS_SEPARATORS: &[u8] = b"=+-";
+const SRS_HASH_LEN: usize = 4;
+const SRS_TIME_PRECISION: u64 = 86400;
+const SRS_TIME_SLOTS: u64 = 1024;
+const BASE32: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SrsAddress {
+    NotSrs,
+    Valid(String),
+    Invalid,
+    Expired,
+}
+
+pub trait SenderRewriting: Sync + Send {
+    fn srs_return_path(
+        &self,
+        envelope: &QueueEnvelope<'_>,
+        session_id: u64,
+    ) -> impl Future<Output = Option<String>> + Send;
+}
+
+pub trait SrsCodec {
+    fn srs_encode(&self, sender: &str, srs_domain: &str, now: u64) -> Option<String>;
+    fn srs_decode(&self, address: &str, now: u64) -> SrsAddress;
+}
+
+impl SenderRewriting for Server {
+    async fn srs_return_path(
+        &self,
+        envelope: &QueueEnvelope<'_>,
+        session_id: u64,
+    ) -> Option<String> {
+        let config = &self.core.smtp.mail_auth.srs;
+        if config.keys.is_empty() || envelope.message.return_path.is_empty() {
+            return None;
+        }
+
+        let srs_domain = self
+            .eval_if::<String, _>(&config.rewrite_domain, envelope, session_id)
+            .await
+            .filter(|domain| !domain.is_empty())?;
+        let return_path = config.srs_encode(&envelope.message.return_path, &srs_domain, now())?;
+
+        trc::event!(
+            Delivery(DeliveryEvent::SrsRewritten),
+            SpanId = session_id,
+            From = envelope.message.return_path.to_string(),
+            Details = return_path.clone(),
+        );
+
+        Some(return_path)
+    }
+}
+
+impl SrsCodec for SrsAuthConfig {
+    fn srs_encode(&self, sender: &str, srs_domain: &str, now: u64) -> Option<String> {
+        let key = self.keys.first()?;
+        let (local_part, domain) = sender.rsplit_once('@')?;
+        if local_part.is_empty() || domain.is_empty() {
+            return None;
+        }
+
+        if let Some(opaque) = srs_tagged(local_part, SRS1_TAG) {
+            // Already rewritten by several forwarders, keep the first forwarder
+            let (_, rest) = opaque.split_once('=')?;
+            let (first_hop, srs_user) = rest.split_once('=')?;
+            if first_hop.is_empty() || !srs_user.starts_with(is_separator) {
+                return None;
+            }
+            let hash = srs_hash(key, &[first_hop, srs_user]);
+            Some(format!(
+                "{SRS1_TAG}={hash}={first_hop}={srs_user}@{srs_domain}"
+            ))
+        } else if srs_tagged(local_part, SRS0_TAG).is_some() {
+            // Rewritten by a previous forwarder, wrap it in a SRS1 address
+            let srs_user = &local_part[SRS0_TAG.len()..];
+            let hash = srs_hash(key, &[domain, srs_user]);
+            Some(format!(
+                "{SRS1_TAG}={hash}={domain}={srs_user}@{srs_domain}"
+            ))
+        } else {
+            let timestamp = (now / SRS_TIME_PRECISION) % SRS_TIME_SLOTS;
+            let timestamp = [
+                BASE32[(timestamp >> 5) as usize & 31] as char,
+                BASE32[timestamp as usize & 31] as char,
+            ]
+            .iter()
+            .collect::<String>();
+            let hash = srs_hash(key, &[&timestamp, domain, local_part]);
+            Some(format!(
+                "{SRS0_TAG}={hash}={timestamp}={domain}={local_part}@{srs_domain}"
+            ))
+        }
+    }
+
+    fn srs_decode(&self, address: &str, now: u64) -> SrsAddress {
+        let Some((local_part, _)) = address.rsplit_once('@') else {
+            return SrsAddress::NotSrs;
+        };
+
+        if let Some(opaque) = srs_tagged(local_part, SRS0_TAG) {
+            let mut parts = opaque.splitn(4, '=');
+            let (Some(hash), Some(timestamp), Some(domain), Some(local_part)) =
+                (parts.next(), parts.next(), parts.next(), parts.next())
+            else {
+                return SrsAddress::Invalid;
+            };
+            if domain.is_empty() || local_part.is_empty() || timestamp.len() != 2 {
+                return SrsAddress::Invalid;
+            }
+            if !srs_verify(&self.keys, hash, &[timestamp, domain, local_part]) {
+                return SrsAddress::Invalid;
+            }
+
+            // Verify timestamp
+            let mut then = 0;
+            for ch in timestamp.bytes() {
+                let Some(pos) = BASE32.iter().position(|b| *b == ch.to_ascii_uppercase()) else {
+                    return SrsAddress::Invalid;
+                };
+                then = (then << 5) | pos as u64;
+            }
+            let today = (now / SRS_TIME_PRECISION) % SRS_TIME_SLOTS;
+            let age = (today + SRS_TIME_SLOTS - then) % SRS_TIME_SLOTS;
+            if age > self.max_age.div_ceil(SRS_TIME_PRECISION) {
+                return SrsAddress::Expired;
+            }
+
+            SrsAddress::Valid(format!("{local_part}@{domain}"))
+        } else if let Some(opaque) = srs_tagged(local_part, SRS1_TAG) {
+            let Some((hash, rest)) = opaque.split_once('=') else {
+                return SrsAddress::Invalid;
+            };
+            let Some((first_hop, srs_user)) = rest.split_once('=') else {
+                return SrsAddress::Invalid;
+            };
+            if first_hop.is_empty() || srs_user.len() < 2 || !srs_user.starts_with(is_separator) {
+                return SrsAddress::Invalid;
+            }
+            if !srs_verify(&self.keys, hash, &[first_hop, srs_user]) {
+                return SrsAddress::Invalid;
+            }
+
+            // Route back to the first forwarder, timestamps are checked there
+            SrsAddress::Valid(format!("{SRS0_TAG}{srs_user}@{first_hop}"))
+        } else {
+            SrsAddress::NotSrs
+        }
+    }
+}
+
+fn srs_verify(keys: &[hmac::Key], hash: &str, data: &[&str]) -> bool {
+    // Some MTAs lowercase the local part, so hashes are compared case-insensitively
+    hash.len() == SRS_HASH_LEN
+        && keys
+            .iter()
+            .any(|key| srs_hash(key, data).eq_ignore_ascii_case(hash))
+}
+
+fn srs_hash(key: &hmac::Key, data: &[&str]) -> String {
+    let mut ctx = hmac::Context::with_key(key);
+    for item in data {
+        ctx.update(item.to_lowercase().as_bytes());
+    }
+    let mut hash = STANDARD.encode(ctx.sign().as_ref());
+    hash.truncate(SRS_HASH_LEN);
+    hash
+}
+
+fn srs_tagged<'x>(local_part: &'x str, tag: &str) -> Option<&'x str> {
+    local_part
+        .get(..tag.len())
+        .filter(|prefix| prefix.eq_ignore_ascii_case(tag))
+        .and_then(|_| local_part[tag.len()..].strip_prefix(is_separator))
+}
+
+fn is_separator(ch: char) -> bool {
+    ch.is_ascii() && SRS_SEPARATORS.contains(&(ch as u8))
+}
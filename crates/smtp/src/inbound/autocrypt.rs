@@ -0,0 +1,72 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::core::Session;
+use base64::{Engine, engine::general_purpose::STANDARD};
+use common::{network::SessionStream, storage::encryption::KeyDiscovery};
+use mail_parser::{HeaderName, Message};
+use trc::SmtpEvent;
+
+impl<T: SessionStream> Session<T> {
+    pub async fn write_autocrypt_header(&self, from: &str, headers: &mut Vec<u8>) {
+        let Some(account_id) = self
+            .data
+            .authenticated_as
+            .as_ref()
+            .map(|account| account.account_id)
+        else {
+            return;
+        };
+
+        match self
+            .server
+            .discoverable_public_key(from, KeyDiscovery::Autocrypt)
+            .await
+        {
+            Ok(Some(key)) if key.account_id == account_id => {
+                headers.extend_from_slice(b"Autocrypt: addr=");
+                headers.extend_from_slice(from.as_bytes());
+                headers.extend_from_slice(b"; keydata=");
+                for chunk in STANDARD.encode(&key.cert).as_bytes().chunks(76) {
+                    headers.extend_from_slice(b"\r\n\t");
+                    headers.extend_from_slice(chunk);
+                }
+                headers.extend_from_slice(b"\r\n");
+
+                trc::event!(
+                    Smtp(SmtpEvent::AutocryptHeaderAdded),
+                    SpanId = self.data.session_id,
+                    From = from.to_string(),
+                );
+            }
+            Ok(_) => {}
+            Err(err) => {
+                trc::error!(
+                    err.span_id(self.data.session_id)
+                        .details("Failed to retrieve Autocrypt key")
+                        .caused_by(trc::location!())
+                );
+            }
+        }
+    }
+}
+
+// Returns the sender address to advertise a key for, unless the submitting
+// client already added its own Autocrypt header
+pub fn autocrypt_sender(message: &Message<'_>) -> Option<String> {
+    if message.headers().iter().any(|header| {
+        matches!(&header.name, HeaderName::Other(name) if name.eq_ignore_ascii_case("Autocrypt"))
+    }) {
+        return None;
+    }
+
+    message
+        .from()
+        .and_then(|from| from.first())
+        .and_then(|addr| addr.address())
+        .map(|addr| addr.trim().to_lowercase())
+        .filter(|addr| addr.contains('@'))
+}
@@ -0,0 +1,681 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use super::milter::Modification;
+use base64::{Engine, engine::general_purpose::STANDARD};
+use common::{
+    Server,
+    config::smtp::{
+        auth::BimiAuthConfig,
+        resolver::{BimiIndicator, BimiLogo, BimiRecord, BimiResult},
+    },
+    psl,
+};
+use mail_auth::flate2::read::GzDecoder;
+use mail_parser::{HeaderName, Message};
+use quick_xml::{Reader, events::Event};
+use rustls_pki_types::{CertificateDer, UnixTime, pem::PemObject};
+use sha1::Sha1;
+use sha2::{Digest, Sha256};
+use std::{
+    io::Read,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+use store::write::now;
+use trc::SmtpEvent;
+use x509_parser::prelude::{FromDer, GeneralName, X509Certificate};
+
+#[cfg(feature = "test_mode")]
+pub static BIMI_TEST_FILES: parking_lot::Mutex<Vec<(String, Vec<u8>)>> =
+    parking_lot::Mutex::new(Vec::new());
+
+#[cfg(not(feature = "test_mode"))]
+use utils::HttpLimitResponse;
+
+const BIMI_RECORD_TTL: Duration = Duration::from_secs(3600);
+const BIMI_INDICATOR_TTL: u64 = 86400;
+const BIMI_INVALID_TTL: Duration = Duration::from_secs(3600);
+#[cfg(not(feature = "test_mode"))]
+const BIMI_FETCH_TIMEOUT: Duration = Duration::from_secs(10);
+
+const OID_LOGOTYPE: &str = "1.3.6.1.5.5.7.1.12";
+// DER encoded 1.3.6.1.5.5.7.3.31 (id-kp-BrandIndicatorforMessageIdentification)
+const EKU_BIMI: &[u8] = &[0x2b, 0x06, 0x01, 0x05, 0x05, 0x07, 0x03, 0x1f];
+// DER encoded 2.16.840.1.101.3.4.2.1 and 1.3.14.3.2.26
+const OID_SHA256: &[u8] = &[0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x01];
+const OID_SHA1: &[u8] = &[0x2b, 0x0e, 0x03, 0x02, 0x1a];
+
+// Elements and constructs excluded by the SVG Tiny Portable/Secure profile
+const SVG_FORBIDDEN_ELEMENTS: &[&[u8]] = &[
+    b"script",
+    b"image",
+    b"foreignObject",
+    b"a",
+    b"animate",
+    b"animateColor",
+    b"animateMotion",
+    b"animateTransform",
+    b"set",
+    b"audio",
+    b"video",
+    b"animation",
+    b"handler",
+    b"listener",
+    b"iframe",
+];
+
+enum BimiError {
+    Temporary(String),
+    Invalid(String),
+}
+
+pub trait BimiLookup: Sync + Send {
+    fn verify_bimi(
+        &self,
+        message: &Message<'_>,
+        domain: &str,
+        session_id: u64,
+    ) -> impl Future<Output = BimiResult> + Send;
+}
+
+impl BimiLookup for Server {
+    async fn verify_bimi(
+        &self,
+        message: &Message<'_>,
+        domain: &str,
+        session_id: u64,
+    ) -> BimiResult {
+        let time = Instant::now();
+
+        // Indicators are only displayed for messages with a single author
+        let mut num_authors = 0;
+        let mut selector = None;
+        for header in message.headers() {
+            match &header.name {
+                HeaderName::From => {
+                    num_authors += header
+                        .value()
+                        .as_address()
+                        .map_or(0, |addrs| addrs.iter().count());
+                }
+                HeaderName::Other(name) if name.eq_ignore_ascii_case("BIMI-Selector") => {
+                    selector = header.value().as_text().and_then(parse_selector);
+                }
+                _ => {}
+            }
+        }
+        let selector = selector.unwrap_or("default");
+
+        let result = if num_authors == 1 {
+            bimi_verify_domain(self, domain, selector).await
+        } else {
+            BimiResult::Fail("Message must have exactly one author".to_string())
+        };
+
+        match &result {
+            BimiResult::Pass { logo, .. } => {
+                trc::event!(
+                    Smtp(SmtpEvent::BimiPass),
+                    SpanId = session_id,
+                    Domain = domain.to_string(),
+                    Details = selector.to_string(),
+                    Strict = logo.has_vmc,
+                    Elapsed = time.elapsed(),
+                );
+            }
+            BimiResult::Fail(reason) | BimiResult::TempError(reason) => {
+                trc::event!(
+                    Smtp(SmtpEvent::BimiFail),
+                    SpanId = session_id,
+                    Domain = domain.to_string(),
+                    Details = selector.to_string(),
+                    Reason = reason.clone(),
+                    Elapsed = time.elapsed(),
+                );
+            }
+            BimiResult::Declined | BimiResult::None => {}
+        }
+
+        result
+    }
+}
+
+async fn bimi_verify_domain(server: &Server, domain: &str, selector: &str) -> BimiResult {
+    // Lookup the record at the author domain, then at the organizational domain
+    let mut record = bimi_record(server, domain, selector).await;
+    if matches!(record, Ok(None))
+        && let Some(org_domain) = psl::domain_str(domain).filter(|d| *d != domain)
+    {
+        record = bimi_record(server, org_domain, selector).await;
+    }
+    let record = match record {
+        Ok(Some(record)) => record,
+        Ok(None) => return BimiResult::None,
+        Err(err) => return BimiResult::TempError(err),
+    };
+    let Some(location) = record.location.as_deref() else {
+        return if record.authority.is_none() {
+            BimiResult::Declined
+        } else {
+            BimiResult::Fail("Record does not include an indicator location".to_string())
+        };
+    };
+
+    match bimi_indicator(server, domain, location, record.authority.as_deref()).await {
+        Ok(BimiIndicator::Valid(logo)) => {
+            if logo.has_vmc || !server.core.smtp.mail_auth.bimi.require_vmc {
+                BimiResult::Pass {
+                    domain: domain.to_string(),
+                    selector: selector.to_string(),
+                    record,
+                    logo,
+                }
+            } else {
+                BimiResult::Fail("Indicator is not backed by a mark certificate".to_string())
+            }
+        }
+        Ok(BimiIndicator::Invalid(reason)) => BimiResult::Fail(reason.to_string()),
+        Err(err) => BimiResult::TempError(err),
+    }
+}
+
+async fn bimi_record(
+    server: &Server,
+    domain: &str,
+    selector: &str,
+) -> Result<Option<Arc<BimiRecord>>, String> {
+    let fqdn = format!("{selector}._bimi.{domain}.");
+    if let Some(record) = server.inner.cache.dns_bimi.get(fqdn.as_str()) {
+        return Ok(record);
+    }
+
+    let record = match server
+        .core
+        .smtp
+        .resolvers
+        .dns
+        .txt_raw_lookup(fqdn.as_str())
+        .await
+    {
+        Ok(bytes) => std::str::from_utf8(&bytes)
+            .ok()
+            .and_then(parse_record)
+            .map(Arc::new),
+        Err(mail_auth::Error::DnsRecordNotFound(_)) => None,
+        Err(err) => return Err(format!("DNS lookup error: {err}")),
+    };
+
+    server.inner.cache.dns_bimi.insert(
+        fqdn.into_boxed_str(),
+        record.clone(),
+        if record.is_some() {
+            BIMI_RECORD_TTL
+        } else {
+            server.inner.cache.negative_cache_ttl
+        },
+    );
+
+    Ok(record)
+}
+
+async fn bimi_indicator(
+    server: &Server,
+    domain: &str,
+    location: &str,
+    authority: Option<&str>,
+) -> Result<BimiIndicator, String> {
+    let key = format!("{domain}\n{location}\n{}", authority.unwrap_or_default());
+    if let Some(indicator) = server.inner.cache.bimi_indicators.get(key.as_str()) {
+        return Ok(indicator);
+    }
+
+    let (indicator, ttl) = match bimi_fetch_indicator(server, domain, location, authority).await {
+        Ok((logo, ttl)) => (BimiIndicator::Valid(Arc::new(logo)), ttl),
+        Err(BimiError::Invalid(reason)) => {
+            (BimiIndicator::Invalid(reason.into()), BIMI_INVALID_TTL)
+        }
+        Err(BimiError::Temporary(reason)) => return Err(reason),
+    };
+
+    server
+        .inner
+        .cache
+        .bimi_indicators
+        .insert(key.into_boxed_str(), indicator.clone(), ttl);
+
+    Ok(indicator)
+}
+
+async fn bimi_fetch_indicator(
+    server: &Server,
+    domain: &str,
+    location: &str,
+    authority: Option<&str>,
+) -> Result<(BimiLogo, Duration), BimiError> {
+    let max_size = server.core.smtp.mail_auth.bimi.max_size;
+    let svg = bimi_fetch(location, max_size).await?;
+    validate_svg(&svg).map_err(BimiError::Invalid)?;
+
+    // Indicators backed by a mark certificate expire with it, mark certificates
+    // are only evaluated when trust anchors are configured
+    let config = &server.core.smtp.mail_auth.bimi;
+    let (has_vmc, ttl) = match authority {
+        Some(authority) if !config.trust_anchors.is_empty() => {
+            let now = now();
+            let pem = bimi_fetch(authority, max_size).await?;
+            let not_after =
+                validate_vmc(&pem, &svg, domain, config, now).map_err(BimiError::Invalid)?;
+            (true, not_after.saturating_sub(now).min(BIMI_INDICATOR_TTL))
+        }
+        _ => (false, BIMI_INDICATOR_TTL),
+    };
+
+    Ok((
+        BimiLogo {
+            svg: svg.into_boxed_slice(),
+            has_vmc,
+        },
+        Duration::from_secs(ttl),
+    ))
+}
+
+async fn bimi_fetch(url: &str, max_size: usize) -> Result<Vec<u8>, BimiError> {
+    if !url
+        .get(..8)
+        .is_some_and(|scheme| scheme.eq_ignore_ascii_case("https://"))
+    {
+        return Err(BimiError::Invalid(format!("Location {url:?} is not HTTPS")));
+    }
+
+    #[cfg(not(feature = "test_mode"))]
+    let bytes = {
+        let response = reqwest::Client::builder()
+            .user_agent(common::USER_AGENT)
+            .timeout(BIMI_FETCH_TIMEOUT)
+            .redirect(reqwest::redirect::Policy::limited(3))
+            .build()
+            .map(|client| client.get(url))
+            .map_err(|err| BimiError::Temporary(format!("Failed to fetch {url:?}: {err}")))?
+            .send()
+            .await
+            .map_err(|err| BimiError::Temporary(format!("Failed to fetch {url:?}: {err}")))?;
+        if !response.status().is_success() {
+            return Err(BimiError::Invalid(format!(
+                "Failed to fetch {url:?}: HTTP status {}",
+                response.status()
+            )));
+        }
+        response
+            .bytes_with_limit(max_size)
+            .await
+            .map_err(|err| BimiError::Temporary(format!("Failed to fetch {url:?}: {err}")))?
+            .ok_or_else(|| {
+                BimiError::Invalid(format!("File at {url:?} exceeds the maximum size"))
+            })?
+    };
+    #[cfg(feature = "test_mode")]
+    let bytes = BIMI_TEST_FILES
+        .lock()
+        .iter()
+        .find(|(test_url, _)| test_url == url)
+        .map(|(_, bytes)| bytes.clone())
+        .ok_or_else(|| BimiError::Invalid(format!("Failed to fetch {url:?}: HTTP status 404")))?;
+
+    if bytes.len() <= max_size {
+        Ok(bytes)
+    } else {
+        Err(BimiError::Invalid(format!(
+            "File at {url:?} exceeds the maximum size"
+        )))
+    }
+}
+
+pub fn write_bimi_headers(result: &BimiResult, headers: &mut Vec<u8>) {
+    if let BimiResult::Pass { record, logo, .. } = result {
+        headers.extend_from_slice(b"BIMI-Location: v=BIMI1;\r\n\tl=");
+        headers.extend_from_slice(record.location.as_deref().unwrap_or_default().as_bytes());
+        if let Some(authority) = &record.authority {
+            headers.extend_from_slice(b";\r\n\ta=");
+            headers.extend_from_slice(authority.as_bytes());
+        }
+        headers.extend_from_slice(b"\r\nBIMI-Indicator: ");
+        for (pos, chunk) in STANDARD.encode(&logo.svg).as_bytes().chunks(76).enumerate() {
+            if pos > 0 {
+                headers.extend_from_slice(b"\r\n\t");
+            }
+            headers.extend_from_slice(chunk);
+        }
+        headers.extend_from_slice(b"\r\n");
+    }
+}
+
+pub fn strip_bimi_headers(message: &Message<'_>, modifications: &mut Vec<Modification>) {
+    // Senders must not be able to inject their own indicators
+    for header in message.headers() {
+        if let HeaderName::Other(name) = &header.name
+            && (name.eq_ignore_ascii_case("BIMI-Location")
+                || name.eq_ignore_ascii_case("BIMI-Indicator"))
+        {
+            modifications.push(Modification::ChangeHeader {
+                index: 1,
+                name: name.to_string(),
+                value: String::new(),
+            });
+        }
+    }
+}
+
+fn parse_selector(value: &str) -> Option<&str> {
+    let mut tags = value.split(';').map(|tag| tag.trim());
+    if !tags.next()?.split_once('=').is_some_and(|(name, version)| {
+        name.trim() == "v" && version.trim().eq_ignore_ascii_case("BIMI1")
+    }) {
+        return None;
+    }
+
+    tags.filter_map(|tag| tag.split_once('='))
+        .find(|(name, _)| name.trim() == "s")
+        .map(|(_, selector)| selector.trim())
+        .filter(|selector| {
+            !selector.is_empty()
+                && selector
+                    .bytes()
+                    .all(|ch| ch.is_ascii_alphanumeric() || matches!(ch, b'-' | b'_' | b'.'))
+        })
+}
+
+pub fn parse_record(txt: &str) -> Option<BimiRecord> {
+    let mut tags = txt.split(';').map(|tag| tag.trim());
+    if !tags.next()?.split_once('=').is_some_and(|(name, version)| {
+        name.trim() == "v" && version.trim().eq_ignore_ascii_case("BIMI1")
+    }) {
+        return None;
+    }
+
+    let mut record = BimiRecord {
+        location: None,
+        authority: None,
+    };
+    for (name, value) in tags.filter_map(|tag| tag.split_once('=')) {
+        let name = name.trim();
+        let value = value.trim();
+        if !matches!(name, "l" | "a") {
+            continue;
+        }
+
+        // Locations are copied into the BIMI-Location header
+        if value
+            .chars()
+            .any(|ch| ch.is_control() || ch.is_whitespace())
+        {
+            return None;
+        }
+        let value = (!value.is_empty()).then(|| value.into());
+        if name == "l" {
+            record.location = value;
+        } else {
+            record.authority = value;
+        }
+    }
+
+    Some(record)
+}
+
+fn validate_svg(svg: &[u8]) -> Result<(), String> {
+    let mut reader = Reader::from_reader(svg);
+    let mut depth = 0usize;
+    let mut has_root = false;
+    let mut has_title = false;
+
+    loop {
+        let (tag, is_empty) = match reader
+            .read_event()
+            .map_err(|err| format!("Invalid SVG document: {err}"))?
+        {
+            Event::Start(tag) => (tag, false),
+            Event::Empty(tag) => (tag, true),
+            Event::End(_) => {
+                depth = depth.saturating_sub(1);
+                continue;
+            }
+            Event::DocType(_) => {
+                return Err("SVG document type declarations are not allowed".to_string());
+            }
+            Event::Eof => break,
+            _ => continue,
+        };
+
+        let name = tag.local_name();
+        let name = name.as_ref();
+        if SVG_FORBIDDEN_ELEMENTS.contains(&name) {
+            return Err(format!(
+                "SVG element {:?} is not allowed",
+                String::from_utf8_lossy(name)
+            ));
+        }
+        if depth == 0 && (has_root || name != b"svg") {
+            return Err("SVG document must have a single svg root element".to_string());
+        }
+        has_title |= depth == 1 && name == b"title";
+
+        let mut is_tiny_ps = false;
+        let mut is_version_1_2 = false;
+        for attr in tag.attributes() {
+            let attr = attr.map_err(|err| format!("Invalid SVG attribute: {err}"))?;
+            let key = attr.key.local_name();
+            let key = key.as_ref();
+            if key.starts_with(b"on") {
+                return Err("SVG event handlers are not allowed".to_string());
+            } else if key == b"href" && !attr.value.starts_with(b"#") {
+                return Err("SVG external references are not allowed".to_string());
+            } else if depth == 0 {
+                match key {
+                    b"baseProfile" => is_tiny_ps = attr.value.as_ref() == b"tiny-ps",
+                    b"version" => is_version_1_2 = attr.value.as_ref() == b"1.2",
+                    b"x" | b"y" => {
+                        return Err("SVG root element must not be positioned".to_string());
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        if depth == 0 {
+            if !is_tiny_ps || !is_version_1_2 {
+                return Err("SVG document is not in the SVG Tiny 1.2 PS profile".to_string());
+            }
+            has_root = true;
+        }
+        if !is_empty {
+            depth += 1;
+        }
+    }
+
+    if !has_root {
+        Err("SVG document is empty".to_string())
+    } else if !has_title {
+        Err("SVG document must have a title".to_string())
+    } else {
+        Ok(())
+    }
+}
+
+fn validate_vmc(
+    pem: &[u8],
+    svg: &[u8],
+    domain: &str,
+    config: &BimiAuthConfig,
+    now: u64,
+) -> Result<u64, String> {
+    let ders = CertificateDer::pem_slice_iter(pem)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|err| format!("Failed to parse mark certificate PEM: {err}"))?;
+    let certs = ders
+        .iter()
+        .map(|der| X509Certificate::from_der(der.as_ref()).map(|(_, cert)| cert))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|err| format!("Failed to parse mark certificate: {err}"))?;
+    let (Some(leaf_der), Some(leaf)) = (ders.first(), certs.first()) else {
+        return Err("Mark certificate chain is empty".to_string());
+    };
+
+    // The chain has to lead to a configured trust anchor and be issued for BIMI
+    let trust_anchors = config
+        .trust_anchors
+        .iter()
+        .filter_map(|cert| webpki::anchor_from_trusted_cert(cert).ok())
+        .collect::<Vec<_>>();
+    webpki::EndEntityCert::try_from(leaf_der)
+        .and_then(|cert| {
+            cert.verify_for_usage(
+                webpki::ALL_VERIFICATION_ALGS,
+                &trust_anchors,
+                &ders[1..],
+                UnixTime::since_unix_epoch(Duration::from_secs(now)),
+                webpki::KeyUsage::required(EKU_BIMI),
+                None,
+                None,
+            )
+            .map(|_| ())
+        })
+        .map_err(|err| format!("Mark certificate is not trusted: {err}"))?;
+
+    let logotype = leaf
+        .extensions()
+        .iter()
+        .find(|ext| ext.oid.to_id_string() == OID_LOGOTYPE)
+        .ok_or_else(|| "Mark certificate does not include a logotype".to_string())?;
+    if !logotype_matches(logotype.value, svg, config.max_size) {
+        return Err("Indicator does not match the mark certificate logotype".to_string());
+    }
+
+    let org_domain = psl::domain_str(domain).unwrap_or(domain);
+    if !leaf
+        .subject_alternative_name()
+        .ok()
+        .flatten()
+        .is_some_and(|san| {
+            san.value.general_names.iter().any(|name| {
+                matches!(name, GeneralName::DNSName(name)
+                    if name.eq_ignore_ascii_case(domain) || name.eq_ignore_ascii_case(org_domain))
+            })
+        })
+    {
+        return Err(format!("Mark certificate is not issued for {domain:?}"));
+    }
+
+    // Indicators expire with the first certificate in the chain to expire
+    Ok(certs
+        .iter()
+        .map(|cert| cert.validity().not_after.timestamp())
+        .min()
+        .unwrap_or_default() as u64)
+}
+
+// Matches the indicator against the images in a LogotypeExtn (RFC 3709), either
+// through their hashes or by comparing it with the embedded data URIs
+fn logotype_matches(logotype: &[u8], svg: &[u8], max_size: usize) -> bool {
+    let mut hashes = Vec::new();
+    let mut uris = Vec::new();
+    logotype_collect(logotype, &mut hashes, &mut uris);
+
+    let hash_matches = |data: &[u8]| {
+        hashes.iter().any(|(alg, value)| {
+            if *alg == OID_SHA256 {
+                Sha256::digest(data).as_slice() == *value
+            } else if *alg == OID_SHA1 {
+                Sha1::digest(data).as_slice() == *value
+            } else {
+                false
+            }
+        })
+    };
+
+    hash_matches(svg)
+        || uris.iter().any(|uri| {
+            logotype_data(uri, max_size)
+                .is_some_and(|(data, image)| hash_matches(&data) && image == svg)
+        })
+}
+
+fn logotype_collect<'x>(
+    mut der: &'x [u8],
+    hashes: &mut Vec<(&'x [u8], &'x [u8])>,
+    uris: &mut Vec<&'x [u8]>,
+) {
+    while let Some((tag, value, rest)) = der_read(der) {
+        match tag {
+            // HashAlgAndValue ::= SEQUENCE { hashAlg AlgorithmIdentifier, hashValue OCTET STRING }
+            0x30 => match hash_alg_and_value(value) {
+                Some(hash) => hashes.push(hash),
+                None => logotype_collect(value, hashes, uris),
+            },
+            // logotypeURI IA5String
+            0x16 => uris.push(value),
+            _ if tag & 0x20 != 0 => logotype_collect(value, hashes, uris),
+            _ => {}
+        }
+        der = rest;
+    }
+}
+
+fn hash_alg_and_value(der: &[u8]) -> Option<(&[u8], &[u8])> {
+    let (0x30, alg, rest) = der_read(der)? else {
+        return None;
+    };
+    let (0x06, oid, _) = der_read(alg)? else {
+        return None;
+    };
+    let (0x04, hash, rest) = der_read(rest)? else {
+        return None;
+    };
+    rest.is_empty().then_some((oid, hash))
+}
+
+fn der_read(der: &[u8]) -> Option<(u8, &[u8], &[u8])> {
+    let (&tag, der) = der.split_first()?;
+    let (&len, mut der) = der.split_first()?;
+    let len = if len & 0x80 == 0 {
+        len as usize
+    } else {
+        let num_bytes = (len & 0x7f) as usize;
+        if num_bytes == 0 || num_bytes > 4 || der.len() < num_bytes {
+            return None;
+        }
+        let (bytes, rest) = der.split_at(num_bytes);
+        der = rest;
+        bytes
+            .iter()
+            .fold(0usize, |len, byte| (len << 8) | *byte as usize)
+    };
+    (der.len() >= len).then(|| (tag, &der[..len], &der[len..]))
+}
+
+// Returns the raw and the decompressed contents of a base64 data URI
+fn logotype_data(uri: &[u8], max_size: usize) -> Option<(Vec<u8>, Vec<u8>)> {
+    let (header, data) = std::str::from_utf8(uri)
+        .ok()?
+        .strip_prefix("data:")?
+        .split_once(',')?;
+    if !header.ends_with(";base64") {
+        return None;
+    }
+    let data = STANDARD.decode(data.trim()).ok()?;
+
+    let image = if data.starts_with(&[0x1f, 0x8b]) {
+        let mut image = Vec::new();
+        GzDecoder::new(data.as_slice())
+            .take(max_size as u64 + 1)
+            .read_to_end(&mut image)
+            .ok()?;
+        (image.len() <= max_size).then_some(image)?
+    } else {
+        data.clone()
+    };
+
+    Some((data, image))
+}
@@ -7,7 +7,11 @@
 use super::{AuthResult, DkimSign};
 use crate::{
     core::{Session, SessionAddress, State},
-    inbound::milter::Modification,
+    inbound::{
+        autocrypt::autocrypt_sender,
+        bimi::{BimiLookup, strip_bimi_headers, write_bimi_headers},
+        milter::Modification,
+    },
     queue::{
         self, Message, MessageSource, MessageWrapper, QueueEnvelope, RCPT_SPAM_PAYLOAD,
         quota::HasQueueQuota,
@@ -73,6 +77,11 @@ impl<T: SessionStream> Session<T> {
         );
         let has_date_header = auth_message.has_date_header();
         let has_message_id_header = auth_message.has_message_id_header();
+        let autocrypt_from = if self.is_authenticated() {
+            autocrypt_sender(&parsed_message)
+        } else {
+            None
+        };
 
         // Loop detection
         let dc = &self.server.core.smtp.session.data;
@@ -250,7 +259,7 @@ impl<T: SessionStream> Session<T> {
 
         // Verify DMARC
         let is_report = !self.is_authenticated() && self.is_report();
-        let (dmarc_result, dmarc_policy) = match &self.data.spf_mail_from {
+        let (dmarc_result, dmarc_policy, bimi_result) = match &self.data.spf_mail_from {
             Some(spf_output) if dmarc.verify() => {
                 let time = Instant::now();
                 let dmarc_output =
@@ -311,6 +320,21 @@ impl<T: SessionStream> Session<T> {
                     Elapsed = time.elapsed(),
                 );
 
+                // Verify BIMI for senders with an enforcing DMARC policy
+                let bimi_result = if pass
+                    && ac.bimi.verify
+                    && matches!(
+                        dmarc_policy,
+                        dmarc::Policy::Quarantine | dmarc::Policy::Reject
+                    ) {
+                    self.server
+                        .verify_bimi(&parsed_message, dmarc_output.domain(), self.data.session_id)
+                        .await
+                        .into()
+                } else {
+                    None
+                };
+
                 // Send DMARC report
                 if dmarc_output.requested_reports() && !is_report {
                     self.send_dmarc_report(
@@ -332,9 +356,9 @@ impl<T: SessionStream> Session<T> {
                     };
                 }
 
-                (dmarc_result.into(), dmarc_policy.into())
+                (dmarc_result.into(), dmarc_policy.into(), bimi_result)
             }
-            _ => (None, None),
+            _ => (None, None, None),
         };
 
         // Analyze reports
@@ -396,6 +420,11 @@ impl<T: SessionStream> Session<T> {
             auth_results.write_header(&mut headers);
         }
 
+        // Add BIMI headers
+        if let Some(bimi_result) = &bimi_result {
+            write_bimi_headers(bimi_result, &mut headers);
+        }
+
         // Add Received-SPF header
         if let Some(spf_output) = &self.data.spf_mail_from
             && self
@@ -416,6 +445,7 @@ impl<T: SessionStream> Session<T> {
 
         // Run SPAM filter
         let mut train_spam = None;
+        let mut quarantine_reason = None;
         if self.server.core.spam.enabled
             && self
                 .server
@@ -430,12 +460,14 @@ impl<T: SessionStream> Session<T> {
                     (&arc_output).into(),
                     dmarc_result.as_ref(),
                     dmarc_policy.as_ref(),
+                    bimi_result.as_ref(),
                 )
                 .await
             {
                 SpamFilterAction::Allow(score) => {
                     // Add headers
                     headers.extend_from_slice(score.headers.as_bytes());
+                    quarantine_reason = score.quarantine;
                     train_spam = score.train_spam.map(|is_spam| {
                         (
                             is_spam,
@@ -461,7 +493,7 @@ impl<T: SessionStream> Session<T> {
                     return (b"550 5.7.1 Message rejected due to excessive spam score.\r\n"[..])
                         .into();
                 }
-                SpamFilterAction::Disabled => {}
+                SpamFilterAction::Quarantine | SpamFilterAction::Disabled => {}
             }
         }
 
@@ -494,6 +526,23 @@ impl<T: SessionStream> Session<T> {
             }
         };
 
+        // Quarantine requests are not message modifications
+        modifications.retain_mut(|m| {
+            if let Modification::Quarantine { reason } = m {
+                if quarantine_reason.is_none() {
+                    quarantine_reason = Some(std::mem::take(reason));
+                }
+                false
+            } else {
+                true
+            }
+        });
+
+        // Remove any indicators added by the sender
+        if ac.bimi.verify {
+            strip_bimi_headers(&parsed_message, &mut modifications);
+        }
+
         // Apply modifications
         let mut edited_message = if !modifications.is_empty() {
             self.data
@@ -640,6 +689,11 @@ impl<T: SessionStream> Session<T> {
             headers.extend_from_slice(b"\r\n");
         }
 
+        // Add Autocrypt header
+        if let Some(from) = &autocrypt_from {
+            self.write_autocrypt_header(from, &mut headers).await;
+        }
+
         // DKIM sign
         let raw_message = edited_message.as_deref().unwrap_or(raw_message.as_slice());
         if let Some(sign_with_domain) = self
@@ -677,6 +731,29 @@ impl<T: SessionStream> Session<T> {
         // Update size
         message.message.size = (raw_message.len() + headers.len()) as u64;
 
+        // Hold message in quarantine
+        if let Some(reason) = quarantine_reason {
+            let queue_id = message.queue_id;
+            return if message
+                .quarantine(
+                    Some(&headers),
+                    raw_message,
+                    reason,
+                    self.data.session_id,
+                    &self.server,
+                )
+                .await
+            {
+                self.state = State::Accepted(queue_id);
+                self.data.messages_sent += 1;
+                format!("250 2.0.0 Message queued with id {queue_id:x}.\r\n")
+                    .into_bytes()
+                    .into()
+            } else {
+                (b"451 4.3.5 Unable to accept message at this time.\r\n"[..]).into()
+            };
+        }
+
         // Verify queue quota
         if self.server.has_quota(&mut message).await {
             // Prepare webhook event
@@ -16,6 +16,7 @@ use mail_auth::{
 use smtp_proto::*;
 use std::{
     borrow::Cow,
+    fmt::Write,
     time::{Duration, Instant, SystemTime},
 };
 use trc::SmtpEvent;
@@ -284,9 +285,49 @@ impl<T: SessionStream> Session<T> {
             };
         }
 
+        // Limits (RFC 9422)
+        let rcpt_max = self
+            .server
+            .eval_if::<usize, _>(
+                &self.server.core.smtp.session.rcpt.max_recipients,
+                self,
+                self.data.session_id,
+            )
+            .await
+            .unwrap_or(100);
+        let mail_max = self
+            .server
+            .eval_if::<usize, _>(&dc.max_messages, self, self.data.session_id)
+            .await
+            .unwrap_or(10);
+
         // Generate response
         let mut buf = Vec::with_capacity(64);
         response.write(&mut buf).ok();
+        write_limits(&mut buf, rcpt_max, mail_max);
         self.write(&buf).await
     }
 }
+
+fn write_limits(buf: &mut Vec<u8>, rcpt_max: usize, mail_max: usize) {
+    let mut limits = String::new();
+    if rcpt_max > 0 {
+        let _ = write!(limits, " RCPTMAX={rcpt_max}");
+    }
+    if mail_max > 0 {
+        let _ = write!(limits, " MAILMAX={mail_max}");
+    }
+    if limits.is_empty() || !buf.ends_with(b"\r\n") {
+        return;
+    }
+
+    // Turn the last line into a continuation line and append LIMITS
+    let last_line = buf[..buf.len() - 2]
+        .windows(2)
+        .rposition(|ch| ch == b"\r\n")
+        .map_or(0, |pos| pos + 2);
+    if buf.get(last_line + 3) == Some(&b' ') {
+        buf[last_line + 3] = b'-';
+        buf.extend_from_slice(format!("250 LIMITS{limits}\r\n").as_bytes());
+    }
+}
@@ -9,7 +9,7 @@ use utils::HttpLimitResponse;
 
 use super::{Request, Response};
 
-pub(super) async fn send_mta_hook_request(
+pub(crate) async fn send_mta_hook_request(
     mta_hook: &MTAHook,
     request: Request,
 ) -> Result<Response, String> {
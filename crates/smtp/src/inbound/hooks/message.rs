@@ -59,53 +59,19 @@ impl<T: SessionStream> Session<T> {
                             Action::Discard => MtaHookEvent::ActionDiscard,
                             Action::Reject => MtaHookEvent::ActionReject,
                             Action::Quarantine => MtaHookEvent::ActionQuarantine,
+                            Action::Hold => MtaHookEvent::ActionHold,
+                            Action::Reroute => MtaHookEvent::ActionReroute,
                         }),
                         SpanId = self.data.session_id,
                         Id = mta_hook.id.to_string(),
                         Elapsed = time.elapsed(),
                     );
 
-                    let mut new_modifications = Vec::with_capacity(response.modifications.len());
-                    for modification in response.modifications {
-                        new_modifications.push(match modification {
-                            super::Modification::ChangeFrom { value, parameters } => {
-                                Modification::ChangeFrom {
-                                    sender: value,
-                                    args: flatten_parameters(parameters),
-                                }
-                            }
-                            super::Modification::AddRecipient { value, parameters } => {
-                                Modification::AddRcpt {
-                                    recipient: value,
-                                    args: flatten_parameters(parameters),
-                                }
-                            }
-                            super::Modification::DeleteRecipient { value } => {
-                                Modification::DeleteRcpt { recipient: value }
-                            }
-                            super::Modification::ReplaceContents { value } => {
-                                Modification::ReplaceBody {
-                                    value: value.as_bytes().to_vec(),
-                                }
-                            }
-                            super::Modification::AddHeader { name, value } => {
-                                Modification::AddHeader { name, value }
-                            }
-                            super::Modification::InsertHeader { index, name, value } => {
-                                Modification::InsertHeader { index, name, value }
-                            }
-                            super::Modification::ChangeHeader { index, name, value } => {
-                                Modification::ChangeHeader { index, name, value }
-                            }
-                            super::Modification::DeleteHeader { index, name } => {
-                                Modification::ChangeHeader {
-                                    index,
-                                    name,
-                                    value: String::new(),
-                                }
-                            }
-                        });
-                    }
+                    let new_modifications = response
+                        .modifications
+                        .into_iter()
+                        .map(Modification::from)
+                        .collect::<Vec<_>>();
 
                     if !modifications.is_empty() {
                         // The message body can only be replaced once, so we need to remove
@@ -123,15 +89,18 @@ impl<T: SessionStream> Session<T> {
                     }
 
                     let mut message = match response.action {
-                        Action::Accept => continue,
+                        // Holding and rerouting only apply at the delivery stage
+                        Action::Accept | Action::Hold | Action::Reroute => continue,
                         Action::Discard => FilterResponse::accept(),
                         Action::Reject => FilterResponse::reject(),
                         Action::Quarantine => {
-                            modifications.push(Modification::AddHeader {
-                                name: "X-Quarantine".into(),
-                                value: "true".into(),
+                            modifications.push(Modification::Quarantine {
+                                reason: response
+                                    .response
+                                    .and_then(|response| response.message)
+                                    .unwrap_or_else(|| format!("MTA hook {}", mta_hook.id)),
                             });
-                            FilterResponse::accept()
+                            continue;
                         }
                     };
 
@@ -179,7 +148,7 @@ impl<T: SessionStream> Session<T> {
         let request = Request {
             context: Context {
                 stage: stage.into(),
-                client: Client {
+                client: Some(Client {
                     ip: self.data.remote_ip.to_string(),
                     port: self.data.remote_port,
                     ptr: self
@@ -192,7 +161,7 @@ impl<T: SessionStream> Session<T> {
                     helo: (!self.data.helo_domain.is_empty())
                         .then(|| self.data.helo_domain.clone()),
                     active_connections: 1,
-                },
+                }),
                 sasl: self.authenticated_as().map(|name| Sasl {
                     login: name.into(),
                     method: None,
@@ -250,6 +219,41 @@ impl<T: SessionStream> Session<T> {
     }
 }
 
+impl From<super::Modification> for Modification {
+    fn from(value: super::Modification) -> Self {
+        match value {
+            super::Modification::ChangeFrom { value, parameters } => Modification::ChangeFrom {
+                sender: value,
+                args: flatten_parameters(parameters),
+            },
+            super::Modification::AddRecipient { value, parameters } => Modification::AddRcpt {
+                recipient: value,
+                args: flatten_parameters(parameters),
+            },
+            super::Modification::DeleteRecipient { value } => {
+                Modification::DeleteRcpt { recipient: value }
+            }
+            super::Modification::ReplaceContents { value } => Modification::ReplaceBody {
+                value: value.as_bytes().to_vec(),
+            },
+            super::Modification::AddHeader { name, value } => {
+                Modification::AddHeader { name, value }
+            }
+            super::Modification::InsertHeader { index, name, value } => {
+                Modification::InsertHeader { index, name, value }
+            }
+            super::Modification::ChangeHeader { index, name, value } => {
+                Modification::ChangeHeader { index, name, value }
+            }
+            super::Modification::DeleteHeader { index, name } => Modification::ChangeHeader {
+                index,
+                name,
+                value: String::new(),
+            },
+        }
+    }
+}
+
 fn flatten_parameters(parameters: AHashMap<String, Option<String>>) -> String {
     let mut arguments = String::new();
     for (key, value) in parameters {
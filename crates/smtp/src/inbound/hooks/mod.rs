@@ -23,7 +23,8 @@ pub struct Request {
 #[derive(Serialize, Deserialize)]
 pub struct Context {
     pub stage: Stage,
-    pub client: Client,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client: Option<Client>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sasl: Option<Sasl>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -97,6 +98,8 @@ pub enum Stage {
     Rcpt,
     #[serde(rename = "data")]
     Data,
+    #[serde(rename = "delivery")]
+    Delivery,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -123,17 +126,45 @@ pub struct Message {
     pub size: usize,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Default)]
 pub struct Response {
     pub action: Action,
     #[serde(default)]
     pub response: Option<SmtpResponse>,
     #[serde(default)]
     pub modifications: Vec<Modification>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub route: Option<String>,
+    #[serde(rename = "holdUntil")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hold_until: Option<u64>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub recipients: Vec<RecipientResponse>,
 }
 
+/// Per-recipient override of the response action, only
+/// honored at the delivery stage.
 #[derive(Serialize, Deserialize)]
+pub struct RecipientResponse {
+    pub address: String,
+    pub action: Action,
+    #[serde(default)]
+    pub response: Option<SmtpResponse>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub route: Option<String>,
+    #[serde(rename = "holdUntil")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hold_until: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize, Default, Clone, Copy, PartialEq, Eq)]
 pub enum Action {
+    #[default]
     #[serde(rename = "accept")]
     Accept,
     #[serde(rename = "discard")]
@@ -142,9 +173,13 @@ pub enum Action {
     Reject,
     #[serde(rename = "quarantine")]
     Quarantine,
+    #[serde(rename = "hold")]
+    Hold,
+    #[serde(rename = "reroute")]
+    Reroute,
 }
 
-#[derive(Serialize, Deserialize, Default)]
+#[derive(Serialize, Deserialize, Default, Clone)]
 pub struct SmtpResponse {
     #[serde(default)]
     pub status: Option<u16>,
@@ -202,6 +237,7 @@ impl From<common::config::smtp::session::Stage> for Stage {
             common::config::smtp::session::Stage::Mail => Stage::Mail,
             common::config::smtp::session::Stage::Rcpt => Stage::Rcpt,
             common::config::smtp::session::Stage::Data => Stage::Data,
+            common::config::smtp::session::Stage::Delivery => Stage::Delivery,
         }
     }
 }
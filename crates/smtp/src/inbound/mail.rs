@@ -228,18 +228,27 @@ impl<T: SessionStream> Session<T> {
             }
         }
 
-        // Make sure that the authenticated user is allowed to send from this address
+        // Make sure that the authenticated user is allowed to send from this address,
+        // internal sessions sending notifications such as MDNs use a null reverse-path
         match self.authenticated_as() {
             Some(authenticated_as)
-                if self
-                    .server
-                    .eval_if(
-                        &self.server.core.smtp.session.auth.must_match_sender,
-                        self,
-                        self.data.session_id,
-                    )
-                    .await
-                    .unwrap_or(true) =>
+                if !(self.data.is_internal
+                    && self
+                        .data
+                        .mail_from
+                        .as_ref()
+                        .unwrap()
+                        .address_lcase
+                        .is_empty())
+                    && self
+                        .server
+                        .eval_if(
+                            &self.server.core.smtp.session.auth.must_match_sender,
+                            self,
+                            self.data.session_id,
+                        )
+                        .await
+                        .unwrap_or(true) =>
             {
                 let address_lcase = self.data.mail_from.as_ref().unwrap().address_lcase.as_str();
                 if authenticated_as != address_lcase
@@ -13,6 +13,8 @@ use mail_auth::{
 };
 
 pub mod auth;
+pub mod autocrypt;
+pub mod bimi;
 pub mod data;
 pub mod ehlo;
 pub mod hooks;
@@ -5,7 +5,10 @@
  */
 
 use crate::{
-    core::{Session, SessionAddress},
+    core::{
+        Session, SessionAddress,
+        srs::{SrsAddress, SrsCodec},
+    },
     scripts::ScriptResult,
 };
 use common::{
@@ -18,7 +21,7 @@ use smtp_proto::{
     RCPT_NOTIFY_DELAY, RCPT_NOTIFY_FAILURE, RCPT_NOTIFY_NEVER, RCPT_NOTIFY_SUCCESS, RcptTo,
 };
 use std::borrow::Cow;
-use store::dispatch::lookup::KeyValue;
+use store::{dispatch::lookup::KeyValue, write::now};
 use trc::{SecurityEvent, SmtpEvent};
 use utils::DomainPart;
 
@@ -187,6 +190,88 @@ impl<T: SessionStream> Session<T> {
             }
         }
 
+        // Route SRS bounces back to the original sender
+        let mut is_srs_bounce = false;
+        let srs = &self.server.core.smtp.mail_auth.srs;
+        if !srs.keys.is_empty() {
+            let rcpt = self.data.rcpt_to.last().unwrap();
+            let mut srs_address = srs.srs_decode(&rcpt.address, now());
+            if srs_address != SrsAddress::NotSrs {
+                match self.server.domain(&rcpt.domain).await {
+                    Ok(Some(_)) => {}
+                    Ok(None) => {
+                        srs_address = SrsAddress::NotSrs;
+                    }
+                    Err(err) => {
+                        trc::error!(
+                            err.span_id(self.data.session_id)
+                                .caused_by(trc::location!())
+                                .details("Failed to verify address.")
+                        );
+
+                        self.data.rcpt_to.pop();
+                        return self
+                            .write(b"451 4.4.3 Unable to verify address at this time.\r\n")
+                            .await;
+                    }
+                }
+            }
+
+            match srs_address {
+                SrsAddress::Valid(address) => {
+                    let orig_addr = self.data.rcpt_to.pop().unwrap();
+                    let mut new_addr = SessionAddress::new(address);
+
+                    trc::event!(
+                        Smtp(SmtpEvent::SrsDecoded),
+                        SpanId = self.data.session_id,
+                        Details = orig_addr.address_lcase.clone(),
+                        To = new_addr.address_lcase.clone(),
+                    );
+
+                    if !self.data.rcpt_to.contains(&new_addr) {
+                        new_addr.dsn_info = orig_addr.dsn_info;
+                        new_addr.flags = orig_addr.flags;
+                        self.data.rcpt_to.push(new_addr);
+                        is_srs_bounce = true;
+                    } else {
+                        trc::event!(
+                            Smtp(SmtpEvent::RcptToDuplicate),
+                            SpanId = self.data.session_id,
+                            To = new_addr.address_lcase,
+                        );
+                        self.data.rcpt_oks += 1;
+                        return self.write(b"250 2.1.5 OK\r\n").await;
+                    }
+                }
+                SrsAddress::Invalid => {
+                    trc::event!(
+                        Smtp(SmtpEvent::SrsInvalid),
+                        SpanId = self.data.session_id,
+                        To = rcpt.address_lcase.clone(),
+                    );
+
+                    let rcpt_to = self.data.rcpt_to.pop().unwrap().address_lcase;
+                    return self
+                        .rcpt_error(b"550 5.1.1 Invalid SRS address.\r\n", rcpt_to)
+                        .await;
+                }
+                SrsAddress::Expired => {
+                    trc::event!(
+                        Smtp(SmtpEvent::SrsExpired),
+                        SpanId = self.data.session_id,
+                        To = rcpt.address_lcase.clone(),
+                    );
+
+                    let rcpt_to = self.data.rcpt_to.pop().unwrap().address_lcase;
+                    return self
+                        .rcpt_error(b"550 5.1.1 SRS address has expired.\r\n", rcpt_to)
+                        .await;
+                }
+                SrsAddress::NotSrs => {}
+            }
+        }
+
         // Verify address
         let rcpt = self.data.rcpt_to.last().unwrap();
         let mut rcpt_members = None;
@@ -231,11 +316,12 @@ impl<T: SessionStream> Session<T> {
                     .await;
             }
             Ok(RcptResolution::UnknownDomain) => {
-                if !self
-                    .server
-                    .eval_if(&rcpt_config.relay, self, self.data.session_id)
-                    .await
-                    .unwrap_or(false)
+                if !is_srs_bounce
+                    && !self
+                        .server
+                        .eval_if(&rcpt_config.relay, self, self.data.session_id)
+                        .await
+                        .unwrap_or(false)
                 {
                     trc::event!(
                         Smtp(SmtpEvent::RelayNotAllowed),
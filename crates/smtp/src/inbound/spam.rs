@@ -5,7 +5,10 @@
  */
 
 use crate::core::Session;
-use common::{config::mailstore::spamfilter::SpamFilterAction, network::SessionStream};
+use common::{
+    config::{mailstore::spamfilter::SpamFilterAction, smtp::resolver::BimiResult},
+    network::SessionStream,
+};
 use mail_auth::{ArcOutput, DkimOutput, DmarcResult, dmarc::Policy};
 use mail_parser::Message;
 use spam_filter::{
@@ -24,6 +27,7 @@ impl<T: SessionStream> Session<T> {
         arc_result: Option<&'x ArcOutput<'x>>,
         dmarc_result: Option<&'x DmarcResult>,
         dmarc_policy: Option<&'x Policy>,
+        bimi_result: Option<&'x BimiResult>,
     ) -> SpamFilterAction<SpamFilterScore> {
         let server = &self.server;
         let mut ctx = server.spam_filter_init(self.build_spam_input(
@@ -32,6 +36,7 @@ impl<T: SessionStream> Session<T> {
             arc_result,
             dmarc_result,
             dmarc_policy,
+            bimi_result,
         ));
 
         if !self.is_authenticated() {
@@ -50,6 +55,7 @@ impl<T: SessionStream> Session<T> {
         arc_result: Option<&'x ArcOutput>,
         dmarc_result: Option<&'x DmarcResult>,
         dmarc_policy: Option<&'x Policy>,
+        bimi_result: Option<&'x BimiResult>,
     ) -> SpamFilterInput<'x> {
         SpamFilterInput {
             message,
@@ -60,6 +66,7 @@ impl<T: SessionStream> Session<T> {
             dkim_result,
             dmarc_result,
             dmarc_policy,
+            bimi_result,
             iprev_result: self.data.iprev.as_ref(),
             remote_ip: self.data.remote_ip,
             ehlo_domain: self.data.helo_domain.as_str().into(),
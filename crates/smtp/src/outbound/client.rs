@@ -10,6 +10,11 @@ use crate::{
     queue::{Error, ErrorDetails, HostResponse, MessageWrapper, Status},
 };
 use base64::{Engine, engine::general_purpose};
+use common::{
+    Server,
+    config::smtp::queue::ConnectionStrategy,
+    network::pool::{IdleSmtpSession, IdleSmtpStream, SmtpSessionKey},
+};
 use directory::Credentials;
 use rustls::ClientConnection;
 use rustls_pki_types::ServerName;
@@ -21,8 +26,9 @@ use smtp_proto::{
     },
 };
 use std::{
+    borrow::Cow,
     net::{IpAddr, SocketAddr},
-    time::Duration,
+    time::{Duration, Instant},
 };
 use tokio::{
     io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt},
@@ -31,10 +37,14 @@ use tokio::{
 use tokio_rustls::{TlsConnector, client::TlsStream};
 use trc::DeliveryEvent;
 
+pub use common::network::pool::SmtpLimits;
+
 pub struct SmtpClient<T: AsyncRead + AsyncWrite> {
     pub stream: T,
     pub timeout: Duration,
     pub session_id: u64,
+    pub limits: SmtpLimits,
+    pub transactions: usize,
 }
 
 impl<T: AsyncRead + AsyncWrite + Unpin> SmtpClient<T> {
@@ -174,12 +184,18 @@ impl<T: AsyncRead + AsyncWrite + Unpin> SmtpClient<T> {
         bdat_cmd: &Option<String>,
         params: &SessionParams<'_>,
     ) -> Result<(), Status<HostResponse<Box<str>>, ErrorDetails>> {
-        match params
-            .server
-            .blob_store()
-            .get_blob(message.message.blob_hash.as_slice(), 0..usize::MAX)
-            .await
-        {
+        let raw_message = if let Some(contents) = params.contents {
+            Ok(Some(Cow::Borrowed(contents)))
+        } else {
+            params
+                .server
+                .blob_store()
+                .get_blob(message.message.blob_hash.as_slice(), 0..usize::MAX)
+                .await
+                .map(|blob| blob.map(Cow::Owned))
+        };
+
+        match raw_message {
             Ok(Some(raw_message)) => {
                 tokio::time::timeout(params.conn_strategy.timeout_data, async {
                     if let Some(bdat_cmd) = bdat_cmd {
@@ -289,6 +305,19 @@ impl<T: AsyncRead + AsyncWrite + Unpin> SmtpClient<T> {
         .await;
     }
 
+    pub async fn rset(&mut self) -> ClientResult<()> {
+        self.cmd(b"RSET\r\n").await?.assert_positive_completion()
+    }
+
+    /// Returns whether another mail transaction can be started on this session.
+    pub fn has_transactions_left(&self, max_transactions: usize) -> bool {
+        self.transactions < max_transactions
+            && self
+                .limits
+                .mail_max
+                .is_none_or(|mail_max| self.transactions < mail_max)
+    }
+
     pub async fn read_ehlo(&mut self) -> ClientResult<EhloResponse<String>> {
         let mut buf = vec![0u8; 8192];
         let mut buf_concat = Vec::with_capacity(0);
@@ -317,7 +346,14 @@ impl<T: AsyncRead + AsyncWrite + Unpin> SmtpClient<T> {
             };
 
             match EhloResponse::parse(&mut iter) {
-                Ok(reply) => return Ok(reply),
+                Ok(reply) => {
+                    self.limits = SmtpLimits::parse(if buf_concat.is_empty() {
+                        &buf[..br]
+                    } else {
+                        &buf_concat
+                    });
+                    return Ok(reply);
+                }
                 Err(err) => match err {
                     smtp_proto::Error::NeedsMoreData { .. } => {
                         if buf_concat.is_empty() {
@@ -520,6 +556,8 @@ impl SmtpClient<TcpStream> {
                     })?,
                 timeout: self.timeout,
                 session_id: self.session_id,
+                limits: SmtpLimits::default(),
+                transactions: self.transactions,
             })
         })
         .await
@@ -539,6 +577,8 @@ impl SmtpClient<TcpStream> {
                 stream: TcpStream::connect(remote_addr).await?,
                 timeout,
                 session_id,
+                limits: SmtpLimits::default(),
+                transactions: 0,
             })
         })
         .await
@@ -564,6 +604,8 @@ impl SmtpClient<TcpStream> {
                 stream: socket.connect(remote_addr).await?,
                 timeout,
                 session_id,
+                limits: SmtpLimits::default(),
+                transactions: 0,
             })
         })
         .await
@@ -675,6 +717,212 @@ pub enum StartTlsResult {
     },
 }
 
+/// Idle SMTP sessions that can be reused to deliver subsequent messages
+/// to the same destination.
+pub trait SessionPool: Sync + Send {
+    fn acquire_session(
+        &self,
+        key: &SmtpSessionKey,
+        conn_strategy: &ConnectionStrategy,
+        session_id: u64,
+    ) -> impl Future<Output = Option<IdleSession>> + Send;
+
+    fn release_session(
+        &self,
+        key: SmtpSessionKey,
+        client: PooledClient,
+        capabilities: EhloResponse<String>,
+        conn_strategy: &ConnectionStrategy,
+    ) -> impl Future<Output = ()> + Send;
+
+    fn reap_idle_sessions(&self) -> impl Future<Output = ()> + Send;
+}
+
+pub struct IdleSession {
+    pub client: PooledClient,
+    pub capabilities: EhloResponse<String>,
+}
+
+#[allow(clippy::large_enum_variant)]
+pub enum PooledClient {
+    Plain(SmtpClient<TcpStream>),
+    Tls(SmtpClient<TlsStream<TcpStream>>),
+}
+
+impl SessionPool for Server {
+    /// Obtains an idle session for the destination and resets it with RSET.
+    async fn acquire_session(
+        &self,
+        key: &SmtpSessionKey,
+        conn_strategy: &ConnectionStrategy,
+        session_id: u64,
+    ) -> Option<IdleSession> {
+        loop {
+            let session = self.inner.data.smtp_sessions.take(key)?;
+            let expired = session.expires <= Instant::now();
+            let capabilities = session.capabilities;
+            let mut client =
+                PooledClient::from_idle(session.stream, session.limits, session.transactions);
+
+            if expired {
+                client.quit().await;
+                continue;
+            }
+
+            match client.reset(session_id, conn_strategy.timeout_mail).await {
+                Ok(_) => {
+                    trc::event!(
+                        Delivery(DeliveryEvent::SessionReused),
+                        SpanId = session_id,
+                        Hostname = key.hostname.to_string(),
+                        RemoteIp = key.remote_ip,
+                        RemotePort = key.remote_port,
+                        Total = client.transactions(),
+                    );
+
+                    return Some(IdleSession {
+                        client,
+                        capabilities,
+                    });
+                }
+                Err(err) => {
+                    trc::event!(
+                        Delivery(DeliveryEvent::SessionResetFailed),
+                        SpanId = session_id,
+                        Hostname = key.hostname.to_string(),
+                        RemoteIp = key.remote_ip,
+                        RemotePort = key.remote_port,
+                        CausedBy = from_mail_send_error(&err),
+                    );
+                }
+            }
+        }
+    }
+
+    /// Returns a session to the pool, or closes it if the destination already has
+    /// enough idle sessions.
+    async fn release_session(
+        &self,
+        key: SmtpSessionKey,
+        client: PooledClient,
+        capabilities: EhloResponse<String>,
+        conn_strategy: &ConnectionStrategy,
+    ) {
+        let session = client.into_idle(
+            capabilities,
+            Instant::now() + conn_strategy.reuse_idle_timeout,
+        );
+
+        if let Some(session) =
+            self.inner
+                .data
+                .smtp_sessions
+                .put(key, session, conn_strategy.reuse_max_idle)
+        {
+            PooledClient::from_idle(session.stream, session.limits, session.transactions)
+                .quit()
+                .await;
+        }
+    }
+
+    /// Closes all sessions that have been idle for longer than their timeout.
+    async fn reap_idle_sessions(&self) {
+        for session in self.inner.data.smtp_sessions.take_expired() {
+            PooledClient::from_idle(session.stream, session.limits, session.transactions)
+                .quit()
+                .await;
+        }
+    }
+}
+
+impl PooledClient {
+    async fn reset(&mut self, session_id: u64, timeout: Duration) -> ClientResult<()> {
+        match self {
+            PooledClient::Plain(client) => {
+                client.session_id = session_id;
+                client.timeout = timeout;
+                client.rset().await
+            }
+            PooledClient::Tls(client) => {
+                client.session_id = session_id;
+                client.timeout = timeout;
+                client.rset().await
+            }
+        }
+    }
+
+    pub async fn quit(self) {
+        match self {
+            PooledClient::Plain(client) => client.quit().await,
+            PooledClient::Tls(client) => client.quit().await,
+        }
+    }
+
+    pub fn transactions(&self) -> usize {
+        match self {
+            PooledClient::Plain(client) => client.transactions,
+            PooledClient::Tls(client) => client.transactions,
+        }
+    }
+
+    fn into_idle(self, capabilities: EhloResponse<String>, expires: Instant) -> IdleSmtpSession {
+        let (stream, limits, transactions) = match self {
+            PooledClient::Plain(client) => (
+                IdleSmtpStream::Plain(client.stream),
+                client.limits,
+                client.transactions,
+            ),
+            PooledClient::Tls(client) => (
+                IdleSmtpStream::Tls(Box::new(client.stream)),
+                client.limits,
+                client.transactions,
+            ),
+        };
+
+        IdleSmtpSession {
+            stream,
+            capabilities,
+            limits,
+            transactions,
+            expires,
+        }
+    }
+
+    fn from_idle(stream: IdleSmtpStream, limits: SmtpLimits, transactions: usize) -> Self {
+        // The timeout and session id are replaced on reuse, these are only
+        // used when closing the session.
+        let timeout = Duration::from_secs(10);
+        match stream {
+            IdleSmtpStream::Plain(stream) => PooledClient::Plain(SmtpClient {
+                stream,
+                timeout,
+                session_id: 0,
+                limits,
+                transactions,
+            }),
+            IdleSmtpStream::Tls(stream) => PooledClient::Tls(SmtpClient {
+                stream: *stream,
+                timeout,
+                session_id: 0,
+                limits,
+                transactions,
+            }),
+        }
+    }
+}
+
+impl From<SmtpClient<TcpStream>> for PooledClient {
+    fn from(client: SmtpClient<TcpStream>) -> Self {
+        PooledClient::Plain(client)
+    }
+}
+
+impl From<SmtpClient<TlsStream<TcpStream>>> for PooledClient {
+    fn from(client: SmtpClient<TlsStream<TcpStream>>) -> Self {
+        PooledClient::Tls(client)
+    }
+}
+
 pub(crate) trait BoxResponse {
     fn into_box(self) -> Response<Box<str>>;
 }
@@ -5,15 +5,18 @@
  */
 
 use super::{NextHop, lookup::ToNextHop, mta_sts, session::SessionParams};
+use crate::core::srs::SenderRewriting;
 use crate::outbound::DeliveryResult;
 use crate::outbound::client::{
-    SmtpClient, from_error_details, from_error_status, from_mail_send_error,
+    PooledClient, SessionPool, SmtpClient, from_error_details, from_error_status,
+    from_mail_send_error,
 };
 use crate::outbound::dane::dnssec::TlsaLookup;
 use crate::outbound::error::ClientError;
-use crate::outbound::lookup::{DnsLookup, SourceIp};
+use crate::outbound::lookup::DnsLookup;
 use crate::outbound::mta_sts::lookup::MtaStsLookup;
 use crate::outbound::mta_sts::verify::VerifyPolicy;
+use crate::outbound::relay::RelayHosts;
 use crate::outbound::{client::StartTlsResult, dane::verify::TlsaVerify};
 use crate::queue::dsn::SendDsn;
 use crate::queue::spool::SmtpSpool;
@@ -28,6 +31,7 @@ use common::Server;
 use common::config::smtp::queue::RoutingStrategy;
 use common::config::{server::ServerProtocol, smtp::report::AggregateFrequency};
 use common::ipc::{PolicyType, QueueEvent, QueueEventStatus, TlsEvent};
+use common::network::pool::SmtpSessionKey;
 use compact_str::ToCompactString;
 use mail_auth::{
     mta_sts::TlsRpt,
@@ -215,10 +219,10 @@ impl QueuedMessage {
             }
         }
 
-        // Group recipients by route
+        // Resolve the route of each due recipient
         let queue_config = &server.core.smtp.queue;
         let now_ = now();
-        let mut routes: AHashMap<(&str, &RoutingStrategy), Vec<usize>> = AHashMap::new();
+        let mut rcpt_routes: Vec<(usize, &RoutingStrategy)> = Vec::new();
         for (rcpt_idx, rcpt) in message.message.recipients.iter().enumerate() {
             if matches!(
                 &rcpt.status,
@@ -235,15 +239,26 @@ impl QueuedMessage {
                     message.span_id,
                 );
 
-                routes
-                    .entry((rcpt.domain_part(), route))
-                    .or_default()
-                    .push(rcpt_idx);
+                rcpt_routes.push((rcpt_idx, route));
             }
         }
 
-        let no_ip = IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0));
+        // Run delivery hooks
         let mut delivery_results: Vec<DeliveryResult> = Vec::new();
+        let contents = message
+            .run_delivery_hooks(&server, &mut rcpt_routes, &mut delivery_results)
+            .await;
+
+        // Group recipients by route
+        let mut routes: AHashMap<(&str, &RoutingStrategy), Vec<usize>> = AHashMap::new();
+        for (rcpt_idx, route) in rcpt_routes {
+            routes
+                .entry((message.message.recipients[rcpt_idx].domain_part(), route))
+                .or_default()
+                .push(rcpt_idx);
+        }
+
+        let no_ip = IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0));
         'next_route: for ((domain, route), rcpt_idxs) in routes {
             trc::event!(
                 Delivery(DeliveryEvent::DomainDeliveryStart),
@@ -284,7 +299,7 @@ impl QueuedMessage {
                 }
                 RoutingStrategy::Mx(mx_config) => (Vec::with_capacity(0), Some(mx_config), true),
                 RoutingStrategy::Relay(relay_config) => (
-                    vec![NextHop::Relay(relay_config)],
+                    server.relay_hosts(relay_config, message.span_id),
                     None,
                     relay_config.protocol == ServerProtocol::Smtp,
                 ),
@@ -299,6 +314,16 @@ impl QueuedMessage {
                 message.span_id,
             );
 
+            // Rewrite the envelope sender of forwarded messages
+            let srs_return_path = if is_smtp {
+                server.srs_return_path(&envelope, message.span_id).await
+            } else {
+                None
+            };
+            let return_path = srs_return_path
+                .as_deref()
+                .unwrap_or(message.message.return_path.as_ref());
+
             // Obtain TLS reporting
             let tls_report =
                 if is_smtp && mx_config.is_some() && (message.message.flags & FROM_REPORT == 0) {
@@ -569,7 +594,16 @@ impl QueuedMessage {
 
             // Try delivering message
             let mut last_status: Status<HostResponse<Box<str>>, ErrorDetails> = Status::Scheduled;
-            'next_host: for remote_host in &remote_hosts {
+            let mut failed_relay = None;
+            'next_host: for (host_idx, remote_host) in remote_hosts.iter().enumerate() {
+                // Mark the previous relay host as unhealthy
+                if let Some(failed_relay) = failed_relay.take() {
+                    server.relay_host_failed(failed_relay, &last_status, message.span_id);
+                }
+                if matches!(remote_host, NextHop::Relay { .. }) {
+                    failed_relay = Some(remote_host);
+                }
+
                 // Validate MTA-STS
                 envelope.mx = remote_host.hostname();
                 if let Some(mta_sts_policy) = &mta_sts_policy {
@@ -873,8 +907,107 @@ impl QueuedMessage {
                         message.span_id,
                     );
 
-                    // Set source IP, if any
-                    let ip_host = conn_strategy.source_ip(remote_ip.is_ipv4());
+                    // Set source IP, if any, honoring IP warm-up schedules
+                    let ip_host = match server
+                        .warm_up_source_ip(conn_strategy, remote_ip, envelope.mx, message.span_id)
+                        .await
+                    {
+                        Ok(ip_host) => ip_host,
+                        Err(retry_at) => {
+                            delivery_results
+                                .push(DeliveryResult::rate_limited(rcpt_idxs, retry_at));
+                            continue 'next_route;
+                        }
+                    };
+
+                    // Obtain session parameters
+                    let results_start = delivery_results.len();
+                    let local_hostname = ip_host
+                        .and_then(|ip| ip.host.as_deref())
+                        .or(conn_strategy.ehlo_hostname.as_deref())
+                        .unwrap_or(server.core.network.server_name.as_str());
+                    let is_strict_tls = tls_strategy.is_tls_required()
+                        || (message.message.flags & MAIL_REQUIRETLS) != 0
+                        || mta_sts_policy.is_some()
+                        || dane_policy.is_some();
+
+                    // Reuse an idle session to the same destination, if available
+                    let reuse_key = (conn_strategy.reuse_max_idle > 0).then(|| SmtpSessionKey {
+                        remote_ip,
+                        remote_port: remote_host.port(),
+                        local_ip: ip_host.map_or(no_ip, |ip_host| ip_host.ip),
+                        hostname: envelope.mx.into(),
+                        credentials: remote_host.credentials().cloned(),
+                        is_smtp: remote_host.is_smtp(),
+                        is_strict_tls,
+                        allow_invalid_certs: tls_strategy.allow_invalid_certs
+                            || remote_host.allow_invalid_certs(),
+                    });
+                    if let Some(key) = &reuse_key
+                        && let Some(session) = server
+                            .acquire_session(key, conn_strategy, message.span_id)
+                            .await
+                    {
+                        envelope.local_ip = key.local_ip;
+                        let params = SessionParams {
+                            session_id: message.span_id,
+                            server: &server,
+                            credentials: None,
+                            is_smtp: remote_host.is_smtp(),
+                            hostname: envelope.mx,
+                            return_path,
+                            local_hostname,
+                            conn_strategy,
+                            capabilities: Some(session.capabilities),
+                            reuse: Some(key.clone()),
+                            contents: contents.as_deref(),
+                        };
+                        match session.client {
+                            PooledClient::Plain(smtp_client) => {
+                                message
+                                    .deliver(
+                                        smtp_client,
+                                        rcpt_idxs.clone(),
+                                        &mut delivery_results,
+                                        params,
+                                    )
+                                    .await
+                            }
+                            PooledClient::Tls(smtp_client) => {
+                                message
+                                    .deliver(
+                                        smtp_client,
+                                        rcpt_idxs.clone(),
+                                        &mut delivery_results,
+                                        params,
+                                    )
+                                    .await
+                            }
+                        }
+
+                        // Fail over to the next relay host if the message was not accepted
+                        if let Some(status) = server.relay_host_result(
+                            remote_host,
+                            DeliveryResult::failover_status(&delivery_results[results_start..]),
+                            host_idx + 1 < remote_hosts.len(),
+                            message.span_id,
+                        ) {
+                            last_status = status;
+                            delivery_results.truncate(results_start);
+                            continue 'next_host;
+                        }
+
+                        // Count successful deliveries towards the IP warm-up limits
+                        if let Some(ip_host) = ip_host
+                            && DeliveryResult::is_delivered(&delivery_results[results_start..])
+                        {
+                            server
+                                .warm_up_delivered(ip_host, envelope.mx, message.span_id)
+                                .await;
+                        }
+
+                        continue 'next_route;
+                    }
 
                     // Connect
                     let time = Instant::now();
@@ -928,27 +1061,21 @@ impl QueuedMessage {
                         }
                     };
 
-                    // Obtain session parameters
-                    let local_hostname = ip_host
-                        .and_then(|ip| ip.host.as_deref())
-                        .or(conn_strategy.ehlo_hostname.as_deref())
-                        .unwrap_or(server.core.network.server_name.as_str());
                     let mut params = SessionParams {
                         session_id: message.span_id,
                         server: &server,
                         credentials: remote_host.credentials(),
                         is_smtp: remote_host.is_smtp(),
                         hostname: envelope.mx,
+                        return_path,
                         local_hostname,
                         conn_strategy,
                         capabilities: None,
+                        reuse: reuse_key,
+                        contents: contents.as_deref(),
                     };
 
                     // Prepare TLS connector
-                    let is_strict_tls = tls_strategy.is_tls_required()
-                        || (message.message.flags & MAIL_REQUIRETLS) != 0
-                        || mta_sts_policy.is_some()
-                        || dane_policy.is_some();
                     // As per RFC7671 Section 5.1, DANE-EE(3) allows name mismatch
                     let tls_connector = if tls_strategy.allow_invalid_certs
                         || remote_host.allow_invalid_certs()
@@ -1088,7 +1215,7 @@ impl QueuedMessage {
                                     message
                                         .deliver(
                                             smtp_client,
-                                            rcpt_idxs,
+                                            rcpt_idxs.clone(),
                                             &mut delivery_results,
                                             params,
                                         )
@@ -1147,7 +1274,7 @@ impl QueuedMessage {
                                         message
                                             .deliver(
                                                 smtp_client,
-                                                rcpt_idxs,
+                                                rcpt_idxs.clone(),
                                                 &mut delivery_results,
                                                 params,
                                             )
@@ -1204,7 +1331,12 @@ impl QueuedMessage {
                             );
 
                             message
-                                .deliver(smtp_client, rcpt_idxs, &mut delivery_results, params)
+                                .deliver(
+                                    smtp_client,
+                                    rcpt_idxs.clone(),
+                                    &mut delivery_results,
+                                    params,
+                                )
                                 .await
                         }
                     } else {
@@ -1244,15 +1376,46 @@ impl QueuedMessage {
 
                         // Deliver message
                         message
-                            .deliver(smtp_client, rcpt_idxs, &mut delivery_results, params)
+                            .deliver(
+                                smtp_client,
+                                rcpt_idxs.clone(),
+                                &mut delivery_results,
+                                params,
+                            )
                             .await
                     }
 
+                    // Fail over to the next relay host if the message was not accepted
+                    if let Some(status) = server.relay_host_result(
+                        remote_host,
+                        DeliveryResult::failover_status(&delivery_results[results_start..]),
+                        host_idx + 1 < remote_hosts.len(),
+                        message.span_id,
+                    ) {
+                        last_status = status;
+                        delivery_results.truncate(results_start);
+                        continue 'next_host;
+                    }
+
+                    // Count successful deliveries towards the IP warm-up limits
+                    if let Some(ip_host) = ip_host
+                        && DeliveryResult::is_delivered(&delivery_results[results_start..])
+                    {
+                        server
+                            .warm_up_delivered(ip_host, envelope.mx, message.span_id)
+                            .await;
+                    }
+
                     // Continue with the next domain/route
                     continue 'next_route;
                 }
             }
 
+            // Mark the last relay host as unhealthy
+            if let Some(failed_relay) = failed_relay {
+                server.relay_host_failed(failed_relay, &last_status, message.span_id);
+            }
+
             // Update status
             delivery_results.push(DeliveryResult::domain(last_status, rcpt_idxs));
         }
@@ -1278,6 +1441,13 @@ impl QueuedMessage {
                         message.set_rcpt_rate_limit(rcpt_idx, retry_at);
                     }
                 }
+                DeliveryResult::Held {
+                    status,
+                    rcpt_idx,
+                    retry_at,
+                } => {
+                    message.set_rcpt_hold(status, rcpt_idx, retry_at);
+                }
             }
         }
 
@@ -1423,4 +1593,15 @@ impl MessageWrapper {
             details: Error::RateLimited,
         });
     }
+
+    pub fn set_rcpt_hold(
+        &mut self,
+        status: Status<HostResponse<Box<str>>, ErrorDetails>,
+        rcpt_idx: usize,
+        retry_at: u64,
+    ) {
+        let rcpt = &mut self.message.recipients[rcpt_idx];
+        rcpt.retry.due = retry_at;
+        rcpt.status = status;
+    }
 }
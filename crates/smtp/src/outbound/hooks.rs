@@ -0,0 +1,366 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use super::DeliveryResult;
+use crate::{
+    inbound::{
+        hooks::{
+            self, Action, Address, Context, Envelope, Protocol, Queue, Request, SmtpResponse,
+            client::send_mta_hook_request,
+        },
+        milter::{Modification, message::apply_content_modifications},
+    },
+    queue::{
+        Error, ErrorDetails, HostResponse, MessageWrapper, QueueEnvelope, Status,
+        UnexpectedResponse,
+    },
+};
+use common::{
+    DAEMON_NAME, Server,
+    config::smtp::{queue::RoutingStrategy, session::Stage},
+};
+use mail_auth::AuthenticatedMessage;
+use smtp_proto::Response;
+use std::time::Instant;
+use trc::MtaHookEvent;
+
+impl MessageWrapper {
+    /// Runs the MTA hooks configured for the delivery stage on the recipients
+    /// about to be delivered remotely. Recipients rejected, held or discarded by a
+    /// hook are removed from `routes` and their outcome is added to `results`.
+    /// Returns the rewritten message, if any hook modified its contents.
+    pub(super) async fn run_delivery_hooks<'x>(
+        &self,
+        server: &'x Server,
+        routes: &mut Vec<(usize, &'x RoutingStrategy)>,
+        results: &mut Vec<DeliveryResult>,
+    ) -> Option<Vec<u8>> {
+        let mut raw_message: Option<Vec<u8>> = None;
+        let mut is_rewritten = false;
+
+        for mta_hook in &server.core.smtp.session.hooks {
+            if !mta_hook.run_on_stage.contains(&Stage::Delivery) {
+                continue;
+            }
+
+            let rcpt_idxs = routes
+                .iter()
+                .filter(|(_, route)| !matches!(route, RoutingStrategy::Local))
+                .map(|(rcpt_idx, _)| *rcpt_idx)
+                .collect::<Vec<_>>();
+            let Some(&first_idx) = rcpt_idxs.first() else {
+                break;
+            };
+            if !server
+                .eval_if(
+                    &mta_hook.enable,
+                    &QueueEnvelope::new(&self.message, &self.message.recipients[first_idx]),
+                    self.span_id,
+                )
+                .await
+                .unwrap_or(false)
+            {
+                continue;
+            }
+
+            // Fetch message
+            if raw_message.is_none() {
+                match server
+                    .blob_store()
+                    .get_blob(self.message.blob_hash.as_slice(), 0..usize::MAX)
+                    .await
+                {
+                    Ok(Some(raw)) => {
+                        raw_message = Some(raw);
+                    }
+                    Ok(None) => {
+                        trc::event!(
+                            Queue(trc::QueueEvent::BlobNotFound),
+                            SpanId = self.span_id,
+                            BlobId = self.message.blob_hash.to_hex(),
+                            CausedBy = trc::location!()
+                        );
+                        return None;
+                    }
+                    Err(err) => {
+                        trc::error!(
+                            err.span_id(self.span_id)
+                                .details("Failed to fetch blobId")
+                                .caused_by(trc::location!())
+                        );
+                        return None;
+                    }
+                }
+            }
+            let Some(message) = raw_message
+                .as_deref()
+                .and_then(|raw| AuthenticatedMessage::parse_with_opts(raw, true))
+            else {
+                trc::event!(
+                    MtaHook(MtaHookEvent::Error),
+                    SpanId = self.span_id,
+                    Id = mta_hook.id.to_string(),
+                    Reason = "Failed to parse message.",
+                );
+                return None;
+            };
+
+            // Build request
+            let request = Request {
+                context: Context {
+                    stage: Stage::Delivery.into(),
+                    client: None,
+                    sasl: None,
+                    tls: None,
+                    server: hooks::Server {
+                        name: Some(DAEMON_NAME.into()),
+                        port: self.message.received_via_port,
+                        ip: None,
+                    },
+                    queue: Some(Queue {
+                        id: format!("{:x}", self.queue_id),
+                    }),
+                    protocol: Protocol { version: 1 },
+                },
+                envelope: Some(Envelope {
+                    from: Address {
+                        address: self.message.return_path.to_string(),
+                        parameters: None,
+                    },
+                    to: rcpt_idxs
+                        .iter()
+                        .map(|rcpt_idx| Address {
+                            address: self.message.recipients[*rcpt_idx].address.to_string(),
+                            parameters: None,
+                        })
+                        .collect(),
+                }),
+                message: Some(hooks::Message {
+                    headers: message
+                        .raw_parsed_headers()
+                        .iter()
+                        .map(|(k, v)| {
+                            (
+                                String::from_utf8_lossy(k).into_owned(),
+                                String::from_utf8_lossy(v).into_owned(),
+                            )
+                        })
+                        .collect(),
+                    server_headers: vec![],
+                    contents: String::from_utf8_lossy(message.raw_body()).into_owned(),
+                    size: message.raw_message().len(),
+                }),
+            };
+
+            let time = Instant::now();
+            let response = match send_mta_hook_request(mta_hook, request).await {
+                Ok(response) => response,
+                Err(err) => {
+                    trc::event!(
+                        MtaHook(MtaHookEvent::Error),
+                        SpanId = self.span_id,
+                        Id = mta_hook.id.to_string(),
+                        Reason = err,
+                        Elapsed = time.elapsed(),
+                    );
+
+                    if mta_hook.tempfail_on_error {
+                        for rcpt_idx in rcpt_idxs {
+                            results.push(DeliveryResult::account(
+                                Status::TemporaryFailure(ErrorDetails {
+                                    entity: "localhost".into(),
+                                    details: Error::Io("MTA hook request failed.".into()),
+                                }),
+                                rcpt_idx,
+                            ));
+                        }
+                        routes.retain(|(_, route)| matches!(route, RoutingStrategy::Local));
+                    }
+                    continue;
+                }
+            };
+
+            trc::event!(
+                MtaHook(action_event(response.action)),
+                SpanId = self.span_id,
+                Id = mta_hook.id.to_string(),
+                Elapsed = time.elapsed(),
+            );
+
+            // Apply the response action to each recipient, unless overridden
+            let mut removed = Vec::new();
+            for rcpt_idx in rcpt_idxs {
+                let rcpt = &self.message.recipients[rcpt_idx];
+                let (action, smtp_response, route, hold_until) = if let Some(rcpt_response) =
+                    response
+                        .recipients
+                        .iter()
+                        .find(|r| r.address.eq_ignore_ascii_case(&rcpt.address))
+                {
+                    trc::event!(
+                        MtaHook(action_event(rcpt_response.action)),
+                        SpanId = self.span_id,
+                        Id = mta_hook.id.to_string(),
+                        To = rcpt_response.address.clone(),
+                    );
+
+                    (
+                        rcpt_response.action,
+                        rcpt_response.response.as_ref(),
+                        rcpt_response.route.as_deref(),
+                        rcpt_response.hold_until,
+                    )
+                } else {
+                    (
+                        response.action,
+                        response.response.as_ref(),
+                        response.route.as_deref(),
+                        response.hold_until,
+                    )
+                };
+
+                match action {
+                    Action::Accept => {}
+                    Action::Reroute => {
+                        if let Some(route) = route {
+                            let route = server.get_route_or_default(route, self.span_id);
+                            if let Some(entry) = routes.iter_mut().find(|(idx, _)| *idx == rcpt_idx)
+                            {
+                                entry.1 = route;
+                            }
+                        }
+                    }
+                    Action::Discard => {
+                        results.push(DeliveryResult::account(discarded(smtp_response), rcpt_idx));
+                        removed.push(rcpt_idx);
+                    }
+                    Action::Reject => {
+                        results.push(DeliveryResult::account(
+                            Status::PermanentFailure(ErrorDetails {
+                                entity: "localhost".into(),
+                                details: Error::UnexpectedResponse(UnexpectedResponse {
+                                    command: "MTA hook".into(),
+                                    response: hook_response(
+                                        smtp_response,
+                                        550,
+                                        [5, 7, 1],
+                                        "Delivery rejected by policy",
+                                    ),
+                                }),
+                            }),
+                            rcpt_idx,
+                        ));
+                        removed.push(rcpt_idx);
+                    }
+                    Action::Hold | Action::Quarantine => {
+                        let status = Status::TemporaryFailure(ErrorDetails {
+                            entity: "localhost".into(),
+                            details: Error::UnexpectedResponse(UnexpectedResponse {
+                                command: "MTA hook".into(),
+                                response: hook_response(
+                                    smtp_response,
+                                    451,
+                                    [4, 7, 1],
+                                    "Delivery held by policy",
+                                ),
+                            }),
+                        });
+                        results.push(if let Some(hold_until) = hold_until {
+                            DeliveryResult::held(status, rcpt_idx, hold_until)
+                        } else {
+                            DeliveryResult::account(status, rcpt_idx)
+                        });
+                        removed.push(rcpt_idx);
+                    }
+                }
+            }
+
+            // Apply modifications
+            let mut modifications = Vec::with_capacity(response.modifications.len());
+            for modification in response.modifications {
+                match Modification::from(modification) {
+                    Modification::DeleteRcpt { recipient } => {
+                        if let Some((rcpt_idx, _)) = routes.iter().find(|(idx, route)| {
+                            !matches!(route, RoutingStrategy::Local)
+                                && !removed.contains(idx)
+                                && self.message.recipients[*idx]
+                                    .address
+                                    .eq_ignore_ascii_case(&recipient)
+                        }) {
+                            results.push(DeliveryResult::account(discarded(None), *rcpt_idx));
+                            removed.push(*rcpt_idx);
+                        }
+                    }
+                    Modification::ChangeFrom { .. } | Modification::AddRcpt { .. } => {
+                        // Envelope changes are not supported once the message is queued
+                    }
+                    modification => {
+                        modifications.push(modification);
+                    }
+                }
+            }
+            if !removed.is_empty() {
+                routes.retain(|(rcpt_idx, _)| !removed.contains(rcpt_idx));
+            }
+            let new_message = if !modifications.is_empty() {
+                apply_content_modifications(modifications, &message)
+            } else {
+                None
+            };
+            drop(message);
+            if let Some(new_message) = new_message {
+                raw_message = Some(new_message);
+                is_rewritten = true;
+            }
+        }
+
+        raw_message.filter(|_| is_rewritten)
+    }
+}
+
+fn action_event(action: Action) -> MtaHookEvent {
+    match action {
+        Action::Accept => MtaHookEvent::ActionAccept,
+        Action::Discard => MtaHookEvent::ActionDiscard,
+        Action::Reject => MtaHookEvent::ActionReject,
+        Action::Quarantine => MtaHookEvent::ActionQuarantine,
+        Action::Hold => MtaHookEvent::ActionHold,
+        Action::Reroute => MtaHookEvent::ActionReroute,
+    }
+}
+
+fn discarded(response: Option<&SmtpResponse>) -> Status<HostResponse<Box<str>>, ErrorDetails> {
+    Status::Completed(HostResponse {
+        hostname: "localhost".into(),
+        response: hook_response(response, 250, [2, 1, 5], "Message discarded by policy"),
+    })
+}
+
+fn hook_response(
+    response: Option<&SmtpResponse>,
+    code: u16,
+    esc: [u8; 3],
+    message: &str,
+) -> Response<Box<str>> {
+    let esc = response
+        .and_then(|response| response.enhanced_status.as_deref())
+        .and_then(|status| {
+            let mut parts = status.split('.').map(|part| part.parse::<u8>().ok());
+            Some([parts.next()??, parts.next()??, parts.next()??])
+        })
+        .unwrap_or(esc);
+
+    Response {
+        code: response
+            .and_then(|response| response.status)
+            .unwrap_or(code),
+        esc,
+        message: response
+            .and_then(|response| response.message.as_deref())
+            .unwrap_or(message)
+            .into(),
+    }
+}
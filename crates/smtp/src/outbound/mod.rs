@@ -10,7 +10,7 @@ use crate::{
 };
 use common::config::{
     server::ServerProtocol,
-    smtp::queue::{HostOrIp, MxConfig, RelayConfig},
+    smtp::queue::{HostOrIp, MxConfig, RelayConfig, RelayHost},
 };
 use directory::Credentials;
 use mail_auth::IpLookupStrategy;
@@ -21,9 +21,11 @@ pub mod client;
 pub mod dane;
 pub mod delivery;
 pub mod error;
+pub mod hooks;
 pub mod local;
 pub mod lookup;
 pub mod mta_sts;
+pub mod relay;
 pub mod session;
 
 pub(super) enum DeliveryResult {
@@ -39,6 +41,11 @@ pub(super) enum DeliveryResult {
         rcpt_idxs: Vec<usize>,
         retry_at: u64,
     },
+    Held {
+        status: Status<HostResponse<Box<str>>, ErrorDetails>,
+        rcpt_idx: usize,
+        retry_at: u64,
+    },
 }
 
 impl Status<HostResponse<Box<str>>, ErrorDetails> {
@@ -228,7 +235,10 @@ impl Status<HostResponse<Box<str>>, ErrorDetails> {
 
 #[derive(Debug)]
 pub enum NextHop<'x> {
-    Relay(&'x RelayConfig),
+    Relay {
+        config: &'x RelayConfig,
+        host: &'x RelayHost,
+    },
     MX {
         is_implicit: bool,
         host: &'x str,
@@ -247,7 +257,7 @@ impl NextHop<'_> {
                     host
                 }
             }
-            NextHop::Relay(host) => match &host.address {
+            NextHop::Relay { host, .. } => match &host.address {
                 HostOrIp::Host(host) => host.as_ref(),
                 HostOrIp::Ip(ip) => ip.ip_str.as_ref(),
             },
@@ -264,7 +274,7 @@ impl NextHop<'_> {
                     HostOrIp::Host((*host).into())
                 }
             }
-            NextHop::Relay(host) => match &host.address {
+            NextHop::Relay { host, .. } => match &host.address {
                 HostOrIp::Host(host) => HostOrIp::Host(host.as_ref().into()),
                 HostOrIp::Ip(ip) => HostOrIp::Ip(ip.ip),
             },
@@ -275,7 +285,7 @@ impl NextHop<'_> {
     pub fn max_multi_homed(&self) -> usize {
         match self {
             NextHop::MX { config, .. } => config.max_multi_homed,
-            NextHop::Relay(_) => 10,
+            NextHop::Relay { .. } => 10,
         }
     }
 
@@ -283,7 +293,7 @@ impl NextHop<'_> {
     pub fn ip_lookup_strategy(&self) -> IpLookupStrategy {
         match self {
             NextHop::MX { config, .. } => config.ip_lookup_strategy,
-            NextHop::Relay(_) => IpLookupStrategy::Ipv4thenIpv6,
+            NextHop::Relay { .. } => IpLookupStrategy::Ipv4thenIpv6,
         }
     }
 
@@ -294,7 +304,7 @@ impl NextHop<'_> {
             NextHop::MX { .. } => 9925,
             #[cfg(not(feature = "test_mode"))]
             NextHop::MX { .. } => 25,
-            NextHop::Relay(host) => host.port,
+            NextHop::Relay { host, .. } => host.port,
         }
     }
 
@@ -302,7 +312,7 @@ impl NextHop<'_> {
     fn credentials(&self) -> Option<&Credentials> {
         match self {
             NextHop::MX { .. } => None,
-            NextHop::Relay(host) => host.auth.as_ref(),
+            NextHop::Relay { host, .. } => host.auth.as_ref(),
         }
     }
 
@@ -315,7 +325,7 @@ impl NextHop<'_> {
         #[cfg(not(feature = "test_mode"))]
         match self {
             NextHop::MX { .. } => false,
-            NextHop::Relay(host) => host.tls_allow_invalid_certs,
+            NextHop::Relay { config, .. } => config.tls_allow_invalid_certs,
         }
     }
 
@@ -323,7 +333,7 @@ impl NextHop<'_> {
     fn implicit_tls(&self) -> bool {
         match self {
             NextHop::MX { .. } => false,
-            NextHop::Relay(host) => host.tls_implicit,
+            NextHop::Relay { config, .. } => config.tls_implicit,
         }
     }
 
@@ -331,7 +341,7 @@ impl NextHop<'_> {
     fn is_smtp(&self) -> bool {
         match self {
             NextHop::MX { .. } => true,
-            NextHop::Relay(host) => host.protocol == ServerProtocol::Smtp,
+            NextHop::Relay { config, .. } => config.protocol == ServerProtocol::Smtp,
         }
     }
 }
@@ -354,4 +364,57 @@ impl DeliveryResult {
     pub fn account(status: Status<HostResponse<Box<str>>, ErrorDetails>, rcpt_idx: usize) -> Self {
         DeliveryResult::Account { status, rcpt_idx }
     }
+
+    pub fn held(
+        status: Status<HostResponse<Box<str>>, ErrorDetails>,
+        rcpt_idx: usize,
+        retry_at: u64,
+    ) -> Self {
+        DeliveryResult::Held {
+            status,
+            rcpt_idx,
+            retry_at,
+        }
+    }
+
+    pub fn temporary_failure(&self) -> Option<&Status<HostResponse<Box<str>>, ErrorDetails>> {
+        match self {
+            DeliveryResult::Domain { status, .. } | DeliveryResult::Account { status, .. }
+                if matches!(status, Status::TemporaryFailure(_)) =>
+            {
+                Some(status)
+            }
+            _ => None,
+        }
+    }
+
+    pub fn is_delivered(results: &[Self]) -> bool {
+        results.iter().any(|result| {
+            matches!(
+                result,
+                DeliveryResult::Domain {
+                    status: Status::Completed(_),
+                    ..
+                } | DeliveryResult::Account {
+                    status: Status::Completed(_),
+                    ..
+                }
+            )
+        })
+    }
+
+    pub fn failover_status(
+        results: &[Self],
+    ) -> Option<&Status<HostResponse<Box<str>>, ErrorDetails>> {
+        if results
+            .iter()
+            .all(|result| result.temporary_failure().is_some())
+        {
+            results
+                .first()
+                .and_then(|result| result.temporary_failure())
+        } else {
+            None
+        }
+    }
 }
@@ -0,0 +1,183 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use super::NextHop;
+use crate::queue::{ErrorDetails, HostResponse, Status};
+use common::{
+    Server,
+    config::smtp::queue::{HostOrIp, RelayConfig, RelayHost},
+};
+use rand::Rng;
+use trc::DeliveryEvent;
+
+pub trait RelayHosts: Sync + Send {
+    fn relay_hosts<'x>(&self, config: &'x RelayConfig, span_id: u64) -> Vec<NextHop<'x>>;
+
+    fn relay_host_success(&self, remote_host: &NextHop<'_>, span_id: u64);
+
+    fn relay_host_failed(
+        &self,
+        remote_host: &NextHop<'_>,
+        status: &Status<HostResponse<Box<str>>, ErrorDetails>,
+        span_id: u64,
+    );
+
+    fn relay_host_result(
+        &self,
+        remote_host: &NextHop<'_>,
+        failed_status: Option<&Status<HostResponse<Box<str>>, ErrorDetails>>,
+        has_next_host: bool,
+        span_id: u64,
+    ) -> Option<Status<HostResponse<Box<str>>, ErrorDetails>>;
+}
+
+impl RelayHosts for Server {
+    fn relay_hosts<'x>(&self, config: &'x RelayConfig, span_id: u64) -> Vec<NextHop<'x>> {
+        // Skip hosts that are cooling down after a failure, unless all of them are
+        let mut hosts = Vec::with_capacity(config.hosts.len());
+        for host in &config.hosts {
+            if self
+                .inner
+                .cache
+                .relay_unhealthy
+                .get(relay_host_key(host).as_str())
+                .is_none()
+            {
+                hosts.push(host);
+            } else {
+                trc::event!(
+                    Delivery(DeliveryEvent::RelayHostSkipped),
+                    SpanId = span_id,
+                    Hostname = relay_host_key(host),
+                );
+            }
+        }
+        if hosts.is_empty() {
+            hosts.extend(config.hosts.iter());
+        }
+
+        // Hosts are sorted by priority, hosts sharing a priority are shuffled by weight
+        let mut rng = rand::rng();
+        let mut start = 0;
+        while start < hosts.len() {
+            let priority = hosts[start].priority;
+            let end = hosts[start..]
+                .iter()
+                .position(|host| host.priority != priority)
+                .map_or(hosts.len(), |pos| start + pos);
+
+            for i in start..end.saturating_sub(1) {
+                let total = hosts[i..end]
+                    .iter()
+                    .map(|host| host.weight.max(1) as u32)
+                    .sum::<u32>();
+                let mut pick = rng.random_range(0..total);
+                let pos = hosts[i..end]
+                    .iter()
+                    .position(|host| {
+                        let weight = host.weight.max(1) as u32;
+                        if pick < weight {
+                            true
+                        } else {
+                            pick -= weight;
+                            false
+                        }
+                    })
+                    .unwrap_or_default();
+                hosts.swap(i, i + pos);
+            }
+
+            start = end;
+        }
+
+        hosts
+            .into_iter()
+            .map(|host| NextHop::Relay { config, host })
+            .collect()
+    }
+
+    fn relay_host_success(&self, remote_host: &NextHop<'_>, span_id: u64) {
+        if let NextHop::Relay { host, .. } = remote_host {
+            let key = relay_host_key(host);
+            self.inner.cache.relay_unhealthy.remove(key.as_str());
+            self.inner
+                .data
+                .relay_stats
+                .lock()
+                .entry(key.clone().into_boxed_str())
+                .or_default()
+                .success += 1;
+
+            trc::event!(
+                Delivery(DeliveryEvent::RelayHostSuccess),
+                SpanId = span_id,
+                Hostname = key,
+            );
+        }
+    }
+
+    fn relay_host_failed(
+        &self,
+        remote_host: &NextHop<'_>,
+        status: &Status<HostResponse<Box<str>>, ErrorDetails>,
+        span_id: u64,
+    ) {
+        if let NextHop::Relay { config, host } = remote_host {
+            let key = relay_host_key(host);
+            self.inner.cache.relay_unhealthy.insert(
+                key.clone().into_boxed_str(),
+                (),
+                config.host_cooldown,
+            );
+            self.inner
+                .data
+                .relay_stats
+                .lock()
+                .entry(key.clone().into_boxed_str())
+                .or_default()
+                .failed += 1;
+
+            trc::event!(
+                Delivery(DeliveryEvent::RelayHostFailed),
+                SpanId = span_id,
+                Hostname = key,
+                Details = status.to_string(),
+            );
+        }
+    }
+
+    fn relay_host_result(
+        &self,
+        remote_host: &NextHop<'_>,
+        failed_status: Option<&Status<HostResponse<Box<str>>, ErrorDetails>>,
+        has_next_host: bool,
+        span_id: u64,
+    ) -> Option<Status<HostResponse<Box<str>>, ErrorDetails>> {
+        if !matches!(remote_host, NextHop::Relay { .. }) {
+            return None;
+        }
+
+        // Fail over to the next host when no recipient was accepted
+        if let Some(status) = failed_status {
+            if has_next_host {
+                return Some(status.clone());
+            }
+            self.relay_host_failed(remote_host, status, span_id);
+        } else {
+            self.relay_host_success(remote_host, span_id);
+        }
+
+        None
+    }
+}
+
+fn relay_host_key(host: &RelayHost) -> String {
+    let address = match &host.address {
+        HostOrIp::Host(host) => host.as_ref(),
+        HostOrIp::Ip(ip) => ip.ip_str.as_ref(),
+    };
+    format!("{address}:{}", host.port)
+}
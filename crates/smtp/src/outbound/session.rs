@@ -4,7 +4,7 @@
  * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
  */
 
-use super::client::SmtpClient;
+use super::client::{PooledClient, SessionPool, SmtpClient, SmtpLimits};
 use crate::outbound::DeliveryResult;
 use crate::outbound::client::{BoxResponse, from_error_status, from_mail_send_error};
 use crate::outbound::error::ClientError;
@@ -12,6 +12,7 @@ use crate::queue::{Error, MessageWrapper, Recipient, Status};
 use crate::queue::{ErrorDetails, HostResponse, UnexpectedResponse};
 use common::Server;
 use common::config::smtp::queue::ConnectionStrategy;
+use common::network::pool::SmtpSessionKey;
 use directory::Credentials;
 use smtp_proto::{
     EXT_CHUNKING, EXT_DSN, EXT_REQUIRE_TLS, EXT_SIZE, EXT_SMTP_UTF8, EhloResponse, MAIL_REQUIRETLS,
@@ -19,18 +20,29 @@ use smtp_proto::{
     RCPT_NOTIFY_NEVER, RCPT_NOTIFY_SUCCESS, Severity,
 };
 use std::{fmt::Write, time::Instant};
+use store::write::now;
 use tokio::io::{AsyncRead, AsyncWrite};
 use trc::DeliveryEvent;
 
 pub struct SessionParams<'x> {
     pub server: &'x Server,
     pub hostname: &'x str,
+    pub return_path: &'x str,
     pub credentials: Option<&'x Credentials>,
     pub capabilities: Option<EhloResponse<String>>,
     pub is_smtp: bool,
     pub local_hostname: &'x str,
     pub conn_strategy: &'x ConnectionStrategy,
     pub session_id: u64,
+    pub reuse: Option<SmtpSessionKey>,
+    pub contents: Option<&'x [u8]>,
+}
+
+impl SessionParams<'_> {
+    pub fn message_size(&self, message: &MessageWrapper) -> u64 {
+        self.contents
+            .map_or(message.message.size, |contents| contents.len() as u64)
+    }
 }
 
 impl MessageWrapper {
@@ -40,7 +52,9 @@ impl MessageWrapper {
         rcpt_idxs: Vec<usize>,
         statuses: &mut Vec<DeliveryResult>,
         mut params: SessionParams<'_>,
-    ) {
+    ) where
+        SmtpClient<T>: Into<PooledClient>,
+    {
         // Obtain capabilities
         let time = Instant::now();
         let capabilities = if let Some(capabilities) = params.capabilities.take() {
@@ -118,10 +132,64 @@ impl MessageWrapper {
             };*/
         }
 
+        // Split recipients into transactions that stay within the remote limits
+        let mut batches = self
+            .rcpt_batches(&rcpt_idxs, &smtp_client.limits)
+            .into_iter();
+        while let Some(batch) = batches.next() {
+            if !smtp_client.has_transactions_left(usize::MAX) {
+                // Retry the remaining recipients over a new session
+                statuses.push(DeliveryResult::rate_limited(
+                    batch.into_iter().chain(batches.flatten()).collect(),
+                    now(),
+                ));
+                break;
+            }
+
+            if let Err(status) = self
+                .deliver_transaction(&mut smtp_client, &batch, &capabilities, statuses, &params)
+                .await
+            {
+                smtp_client.quit().await;
+                statuses.push(DeliveryResult::domain(
+                    status,
+                    batch.into_iter().chain(batches.flatten()).collect(),
+                ));
+                return;
+            }
+            smtp_client.transactions += 1;
+        }
+
+        // Keep the session open for subsequent messages
+        if let Some(reuse) = params.reuse
+            && smtp_client.has_transactions_left(params.conn_strategy.reuse_max_messages)
+        {
+            params
+                .server
+                .release_session(
+                    reuse,
+                    smtp_client.into(),
+                    capabilities,
+                    params.conn_strategy,
+                )
+                .await;
+        } else {
+            smtp_client.quit().await;
+        }
+    }
+
+    async fn deliver_transaction<T: AsyncRead + AsyncWrite + Unpin>(
+        &self,
+        smtp_client: &mut SmtpClient<T>,
+        rcpt_idxs: &[usize],
+        capabilities: &EhloResponse<String>,
+        statuses: &mut Vec<DeliveryResult>,
+        params: &SessionParams<'_>,
+    ) -> Result<(), Status<HostResponse<Box<str>>, ErrorDetails>> {
         // MAIL FROM
         let time = Instant::now();
         smtp_client.timeout = params.conn_strategy.timeout_mail;
-        let cmd = self.build_mail_from(&capabilities);
+        let cmd = self.build_mail_from(params, capabilities);
         match smtp_client.cmd(cmd.as_bytes()).await.and_then(|r| {
             if r.is_positive_completion() {
                 Ok(r)
@@ -134,7 +202,7 @@ impl MessageWrapper {
                     Delivery(DeliveryEvent::MailFrom),
                     SpanId = params.session_id,
                     Hostname = params.hostname.to_string(),
-                    From = self.message.return_path.to_string(),
+                    From = params.return_path.to_string(),
                     Code = response.code,
                     Details = response.message.to_string(),
                     Elapsed = time.elapsed(),
@@ -149,19 +217,14 @@ impl MessageWrapper {
                     Elapsed = time.elapsed(),
                 );
 
-                smtp_client.quit().await;
-                statuses.push(DeliveryResult::domain(
-                    Status::from_smtp_error(params.hostname, &cmd, err),
-                    rcpt_idxs,
-                ));
-                return;
+                return Err(Status::from_smtp_error(params.hostname, &cmd, err));
             }
         }
 
         // RCPT TO
         let mut accepted_rcpts = Vec::new();
         smtp_client.timeout = params.conn_strategy.timeout_rcpt;
-        for rcpt_idx in &rcpt_idxs {
+        for rcpt_idx in rcpt_idxs {
             let time = Instant::now();
             let rcpt = &self.message.recipients[*rcpt_idx];
             if matches!(
@@ -171,7 +234,7 @@ impl MessageWrapper {
                 continue;
             }
 
-            let cmd = self.build_rcpt_to(rcpt, &capabilities);
+            let cmd = self.build_rcpt_to(rcpt, capabilities);
             match smtp_client.cmd(cmd.as_bytes()).await {
                 Ok(response) => match response.severity() {
                     Severity::PositiveCompletion => {
@@ -233,12 +296,7 @@ impl MessageWrapper {
                     );
 
                     // Something went wrong, abort.
-                    smtp_client.quit().await;
-                    statuses.push(DeliveryResult::domain(
-                        Status::from_smtp_error(params.hostname, "", err),
-                        rcpt_idxs,
-                    ));
-                    return;
+                    return Err(Status::from_smtp_error(params.hostname, "", err));
                 }
             }
         }
@@ -248,9 +306,9 @@ impl MessageWrapper {
             let time = Instant::now();
             let bdat_cmd = capabilities
                 .has_capability(EXT_CHUNKING)
-                .then(|| format!("BDAT {} LAST\r\n", self.message.size));
+                .then(|| format!("BDAT {} LAST\r\n", params.message_size(self)));
 
-            if let Err(status) = smtp_client.send_message(self, &bdat_cmd, &params).await {
+            if let Err(status) = smtp_client.send_message(self, &bdat_cmd, params).await {
                 trc::event!(
                     Delivery(DeliveryEvent::MessageRejected),
                     SpanId = params.session_id,
@@ -259,9 +317,7 @@ impl MessageWrapper {
                     Elapsed = time.elapsed(),
                 );
 
-                smtp_client.quit().await;
-                statuses.push(DeliveryResult::domain(status, rcpt_idxs));
-                return;
+                return Err(status);
             }
 
             if params.is_smtp {
@@ -296,16 +352,11 @@ impl MessageWrapper {
                                 Elapsed = time.elapsed(),
                             );
 
-                            smtp_client.quit().await;
-                            statuses.push(DeliveryResult::domain(
-                                Status::from_smtp_error(
-                                    params.hostname,
-                                    bdat_cmd.as_deref().unwrap_or("DATA"),
-                                    ClientError::UnexpectedReply(response),
-                                ),
-                                rcpt_idxs,
+                            return Err(Status::from_smtp_error(
+                                params.hostname,
+                                bdat_cmd.as_deref().unwrap_or("DATA"),
+                                ClientError::UnexpectedReply(response),
                             ));
-                            return;
                         }
                     }
                     Err(status) => {
@@ -317,9 +368,7 @@ impl MessageWrapper {
                             Elapsed = time.elapsed(),
                         );
 
-                        smtp_client.quit().await;
-                        statuses.push(DeliveryResult::domain(status, rcpt_idxs));
-                        return;
+                        return Err(status);
                     }
                 }
             } else {
@@ -393,22 +442,57 @@ impl MessageWrapper {
                             Elapsed = time.elapsed(),
                         );
 
-                        smtp_client.quit().await;
-                        statuses.push(DeliveryResult::domain(status, rcpt_idxs));
-                        return;
+                        return Err(status);
                     }
                 }
             }
+        } else {
+            // No recipients were accepted, abort the transaction
+            smtp_client
+                .rset()
+                .await
+                .map_err(|err| Status::from_smtp_error(params.hostname, "RSET", err))?;
         }
 
-        smtp_client.quit().await;
+        Ok(())
     }
 
-    fn build_mail_from(&self, capabilities: &EhloResponse<String>) -> String {
-        let mut mail_from = String::with_capacity(self.message.return_path.len() + 60);
-        let _ = write!(mail_from, "MAIL FROM:<{}>", self.message.return_path);
+    fn rcpt_batches(&self, rcpt_idxs: &[usize], limits: &SmtpLimits) -> Vec<Vec<usize>> {
+        let rcpt_max = limits.rcpt_max.unwrap_or(usize::MAX);
+        let rcpt_domain_max = limits.rcpt_domain_max.unwrap_or(usize::MAX);
+        let mut batches: Vec<Vec<usize>> = Vec::new();
+        let mut domains: Vec<&str> = Vec::new();
+
+        for &rcpt_idx in rcpt_idxs {
+            let domain = self.message.recipients[rcpt_idx].domain_part();
+            let is_new_domain = !domains.contains(&domain);
+            if batches.last().is_none_or(|batch| {
+                batch.len() >= rcpt_max || (is_new_domain && domains.len() >= rcpt_domain_max)
+            }) {
+                batches.push(Vec::new());
+                domains.clear();
+            }
+            if !domains.contains(&domain) {
+                domains.push(domain);
+            }
+            if let Some(batch) = batches.last_mut() {
+                batch.push(rcpt_idx);
+            }
+        }
+
+        batches
+    }
+
+    fn build_mail_from(
+        &self,
+        params: &SessionParams<'_>,
+        capabilities: &EhloResponse<String>,
+    ) -> String {
+        let return_path = params.return_path;
+        let mut mail_from = String::with_capacity(return_path.len() + 60);
+        let _ = write!(mail_from, "MAIL FROM:<{return_path}>");
         if capabilities.has_capability(EXT_SIZE) {
-            let _ = write!(mail_from, " SIZE={}", self.message.size);
+            let _ = write!(mail_from, " SIZE={}", params.message_size(self));
         }
         if self.has_flag(MAIL_REQUIRETLS) & capabilities.has_capability(EXT_REQUIRE_TLS) {
             mail_from.push_str(" REQUIRETLS");
@@ -11,22 +11,25 @@ use super::{
 };
 use crate::queue::{MessageWrapper, UnexpectedResponse};
 use crate::reporting::send::MtaReportSend;
-use common::Server;
+use common::{Server, config::smtp::queue::DsnTemplateVariable, i18n};
 use mail_builder::MessageBuilder;
 use mail_builder::headers::HeaderType;
 use mail_builder::headers::content_type::ContentType;
 use mail_builder::mime::{BodyPart, MimePart, make_boundary};
 use mail_parser::DateTime;
+use registry::types::EnumImpl;
 use smtp_proto::{
     RCPT_NOTIFY_DELAY, RCPT_NOTIFY_FAILURE, RCPT_NOTIFY_NEVER, RCPT_NOTIFY_SUCCESS, Response,
 };
 use std::fmt::Write;
 use std::future::Future;
 use store::write::now;
+use utils::template::Variables;
 
 pub trait SendDsn: Sync + Send {
     fn send_dsn(&self, message: &mut MessageWrapper) -> impl Future<Output = ()> + Send;
     fn log_dsn(&self, message: &MessageWrapper) -> impl Future<Output = ()> + Send;
+    fn dsn_locale(&self, address: &str) -> impl Future<Output = &'static i18n::Locale> + Send;
 }
 
 impl SendDsn for Server {
@@ -124,6 +127,36 @@ impl SendDsn for Server {
             }
         }
     }
+
+    async fn dsn_locale(&self, address: &str) -> &'static i18n::Locale {
+        // Use the sender's account locale, otherwise the default locale of its domain
+        let locale = match self.account_id_from_email(address, true).await {
+            Ok(Some(account_id)) => self
+                .try_account(account_id)
+                .await
+                .map(|account| account.map(|account| account.locale)),
+            Ok(None) => match address.rsplit_once('@') {
+                Some((_, domain)) => self
+                    .domain(domain)
+                    .await
+                    .map(|domain| domain.map(|domain| domain.locale)),
+                None => Ok(None),
+            },
+            Err(err) => Err(err),
+        };
+
+        match locale {
+            Ok(Some(locale)) => i18n::locale_or_default(locale.as_str()),
+            Ok(None) => &i18n::EN_LOCALES,
+            Err(err) => {
+                trc::error!(
+                    err.caused_by(trc::location!())
+                        .details("Failed to obtain sender locale")
+                );
+                &i18n::EN_LOCALES
+            }
+        }
+    }
 }
 
 const MAX_HEADER_SIZE: usize = 4096;
@@ -133,9 +166,10 @@ impl MessageWrapper {
         let config = &server.core.smtp.queue;
         let now = now();
 
-        let mut txt_success = String::new();
-        let mut txt_delay = String::new();
-        let mut txt_failed = String::new();
+        let locale = server.dsn_locale(&self.message.return_path).await;
+        let mut rcpt_success = Vec::new();
+        let mut rcpt_delay = Vec::new();
+        let mut rcpt_failed = Vec::new();
         let mut dsn = String::new();
 
         for rcpt in &mut self.message.recipients {
@@ -150,7 +184,7 @@ impl MessageWrapper {
                     }
                     rcpt.write_dsn(&mut dsn);
                     rcpt.status.write_dsn(&mut dsn);
-                    response.write_dsn_text(&rcpt.address, &mut txt_success);
+                    rcpt_success.push((rcpt.address.clone(), response.dsn_details(locale)));
                 }
                 Status::TemporaryFailure(response)
                     if rcpt.notify.due <= now && rcpt.has_flag(RCPT_NOTIFY_DELAY) =>
@@ -158,7 +192,7 @@ impl MessageWrapper {
                     rcpt.write_dsn(&mut dsn);
                     rcpt.status.write_dsn(&mut dsn);
                     rcpt.write_dsn_will_retry_until(self.message.created, &mut dsn);
-                    response.write_dsn_text(&rcpt.address, &mut txt_delay);
+                    rcpt_delay.push((rcpt.address.clone(), response.dsn_details(locale)));
                 }
                 Status::PermanentFailure(response) => {
                     rcpt.flags |= RCPT_DSN_SENT;
@@ -167,18 +201,21 @@ impl MessageWrapper {
                     }
                     rcpt.write_dsn(&mut dsn);
                     rcpt.status.write_dsn(&mut dsn);
-                    response.write_dsn_text(&rcpt.address, &mut txt_failed);
+                    rcpt_failed.push((rcpt.address.clone(), response.dsn_details(locale)));
                 }
                 Status::Scheduled if rcpt.notify.due <= now && rcpt.has_flag(RCPT_NOTIFY_DELAY) => {
                     // This case should not happen under normal circumstances
                     rcpt.write_dsn(&mut dsn);
                     rcpt.status.write_dsn(&mut dsn);
                     rcpt.write_dsn_will_retry_until(self.message.created, &mut dsn);
-                    ErrorDetails {
-                        entity: "localhost".into(),
-                        details: Error::ConcurrencyLimited,
-                    }
-                    .write_dsn_text(&rcpt.address, &mut txt_delay);
+                    rcpt_delay.push((
+                        rcpt.address.clone(),
+                        ErrorDetails {
+                            entity: "localhost".into(),
+                            details: Error::ConcurrencyLimited,
+                        }
+                        .dsn_details(locale),
+                    ));
                 }
                 _ => continue,
             }
@@ -186,69 +223,66 @@ impl MessageWrapper {
             dsn.push_str("\r\n");
         }
 
-        // Build text response
-        let txt_len = txt_success.len() + txt_delay.len() + txt_failed.len();
-        if txt_len == 0 {
+        let has_success = !rcpt_success.is_empty();
+        let has_delay = !rcpt_delay.is_empty();
+        let has_failure = !rcpt_failed.is_empty();
+        if !has_success && !has_delay && !has_failure {
             return None;
         }
 
-        let has_success = !txt_success.is_empty();
-        let has_delay = !txt_delay.is_empty();
-        let has_failure = !txt_failed.is_empty();
-
-        let mut txt = String::with_capacity(txt_len + 128);
-        let (subject, is_mixed) = if has_success && !has_delay && !has_failure {
-            txt.push_str(
-                "Your message has been successfully delivered to the following recipients:\r\n\r\n",
-            );
-            ("Successfully delivered message", false)
+        let (subject, title, is_mixed) = if has_success && !has_delay && !has_failure {
+            (locale.dsn_subject_success, locale.dsn_intro_success, false)
         } else if has_delay && !has_success && !has_failure {
-            txt.push_str("There was a temporary problem delivering your message to the following recipients:\r\n\r\n");
-            ("Warning: Delay in message delivery", false)
+            (locale.dsn_subject_delay, locale.dsn_intro_delay, false)
         } else if has_failure && !has_success && !has_delay {
-            txt.push_str(
-                "Your message could not be delivered to the following recipients:\r\n\r\n",
-            );
-            ("Failed to deliver message", false)
+            (locale.dsn_subject_failure, locale.dsn_intro_failure, false)
         } else if has_success {
-            txt.push_str("Your message has been partially delivered:\r\n\r\n");
-            ("Partially delivered message", true)
+            (locale.dsn_subject_partial, locale.dsn_intro_partial, true)
         } else {
-            txt.push_str("Your message could not be delivered to some recipients:\r\n\r\n");
-            (
-                "Warning: Temporary and permanent failures during message delivery",
-                true,
-            )
+            (locale.dsn_subject_mixed, locale.dsn_intro_mixed, true)
         };
+        let sections = [
+            (locale.dsn_section_success, &rcpt_success),
+            (locale.dsn_section_delay, &rcpt_delay),
+            (locale.dsn_section_failure, &rcpt_failed),
+        ];
 
-        if has_success {
-            if is_mixed {
-                txt.push_str(
-                    "    ----- Delivery to the following addresses was successful -----\r\n",
-                );
+        // Build text response
+        let mut txt = String::with_capacity(256);
+        let _ = write!(txt, "{title}\r\n\r\n");
+        for (section, recipients) in &sections {
+            if recipients.is_empty() {
+                continue;
             }
-
-            txt.push_str(&txt_success);
-            txt.push_str("\r\n");
-        }
-
-        if has_delay {
             if is_mixed {
-                txt.push_str(
-                    "    ----- There was a temporary problem delivering to these addresses -----\r\n",
-                );
+                let _ = write!(txt, "    ----- {section} -----\r\n");
+            }
+            for (address, details) in recipients.iter() {
+                let _ = write!(txt, "<{address}> ({details})\r\n");
             }
-            txt.push_str(&txt_delay);
             txt.push_str("\r\n");
         }
 
-        if has_failure {
-            if is_mixed {
-                txt.push_str("    ----- Delivery to the following addresses failed -----\r\n");
+        // Build HTML response
+        let mut entries = Vec::new();
+        for (section, recipients) in &sections {
+            for (idx, (address, details)) in recipients.iter().enumerate() {
+                let mut entry = Vec::with_capacity(3);
+                if is_mixed && idx == 0 {
+                    entry.push((DsnTemplateVariable::Section, section.to_string()));
+                }
+                entry.push((DsnTemplateVariable::Address, address.clone()));
+                entry.push((DsnTemplateVariable::Details, details.clone()));
+                entries.push(entry);
             }
-            txt.push_str(&txt_failed);
-            txt.push_str("\r\n");
         }
+        let mut variables = Variables::new();
+        variables.insert_single(DsnTemplateVariable::PageTitle, subject.to_string());
+        variables.insert_single(DsnTemplateVariable::Header, locale.dsn_header.to_string());
+        variables.insert_single(DsnTemplateVariable::Footer, locale.dsn_footer.to_string());
+        variables.insert_single(DsnTemplateVariable::Title, title.to_string());
+        variables.insert_block(DsnTemplateVariable::Recipients, entries);
+        let html = config.dsn.template.eval(&variables);
 
         // Update next delay notification time
         if has_delay {
@@ -377,7 +411,19 @@ impl MessageWrapper {
             .body(MimePart::new(
                 ContentType::new("multipart/report").attribute("report-type", "delivery-status"),
                 BodyPart::Multipart(vec![
-                    MimePart::new(ContentType::new("text/plain"), BodyPart::Text(txt.into())),
+                    MimePart::new(
+                        ContentType::new("multipart/alternative"),
+                        BodyPart::Multipart(vec![
+                            MimePart::new(
+                                ContentType::new("text/plain"),
+                                BodyPart::Text(txt.into()),
+                            ),
+                            MimePart::new(
+                                ContentType::new("text/html"),
+                                BodyPart::Text(html.into()),
+                            ),
+                        ]),
+                    ),
                     MimePart::new(
                         ContentType::new("message/delivery-status"),
                         BodyPart::Text(dsn.into()),
@@ -402,9 +448,11 @@ impl MessageWrapper {
                 && let Status::PermanentFailure(err) = &rcpt.status
             {
                 rcpt.flags |= RCPT_DSN_SENT;
-                let mut dsn = String::new();
-                err.write_dsn_text(&rcpt.address, &mut dsn);
-                is_double_bounce.push(dsn);
+                is_double_bounce.push(format!(
+                    "<{}> ({})\r\n",
+                    rcpt.address,
+                    err.dsn_details(&i18n::EN_LOCALES)
+                ));
             }
 
             if rcpt.notify.due <= now {
@@ -426,86 +474,44 @@ impl MessageWrapper {
 }
 
 impl HostResponse<Box<str>> {
-    fn write_dsn_text(&self, addr: &str, dsn: &mut String) {
-        let _ = write!(
-            dsn,
-            "<{}> (delivered to '{}' with code {} ({}.{}.{}) '",
-            addr,
-            self.hostname,
-            self.response.code,
-            self.response.esc[0],
-            self.response.esc[1],
-            self.response.esc[2]
-        );
-        self.response.write_response(dsn);
-        dsn.push_str("')\r\n");
+    fn dsn_details(&self, locale: &i18n::Locale) -> String {
+        self.response
+            .dsn_details(locale.dsn_delivered, &self.hostname, "")
     }
 }
 
 impl UnexpectedResponse {
-    fn write_dsn_text(&self, host: &str, addr: &str, dsn: &mut String) {
-        let _ = write!(dsn, "<{addr}> (host '{host}' rejected ");
-
+    fn dsn_details(&self, host: &str, locale: &i18n::Locale) -> String {
         if !self.command.is_empty() {
-            let _ = write!(dsn, "command '{}'", self.command);
+            self.response
+                .dsn_details(locale.dsn_rejected_command, host, &self.command)
         } else {
-            dsn.push_str("transaction");
+            self.response
+                .dsn_details(locale.dsn_rejected_transaction, host, "")
         }
-
-        let _ = write!(
-            dsn,
-            " with code {} ({}.{}.{}) '",
-            self.response.code, self.response.esc[0], self.response.esc[1], self.response.esc[2]
-        );
-        self.response.write_response(dsn);
-        dsn.push_str("')\r\n");
     }
 }
 
 impl ErrorDetails {
-    fn write_dsn_text(&self, addr: &str, dsn: &mut String) {
+    fn dsn_details(&self, locale: &i18n::Locale) -> String {
         let entity = self.entity.as_ref();
-        match &self.details {
+        let (template, details) = match &self.details {
             Error::UnexpectedResponse(response) => {
-                response.write_dsn_text(entity, addr, dsn);
-            }
-            Error::DnsError(err) => {
-                let _ = write!(dsn, "<{addr}> (failed to lookup '{entity}': {err})\r\n",);
-            }
-            Error::ConnectionError(details) => {
-                let _ = write!(
-                    dsn,
-                    "<{addr}> (connection to '{entity}' failed: {details})\r\n",
-                );
-            }
-            Error::TlsError(details) => {
-                let _ = write!(dsn, "<{addr}> (TLS error from '{entity}': {details})\r\n",);
-            }
-            Error::DaneError(details) => {
-                let _ = write!(
-                    dsn,
-                    "<{addr}> (DANE failed to authenticate '{entity}': {details})\r\n",
-                );
-            }
-            Error::MtaStsError(details) => {
-                let _ = write!(
-                    dsn,
-                    "<{addr}> (MTA-STS failed to authenticate '{entity}': {details})\r\n",
-                );
-            }
-            Error::RateLimited => {
-                let _ = write!(dsn, "<{addr}> (rate limited)\r\n");
-            }
-            Error::ConcurrencyLimited => {
-                let _ = write!(
-                    dsn,
-                    "<{addr}> (too many concurrent connections to remote server)\r\n",
-                );
-            }
-            Error::Io(err) => {
-                let _ = write!(dsn, "<{addr}> (queue error: {err})\r\n");
+                return response.dsn_details(entity, locale);
             }
-        }
+            Error::DnsError(err) => (locale.dsn_dns_error, err.as_ref()),
+            Error::ConnectionError(details) => (locale.dsn_connection_error, details.as_ref()),
+            Error::TlsError(details) => (locale.dsn_tls_error, details.as_ref()),
+            Error::DaneError(details) => (locale.dsn_dane_error, details.as_ref()),
+            Error::MtaStsError(details) => (locale.dsn_mta_sts_error, details.as_ref()),
+            Error::RateLimited => (locale.dsn_rate_limited, ""),
+            Error::ConcurrencyLimited => (locale.dsn_concurrency_limited, ""),
+            Error::Io(err) => (locale.dsn_queue_error, err.as_ref()),
+        };
+
+        template
+            .replace("$host", entity)
+            .replace("$details", details)
     }
 }
 
@@ -662,10 +668,26 @@ impl WriteDsn for Response<Box<str>> {
             }
         }
     }
+
+    fn dsn_details(&self, template: &str, host: &str, command: &str) -> String {
+        // The remote response is substituted last so it cannot inject placeholders
+        let mut response = String::with_capacity(self.message.len());
+        self.write_response(&mut response);
+        template
+            .replace("$code", &self.code.to_string())
+            .replace(
+                "$status",
+                &format!("{}.{}.{}", self.esc[0], self.esc[1], self.esc[2]),
+            )
+            .replace("$host", host)
+            .replace("$command", command)
+            .replace("$response", &response)
+    }
 }
 
 trait WriteDsn {
     fn write_dsn_status(&self, dsn: &mut String);
     fn write_dsn_diagnostic(&self, dsn: &mut String);
     fn write_response(&self, dsn: &mut String);
+    fn dsn_details(&self, template: &str, host: &str, command: &str) -> String;
 }
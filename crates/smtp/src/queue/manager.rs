@@ -5,7 +5,10 @@
  */
 
 use super::{Message, QueueId, Status, spool::SmtpSpool};
-use crate::queue::{Recipient, spool::LOCK_EXPIRY};
+use crate::{
+    outbound::client::SessionPool,
+    queue::{Recipient, spool::LOCK_EXPIRY},
+};
 use ahash::AHashMap;
 use common::{
     BuildServer, Inner,
@@ -27,6 +30,7 @@ pub struct Queue {
     pub locked_revision: u64,
     pub stats: AHashMap<QueueName, QueueStats>,
     pub next_refresh: Instant,
+    pub next_reap: Instant,
     pub rx: mpsc::Receiver<QueueEvent>,
     pub is_paused: bool,
 }
@@ -53,6 +57,7 @@ impl SpawnQueue for mpsc::Receiver<QueueEvent> {
 }
 
 const BACK_PRESSURE_WARN_INTERVAL: Duration = Duration::from_secs(60);
+const IDLE_SESSION_REAP_INTERVAL: Duration = Duration::from_secs(30);
 
 impl Queue {
     pub fn new(core: Arc<Inner>, rx: mpsc::Receiver<QueueEvent>) -> Self {
@@ -62,6 +67,7 @@ impl Queue {
             locked_revision: 0,
             stats: AHashMap::new(),
             next_refresh: Instant::now() + Duration::from_secs(1),
+            next_reap: Instant::now() + IDLE_SESSION_REAP_INTERVAL,
             is_paused: false,
             rx,
         }
@@ -74,7 +80,9 @@ impl Queue {
             let mut refresh_queue;
 
             match tokio::time::timeout(
-                self.next_refresh.duration_since(Instant::now()),
+                self.next_refresh
+                    .min(self.next_reap)
+                    .duration_since(Instant::now()),
                 self.rx.recv(),
             )
             .await
@@ -87,13 +95,24 @@ impl Queue {
                     }
                 }
                 Err(_) => {
-                    refresh_queue = true;
+                    refresh_queue = self.next_refresh <= Instant::now();
                 }
                 Ok(None) => {
                     break;
                 }
             };
 
+            // Close idle outbound sessions that have expired
+            if self.next_reap <= Instant::now() {
+                self.next_reap = Instant::now() + IDLE_SESSION_REAP_INTERVAL;
+                if self.core.data.smtp_sessions.idle_sessions() > 0 {
+                    let server = self.core.build_server();
+                    tokio::spawn(async move {
+                        server.reap_idle_sessions().await;
+                    });
+                }
+            }
+
             if !self.is_paused {
                 // Deliver scheduled messages
                 if refresh_queue || self.next_refresh <= Instant::now() {
@@ -22,6 +22,7 @@ use utils::DomainPart;
 
 pub mod dsn;
 pub mod manager;
+pub mod quarantine;
 pub mod quota;
 pub mod spool;
 pub mod throttle;
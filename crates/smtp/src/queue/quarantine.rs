@@ -0,0 +1,507 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use super::{MessageSource, MessageWrapper, spool::SmtpSpool};
+use ahash::AHashMap;
+use common::{
+    Server, auth::EmailCache, auth::oauth::GrantType,
+    config::mailstore::email::QuarantineTemplateVariable, i18n,
+};
+use email::message::index::PREVIEW_LENGTH;
+use mail_builder::headers::date::Date;
+use mail_parser::MessageParser;
+use registry::{
+    schema::{
+        prelude::{Object, ObjectType, Property},
+        structs::QuarantinedMessage,
+    },
+    types::{
+        EnumImpl, ObjectImpl, datetime::UTCDateTime, id::ObjectId, index::IndexBuilder, map::Map,
+    },
+};
+use std::borrow::Cow;
+use store::{
+    Serialize, ValueKey,
+    write::{BatchBuilder, BlobLink, BlobOp, RegistryClass, ValueClass, assert::AssertValue, now},
+};
+use trc::{AddContext, QueueEvent};
+use types::{blob::BlobId, blob_hash::BlobHash};
+use utils::{template::Variables, url_params::UrlParams};
+
+pub trait QuarantineIndex {
+    fn write_ops(&self, batch: &mut BatchBuilder, item_id: u64, is_set: bool);
+}
+
+pub trait SmtpQuarantine: Sync + Send {
+    fn quarantine_release(
+        &self,
+        item_id: u64,
+        account_id: Option<u32>,
+        session_id: u64,
+    ) -> impl Future<Output = trc::Result<bool>> + Send;
+
+    fn quarantine_delete(
+        &self,
+        item_id: u64,
+        account_id: Option<u32>,
+    ) -> impl Future<Output = trc::Result<bool>> + Send;
+
+    fn quarantine_release_url(
+        &self,
+        account_id: u32,
+        item_id: u64,
+        expires_at: u64,
+    ) -> impl Future<Output = Option<String>> + Send;
+
+    fn http_quarantine_release(
+        &self,
+        params: &str,
+        language: &str,
+        confirmed: bool,
+    ) -> impl Future<Output = trc::Result<String>> + Send;
+}
+
+impl QuarantineIndex for QuarantinedMessage {
+    fn write_ops(&self, batch: &mut BatchBuilder, item_id: u64, is_set: bool) {
+        let object_id = ObjectType::QuarantinedMessage.to_id();
+        let account_id = self
+            .account_id
+            .map_or(u32::MAX, |account_id| account_id.document_id());
+        let expires_at = self.expires_at.timestamp() as u64;
+
+        // Messages addressed to non-local recipients are indexed under u32::MAX
+        // so they are only visible to administrators.
+        let mut index_builder = IndexBuilder::default();
+        index_builder.search(Property::AccountId, account_id as u64);
+        index_builder.search(Property::ExpiresAt, expires_at);
+        batch.with_account_id(account_id).registry_index(
+            object_id,
+            item_id,
+            index_builder.keys.iter(),
+            is_set,
+        );
+
+        let link = BlobOp::Link {
+            hash: self.blob_id.hash.clone(),
+            to: BlobLink::Temporary { until: expires_at },
+        };
+        let key = ValueClass::Registry(RegistryClass::Item { object_id, item_id });
+        if is_set {
+            batch
+                .set(
+                    link,
+                    ObjectId::new(ObjectType::QuarantinedMessage, item_id.into()).serialize(),
+                )
+                .set(key, self.to_pickled_vec());
+        } else {
+            batch.clear(link).clear(key);
+        }
+    }
+}
+
+impl MessageWrapper {
+    pub async fn quarantine(
+        self,
+        raw_headers: Option<&[u8]>,
+        raw_message: &[u8],
+        reason: String,
+        session_id: u64,
+        server: &Server,
+    ) -> bool {
+        let message = if let Some(raw_headers) = raw_headers {
+            let mut message = Vec::with_capacity(raw_headers.len() + raw_message.len());
+            message.extend_from_slice(raw_headers);
+            message.extend_from_slice(raw_message);
+            Cow::Owned(message)
+        } else {
+            raw_message.into()
+        };
+        let blob_hash = BlobHash::generate(message.as_ref());
+
+        // Reserve and write blob
+        let mut batch = BatchBuilder::new();
+        let now = now();
+        let reserve_until = now + 120;
+        batch.set(
+            BlobOp::Link {
+                hash: blob_hash.clone(),
+                to: BlobLink::Temporary {
+                    until: reserve_until,
+                },
+            },
+            vec![],
+        );
+        if let Err(err) = server.store().write(batch.build_all()).await {
+            trc::error!(
+                err.details("Failed to write to store.")
+                    .span_id(session_id)
+                    .caused_by(trc::location!())
+            );
+
+            return false;
+        }
+        if let Err(err) = server
+            .blob_store()
+            .put_blob(
+                blob_hash.as_slice(),
+                message.as_ref(),
+                server.core.email.compression,
+            )
+            .await
+        {
+            trc::error!(
+                err.details("Failed to write blob.")
+                    .span_id(session_id)
+                    .caused_by(trc::location!())
+            );
+
+            return false;
+        }
+
+        // Group recipients by local account
+        let mut accounts: AHashMap<Option<u32>, Map<String>> = AHashMap::new();
+        for rcpt in &self.message.recipients {
+            let account_id = match server.rcpt_id_from_email(rcpt.address()).await {
+                Ok(Some(EmailCache::Account(account_id))) => Some(account_id),
+                Ok(_) => None,
+                Err(err) => {
+                    trc::error!(
+                        err.span_id(session_id)
+                            .caused_by(trc::location!())
+                            .details("Failed to resolve quarantine recipient")
+                    );
+                    None
+                }
+            };
+            accounts
+                .entry(account_id)
+                .or_default()
+                .push(rcpt.address().to_string());
+        }
+
+        let parsed = MessageParser::new().parse_headers(message.as_ref());
+        let subject = parsed
+            .as_ref()
+            .and_then(|m| m.subject())
+            .unwrap_or_default()
+            .to_string();
+        let preview = MessageParser::new()
+            .parse(message.as_ref())
+            .and_then(|m| m.body_preview(PREVIEW_LENGTH).map(|p| p.into_owned()))
+            .unwrap_or_default();
+        let expires_at = now + server.core.email.quarantine_hold.as_secs();
+
+        let mut batch = BatchBuilder::new();
+        let mut items = Vec::with_capacity(accounts.len());
+        for (account_id, recipients) in accounts {
+            let item_id = server.inner.data.registry_id_gen.generate();
+            let item = QuarantinedMessage {
+                from: self.message.return_path.to_string(),
+                recipients,
+                subject: subject.clone(),
+                reason: reason.clone(),
+                size: message.len() as u64,
+                account_id: account_id.map(Into::into),
+                received_at: UTCDateTime::from_timestamp(now as i64),
+                expires_at: UTCDateTime::from_timestamp(expires_at as i64),
+                blob_id: BlobId::new(blob_hash.clone(), Default::default()),
+                preview: preview.clone(),
+            };
+            item.write_ops(&mut batch, item_id, true);
+            items.push((item_id, account_id, item.recipients));
+        }
+        batch
+            .with_account_id(u32::MAX)
+            .clear(BlobOp::Link {
+                hash: blob_hash.clone(),
+                to: BlobLink::Temporary {
+                    until: reserve_until,
+                },
+            })
+            .set(BlobOp::Commit { hash: blob_hash }, vec![]);
+
+        if let Err(err) = server.store().write(batch.build_all()).await {
+            trc::error!(
+                err.details("Failed to write to store.")
+                    .span_id(session_id)
+                    .caused_by(trc::location!())
+            );
+
+            return false;
+        }
+
+        for (item_id, account_id, recipients) in items {
+            trc::event!(
+                Queue(QueueEvent::MessageQuarantined),
+                SpanId = session_id,
+                Id = item_id,
+                AccountId = account_id,
+                From = self.message.return_path.to_string(),
+                To = recipients
+                    .into_inner()
+                    .into_iter()
+                    .map(trc::Value::from)
+                    .collect::<Vec<_>>(),
+                Reason = reason.clone(),
+                Size = message.len(),
+                Expires = trc::Value::Timestamp(expires_at),
+            );
+        }
+
+        true
+    }
+}
+
+impl SmtpQuarantine for Server {
+    async fn quarantine_release(
+        &self,
+        item_id: u64,
+        account_id: Option<u32>,
+        session_id: u64,
+    ) -> trc::Result<bool> {
+        let object_id = ObjectType::QuarantinedMessage.to_id();
+        let Some((item, revision)) = self
+            .store()
+            .get_value::<Object>(ValueKey::from(ValueClass::Registry(RegistryClass::Item {
+                object_id,
+                item_id,
+            })))
+            .await
+            .caused_by(trc::location!())?
+            .map(|object| {
+                let revision = object.revision;
+                (QuarantinedMessage::from(object), revision)
+            })
+            .filter(|(item, _)| {
+                account_id.is_none_or(|account_id| {
+                    item.account_id.map(|id| id.document_id()) == Some(account_id)
+                })
+            })
+        else {
+            return Ok(false);
+        };
+
+        let Some(raw_message) = self
+            .blob_store()
+            .get_blob(item.blob_id.hash.as_slice(), 0..usize::MAX)
+            .await
+            .caused_by(trc::location!())?
+        else {
+            return Err(trc::StoreEvent::NotFound
+                .into_err()
+                .details("Quarantined message blob not found")
+                .ctx(trc::Key::Id, item_id)
+                .caused_by(trc::location!()));
+        };
+
+        let mut message = self.new_message(&item.from, session_id);
+        for rcpt in item.recipients.iter() {
+            message.add_recipient(rcpt, self).await;
+        }
+        let queue_id = message.queue_id;
+        if !message
+            .queue(
+                None,
+                &raw_message,
+                session_id,
+                self,
+                MessageSource::Unauthenticated {
+                    dmarc_pass: false,
+                    train_spam: None,
+                },
+            )
+            .await
+        {
+            return Err(trc::StoreEvent::UnexpectedError
+                .into_err()
+                .details("Failed to queue released message")
+                .ctx(trc::Key::Id, item_id)
+                .caused_by(trc::location!()));
+        }
+
+        // Only remove the item once the message is queued so that it is kept
+        // held if queueing fails
+        let mut batch = BatchBuilder::new();
+        batch.assert_value(
+            ValueClass::Registry(RegistryClass::Item { object_id, item_id }),
+            AssertValue::Hash(revision),
+        );
+        item.write_ops(&mut batch, item_id, false);
+        match self.store().write(batch.build_all()).await {
+            Ok(_) => {}
+            Err(err) if err.is_assertion_failure() => {
+                // Removed by a concurrent request, the message is queued regardless
+            }
+            Err(err) => return Err(err.caused_by(trc::location!())),
+        }
+
+        trc::event!(
+            Queue(QueueEvent::QuarantineReleased),
+            SpanId = session_id,
+            Id = item_id,
+            AccountId = item.account_id.map(|id| id.document_id()),
+            QueueId = queue_id,
+        );
+
+        Ok(true)
+    }
+
+    async fn quarantine_delete(&self, item_id: u64, account_id: Option<u32>) -> trc::Result<bool> {
+        let object_id = ObjectType::QuarantinedMessage.to_id();
+        if let Some(item) = self
+            .store()
+            .get_value::<QuarantinedMessage>(ValueKey::from(ValueClass::Registry(
+                RegistryClass::Item { object_id, item_id },
+            )))
+            .await
+            .caused_by(trc::location!())?
+            .filter(|item| {
+                account_id.is_none_or(|account_id| {
+                    item.account_id.map(|id| id.document_id()) == Some(account_id)
+                })
+            })
+        {
+            let mut batch = BatchBuilder::new();
+            item.write_ops(&mut batch, item_id, false);
+            self.store()
+                .write(batch.build_all())
+                .await
+                .caused_by(trc::location!())?;
+
+            trc::event!(
+                Queue(QueueEvent::QuarantineDeleted),
+                Id = item_id,
+                AccountId = item.account_id.map(|id| id.document_id()),
+            );
+
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+
+    async fn quarantine_release_url(
+        &self,
+        account_id: u32,
+        item_id: u64,
+        expires_at: u64,
+    ) -> Option<String> {
+        match self
+            .encode_access_token(
+                GrantType::QuarantineRelease,
+                account_id,
+                &item_id.to_string(),
+                expires_at.saturating_sub(now()).max(1),
+            )
+            .await
+        {
+            Ok(access_token) => Some(format!(
+                "{}?i={}",
+                self.core.email.quarantine_release_url,
+                form_urlencoded::byte_serialize(access_token.as_bytes()).collect::<String>()
+            )),
+            Err(err) => {
+                trc::error!(err.caused_by(trc::location!()));
+                None
+            }
+        }
+    }
+
+    async fn http_quarantine_release(
+        &self,
+        params: &str,
+        language: &str,
+        confirmed: bool,
+    ) -> trc::Result<String> {
+        let locale = i18n::locale_or_default(language);
+        let params = UrlParams::new(params.into());
+        let mut token = None;
+        if let Some(access_token) = params.get("i")
+            && let Ok(grant) = self
+                .validate_access_token(GrantType::QuarantineRelease.into(), access_token)
+                .await
+            && let Ok(item_id) = grant.client_id.parse::<u64>()
+        {
+            token = Some((access_token, grant.account_id, item_id));
+        }
+
+        let mut variables = Variables::new();
+        let (title, description) = match token {
+            Some((_, account_id, item_id)) if confirmed => {
+                if self
+                    .quarantine_release(item_id, Some(account_id), 0)
+                    .await?
+                {
+                    (
+                        locale.quarantine_released,
+                        locale.quarantine_released_description,
+                    )
+                } else {
+                    (
+                        locale.quarantine_release_failed,
+                        locale.quarantine_not_found,
+                    )
+                }
+            }
+            Some((access_token, account_id, item_id)) => {
+                // Links are opened by mail scanners, so only release on POST
+                if let Some(item) = self
+                    .store()
+                    .get_value::<QuarantinedMessage>(ValueKey::from(ValueClass::Registry(
+                        RegistryClass::Item {
+                            object_id: ObjectType::QuarantinedMessage.to_id(),
+                            item_id,
+                        },
+                    )))
+                    .await
+                    .caused_by(trc::location!())?
+                    .filter(|item| item.account_id.map(|id| id.document_id()) == Some(account_id))
+                {
+                    variables.insert_single(QuarantineTemplateVariable::From, item.from);
+                    variables.insert_single(QuarantineTemplateVariable::Subject, item.subject);
+                    variables.insert_single(QuarantineTemplateVariable::Reason, item.reason);
+                    variables.insert_single(
+                        QuarantineTemplateVariable::ReceivedAt,
+                        Date::new(item.received_at.timestamp()).to_rfc822(),
+                    );
+                    variables.insert_single(
+                        QuarantineTemplateVariable::ReleaseUrl,
+                        self.core.email.quarantine_release_url.clone(),
+                    );
+                    variables.insert_single(
+                        QuarantineTemplateVariable::ReleaseLabel,
+                        locale.quarantine_release.to_string(),
+                    );
+                    variables
+                        .insert_single(QuarantineTemplateVariable::Token, access_token.to_string());
+
+                    (
+                        locale.quarantine_release_confirm,
+                        locale.quarantine_release_confirm_description,
+                    )
+                } else {
+                    (
+                        locale.quarantine_release_failed,
+                        locale.quarantine_not_found,
+                    )
+                }
+            }
+            None => (
+                locale.quarantine_release_failed,
+                locale.quarantine_invalid_link,
+            ),
+        };
+
+        variables.insert_single(QuarantineTemplateVariable::PageTitle, title.to_string());
+        variables.insert_single(QuarantineTemplateVariable::Title, title.to_string());
+        variables.insert_single(
+            QuarantineTemplateVariable::Description,
+            description.to_string(),
+        );
+
+        Ok(self.core.email.quarantine_release_template.eval(&variables))
+    }
+}
@@ -4,13 +4,20 @@
  * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
  */
 
-use crate::core::throttle::NewKey;
+use crate::{core::throttle::NewKey, outbound::lookup::SourceIp};
 use common::{
-    KV_RATE_LIMIT_SMTP, Server, config::smtp::QueueRateLimiter, expr::functions::ResolveVariable,
+    KV_RATE_LIMIT_SMTP, Server,
+    config::smtp::{
+        QueueRateLimiter,
+        queue::{ConnectionStrategy, IpAndHost, IpWarmUp},
+    },
+    expr::functions::ResolveVariable,
+    psl,
 };
+use rand::{Rng, seq::SliceRandom};
 use registry::schema::prelude::Property;
-use std::future::Future;
-use store::write::now;
+use std::{future::Future, net::IpAddr};
+use store::{dispatch::lookup::KeyValue, write::now};
 
 pub trait IsAllowed: Sync + Send {
     fn is_allowed<'x>(
@@ -19,6 +26,21 @@ pub trait IsAllowed: Sync + Send {
         envelope: &impl ResolveVariable,
         session_id: u64,
     ) -> impl Future<Output = Result<(), u64>> + Send;
+
+    fn warm_up_source_ip<'x>(
+        &self,
+        strategy: &'x ConnectionStrategy,
+        remote_ip: IpAddr,
+        mx: &str,
+        session_id: u64,
+    ) -> impl Future<Output = Result<Option<&'x IpAndHost>, u64>> + Send;
+
+    fn warm_up_delivered(
+        &self,
+        ip_host: &IpAndHost,
+        mx: &str,
+        session_id: u64,
+    ) -> impl Future<Output = ()> + Send;
 }
 
 impl IsAllowed for Server {
@@ -69,4 +91,106 @@ impl IsAllowed for Server {
 
         Ok(())
     }
+
+    async fn warm_up_source_ip<'x>(
+        &self,
+        strategy: &'x ConnectionStrategy,
+        remote_ip: IpAddr,
+        mx: &str,
+        session_id: u64,
+    ) -> Result<Option<&'x IpAndHost>, u64> {
+        let ips = if remote_ip.is_ipv4() {
+            &strategy.source_ipv4
+        } else {
+            &strategy.source_ipv6
+        };
+        if ips.iter().all(|ip_host| ip_host.warm_up.is_none()) {
+            return Ok(strategy.source_ip(remote_ip.is_ipv4()));
+        }
+
+        // Split the pool into established IPs and IPs that are still warming up
+        let now = now();
+        let mut established = Vec::with_capacity(ips.len());
+        let mut warming = Vec::with_capacity(ips.len());
+        let mut retry_at = u64::MAX;
+        for ip_host in ips {
+            if let Some(warm_up) = &ip_host.warm_up {
+                if let Some(day) = warm_up.day(now) {
+                    if let Some(limit) = warm_up.daily_limit(day) {
+                        warming.push((ip_host, warm_up, day, limit));
+                    } else {
+                        established.push(ip_host);
+                    }
+                } else {
+                    retry_at = retry_at.min(warm_up.start);
+                }
+            } else {
+                established.push(ip_host);
+            }
+        }
+
+        // Warming IPs are preferred while they have volume left for this provider,
+        // any excess traffic spills over to the established IPs. Only successful
+        // deliveries count towards the daily limit, see `warm_up_delivered`.
+        let provider = psl::domain_str(mx).unwrap_or(mx);
+        warming.shuffle(&mut rand::rng());
+        for (ip_host, warm_up, day, limit) in warming {
+            match self
+                .in_memory_store()
+                .counter_get(warm_up.counter_key(ip_host.ip, day, provider).as_slice())
+                .await
+            {
+                Ok(count) if count < limit as i64 => {
+                    return Ok(Some(ip_host));
+                }
+                Ok(_) => {
+                    trc::event!(
+                        Queue(trc::QueueEvent::WarmUpLimitExceeded),
+                        SpanId = session_id,
+                        LocalIp = ip_host.ip,
+                        Domain = provider.to_string(),
+                        Limit = limit,
+                    );
+
+                    retry_at = retry_at.min(warm_up.day_ends_at(day));
+                }
+                Err(err) => {
+                    trc::error!(err.span_id(session_id).caused_by(trc::location!()));
+                }
+            }
+        }
+
+        match established.len() {
+            0 => Err(retry_at),
+            1 => Ok(established.first().copied()),
+            len => Ok(Some(established[rand::rng().random_range(0..len)])),
+        }
+    }
+
+    async fn warm_up_delivered(&self, ip_host: &IpAndHost, mx: &str, session_id: u64) {
+        let Some(warm_up) = &ip_host.warm_up else {
+            return;
+        };
+        let Some(day) = warm_up
+            .day(now())
+            .filter(|day| warm_up.daily_limit(*day).is_some())
+        else {
+            return;
+        };
+
+        // Track the volume sent to this provider and the total volume sent by this IP today
+        let provider = psl::domain_str(mx).unwrap_or(mx);
+        for key in [
+            warm_up.counter_key(ip_host.ip, day, provider),
+            warm_up.counter_key(ip_host.ip, day, ""),
+        ] {
+            if let Err(err) = self
+                .in_memory_store()
+                .counter_incr(KeyValue::new(key, 1).expires(IpWarmUp::DAY * 2), false)
+                .await
+            {
+                trc::error!(err.span_id(session_id).caused_by(trc::location!()));
+            }
+        }
+    }
 }
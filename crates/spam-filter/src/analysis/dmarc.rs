@@ -6,7 +6,7 @@
 
 use std::future::Future;
 
-use common::Server;
+use common::{Server, config::smtp::resolver::BimiResult};
 use mail_auth::{DkimResult, DmarcResult, SpfResult, dmarc::Policy};
 
 use crate::SpamFilterContext;
@@ -76,5 +76,15 @@ impl SpamFilterAnalyzeDmarc for Server {
                     },
                 ),
             }));
+
+        if let Some(bimi_result) = ctx.input.bimi_result {
+            ctx.result.add_tag(match bimi_result {
+                BimiResult::Pass { logo, .. } if logo.has_vmc => "BIMI_VMC",
+                BimiResult::Pass { .. } => "BIMI_VALID",
+                BimiResult::Fail(_) => "BIMI_INVALID",
+                BimiResult::TempError(_) => "BIMI_DNSFAIL",
+                BimiResult::Declined | BimiResult::None => "BIMI_NA",
+            });
+        }
     }
 }
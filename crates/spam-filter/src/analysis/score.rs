@@ -47,6 +47,7 @@ pub struct SpamFilterScore {
     pub train_spam: Option<bool>,
     pub score: f32,
     pub is_spam: bool,
+    pub quarantine: Option<String>,
 }
 
 impl SpamFilterAnalyzeScore for Server {
@@ -59,6 +60,7 @@ impl SpamFilterAnalyzeScore for Server {
         let mut header_len = 60;
         let mut is_spam_trap = false;
         let mut rbl_count = 0;
+        let mut quarantine = None;
 
         for tag in &ctx.result.tags {
             let score = match self.core.spam.lists.scores.get(tag) {
@@ -69,6 +71,12 @@ impl SpamFilterAnalyzeScore for Server {
                 Some(SpamFilterAction::Reject) => {
                     return SpamFilterAction::Reject;
                 }
+                Some(SpamFilterAction::Quarantine) => {
+                    if quarantine.is_none() {
+                        quarantine = Some(tag.to_string());
+                    }
+                    0.0
+                }
                 None | Some(SpamFilterAction::Disabled) => 0.0,
             };
             if tag == "SPAM_TRAP" {
@@ -186,6 +194,7 @@ impl SpamFilterAnalyzeScore for Server {
                 train_spam,
                 score: final_score,
                 is_spam,
+                quarantine,
             })
         }
     }
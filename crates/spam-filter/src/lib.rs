@@ -9,6 +9,7 @@ pub mod modules;
 
 use analysis::ElementLocation;
 use analysis::url::UrlParts;
+use common::config::smtp::resolver::BimiResult;
 use mail_auth::{ArcOutput, DkimOutput, DmarcResult, IprevOutput, SpfOutput, dmarc::Policy};
 use mail_parser::Message;
 use modules::html::HtmlToken;
@@ -30,6 +31,7 @@ pub struct SpamFilterInput<'x> {
     pub dkim_result: &'x [DkimOutput<'x>],
     pub dmarc_result: Option<&'x DmarcResult>,
     pub dmarc_policy: Option<&'x Policy>,
+    pub bimi_result: Option<&'x BimiResult>,
     pub iprev_result: Option<&'x IprevOutput>,
 
     // Session details
@@ -145,6 +147,7 @@ impl<'x> SpamFilterInput<'x> {
             dkim_result: &[],
             dmarc_result: None,
             dmarc_policy: None,
+            bimi_result: None,
             iprev_result: None,
             remote_ip: IpAddr::V4(Ipv4Addr::LOCALHOST),
             ehlo_domain: None,
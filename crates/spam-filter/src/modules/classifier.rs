@@ -11,9 +11,16 @@ use crate::analysis::url::SpamFilterAnalyzeUrl;
 use crate::modules::html::{A, ALT, HREF, HtmlToken, IMG, SRC, TITLE};
 use crate::{Email, SpamFilterContext, TextPart};
 use crate::{Hostname, SpamFilterInput};
-use common::config::mailstore::spamfilter;
+use common::cache::invalidate::CacheInvalidationBuilder;
+use common::config::mailstore::spamfilter::{
+    self, AccountModelConfig, AccountSpamClassifier, ClassifierConfig,
+};
 use common::manager::{SPAM_CLASSIFIER_KEY, SPAM_TRAINER_KEY};
-use common::{Server, config::mailstore::spamfilter::Location, ipc::BroadcastEvent};
+use common::{
+    Server,
+    config::mailstore::spamfilter::Location,
+    ipc::{BroadcastEvent, CacheInvalidation},
+};
 use mail_auth::DmarcResult;
 use mail_parser::{MessageParser, MimeHeaders};
 use nlp::classifier::feature::{
@@ -35,7 +42,7 @@ use std::{
     hash::{Hash, RandomState},
     sync::Arc,
 };
-use store::ahash::AHashSet;
+use store::ahash::{AHashMap, AHashSet};
 use store::rand::seq::SliceRandom;
 use store::write::{BlobLink, RegistryClass, now};
 use store::{
@@ -48,7 +55,7 @@ use store::{
 use store::{SerializeInfallible, U16_LEN};
 use tokio::sync::{mpsc, oneshot};
 use trc::{AddContext, SpamEvent};
-use types::blob_hash::BlobHash;
+use types::{blob_hash::BlobHash, collection::Collection, field::PrincipalField};
 use unicode_general_category::{GeneralCategory, get_general_category};
 use unicode_normalization::UnicodeNormalization;
 use unicode_security::mixed_script::AugmentedScriptSet;
@@ -65,6 +72,11 @@ pub trait SpamClassifier {
         &self,
         ctx: &'x SpamFilterContext<'_>,
     ) -> impl Future<Output = Tokens<'x>> + Send;
+
+    fn spam_prune_account_models(
+        &self,
+        remove_all: bool,
+    ) -> impl Future<Output = trc::Result<()>> + Send;
 }
 
 #[derive(
@@ -106,6 +118,18 @@ pub enum SpamTrainerClass {
     FtrlCfh(Box<CcfhTrainer<Ftrl, Ftrl>>),
 }
 
+// Personalized model trained from an account's own samples,
+// stored in the account's data subspace.
+#[derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize, Debug)]
+pub struct AccountSpamModel {
+    pub trainer: FhTrainer<Ftrl>,
+    pub ham_count: u64,
+    pub spam_count: u64,
+    pub last_trained_at: u64,
+}
+
+const ACCOUNT_MODEL_FEATURES: usize = 1 << 14;
+
 impl SpamClassifier for Server {
     async fn spam_train(&self, retrain: bool) -> trc::Result<()> {
         let Some(config) = &self.core.spam.classifier else {
@@ -350,6 +374,11 @@ impl SpamClassifier for Server {
         };
         let task = trainer.trainer.spawn(epochs)?;
         let is_fh = matches!(task, TrainTask::Fh { .. });
+        let account_builder = config
+            .account_model
+            .as_ref()
+            .map(|_| FhFeatureBuilder::new(ACCOUNT_MODEL_FEATURES));
+        let mut account_samples: AHashMap<u32, Vec<Sample<FhFeature>>> = AHashMap::new();
 
         // Train
         for chunk in samples.chunks(128) {
@@ -433,6 +462,20 @@ impl SpamClassifier for Server {
                     }
                 }
 
+                // Collect samples for the per-account models
+                if let Some(account_builder) = &account_builder
+                    && let Some(account_id) = account_id
+                    && !sample.is_replay
+                {
+                    let samples = account_samples.entry(account_id).or_default();
+                    if samples.len() < config.reservoir_capacity {
+                        samples.push(Sample::new(
+                            account_builder.build(&tokens, None, config.l2_normalize),
+                            sample.is_spam,
+                        ));
+                    }
+                }
+
                 // Look for stop requests
                 if self.inner.ipc.train_task_controller.should_stop() {
                     trc::event!(
@@ -555,6 +598,11 @@ impl SpamClassifier for Server {
         self.cluster_broadcast(BroadcastEvent::reload(ObjectType::SpamClassifier))
             .await;
 
+        // Train per-account models
+        if !account_samples.is_empty() {
+            train_account_models(self, config, account_samples, retrain).await?;
+        }
+
         trc::event!(
             Spam(SpamEvent::TrainCompleted),
             Total = num_samples,
@@ -586,6 +634,16 @@ impl SpamClassifier for Server {
                 if config.log_scale {
                     feature_builder.scale(&mut tokens);
                 }
+                let account_features = config.account_model.as_ref().map(|account_config| {
+                    (
+                        account_config,
+                        FhFeatureBuilder::new(ACCOUNT_MODEL_FEATURES).build(
+                            &tokens,
+                            None,
+                            config.l2_normalize,
+                        ),
+                    )
+                });
 
                 for rcpt in &ctx.input.env_rcpt_to {
                     let prediction = if let Some(account_id) = self
@@ -594,13 +652,19 @@ impl SpamClassifier for Server {
                         .caused_by(trc::location!())?
                     {
                         has_prediction = true;
-                        classifier
-                            .predict_proba_sample(&feature_builder.build(
-                                &tokens,
-                                account_id.into(),
-                                config.l2_normalize,
-                            ))
-                            .into()
+                        let prediction = classifier.predict_proba_sample(&feature_builder.build(
+                            &tokens,
+                            account_id.into(),
+                            config.l2_normalize,
+                        ));
+                        blend_account_prediction(
+                            self,
+                            account_id,
+                            prediction,
+                            account_features.as_ref(),
+                        )
+                        .await
+                        .into()
                     } else {
                         None
                     };
@@ -628,6 +692,16 @@ impl SpamClassifier for Server {
                 if config.log_scale {
                     feature_builder.scale(&mut tokens);
                 }
+                let account_features = config.account_model.as_ref().map(|account_config| {
+                    (
+                        account_config,
+                        FhFeatureBuilder::new(ACCOUNT_MODEL_FEATURES).build(
+                            &tokens,
+                            None,
+                            config.l2_normalize,
+                        ),
+                    )
+                });
 
                 for rcpt in &ctx.input.env_rcpt_to {
                     let prediction = if let Some(account_id) = self
@@ -636,13 +710,19 @@ impl SpamClassifier for Server {
                         .caused_by(trc::location!())?
                     {
                         has_prediction = true;
-                        classifier
-                            .predict_proba_sample(&feature_builder.build(
-                                &tokens,
-                                account_id.into(),
-                                config.l2_normalize,
-                            ))
-                            .into()
+                        let prediction = classifier.predict_proba_sample(&feature_builder.build(
+                            &tokens,
+                            account_id.into(),
+                            config.l2_normalize,
+                        ));
+                        blend_account_prediction(
+                            self,
+                            account_id,
+                            prediction,
+                            account_features.as_ref(),
+                        )
+                        .await
+                        .into()
                     } else {
                         None
                     };
@@ -872,6 +952,264 @@ impl SpamClassifier for Server {
 
         tokens
     }
+
+    async fn spam_prune_account_models(&self, remove_all: bool) -> trc::Result<()> {
+        let started = Instant::now();
+        let expires = match self
+            .core
+            .spam
+            .classifier
+            .as_ref()
+            .and_then(|config| config.account_model.as_ref())
+        {
+            Some(account_config) if !remove_all => now().saturating_sub(account_config.max_age),
+            _ => u64::MAX,
+        };
+
+        let mut batch = BatchBuilder::new();
+        let mut invalidate = CacheInvalidationBuilder::default();
+        let mut total = 0;
+        for account_id in self
+            .document_ids(u32::MAX, Collection::Principal, PrincipalField::SpamModel)
+            .await
+            .caused_by(trc::location!())?
+        {
+            let is_stale = expires == u64::MAX
+                || self
+                    .store()
+                    .get_value::<Archive<AlignedBytes>>(ValueKey::property(
+                        account_id,
+                        Collection::Principal,
+                        0,
+                        PrincipalField::SpamModel,
+                    ))
+                    .await
+                    .caused_by(trc::location!())?
+                    .is_none_or(|archive| {
+                        archive
+                            .unarchive::<AccountSpamModel>()
+                            .is_ok_and(|model| model.last_trained_at.to_native() < expires)
+                    });
+
+            if is_stale {
+                remove_account_model(&mut batch, account_id);
+                invalidate.invalidate(CacheInvalidation::SpamModel(account_id));
+                total += 1;
+
+                if batch.is_large_batch() {
+                    self.store()
+                        .write(batch.build_all())
+                        .await
+                        .caused_by(trc::location!())?;
+                    batch = BatchBuilder::new();
+                }
+            }
+        }
+        if !batch.is_empty() {
+            self.store()
+                .write(batch.build_all())
+                .await
+                .caused_by(trc::location!())?;
+        }
+        self.invalidate_caches(invalidate)
+            .await
+            .caused_by(trc::location!())?;
+
+        trc::event!(
+            Spam(SpamEvent::AccountModelPruned),
+            Total = total,
+            Elapsed = started.elapsed()
+        );
+
+        Ok(())
+    }
+}
+
+pub fn remove_account_model(batch: &mut BatchBuilder, account_id: u32) {
+    batch
+        .with_account_id(u32::MAX)
+        .with_collection(Collection::Principal)
+        .with_document(account_id)
+        .untag(PrincipalField::SpamModel)
+        .with_account_id(account_id)
+        .with_collection(Collection::Principal)
+        .with_document(0)
+        .clear(PrincipalField::SpamModel);
+}
+
+async fn train_account_models(
+    server: &Server,
+    config: &ClassifierConfig,
+    account_samples: AHashMap<u32, Vec<Sample<FhFeature>>>,
+    retrain: bool,
+) -> trc::Result<()> {
+    let mut invalidate = CacheInvalidationBuilder::default();
+    for (account_id, mut samples) in account_samples {
+        let started = Instant::now();
+        let mut model = if !retrain
+            && let Some(archive) = server
+                .store()
+                .get_value::<Archive<AlignedBytes>>(ValueKey::property(
+                    account_id,
+                    Collection::Principal,
+                    0,
+                    PrincipalField::SpamModel,
+                ))
+                .await
+                .caused_by(trc::location!())?
+        {
+            archive
+                .deserialize::<AccountSpamModel>()
+                .caused_by(trc::location!())?
+        } else {
+            AccountSpamModel {
+                trainer: FhTrainer::new(Ftrl::new(ACCOUNT_MODEL_FEATURES)),
+                ham_count: 0,
+                spam_count: 0,
+                last_trained_at: 0,
+            }
+        };
+
+        model.trainer.optimizer_mut().set_hyperparams(
+            config.w_params.alpha,
+            config.w_params.beta,
+            config.w_params.l1_ratio,
+            config.w_params.l2_ratio,
+        );
+        for sample in &samples {
+            if sample.class > 0.5 {
+                model.spam_count += 1;
+            } else {
+                model.ham_count += 1;
+            }
+        }
+        let epochs = match model.ham_count.min(model.spam_count) {
+            0..=50 => 3,
+            51..=200 => 2,
+            _ => 1,
+        };
+        model.trainer.fit(&mut samples, epochs);
+        model.last_trained_at = now();
+
+        let ham_count = model.ham_count;
+        let spam_count = model.spam_count;
+        let mut batch = BatchBuilder::new();
+        batch
+            .with_account_id(u32::MAX)
+            .with_collection(Collection::Principal)
+            .with_document(account_id)
+            .tag(PrincipalField::SpamModel)
+            .with_account_id(account_id)
+            .with_collection(Collection::Principal)
+            .with_document(0)
+            .set(
+                PrincipalField::SpamModel,
+                Archiver::new(model)
+                    .serialize()
+                    .caused_by(trc::location!())?,
+            );
+        server
+            .store()
+            .write(batch.build_all())
+            .await
+            .caused_by(trc::location!())?;
+        invalidate.invalidate(CacheInvalidation::SpamModel(account_id));
+
+        trc::event!(
+            Spam(SpamEvent::AccountModelTrained),
+            AccountId = account_id,
+            Total = samples.len(),
+            Details = vec![trc::Value::from(ham_count), trc::Value::from(spam_count)],
+            Elapsed = started.elapsed()
+        );
+    }
+
+    server
+        .invalidate_caches(invalidate)
+        .await
+        .caused_by(trc::location!())
+}
+
+// Combines the global prediction with the account's personalized model,
+// once it has seen enough samples of each class.
+pub async fn blend_account_prediction(
+    server: &Server,
+    account_id: u32,
+    prediction: f32,
+    account_features: Option<&(&AccountModelConfig, Vec<FhFeature>)>,
+) -> f32 {
+    let Some((account_config, features)) = account_features else {
+        return prediction;
+    };
+
+    match account_spam_classifier(server, account_id).await {
+        Ok(Some(model))
+            if model.ham_count >= account_config.min_samples
+                && model.spam_count >= account_config.min_samples =>
+        {
+            let account_prediction = model.classifier.predict_proba_sample(features);
+            prediction * (1.0 - account_config.weight) + account_prediction * account_config.weight
+        }
+        Ok(_) => prediction,
+        Err(err) => {
+            trc::error!(
+                err.account_id(account_id)
+                    .caused_by(trc::location!())
+                    .details("Failed to load per-account spam model")
+            );
+            prediction
+        }
+    }
+}
+
+async fn account_spam_classifier(
+    server: &Server,
+    account_id: u32,
+) -> trc::Result<Option<Arc<AccountSpamClassifier>>> {
+    let cache = &server.inner.cache.spam_models;
+    match cache.get_value_or_guard_async(&account_id).await {
+        Ok(model) => {
+            trc::event!(
+                Store(trc::StoreEvent::CacheHit),
+                Key = account_id,
+                Collection = "spamModels",
+            );
+
+            Ok(model)
+        }
+        Err(guard) => {
+            trc::event!(
+                Store(trc::StoreEvent::CacheMiss),
+                Key = account_id,
+                Collection = "spamModels",
+            );
+
+            let model = server
+                .store()
+                .get_value::<Archive<AlignedBytes>>(ValueKey::property(
+                    account_id,
+                    Collection::Principal,
+                    0,
+                    PrincipalField::SpamModel,
+                ))
+                .await
+                .and_then(|archive| {
+                    archive
+                        .map(|archive| archive.deserialize::<AccountSpamModel>())
+                        .transpose()
+                })?
+                .map(|model| {
+                    Arc::new(AccountSpamClassifier {
+                        classifier: model.trainer.build_classifier(),
+                        ham_count: model.ham_count,
+                        spam_count: model.spam_count,
+                    })
+                });
+            let _ = guard.insert(model.clone());
+
+            Ok(model)
+        }
+    }
 }
 
 async fn delete_samples(
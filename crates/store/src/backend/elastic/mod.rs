@@ -53,12 +53,17 @@ impl SearchField {
         match self {
             SearchField::AccountId
             | SearchField::DocumentId
-            | SearchField::Email(EmailSearchField::Size) => json!({
+            | SearchField::Email(EmailSearchField::Size)
+            | SearchField::Calendar(CalendarSearchField::PercentComplete) => json!({
               "type": "integer"
             }),
             SearchField::Id
             | SearchField::Email(EmailSearchField::SentAt | EmailSearchField::ReceivedAt)
-            | SearchField::Calendar(CalendarSearchField::Start)
+            | SearchField::Calendar(
+                CalendarSearchField::Start
+                | CalendarSearchField::Due
+                | CalendarSearchField::Completed,
+            )
             | SearchField::Tracing(TracingSearchField::QueueId | TracingSearchField::EventType) => {
                 json!({
                   "type": "long"
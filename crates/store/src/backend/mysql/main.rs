@@ -89,6 +89,7 @@ impl MysqlStore {
             SUBSPACE_TASK_QUEUE,
             SUBSPACE_DELETED_ITEMS,
             SUBSPACE_SPAM_SAMPLES,
+            SUBSPACE_QUARANTINE,
             SUBSPACE_BLOB_LINK,
             SUBSPACE_IN_MEMORY_VALUE,
             SUBSPACE_PROPERTY,
@@ -196,6 +197,18 @@ async fn create_search_tables<T: SearchableField + MysqlSearchField + 'static>(
 
     conn.query_drop(&query).await.map_err(into_error)?;
 
+    // Add columns missing from tables created by earlier versions,
+    // MySQL lacks ADD COLUMN IF NOT EXISTS so duplicate column errors are ignored
+    for field in T::all_fields() {
+        let column_name = field.column();
+        let column_type = field.column_type();
+        let _ = conn
+            .query_drop(&format!(
+                "ALTER TABLE {table_name} ADD COLUMN {column_name} {column_type}"
+            ))
+            .await;
+    }
+
     // Create indexes
     for field in T::all_fields() {
         if field.is_text() {
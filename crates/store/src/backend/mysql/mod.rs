@@ -93,12 +93,17 @@ impl MysqlSearchField for CalendarSearchField {
             CalendarSearchField::Attendee => "atnd",
             CalendarSearchField::Start => "strt",
             CalendarSearchField::Uid => "uid",
+            CalendarSearchField::Due => "due",
+            CalendarSearchField::Completed => "cmpl",
+            CalendarSearchField::PercentComplete => "pcnt",
         }
     }
 
     fn column_type(&self) -> &'static str {
         match self {
             CalendarSearchField::Start => "BIGINT NOT NULL",
+            CalendarSearchField::Due | CalendarSearchField::Completed => "BIGINT",
+            CalendarSearchField::PercentComplete => "INT",
             _ => "TEXT",
         }
     }
@@ -95,6 +95,7 @@ impl PostgresStore {
             SUBSPACE_TASK_QUEUE,
             SUBSPACE_DELETED_ITEMS,
             SUBSPACE_SPAM_SAMPLES,
+            SUBSPACE_QUARANTINE,
             SUBSPACE_BLOB_LINK,
             SUBSPACE_IN_MEMORY_VALUE,
             SUBSPACE_PROPERTY,
@@ -202,6 +203,27 @@ async fn create_search_tables<T: SearchableField + PsqlSearchField + 'static>(
 
     conn.execute(&query, &[]).await.map_err(into_error)?;
 
+    // Add columns missing from tables created by earlier versions
+    let mut query = format!("ALTER TABLE {table_name} ");
+    for (i, field) in T::all_fields().iter().enumerate() {
+        if i > 0 {
+            query.push_str(", ");
+        }
+        query.push_str(&format!(
+            "ADD COLUMN IF NOT EXISTS {} {}",
+            field.column(),
+            field.column_type()
+        ));
+        if let Some(sort_type) = field.sort_column_type() {
+            query.push_str(&format!(
+                ", ADD COLUMN IF NOT EXISTS {} {}",
+                field.sort_column().unwrap(),
+                sort_type
+            ));
+        }
+    }
+    conn.execute(&query, &[]).await.map_err(into_error)?;
+
     // Create indexes
     for field in T::all_fields() {
         if field.is_text() || field.is_json() {
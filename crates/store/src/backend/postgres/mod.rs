@@ -129,12 +129,18 @@ impl PsqlSearchField for CalendarSearchField {
             CalendarSearchField::Attendee => "atnd",
             CalendarSearchField::Start => "strt",
             CalendarSearchField::Uid => "uid",
+            CalendarSearchField::Due => "due",
+            CalendarSearchField::Completed => "cmpl",
+            CalendarSearchField::PercentComplete => "pcnt",
         }
     }
 
     fn column_type(&self) -> &'static str {
         match self {
-            CalendarSearchField::Start => "BIGINT",
+            CalendarSearchField::Start
+            | CalendarSearchField::Due
+            | CalendarSearchField::Completed => "BIGINT",
+            CalendarSearchField::PercentComplete => "INTEGER",
             CalendarSearchField::Uid => "TEXT",
             _ => "TSVECTOR",
         }
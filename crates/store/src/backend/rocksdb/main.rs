@@ -61,6 +61,7 @@ impl RocksDbStore {
             SUBSPACE_TELEMETRY_METRIC,
             SUBSPACE_SEARCH_INDEX,
             SUBSPACE_SPAM_SAMPLES,
+            SUBSPACE_QUARANTINE,
             SUBSPACE_REGISTRY_IDX,
             SUBSPACE_REGISTRY_PK,
             SUBSPACE_DIRECTORY,
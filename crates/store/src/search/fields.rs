@@ -96,11 +96,21 @@ impl SearchableField for CalendarSearchField {
             SearchField::Calendar(CalendarSearchField::Attendee),
             SearchField::Calendar(CalendarSearchField::Start),
             SearchField::Calendar(CalendarSearchField::Uid),
+            SearchField::Calendar(CalendarSearchField::Due),
+            SearchField::Calendar(CalendarSearchField::Completed),
+            SearchField::Calendar(CalendarSearchField::PercentComplete),
         ]
     }
 
     fn is_indexed(&self) -> bool {
-        matches!(self, CalendarSearchField::Start | CalendarSearchField::Uid)
+        matches!(
+            self,
+            CalendarSearchField::Start
+                | CalendarSearchField::Uid
+                | CalendarSearchField::Due
+                | CalendarSearchField::Completed
+                | CalendarSearchField::PercentComplete
+        )
     }
 
     fn is_text(&self) -> bool {
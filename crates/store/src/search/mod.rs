@@ -71,6 +71,9 @@ pub enum CalendarSearchField {
     Attendee,
     Start,
     Uid,
+    Due,
+    Completed,
+    PercentComplete,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -312,6 +315,9 @@ impl SearchField {
                 CalendarSearchField::Attendee => "attendee",
                 CalendarSearchField::Start => "start",
                 CalendarSearchField::Uid => "uid",
+                CalendarSearchField::Due => "due",
+                CalendarSearchField::Completed => "completed",
+                CalendarSearchField::PercentComplete => "percent",
             },
             SearchField::Contact(field) => match field {
                 ContactSearchField::Member => "member",
@@ -423,6 +423,9 @@ impl SearchField {
                 CalendarSearchField::Attendee => 7,
                 CalendarSearchField::Start => 8,
                 CalendarSearchField::Uid => 9,
+                CalendarSearchField::Due => 10,
+                CalendarSearchField::Completed => 11,
+                CalendarSearchField::PercentComplete => 12,
             },
             SearchField::Contact(field) => match field {
                 ContactSearchField::Member => 3,
@@ -11,10 +11,11 @@ use crate::{
     IndexKey, IndexKeyPrefix, Key, LogKey, SUBSPACE_ACL, SUBSPACE_BLOB_LINK, SUBSPACE_COUNTER,
     SUBSPACE_DELETED_ITEMS, SUBSPACE_DIRECTORY, SUBSPACE_IN_MEMORY_COUNTER,
     SUBSPACE_IN_MEMORY_VALUE, SUBSPACE_INDEXES, SUBSPACE_LOGS, SUBSPACE_PROPERTY,
-    SUBSPACE_QUEUE_EVENT, SUBSPACE_QUEUE_MESSAGE, SUBSPACE_QUOTA, SUBSPACE_REGISTRY,
-    SUBSPACE_REGISTRY_IDX, SUBSPACE_REGISTRY_PK, SUBSPACE_REPORT_IN, SUBSPACE_REPORT_OUT,
-    SUBSPACE_SEARCH_INDEX, SUBSPACE_SPAM_SAMPLES, SUBSPACE_TASK_QUEUE, SUBSPACE_TELEMETRY_METRIC,
-    SUBSPACE_TELEMETRY_SPAN, U16_LEN, U32_LEN, U64_LEN, ValueKey, WITH_SUBSPACE,
+    SUBSPACE_QUARANTINE, SUBSPACE_QUEUE_EVENT, SUBSPACE_QUEUE_MESSAGE, SUBSPACE_QUOTA,
+    SUBSPACE_REGISTRY, SUBSPACE_REGISTRY_IDX, SUBSPACE_REGISTRY_PK, SUBSPACE_REPORT_IN,
+    SUBSPACE_REPORT_OUT, SUBSPACE_SEARCH_INDEX, SUBSPACE_SPAM_SAMPLES, SUBSPACE_TASK_QUEUE,
+    SUBSPACE_TELEMETRY_METRIC, SUBSPACE_TELEMETRY_SPAN, U16_LEN, U32_LEN, U64_LEN, ValueKey,
+    WITH_SUBSPACE,
     write::{
         BlobLink, IndexPropertyClass, RegistryClass, SearchIndex, SearchIndexId, SearchIndexType,
     },
@@ -468,6 +469,7 @@ const MAILBOX_COLLECTION: u8 = Collection::Mailbox as u8;
 const MAILBOX_COUNTER_FIELD: u8 = MailboxField::UidCounter as u8;
 const REG_ARCHIVED_ITEM: u16 = ObjectType::ArchivedItem as u16;
 const REG_SPAM_SAMPLE: u16 = ObjectType::SpamTrainingSample as u16;
+const REG_QUARANTINE: u16 = ObjectType::QuarantinedMessage as u16;
 const REG_ACCOUNT: u16 = ObjectType::Account as u16;
 const REG_DOMAIN: u16 = ObjectType::Domain as u16;
 const REG_TENANT: u16 = ObjectType::Tenant as u16;
@@ -559,6 +561,7 @@ impl ValueClass {
                     | REG_MAILING_LIST | REG_MASKED_EMAIL | REG_PUBLIC_KEY => SUBSPACE_DIRECTORY,
                     REG_ARCHIVED_ITEM => SUBSPACE_DELETED_ITEMS,
                     REG_SPAM_SAMPLE => SUBSPACE_SPAM_SAMPLES,
+                    REG_QUARANTINE => SUBSPACE_QUARANTINE,
                     REG_TRACE => SUBSPACE_TELEMETRY_SPAN,
                     REG_METRIC => SUBSPACE_TELEMETRY_METRIC,
                     REPORT_EXTERNAL_ARF | REPORT_EXTERNAL_DMARC | REPORT_EXTERNAL_TLS => {
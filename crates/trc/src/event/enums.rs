@@ -6,8 +6,8 @@
 
 // This file is auto-generated. Do not edit directly.
 
-pub const TOTAL_EVENT_COUNT: usize = 605;
-pub const TOTAL_METRIC_COUNT: usize = 339;
+pub const TOTAL_EVENT_COUNT: usize = 640;
+pub const TOTAL_METRIC_COUNT: usize = 344;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum EventType {
@@ -124,6 +124,9 @@ pub enum CalendarEvent {
     ItipMessageSent = 583,
     ItipMessageReceived = 584,
     ItipMessageError = 585,
+    BookingAccepted = 636,
+    BookingDeclined = 637,
+    BookingDelegated = 638,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -173,6 +176,8 @@ pub enum DeliveryEvent {
     NullMx = 103,
     Connect = 82,
     ConnectError = 83,
+    SessionReused = 627,
+    SessionResetFailed = 628,
     MissingOutboundHostname = 100,
     GreetingFailed = 93,
     Ehlo = 90,
@@ -180,6 +185,7 @@ pub enum DeliveryEvent {
     Auth = 78,
     AuthFailed = 79,
     MailFrom = 97,
+    SrsRewritten = 623,
     MailFromRejected = 98,
     Delivered = 84,
     RcptTo = 107,
@@ -193,6 +199,9 @@ pub enum DeliveryEvent {
     ImplicitTlsError = 94,
     ConcurrencyLimitExceeded = 81,
     RateLimitExceeded = 104,
+    RelayHostSuccess = 624,
+    RelayHostFailed = 625,
+    RelayHostSkipped = 626,
     DoubleBounce = 86,
     DsnSuccess = 88,
     DsnTempFail = 89,
@@ -293,6 +302,7 @@ pub enum ImapEvent {
     DeleteMailbox = 166,
     RenameMailbox = 185,
     Enable = 167,
+    Compress = 605,
     Expunge = 169,
     Fetch = 170,
     IdleStart = 173,
@@ -311,6 +321,9 @@ pub enum ImapEvent {
     Unsubscribe = 194,
     Thread = 193,
     GetQuota = 57,
+    GetMetadata = 606,
+    SetMetadata = 607,
+    Notify = 608,
     Error = 168,
     RawInput = 183,
     RawOutput = 184,
@@ -385,6 +398,8 @@ pub enum LimitEvent {
     Quota = 242,
     BlobQuota = 237,
     TenantQuota = 553,
+    QuotaWarningSent = 613,
+    QuotaWarningFailed = 614,
     TooManyRequests = 245,
 }
 
@@ -437,6 +452,7 @@ pub enum MessageIngestEvent {
     Duplicate = 281,
     Error = 282,
     SearchIndex = 142,
+    AutocryptKeyCollected = 635,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -468,6 +484,8 @@ pub enum MtaHookEvent {
     ActionDiscard = 305,
     ActionReject = 307,
     ActionQuarantine = 306,
+    ActionHold = 632,
+    ActionReroute = 633,
     Error = 308,
 }
 
@@ -557,6 +575,11 @@ pub enum PushSubscriptionEvent {
 pub enum QueueEvent {
     Started = 390,
     MessageQueued = 380,
+    MessageQuarantined = 615,
+    QuarantineReleased = 616,
+    QuarantineDeleted = 617,
+    QuarantineDigestSent = 618,
+    QuarantineDigestFailed = 619,
     AuthenticatedMessageQueued = 381,
     ReportQueued = 382,
     DsnQueued = 379,
@@ -565,6 +588,7 @@ pub enum QueueEvent {
     Locked = 377,
     BlobNotFound = 374,
     RateLimitExceeded = 384,
+    WarmUpLimitExceeded = 629,
     ConcurrencyLimitExceeded = 375,
     QuotaExceeded = 383,
     BackPressure = 48,
@@ -630,6 +654,8 @@ pub enum SieveEvent {
     ActionDiscard = 398,
     ActionReject = 399,
     SendMessage = 406,
+    Notify = 609,
+    NotifyRateLimit = 610,
     MessageTooLarge = 401,
     ScriptNotFound = 405,
     ListNotFound = 400,
@@ -664,6 +690,9 @@ pub enum SmtpEvent {
     SpfFromFail = 475,
     DmarcPass = 424,
     DmarcFail = 423,
+    BimiPass = 630,
+    BimiFail = 631,
+    AutocryptHeaderAdded = 634,
     IprevPass = 441,
     IprevFail = 440,
     TooManyMessages = 483,
@@ -684,6 +713,9 @@ pub enum SmtpEvent {
     RcptTo = 464,
     RcptToDuplicate = 465,
     RcptToRewritten = 467,
+    SrsDecoded = 620,
+    SrsInvalid = 621,
+    SrsExpired = 622,
     RcptToMissing = 466,
     RcptToGreylisted = 561,
     TooManyRecipients = 484,
@@ -740,6 +772,8 @@ pub enum SpamEvent {
     Classify = 490,
     ModelLoaded = 589,
     ModelNotReady = 496,
+    AccountModelTrained = 611,
+    AccountModelPruned = 612,
     ModelNotFound = 497,
     RulesUpdated = 280,
 }
@@ -797,6 +831,7 @@ pub enum StoreEvent {
     LdapWarning = 519,
     HttpStoreFetch = 492,
     AutoExpunge = 364,
+    SnoozeWakeUp = 639,
     BlobStorePurged = 369,
     DataStorePurged = 368,
 }
@@ -935,6 +970,9 @@ pub enum MetricType {
     DeliveryImplicitTlsError = 81,
     DeliveryConcurrencyLimitExceeded = 82,
     DeliveryRateLimitExceeded = 83,
+    DeliveryRelayHostSuccess = 339,
+    DeliveryRelayHostFailed = 340,
+    DeliverySessionReused = 341,
     DeliveryDoubleBounce = 84,
     DeliveryDsnSuccess = 85,
     DeliveryDsnTempFail = 86,
@@ -1140,6 +1178,8 @@ pub enum MetricType {
     SmtpSpfFromFail = 263,
     SmtpDmarcPass = 264,
     SmtpDmarcFail = 265,
+    SmtpBimiPass = 342,
+    SmtpBimiFail = 343,
     SmtpIprevPass = 266,
     SmtpIprevFail = 267,
     SmtpTooManyMessages = 268,
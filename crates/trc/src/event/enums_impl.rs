@@ -59,6 +59,9 @@ impl EventType {
             b"calendar.itip-message-sent" => EventType::Calendar(CalendarEvent::ItipMessageSent),
             b"calendar.itip-message-received" => EventType::Calendar(CalendarEvent::ItipMessageReceived),
             b"calendar.itip-message-error" => EventType::Calendar(CalendarEvent::ItipMessageError),
+            b"calendar.booking-accepted" => EventType::Calendar(CalendarEvent::BookingAccepted),
+            b"calendar.booking-declined" => EventType::Calendar(CalendarEvent::BookingDeclined),
+            b"calendar.booking-delegated" => EventType::Calendar(CalendarEvent::BookingDelegated),
             b"cluster.startup" => EventType::Cluster(ClusterEvent::Startup),
             b"cluster.subscriber-start" => EventType::Cluster(ClusterEvent::SubscriberStart),
             b"cluster.subscriber-stop" => EventType::Cluster(ClusterEvent::SubscriberStop),
@@ -93,6 +96,8 @@ impl EventType {
             b"delivery.null-mx" => EventType::Delivery(DeliveryEvent::NullMx),
             b"delivery.connect" => EventType::Delivery(DeliveryEvent::Connect),
             b"delivery.connect-error" => EventType::Delivery(DeliveryEvent::ConnectError),
+            b"delivery.session-reused" => EventType::Delivery(DeliveryEvent::SessionReused),
+            b"delivery.session-reset-failed" => EventType::Delivery(DeliveryEvent::SessionResetFailed),
             b"delivery.missing-outbound-hostname" => EventType::Delivery(DeliveryEvent::MissingOutboundHostname),
             b"delivery.greeting-failed" => EventType::Delivery(DeliveryEvent::GreetingFailed),
             b"delivery.ehlo" => EventType::Delivery(DeliveryEvent::Ehlo),
@@ -100,6 +105,7 @@ impl EventType {
             b"delivery.auth" => EventType::Delivery(DeliveryEvent::Auth),
             b"delivery.auth-failed" => EventType::Delivery(DeliveryEvent::AuthFailed),
             b"delivery.mail-from" => EventType::Delivery(DeliveryEvent::MailFrom),
+            b"delivery.srs-rewritten" => EventType::Delivery(DeliveryEvent::SrsRewritten),
             b"delivery.mail-from-rejected" => EventType::Delivery(DeliveryEvent::MailFromRejected),
             b"delivery.delivered" => EventType::Delivery(DeliveryEvent::Delivered),
             b"delivery.rcpt-to" => EventType::Delivery(DeliveryEvent::RcptTo),
@@ -113,6 +119,9 @@ impl EventType {
             b"delivery.implicit-tls-error" => EventType::Delivery(DeliveryEvent::ImplicitTlsError),
             b"delivery.concurrency-limit-exceeded" => EventType::Delivery(DeliveryEvent::ConcurrencyLimitExceeded),
             b"delivery.rate-limit-exceeded" => EventType::Delivery(DeliveryEvent::RateLimitExceeded),
+            b"delivery.relay-host-success" => EventType::Delivery(DeliveryEvent::RelayHostSuccess),
+            b"delivery.relay-host-failed" => EventType::Delivery(DeliveryEvent::RelayHostFailed),
+            b"delivery.relay-host-skipped" => EventType::Delivery(DeliveryEvent::RelayHostSkipped),
             b"delivery.double-bounce" => EventType::Delivery(DeliveryEvent::DoubleBounce),
             b"delivery.dsn-success" => EventType::Delivery(DeliveryEvent::DsnSuccess),
             b"delivery.dsn-temp-fail" => EventType::Delivery(DeliveryEvent::DsnTempFail),
@@ -183,6 +192,7 @@ impl EventType {
             b"imap.delete-mailbox" => EventType::Imap(ImapEvent::DeleteMailbox),
             b"imap.rename-mailbox" => EventType::Imap(ImapEvent::RenameMailbox),
             b"imap.enable" => EventType::Imap(ImapEvent::Enable),
+            b"imap.compress" => EventType::Imap(ImapEvent::Compress),
             b"imap.expunge" => EventType::Imap(ImapEvent::Expunge),
             b"imap.fetch" => EventType::Imap(ImapEvent::Fetch),
             b"imap.idle-start" => EventType::Imap(ImapEvent::IdleStart),
@@ -201,6 +211,9 @@ impl EventType {
             b"imap.unsubscribe" => EventType::Imap(ImapEvent::Unsubscribe),
             b"imap.thread" => EventType::Imap(ImapEvent::Thread),
             b"imap.get-quota" => EventType::Imap(ImapEvent::GetQuota),
+            b"imap.get-metadata" => EventType::Imap(ImapEvent::GetMetadata),
+            b"imap.set-metadata" => EventType::Imap(ImapEvent::SetMetadata),
+            b"imap.notify" => EventType::Imap(ImapEvent::Notify),
             b"imap.error" => EventType::Imap(ImapEvent::Error),
             b"imap.raw-input" => EventType::Imap(ImapEvent::RawInput),
             b"imap.raw-output" => EventType::Imap(ImapEvent::RawOutput),
@@ -255,6 +268,8 @@ impl EventType {
             b"limit.quota" => EventType::Limit(LimitEvent::Quota),
             b"limit.blob-quota" => EventType::Limit(LimitEvent::BlobQuota),
             b"limit.tenant-quota" => EventType::Limit(LimitEvent::TenantQuota),
+            b"limit.quota-warning-sent" => EventType::Limit(LimitEvent::QuotaWarningSent),
+            b"limit.quota-warning-failed" => EventType::Limit(LimitEvent::QuotaWarningFailed),
             b"limit.too-many-requests" => EventType::Limit(LimitEvent::TooManyRequests),
             b"mail-auth.parse-error" => EventType::MailAuth(MailAuthEvent::ParseError),
             b"mail-auth.missing-parameters" => EventType::MailAuth(MailAuthEvent::MissingParameters),
@@ -292,6 +307,7 @@ impl EventType {
             b"message-ingest.duplicate" => EventType::MessageIngest(MessageIngestEvent::Duplicate),
             b"message-ingest.error" => EventType::MessageIngest(MessageIngestEvent::Error),
             b"message-ingest.search-index" => EventType::MessageIngest(MessageIngestEvent::SearchIndex),
+            b"message-ingest.autocrypt-key-collected" => EventType::MessageIngest(MessageIngestEvent::AutocryptKeyCollected),
             b"milter.read" => EventType::Milter(MilterEvent::Read),
             b"milter.write" => EventType::Milter(MilterEvent::Write),
             b"milter.action-accept" => EventType::Milter(MilterEvent::ActionAccept),
@@ -313,6 +329,8 @@ impl EventType {
             b"mta-hook.action-discard" => EventType::MtaHook(MtaHookEvent::ActionDiscard),
             b"mta-hook.action-reject" => EventType::MtaHook(MtaHookEvent::ActionReject),
             b"mta-hook.action-quarantine" => EventType::MtaHook(MtaHookEvent::ActionQuarantine),
+            b"mta-hook.action-hold" => EventType::MtaHook(MtaHookEvent::ActionHold),
+            b"mta-hook.action-reroute" => EventType::MtaHook(MtaHookEvent::ActionReroute),
             b"mta-hook.error" => EventType::MtaHook(MtaHookEvent::Error),
             b"mta-sts.authorized" => EventType::MtaSts(MtaStsEvent::Authorized),
             b"mta-sts.not-authorized" => EventType::MtaSts(MtaStsEvent::NotAuthorized),
@@ -372,6 +390,11 @@ impl EventType {
             b"push-subscription.not-found" => EventType::PushSubscription(PushSubscriptionEvent::NotFound),
             b"queue.started" => EventType::Queue(QueueEvent::Started),
             b"queue.message-queued" => EventType::Queue(QueueEvent::MessageQueued),
+            b"queue.message-quarantined" => EventType::Queue(QueueEvent::MessageQuarantined),
+            b"queue.quarantine-released" => EventType::Queue(QueueEvent::QuarantineReleased),
+            b"queue.quarantine-deleted" => EventType::Queue(QueueEvent::QuarantineDeleted),
+            b"queue.quarantine-digest-sent" => EventType::Queue(QueueEvent::QuarantineDigestSent),
+            b"queue.quarantine-digest-failed" => EventType::Queue(QueueEvent::QuarantineDigestFailed),
             b"queue.authenticated-message-queued" => EventType::Queue(QueueEvent::AuthenticatedMessageQueued),
             b"queue.report-queued" => EventType::Queue(QueueEvent::ReportQueued),
             b"queue.dsn-queued" => EventType::Queue(QueueEvent::DsnQueued),
@@ -380,6 +403,7 @@ impl EventType {
             b"queue.locked" => EventType::Queue(QueueEvent::Locked),
             b"queue.blob-not-found" => EventType::Queue(QueueEvent::BlobNotFound),
             b"queue.rate-limit-exceeded" => EventType::Queue(QueueEvent::RateLimitExceeded),
+            b"queue.warm-up-limit-exceeded" => EventType::Queue(QueueEvent::WarmUpLimitExceeded),
             b"queue.concurrency-limit-exceeded" => EventType::Queue(QueueEvent::ConcurrencyLimitExceeded),
             b"queue.quota-exceeded" => EventType::Queue(QueueEvent::QuotaExceeded),
             b"queue.back-pressure" => EventType::Queue(QueueEvent::BackPressure),
@@ -420,6 +444,8 @@ impl EventType {
             b"sieve.action-discard" => EventType::Sieve(SieveEvent::ActionDiscard),
             b"sieve.action-reject" => EventType::Sieve(SieveEvent::ActionReject),
             b"sieve.send-message" => EventType::Sieve(SieveEvent::SendMessage),
+            b"sieve.notify" => EventType::Sieve(SieveEvent::Notify),
+            b"sieve.notify-rate-limit" => EventType::Sieve(SieveEvent::NotifyRateLimit),
             b"sieve.message-too-large" => EventType::Sieve(SieveEvent::MessageTooLarge),
             b"sieve.script-not-found" => EventType::Sieve(SieveEvent::ScriptNotFound),
             b"sieve.list-not-found" => EventType::Sieve(SieveEvent::ListNotFound),
@@ -449,6 +475,9 @@ impl EventType {
             b"smtp.spf-from-fail" => EventType::Smtp(SmtpEvent::SpfFromFail),
             b"smtp.dmarc-pass" => EventType::Smtp(SmtpEvent::DmarcPass),
             b"smtp.dmarc-fail" => EventType::Smtp(SmtpEvent::DmarcFail),
+            b"smtp.bimi-pass" => EventType::Smtp(SmtpEvent::BimiPass),
+            b"smtp.bimi-fail" => EventType::Smtp(SmtpEvent::BimiFail),
+            b"smtp.autocrypt-header-added" => EventType::Smtp(SmtpEvent::AutocryptHeaderAdded),
             b"smtp.iprev-pass" => EventType::Smtp(SmtpEvent::IprevPass),
             b"smtp.iprev-fail" => EventType::Smtp(SmtpEvent::IprevFail),
             b"smtp.too-many-messages" => EventType::Smtp(SmtpEvent::TooManyMessages),
@@ -469,6 +498,9 @@ impl EventType {
             b"smtp.rcpt-to" => EventType::Smtp(SmtpEvent::RcptTo),
             b"smtp.rcpt-to-duplicate" => EventType::Smtp(SmtpEvent::RcptToDuplicate),
             b"smtp.rcpt-to-rewritten" => EventType::Smtp(SmtpEvent::RcptToRewritten),
+            b"smtp.srs-decoded" => EventType::Smtp(SmtpEvent::SrsDecoded),
+            b"smtp.srs-invalid" => EventType::Smtp(SmtpEvent::SrsInvalid),
+            b"smtp.srs-expired" => EventType::Smtp(SmtpEvent::SrsExpired),
             b"smtp.rcpt-to-missing" => EventType::Smtp(SmtpEvent::RcptToMissing),
             b"smtp.rcpt-to-greylisted" => EventType::Smtp(SmtpEvent::RcptToGreylisted),
             b"smtp.too-many-recipients" => EventType::Smtp(SmtpEvent::TooManyRecipients),
@@ -520,6 +552,8 @@ impl EventType {
             b"spam.classify" => EventType::Spam(SpamEvent::Classify),
             b"spam.model-loaded" => EventType::Spam(SpamEvent::ModelLoaded),
             b"spam.model-not-ready" => EventType::Spam(SpamEvent::ModelNotReady),
+            b"spam.account-model-trained" => EventType::Spam(SpamEvent::AccountModelTrained),
+            b"spam.account-model-pruned" => EventType::Spam(SpamEvent::AccountModelPruned),
             b"spam.model-not-found" => EventType::Spam(SpamEvent::ModelNotFound),
             b"spam.rules-updated" => EventType::Spam(SpamEvent::RulesUpdated),
             b"spf.pass" => EventType::Spf(SpfEvent::Pass),
@@ -567,6 +601,7 @@ impl EventType {
             b"store.ldap-warning" => EventType::Store(StoreEvent::LdapWarning),
             b"store.http-store-fetch" => EventType::Store(StoreEvent::HttpStoreFetch),
             b"store.auto-expunge" => EventType::Store(StoreEvent::AutoExpunge),
+            b"store.snooze-wake-up" => EventType::Store(StoreEvent::SnoozeWakeUp),
             b"store.blob-store-purged" => EventType::Store(StoreEvent::BlobStorePurged),
             b"store.data-store-purged" => EventType::Store(StoreEvent::DataStorePurged),
             b"task-manager.task-acquired" => EventType::TaskManager(TaskManagerEvent::TaskAcquired),
@@ -676,6 +711,9 @@ impl EventType {
                 "calendar.itip-message-received"
             }
             EventType::Calendar(CalendarEvent::ItipMessageError) => "calendar.itip-message-error",
+            EventType::Calendar(CalendarEvent::BookingAccepted) => "calendar.booking-accepted",
+            EventType::Calendar(CalendarEvent::BookingDeclined) => "calendar.booking-declined",
+            EventType::Calendar(CalendarEvent::BookingDelegated) => "calendar.booking-delegated",
             EventType::Cluster(ClusterEvent::Startup) => "cluster.startup",
             EventType::Cluster(ClusterEvent::SubscriberStart) => "cluster.subscriber-start",
             EventType::Cluster(ClusterEvent::SubscriberStop) => "cluster.subscriber-stop",
@@ -716,6 +754,10 @@ impl EventType {
             EventType::Delivery(DeliveryEvent::NullMx) => "delivery.null-mx",
             EventType::Delivery(DeliveryEvent::Connect) => "delivery.connect",
             EventType::Delivery(DeliveryEvent::ConnectError) => "delivery.connect-error",
+            EventType::Delivery(DeliveryEvent::SessionReused) => "delivery.session-reused",
+            EventType::Delivery(DeliveryEvent::SessionResetFailed) => {
+                "delivery.session-reset-failed"
+            }
             EventType::Delivery(DeliveryEvent::MissingOutboundHostname) => {
                 "delivery.missing-outbound-hostname"
             }
@@ -725,6 +767,7 @@ impl EventType {
             EventType::Delivery(DeliveryEvent::Auth) => "delivery.auth",
             EventType::Delivery(DeliveryEvent::AuthFailed) => "delivery.auth-failed",
             EventType::Delivery(DeliveryEvent::MailFrom) => "delivery.mail-from",
+            EventType::Delivery(DeliveryEvent::SrsRewritten) => "delivery.srs-rewritten",
             EventType::Delivery(DeliveryEvent::MailFromRejected) => "delivery.mail-from-rejected",
             EventType::Delivery(DeliveryEvent::Delivered) => "delivery.delivered",
             EventType::Delivery(DeliveryEvent::RcptTo) => "delivery.rcpt-to",
@@ -742,6 +785,9 @@ impl EventType {
                 "delivery.concurrency-limit-exceeded"
             }
             EventType::Delivery(DeliveryEvent::RateLimitExceeded) => "delivery.rate-limit-exceeded",
+            EventType::Delivery(DeliveryEvent::RelayHostSuccess) => "delivery.relay-host-success",
+            EventType::Delivery(DeliveryEvent::RelayHostFailed) => "delivery.relay-host-failed",
+            EventType::Delivery(DeliveryEvent::RelayHostSkipped) => "delivery.relay-host-skipped",
             EventType::Delivery(DeliveryEvent::DoubleBounce) => "delivery.double-bounce",
             EventType::Delivery(DeliveryEvent::DsnSuccess) => "delivery.dsn-success",
             EventType::Delivery(DeliveryEvent::DsnTempFail) => "delivery.dsn-temp-fail",
@@ -814,6 +860,7 @@ impl EventType {
             EventType::Imap(ImapEvent::DeleteMailbox) => "imap.delete-mailbox",
             EventType::Imap(ImapEvent::RenameMailbox) => "imap.rename-mailbox",
             EventType::Imap(ImapEvent::Enable) => "imap.enable",
+            EventType::Imap(ImapEvent::Compress) => "imap.compress",
             EventType::Imap(ImapEvent::Expunge) => "imap.expunge",
             EventType::Imap(ImapEvent::Fetch) => "imap.fetch",
             EventType::Imap(ImapEvent::IdleStart) => "imap.idle-start",
@@ -832,6 +879,9 @@ impl EventType {
             EventType::Imap(ImapEvent::Unsubscribe) => "imap.unsubscribe",
             EventType::Imap(ImapEvent::Thread) => "imap.thread",
             EventType::Imap(ImapEvent::GetQuota) => "imap.get-quota",
+            EventType::Imap(ImapEvent::GetMetadata) => "imap.get-metadata",
+            EventType::Imap(ImapEvent::SetMetadata) => "imap.set-metadata",
+            EventType::Imap(ImapEvent::Notify) => "imap.notify",
             EventType::Imap(ImapEvent::Error) => "imap.error",
             EventType::Imap(ImapEvent::RawInput) => "imap.raw-input",
             EventType::Imap(ImapEvent::RawOutput) => "imap.raw-output",
@@ -918,6 +968,8 @@ impl EventType {
             EventType::Limit(LimitEvent::Quota) => "limit.quota",
             EventType::Limit(LimitEvent::BlobQuota) => "limit.blob-quota",
             EventType::Limit(LimitEvent::TenantQuota) => "limit.tenant-quota",
+            EventType::Limit(LimitEvent::QuotaWarningSent) => "limit.quota-warning-sent",
+            EventType::Limit(LimitEvent::QuotaWarningFailed) => "limit.quota-warning-failed",
             EventType::Limit(LimitEvent::TooManyRequests) => "limit.too-many-requests",
             EventType::MailAuth(MailAuthEvent::ParseError) => "mail-auth.parse-error",
             EventType::MailAuth(MailAuthEvent::MissingParameters) => "mail-auth.missing-parameters",
@@ -972,6 +1024,9 @@ impl EventType {
                 "message-ingest.search-index"
             }
             EventType::Milter(MilterEvent::Read) => "milter.read",
+            EventType::MessageIngest(MessageIngestEvent::AutocryptKeyCollected) => {
+                "message-ingest.autocrypt-key-collected"
+            }
             EventType::Milter(MilterEvent::Write) => "milter.write",
             EventType::Milter(MilterEvent::ActionAccept) => "milter.action-accept",
             EventType::Milter(MilterEvent::ActionDiscard) => "milter.action-discard",
@@ -994,6 +1049,8 @@ impl EventType {
             EventType::MtaHook(MtaHookEvent::ActionDiscard) => "mta-hook.action-discard",
             EventType::MtaHook(MtaHookEvent::ActionReject) => "mta-hook.action-reject",
             EventType::MtaHook(MtaHookEvent::ActionQuarantine) => "mta-hook.action-quarantine",
+            EventType::MtaHook(MtaHookEvent::ActionHold) => "mta-hook.action-hold",
+            EventType::MtaHook(MtaHookEvent::ActionReroute) => "mta-hook.action-reroute",
             EventType::MtaHook(MtaHookEvent::Error) => "mta-hook.error",
             EventType::MtaSts(MtaStsEvent::Authorized) => "mta-sts.authorized",
             EventType::MtaSts(MtaStsEvent::NotAuthorized) => "mta-sts.not-authorized",
@@ -1085,6 +1142,13 @@ impl EventType {
             }
             EventType::Queue(QueueEvent::Started) => "queue.started",
             EventType::Queue(QueueEvent::MessageQueued) => "queue.message-queued",
+            EventType::Queue(QueueEvent::MessageQuarantined) => "queue.message-quarantined",
+            EventType::Queue(QueueEvent::QuarantineReleased) => "queue.quarantine-released",
+            EventType::Queue(QueueEvent::QuarantineDeleted) => "queue.quarantine-deleted",
+            EventType::Queue(QueueEvent::QuarantineDigestSent) => "queue.quarantine-digest-sent",
+            EventType::Queue(QueueEvent::QuarantineDigestFailed) => {
+                "queue.quarantine-digest-failed"
+            }
             EventType::Queue(QueueEvent::AuthenticatedMessageQueued) => {
                 "queue.authenticated-message-queued"
             }
@@ -1095,6 +1159,7 @@ impl EventType {
             EventType::Queue(QueueEvent::Locked) => "queue.locked",
             EventType::Queue(QueueEvent::BlobNotFound) => "queue.blob-not-found",
             EventType::Queue(QueueEvent::RateLimitExceeded) => "queue.rate-limit-exceeded",
+            EventType::Queue(QueueEvent::WarmUpLimitExceeded) => "queue.warm-up-limit-exceeded",
             EventType::Queue(QueueEvent::ConcurrencyLimitExceeded) => {
                 "queue.concurrency-limit-exceeded"
             }
@@ -1143,6 +1208,8 @@ impl EventType {
             EventType::Sieve(SieveEvent::ActionDiscard) => "sieve.action-discard",
             EventType::Sieve(SieveEvent::ActionReject) => "sieve.action-reject",
             EventType::Sieve(SieveEvent::SendMessage) => "sieve.send-message",
+            EventType::Sieve(SieveEvent::Notify) => "sieve.notify",
+            EventType::Sieve(SieveEvent::NotifyRateLimit) => "sieve.notify-rate-limit",
             EventType::Sieve(SieveEvent::MessageTooLarge) => "sieve.message-too-large",
             EventType::Sieve(SieveEvent::ScriptNotFound) => "sieve.script-not-found",
             EventType::Sieve(SieveEvent::ListNotFound) => "sieve.list-not-found",
@@ -1174,6 +1241,9 @@ impl EventType {
             EventType::Smtp(SmtpEvent::SpfFromFail) => "smtp.spf-from-fail",
             EventType::Smtp(SmtpEvent::DmarcPass) => "smtp.dmarc-pass",
             EventType::Smtp(SmtpEvent::DmarcFail) => "smtp.dmarc-fail",
+            EventType::Smtp(SmtpEvent::BimiPass) => "smtp.bimi-pass",
+            EventType::Smtp(SmtpEvent::BimiFail) => "smtp.bimi-fail",
+            EventType::Smtp(SmtpEvent::AutocryptHeaderAdded) => "smtp.autocrypt-header-added",
             EventType::Smtp(SmtpEvent::IprevPass) => "smtp.iprev-pass",
             EventType::Smtp(SmtpEvent::IprevFail) => "smtp.iprev-fail",
             EventType::Smtp(SmtpEvent::TooManyMessages) => "smtp.too-many-messages",
@@ -1194,6 +1264,9 @@ impl EventType {
             EventType::Smtp(SmtpEvent::RcptTo) => "smtp.rcpt-to",
             EventType::Smtp(SmtpEvent::RcptToDuplicate) => "smtp.rcpt-to-duplicate",
             EventType::Smtp(SmtpEvent::RcptToRewritten) => "smtp.rcpt-to-rewritten",
+            EventType::Smtp(SmtpEvent::SrsDecoded) => "smtp.srs-decoded",
+            EventType::Smtp(SmtpEvent::SrsInvalid) => "smtp.srs-invalid",
+            EventType::Smtp(SmtpEvent::SrsExpired) => "smtp.srs-expired",
             EventType::Smtp(SmtpEvent::RcptToMissing) => "smtp.rcpt-to-missing",
             EventType::Smtp(SmtpEvent::RcptToGreylisted) => "smtp.rcpt-to-greylisted",
             EventType::Smtp(SmtpEvent::TooManyRecipients) => "smtp.too-many-recipients",
@@ -1247,6 +1320,8 @@ impl EventType {
             EventType::Spam(SpamEvent::Classify) => "spam.classify",
             EventType::Spam(SpamEvent::ModelLoaded) => "spam.model-loaded",
             EventType::Spam(SpamEvent::ModelNotReady) => "spam.model-not-ready",
+            EventType::Spam(SpamEvent::AccountModelTrained) => "spam.account-model-trained",
+            EventType::Spam(SpamEvent::AccountModelPruned) => "spam.account-model-pruned",
             EventType::Spam(SpamEvent::ModelNotFound) => "spam.model-not-found",
             EventType::Spam(SpamEvent::RulesUpdated) => "spam.rules-updated",
             EventType::Spf(SpfEvent::Pass) => "spf.pass",
@@ -1294,6 +1369,7 @@ impl EventType {
             EventType::Store(StoreEvent::LdapWarning) => "store.ldap-warning",
             EventType::Store(StoreEvent::HttpStoreFetch) => "store.http-store-fetch",
             EventType::Store(StoreEvent::AutoExpunge) => "store.auto-expunge",
+            EventType::Store(StoreEvent::SnoozeWakeUp) => "store.snooze-wake-up",
             EventType::Store(StoreEvent::BlobStorePurged) => "store.blob-store-purged",
             EventType::Store(StoreEvent::DataStorePurged) => "store.data-store-purged",
             EventType::TaskManager(TaskManagerEvent::TaskAcquired) => "task-manager.task-acquired",
@@ -1414,6 +1490,9 @@ impl EventType {
             EventType::Calendar(CalendarEvent::ItipMessageSent) => 583,
             EventType::Calendar(CalendarEvent::ItipMessageReceived) => 584,
             EventType::Calendar(CalendarEvent::ItipMessageError) => 585,
+            EventType::Calendar(CalendarEvent::BookingAccepted) => 636,
+            EventType::Calendar(CalendarEvent::BookingDeclined) => 637,
+            EventType::Calendar(CalendarEvent::BookingDelegated) => 638,
             EventType::Cluster(ClusterEvent::Startup) => 278,
             EventType::Cluster(ClusterEvent::SubscriberStart) => 39,
             EventType::Cluster(ClusterEvent::SubscriberStop) => 40,
@@ -1448,6 +1527,8 @@ impl EventType {
             EventType::Delivery(DeliveryEvent::NullMx) => 103,
             EventType::Delivery(DeliveryEvent::Connect) => 82,
             EventType::Delivery(DeliveryEvent::ConnectError) => 83,
+            EventType::Delivery(DeliveryEvent::SessionReused) => 627,
+            EventType::Delivery(DeliveryEvent::SessionResetFailed) => 628,
             EventType::Delivery(DeliveryEvent::MissingOutboundHostname) => 100,
             EventType::Delivery(DeliveryEvent::GreetingFailed) => 93,
             EventType::Delivery(DeliveryEvent::Ehlo) => 90,
@@ -1455,6 +1536,7 @@ impl EventType {
             EventType::Delivery(DeliveryEvent::Auth) => 78,
             EventType::Delivery(DeliveryEvent::AuthFailed) => 79,
             EventType::Delivery(DeliveryEvent::MailFrom) => 97,
+            EventType::Delivery(DeliveryEvent::SrsRewritten) => 623,
             EventType::Delivery(DeliveryEvent::MailFromRejected) => 98,
             EventType::Delivery(DeliveryEvent::Delivered) => 84,
             EventType::Delivery(DeliveryEvent::RcptTo) => 107,
@@ -1468,6 +1550,9 @@ impl EventType {
             EventType::Delivery(DeliveryEvent::ImplicitTlsError) => 94,
             EventType::Delivery(DeliveryEvent::ConcurrencyLimitExceeded) => 81,
             EventType::Delivery(DeliveryEvent::RateLimitExceeded) => 104,
+            EventType::Delivery(DeliveryEvent::RelayHostSuccess) => 624,
+            EventType::Delivery(DeliveryEvent::RelayHostFailed) => 625,
+            EventType::Delivery(DeliveryEvent::RelayHostSkipped) => 626,
             EventType::Delivery(DeliveryEvent::DoubleBounce) => 86,
             EventType::Delivery(DeliveryEvent::DsnSuccess) => 88,
             EventType::Delivery(DeliveryEvent::DsnTempFail) => 89,
@@ -1538,6 +1623,7 @@ impl EventType {
             EventType::Imap(ImapEvent::DeleteMailbox) => 166,
             EventType::Imap(ImapEvent::RenameMailbox) => 185,
             EventType::Imap(ImapEvent::Enable) => 167,
+            EventType::Imap(ImapEvent::Compress) => 605,
             EventType::Imap(ImapEvent::Expunge) => 169,
             EventType::Imap(ImapEvent::Fetch) => 170,
             EventType::Imap(ImapEvent::IdleStart) => 173,
@@ -1556,6 +1642,9 @@ impl EventType {
             EventType::Imap(ImapEvent::Unsubscribe) => 194,
             EventType::Imap(ImapEvent::Thread) => 193,
             EventType::Imap(ImapEvent::GetQuota) => 57,
+            EventType::Imap(ImapEvent::GetMetadata) => 606,
+            EventType::Imap(ImapEvent::SetMetadata) => 607,
+            EventType::Imap(ImapEvent::Notify) => 608,
             EventType::Imap(ImapEvent::Error) => 168,
             EventType::Imap(ImapEvent::RawInput) => 183,
             EventType::Imap(ImapEvent::RawOutput) => 184,
@@ -1610,6 +1699,8 @@ impl EventType {
             EventType::Limit(LimitEvent::Quota) => 242,
             EventType::Limit(LimitEvent::BlobQuota) => 237,
             EventType::Limit(LimitEvent::TenantQuota) => 553,
+            EventType::Limit(LimitEvent::QuotaWarningSent) => 613,
+            EventType::Limit(LimitEvent::QuotaWarningFailed) => 614,
             EventType::Limit(LimitEvent::TooManyRequests) => 245,
             EventType::MailAuth(MailAuthEvent::ParseError) => 254,
             EventType::MailAuth(MailAuthEvent::MissingParameters) => 252,
@@ -1647,6 +1738,7 @@ impl EventType {
             EventType::MessageIngest(MessageIngestEvent::Duplicate) => 281,
             EventType::MessageIngest(MessageIngestEvent::Error) => 282,
             EventType::MessageIngest(MessageIngestEvent::SearchIndex) => 142,
+            EventType::MessageIngest(MessageIngestEvent::AutocryptKeyCollected) => 635,
             EventType::Milter(MilterEvent::Read) => 299,
             EventType::Milter(MilterEvent::Write) => 303,
             EventType::Milter(MilterEvent::ActionAccept) => 287,
@@ -1668,6 +1760,8 @@ impl EventType {
             EventType::MtaHook(MtaHookEvent::ActionDiscard) => 305,
             EventType::MtaHook(MtaHookEvent::ActionReject) => 307,
             EventType::MtaHook(MtaHookEvent::ActionQuarantine) => 306,
+            EventType::MtaHook(MtaHookEvent::ActionHold) => 632,
+            EventType::MtaHook(MtaHookEvent::ActionReroute) => 633,
             EventType::MtaHook(MtaHookEvent::Error) => 308,
             EventType::MtaSts(MtaStsEvent::Authorized) => 309,
             EventType::MtaSts(MtaStsEvent::NotAuthorized) => 311,
@@ -1727,6 +1821,11 @@ impl EventType {
             EventType::PushSubscription(PushSubscriptionEvent::NotFound) => 372,
             EventType::Queue(QueueEvent::Started) => 390,
             EventType::Queue(QueueEvent::MessageQueued) => 380,
+            EventType::Queue(QueueEvent::MessageQuarantined) => 615,
+            EventType::Queue(QueueEvent::QuarantineReleased) => 616,
+            EventType::Queue(QueueEvent::QuarantineDeleted) => 617,
+            EventType::Queue(QueueEvent::QuarantineDigestSent) => 618,
+            EventType::Queue(QueueEvent::QuarantineDigestFailed) => 619,
             EventType::Queue(QueueEvent::AuthenticatedMessageQueued) => 381,
             EventType::Queue(QueueEvent::ReportQueued) => 382,
             EventType::Queue(QueueEvent::DsnQueued) => 379,
@@ -1735,6 +1834,7 @@ impl EventType {
             EventType::Queue(QueueEvent::Locked) => 377,
             EventType::Queue(QueueEvent::BlobNotFound) => 374,
             EventType::Queue(QueueEvent::RateLimitExceeded) => 384,
+            EventType::Queue(QueueEvent::WarmUpLimitExceeded) => 629,
             EventType::Queue(QueueEvent::ConcurrencyLimitExceeded) => 375,
             EventType::Queue(QueueEvent::QuotaExceeded) => 383,
             EventType::Queue(QueueEvent::BackPressure) => 48,
@@ -1775,6 +1875,8 @@ impl EventType {
             EventType::Sieve(SieveEvent::ActionDiscard) => 398,
             EventType::Sieve(SieveEvent::ActionReject) => 399,
             EventType::Sieve(SieveEvent::SendMessage) => 406,
+            EventType::Sieve(SieveEvent::Notify) => 609,
+            EventType::Sieve(SieveEvent::NotifyRateLimit) => 610,
             EventType::Sieve(SieveEvent::MessageTooLarge) => 401,
             EventType::Sieve(SieveEvent::ScriptNotFound) => 405,
             EventType::Sieve(SieveEvent::ListNotFound) => 400,
@@ -1804,6 +1906,9 @@ impl EventType {
             EventType::Smtp(SmtpEvent::SpfFromFail) => 475,
             EventType::Smtp(SmtpEvent::DmarcPass) => 424,
             EventType::Smtp(SmtpEvent::DmarcFail) => 423,
+            EventType::Smtp(SmtpEvent::BimiPass) => 630,
+            EventType::Smtp(SmtpEvent::BimiFail) => 631,
+            EventType::Smtp(SmtpEvent::AutocryptHeaderAdded) => 634,
             EventType::Smtp(SmtpEvent::IprevPass) => 441,
             EventType::Smtp(SmtpEvent::IprevFail) => 440,
             EventType::Smtp(SmtpEvent::TooManyMessages) => 483,
@@ -1824,6 +1929,9 @@ impl EventType {
             EventType::Smtp(SmtpEvent::RcptTo) => 464,
             EventType::Smtp(SmtpEvent::RcptToDuplicate) => 465,
             EventType::Smtp(SmtpEvent::RcptToRewritten) => 467,
+            EventType::Smtp(SmtpEvent::SrsDecoded) => 620,
+            EventType::Smtp(SmtpEvent::SrsInvalid) => 621,
+            EventType::Smtp(SmtpEvent::SrsExpired) => 622,
             EventType::Smtp(SmtpEvent::RcptToMissing) => 466,
             EventType::Smtp(SmtpEvent::RcptToGreylisted) => 561,
             EventType::Smtp(SmtpEvent::TooManyRecipients) => 484,
@@ -1875,6 +1983,8 @@ impl EventType {
             EventType::Spam(SpamEvent::Classify) => 490,
             EventType::Spam(SpamEvent::ModelLoaded) => 589,
             EventType::Spam(SpamEvent::ModelNotReady) => 496,
+            EventType::Spam(SpamEvent::AccountModelTrained) => 611,
+            EventType::Spam(SpamEvent::AccountModelPruned) => 612,
             EventType::Spam(SpamEvent::ModelNotFound) => 497,
             EventType::Spam(SpamEvent::RulesUpdated) => 280,
             EventType::Spf(SpfEvent::Pass) => 501,
@@ -1922,6 +2032,7 @@ impl EventType {
             EventType::Store(StoreEvent::LdapWarning) => 519,
             EventType::Store(StoreEvent::HttpStoreFetch) => 492,
             EventType::Store(StoreEvent::AutoExpunge) => 364,
+            EventType::Store(StoreEvent::SnoozeWakeUp) => 639,
             EventType::Store(StoreEvent::BlobStorePurged) => 369,
             EventType::Store(StoreEvent::DataStorePurged) => 368,
             EventType::TaskManager(TaskManagerEvent::TaskAcquired) => 578,
@@ -2024,6 +2135,9 @@ impl EventType {
             583 => Some(EventType::Calendar(CalendarEvent::ItipMessageSent)),
             584 => Some(EventType::Calendar(CalendarEvent::ItipMessageReceived)),
             585 => Some(EventType::Calendar(CalendarEvent::ItipMessageError)),
+            636 => Some(EventType::Calendar(CalendarEvent::BookingAccepted)),
+            637 => Some(EventType::Calendar(CalendarEvent::BookingDeclined)),
+            638 => Some(EventType::Calendar(CalendarEvent::BookingDelegated)),
             278 => Some(EventType::Cluster(ClusterEvent::Startup)),
             39 => Some(EventType::Cluster(ClusterEvent::SubscriberStart)),
             40 => Some(EventType::Cluster(ClusterEvent::SubscriberStop)),
@@ -2058,6 +2172,8 @@ impl EventType {
             103 => Some(EventType::Delivery(DeliveryEvent::NullMx)),
             82 => Some(EventType::Delivery(DeliveryEvent::Connect)),
             83 => Some(EventType::Delivery(DeliveryEvent::ConnectError)),
+            627 => Some(EventType::Delivery(DeliveryEvent::SessionReused)),
+            628 => Some(EventType::Delivery(DeliveryEvent::SessionResetFailed)),
             100 => Some(EventType::Delivery(DeliveryEvent::MissingOutboundHostname)),
             93 => Some(EventType::Delivery(DeliveryEvent::GreetingFailed)),
             90 => Some(EventType::Delivery(DeliveryEvent::Ehlo)),
@@ -2065,6 +2181,7 @@ impl EventType {
             78 => Some(EventType::Delivery(DeliveryEvent::Auth)),
             79 => Some(EventType::Delivery(DeliveryEvent::AuthFailed)),
             97 => Some(EventType::Delivery(DeliveryEvent::MailFrom)),
+            623 => Some(EventType::Delivery(DeliveryEvent::SrsRewritten)),
             98 => Some(EventType::Delivery(DeliveryEvent::MailFromRejected)),
             84 => Some(EventType::Delivery(DeliveryEvent::Delivered)),
             107 => Some(EventType::Delivery(DeliveryEvent::RcptTo)),
@@ -2078,6 +2195,9 @@ impl EventType {
             94 => Some(EventType::Delivery(DeliveryEvent::ImplicitTlsError)),
             81 => Some(EventType::Delivery(DeliveryEvent::ConcurrencyLimitExceeded)),
             104 => Some(EventType::Delivery(DeliveryEvent::RateLimitExceeded)),
+            624 => Some(EventType::Delivery(DeliveryEvent::RelayHostSuccess)),
+            625 => Some(EventType::Delivery(DeliveryEvent::RelayHostFailed)),
+            626 => Some(EventType::Delivery(DeliveryEvent::RelayHostSkipped)),
             86 => Some(EventType::Delivery(DeliveryEvent::DoubleBounce)),
             88 => Some(EventType::Delivery(DeliveryEvent::DsnSuccess)),
             89 => Some(EventType::Delivery(DeliveryEvent::DsnTempFail)),
@@ -2148,6 +2268,7 @@ impl EventType {
             166 => Some(EventType::Imap(ImapEvent::DeleteMailbox)),
             185 => Some(EventType::Imap(ImapEvent::RenameMailbox)),
             167 => Some(EventType::Imap(ImapEvent::Enable)),
+            605 => Some(EventType::Imap(ImapEvent::Compress)),
             169 => Some(EventType::Imap(ImapEvent::Expunge)),
             170 => Some(EventType::Imap(ImapEvent::Fetch)),
             173 => Some(EventType::Imap(ImapEvent::IdleStart)),
@@ -2166,6 +2287,9 @@ impl EventType {
             194 => Some(EventType::Imap(ImapEvent::Unsubscribe)),
             193 => Some(EventType::Imap(ImapEvent::Thread)),
             57 => Some(EventType::Imap(ImapEvent::GetQuota)),
+            606 => Some(EventType::Imap(ImapEvent::GetMetadata)),
+            607 => Some(EventType::Imap(ImapEvent::SetMetadata)),
+            608 => Some(EventType::Imap(ImapEvent::Notify)),
             168 => Some(EventType::Imap(ImapEvent::Error)),
             183 => Some(EventType::Imap(ImapEvent::RawInput)),
             184 => Some(EventType::Imap(ImapEvent::RawOutput)),
@@ -2238,6 +2362,8 @@ impl EventType {
             242 => Some(EventType::Limit(LimitEvent::Quota)),
             237 => Some(EventType::Limit(LimitEvent::BlobQuota)),
             553 => Some(EventType::Limit(LimitEvent::TenantQuota)),
+            613 => Some(EventType::Limit(LimitEvent::QuotaWarningSent)),
+            614 => Some(EventType::Limit(LimitEvent::QuotaWarningFailed)),
             245 => Some(EventType::Limit(LimitEvent::TooManyRequests)),
             254 => Some(EventType::MailAuth(MailAuthEvent::ParseError)),
             252 => Some(EventType::MailAuth(MailAuthEvent::MissingParameters)),
@@ -2275,6 +2401,7 @@ impl EventType {
             281 => Some(EventType::MessageIngest(MessageIngestEvent::Duplicate)),
             282 => Some(EventType::MessageIngest(MessageIngestEvent::Error)),
             142 => Some(EventType::MessageIngest(MessageIngestEvent::SearchIndex)),
+            635 => Some(EventType::MessageIngest(MessageIngestEvent::AutocryptKeyCollected)),
             299 => Some(EventType::Milter(MilterEvent::Read)),
             303 => Some(EventType::Milter(MilterEvent::Write)),
             287 => Some(EventType::Milter(MilterEvent::ActionAccept)),
@@ -2296,6 +2423,8 @@ impl EventType {
             305 => Some(EventType::MtaHook(MtaHookEvent::ActionDiscard)),
             307 => Some(EventType::MtaHook(MtaHookEvent::ActionReject)),
             306 => Some(EventType::MtaHook(MtaHookEvent::ActionQuarantine)),
+            632 => Some(EventType::MtaHook(MtaHookEvent::ActionHold)),
+            633 => Some(EventType::MtaHook(MtaHookEvent::ActionReroute)),
             308 => Some(EventType::MtaHook(MtaHookEvent::Error)),
             309 => Some(EventType::MtaSts(MtaStsEvent::Authorized)),
             311 => Some(EventType::MtaSts(MtaStsEvent::NotAuthorized)),
@@ -2375,6 +2504,11 @@ impl EventType {
             372 => Some(EventType::PushSubscription(PushSubscriptionEvent::NotFound)),
             390 => Some(EventType::Queue(QueueEvent::Started)),
             380 => Some(EventType::Queue(QueueEvent::MessageQueued)),
+            615 => Some(EventType::Queue(QueueEvent::MessageQuarantined)),
+            616 => Some(EventType::Queue(QueueEvent::QuarantineReleased)),
+            617 => Some(EventType::Queue(QueueEvent::QuarantineDeleted)),
+            618 => Some(EventType::Queue(QueueEvent::QuarantineDigestSent)),
+            619 => Some(EventType::Queue(QueueEvent::QuarantineDigestFailed)),
             381 => Some(EventType::Queue(QueueEvent::AuthenticatedMessageQueued)),
             382 => Some(EventType::Queue(QueueEvent::ReportQueued)),
             379 => Some(EventType::Queue(QueueEvent::DsnQueued)),
@@ -2383,6 +2517,7 @@ impl EventType {
             377 => Some(EventType::Queue(QueueEvent::Locked)),
             374 => Some(EventType::Queue(QueueEvent::BlobNotFound)),
             384 => Some(EventType::Queue(QueueEvent::RateLimitExceeded)),
+            629 => Some(EventType::Queue(QueueEvent::WarmUpLimitExceeded)),
             375 => Some(EventType::Queue(QueueEvent::ConcurrencyLimitExceeded)),
             383 => Some(EventType::Queue(QueueEvent::QuotaExceeded)),
             48 => Some(EventType::Queue(QueueEvent::BackPressure)),
@@ -2423,6 +2558,8 @@ impl EventType {
             398 => Some(EventType::Sieve(SieveEvent::ActionDiscard)),
             399 => Some(EventType::Sieve(SieveEvent::ActionReject)),
             406 => Some(EventType::Sieve(SieveEvent::SendMessage)),
+            609 => Some(EventType::Sieve(SieveEvent::Notify)),
+            610 => Some(EventType::Sieve(SieveEvent::NotifyRateLimit)),
             401 => Some(EventType::Sieve(SieveEvent::MessageTooLarge)),
             405 => Some(EventType::Sieve(SieveEvent::ScriptNotFound)),
             400 => Some(EventType::Sieve(SieveEvent::ListNotFound)),
@@ -2452,6 +2589,9 @@ impl EventType {
             475 => Some(EventType::Smtp(SmtpEvent::SpfFromFail)),
             424 => Some(EventType::Smtp(SmtpEvent::DmarcPass)),
             423 => Some(EventType::Smtp(SmtpEvent::DmarcFail)),
+            630 => Some(EventType::Smtp(SmtpEvent::BimiPass)),
+            631 => Some(EventType::Smtp(SmtpEvent::BimiFail)),
+            634 => Some(EventType::Smtp(SmtpEvent::AutocryptHeaderAdded)),
             441 => Some(EventType::Smtp(SmtpEvent::IprevPass)),
             440 => Some(EventType::Smtp(SmtpEvent::IprevFail)),
             483 => Some(EventType::Smtp(SmtpEvent::TooManyMessages)),
@@ -2472,6 +2612,9 @@ impl EventType {
             464 => Some(EventType::Smtp(SmtpEvent::RcptTo)),
             465 => Some(EventType::Smtp(SmtpEvent::RcptToDuplicate)),
             467 => Some(EventType::Smtp(SmtpEvent::RcptToRewritten)),
+            620 => Some(EventType::Smtp(SmtpEvent::SrsDecoded)),
+            621 => Some(EventType::Smtp(SmtpEvent::SrsInvalid)),
+            622 => Some(EventType::Smtp(SmtpEvent::SrsExpired)),
             466 => Some(EventType::Smtp(SmtpEvent::RcptToMissing)),
             561 => Some(EventType::Smtp(SmtpEvent::RcptToGreylisted)),
             484 => Some(EventType::Smtp(SmtpEvent::TooManyRecipients)),
@@ -2523,6 +2666,8 @@ impl EventType {
             490 => Some(EventType::Spam(SpamEvent::Classify)),
             589 => Some(EventType::Spam(SpamEvent::ModelLoaded)),
             496 => Some(EventType::Spam(SpamEvent::ModelNotReady)),
+            611 => Some(EventType::Spam(SpamEvent::AccountModelTrained)),
+            612 => Some(EventType::Spam(SpamEvent::AccountModelPruned)),
             497 => Some(EventType::Spam(SpamEvent::ModelNotFound)),
             280 => Some(EventType::Spam(SpamEvent::RulesUpdated)),
             501 => Some(EventType::Spf(SpfEvent::Pass)),
@@ -2570,6 +2715,7 @@ impl EventType {
             519 => Some(EventType::Store(StoreEvent::LdapWarning)),
             492 => Some(EventType::Store(StoreEvent::HttpStoreFetch)),
             364 => Some(EventType::Store(StoreEvent::AutoExpunge)),
+            639 => Some(EventType::Store(StoreEvent::SnoozeWakeUp)),
             369 => Some(EventType::Store(StoreEvent::BlobStorePurged)),
             368 => Some(EventType::Store(StoreEvent::DataStorePurged)),
             578 => Some(EventType::TaskManager(TaskManagerEvent::TaskAcquired)),
@@ -2972,6 +3118,34 @@ impl EventType {
             EventType::Telemetry(TelemetryEvent::JournalError) => Level::Warn,
             EventType::Tls(TlsEvent::NoCertificatesAvailable) => Level::Warn,
             EventType::Tls(TlsEvent::MultipleCertificatesAvailable) => Level::Warn,
+            EventType::Sieve(SieveEvent::Notify) => Level::Info,
+            EventType::Sieve(SieveEvent::NotifyRateLimit) => Level::Warn,
+            EventType::Spam(SpamEvent::AccountModelTrained) => Level::Info,
+            EventType::Spam(SpamEvent::AccountModelPruned) => Level::Info,
+            EventType::Limit(LimitEvent::QuotaWarningSent) => Level::Info,
+            EventType::Limit(LimitEvent::QuotaWarningFailed) => Level::Warn,
+            EventType::Queue(QueueEvent::MessageQuarantined) => Level::Info,
+            EventType::Queue(QueueEvent::QuarantineReleased) => Level::Info,
+            EventType::Queue(QueueEvent::QuarantineDeleted) => Level::Info,
+            EventType::Queue(QueueEvent::QuarantineDigestSent) => Level::Info,
+            EventType::Queue(QueueEvent::QuarantineDigestFailed) => Level::Warn,
+            EventType::Smtp(SmtpEvent::SrsDecoded) => Level::Info,
+            EventType::Smtp(SmtpEvent::SrsInvalid) => Level::Info,
+            EventType::Smtp(SmtpEvent::SrsExpired) => Level::Info,
+            EventType::Delivery(DeliveryEvent::SrsRewritten) => Level::Info,
+            EventType::Delivery(DeliveryEvent::SessionReused) => Level::Info,
+            EventType::Delivery(DeliveryEvent::SessionResetFailed) => Level::Debug,
+            EventType::Queue(QueueEvent::WarmUpLimitExceeded) => Level::Info,
+            EventType::Smtp(SmtpEvent::BimiPass) => Level::Info,
+            EventType::Smtp(SmtpEvent::BimiFail) => Level::Info,
+            EventType::MtaHook(MtaHookEvent::ActionHold) => Level::Info,
+            EventType::MtaHook(MtaHookEvent::ActionReroute) => Level::Info,
+            EventType::Smtp(SmtpEvent::AutocryptHeaderAdded) => Level::Info,
+            EventType::MessageIngest(MessageIngestEvent::AutocryptKeyCollected) => Level::Info,
+            EventType::Calendar(CalendarEvent::BookingAccepted) => Level::Info,
+            EventType::Calendar(CalendarEvent::BookingDeclined) => Level::Info,
+            EventType::Calendar(CalendarEvent::BookingDelegated) => Level::Info,
+            EventType::Store(StoreEvent::SnoozeWakeUp) => Level::Info,
             _ => Level::Debug,
         }
     }
@@ -3031,6 +3205,9 @@ impl EventType {
                 "Calendar iTIP message received"
             }
             EventType::Calendar(CalendarEvent::ItipMessageError) => "iTIP message error",
+            EventType::Calendar(CalendarEvent::BookingAccepted) => "Booking accepted",
+            EventType::Calendar(CalendarEvent::BookingDeclined) => "Booking declined",
+            EventType::Calendar(CalendarEvent::BookingDelegated) => "Booking delegated",
             EventType::Cluster(ClusterEvent::Startup) => "Clustering enabled",
             EventType::Cluster(ClusterEvent::SubscriberStart) => "PubSub subscriber started",
             EventType::Cluster(ClusterEvent::SubscriberStop) => "PubSub subscriber stopped",
@@ -3073,6 +3250,10 @@ impl EventType {
             EventType::Delivery(DeliveryEvent::NullMx) => "Null MX record found",
             EventType::Delivery(DeliveryEvent::Connect) => "Connecting to remote server",
             EventType::Delivery(DeliveryEvent::ConnectError) => "Connection error",
+            EventType::Delivery(DeliveryEvent::SessionReused) => "Reusing idle SMTP session",
+            EventType::Delivery(DeliveryEvent::SessionResetFailed) => {
+                "Idle SMTP session reset failed"
+            }
             EventType::Delivery(DeliveryEvent::MissingOutboundHostname) => {
                 "Missing outbound hostname in configuration"
             }
@@ -3082,6 +3263,7 @@ impl EventType {
             EventType::Delivery(DeliveryEvent::Auth) => "SMTP authentication",
             EventType::Delivery(DeliveryEvent::AuthFailed) => "SMTP authentication failed",
             EventType::Delivery(DeliveryEvent::MailFrom) => "SMTP MAIL FROM command",
+            EventType::Delivery(DeliveryEvent::SrsRewritten) => "Sender rewritten using SRS",
             EventType::Delivery(DeliveryEvent::MailFromRejected) => "SMTP MAIL FROM rejected",
             EventType::Delivery(DeliveryEvent::Delivered) => "Message delivered",
             EventType::Delivery(DeliveryEvent::RcptTo) => "SMTP RCPT TO command",
@@ -3099,6 +3281,9 @@ impl EventType {
                 "Concurrency limit exceeded"
             }
             EventType::Delivery(DeliveryEvent::RateLimitExceeded) => "Rate limit exceeded",
+            EventType::Delivery(DeliveryEvent::RelayHostSuccess) => "Relay host delivery succeeded",
+            EventType::Delivery(DeliveryEvent::RelayHostFailed) => "Relay host failed",
+            EventType::Delivery(DeliveryEvent::RelayHostSkipped) => "Unhealthy relay host skipped",
             EventType::Delivery(DeliveryEvent::DoubleBounce) => {
                 "Discarding message after double bounce"
             }
@@ -3177,6 +3362,7 @@ impl EventType {
             EventType::Imap(ImapEvent::DeleteMailbox) => "IMAP DELETE mailbox command",
             EventType::Imap(ImapEvent::RenameMailbox) => "IMAP RENAME mailbox command",
             EventType::Imap(ImapEvent::Enable) => "IMAP ENABLE command",
+            EventType::Imap(ImapEvent::Compress) => "IMAP COMPRESS command",
             EventType::Imap(ImapEvent::Expunge) => "IMAP EXPUNGE command",
             EventType::Imap(ImapEvent::Fetch) => "IMAP FETCH command",
             EventType::Imap(ImapEvent::IdleStart) => "IMAP IDLE start",
@@ -3195,6 +3381,9 @@ impl EventType {
             EventType::Imap(ImapEvent::Unsubscribe) => "IMAP UNSUBSCRIBE command",
             EventType::Imap(ImapEvent::Thread) => "IMAP THREAD command",
             EventType::Imap(ImapEvent::GetQuota) => "IMAP GETQUOTA command",
+            EventType::Imap(ImapEvent::GetMetadata) => "IMAP GETMETADATA command",
+            EventType::Imap(ImapEvent::SetMetadata) => "IMAP SETMETADATA command",
+            EventType::Imap(ImapEvent::Notify) => "IMAP NOTIFY command",
             EventType::Imap(ImapEvent::Error) => "IMAP error occurred",
             EventType::Imap(ImapEvent::RawInput) => "Raw IMAP input received",
             EventType::Imap(ImapEvent::RawOutput) => "Raw IMAP output sent",
@@ -3273,6 +3462,8 @@ impl EventType {
             EventType::Limit(LimitEvent::Quota) => "Quota limit reached",
             EventType::Limit(LimitEvent::BlobQuota) => "Blob quota limit reached",
             EventType::Limit(LimitEvent::TenantQuota) => "Tenant quota limit reached",
+            EventType::Limit(LimitEvent::QuotaWarningSent) => "Quota warning sent",
+            EventType::Limit(LimitEvent::QuotaWarningFailed) => "Quota warning failed",
             EventType::Limit(LimitEvent::TooManyRequests) => "Too many requests",
             EventType::MailAuth(MailAuthEvent::ParseError) => "Mail authentication parse error",
             EventType::MailAuth(MailAuthEvent::MissingParameters) => {
@@ -3332,6 +3523,9 @@ impl EventType {
             EventType::MessageIngest(MessageIngestEvent::Duplicate) => "Skipping duplicate message",
             EventType::MessageIngest(MessageIngestEvent::Error) => "Message ingestion error",
             EventType::MessageIngest(MessageIngestEvent::SearchIndex) => "Search index updated",
+            EventType::MessageIngest(MessageIngestEvent::AutocryptKeyCollected) => {
+                "Autocrypt key collected"
+            }
             EventType::Milter(MilterEvent::Read) => "Reading from Milter",
             EventType::Milter(MilterEvent::Write) => "Writing to Milter",
             EventType::Milter(MilterEvent::ActionAccept) => "Milter action: Accept",
@@ -3355,6 +3549,8 @@ impl EventType {
             EventType::MtaHook(MtaHookEvent::ActionDiscard) => "MTA hook action: Discard",
             EventType::MtaHook(MtaHookEvent::ActionReject) => "MTA hook action: Reject",
             EventType::MtaHook(MtaHookEvent::ActionQuarantine) => "MTA hook action: Quarantine",
+            EventType::MtaHook(MtaHookEvent::ActionHold) => "MTA hook action: Hold",
+            EventType::MtaHook(MtaHookEvent::ActionReroute) => "MTA hook action: Reroute",
             EventType::MtaHook(MtaHookEvent::Error) => "MTA hook error",
             EventType::MtaSts(MtaStsEvent::Authorized) => "Host authorized by MTA-STS policy",
             EventType::MtaSts(MtaStsEvent::NotAuthorized) => {
@@ -3444,6 +3640,11 @@ impl EventType {
             }
             EventType::Queue(QueueEvent::Started) => "MTA queue started",
             EventType::Queue(QueueEvent::MessageQueued) => "Queued message for delivery",
+            EventType::Queue(QueueEvent::MessageQuarantined) => "Message quarantined",
+            EventType::Queue(QueueEvent::QuarantineReleased) => "Quarantined message released",
+            EventType::Queue(QueueEvent::QuarantineDeleted) => "Quarantined message deleted",
+            EventType::Queue(QueueEvent::QuarantineDigestSent) => "Quarantine digest sent",
+            EventType::Queue(QueueEvent::QuarantineDigestFailed) => "Quarantine digest failed",
             EventType::Queue(QueueEvent::AuthenticatedMessageQueued) => {
                 "Queued message submission for delivery"
             }
@@ -3456,6 +3657,7 @@ impl EventType {
             EventType::Queue(QueueEvent::Locked) => "Queue event is locked by another process",
             EventType::Queue(QueueEvent::BlobNotFound) => "Message blob not found",
             EventType::Queue(QueueEvent::RateLimitExceeded) => "Rate limit exceeded",
+            EventType::Queue(QueueEvent::WarmUpLimitExceeded) => "IP warm-up limit exceeded",
             EventType::Queue(QueueEvent::ConcurrencyLimitExceeded) => "Concurrency limit exceeded",
             EventType::Queue(QueueEvent::QuotaExceeded) => "Quota exceeded",
             EventType::Queue(QueueEvent::BackPressure) => "Queue backpressure detected",
@@ -3506,6 +3708,10 @@ impl EventType {
             EventType::Sieve(SieveEvent::ActionDiscard) => "Sieve action: Discard",
             EventType::Sieve(SieveEvent::ActionReject) => "Sieve action: Reject",
             EventType::Sieve(SieveEvent::SendMessage) => "Sieve sending message",
+            EventType::Sieve(SieveEvent::Notify) => "Sieve notification sent",
+            EventType::Sieve(SieveEvent::NotifyRateLimit) => {
+                "Sieve notification rate limit exceeded"
+            }
             EventType::Sieve(SieveEvent::MessageTooLarge) => "Sieve message too large",
             EventType::Sieve(SieveEvent::ScriptNotFound) => "Sieve script not found",
             EventType::Sieve(SieveEvent::ListNotFound) => "Sieve list not found",
@@ -3535,6 +3741,9 @@ impl EventType {
             EventType::Smtp(SmtpEvent::SpfFromFail) => "SPF From check failed",
             EventType::Smtp(SmtpEvent::DmarcPass) => "DMARC check passed",
             EventType::Smtp(SmtpEvent::DmarcFail) => "DMARC check failed",
+            EventType::Smtp(SmtpEvent::BimiPass) => "BIMI check passed",
+            EventType::Smtp(SmtpEvent::BimiFail) => "BIMI check failed",
+            EventType::Smtp(SmtpEvent::AutocryptHeaderAdded) => "Autocrypt header added",
             EventType::Smtp(SmtpEvent::IprevPass) => "IPREV check passed",
             EventType::Smtp(SmtpEvent::IprevFail) => "IPREV check failed",
             EventType::Smtp(SmtpEvent::TooManyMessages) => "Too many messages",
@@ -3557,6 +3766,9 @@ impl EventType {
             EventType::Smtp(SmtpEvent::RcptTo) => "SMTP RCPT TO command",
             EventType::Smtp(SmtpEvent::RcptToDuplicate) => "Duplicate RCPT TO",
             EventType::Smtp(SmtpEvent::RcptToRewritten) => "RCPT TO address rewritten",
+            EventType::Smtp(SmtpEvent::SrsDecoded) => "SRS address decoded",
+            EventType::Smtp(SmtpEvent::SrsInvalid) => "Invalid SRS address",
+            EventType::Smtp(SmtpEvent::SrsExpired) => "Expired SRS address",
             EventType::Smtp(SmtpEvent::RcptToMissing) => "RCPT TO address missing",
             EventType::Smtp(SmtpEvent::RcptToGreylisted) => "RCPT TO greylisted",
             EventType::Smtp(SmtpEvent::TooManyRecipients) => "Too many recipients",
@@ -3610,6 +3822,8 @@ impl EventType {
             EventType::Spam(SpamEvent::Classify) => "Classifying message for spam",
             EventType::Spam(SpamEvent::ModelLoaded) => "Spam classifier model loaded",
             EventType::Spam(SpamEvent::ModelNotReady) => "Spam classifier model not ready",
+            EventType::Spam(SpamEvent::AccountModelTrained) => "Per-account spam model trained",
+            EventType::Spam(SpamEvent::AccountModelPruned) => "Per-account spam models pruned",
             EventType::Spam(SpamEvent::ModelNotFound) => "Spam classifier model not found",
             EventType::Spam(SpamEvent::RulesUpdated) => "Spam filter rules updated",
             EventType::Spf(SpfEvent::Pass) => "SPF check passed",
@@ -3659,6 +3873,7 @@ impl EventType {
             EventType::Store(StoreEvent::LdapWarning) => "LDAP authentication warning",
             EventType::Store(StoreEvent::HttpStoreFetch) => "HTTP store updated",
             EventType::Store(StoreEvent::AutoExpunge) => "Auto-expunge executed",
+            EventType::Store(StoreEvent::SnoozeWakeUp) => "Snoozed messages woken up",
             EventType::Store(StoreEvent::BlobStorePurged) => "Blob store purge completed",
             EventType::Store(StoreEvent::DataStorePurged) => "Data store purge completed",
             EventType::TaskManager(TaskManagerEvent::TaskAcquired) => "Task acquired from queue",
@@ -3758,6 +3973,7 @@ impl EventType {
             EventType::Imap(ImapEvent::DeleteMailbox) => "IMAP error",
             EventType::Imap(ImapEvent::RenameMailbox) => "IMAP error",
             EventType::Imap(ImapEvent::Enable) => "IMAP error",
+            EventType::Imap(ImapEvent::Compress) => "IMAP error",
             EventType::Imap(ImapEvent::Expunge) => "IMAP error",
             EventType::Imap(ImapEvent::Fetch) => "IMAP error",
             EventType::Imap(ImapEvent::IdleStart) => "IMAP error",
@@ -3776,6 +3992,9 @@ impl EventType {
             EventType::Imap(ImapEvent::Unsubscribe) => "IMAP error",
             EventType::Imap(ImapEvent::Thread) => "IMAP error",
             EventType::Imap(ImapEvent::GetQuota) => "IMAP error",
+            EventType::Imap(ImapEvent::GetMetadata) => "IMAP error",
+            EventType::Imap(ImapEvent::SetMetadata) => "IMAP error",
+            EventType::Imap(ImapEvent::Notify) => "IMAP error",
             EventType::Imap(ImapEvent::Error) => "IMAP error",
             EventType::Imap(ImapEvent::RawInput) => "IMAP error",
             EventType::Imap(ImapEvent::RawOutput) => "IMAP error",
@@ -3810,6 +4029,8 @@ impl EventType {
             EventType::Limit(LimitEvent::Quota) => "Quota exceeded",
             EventType::Limit(LimitEvent::BlobQuota) => "Blob quota exceeded",
             EventType::Limit(LimitEvent::TenantQuota) => "Tenant quota exceeded",
+            EventType::Limit(LimitEvent::QuotaWarningSent) => "Quota warning sent",
+            EventType::Limit(LimitEvent::QuotaWarningFailed) => "Quota warning failed",
             EventType::Limit(LimitEvent::TooManyRequests) => "Too many requests",
             EventType::ManageSieve(ManageSieveEvent::ConnectionStart) => "ManageSieve error",
             EventType::ManageSieve(ManageSieveEvent::ConnectionEnd) => "ManageSieve error",
@@ -3896,6 +4117,9 @@ impl EventType {
             EventType::Smtp(SmtpEvent::SpfFromFail) => "SMTP error",
             EventType::Smtp(SmtpEvent::DmarcPass) => "SMTP error",
             EventType::Smtp(SmtpEvent::DmarcFail) => "SMTP error",
+            EventType::Smtp(SmtpEvent::BimiPass) => "SMTP error",
+            EventType::Smtp(SmtpEvent::BimiFail) => "SMTP error",
+            EventType::Smtp(SmtpEvent::AutocryptHeaderAdded) => "SMTP error",
             EventType::Smtp(SmtpEvent::IprevPass) => "SMTP error",
             EventType::Smtp(SmtpEvent::IprevFail) => "SMTP error",
             EventType::Smtp(SmtpEvent::TooManyMessages) => "SMTP error",
@@ -3916,6 +4140,9 @@ impl EventType {
             EventType::Smtp(SmtpEvent::RcptTo) => "SMTP error",
             EventType::Smtp(SmtpEvent::RcptToDuplicate) => "SMTP error",
             EventType::Smtp(SmtpEvent::RcptToRewritten) => "SMTP error",
+            EventType::Smtp(SmtpEvent::SrsDecoded) => "SMTP error",
+            EventType::Smtp(SmtpEvent::SrsInvalid) => "SMTP error",
+            EventType::Smtp(SmtpEvent::SrsExpired) => "SMTP error",
             EventType::Smtp(SmtpEvent::RcptToMissing) => "SMTP error",
             EventType::Smtp(SmtpEvent::RcptToGreylisted) => "SMTP error",
             EventType::Smtp(SmtpEvent::TooManyRecipients) => "SMTP error",
@@ -4044,6 +4271,9 @@ impl EventType {
             EventType::Calendar(CalendarEvent::ItipMessageSent),
             EventType::Calendar(CalendarEvent::ItipMessageReceived),
             EventType::Calendar(CalendarEvent::ItipMessageError),
+            EventType::Calendar(CalendarEvent::BookingAccepted),
+            EventType::Calendar(CalendarEvent::BookingDeclined),
+            EventType::Calendar(CalendarEvent::BookingDelegated),
             EventType::Cluster(ClusterEvent::Startup),
             EventType::Cluster(ClusterEvent::SubscriberStart),
             EventType::Cluster(ClusterEvent::SubscriberStop),
@@ -4078,6 +4308,8 @@ impl EventType {
             EventType::Delivery(DeliveryEvent::NullMx),
             EventType::Delivery(DeliveryEvent::Connect),
             EventType::Delivery(DeliveryEvent::ConnectError),
+            EventType::Delivery(DeliveryEvent::SessionReused),
+            EventType::Delivery(DeliveryEvent::SessionResetFailed),
             EventType::Delivery(DeliveryEvent::MissingOutboundHostname),
             EventType::Delivery(DeliveryEvent::GreetingFailed),
             EventType::Delivery(DeliveryEvent::Ehlo),
@@ -4085,6 +4317,7 @@ impl EventType {
             EventType::Delivery(DeliveryEvent::Auth),
             EventType::Delivery(DeliveryEvent::AuthFailed),
             EventType::Delivery(DeliveryEvent::MailFrom),
+            EventType::Delivery(DeliveryEvent::SrsRewritten),
             EventType::Delivery(DeliveryEvent::MailFromRejected),
             EventType::Delivery(DeliveryEvent::Delivered),
             EventType::Delivery(DeliveryEvent::RcptTo),
@@ -4098,6 +4331,9 @@ impl EventType {
             EventType::Delivery(DeliveryEvent::ImplicitTlsError),
             EventType::Delivery(DeliveryEvent::ConcurrencyLimitExceeded),
             EventType::Delivery(DeliveryEvent::RateLimitExceeded),
+            EventType::Delivery(DeliveryEvent::RelayHostSuccess),
+            EventType::Delivery(DeliveryEvent::RelayHostFailed),
+            EventType::Delivery(DeliveryEvent::RelayHostSkipped),
             EventType::Delivery(DeliveryEvent::DoubleBounce),
             EventType::Delivery(DeliveryEvent::DsnSuccess),
             EventType::Delivery(DeliveryEvent::DsnTempFail),
@@ -4168,6 +4404,7 @@ impl EventType {
             EventType::Imap(ImapEvent::DeleteMailbox),
             EventType::Imap(ImapEvent::RenameMailbox),
             EventType::Imap(ImapEvent::Enable),
+            EventType::Imap(ImapEvent::Compress),
             EventType::Imap(ImapEvent::Expunge),
             EventType::Imap(ImapEvent::Fetch),
             EventType::Imap(ImapEvent::IdleStart),
@@ -4186,6 +4423,9 @@ impl EventType {
             EventType::Imap(ImapEvent::Unsubscribe),
             EventType::Imap(ImapEvent::Thread),
             EventType::Imap(ImapEvent::GetQuota),
+            EventType::Imap(ImapEvent::GetMetadata),
+            EventType::Imap(ImapEvent::SetMetadata),
+            EventType::Imap(ImapEvent::Notify),
             EventType::Imap(ImapEvent::Error),
             EventType::Imap(ImapEvent::RawInput),
             EventType::Imap(ImapEvent::RawOutput),
@@ -4240,6 +4480,8 @@ impl EventType {
             EventType::Limit(LimitEvent::Quota),
             EventType::Limit(LimitEvent::BlobQuota),
             EventType::Limit(LimitEvent::TenantQuota),
+            EventType::Limit(LimitEvent::QuotaWarningSent),
+            EventType::Limit(LimitEvent::QuotaWarningFailed),
             EventType::Limit(LimitEvent::TooManyRequests),
             EventType::MailAuth(MailAuthEvent::ParseError),
             EventType::MailAuth(MailAuthEvent::MissingParameters),
@@ -4277,6 +4519,7 @@ impl EventType {
             EventType::MessageIngest(MessageIngestEvent::Duplicate),
             EventType::MessageIngest(MessageIngestEvent::Error),
             EventType::MessageIngest(MessageIngestEvent::SearchIndex),
+            EventType::MessageIngest(MessageIngestEvent::AutocryptKeyCollected),
             EventType::Milter(MilterEvent::Read),
             EventType::Milter(MilterEvent::Write),
             EventType::Milter(MilterEvent::ActionAccept),
@@ -4298,6 +4541,8 @@ impl EventType {
             EventType::MtaHook(MtaHookEvent::ActionDiscard),
             EventType::MtaHook(MtaHookEvent::ActionReject),
             EventType::MtaHook(MtaHookEvent::ActionQuarantine),
+            EventType::MtaHook(MtaHookEvent::ActionHold),
+            EventType::MtaHook(MtaHookEvent::ActionReroute),
             EventType::MtaHook(MtaHookEvent::Error),
             EventType::MtaSts(MtaStsEvent::Authorized),
             EventType::MtaSts(MtaStsEvent::NotAuthorized),
@@ -4357,6 +4602,11 @@ impl EventType {
             EventType::PushSubscription(PushSubscriptionEvent::NotFound),
             EventType::Queue(QueueEvent::Started),
             EventType::Queue(QueueEvent::MessageQueued),
+            EventType::Queue(QueueEvent::MessageQuarantined),
+            EventType::Queue(QueueEvent::QuarantineReleased),
+            EventType::Queue(QueueEvent::QuarantineDeleted),
+            EventType::Queue(QueueEvent::QuarantineDigestSent),
+            EventType::Queue(QueueEvent::QuarantineDigestFailed),
             EventType::Queue(QueueEvent::AuthenticatedMessageQueued),
             EventType::Queue(QueueEvent::ReportQueued),
             EventType::Queue(QueueEvent::DsnQueued),
@@ -4365,6 +4615,7 @@ impl EventType {
             EventType::Queue(QueueEvent::Locked),
             EventType::Queue(QueueEvent::BlobNotFound),
             EventType::Queue(QueueEvent::RateLimitExceeded),
+            EventType::Queue(QueueEvent::WarmUpLimitExceeded),
             EventType::Queue(QueueEvent::ConcurrencyLimitExceeded),
             EventType::Queue(QueueEvent::QuotaExceeded),
             EventType::Queue(QueueEvent::BackPressure),
@@ -4405,6 +4656,8 @@ impl EventType {
             EventType::Sieve(SieveEvent::ActionDiscard),
             EventType::Sieve(SieveEvent::ActionReject),
             EventType::Sieve(SieveEvent::SendMessage),
+            EventType::Sieve(SieveEvent::Notify),
+            EventType::Sieve(SieveEvent::NotifyRateLimit),
             EventType::Sieve(SieveEvent::MessageTooLarge),
             EventType::Sieve(SieveEvent::ScriptNotFound),
             EventType::Sieve(SieveEvent::ListNotFound),
@@ -4434,6 +4687,9 @@ impl EventType {
             EventType::Smtp(SmtpEvent::SpfFromFail),
             EventType::Smtp(SmtpEvent::DmarcPass),
             EventType::Smtp(SmtpEvent::DmarcFail),
+            EventType::Smtp(SmtpEvent::BimiPass),
+            EventType::Smtp(SmtpEvent::BimiFail),
+            EventType::Smtp(SmtpEvent::AutocryptHeaderAdded),
             EventType::Smtp(SmtpEvent::IprevPass),
             EventType::Smtp(SmtpEvent::IprevFail),
             EventType::Smtp(SmtpEvent::TooManyMessages),
@@ -4454,6 +4710,9 @@ impl EventType {
             EventType::Smtp(SmtpEvent::RcptTo),
             EventType::Smtp(SmtpEvent::RcptToDuplicate),
             EventType::Smtp(SmtpEvent::RcptToRewritten),
+            EventType::Smtp(SmtpEvent::SrsDecoded),
+            EventType::Smtp(SmtpEvent::SrsInvalid),
+            EventType::Smtp(SmtpEvent::SrsExpired),
             EventType::Smtp(SmtpEvent::RcptToMissing),
             EventType::Smtp(SmtpEvent::RcptToGreylisted),
             EventType::Smtp(SmtpEvent::TooManyRecipients),
@@ -4505,6 +4764,8 @@ impl EventType {
             EventType::Spam(SpamEvent::Classify),
             EventType::Spam(SpamEvent::ModelLoaded),
             EventType::Spam(SpamEvent::ModelNotReady),
+            EventType::Spam(SpamEvent::AccountModelTrained),
+            EventType::Spam(SpamEvent::AccountModelPruned),
             EventType::Spam(SpamEvent::ModelNotFound),
             EventType::Spam(SpamEvent::RulesUpdated),
             EventType::Spf(SpfEvent::Pass),
@@ -4552,6 +4813,7 @@ impl EventType {
             EventType::Store(StoreEvent::LdapWarning),
             EventType::Store(StoreEvent::HttpStoreFetch),
             EventType::Store(StoreEvent::AutoExpunge),
+            EventType::Store(StoreEvent::SnoozeWakeUp),
             EventType::Store(StoreEvent::BlobStorePurged),
             EventType::Store(StoreEvent::DataStorePurged),
             EventType::TaskManager(TaskManagerEvent::TaskAcquired),
@@ -4669,6 +4931,9 @@ impl MetricType {
             b"delivery.implicit-tls-error" => MetricType::DeliveryImplicitTlsError,
             b"delivery.concurrency-limit-exceeded" => MetricType::DeliveryConcurrencyLimitExceeded,
             b"delivery.rate-limit-exceeded" => MetricType::DeliveryRateLimitExceeded,
+            b"delivery.relay-host-success" => MetricType::DeliveryRelayHostSuccess,
+            b"delivery.relay-host-failed" => MetricType::DeliveryRelayHostFailed,
+            b"delivery.session-reused" => MetricType::DeliverySessionReused,
             b"delivery.double-bounce" => MetricType::DeliveryDoubleBounce,
             b"delivery.dsn-success" => MetricType::DeliveryDsnSuccess,
             b"delivery.dsn-temp-fail" => MetricType::DeliveryDsnTempFail,
@@ -4874,6 +5139,8 @@ impl MetricType {
             b"smtp.spf-from-fail" => MetricType::SmtpSpfFromFail,
             b"smtp.dmarc-pass" => MetricType::SmtpDmarcPass,
             b"smtp.dmarc-fail" => MetricType::SmtpDmarcFail,
+            b"smtp.bimi-pass" => MetricType::SmtpBimiPass,
+            b"smtp.bimi-fail" => MetricType::SmtpBimiFail,
             b"smtp.iprev-pass" => MetricType::SmtpIprevPass,
             b"smtp.iprev-fail" => MetricType::SmtpIprevFail,
             b"smtp.too-many-messages" => MetricType::SmtpTooManyMessages,
@@ -5014,6 +5281,9 @@ impl MetricType {
             MetricType::DeliveryImplicitTlsError => "delivery.implicit-tls-error",
             MetricType::DeliveryConcurrencyLimitExceeded => "delivery.concurrency-limit-exceeded",
             MetricType::DeliveryRateLimitExceeded => "delivery.rate-limit-exceeded",
+            MetricType::DeliveryRelayHostSuccess => "delivery.relay-host-success",
+            MetricType::DeliveryRelayHostFailed => "delivery.relay-host-failed",
+            MetricType::DeliverySessionReused => "delivery.session-reused",
             MetricType::DeliveryDoubleBounce => "delivery.double-bounce",
             MetricType::DeliveryDsnSuccess => "delivery.dsn-success",
             MetricType::DeliveryDsnTempFail => "delivery.dsn-temp-fail",
@@ -5229,6 +5499,8 @@ impl MetricType {
             MetricType::SmtpSpfFromFail => "smtp.spf-from-fail",
             MetricType::SmtpDmarcPass => "smtp.dmarc-pass",
             MetricType::SmtpDmarcFail => "smtp.dmarc-fail",
+            MetricType::SmtpBimiPass => "smtp.bimi-pass",
+            MetricType::SmtpBimiFail => "smtp.bimi-fail",
             MetricType::SmtpIprevPass => "smtp.iprev-pass",
             MetricType::SmtpIprevFail => "smtp.iprev-fail",
             MetricType::SmtpTooManyMessages => "smtp.too-many-messages",
@@ -5370,6 +5642,9 @@ impl MetricType {
             MetricType::DeliveryImplicitTlsError => 81,
             MetricType::DeliveryConcurrencyLimitExceeded => 82,
             MetricType::DeliveryRateLimitExceeded => 83,
+            MetricType::DeliveryRelayHostSuccess => 339,
+            MetricType::DeliveryRelayHostFailed => 340,
+            MetricType::DeliverySessionReused => 341,
             MetricType::DeliveryDoubleBounce => 84,
             MetricType::DeliveryDsnSuccess => 85,
             MetricType::DeliveryDsnTempFail => 86,
@@ -5575,6 +5850,8 @@ impl MetricType {
             MetricType::SmtpSpfFromFail => 263,
             MetricType::SmtpDmarcPass => 264,
             MetricType::SmtpDmarcFail => 265,
+            MetricType::SmtpBimiPass => 342,
+            MetricType::SmtpBimiFail => 343,
             MetricType::SmtpIprevPass => 266,
             MetricType::SmtpIprevFail => 267,
             MetricType::SmtpTooManyMessages => 268,
@@ -5714,6 +5991,9 @@ impl MetricType {
             81 => Some(MetricType::DeliveryImplicitTlsError),
             82 => Some(MetricType::DeliveryConcurrencyLimitExceeded),
             83 => Some(MetricType::DeliveryRateLimitExceeded),
+            339 => Some(MetricType::DeliveryRelayHostSuccess),
+            340 => Some(MetricType::DeliveryRelayHostFailed),
+            341 => Some(MetricType::DeliverySessionReused),
             84 => Some(MetricType::DeliveryDoubleBounce),
             85 => Some(MetricType::DeliveryDsnSuccess),
             86 => Some(MetricType::DeliveryDsnTempFail),
@@ -5919,6 +6199,8 @@ impl MetricType {
             263 => Some(MetricType::SmtpSpfFromFail),
             264 => Some(MetricType::SmtpDmarcPass),
             265 => Some(MetricType::SmtpDmarcFail),
+            342 => Some(MetricType::SmtpBimiPass),
+            343 => Some(MetricType::SmtpBimiFail),
             266 => Some(MetricType::SmtpIprevPass),
             267 => Some(MetricType::SmtpIprevFail),
             268 => Some(MetricType::SmtpTooManyMessages),
@@ -6056,6 +6338,9 @@ impl MetricType {
             MetricType::DeliveryImplicitTlsError => 94,
             MetricType::DeliveryConcurrencyLimitExceeded => 81,
             MetricType::DeliveryRateLimitExceeded => 104,
+            MetricType::DeliveryRelayHostSuccess => 624,
+            MetricType::DeliveryRelayHostFailed => 625,
+            MetricType::DeliverySessionReused => 627,
             MetricType::DeliveryDoubleBounce => 86,
             MetricType::DeliveryDsnSuccess => 88,
             MetricType::DeliveryDsnTempFail => 89,
@@ -6242,6 +6527,8 @@ impl MetricType {
             MetricType::SmtpSpfFromFail => 475,
             MetricType::SmtpDmarcPass => 424,
             MetricType::SmtpDmarcFail => 423,
+            MetricType::SmtpBimiPass => 630,
+            MetricType::SmtpBimiFail => 631,
             MetricType::SmtpIprevPass => 441,
             MetricType::SmtpIprevFail => 440,
             MetricType::SmtpTooManyMessages => 483,
@@ -6379,6 +6666,9 @@ impl MetricType {
             MetricType::DeliveryImplicitTlsError => "Implicit TLS error",
             MetricType::DeliveryConcurrencyLimitExceeded => "Concurrency limit exceeded",
             MetricType::DeliveryRateLimitExceeded => "Rate limit exceeded",
+            MetricType::DeliveryRelayHostSuccess => "Relay host delivery succeeded",
+            MetricType::DeliveryRelayHostFailed => "Relay host failed",
+            MetricType::DeliverySessionReused => "Number of idle SMTP sessions reused",
             MetricType::DeliveryDoubleBounce => "Discarding message after double bounce",
             MetricType::DeliveryDsnSuccess => "DSN success notification",
             MetricType::DeliveryDsnTempFail => "DSN temporary failure notification",
@@ -6594,6 +6884,8 @@ impl MetricType {
             MetricType::SmtpSpfFromFail => "SPF From check failed",
             MetricType::SmtpDmarcPass => "DMARC check passed",
             MetricType::SmtpDmarcFail => "DMARC check failed",
+            MetricType::SmtpBimiPass => "BIMI check passed",
+            MetricType::SmtpBimiFail => "BIMI check failed",
             MetricType::SmtpIprevPass => "IPREV check passed",
             MetricType::SmtpIprevFail => "IPREV check failed",
             MetricType::SmtpTooManyMessages => "Too many messages",
@@ -6740,6 +7032,9 @@ impl MetricType {
             | MetricType::DeliveryImplicitTlsError
             | MetricType::DeliveryConcurrencyLimitExceeded
             | MetricType::DeliveryRateLimitExceeded
+            | MetricType::DeliveryRelayHostSuccess
+            | MetricType::DeliveryRelayHostFailed
+            | MetricType::DeliverySessionReused
             | MetricType::DeliveryDoubleBounce
             | MetricType::DeliveryDsnSuccess
             | MetricType::DeliveryDsnTempFail
@@ -6926,6 +7221,8 @@ impl MetricType {
             | MetricType::SmtpSpfFromFail
             | MetricType::SmtpDmarcPass
             | MetricType::SmtpDmarcFail
+            | MetricType::SmtpBimiPass
+            | MetricType::SmtpBimiFail
             | MetricType::SmtpIprevPass
             | MetricType::SmtpIprevFail
             | MetricType::SmtpTooManyMessages
@@ -7077,6 +7374,9 @@ impl MetricType {
             MetricType::DeliveryImplicitTlsError,
             MetricType::DeliveryConcurrencyLimitExceeded,
             MetricType::DeliveryRateLimitExceeded,
+            MetricType::DeliveryRelayHostSuccess,
+            MetricType::DeliveryRelayHostFailed,
+            MetricType::DeliverySessionReused,
             MetricType::DeliveryDoubleBounce,
             MetricType::DeliveryDsnSuccess,
             MetricType::DeliveryDsnTempFail,
@@ -7282,6 +7582,8 @@ impl MetricType {
             MetricType::SmtpSpfFromFail,
             MetricType::SmtpDmarcPass,
             MetricType::SmtpDmarcFail,
+            MetricType::SmtpBimiPass,
+            MetricType::SmtpBimiFail,
             MetricType::SmtpIprevPass,
             MetricType::SmtpIprevFail,
             MetricType::SmtpTooManyMessages,
@@ -21,6 +21,13 @@ pub enum ContactField {
     CreatedToUpdated,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum CalendarField {
+    Archive,
+    Publish,
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[repr(u8)]
 pub enum CalendarEventField {
@@ -42,12 +49,15 @@ pub enum EmailField {
     Metadata,
     Threading,
     DeletedAt,
+    SnoozedUntil,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[repr(u8)]
 pub enum MailboxField {
     UidCounter = 84,
+    Metadata = 85,
+    SaveDate = 86,
     Archive = ARCHIVE_FIELD,
 }
 
@@ -78,10 +88,12 @@ pub enum IdentityField {
 pub enum PrincipalField {
     Archive = ARCHIVE_FIELD,
     ParticipantIdentities = 45,
+    Metadata = 46,
     DefaultCalendarId = 47,
     DefaultAddressBookId = 48,
     ActiveScriptId = 49,
     PushSubscriptions = 44,
+    SpamModel = 50,
 }
 
 impl From<ContactField> for u8 {
@@ -95,6 +107,15 @@ impl From<ContactField> for u8 {
     }
 }
 
+impl From<CalendarField> for u8 {
+    fn from(value: CalendarField) -> Self {
+        match value {
+            CalendarField::Publish => 1,
+            CalendarField::Archive => ARCHIVE_FIELD,
+        }
+    }
+}
+
 impl From<CalendarEventField> for u8 {
     fn from(value: CalendarEventField) -> Self {
         match value {
@@ -119,6 +140,7 @@ impl From<EmailField> for u8 {
             EmailField::Metadata => 71,
             EmailField::Threading => 90,
             EmailField::DeletedAt => 91,
+            EmailField::SnoozedUntil => 92,
             EmailField::Archive => ARCHIVE_FIELD,
         }
     }
@@ -128,6 +150,8 @@ impl From<MailboxField> for u8 {
     fn from(value: MailboxField) -> Self {
         match value {
             MailboxField::UidCounter => 84,
+            MailboxField::Metadata => 85,
+            MailboxField::SaveDate => 86,
             MailboxField::Archive => ARCHIVE_FIELD,
         }
     }
@@ -156,10 +180,12 @@ impl From<PrincipalField> for u8 {
     fn from(value: PrincipalField) -> Self {
         match value {
             PrincipalField::ParticipantIdentities => 45,
+            PrincipalField::Metadata => 46,
             PrincipalField::DefaultCalendarId => 47,
             PrincipalField::DefaultAddressBookId => 48,
             PrincipalField::ActiveScriptId => 49,
             PrincipalField::PushSubscriptions => 44,
+            PrincipalField::SpamModel => 50,
             PrincipalField::Archive => ARCHIVE_FIELD,
         }
     }
@@ -186,6 +212,12 @@ impl From<ContactField> for Field {
     }
 }
 
+impl From<CalendarField> for Field {
+    fn from(value: CalendarField) -> Self {
+        Field(u8::from(value))
+    }
+}
+
 impl From<CalendarEventField> for Field {
     fn from(value: CalendarEventField) -> Self {
         Field(u8::from(value))
@@ -248,6 +280,7 @@ impl Field {
 
 impl FieldType for Field {}
 impl FieldType for ContactField {}
+impl FieldType for CalendarField {}
 impl FieldType for CalendarEventField {}
 impl FieldType for CalendarNotificationField {}
 impl FieldType for EmailField {}
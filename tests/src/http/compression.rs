@@ -0,0 +1,154 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::utils::server::TestServerBuilder;
+use http_proto::compression::ContentEncoding;
+use hyper::{Method, StatusCode};
+use std::time::Duration;
+
+#[tokio::test]
+async fn http_compression() {
+    let test = TestServerBuilder::new("http_compression_test")
+        .await
+        .with_http_listener(19062)
+        .await
+        .disable_services()
+        .build()
+        .await;
+    let john = test
+        .account("admin")
+        .create_user_account(
+            "jdoe@example.org",
+            "this is a very strong password",
+            "John Doe",
+            &[],
+            vec![],
+        )
+        .await;
+    let client = reqwest::Client::builder()
+        .timeout(Duration::from_secs(5))
+        .danger_accept_invalid_certs(true)
+        .build()
+        .unwrap();
+    let request = |method: Method, path: String| {
+        client
+            .request(method, format!("https://127.0.0.1:19062{path}"))
+            .basic_auth(john.name(), Some(john.secret()))
+    };
+    let contents = "The quick brown fox jumps over the lazy dog.\r\n".repeat(200);
+
+    // Compressed request bodies are decoded before processing
+    let response = request(Method::POST, format!("/jmap/upload/{}/", john.id_string()))
+        .header("Content-Type", "text/plain")
+        .header("Content-Encoding", "gzip")
+        .body(ContentEncoding::Gzip.encode(contents.as_bytes()).unwrap())
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let upload =
+        serde_json::from_slice::<serde_json::Value>(&response.bytes().await.unwrap()).unwrap();
+    assert_eq!(upload["size"].as_u64(), Some(contents.len() as u64));
+    let response = request(
+        Method::GET,
+        format!(
+            "/jmap/download/{}/{}/notes.txt?accept=text/plain",
+            john.id_string(),
+            upload["blobId"].as_str().unwrap()
+        ),
+    )
+    .send()
+    .await
+    .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.text().await.unwrap(), contents);
+
+    // Unsupported or corrupted request encodings are rejected
+    for (encoding, body) in [
+        ("compress", contents.as_bytes().to_vec()),
+        ("br", contents.as_bytes().to_vec()),
+    ] {
+        let response = request(Method::POST, format!("/jmap/upload/{}/", john.id_string()))
+            .header("Content-Type", "text/plain")
+            .header("Content-Encoding", encoding)
+            .body(body)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST, "{encoding}");
+    }
+
+    // Store a file over WebDAV to obtain a response with an ETag
+    let path = "/dav/file/jdoe%40example.org/notes.txt".to_string();
+    let response = request(Method::PUT, path.clone())
+        .header("Content-Type", "text/plain")
+        .body(contents.clone())
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let etag = response.headers()["etag"].to_str().unwrap().to_string();
+    assert!(!etag.starts_with("W/"), "{etag}");
+
+    // Negotiate the response encoding
+    for (accept_encoding, expected) in [
+        (None, None),
+        (Some("identity"), None),
+        (Some("gzip"), Some(ContentEncoding::Gzip)),
+        (Some("br"), Some(ContentEncoding::Brotli)),
+        (Some("gzip, br"), Some(ContentEncoding::Brotli)),
+        (Some("gzip;q=1.0, br;q=0.5"), Some(ContentEncoding::Gzip)),
+        (Some("gzip;q=0, br"), Some(ContentEncoding::Brotli)),
+        (Some("gzip;q=0"), None),
+        (Some("br;q=0, gzip;q=0, identity"), None),
+        (Some("compress, deflate"), None),
+    ] {
+        let mut get = request(Method::GET, path.clone());
+        if let Some(accept_encoding) = accept_encoding {
+            get = get.header("Accept-Encoding", accept_encoding);
+        }
+        let response = get.send().await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK, "{accept_encoding:?}");
+        let headers = response.headers().clone();
+        let body = response.bytes().await.unwrap();
+
+        // Responses that could be compressed always vary on Accept-Encoding
+        assert!(
+            headers
+                .get_all("vary")
+                .iter()
+                .any(|value| value.to_str().unwrap().contains("Accept-Encoding")),
+            "{accept_encoding:?}: {headers:?}"
+        );
+        assert_eq!(
+            headers
+                .get("content-encoding")
+                .map(|value| value.to_str().unwrap()),
+            expected.map(|encoding| encoding.as_str()),
+            "{accept_encoding:?}"
+        );
+        assert_eq!(
+            headers["content-length"].to_str().unwrap(),
+            body.len().to_string(),
+            "{accept_encoding:?}"
+        );
+
+        // Compressed representations carry a weak ETag
+        let response_etag = headers["etag"].to_str().unwrap();
+        if let Some(encoding) = expected {
+            assert_eq!(response_etag, format!("W/{etag}"), "{accept_encoding:?}");
+            assert!(body.len() < contents.len(), "{accept_encoding:?}");
+            assert_eq!(
+                encoding.decode(&body, 0).unwrap().unwrap(),
+                contents.as_bytes(),
+                "{accept_encoding:?}"
+            );
+        } else {
+            assert_eq!(response_etag, etag, "{accept_encoding:?}");
+            assert_eq!(body, contents.as_bytes(), "{accept_encoding:?}");
+        }
+    }
+}
@@ -9,18 +9,22 @@ use crate::{
     system::antispam::{HAM, SPAM, TEST},
     utils::{imap::AssertResult, server::TestServer, smtp::SmtpConnection},
 };
-use common::{Server, manager::SPAM_TRAINER_KEY};
+use common::{
+    Server, config::mailstore::spamfilter::AccountModelConfig, manager::SPAM_TRAINER_KEY,
+};
 use imap_proto::ResponseType;
+use nlp::classifier::feature::FhFeature;
 use registry::schema::{
     enums::TaskSpamFilterMaintenanceType,
     prelude::ObjectType,
     structs::{Task, TaskSpamFilterMaintenance, TaskStatus},
 };
-use spam_filter::modules::classifier::SpamTrainer;
+use spam_filter::modules::classifier::{AccountSpamModel, SpamTrainer, blend_account_prediction};
 use store::{
-    Deserialize,
+    Deserialize, ValueKey,
     write::{AlignedBytes, Archive},
 };
+use types::{collection::Collection, field::PrincipalField};
 
 pub async fn test(test: &TestServer) {
     println!("Running Spam classifier tests...");
@@ -85,6 +89,27 @@ pub async fn test(test: &TestServer) {
     assert_eq!(account.spam_training_samples().await.len(), 20);
     assert!(test.server.inner.data.spam_classifier.load().is_active());
 
+    // A personalized model should have been trained for the account
+    let model = account_spam_model(&test.server, account_id.document_id())
+        .await
+        .expect("Per-account model not found");
+    assert_eq!(model.ham_count, 10);
+    assert_eq!(model.spam_count, 10);
+
+    // Recently trained models are not pruned
+    admin
+        .registry_create_object(Task::SpamFilterMaintenance(TaskSpamFilterMaintenance {
+            maintenance_type: TaskSpamFilterMaintenanceType::PruneAccountModels,
+            status: TaskStatus::now(),
+        }))
+        .await;
+    test.wait_for_tasks().await;
+    assert!(
+        account_spam_model(&test.server, account_id.document_id())
+            .await
+            .is_some()
+    );
+
     // Send 3 test emails
     for message in TEST {
         let mut lmtp = SmtpConnection::connect().await;
@@ -116,6 +141,56 @@ pub async fn test(test: &TestServer) {
         .assert_contains("X-Spam-Status: Yes")
         .assert_contains("PROB_SPAM_HIGH");
     imap.send_ok("MOVE 10 INBOX").await;
+
+    // The personalized model is cached after its first use
+    let cached_model = test
+        .server
+        .inner
+        .cache
+        .spam_models
+        .peek(&account_id.document_id())
+        .flatten()
+        .expect("Per-account model not cached");
+    assert_eq!(cached_model.ham_count, 10);
+    assert_eq!(cached_model.spam_count, 10);
+
+    // Global and per-account predictions are blended using the configured weight
+    let account_config = AccountModelConfig {
+        weight: 0.25,
+        min_samples: 10,
+        max_age: u64::MAX,
+    };
+    let features = (
+        &account_config,
+        vec![
+            FhFeature {
+                idx: 1,
+                weight: 0.5,
+            },
+            FhFeature {
+                idx: 100,
+                weight: 0.5,
+            },
+        ],
+    );
+    let account_prediction = cached_model.classifier.predict_proba_sample(&features.1);
+    let blended =
+        blend_account_prediction(&test.server, account_id.document_id(), 0.8, Some(&features))
+            .await;
+    assert!((blended - (0.8 * 0.75 + account_prediction * 0.25)).abs() < 1e-6);
+
+    // Models that have not seen enough samples are ignored
+    let account_config = AccountModelConfig {
+        min_samples: 11,
+        ..account_config
+    };
+    let features = (&account_config, features.1);
+    assert_eq!(
+        blend_account_prediction(&test.server, account_id.document_id(), 0.8, Some(&features))
+            .await,
+        0.8
+    );
+
     let samples = account.spam_training_samples().await;
     assert_eq!(samples.iter().filter(|x| !x.1.is_spam).count(), 11);
     assert_eq!(samples.iter().filter(|x| x.1.is_spam).count(), 10);
@@ -133,6 +208,32 @@ pub async fn test(test: &TestServer) {
     let samples = account.spam_training_samples().await;
     assert_eq!(samples.iter().filter(|x| !x.1.is_spam).count(), 11);
     assert_eq!(samples.iter().filter(|x| x.1.is_spam).count(), 10);
+
+    // Destroying the account removes its personalized model
+    admin
+        .registry_destroy(ObjectType::Account, [account_id])
+        .await
+        .assert_destroyed(&[account_id]);
+    test.wait_for_tasks().await;
+    assert!(
+        account_spam_model(&test.server, account_id.document_id())
+            .await
+            .is_none()
+    );
+    assert!(
+        test.server
+            .inner
+            .cache
+            .spam_models
+            .peek(&account_id.document_id())
+            .is_none()
+    );
+    let features = (&account_config, features.1);
+    assert_eq!(
+        blend_account_prediction(&test.server, account_id.document_id(), 0.8, Some(&features))
+            .await,
+        0.8
+    );
 }
 
 pub async fn spam_classifier_model(server: &Server) -> SpamTrainer {
@@ -149,3 +250,17 @@ pub async fn spam_classifier_model(server: &Server) -> SpamTrainer {
         .unwrap()
         .unwrap()
 }
+
+pub async fn account_spam_model(server: &Server, account_id: u32) -> Option<AccountSpamModel> {
+    server
+        .store()
+        .get_value::<Archive<AlignedBytes>>(ValueKey::property(
+            account_id,
+            Collection::Principal,
+            0,
+            PrincipalField::SpamModel,
+        ))
+        .await
+        .unwrap()
+        .map(|archive| archive.deserialize::<AccountSpamModel>().unwrap())
+}
@@ -0,0 +1,201 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::utils::server::TestServer;
+use base64::{Engine, engine::general_purpose};
+use flate2::{
+    Compression,
+    write::{DeflateDecoder, DeflateEncoder},
+};
+use imap_proto::ResponseType;
+use rustls_pki_types::ServerName;
+use std::{io::Write, time::Duration};
+use tokio::{
+    io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt},
+    net::TcpStream,
+};
+use utils::tls::build_tls_connector;
+
+pub async fn test(test: &TestServer) {
+    println!("Running COMPRESS tests...");
+    let account = test.account("jdoe@example.com");
+
+    // Clear-text listener
+    test_compress(
+        TcpStream::connect("127.0.0.1:9991").await.unwrap(),
+        account.name(),
+        account.secret(),
+    )
+    .await;
+
+    // Implicit TLS listener
+    test_compress(
+        build_tls_connector(true)
+            .unwrap()
+            .connect(
+                ServerName::try_from("imap.example.org").unwrap().to_owned(),
+                TcpStream::connect("127.0.0.1:9992").await.unwrap(),
+            )
+            .await
+            .unwrap(),
+        account.name(),
+        account.secret(),
+    )
+    .await;
+}
+
+async fn test_compress<T: AsyncRead + AsyncWrite + Unpin>(stream: T, login: &str, secret: &str) {
+    let mut imap = DeflateConnection::new(stream);
+    imap.read(b"* ").await;
+
+    // COMPRESS requires authentication
+    imap.send("COMPRESS DEFLATE").await;
+    imap.assert_read(ResponseType::No).await;
+
+    let creds = general_purpose::STANDARD.encode(format!("\0{login}\0{secret}"));
+    imap.send(&format!(
+        "AUTHENTICATE PLAIN {{{}+}}\r\n{creds}",
+        creds.len()
+    ))
+    .await;
+    imap.assert_read(ResponseType::Ok).await;
+
+    // COMPRESS=DEFLATE is advertised after authentication
+    imap.send("CAPABILITY").await;
+    assert!(
+        imap.assert_read(ResponseType::Ok)
+            .await
+            .iter()
+            .any(|line| line.contains("COMPRESS=DEFLATE"))
+    );
+
+    // Unknown mechanisms are rejected
+    imap.send("COMPRESS GZIP").await;
+    imap.assert_read(ResponseType::Bad).await;
+
+    // Enable compression, everything from now on is deflated
+    imap.send("COMPRESS DEFLATE").await;
+    imap.assert_read(ResponseType::Ok).await;
+    imap.enable_deflate();
+
+    imap.send("NOOP").await;
+    imap.assert_read(ResponseType::Ok).await;
+    imap.send("SELECT INBOX").await;
+    assert!(
+        imap.assert_read(ResponseType::Ok)
+            .await
+            .iter()
+            .any(|line| line.contains("EXISTS"))
+    );
+
+    // A second compression layer must be refused
+    imap.send("COMPRESS DEFLATE").await;
+    assert!(
+        imap.assert_read(ResponseType::No)
+            .await
+            .last()
+            .unwrap()
+            .contains("[COMPRESSIONACTIVE]")
+    );
+
+    // STARTTLS cannot be negotiated on top of a compressed stream
+    imap.send("STARTTLS").await;
+    imap.assert_read(ResponseType::No).await;
+
+    // The session is still usable after the rejected commands
+    imap.send("UNSELECT").await;
+    imap.assert_read(ResponseType::Ok).await;
+    imap.send("LOGOUT").await;
+    imap.assert_read(ResponseType::Ok).await;
+}
+
+struct DeflateConnection<T: AsyncRead + AsyncWrite + Unpin> {
+    stream: T,
+    deflate: Option<(DeflateEncoder<Vec<u8>>, DeflateDecoder<Vec<u8>>)>,
+    buf: Vec<u8>,
+}
+
+const TAG: &[u8] = b"_z ";
+
+impl<T: AsyncRead + AsyncWrite + Unpin> DeflateConnection<T> {
+    fn new(stream: T) -> Self {
+        DeflateConnection {
+            stream,
+            deflate: None,
+            buf: Vec::new(),
+        }
+    }
+
+    fn enable_deflate(&mut self) {
+        assert!(self.buf.is_empty(), "Unexpected data: {:?}", self.buf);
+        self.deflate = Some((
+            DeflateEncoder::new(Vec::new(), Compression::default()),
+            DeflateDecoder::new(Vec::new()),
+        ));
+    }
+
+    async fn send(&mut self, text: &str) {
+        let mut bytes = Vec::with_capacity(TAG.len() + text.len() + 2);
+        bytes.extend_from_slice(TAG);
+        bytes.extend_from_slice(text.as_bytes());
+        bytes.extend_from_slice(b"\r\n");
+
+        if let Some((encoder, _)) = &mut self.deflate {
+            encoder.write_all(&bytes).unwrap();
+            encoder.flush().unwrap();
+            bytes = std::mem::take(encoder.get_mut());
+        }
+
+        self.stream.write_all(&bytes).await.unwrap();
+        self.stream.flush().await.unwrap();
+    }
+
+    async fn assert_read(&mut self, rt: ResponseType) -> Vec<String> {
+        let lines = self.read(TAG).await;
+        let expected = format!("{}{}", std::str::from_utf8(TAG).unwrap(), rt.as_str());
+        if lines.last().unwrap().starts_with(&expected) {
+            lines
+        } else {
+            panic!("Expected {:?} from server but got: {:?}", rt, lines);
+        }
+    }
+
+    async fn read(&mut self, prefix: &[u8]) -> Vec<String> {
+        let mut lines = Vec::new();
+        let mut chunk = vec![0u8; 4096];
+
+        loop {
+            while let Some(pos) = self.buf.windows(2).position(|w| w == b"\r\n") {
+                let line = self.buf.drain(..pos + 2).collect::<Vec<_>>();
+                let is_done = line.starts_with(prefix);
+                lines.push(String::from_utf8(line[..pos].to_vec()).unwrap());
+                if is_done {
+                    return lines;
+                }
+            }
+
+            let bytes_read = match tokio::time::timeout(
+                Duration::from_millis(1500),
+                self.stream.read(&mut chunk),
+            )
+            .await
+            {
+                Ok(Ok(bytes_read)) if bytes_read > 0 => bytes_read,
+                Ok(Ok(_)) => panic!("Connection closed: {:?}.", lines),
+                Ok(Err(err)) => panic!("Connection broken: {} ({:?})", err, lines),
+                Err(_) => panic!("Timeout while waiting for server response: {:?}", lines),
+            };
+
+            if let Some((_, decoder)) = &mut self.deflate {
+                decoder.write_all(&chunk[..bytes_read]).unwrap();
+                decoder.flush().unwrap();
+                self.buf.append(decoder.get_mut());
+            } else {
+                self.buf.extend_from_slice(&chunk[..bytes_read]);
+            }
+        }
+    }
+}
@@ -0,0 +1,106 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use imap_proto::ResponseType;
+
+use super::{AssertResult, ImapConnection, Type};
+
+pub async fn test(imap: &mut ImapConnection, imap_check: &mut ImapConnection) {
+    println!("Running METADATA tests...");
+
+    // Capabilities should be advertised
+    imap.send("CAPABILITY").await;
+    imap.assert_read(Type::Tagged, ResponseType::Ok)
+        .await
+        .assert_contains("METADATA-SERVER");
+
+    // Server annotations
+    imap.send_ok("SETMETADATA \"\" (/private/comment \"server note\")")
+        .await;
+    imap_check.send("GETMETADATA \"\" /private/comment").await;
+    imap_check
+        .assert_read(Type::Tagged, ResponseType::Ok)
+        .await
+        .assert_contains("* METADATA \"\" (\"/private/comment\" {11}")
+        .assert_contains("server note)");
+
+    // Mailbox annotations
+    imap.send_ok("CREATE \"Annotated\"").await;
+    imap.send_ok(concat!(
+        "SETMETADATA \"Annotated\" (/shared/Comment {14+}\r\nMy own comment ",
+        "/private/color \"#ff0000\")"
+    ))
+    .await;
+    imap_check
+        .send("GETMETADATA (DEPTH infinity) \"Annotated\" /shared")
+        .await;
+    imap_check
+        .assert_read(Type::Tagged, ResponseType::Ok)
+        .await
+        .assert_contains("\"/shared/comment\" {14}")
+        .assert_contains("My own comment")
+        .assert_not_contains("/private/color");
+    imap_check
+        .send("GETMETADATA (MAXSIZE 10) \"Annotated\" (/shared/comment /private/color)")
+        .await;
+    imap_check
+        .assert_read(Type::Tagged, ResponseType::Ok)
+        .await
+        .assert_response_code("METADATA LONGENTRIES 14")
+        .assert_contains("\"/private/color\" {7}")
+        .assert_not_contains("My own comment");
+    imap_check
+        .send("GETMETADATA \"Annotated\" /private/missing")
+        .await;
+    imap_check
+        .assert_read(Type::Tagged, ResponseType::Ok)
+        .await
+        .assert_contains("* METADATA \"Annotated\" (\"/private/missing\" NIL)");
+
+    // Remove an entry
+    imap.send_ok("SETMETADATA \"Annotated\" (/private/color NIL)")
+        .await;
+    imap.send("GETMETADATA \"Annotated\" /private/color").await;
+    imap.assert_read(Type::Tagged, ResponseType::Ok)
+        .await
+        .assert_contains("(\"/private/color\" NIL)");
+
+    // Special use attributes are exposed but read-only
+    imap.send("GETMETADATA \"Deleted Items\" /private/specialuse")
+        .await;
+    imap.assert_read(Type::Tagged, ResponseType::Ok)
+        .await
+        .assert_contains("\\Trash");
+    imap.send("SETMETADATA \"Annotated\" (/private/specialuse \"\\\\Sent\")")
+        .await;
+    imap.assert_read(Type::Tagged, ResponseType::No)
+        .await
+        .assert_response_code("CANNOT");
+
+    // Size limits
+    imap.send(&format!(
+        "SETMETADATA \"Annotated\" (/private/large {{70000+}}\r\n{})",
+        "a".repeat(70000)
+    ))
+    .await;
+    imap.assert_read(Type::Tagged, ResponseType::No)
+        .await
+        .assert_response_code("METADATA MAXSIZE 65536");
+
+    // Invalid requests
+    imap.send("SETMETADATA \"Annotated\" (/comment \"test\")")
+        .await;
+    imap.assert_read(Type::Tagged, ResponseType::Bad).await;
+    imap.send("GETMETADATA \"Unknown\" /shared/comment").await;
+    imap.assert_read(Type::Tagged, ResponseType::No)
+        .await
+        .assert_response_code("NONEXISTENT");
+
+    // Cleanup
+    imap.send_ok("SETMETADATA \"\" (/private/comment NIL)")
+        .await;
+    imap.send_ok("DELETE \"Annotated\"").await;
+}
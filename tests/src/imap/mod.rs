@@ -9,16 +9,20 @@ pub mod antispam;
 pub mod append;
 pub mod basic;
 pub mod body_structure;
+pub mod compress;
 pub mod condstore;
 pub mod copy_move;
 pub mod fetch;
 pub mod idle;
 pub mod mailbox;
 pub mod managesieve;
+pub mod metadata;
+pub mod notify;
 pub mod pop;
 pub mod search;
 pub mod store;
 pub mod thread;
+pub mod uidonly;
 
 use crate::utils::{
     imap::{AssertResult, ImapConnection, Type},
@@ -149,6 +153,7 @@ fn imap_tests() {
                 .registry_create_object(SpamClassifier {
                     min_ham_samples: 10,
                     min_spam_samples: 10,
+                    account_model: true,
                     ..Default::default()
                 })
                 .await;
@@ -253,6 +258,9 @@ fn imap_tests() {
             thread::test(&mut imap, &mut imap_check, &test).await;
             idle::test(&mut imap, &mut imap_check, false).await;
             condstore::test(&mut imap, &mut imap_check).await;
+            metadata::test(&mut imap, &mut imap_check).await;
+            notify::test(&mut imap, &mut imap_check).await;
+            uidonly::test(&mut imap, &test).await;
             acl::test(&mut imap, &mut imap_check, &test).await;
 
             // Logout
@@ -264,6 +272,9 @@ fn imap_tests() {
                 imap.assert_read(Type::Untagged, ResponseType::Bye).await;
             }
 
+            // Compression tests
+            compress::test(&test).await;
+
             // Antispam training
             antispam::test(&test).await;
 
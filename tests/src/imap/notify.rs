@@ -0,0 +1,83 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use imap_proto::ResponseType;
+
+use super::{AssertResult, ImapConnection, Type};
+
+pub async fn test(imap: &mut ImapConnection, imap_check: &mut ImapConnection) {
+    println!("Running NOTIFY tests...");
+
+    // Capability should be advertised
+    imap.send("CAPABILITY").await;
+    imap.assert_read(Type::Tagged, ResponseType::Ok)
+        .await
+        .assert_contains("NOTIFY");
+
+    // Unsupported events are rejected
+    imap_check
+        .send("NOTIFY SET (personal (MessageNew MessageExpunge SubscriptionChange))")
+        .await;
+    imap_check
+        .assert_read(Type::Tagged, ResponseType::No)
+        .await
+        .assert_response_code("BADEVENT (MessageNew MessageExpunge FlagChange MailboxName)");
+    imap_check.send("NOTIFY SET (personal (MessageNew))").await;
+    imap_check
+        .assert_read(Type::Tagged, ResponseType::Bad)
+        .await;
+
+    // Request notifications for the personal namespace
+    imap.send_ok("CREATE \"Notified\"").await;
+    imap_check.send_ok("SELECT INBOX").await;
+    imap_check
+        .send(concat!(
+            "NOTIFY SET (STATUS) (selected (MessageNew (UID) MessageExpunge FlagChange)) ",
+            "(personal (MessageNew MessageExpunge MailboxName))"
+        ))
+        .await;
+    imap_check
+        .assert_read(Type::Tagged, ResponseType::Ok)
+        .await
+        .assert_contains("* STATUS \"Notified\" (MESSAGES 0")
+        .assert_not_contains("* STATUS \"INBOX\"");
+
+    // New mailboxes are announced
+    imap.send_ok("CREATE \"Notified/Child\"").await;
+    imap_check
+        .assert_read(Type::Status, ResponseType::Ok)
+        .await
+        .assert_contains("LIST () \"/\" \"Notified/Child\"");
+
+    // New messages in other mailboxes produce STATUS responses
+    imap.append(
+        "Notified",
+        "From: test@domain.com\nSubject: Notify\n\nTest message\n",
+    )
+    .await;
+    imap_check
+        .assert_read(Type::Status, ResponseType::Ok)
+        .await
+        .assert_contains("STATUS \"Notified\"")
+        .assert_contains("MESSAGES 1")
+        .assert_contains("UNSEEN 1");
+
+    // Deleted mailboxes are announced
+    imap.send_ok("DELETE \"Notified/Child\"").await;
+    imap_check
+        .assert_read(Type::Status, ResponseType::Ok)
+        .await
+        .assert_contains("LIST (\\NonExistent) \"/\" \"Notified/Child\"");
+
+    // Disable notifications
+    imap_check.send_ok("NOTIFY NONE").await;
+    imap.send_ok("DELETE \"Notified\"").await;
+    imap_check.send("NOOP").await;
+    imap_check
+        .assert_read(Type::Tagged, ResponseType::Ok)
+        .await
+        .assert_not_contains("LIST");
+}
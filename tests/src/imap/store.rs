@@ -72,4 +72,48 @@ pub async fn test(imap: &mut ImapConnection, _imap_check: &mut ImapConnection, t
         .await
         .assert_count("FLAGS", 3)
         .assert_count("Answered", 0);
+
+    // Snooze a message until a future date, the snooze date is returned as a keyword
+    imap.send("UID STORE 2 +FLAGS ($SnoozedUntil:2099-01-01T08:00:00Z)")
+        .await;
+    imap.assert_read(Type::Tagged, ResponseType::Ok)
+        .await
+        .assert_count("FLAGS", 1)
+        .assert_contains("$SnoozedUntil:2099-01-01T08:00:00");
+    imap.send("UID FETCH 2 (FLAGS)").await;
+    imap.assert_read(Type::Tagged, ResponseType::Ok)
+        .await
+        .assert_contains("$SnoozedUntil:2099-01-01T08:00:00");
+    imap.send("UID STORE 2 -FLAGS ($SnoozedUntil:2099-01-01T08:00:00Z)")
+        .await;
+    imap.assert_read(Type::Tagged, ResponseType::Ok)
+        .await
+        .assert_count("SnoozedUntil", 0);
+    imap.send("UID FETCH 2 (FLAGS)").await;
+    imap.assert_read(Type::Tagged, ResponseType::Ok)
+        .await
+        .assert_count("SnoozedUntil", 0);
+
+    // Snooze a message until a past date
+    imap.send("UID STORE 1 +FLAGS.SILENT (\\Seen)").await;
+    imap.assert_read(Type::Tagged, ResponseType::Ok).await;
+    imap.send("UID STORE 1 +FLAGS ($SnoozedUntil:2020-01-01T00:00:00Z)")
+        .await;
+    imap.assert_read(Type::Tagged, ResponseType::Ok)
+        .await
+        .assert_count("FLAGS", 1)
+        .assert_contains("$SnoozedUntil:2020-01-01T00:00:00");
+    imap.send("UID STORE 1 +FLAGS ($SnoozedUntil:tomorrow)")
+        .await;
+    imap.assert_read(Type::Tagged, ResponseType::No)
+        .await
+        .assert_contains("PARSE");
+
+    // The wake-up task marks the message as unread and clears the snooze date
+    test.wait_for_tasks().await;
+    imap.send("UID FETCH 1 (FLAGS)").await;
+    imap.assert_read(Type::Tagged, ResponseType::Ok)
+        .await
+        .assert_count("\\Seen", 0)
+        .assert_count("SnoozedUntil", 0);
 }
@@ -0,0 +1,174 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use imap_proto::ResponseType;
+
+use super::{AssertResult, ImapConnection, Type};
+use crate::utils::server::TestServer;
+
+pub async fn test(imap: &mut ImapConnection, test: &TestServer) {
+    println!("Running UIDONLY, PARTIAL and SAVEDATE tests...");
+
+    // Capabilities should be advertised
+    imap.send("CAPABILITY").await;
+    imap.assert_read(Type::Tagged, ResponseType::Ok)
+        .await
+        .assert_contains("UIDONLY")
+        .assert_contains("PARTIAL")
+        .assert_contains("SAVEDATE");
+
+    // Create a mailbox with five messages
+    imap.send_ok("CREATE \"Paginated\"").await;
+    for num in 1..=5 {
+        imap.append(
+            "Paginated",
+            &format!("Message-ID: <partial{num}@domain>\r\nSubject: Page {num}\r\n\r\nbody\r\n"),
+        )
+        .await;
+    }
+    imap.send_ok("SELECT \"Paginated\"").await;
+
+    // PARTIAL search results
+    imap.send("UID SEARCH RETURN (COUNT PARTIAL 1:2) ALL").await;
+    imap.assert_read(Type::Tagged, ResponseType::Ok)
+        .await
+        .assert_contains("COUNT 5 PARTIAL (1:2 1:2)");
+    imap.send("UID SEARCH RETURN (PARTIAL -1:-2) ALL").await;
+    imap.assert_read(Type::Tagged, ResponseType::Ok)
+        .await
+        .assert_contains("PARTIAL (-1:-2 4:5)");
+    imap.send("UID SEARCH RETURN (PARTIAL 10:20) ALL").await;
+    imap.assert_read(Type::Tagged, ResponseType::Ok)
+        .await
+        .assert_contains("PARTIAL (10:20 NIL)");
+    imap.send("UID SEARCH RETURN (PARTIAL 1:2 ALL) ALL").await;
+    imap.assert_read(Type::Tagged, ResponseType::Bad).await;
+
+    // PARTIAL fetch modifier
+    imap.send("UID FETCH 1:* (FLAGS) (PARTIAL -1:-1)").await;
+    imap.assert_read(Type::Tagged, ResponseType::Ok)
+        .await
+        .assert_contains("UID 5")
+        .assert_not_contains("UID 4");
+
+    // SAVEDATE fetch and search
+    imap.send("FETCH 1 (SAVEDATE)").await;
+    imap.assert_read(Type::Tagged, ResponseType::Ok)
+        .await
+        .assert_contains("SAVEDATE \"");
+    imap.send("SEARCH SAVEDATESUPPORTED").await;
+    imap.assert_read(Type::Tagged, ResponseType::Ok)
+        .await
+        .assert_contains("1:5");
+    imap.send("SEARCH SAVEDBEFORE 1-Jan-2000").await;
+    imap.assert_read(Type::Tagged, ResponseType::Ok)
+        .await
+        .assert_not_contains("1:5");
+    imap.send("SEARCH SAVEDSINCE 1-Jan-2000").await;
+    imap.assert_read(Type::Tagged, ResponseType::Ok)
+        .await
+        .assert_contains("1:5");
+
+    // The save date is the time of the APPEND, not the internal date
+    imap.send_ok("CREATE \"Saved\"").await;
+    imap.send_ok("CREATE \"Saved Copies\"").await;
+    for num in 1..=2 {
+        let message =
+            format!("Message-ID: <saved{num}@domain>\r\nSubject: Saved {num}\r\n\r\nbody\r\n");
+        imap.send_ok(&format!(
+            "APPEND \"Saved\" \"01-Jan-2010 00:00:00 +0000\" {{{}+}}\r\n{}",
+            message.len(),
+            message
+        ))
+        .await;
+    }
+    imap.send_ok("SELECT \"Saved\"").await;
+    imap.send("UID FETCH 1:2 (INTERNALDATE SAVEDATE)").await;
+    imap.assert_read(Type::Tagged, ResponseType::Ok)
+        .await
+        .assert_contains("INTERNALDATE \"01-Jan-2010")
+        .assert_contains("SAVEDATE \"")
+        .assert_not_contains("SAVEDATE \"01-Jan-2010");
+    imap.send("UID SEARCH SAVEDSINCE 1-Jan-2020").await;
+    imap.assert_read(Type::Tagged, ResponseType::Ok)
+        .await
+        .assert_contains("ALL 1:2");
+    imap.send("UID SEARCH SAVEDBEFORE 1-Jan-2020").await;
+    imap.assert_read(Type::Tagged, ResponseType::Ok)
+        .await
+        .assert_not_contains(" ALL ");
+    imap.send("UID SEARCH SINCE 1-Jan-2020").await;
+    imap.assert_read(Type::Tagged, ResponseType::Ok)
+        .await
+        .assert_not_contains(" ALL ");
+
+    // Copied and moved messages are saved to the destination mailbox at COPY/MOVE time
+    imap.send_ok("UID COPY 1 \"Saved Copies\"").await;
+    imap.send_ok("UID MOVE 2 \"Saved Copies\"").await;
+    imap.send_ok("SELECT \"Saved Copies\"").await;
+    imap.send("UID FETCH 1:2 (SAVEDATE)").await;
+    imap.assert_read(Type::Tagged, ResponseType::Ok)
+        .await
+        .assert_contains("UID 1 SAVEDATE \"")
+        .assert_contains("UID 2 SAVEDATE \"")
+        .assert_not_contains("SAVEDATE \"01-Jan-2010")
+        .assert_not_contains("SAVEDATE NIL");
+    imap.send("UID SEARCH SAVEDSINCE 1-Jan-2020").await;
+    imap.assert_read(Type::Tagged, ResponseType::Ok)
+        .await
+        .assert_contains("ALL 1:2");
+    imap.send("UID SEARCH SAVEDBEFORE 1-Jan-2020").await;
+    imap.assert_read(Type::Tagged, ResponseType::Ok)
+        .await
+        .assert_not_contains(" ALL ");
+    imap.send_ok("UNSELECT").await;
+    imap.send_ok("DELETE \"Saved\"").await;
+    imap.send_ok("DELETE \"Saved Copies\"").await;
+    imap.send_ok("SELECT \"Paginated\"").await;
+
+    // Enable UIDONLY on a separate session
+    let mut imap_uid = test.account("jdoe@example.com").imap_client().await;
+    imap_uid.send("ENABLE UIDONLY").await;
+    imap_uid
+        .assert_read(Type::Tagged, ResponseType::Ok)
+        .await
+        .assert_contains("* ENABLED UIDONLY");
+    imap_uid.send_ok("SELECT \"Paginated\"").await;
+
+    // Commands using sequence numbers are rejected
+    for command in ["FETCH 1 (FLAGS)", "STORE 1 +FLAGS (\\Seen)", "SEARCH ALL"] {
+        imap_uid.send(command).await;
+        imap_uid
+            .assert_read(Type::Tagged, ResponseType::Bad)
+            .await
+            .assert_response_code("UIDREQUIRED");
+    }
+
+    // Responses are keyed by UID
+    imap_uid.send("UID FETCH 2:3 (FLAGS)").await;
+    imap_uid
+        .assert_read(Type::Tagged, ResponseType::Ok)
+        .await
+        .assert_contains("* 2 UIDFETCH (FLAGS ()")
+        .assert_contains("* 3 UIDFETCH (FLAGS ()")
+        .assert_not_contains(" FETCH ");
+    imap_uid.send("UID STORE 4 +FLAGS (\\Deleted)").await;
+    imap_uid
+        .assert_read(Type::Tagged, ResponseType::Ok)
+        .await
+        .assert_contains("* 4 UIDFETCH (FLAGS (\\Deleted))");
+    imap_uid.send("UID EXPUNGE 4").await;
+    imap_uid
+        .assert_read(Type::Tagged, ResponseType::Ok)
+        .await
+        .assert_contains("* VANISHED 4")
+        .assert_not_contains("EXPUNGE\r\n");
+
+    // Cleanup
+    imap_uid.send_ok("UNSELECT").await;
+    imap.send_ok("UNSELECT").await;
+    imap.send_ok("DELETE \"Paginated\"").await;
+}
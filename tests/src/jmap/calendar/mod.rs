@@ -10,3 +10,5 @@ pub mod calendars;
 pub mod event;
 pub mod identity;
 pub mod notification;
+pub mod publish;
+pub mod task;
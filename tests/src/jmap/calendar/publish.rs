@@ -0,0 +1,212 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::utils::{jmap::JmapUtils, server::TestServer};
+use hyper::StatusCode;
+use jmap_proto::request::method::MethodObject;
+use serde_json::{Value, json};
+
+pub async fn test(test: &TestServer) {
+    println!("Running Calendar publishing tests...");
+    let account = test.account("jdoe@example.com");
+    let dav_client = account.webdav_client();
+
+    // Create a recurring event with an alarm and a private event
+    let response = account
+        .jmap_get(MethodObject::Calendar, ["id", "name"], Vec::<&str>::new())
+        .await;
+    let calendar_id = response.list()[0].id().to_string();
+    for (name, ical) in [
+        ("sync.ics", TEST_VEVENT),
+        ("doctor.ics", TEST_PRIVATE_VEVENT),
+    ] {
+        dav_client
+            .request(
+                "PUT",
+                &format!("/dav/cal/jdoe%40example.com/default/{name}"),
+                ical,
+            )
+            .await
+            .with_status(StatusCode::CREATED);
+    }
+
+    // Calendars are not published by default
+    let response = account
+        .jmap_get(
+            MethodObject::Calendar,
+            ["publish", "publishUrl"],
+            [calendar_id.as_str()],
+        )
+        .await;
+    assert_eq!(response.list()[0]["publish"], Value::Null);
+    assert_eq!(response.list()[0]["publishUrl"], Value::Null);
+
+    // Publish the full calendar
+    account
+        .jmap_update(
+            MethodObject::Calendar,
+            [(&calendar_id, json!({ "publish": "full" }))],
+            Vec::<(&str, &str)>::new(),
+        )
+        .await
+        .updated(&calendar_id);
+    let response = account
+        .jmap_get(
+            MethodObject::Calendar,
+            ["publish", "publishUrl"],
+            [calendar_id.as_str()],
+        )
+        .await;
+    assert_eq!(response.list()[0]["publish"], "full");
+    let publish_url = response.list()[0]["publishUrl"]
+        .as_str()
+        .unwrap()
+        .to_string();
+    let feed_path = publish_url.split_once("/calendar/").unwrap().1.to_string();
+
+    // Fetch the feed without authentication
+    let (status, etag, ical) = feed_get(&feed_path, None).await;
+    assert_eq!(status, 200);
+    assert!(ical.contains("SUMMARY:Team sync"), "{ical}");
+    assert!(ical.contains("RRULE:FREQ=WEEKLY"), "{ical}");
+    assert!(!ical.contains("BEGIN:VALARM"), "{ical}");
+
+    // Participants and private event details are not exposed
+    assert!(!ical.contains("ATTENDEE"), "{ical}");
+    assert!(!ical.contains("ORGANIZER"), "{ical}");
+    assert!(!ical.contains("Doctor"), "{ical}");
+    assert!(!ical.contains("Cardiology"), "{ical}");
+    assert!(ical.contains("UID:doctor-appointment"), "{ical}");
+    assert!(ical.contains("SUMMARY:Busy"), "{ical}");
+    let etag = etag.unwrap();
+
+    // Conditional requests
+    let (status, new_etag, _) = feed_get(&feed_path, Some(&etag)).await;
+    assert_eq!(status, 304);
+    assert_eq!(new_etag.unwrap(), etag);
+
+    // Switch to free/busy only, the token is preserved
+    account
+        .jmap_update(
+            MethodObject::Calendar,
+            [(&calendar_id, json!({ "publish": "freeBusy" }))],
+            Vec::<(&str, &str)>::new(),
+        )
+        .await
+        .updated(&calendar_id);
+    let response = account
+        .jmap_get(
+            MethodObject::Calendar,
+            ["publish", "publishUrl"],
+            [calendar_id.as_str()],
+        )
+        .await;
+    assert_eq!(response.list()[0]["publish"], "freeBusy");
+    assert_eq!(response.list()[0]["publishUrl"], publish_url.as_str());
+    let (status, fb_etag, ical) = feed_get(&feed_path, Some(&etag)).await;
+    assert_eq!(status, 200);
+    assert_ne!(fb_etag.unwrap(), etag);
+    assert!(ical.contains("SUMMARY:Busy"), "{ical}");
+    assert!(!ical.contains("Team sync"), "{ical}");
+    assert!(!ical.contains("RRULE"), "{ical}");
+
+    // Invalid tokens are rejected
+    let (id, _) = feed_path.split_once('-').unwrap();
+    let (status, _, _) = feed_get(&format!("{id}-{}.ics", "x".repeat(32)), None).await;
+    assert_eq!(status, 404);
+
+    // Unpublishing revokes the token
+    account
+        .jmap_update(
+            MethodObject::Calendar,
+            [(&calendar_id, json!({ "publish": null }))],
+            Vec::<(&str, &str)>::new(),
+        )
+        .await
+        .updated(&calendar_id);
+    let (status, _, _) = feed_get(&feed_path, None).await;
+    assert_eq!(status, 404);
+
+    // Publishing again issues a new token
+    account
+        .jmap_update(
+            MethodObject::Calendar,
+            [(&calendar_id, json!({ "publish": "full" }))],
+            Vec::<(&str, &str)>::new(),
+        )
+        .await
+        .updated(&calendar_id);
+    let response = account
+        .jmap_get(
+            MethodObject::Calendar,
+            ["publishUrl"],
+            [calendar_id.as_str()],
+        )
+        .await;
+    assert_ne!(response.list()[0]["publishUrl"], publish_url.as_str());
+
+    // Clean up
+    account.destroy_all_calendars().await;
+    test.assert_is_empty().await;
+}
+
+async fn feed_get(path: &str, if_none_match: Option<&str>) -> (u16, Option<String>, String) {
+    let mut request = reqwest::Client::builder()
+        .danger_accept_invalid_certs(true)
+        .build()
+        .unwrap()
+        .get(format!("https://127.0.0.1:8899/calendar/{path}"));
+    if let Some(etag) = if_none_match {
+        request = request.header("If-None-Match", etag);
+    }
+    let response = request.send().await.unwrap();
+
+    (
+        response.status().as_u16(),
+        response
+            .headers()
+            .get("ETag")
+            .and_then(|v| v.to_str().ok())
+            .map(|v| v.to_string()),
+        response.text().await.unwrap(),
+    )
+}
+
+const TEST_VEVENT: &str = r#"BEGIN:VCALENDAR
+VERSION:2.0
+PRODID:-//Apple Inc.//iOS 17.0//EN
+BEGIN:VEVENT
+UID:team-sync
+DTSTAMP:20250101T090000Z
+DTSTART:20250106T100000Z
+DTEND:20250106T110000Z
+RRULE:FREQ=WEEKLY
+SUMMARY:Team sync
+ORGANIZER:mailto:jdoe@example.com
+ATTENDEE;PARTSTAT=ACCEPTED:mailto:bill@example.com
+BEGIN:VALARM
+ACTION:DISPLAY
+TRIGGER:-PT15M
+DESCRIPTION:Team sync
+END:VALARM
+END:VEVENT
+END:VCALENDAR
+"#;
+
+const TEST_PRIVATE_VEVENT: &str = r#"BEGIN:VCALENDAR
+VERSION:2.0
+PRODID:-//Apple Inc.//iOS 17.0//EN
+BEGIN:VEVENT
+UID:doctor-appointment
+DTSTAMP:20250101T090000Z
+DTSTART:20250107T150000Z
+DTEND:20250107T160000Z
+CLASS:PRIVATE
+SUMMARY:Doctor appointment
+LOCATION:Cardiology
+END:VEVENT
+END:VCALENDAR
+"#;
@@ -0,0 +1,233 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::utils::{jmap::JmapUtils, server::TestServer};
+use ahash::AHashSet;
+use hyper::StatusCode;
+use jmap_proto::request::method::MethodObject;
+use serde_json::json;
+
+pub async fn test(test: &TestServer) {
+    println!("Running Task tests...");
+    let account = test.account("jdoe@example.com");
+    let dav_client = account.webdav_client();
+
+    // The default calendar accepts VTODO components
+    let response = account
+        .jmap_get(MethodObject::TaskList, ["id", "name"], Vec::<&str>::new())
+        .await;
+    let list = response.list();
+    assert_eq!(list.len(), 1);
+    let task_list_id = list[0].id().to_string();
+
+    // Create tasks and an event over CalDAV, as Apple Reminders would
+    for (name, ical) in [
+        ("groceries.ics", TEST_VTODO_1),
+        ("taxes.ics", TEST_VTODO_2),
+        ("meeting.ics", TEST_VEVENT),
+    ] {
+        dav_client
+            .request(
+                "PUT",
+                &format!("/dav/cal/jdoe%40example.com/default/{name}"),
+                ical,
+            )
+            .await
+            .with_status(StatusCode::CREATED);
+    }
+    test.wait_for_tasks().await;
+
+    // Only VTODO resources are returned by Task/get
+    let response = account
+        .jmap_get(
+            MethodObject::Task,
+            ["id", "@type", "uid", "title"],
+            Vec::<&str>::new(),
+        )
+        .await;
+    let list = response.list();
+    assert_eq!(list.len(), 2);
+    let mut groceries_id = String::new();
+    let mut taxes_id = String::new();
+    for task in list {
+        assert_eq!(task["@type"], "Task");
+        assert_eq!(task["taskListId"], task_list_id.as_str());
+        match task["uid"].as_str().unwrap() {
+            "buy-groceries" => {
+                assert_eq!(task["title"], "Buy groceries");
+                groceries_id = task.id().to_string();
+            }
+            "file-taxes" => {
+                assert_eq!(task["title"], "File taxes");
+                taxes_id = task.id().to_string();
+            }
+            uid => panic!("Unexpected task {uid}"),
+        }
+    }
+
+    // Query by completion status and sort by due date
+    assert_eq!(
+        account
+            .jmap_query(
+                MethodObject::Task,
+                [("isCompleted", false)],
+                ["due"],
+                Vec::<(&str, &str)>::new(),
+            )
+            .await
+            .ids()
+            .collect::<Vec<_>>(),
+        vec![groceries_id.as_str()]
+    );
+    assert_eq!(
+        account
+            .jmap_query(
+                MethodObject::Task,
+                [("isCompleted", true)],
+                ["due"],
+                Vec::<(&str, &str)>::new(),
+            )
+            .await
+            .ids()
+            .collect::<Vec<_>>(),
+        vec![taxes_id.as_str()]
+    );
+    assert_eq!(
+        account
+            .jmap_query(
+                MethodObject::Task,
+                Vec::<(&str, &str)>::new(),
+                ["due"],
+                Vec::<(&str, &str)>::new(),
+            )
+            .await
+            .ids()
+            .collect::<Vec<_>>(),
+        vec![taxes_id.as_str(), groceries_id.as_str()]
+    );
+
+    // Create a task over JMAP
+    let task_id = account
+        .jmap_create(
+            MethodObject::Task,
+            [json!({
+                "taskListId": task_list_id,
+                "uid": "walk-the-dog",
+                "title": "Walk the dog",
+            })],
+            Vec::<(&str, &str)>::new(),
+        )
+        .await
+        .created(0)
+        .id()
+        .to_string();
+    let response = account
+        .jmap_get(
+            MethodObject::Task,
+            ["id", "@type", "title"],
+            [task_id.as_str()],
+        )
+        .await;
+    assert_eq!(
+        response.list()[0],
+        json!({
+            "id": task_id,
+            "@type": "Task",
+            "title": "Walk the dog",
+            "taskListId": task_list_id
+        })
+    );
+
+    // Events cannot be modified through Task/set
+    let response = account
+        .jmap_get(
+            MethodObject::CalendarEvent,
+            ["id", "uid"],
+            Vec::<&str>::new(),
+        )
+        .await;
+    let event_id = response
+        .list()
+        .iter()
+        .find(|event| event["uid"] == "team-meeting")
+        .unwrap()
+        .id()
+        .to_string();
+    assert_eq!(
+        account
+            .jmap_destroy(
+                MethodObject::Task,
+                [event_id.as_str()],
+                Vec::<(&str, &str)>::new(),
+            )
+            .await
+            .not_destroyed(&event_id)["type"],
+        "notFound"
+    );
+
+    // Destroy tasks
+    assert_eq!(
+        account
+            .jmap_destroy(
+                MethodObject::Task,
+                [groceries_id.as_str(), taxes_id.as_str(), task_id.as_str()],
+                Vec::<(&str, &str)>::new(),
+            )
+            .await
+            .destroyed()
+            .collect::<AHashSet<_>>(),
+        [groceries_id.as_str(), taxes_id.as_str(), task_id.as_str()]
+            .into_iter()
+            .collect::<AHashSet<_>>()
+    );
+
+    // Clean up
+    test.wait_for_tasks().await;
+    account.destroy_all_calendars().await;
+    test.assert_is_empty().await;
+}
+
+const TEST_VTODO_1: &str = r#"BEGIN:VCALENDAR
+VERSION:2.0
+PRODID:-//Apple Inc.//iOS 17.0//EN
+BEGIN:VTODO
+UID:buy-groceries
+DTSTAMP:20260101T090000Z
+SUMMARY:Buy groceries
+DUE:20260301T170000Z
+PERCENT-COMPLETE:50
+STATUS:IN-PROCESS
+END:VTODO
+END:VCALENDAR
+"#;
+
+const TEST_VTODO_2: &str = r#"BEGIN:VCALENDAR
+VERSION:2.0
+PRODID:-//Apple Inc.//iOS 17.0//EN
+BEGIN:VTODO
+UID:file-taxes
+DTSTAMP:20260101T090000Z
+SUMMARY:File taxes
+DUE:20260115T170000Z
+COMPLETED:20260110T120000Z
+PERCENT-COMPLETE:100
+STATUS:COMPLETED
+END:VTODO
+END:VCALENDAR
+"#;
+
+const TEST_VEVENT: &str = r#"BEGIN:VCALENDAR
+VERSION:2.0
+PRODID:-//Apple Inc.//iOS 17.0//EN
+BEGIN:VEVENT
+UID:team-meeting
+DTSTAMP:20260101T090000Z
+DTSTART:20260201T100000Z
+DTEND:20260201T110000Z
+SUMMARY:Team meeting
+END:VEVENT
+END:VCALENDAR
+"#;
@@ -4,7 +4,10 @@
  * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
  */
 
-use crate::{AssertConfig, utils::server::TestServer};
+use crate::{
+    AssertConfig,
+    utils::{server::TestServer, smtp::SmtpConnection},
+};
 use base64::{Engine, engine::general_purpose};
 use common::{config::server::Listeners, network::SessionData};
 use ece::EcKeyComponents;
@@ -196,6 +199,51 @@ pub async fn test(test: &TestServer) {
     assert_state(&mut event_rx, account.id(), &[DataType::Mailbox]).await;
     expect_nothing(&mut event_rx).await;
 
+    // Sieve notifications are delivered through push subscriptions
+    client
+        .sieve_script_create(
+            "test_notify_push",
+            concat!(
+                "require \"enotify\";\r\n",
+                "if address :is \"From\" \"boss@remote.org\" {\r\n",
+                "    notify :importance \"1\" :message \"The boss wrote\" \"push:\";\r\n",
+                "}\r\n",
+                "keep;\r\n"
+            ),
+            true,
+        )
+        .await
+        .unwrap();
+    SmtpConnection::connect()
+        .await
+        .ingest(
+            "boss@remote.org",
+            &["jdoe@example.com"],
+            "From: boss@remote.org\r\nSubject: TPS reports\r\n\r\nWhere are they?\r\n",
+        )
+        .await;
+    let mut notification = None;
+    while notification.is_none() {
+        notification = expect_push(&mut event_rx)
+            .await
+            .find_sieve_notification()
+            .map(|(account_id, importance, message)| {
+                (*account_id, importance, message.to_string())
+            });
+    }
+    assert_eq!(
+        notification.unwrap(),
+        (account.id(), 1, "The boss wrote".to_string())
+    );
+    client.sieve_script_deactivate().await.unwrap();
+    let mut request = client.build();
+    request.query_sieve_script();
+    for id in request.send_query_sieve_script().await.unwrap().take_ids() {
+        client.sieve_script_destroy(&id).await.unwrap();
+    }
+    tokio::time::sleep(Duration::from_millis(1500)).await;
+    while event_rx.try_recv().is_ok() {}
+
     // Destroy mailbox
     client.push_subscription_destroy(&push_id).await.unwrap();
     client.mailbox_destroy(&mailbox_id, true).await.unwrap();
@@ -237,6 +285,25 @@ impl PushMessage {
         }
     }
 
+    pub fn find_sieve_notification(&self) -> Option<(&Id, u8, &str)> {
+        fn find(object: &PushObject) -> Option<(&Id, u8, &str)> {
+            match object {
+                PushObject::SieveNotification {
+                    account_id,
+                    importance,
+                    message,
+                } => Some((account_id, *importance, message.as_str())),
+                PushObject::Group { entries } => entries.iter().find_map(find),
+                _ => None,
+            }
+        }
+
+        match self {
+            PushMessage::PushObject(object) => find(object),
+            _ => None,
+        }
+    }
+
     pub fn unwrap_verification(self) -> PushVerification {
         match self {
             PushMessage::Verification(verification) => verification,
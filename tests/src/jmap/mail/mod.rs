@@ -15,6 +15,7 @@ pub mod query_changes;
 pub mod search_snippet;
 pub mod set;
 pub mod sieve_script;
+pub mod snooze;
 pub mod submission;
 pub mod thread_get;
 pub mod thread_merge;
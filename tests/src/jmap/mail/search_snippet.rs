@@ -31,6 +31,7 @@ pub async fn test(test: &TestServer) {
         "mixed",
         "text_plain",
         "text_plain_chinese",
+        "attachments",
     ] {
         let mut file_name = test_dir.clone();
         file_name.push(format!("{}.eml", email_name));
@@ -168,6 +169,20 @@ pub async fn test(test: &TestServer) {
         );
     }
 
+    // Text extracted from ZIP, DOCX and PDF attachments should be searchable
+    let attachments_id = email_ids.get("attachments").unwrap();
+    for text in ["quokka", "\"wombat population\"", "platypus"] {
+        assert_eq!(
+            client
+                .email_query(Filter::text(text).into(), None::<Vec<_>>)
+                .await
+                .unwrap()
+                .ids(),
+            [attachments_id.as_str()],
+            "text: {text}"
+        );
+    }
+
     // Destroy test data
     test.destroy_all_mailboxes(account).await;
     test.assert_is_empty().await;
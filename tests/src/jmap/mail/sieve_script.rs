@@ -5,8 +5,10 @@
  */
 
 use crate::{
-    jmap::mail::submission::{MockMessage, assert_message_delivery, spawn_mock_smtp_server},
-    utils::{dns::DnsCache, server::TestServer, smtp::SmtpConnection},
+    jmap::mail::submission::{
+        MockMessage, assert_message_delivery, expect_nothing, spawn_mock_smtp_server,
+    },
+    utils::{dns::DnsCache, jmap::JmapUtils, server::TestServer, smtp::SmtpConnection},
 };
 use jmap_client::{
     Error,
@@ -14,7 +16,15 @@ use jmap_client::{
     email, mailbox,
     sieve::query::{Comparator, Filter},
 };
-use registry::schema::{prelude::ObjectType, structs::SieveUserScript};
+use jmap_proto::request::method::MethodObject;
+use registry::{
+    schema::{
+        prelude::{ObjectType, Property},
+        structs::{MemoryLookupKey, Rate, SieveUserInterpreter, SieveUserScript},
+    },
+    types::map::Map,
+};
+use serde_json::json;
 use std::{
     fs,
     path::PathBuf,
@@ -498,7 +508,198 @@ pub async fn test(test: &TestServer) {
         panic!("Email {:?} not found in: {:#?}", subject, emails);
     }
 
+    // Notifications sent to mailto: URIs are rate limited
+    admin
+        .registry_update_setting(
+            SieveUserInterpreter {
+                rate_limit_notify: Some(Rate {
+                    count: 1,
+                    period: 3_600_000u64.into(),
+                }),
+                ..Default::default()
+            },
+            &[Property::RateLimitNotify],
+        )
+        .await;
+    admin.reload_settings().await;
+    for (subject, is_notified) in [("TPS reminder", true), ("TPS reminder again", false)] {
+        lmtp.ingest(
+            "bill@remote.org",
+            &["jdoe@example.com"],
+            &format!(
+                "From: bill@remote.org\r\nTo: jdoe@example.com\r\nSubject: {subject}\r\n\r\nTPS?"
+            ),
+        )
+        .await;
+        if is_notified {
+            assert_message_delivery(
+                &mut smtp_rx,
+                MockMessage::new(
+                    "<jdoe@example.com>",
+                    ["<sms_gateway@remote.org>"],
+                    "@It's TPS-o-clock",
+                ),
+            )
+            .await;
+        } else {
+            expect_nothing(&mut smtp_rx).await;
+        }
+    }
+    admin
+        .registry_update_setting(
+            SieveUserInterpreter::default(),
+            &[Property::RateLimitNotify],
+        )
+        .await;
+    admin.reload_settings().await;
+
+    // Run extlists tests against the default address book
+    let book_id = account
+        .jmap_get(MethodObject::AddressBook, ["id"], Vec::<&str>::new())
+        .await
+        .list()[0]
+        .get("id")
+        .unwrap()
+        .as_str()
+        .unwrap()
+        .to_string();
+    account
+        .jmap_create(
+            MethodObject::ContactCard,
+            [json!({
+                "name": {
+                    "full": "Sarah Connor",
+                },
+                "emails": {
+                    "e1": {
+                        "address": "sarah@remote.org"
+                    }
+                },
+                "addressBookIds": {
+                    &book_id: true
+                },
+            })],
+            Vec::<(&str, &str)>::new(),
+        )
+        .await
+        .created(0);
+
+    // Only lookup stores exposed to user scripts can be queried
+    let mut lookup_ids = Vec::new();
+    for (namespace, key) in [
+        ("vip-senders", "sarah@remote.org"),
+        ("blocked-senders", "stranger@remote.org"),
+    ] {
+        lookup_ids.push(
+            admin
+                .registry_create_object(MemoryLookupKey {
+                    namespace: namespace.into(),
+                    key: key.into(),
+                    is_glob_pattern: false,
+                })
+                .await,
+        );
+    }
+    admin
+        .registry_update_setting(
+            SieveUserInterpreter {
+                allowed_ext_lists: Map::new(vec!["vip-senders".to_string()]),
+                ..Default::default()
+            },
+            &[Property::AllowedExtLists],
+        )
+        .await;
+    admin.reload_settings().await;
+    admin.reload_lookup_stores().await;
+
+    client
+        .sieve_script_create("test_extlists", get_script("test_extlists"), true)
+        .await
+        .unwrap();
+    for sender in ["sarah@remote.org", "stranger@remote.org"] {
+        lmtp.ingest(
+            sender,
+            &["jdoe@example.com"],
+            &format!(
+                concat!(
+                    "From: {}\r\n",
+                    "To: jdoe@example.com\r\n",
+                    "Subject: Hello\r\n",
+                    "\r\n",
+                    "Are you there?"
+                ),
+                sender
+            ),
+        )
+        .await;
+    }
+    let unknown_id = client
+        .mailbox_query(
+            mailbox::query::Filter::name("Unknown").into(),
+            None::<Vec<_>>,
+        )
+        .await
+        .unwrap()
+        .take_ids()
+        .pop()
+        .expect("Unknown mailbox not found");
+    assert_eq!(
+        client
+            .email_query(
+                email::query::Filter::in_mailbox(&unknown_id).into(),
+                None::<Vec<_>>
+            )
+            .await
+            .unwrap()
+            .ids()
+            .len(),
+        1,
+        "Only the message from the unknown sender should be filed into Unknown."
+    );
+    let mailbox_ids = client
+        .mailbox_query(mailbox::query::Filter::name("VIP").into(), None::<Vec<_>>)
+        .await
+        .unwrap()
+        .take_ids();
+    assert_eq!(mailbox_ids.len(), 1, "VIP mailbox not found");
+    assert_eq!(
+        client
+            .email_query(
+                email::query::Filter::in_mailbox(&mailbox_ids[0]).into(),
+                None::<Vec<_>>
+            )
+            .await
+            .unwrap()
+            .ids()
+            .len(),
+        1,
+    );
+    assert!(
+        client
+            .mailbox_query(
+                mailbox::query::Filter::name("Blocked").into(),
+                None::<Vec<_>>
+            )
+            .await
+            .unwrap()
+            .ids()
+            .is_empty(),
+        "Lookup stores not exposed to user scripts must not be queried."
+    );
+    admin
+        .registry_destroy(ObjectType::MemoryLookupKey, lookup_ids)
+        .await;
+    admin
+        .registry_update_setting(
+            SieveUserInterpreter::default(),
+            &[Property::AllowedExtLists],
+        )
+        .await;
+    admin.reload_settings().await;
+    admin.reload_lookup_stores().await;
+
     // Remove test data
+    account.destroy_all_addressbooks().await;
     client.sieve_script_deactivate().await.unwrap();
     let mut request = client.build();
     request.query_sieve_script();
@@ -0,0 +1,148 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::utils::{jmap::JmapUtils, server::TestServer};
+use jmap_proto::{request::method::MethodObject, types::date::UTCDate};
+use serde_json::{Value, json};
+use store::write::now;
+
+pub async fn test(test: &TestServer) {
+    println!("Running Email snooze tests...");
+    let account = test.account("jdoe@example.com");
+    let client = account.jmap_client().await;
+
+    // Create the Snoozed folder
+    let snoozed_id = account
+        .jmap_create(
+            MethodObject::Mailbox,
+            [json!({
+                "name": "Snoozed",
+                "role": "snoozed"
+            })],
+            Vec::<(&str, &str)>::new(),
+        )
+        .await
+        .created(0)
+        .id()
+        .to_string();
+    let inbox_id = account
+        .jmap_get(MethodObject::Mailbox, ["id", "role"], Vec::<&str>::new())
+        .await
+        .list()
+        .iter()
+        .find(|mailbox| mailbox["role"] == "inbox")
+        .unwrap()
+        .id()
+        .to_string();
+
+    // Insert a read message
+    let email_id = client
+        .email_import(
+            concat!(
+                "From: bill@example.com\r\n",
+                "To: jdoe@example.com\r\n",
+                "Subject: TPS Report\r\n",
+                "\r\n",
+                "Please have a look at the TPS reports next week."
+            )
+            .as_bytes()
+            .to_vec(),
+            [&inbox_id],
+            Some(["$seen"]),
+            None,
+        )
+        .await
+        .unwrap()
+        .take_id();
+
+    // Snooze the message and move it to the Snoozed folder
+    let until = UTCDate::from_timestamp(now() as i64 + 2).to_string();
+    account
+        .jmap_update(
+            MethodObject::Email,
+            [(
+                &email_id,
+                json!({
+                    "mailboxIds": { &snoozed_id: true },
+                    "snoozedUntil": &until
+                }),
+            )],
+            Vec::<(&str, &str)>::new(),
+        )
+        .await
+        .updated(&email_id);
+    let response = account
+        .jmap_get(
+            MethodObject::Email,
+            ["mailboxIds", "keywords", "snoozedUntil"],
+            [&email_id],
+        )
+        .await;
+    assert_eq!(
+        response.list()[0],
+        json!({
+            "id": email_id,
+            "mailboxIds": { &snoozed_id: true },
+            "keywords": { "$seen": true },
+            "snoozedUntil": until
+        })
+    );
+
+    // Clearing the snooze date does not move the message
+    account
+        .jmap_update(
+            MethodObject::Email,
+            [(&email_id, json!({ "snoozedUntil": null }))],
+            Vec::<(&str, &str)>::new(),
+        )
+        .await
+        .updated(&email_id);
+    let response = account
+        .jmap_get(
+            MethodObject::Email,
+            ["mailboxIds", "snoozedUntil"],
+            [&email_id],
+        )
+        .await;
+    assert_eq!(response.list()[0]["snoozedUntil"], Value::Null);
+    assert_eq!(
+        response.list()[0]["mailboxIds"],
+        json!({ &snoozed_id: true })
+    );
+
+    // Snooze it again and wait for the wake-up task
+    account
+        .jmap_update(
+            MethodObject::Email,
+            [(&email_id, json!({ "snoozedUntil": &until }))],
+            Vec::<(&str, &str)>::new(),
+        )
+        .await
+        .updated(&email_id);
+    test.wait_for_tasks().await;
+
+    // The message is back in the Inbox and unread
+    let response = account
+        .jmap_get(
+            MethodObject::Email,
+            ["mailboxIds", "keywords", "snoozedUntil"],
+            [&email_id],
+        )
+        .await;
+    assert_eq!(
+        response.list()[0],
+        json!({
+            "id": email_id,
+            "mailboxIds": { &inbox_id: true },
+            "keywords": {},
+            "snoozedUntil": null
+        })
+    );
+
+    // Clean up
+    test.destroy_all_mailboxes(account).await;
+    test.assert_is_empty().await;
+}
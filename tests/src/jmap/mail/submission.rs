@@ -16,6 +16,7 @@ use jmap_client::{
     mailbox::Role,
 };
 use mail_parser::DateTime;
+use serde_json::json;
 use std::{
     sync::Arc,
     time::{Duration, Instant},
@@ -479,6 +480,124 @@ pub async fn test(test: &TestServer) {
             .unwrap()
             .is_none()
     );
+    // Send a message disposition notification
+    let email_id = client
+        .email_import(
+            concat!(
+                "From: jane_smith@remote.org\r\n",
+                "To: jdoe@example.com\r\n",
+                "Message-ID: <mdn-test@remote.org>\r\n",
+                "Disposition-Notification-To: Jane Smith <jane_smith@remote.org>\r\n",
+                "Subject: Please confirm\r\n\r\n",
+                "Did you read this?"
+            )
+            .as_bytes()
+            .to_vec(),
+            [&mailbox_id],
+            None::<Vec<&str>>,
+            None,
+        )
+        .await
+        .unwrap()
+        .take_id();
+    let mdn_request = json!({
+        "identityId": &identity_id,
+        "send": {
+            "k1": {
+                "forEmailId": &email_id,
+                "textBody": "Your message was displayed.",
+                "disposition": {
+                    "actionMode": "manual-action",
+                    "sendingMode": "mdn-sent-manually",
+                    "type": "displayed"
+                }
+            }
+        },
+        "onSuccessUpdateEmail": {
+            "#k1": {
+                "keywords/$seen": true
+            }
+        }
+    });
+    let response = account
+        .jmap_method_call("MDN/send", mdn_request.clone())
+        .await;
+    assert_eq!(
+        response
+            .pointer("/methodResponses/0/1/sent/k1/originalMessageId")
+            .and_then(|v| v.as_str()),
+        Some("<mdn-test@remote.org>"),
+        "{:?}",
+        response.0
+    );
+    assert_eq!(
+        response
+            .pointer("/methodResponses/0/1/sent/k1/finalRecipient")
+            .and_then(|v| v.as_str()),
+        Some("rfc822; jdoe@example.com")
+    );
+    let message = expect_message_delivery(&mut smtp_rx).await;
+    assert_eq!(message.mail_from, "<>");
+    assert_eq!(message.rcpt_to, vec!["<jane_smith@remote.org>".to_string()]);
+    for needle in [
+        "Content-Type: message/disposition-notification",
+        "Final-Recipient: rfc822; jdoe@example.com",
+        "Original-Message-ID: <mdn-test@remote.org>",
+        "Disposition: manual-action/mdn-sent-manually; displayed",
+        "Your message was displayed.",
+    ] {
+        assert!(
+            message.message.contains(needle),
+            "{needle}: {}",
+            message.message
+        );
+    }
+    assert_email_properties(&client, &email_id, &[&mailbox_id], &["$mdnsent", "$seen"]).await;
+
+    // Only one MDN can be sent per message
+    let response = account.jmap_method_call("MDN/send", mdn_request).await;
+    assert_eq!(
+        response
+            .pointer("/methodResponses/0/1/notSent/k1/type")
+            .and_then(|v| v.as_str()),
+        Some("mdnAlreadySent")
+    );
+    expect_nothing(&mut smtp_rx).await;
+
+    // Parse the received MDN
+    let blob_id = client
+        .email_import(
+            message.message.into_bytes(),
+            [&mailbox_id],
+            None::<Vec<&str>>,
+            None,
+        )
+        .await
+        .unwrap()
+        .blob_id()
+        .unwrap()
+        .to_string();
+    let response = account
+        .jmap_method_call("MDN/parse", json!({"blobIds": [&blob_id]}))
+        .await;
+    let parsed = response
+        .pointer(&format!("/methodResponses/0/1/parsed/{blob_id}"))
+        .unwrap_or_else(|| panic!("Missing parsed MDN: {:?}", response.0));
+    assert_eq!(parsed["forEmailId"].as_str(), Some(email_id.as_str()));
+    assert_eq!(
+        parsed["originalMessageId"].as_str(),
+        Some("<mdn-test@remote.org>")
+    );
+    assert_eq!(
+        parsed["disposition"],
+        json!({
+            "actionMode": "manual-action",
+            "sendingMode": "mdn-sent-manually",
+            "type": "displayed"
+        })
+    );
+    assert_eq!(parsed["includeOriginalMessage"].as_bool(), Some(false));
+
     smtp_settings.lock().do_stop = true;
 
     // Destroy the created mailbox, identity and all submissions
@@ -190,6 +190,7 @@ fn jmap_tests() {
             mail::sieve_script::test(&test).await;
             mail::vacation_response::test(&test).await;
             mail::submission::test(&test).await;
+            mail::snooze::test(&test).await;
 
             core::event_source::test(&test).await;
             core::websocket::test(&test).await;
@@ -207,6 +208,8 @@ fn jmap_tests() {
             calendar::event::test(&test).await;
             calendar::notification::test(&test).await;
             calendar::alarm::test(&test).await;
+            calendar::task::test(&test).await;
+            calendar::publish::test(&test).await;
 
             calendar::identity::test(&test).await;
             calendar::acl::test(&test).await;
@@ -42,12 +42,14 @@ pub async fn test(test: &TestServer) {
         "urn:ietf:params:jmap:mail": {},
         "urn:ietf:params:jmap:calendars": {},
         "urn:ietf:params:jmap:calendars:parse": {},
+        "urn:ietf:params:jmap:tasks": {},
         "urn:ietf:params:jmap:contacts": {},
         "urn:ietf:params:jmap:contacts:parse": {},
         "urn:ietf:params:jmap:filenode": {},
         "urn:ietf:params:jmap:principals": {},
         "urn:ietf:params:jmap:principals:availability": {},
         "urn:ietf:params:jmap:submission": {},
+        "urn:ietf:params:jmap:mdn": {},
         "urn:ietf:params:jmap:vacationresponse": {},
         "urn:ietf:params:jmap:sieve": {
           "implementation": "Stalwart v1.0.0"
@@ -199,7 +201,13 @@ pub async fn test(test: &TestServer) {
               "mayCreateTopLevelFileNode": true
             },
             "urn:ietf:params:jmap:mail:share": {},
-            "urn:stalwart:jmap": {}
+            "urn:stalwart:jmap": {},
+            "urn:ietf:params:jmap:mdn": {},
+            "urn:ietf:params:jmap:tasks": {
+              "minDateTime": "0001-01-01T00:00:00Z",
+              "maxDateTime": "65534-12-31T23:59:59Z",
+              "mayCreateTaskList": true
+            }
           }
         }
       },
@@ -219,7 +227,9 @@ pub async fn test(test: &TestServer) {
         "urn:ietf:params:jmap:principals:availability": john_id,
         "urn:ietf:params:jmap:filenode": john_id,
         "urn:ietf:params:jmap:mail:share": john_id,
-        "urn:stalwart:jmap": john_id
+        "urn:stalwart:jmap": john_id,
+        "urn:ietf:params:jmap:mdn": john_id,
+        "urn:ietf:params:jmap:tasks": john_id
       },
       "username": "jdoe@example.com",
       "apiUrl": "https://127.0.0.1:8899/jmap/",
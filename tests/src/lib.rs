@@ -20,6 +20,8 @@ pub mod cluster;
 #[cfg(test)]
 pub mod directory;
 #[cfg(test)]
+pub mod http;
+#[cfg(test)]
 pub mod imap;
 #[cfg(test)]
 pub mod jmap;
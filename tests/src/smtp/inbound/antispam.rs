@@ -13,7 +13,10 @@ use ahash::AHashSet;
 use common::{
     Server,
     auth::{AccountCache, AccountInfo},
-    config::mailstore::spamfilter::SpamFilterAction,
+    config::{
+        mailstore::spamfilter::SpamFilterAction,
+        smtp::resolver::{BimiLogo, BimiRecord, BimiResult},
+    },
     enterprise::llm::{
         ChatCompletionChoice, ChatCompletionRequest, ChatCompletionResponse, Message,
     },
@@ -355,6 +358,7 @@ async fn antispam() {
             let mut dkim_signatures = vec![];
             let mut dmarc_result = None;
             let mut dmarc_policy = None;
+            let mut bimi_result = None;
             let mut expected_tags: AHashSet<String> = AHashSet::new();
             let mut expect_headers = String::new();
             let mut body_params = 0;
@@ -450,6 +454,28 @@ async fn antispam() {
                         "dmarc.policy" => {
                             dmarc_policy = Policy::from_str(value).into();
                         }
+                        "bimi.result" => {
+                            bimi_result = match value {
+                                "pass" | "vmc" => BimiResult::Pass {
+                                    domain: "bimi.org".to_string(),
+                                    selector: "default".to_string(),
+                                    record: Arc::new(BimiRecord {
+                                        location: None,
+                                        authority: None,
+                                    }),
+                                    logo: Arc::new(BimiLogo {
+                                        svg: Box::default(),
+                                        has_vmc: value == "vmc",
+                                    }),
+                                },
+                                "fail" => BimiResult::Fail("Invalid indicator".to_string()),
+                                "temperror" => BimiResult::TempError("Timeout".to_string()),
+                                "declined" => BimiResult::Declined,
+                                "none" => BimiResult::None,
+                                _ => panic!("Invalid BIMI result {value:?}"),
+                            }
+                            .into();
+                        }
                         "expect" => {
                             expected_tags
                                 .extend(value.split_ascii_whitespace().map(|v| v.to_uppercase()));
@@ -522,6 +548,7 @@ async fn antispam() {
                         arc_result.as_ref(),
                         dmarc_result.as_ref(),
                         dmarc_policy.as_ref(),
+                        bimi_result.as_ref(),
                     )
                     .await
                 {
@@ -551,6 +578,7 @@ async fn antispam() {
                 arc_result.as_ref(),
                 dmarc_result.as_ref(),
                 dmarc_policy.as_ref(),
+                bimi_result.as_ref(),
             );
             spam_input.is_tls = is_tls;
             let server = &test.server;
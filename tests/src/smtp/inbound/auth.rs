@@ -139,6 +139,7 @@ async fn auth() {
 
     // Users should be able to send emails only from their own email addresses
     session.mail_from("bill@foobar.org", "501 5.5.4").await;
+    session.mail_from("<>", "501 5.5.4").await;
     session.mail_from("john@example.org", "250").await;
     session.data.mail_from.take();
 
@@ -7,11 +7,11 @@
 use crate::{
     smtp::{
         inbound::{TestMessage, TestReportingEvent},
-        session::{TestSession, VerifyResponse},
+        session::{TestSession, VerifyResponse, load_test_message},
     },
     utils::{dns::DnsCache, server::TestServerBuilder},
 };
-use common::config::smtp::report::AggregateFrequency;
+use common::config::smtp::{report::AggregateFrequency, resolver::BimiRecord};
 use mail_auth::{
     common::{parse::TxtRecordParser, verify::DomainKey},
     dkim::DomainKeyReport,
@@ -26,7 +26,11 @@ use registry::{
     },
     types::list::List,
 };
-use std::time::{Duration, Instant};
+use smtp::inbound::bimi::{BIMI_TEST_FILES, parse_record};
+use std::{
+    path::PathBuf,
+    time::{Duration, Instant},
+};
 
 #[tokio::test]
 async fn dmarc() {
@@ -95,6 +99,9 @@ async fn dmarc() {
                 else_: "strict".into(),
             },
             dkim_strict: false,
+            bimi_verify: true,
+            bimi_trust_anchors: Some(load_bimi_file("vmc_ca.pem")),
+            ..Default::default()
         })
         .await;
     admin
@@ -329,6 +336,44 @@ async fn dmarc() {
         .await;
     test.assert_no_events();
 
+    // BIMI records with locations that could inject header content are ignored
+    assert_eq!(
+        parse_record("v=BIMI1; l=https://example.com/bimi/logo.svg; a="),
+        Some(BimiRecord {
+            location: Some("https://example.com/bimi/logo.svg".into()),
+            authority: None,
+        })
+    );
+    for record in [
+        "v=BIMI1; l=https://example.com/logo.svg\r\nX-Injected: yes",
+        "v=BIMI1; l=https://example.com/logo.svg a=https://example.com/vmc.pem",
+        "v=BIMI1; l=https://example.com/logo.svg; a=https://example.com/\tvmc.pem",
+    ] {
+        assert_eq!(parse_record(record), None, "{record:?}");
+    }
+
+    // Add BIMI record and indicator
+    test.server.bimi_add(
+        "default._bimi.example.com",
+        BimiRecord {
+            location: Some("https://example.com/bimi/logo.svg".into()),
+            authority: None,
+        },
+        Instant::now() + Duration::from_secs(5),
+    );
+    BIMI_TEST_FILES.lock().extend([
+        (
+            "https://example.com/bimi/logo.svg".to_string(),
+            BIMI_LOGO.as_bytes().to_vec(),
+        ),
+        (
+            "https://example.com/bimi/invalid.svg".to_string(),
+            BIMI_LOGO
+                .replace("<title>", "<script>alert(1)</script><title>")
+                .into_bytes(),
+        ),
+    ]);
+
     // Messages passing DMARC should be accepted
     session
         .send_message(
@@ -345,5 +390,112 @@ async fn dmarc() {
         .assert_contains("dkim=pass")
         .assert_contains("spf=pass")
         .assert_contains("dmarc=pass")
-        .assert_contains("Received-SPF: pass");
+        .assert_contains("Received-SPF: pass")
+        .assert_contains("BIMI-Location: v=BIMI1;")
+        .assert_contains("l=https://example.com/bimi/logo.svg")
+        .assert_contains("BIMI-Indicator: PD94bWwgdmVyc2lvbj0i");
+
+    // Indicators backed by a trusted mark certificate should be accepted
+    BIMI_TEST_FILES.lock().extend([
+        (
+            "https://example.com/bimi/vmc.pem".to_string(),
+            load_bimi_file("vmc.pem").into_bytes(),
+        ),
+        (
+            "https://example.com/bimi/other.svg".to_string(),
+            BIMI_LOGO.replace("#336699", "#993366").into_bytes(),
+        ),
+    ]);
+    test.server.bimi_add(
+        "default._bimi.example.com",
+        BimiRecord {
+            location: Some("https://example.com/bimi/logo.svg".into()),
+            authority: Some("https://example.com/bimi/vmc.pem".into()),
+        },
+        Instant::now() + Duration::from_secs(5),
+    );
+    session
+        .send_message(
+            "bill@example.com",
+            &["jdoe@localdomain.org"],
+            "test:dkim",
+            "250",
+        )
+        .await;
+    test.expect_message()
+        .await
+        .read_lines(&test)
+        .await
+        .assert_contains("dmarc=pass")
+        .assert_contains("a=https://example.com/bimi/vmc.pem")
+        .assert_contains("BIMI-Indicator: PD94bWwgdmVyc2lvbj0i");
+
+    // Indicators not matching the mark certificate logotype should be ignored
+    test.server.bimi_add(
+        "default._bimi.example.com",
+        BimiRecord {
+            location: Some("https://example.com/bimi/other.svg".into()),
+            authority: Some("https://example.com/bimi/vmc.pem".into()),
+        },
+        Instant::now() + Duration::from_secs(5),
+    );
+    session
+        .send_message(
+            "bill@example.com",
+            &["jdoe@localdomain.org"],
+            "test:dkim",
+            "250",
+        )
+        .await;
+    test.expect_message()
+        .await
+        .read_lines(&test)
+        .await
+        .assert_contains("dmarc=pass")
+        .assert_not_contains("BIMI-Location")
+        .assert_not_contains("BIMI-Indicator");
+
+    // Indicators added by the sender should be removed and invalid indicators ignored
+    test.server.bimi_add(
+        "default._bimi.example.com",
+        BimiRecord {
+            location: Some("https://example.com/bimi/invalid.svg".into()),
+            authority: None,
+        },
+        Instant::now() + Duration::from_secs(5),
+    );
+    session
+        .send_message(
+            "bill@example.com",
+            &["jdoe@localdomain.org"],
+            &format!(
+                "BIMI-Indicator: Zm9yZ2Vk\r\n{}",
+                load_test_message("dkim", "messages")
+            ),
+            "250",
+        )
+        .await;
+    test.expect_message()
+        .await
+        .read_lines(&test)
+        .await
+        .assert_contains("dmarc=pass")
+        .assert_not_contains("BIMI-Location")
+        .assert_not_contains("BIMI-Indicator");
 }
+
+fn load_bimi_file(name: &str) -> String {
+    let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    path.push("resources");
+    path.push("smtp");
+    path.push("bimi");
+    path.push(name);
+    std::fs::read_to_string(path).unwrap()
+}
+
+const BIMI_LOGO: &str = r##"<?xml version="1.0" encoding="UTF-8"?>
+<svg xmlns="http://www.w3.org/2000/svg" version="1.2" baseProfile="tiny-ps" viewBox="0 0 100 100">
+<title>Example Inc.</title>
+<circle cx="50" cy="50" r="40" fill="#336699"/>
+</svg>
+"##;
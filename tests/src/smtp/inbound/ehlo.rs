@@ -123,7 +123,8 @@ async fn ehlo() {
         .assert_contains("SIZE 1024")
         .assert_contains("MT-PRIORITY NSEP")
         .assert_contains("FUTURERELEASE 3600")
-        .assert_contains("STARTTLS");
+        .assert_contains("STARTTLS")
+        .assert_contains("250 LIMITS RCPTMAX=100 MAILMAX=10");
 
     // SPF should be a Pass for 10.0.0.1
     assert_eq!(
@@ -26,9 +26,9 @@ use registry::{
     schema::{
         enums::{self, MtaStage},
         prelude::{ObjectType, Property},
-        structs::{Expression, MtaHook, MtaMilter, MtaStageRcpt},
+        structs::{Expression, MtaHook, MtaMilter, MtaStageRcpt, QuarantinedMessage},
     },
-    types::map::Map,
+    types::{EnumImpl, map::Map},
 };
 use serde::Deserialize;
 use smtp::{
@@ -40,13 +40,20 @@ use smtp::{
             receiver::{FrameResult, Receiver},
         },
     },
+    queue::quarantine::SmtpQuarantine,
 };
 use std::{fs, net::SocketAddr, path::PathBuf, sync::Arc, time::Duration};
+use store::{
+    ValueKey,
+    registry::RegistryQuery,
+    write::{RegistryClass, ValueClass},
+};
 use tokio::{
     io::{AsyncReadExt, AsyncWriteExt},
     net::{TcpListener, TcpStream},
     sync::watch,
 };
+use types::id::Id;
 
 #[derive(Debug, Deserialize)]
 struct HeaderTest {
@@ -353,6 +360,101 @@ async fn mta_hook_session() {
         .await
         .assert_contains("X-Spam: Yes")
         .assert_contains("123456");
+
+    // Test quarantine
+    session
+        .send_message(
+            "quarantine@doe.org",
+            &["bill@foobar.org"],
+            "test:no_dkim",
+            "250 2.0.0",
+        )
+        .await;
+    test.assert_no_events();
+    let ids = test
+        .server
+        .registry()
+        .query::<Vec<Id>>(
+            RegistryQuery::new(ObjectType::QuarantinedMessage)
+                .greater_than_or_equal(Property::AccountId, 0u64),
+        )
+        .await
+        .unwrap();
+    assert_eq!(ids.len(), 1);
+    let item = test
+        .server
+        .store()
+        .get_value::<QuarantinedMessage>(ValueKey::from(ValueClass::Registry(
+            RegistryClass::Item {
+                object_id: ObjectType::QuarantinedMessage.to_id(),
+                item_id: ids[0].id(),
+            },
+        )))
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(item.reason, "Suspicious content");
+    assert_eq!(item.from, "quarantine@doe.org");
+    assert_eq!(item.recipients.as_slice(), &["bill@foobar.org".to_string()]);
+
+    // Release the quarantined message
+    assert!(
+        test.server
+            .quarantine_release(ids[0].id(), None, 0)
+            .await
+            .unwrap()
+    );
+    assert!(
+        !test
+            .server
+            .quarantine_release(ids[0].id(), None, 0)
+            .await
+            .unwrap()
+    );
+    test.expect_message()
+        .await
+        .read_lines(&test)
+        .await
+        .assert_contains("Subject: Is dinner ready?");
+
+    // Test quarantine deletion
+    session
+        .send_message(
+            "quarantine@doe.org",
+            &["bill@foobar.org"],
+            "test:no_dkim",
+            "250 2.0.0",
+        )
+        .await;
+    test.assert_no_events();
+    let ids = test
+        .server
+        .registry()
+        .query::<Vec<Id>>(
+            RegistryQuery::new(ObjectType::QuarantinedMessage)
+                .greater_than_or_equal(Property::AccountId, 0u64),
+        )
+        .await
+        .unwrap();
+    assert_eq!(ids.len(), 1);
+    assert!(
+        test.server
+            .quarantine_delete(ids[0].id(), None)
+            .await
+            .unwrap()
+    );
+    assert!(
+        test.server
+            .registry()
+            .query::<Vec<Id>>(
+                RegistryQuery::new(ObjectType::QuarantinedMessage)
+                    .greater_than_or_equal(Property::AccountId, 0u64),
+            )
+            .await
+            .unwrap()
+            .is_empty()
+    );
+    test.assert_no_events();
 }
 
 #[test]
@@ -852,16 +954,19 @@ fn handle_mta_hook(request: Request, tests: Arc<Vec<HeaderTest>>) -> hooks::Resp
             action: hooks::Action::Accept,
             response: None,
             modifications: vec![],
+            ..Default::default()
         },
         "reject" => hooks::Response {
             action: hooks::Action::Reject,
             response: None,
             modifications: vec![],
+            ..Default::default()
         },
         "discard" => hooks::Response {
             action: hooks::Action::Discard,
             response: None,
             modifications: vec![],
+            ..Default::default()
         },
         "temp_fail" => hooks::Response {
             action: hooks::Action::Reject,
@@ -873,6 +978,7 @@ fn handle_mta_hook(request: Request, tests: Arc<Vec<HeaderTest>>) -> hooks::Resp
             }
             .into(),
             modifications: vec![],
+            ..Default::default()
         },
         "shutdown" => hooks::Response {
             action: hooks::Action::Reject,
@@ -884,6 +990,7 @@ fn handle_mta_hook(request: Request, tests: Arc<Vec<HeaderTest>>) -> hooks::Resp
             }
             .into(),
             modifications: vec![],
+            ..Default::default()
         },
         "conn_fail" => hooks::Response {
             action: hooks::Action::Accept,
@@ -893,6 +1000,17 @@ fn handle_mta_hook(request: Request, tests: Arc<Vec<HeaderTest>>) -> hooks::Resp
             }
             .into(),
             modifications: vec![],
+            ..Default::default()
+        },
+        "quarantine" => hooks::Response {
+            action: hooks::Action::Quarantine,
+            response: SmtpResponse {
+                message: Some("Suspicious content".into()),
+                ..Default::default()
+            }
+            .into(),
+            modifications: vec![],
+            ..Default::default()
         },
         "reply_code" => hooks::Response {
             action: hooks::Action::Reject,
@@ -904,6 +1022,7 @@ fn handle_mta_hook(request: Request, tests: Arc<Vec<HeaderTest>>) -> hooks::Resp
             }
             .into(),
             modifications: vec![],
+            ..Default::default()
         },
         test_num => hooks::Response {
             action: hooks::Action::Accept,
@@ -966,6 +1085,7 @@ fn handle_mta_hook(request: Request, tests: Arc<Vec<HeaderTest>>) -> hooks::Resp
                     },
                 })
                 .collect(),
+            ..Default::default()
         },
     }
 }
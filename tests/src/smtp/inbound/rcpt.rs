@@ -13,13 +13,18 @@ use registry::{
         enums::MtaInboundThrottleKey,
         structs::{
             Expression, ExpressionMatch, MtaExtensions, MtaInboundThrottle, MtaStageRcpt, Rate,
+            SecretKeyOptional, SecretKeyValue, SenderAuth,
         },
     },
     types::{list::List, map::Map},
 };
-use smtp::core::State;
+use smtp::core::{
+    State,
+    srs::{SrsAddress, SrsCodec},
+};
 use smtp_proto::{RCPT_NOTIFY_DELAY, RCPT_NOTIFY_FAILURE, RCPT_NOTIFY_SUCCESS};
 use std::time::Duration;
+use store::write::now;
 
 #[tokio::test]
 async fn rcpt() {
@@ -116,6 +121,14 @@ async fn rcpt() {
             },
         })
         .await;
+    admin
+        .registry_create_object(SenderAuth {
+            srs_signing_key: SecretKeyOptional::Value(SecretKeyValue {
+                secret: "srs-test-secret".into(),
+            }),
+            ..Default::default()
+        })
+        .await;
     admin.reload_settings().await;
     test.reload_core();
 
@@ -189,4 +202,77 @@ async fn rcpt() {
     let rcpt = session.data.rcpt_to.last().unwrap();
     assert!((rcpt.flags & (RCPT_NOTIFY_DELAY | RCPT_NOTIFY_SUCCESS | RCPT_NOTIFY_FAILURE)) != 0);
     assert_eq!(rcpt.dsn_info.as_ref().unwrap(), "Jane.Doe@Foobar.org");
+
+    // SRS addresses are encoded and decoded
+    let srs = &test.server.core.smtp.mail_auth.srs;
+    let srs_address = srs
+        .srs_encode("sender@example.net", "foobar.org", now())
+        .unwrap();
+    assert!(srs_address.starts_with("SRS0="), "{srs_address}");
+    assert!(srs_address.ends_with("=example.net=sender@foobar.org"));
+    assert_eq!(
+        srs.srs_decode(&srs_address, now()),
+        SrsAddress::Valid("sender@example.net".into())
+    );
+    assert_eq!(
+        srs.srs_decode(&srs_address.to_lowercase(), now()),
+        SrsAddress::Valid("sender@example.net".into())
+    );
+    assert_eq!(srs.srs_decode("jane@foobar.org", now()), SrsAddress::NotSrs);
+
+    // Forwarding an SRS0 address produces a SRS1 address pointing to the first forwarder
+    let srs1_address = srs
+        .srs_encode(
+            "SRS0=AbCd=2Q=example.org=bob@forwarder.net",
+            "foobar.org",
+            now(),
+        )
+        .unwrap();
+    assert!(srs1_address.starts_with("SRS1="), "{srs1_address}");
+    assert!(srs1_address.ends_with("=forwarder.net==AbCd=2Q=example.org=bob@foobar.org"));
+    assert_eq!(
+        srs.srs_decode(&srs1_address, now()),
+        SrsAddress::Valid("SRS0=AbCd=2Q=example.org=bob@forwarder.net".into())
+    );
+    let srs1_address_rewrap = srs
+        .srs_encode(
+            &srs1_address.replace("@foobar.org", "@other.org"),
+            "foobar.org",
+            now(),
+        )
+        .unwrap();
+    assert_eq!(
+        srs.srs_decode(&srs1_address_rewrap, now()),
+        SrsAddress::Valid("SRS0=AbCd=2Q=example.org=bob@forwarder.net".into())
+    );
+
+    // Expired SRS addresses are rejected
+    let expired_address = srs
+        .srs_encode("sender@example.net", "foobar.org", now() - 40 * 86400)
+        .unwrap();
+    assert_eq!(srs.srs_decode(&expired_address, now()), SrsAddress::Expired);
+
+    // Bounces to valid SRS addresses are relayed back to the original sender
+    session.data.remote_ip_str = "10.0.0.1".into();
+    session.eval_session_params().await;
+    session.data.rcpt_errors = 0;
+    session.rset().await;
+    session.mail_from("bounces@example.org", "250").await;
+    session.rcpt_to(&srs_address, "250").await;
+    let rcpt = session.data.rcpt_to.last().unwrap();
+    assert_eq!(rcpt.address_lcase, "sender@example.net");
+
+    // Forged and expired SRS addresses are rejected
+    let (hash, rest) = srs_address["SRS0=".len()..].split_once('=').unwrap();
+    let forged_address = format!(
+        "SRS0={}={rest}",
+        if hash.starts_with('A') {
+            "BBBB"
+        } else {
+            "AAAA"
+        }
+    );
+    session.rcpt_to(&forged_address, "550 5.1.1").await;
+    session.rcpt_to(&expired_address, "550 5.1.1").await;
+    assert_eq!(session.data.rcpt_to.len(), 1);
 }
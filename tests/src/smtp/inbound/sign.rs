@@ -82,6 +82,7 @@ async fn sign_and_seal() {
                 ..Default::default()
             },
             dkim_strict: false,
+            ..Default::default()
         })
         .await;
     admin.reload_settings().await;
@@ -85,34 +85,42 @@ async fn strategies() {
                 MtaConnectionIpHost {
                     ehlo_hostname: "test1.example.com".to_string().into(),
                     source_ip: IpAddr::from_str("10.0.0.1").unwrap(),
+                    ..Default::default()
                 },
                 MtaConnectionIpHost {
                     ehlo_hostname: "test2.example.com".to_string().into(),
                     source_ip: IpAddr::from_str("10.0.0.2").unwrap(),
+                    ..Default::default()
                 },
                 MtaConnectionIpHost {
                     ehlo_hostname: "test3.example.com".to_string().into(),
                     source_ip: IpAddr::from_str("10.0.0.3").unwrap(),
+                    ..Default::default()
                 },
                 MtaConnectionIpHost {
                     ehlo_hostname: "test4.example.com".to_string().into(),
                     source_ip: IpAddr::from_str("10.0.0.4").unwrap(),
+                    ..Default::default()
                 },
                 MtaConnectionIpHost {
                     ehlo_hostname: "test5.example.com".to_string().into(),
                     source_ip: IpAddr::from_str("a:b::1").unwrap(),
+                    ..Default::default()
                 },
                 MtaConnectionIpHost {
                     ehlo_hostname: "test6.example.com".to_string().into(),
                     source_ip: IpAddr::from_str("a:b::2").unwrap(),
+                    ..Default::default()
                 },
                 MtaConnectionIpHost {
                     ehlo_hostname: "test7.example.com".to_string().into(),
                     source_ip: IpAddr::from_str("a:b::3").unwrap(),
+                    ..Default::default()
                 },
                 MtaConnectionIpHost {
                     ehlo_hostname: "test8.example.com".to_string().into(),
                     source_ip: IpAddr::from_str("a:b::4").unwrap(),
+                    ..Default::default()
                 },
             ]),
             ..Default::default()
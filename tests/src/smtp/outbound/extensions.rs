@@ -12,7 +12,7 @@ use crate::{
     utils::{dns::DnsCache, server::TestServerBuilder},
 };
 use mail_auth::MX;
-use registry::schema::structs::{Expression, MtaStageData};
+use registry::schema::structs::{Expression, MtaStageData, MtaStageRcpt};
 use smtp_proto::{MAIL_REQUIRETLS, MAIL_RET_HDRS, MAIL_SMTPUTF8, RCPT_NOTIFY_NEVER};
 use std::time::{Duration, Instant};
 
@@ -180,3 +180,124 @@ async fn extensions() {
     assert!((message.message.flags & MAIL_SMTPUTF8) != 0);
     assert!((message.message.recipients.last().unwrap().flags & RCPT_NOTIFY_NEVER) != 0);
 }
+
+#[tokio::test]
+#[serial_test::serial]
+async fn limits() {
+    let mut local = TestServerBuilder::new("smtp_limits_local")
+        .await
+        .with_http_listener(19055)
+        .await
+        .disable_services()
+        .capture_queue()
+        .build()
+        .await;
+    let mut remote = TestServerBuilder::new("smtp_limits_remote")
+        .await
+        .with_http_listener(19056)
+        .await
+        .with_smtp_listener(9925)
+        .await
+        .disable_services()
+        .capture_queue()
+        .build()
+        .await;
+
+    let local_admin = local.account("admin");
+    local_admin.mta_allow_relaying().await;
+    local_admin.mta_no_auth().await;
+    local_admin.mta_all_extensions().await;
+    local_admin.reload_settings().await;
+    local.reload_core();
+    local.expect_reload_settings().await;
+
+    // Remote advertises LIMITS RCPTMAX=2 MAILMAX=1
+    let remote_admin = remote.account("admin");
+    remote_admin.mta_all_extensions().await;
+    remote_admin.mta_no_auth().await;
+    remote_admin
+        .registry_create_object(MtaStageRcpt {
+            allow_relaying: Expression {
+                else_: "true".into(),
+                ..Default::default()
+            },
+            max_recipients: Expression {
+                else_: "2".into(),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .await;
+    remote_admin
+        .registry_create_object(MtaStageData {
+            max_messages: Expression {
+                else_: "1".into(),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .await;
+    remote_admin.reload_settings().await;
+    remote.reload_core();
+    remote.expect_reload_settings().await;
+
+    // Add mock DNS entries
+    local.server.mx_add(
+        "foobar.org",
+        vec![MX {
+            exchanges: vec!["mx.foobar.org".into()].into_boxed_slice(),
+            preference: 10,
+        }],
+        Instant::now() + Duration::from_secs(10),
+    );
+    local.server.ipv4_add(
+        "mx.foobar.org",
+        vec!["127.0.0.1".parse().unwrap()],
+        Instant::now() + Duration::from_secs(10),
+    );
+
+    // The first two recipients are sent in a single transaction
+    let mut session = local.new_mta_session();
+    session.data.remote_ip_str = "10.0.0.1".into();
+    session.eval_session_params().await;
+    session.ehlo("mx.test.org").await;
+    session
+        .send_message(
+            "john@test.org",
+            &["bill@foobar.org", "jane@foobar.org", "mike@foobar.org"],
+            "test:no_dkim",
+            "250",
+        )
+        .await;
+    local
+        .expect_message_then_deliver()
+        .await
+        .try_deliver(local.server.clone());
+    let message = remote.expect_message().await;
+    assert_eq!(
+        message
+            .message
+            .recipients
+            .iter()
+            .map(|rcpt| rcpt.address())
+            .collect::<Vec<_>>(),
+        vec!["bill@foobar.org", "jane@foobar.org"]
+    );
+
+    // MAILMAX was reached, the last recipient is retried over a new session
+    let retry = local.expect_message().await;
+    local
+        .delivery_attempt(retry.queue_id)
+        .await
+        .try_deliver(local.server.clone());
+    let message = remote.expect_message().await;
+    assert_eq!(
+        message
+            .message
+            .recipients
+            .iter()
+            .map(|rcpt| rcpt.address())
+            .collect::<Vec<_>>(),
+        vec!["mike@foobar.org"]
+    );
+}
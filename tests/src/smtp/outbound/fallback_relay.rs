@@ -8,12 +8,14 @@ use crate::{
     smtp::session::TestSession,
     utils::{dns::DnsCache, server::TestServerBuilder},
 };
+use common::RelayHostStats;
 use mail_auth::MX;
 use registry::{
     schema::{
         enums::MtaProtocol,
         structs::{
-            Expression, ExpressionMatch, MtaOutboundStrategy, MtaRoute, MtaRouteRelay, MtaStageRcpt,
+            Expression, ExpressionMatch, MtaOutboundStrategy, MtaRelayHost, MtaRoute,
+            MtaRouteRelay, MtaStageRcpt,
         },
     },
     types::list::List,
@@ -134,3 +136,134 @@ async fn fallback_relay() {
     tokio::time::sleep(Duration::from_millis(100)).await;
     remote.expect_message().await;
 }
+
+#[tokio::test]
+#[serial_test::serial]
+async fn relay_failover() {
+    let mut local = TestServerBuilder::new("smtp_failover_local")
+        .await
+        .with_http_listener(19051)
+        .await
+        .disable_services()
+        .capture_queue()
+        .build()
+        .await;
+    let mut remote = TestServerBuilder::new("smtp_failover_remote")
+        .await
+        .with_http_listener(19052)
+        .await
+        .with_smtp_listener(9925)
+        .await
+        .disable_services()
+        .capture_queue()
+        .build()
+        .await;
+
+    // The primary relay host is down, the secondary is listening on port 9925
+    let local_admin = local.account("admin");
+    local_admin
+        .registry_create_object(MtaStageRcpt {
+            allow_relaying: Expression {
+                else_: "true".into(),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .await;
+    local_admin
+        .registry_create_object(MtaOutboundStrategy {
+            route: Expression {
+                else_: "'relay'".into(),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .await;
+    local_admin
+        .registry_create_object(MtaRoute::Relay(MtaRouteRelay {
+            address: "primary.foobar.org".into(),
+            port: 9926,
+            hosts: List::from_iter([MtaRelayHost {
+                address: "secondary.foobar.org".into(),
+                port: 9925,
+                priority: 1,
+                ..Default::default()
+            }]),
+            host_cooldown: 60_000u64.into(),
+            allow_invalid_certs: true,
+            name: "relay".into(),
+            protocol: MtaProtocol::Smtp,
+            ..Default::default()
+        }))
+        .await;
+    local_admin.mta_no_auth().await;
+    local_admin.mta_all_extensions().await;
+    local_admin.reload_settings().await;
+    local.reload_core();
+    local.expect_reload_settings().await;
+
+    let remote_admin = remote.account("admin");
+    remote_admin.mta_allow_relaying().await;
+    remote_admin.mta_no_auth().await;
+    remote_admin.mta_all_extensions().await;
+    remote_admin.mta_allow_non_fqdn().await;
+    remote_admin.reload_settings().await;
+    remote.reload_core();
+    remote.expect_reload_settings().await;
+
+    // Add mock DNS entries
+    for host in ["primary.foobar.org", "secondary.foobar.org"] {
+        local.server.ipv4_add(
+            host,
+            vec!["127.0.0.1".parse().unwrap()],
+            Instant::now() + Duration::from_secs(10),
+        );
+    }
+
+    // Delivery fails over to the secondary host
+    let mut session = local.new_mta_session();
+    session.data.remote_ip_str = "10.0.0.1".into();
+    session.eval_session_params().await;
+    session.ehlo("mx.test.org").await;
+    session
+        .send_message("john@test.org", &["bill@foobar.org"], "test:no_dkim", "250")
+        .await;
+    local
+        .expect_message_then_deliver()
+        .await
+        .try_deliver(local.server.clone());
+    remote.expect_message().await;
+
+    // The primary host is marked as unhealthy for the cooldown period
+    let unhealthy = &local.server.inner.cache.relay_unhealthy;
+    assert!(unhealthy.get("primary.foobar.org:9926").is_some());
+    assert!(unhealthy.get("secondary.foobar.org:9925").is_none());
+
+    // Unhealthy hosts are skipped on the next delivery
+    session
+        .send_message("john@test.org", &["jane@foobar.org"], "test:no_dkim", "250")
+        .await;
+    local
+        .expect_message_then_deliver()
+        .await
+        .try_deliver(local.server.clone());
+    remote.expect_message().await;
+    assert!(unhealthy.get("primary.foobar.org:9926").is_some());
+
+    // Deliveries and failures are counted per relay host
+    let relay_stats = local.server.inner.data.relay_stats.lock().clone();
+    assert_eq!(
+        relay_stats.get("primary.foobar.org:9926"),
+        Some(&RelayHostStats {
+            success: 0,
+            failed: 1
+        })
+    );
+    assert_eq!(
+        relay_stats.get("secondary.foobar.org:9925"),
+        Some(&RelayHostStats {
+            success: 2,
+            failed: 0
+        })
+    );
+}
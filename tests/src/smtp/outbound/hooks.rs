@@ -0,0 +1,276 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::{
+    smtp::{
+        inbound::TestMessage,
+        session::{TestSession, VerifyResponse},
+    },
+    utils::{
+        dns::DnsCache,
+        http_server::{HttpMessage, spawn_mock_http_server},
+        server::TestServerBuilder,
+    },
+};
+use http_proto::{JsonResponse, ToHttpResponse};
+use registry::{
+    schema::{
+        enums::{MtaProtocol, MtaStage},
+        structs::{
+            Expression, MtaHook, MtaOutboundStrategy, MtaRoute, MtaRouteRelay, MtaStageRcpt,
+        },
+    },
+    types::map::Map,
+};
+use smtp::{
+    inbound::hooks::{self, Action, Modification, RecipientResponse, Request, SmtpResponse, Stage},
+    queue::{Error, Status},
+};
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+use store::write::now;
+
+#[tokio::test]
+#[serial_test::serial]
+async fn delivery_hooks() {
+    let mut local = TestServerBuilder::new("smtp_delivery_hook_local")
+        .await
+        .with_http_listener(19058)
+        .await
+        .disable_services()
+        .capture_queue()
+        .build()
+        .await;
+    let mut remote = TestServerBuilder::new("smtp_delivery_hook_remote")
+        .await
+        .with_http_listener(19059)
+        .await
+        .with_smtp_listener(9925)
+        .await
+        .disable_services()
+        .capture_queue()
+        .build()
+        .await;
+
+    // Recipients are routed through MX by default, which is not resolvable,
+    // so only the recipients rerouted by the hook reach the remote server
+    let local_admin = local.account("admin");
+    local_admin
+        .registry_create_object(MtaStageRcpt {
+            allow_relaying: Expression {
+                else_: "true".into(),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .await;
+    local_admin
+        .registry_create_object(MtaOutboundStrategy {
+            route: Expression {
+                else_: "'mx'".into(),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .await;
+    local_admin
+        .registry_create_object(MtaRoute::Relay(MtaRouteRelay {
+            address: "relay.foobar.org".into(),
+            port: 9925,
+            allow_invalid_certs: true,
+            name: "relay".into(),
+            protocol: MtaProtocol::Smtp,
+            ..Default::default()
+        }))
+        .await;
+    local_admin
+        .registry_create_object(MtaHook {
+            enable: Expression {
+                else_: "true".into(),
+                ..Default::default()
+            },
+            url: "https://127.0.0.1:9334/hook".into(),
+            allow_invalid_certs: true,
+            stages: Map::new(vec![MtaStage::Delivery]),
+            ..Default::default()
+        })
+        .await;
+    local_admin.mta_no_auth().await;
+    local_admin.mta_all_extensions().await;
+    local_admin.reload_settings().await;
+    local.reload_core();
+    local.expect_reload_settings().await;
+
+    let remote_admin = remote.account("admin");
+    remote_admin.mta_allow_relaying().await;
+    remote_admin.mta_no_auth().await;
+    remote_admin.mta_all_extensions().await;
+    remote_admin.mta_allow_non_fqdn().await;
+    remote_admin.reload_settings().await;
+    remote.reload_core();
+    remote.expect_reload_settings().await;
+
+    local.server.ipv4_add(
+        "relay.foobar.org",
+        vec!["127.0.0.1".parse().unwrap()],
+        Instant::now() + Duration::from_secs(10),
+    );
+
+    // Spawn mock hook server
+    let hold_until = now() + 3600;
+    let _tx = spawn_mock_http_server(
+        &local,
+        Arc::new(move |req: HttpMessage| {
+            let request = serde_json::from_slice::<Request>(req.body.as_ref().unwrap()).unwrap();
+            assert!(matches!(request.context.stage, Stage::Delivery));
+            assert!(request.context.client.is_none());
+            assert!(request.context.queue.is_some());
+            let envelope = request.envelope.unwrap();
+            assert_eq!(envelope.from.address, "john@test.org");
+            assert_eq!(
+                envelope
+                    .to
+                    .iter()
+                    .map(|rcpt| rcpt.address.as_str())
+                    .collect::<Vec<_>>(),
+                [
+                    "bill@foobar.org",
+                    "jane@foobar.org",
+                    "mike@foobar.org",
+                    "ann@foobar.org"
+                ]
+            );
+            assert!(
+                request
+                    .message
+                    .unwrap()
+                    .headers
+                    .iter()
+                    .any(|(name, _)| name == "Subject")
+            );
+
+            JsonResponse::new(&hooks::Response {
+                action: Action::Accept,
+                modifications: vec![
+                    Modification::AddHeader {
+                        name: "X-DLP-Review".into(),
+                        value: "passed".into(),
+                    },
+                    Modification::DeleteRecipient {
+                        value: "ann@foobar.org".into(),
+                    },
+                ],
+                recipients: vec![
+                    RecipientResponse {
+                        address: "bill@foobar.org".into(),
+                        action: Action::Reroute,
+                        response: None,
+                        route: Some("relay".into()),
+                        hold_until: None,
+                    },
+                    RecipientResponse {
+                        address: "jane@foobar.org".into(),
+                        action: Action::Reject,
+                        response: SmtpResponse {
+                            status: 550.into(),
+                            enhanced_status: Some("5.7.1".into()),
+                            message: Some("Blocked by DLP policy".into()),
+                            disconnect: false,
+                        }
+                        .into(),
+                        route: None,
+                        hold_until: None,
+                    },
+                    RecipientResponse {
+                        address: "mike@foobar.org".into(),
+                        action: Action::Hold,
+                        response: None,
+                        route: None,
+                        hold_until: Some(hold_until),
+                    },
+                ],
+                ..Default::default()
+            })
+            .into_http_response()
+        }),
+        9334,
+    )
+    .await;
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    let mut session = local.new_mta_session();
+    session.data.remote_ip_str = "10.0.0.1".into();
+    session.eval_session_params().await;
+    session.ehlo("mx.test.org").await;
+    session
+        .send_message(
+            "john@test.org",
+            &[
+                "bill@foobar.org",
+                "jane@foobar.org",
+                "mike@foobar.org",
+                "ann@foobar.org",
+            ],
+            "test:no_dkim",
+            "250",
+        )
+        .await;
+    local
+        .expect_message_then_deliver()
+        .await
+        .try_deliver(local.server.clone());
+
+    // Only the rerouted recipient is delivered, with the rewritten contents
+    let message = remote.expect_message().await;
+    assert_eq!(message.message.recipients.len(), 1);
+    assert_eq!(message.message.recipients[0].address(), "bill@foobar.org");
+    message
+        .read_lines(&remote)
+        .await
+        .assert_contains("X-DLP-Review: passed");
+    tokio::time::sleep(Duration::from_millis(200)).await;
+
+    // Rejected recipients bounce, held ones are rescheduled and deleted ones are dropped
+    let messages = local.read_queued_messages().await;
+    let message = messages
+        .iter()
+        .find(|message| !message.message.return_path.is_empty())
+        .expect("Original message not found");
+    for rcpt in &message.message.recipients {
+        match rcpt.address() {
+            "bill@foobar.org" | "ann@foobar.org" => {
+                assert!(matches!(rcpt.status, Status::Completed(_)), "{rcpt:?}");
+            }
+            "jane@foobar.org" => match &rcpt.status {
+                Status::PermanentFailure(err) => match &err.details {
+                    Error::UnexpectedResponse(response) => {
+                        assert_eq!(response.response.code, 550);
+                        assert_eq!(response.response.esc, [5, 7, 1]);
+                    }
+                    err => panic!("Unexpected error {err:?}"),
+                },
+                status => panic!("Unexpected status {status:?}"),
+            },
+            "mike@foobar.org" => {
+                assert!(
+                    matches!(rcpt.status, Status::TemporaryFailure(_)),
+                    "{rcpt:?}"
+                );
+                assert_eq!(rcpt.retry.due, hold_until);
+            }
+            rcpt => panic!("Unexpected recipient {rcpt}"),
+        }
+    }
+    messages
+        .iter()
+        .find(|message| message.message.return_path.is_empty())
+        .expect("DSN not found")
+        .read_lines(&local)
+        .await
+        .assert_contains("Blocked by DLP policy");
+}
@@ -7,9 +7,12 @@
 pub mod dane;
 pub mod extensions;
 pub mod fallback_relay;
+pub mod hooks;
 pub mod ip_lookup;
 pub mod lmtp;
 pub mod mta_sts;
+pub mod reuse;
 pub mod smtp;
+pub mod srs;
 pub mod throttle;
 pub mod tls;
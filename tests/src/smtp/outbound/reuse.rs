@@ -0,0 +1,143 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::{
+    smtp::session::TestSession,
+    utils::{dns::DnsCache, server::TestServerBuilder},
+};
+use registry::schema::{
+    enums::MtaProtocol,
+    structs::{
+        Expression, MtaConnectionStrategy, MtaOutboundStrategy, MtaRoute, MtaRouteRelay,
+        MtaStageRcpt,
+    },
+};
+use smtp::outbound::client::SessionPool;
+use std::time::{Duration, Instant};
+
+#[tokio::test]
+#[serial_test::serial]
+async fn connection_reuse() {
+    let mut local = TestServerBuilder::new("smtp_reuse_local")
+        .await
+        .with_http_listener(19053)
+        .await
+        .disable_services()
+        .capture_queue()
+        .build()
+        .await;
+    let mut remote = TestServerBuilder::new("smtp_reuse_remote")
+        .await
+        .with_http_listener(19054)
+        .await
+        .with_smtp_listener(9925)
+        .await
+        .disable_services()
+        .capture_queue()
+        .build()
+        .await;
+
+    // Keep up to two idle sessions open, closing them after two messages
+    // or three seconds of inactivity
+    let local_admin = local.account("admin");
+    local_admin
+        .registry_create_object(MtaStageRcpt {
+            allow_relaying: Expression {
+                else_: "true".into(),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .await;
+    local_admin
+        .registry_create_object(MtaConnectionStrategy {
+            name: "reuse".into(),
+            reuse_max_idle: 2,
+            reuse_max_messages: 2,
+            reuse_idle_timeout: 3_000u64.into(),
+            ..Default::default()
+        })
+        .await;
+    local_admin
+        .registry_create_object(MtaOutboundStrategy {
+            route: Expression {
+                else_: "'relay'".into(),
+                ..Default::default()
+            },
+            connection: Expression {
+                else_: "'reuse'".into(),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .await;
+    local_admin
+        .registry_create_object(MtaRoute::Relay(MtaRouteRelay {
+            address: "relay.foobar.org".into(),
+            port: 9925,
+            allow_invalid_certs: true,
+            name: "relay".into(),
+            protocol: MtaProtocol::Smtp,
+            ..Default::default()
+        }))
+        .await;
+    local_admin.mta_no_auth().await;
+    local_admin.mta_all_extensions().await;
+    local_admin.reload_settings().await;
+    local.reload_core();
+    local.expect_reload_settings().await;
+
+    let remote_admin = remote.account("admin");
+    remote_admin.mta_allow_relaying().await;
+    remote_admin.mta_no_auth().await;
+    remote_admin.mta_all_extensions().await;
+    remote_admin.mta_allow_non_fqdn().await;
+    remote_admin.reload_settings().await;
+    remote.reload_core();
+    remote.expect_reload_settings().await;
+
+    local.server.ipv4_add(
+        "relay.foobar.org",
+        vec!["127.0.0.1".parse().unwrap()],
+        Instant::now() + Duration::from_secs(10),
+    );
+
+    let mut session = local.new_mta_session();
+    session.data.remote_ip_str = "10.0.0.1".into();
+    session.eval_session_params().await;
+    session.ehlo("mx.test.org").await;
+
+    // The first message opens a new session that is kept idle afterwards,
+    // the second message reuses it and reaches the per-session message limit
+    for (rcpt, idle_sessions) in [
+        ("bill@foobar.org", 1),
+        ("jane@foobar.org", 0),
+        ("mike@foobar.org", 1),
+    ] {
+        session
+            .send_message("john@test.org", &[rcpt], "test:no_dkim", "250")
+            .await;
+        local
+            .expect_message_then_deliver()
+            .await
+            .try_deliver(local.server.clone());
+        let message = remote.expect_message().await;
+        assert_eq!(message.message.recipients[0].address(), rcpt);
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        assert_eq!(
+            local.server.inner.data.smtp_sessions.idle_sessions(),
+            idle_sessions,
+            "{rcpt}"
+        );
+    }
+
+    // Sessions are only reaped once their idle timeout elapses
+    local.server.reap_idle_sessions().await;
+    assert_eq!(local.server.inner.data.smtp_sessions.idle_sessions(), 1);
+    tokio::time::sleep(Duration::from_millis(3500)).await;
+    local.server.reap_idle_sessions().await;
+    assert_eq!(local.server.inner.data.smtp_sessions.idle_sessions(), 0);
+}
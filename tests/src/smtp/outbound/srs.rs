@@ -0,0 +1,134 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::{
+    smtp::session::TestSession,
+    utils::{dns::DnsCache, server::TestServerBuilder},
+};
+use registry::schema::{
+    enums::MtaProtocol,
+    structs::{
+        Expression, MtaOutboundStrategy, MtaRoute, MtaRouteRelay, MtaStageRcpt, SecretKeyOptional,
+        SecretKeyValue, SenderAuth,
+    },
+};
+use smtp::queue::{FROM_AUTOGENERATED, FROM_UNAUTHENTICATED, FROM_UNAUTHENTICATED_DMARC};
+use std::time::{Duration, Instant};
+
+#[tokio::test]
+#[serial_test::serial]
+async fn srs_rewrite() {
+    let mut local = TestServerBuilder::new("smtp_srs_local")
+        .await
+        .with_http_listener(19060)
+        .await
+        .disable_services()
+        .capture_queue()
+        .build()
+        .await;
+    let mut remote = TestServerBuilder::new("smtp_srs_remote")
+        .await
+        .with_http_listener(19061)
+        .await
+        .with_smtp_listener(9925)
+        .await
+        .disable_services()
+        .capture_queue()
+        .build()
+        .await;
+
+    // Enable SRS using the default rewrite domain expression
+    let local_admin = local.account("admin");
+    local_admin
+        .registry_create_object(MtaStageRcpt {
+            allow_relaying: Expression {
+                else_: "true".into(),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .await;
+    local_admin
+        .registry_create_object(SenderAuth {
+            srs_signing_key: SecretKeyOptional::Value(SecretKeyValue {
+                secret: "srs-test-secret".into(),
+            }),
+            ..Default::default()
+        })
+        .await;
+    local_admin
+        .registry_create_object(MtaOutboundStrategy {
+            route: Expression {
+                else_: "'relay'".into(),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .await;
+    local_admin
+        .registry_create_object(MtaRoute::Relay(MtaRouteRelay {
+            address: "relay.foobar.org".into(),
+            port: 9925,
+            allow_invalid_certs: true,
+            name: "relay".into(),
+            protocol: MtaProtocol::Smtp,
+            ..Default::default()
+        }))
+        .await;
+    local_admin.mta_no_auth().await;
+    local_admin.mta_all_extensions().await;
+    local_admin.reload_settings().await;
+    local.reload_core();
+    local.expect_reload_settings().await;
+
+    let remote_admin = remote.account("admin");
+    remote_admin.mta_allow_relaying().await;
+    remote_admin.mta_no_auth().await;
+    remote_admin.mta_all_extensions().await;
+    remote_admin.mta_allow_non_fqdn().await;
+    remote_admin.reload_settings().await;
+    remote.reload_core();
+    remote.expect_reload_settings().await;
+
+    local.server.ipv4_add(
+        "relay.foobar.org",
+        vec!["127.0.0.1".parse().unwrap()],
+        Instant::now() + Duration::from_secs(10),
+    );
+
+    // Forwarded messages are sent with an SRS return path
+    let mut session = local.new_mta_session();
+    session.data.remote_ip_str = "10.0.0.1".into();
+    session.eval_session_params().await;
+    session.ehlo("mx.test.org").await;
+    session
+        .send_message("john@test.org", &["bill@foobar.org"], "test:no_dkim", "250")
+        .await;
+    local
+        .expect_message_then_deliver()
+        .await
+        .try_deliver(local.server.clone());
+    let return_path = remote.expect_message().await.message.return_path;
+    assert!(return_path.starts_with("SRS0="), "{return_path}");
+    assert!(return_path.contains("=test.org=john@"), "{return_path}");
+
+    // Messages redirected by Sieve scripts are rewritten as well
+    session
+        .send_message("john@test.org", &["jane@foobar.org"], "test:no_dkim", "250")
+        .await;
+    let mut message = local.expect_message().await;
+    message.message.flags &= !(FROM_UNAUTHENTICATED | FROM_UNAUTHENTICATED_DMARC);
+    message.message.flags |= FROM_AUTOGENERATED;
+    let queue_id = message.queue_id;
+    message.save_changes(&local.server, None).await;
+    local
+        .delivery_attempt(queue_id)
+        .await
+        .try_deliver(local.server.clone());
+    let return_path = remote.expect_message().await.message.return_path;
+    assert!(return_path.starts_with("SRS0="), "{return_path}");
+    assert!(return_path.contains("=test.org=john@"), "{return_path}");
+}
@@ -12,22 +12,24 @@ use crate::{
     },
     utils::{dns::DnsCache, server::TestServerBuilder},
 };
+use common::{Server, config::smtp::queue::ConnectionStrategy};
 use mail_auth::MX;
 use registry::{
     schema::{
-        enums::MtaOutboundThrottleKey,
+        enums::{MtaIpWarmUpStatus, MtaOutboundThrottleKey},
         structs::{
-            Expression, MtaDeliveryExpiration, MtaDeliveryExpirationTtl, MtaDeliverySchedule,
-            MtaDeliveryScheduleInterval, MtaDeliveryScheduleIntervals,
-            MtaDeliveryScheduleIntervalsOrDefault, MtaOutboundStrategy, MtaOutboundThrottle,
-            MtaVirtualQueue, Rate,
+            Expression, MtaConnectionIpHost, MtaConnectionStrategy, MtaDeliveryExpiration,
+            MtaDeliveryExpirationTtl, MtaDeliverySchedule, MtaDeliveryScheduleInterval,
+            MtaDeliveryScheduleIntervals, MtaDeliveryScheduleIntervalsOrDefault, MtaIpWarmUp,
+            MtaOutboundStrategy, MtaOutboundThrottle, MtaVirtualQueue, Rate,
         },
     },
-    types::{list::List, map::Map},
+    types::{datetime::UTCDateTime, list::List, map::Map},
 };
 use smtp::queue::{Message, QueueEnvelope, Recipient, throttle::IsAllowed};
 use std::{
     net::{IpAddr, Ipv4Addr},
+    str::FromStr,
     time::{Duration, Instant},
 };
 use store::write::now;
@@ -302,6 +304,164 @@ async fn throttle_outbound() {
     assert!(due > 0, "Due: {}", due);
 }
 
+#[tokio::test]
+async fn throttle_ip_warm_up() {
+    let mut local = TestServerBuilder::new("smtp_throttle_ip_warm_up")
+        .await
+        .with_http_listener(19057)
+        .await
+        .disable_services()
+        .build()
+        .await;
+
+    let now = now();
+    let started_at = UTCDateTime::from_timestamp((now - 3600) as i64);
+    let warm_up_ip = |ip: &str, start: Option<UTCDateTime>| MtaConnectionIpHost {
+        source_ip: registry::types::ipaddr::IpAddr::from_str(ip).unwrap(),
+        warm_up_start: start,
+        warm_up_days: 30,
+        warm_up_initial_volume: 2,
+        warm_up_target_volume: 1000,
+        ..Default::default()
+    };
+
+    let admin = local.account("admin");
+    let spill_id = admin
+        .registry_create_object(MtaConnectionStrategy {
+            name: "spill".into(),
+            source_ips: List::from_iter([
+                warm_up_ip("10.0.0.1", started_at.into()),
+                warm_up_ip("10.0.0.2", None),
+            ]),
+            ..Default::default()
+        })
+        .await;
+    admin
+        .registry_create_object(MtaConnectionStrategy {
+            name: "warming".into(),
+            source_ips: List::from_iter([
+                warm_up_ip("10.0.0.3", started_at.into()),
+                warm_up_ip(
+                    "10.0.0.4",
+                    UTCDateTime::from_timestamp((now - 40 * 86400) as i64).into(),
+                ),
+                warm_up_ip(
+                    "10.0.0.5",
+                    UTCDateTime::from_timestamp((now + 86400) as i64).into(),
+                ),
+            ]),
+            ..Default::default()
+        })
+        .await;
+    admin.reload_settings().await;
+    local.reload_core();
+
+    let core = local.server.core.clone();
+    let spill = core.smtp.queue.connection_strategy.get("spill").unwrap();
+    let warming = core.smtp.queue.connection_strategy.get("warming").unwrap();
+    let remote_ip = IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1));
+
+    // Selecting an IP does not consume volume, only successful deliveries do
+    for _ in 0..5 {
+        let ip_host = local
+            .server
+            .warm_up_source_ip(spill, remote_ip, "mx1.gmail.com", 0)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(ip_host.ip.to_string(), "10.0.0.1");
+    }
+
+    // The warming IP is preferred until today's volume for the provider is exhausted
+    for expected_ip in ["10.0.0.1", "10.0.0.1", "10.0.0.2", "10.0.0.2"] {
+        assert_eq!(
+            warm_up_deliver(&local.server, spill, "mx1.gmail.com").await,
+            expected_ip
+        );
+    }
+
+    // Volumes are tracked per destination provider
+    for mx in ["mx2.gmail.com", "mx.example.org"] {
+        let expected_ip = if mx == "mx2.gmail.com" {
+            "10.0.0.2"
+        } else {
+            "10.0.0.1"
+        };
+        assert_eq!(warm_up_deliver(&local.server, spill, mx).await, expected_ip);
+    }
+
+    // Completed warm-ups are treated as established IPs and scheduled ones are skipped
+    let mut used_ips = Vec::new();
+    for _ in 0..10 {
+        used_ips.push(warm_up_deliver(&local.server, warming, "mx.example.net").await);
+    }
+    assert_eq!(&used_ips[..2], ["10.0.0.3", "10.0.0.3"]);
+    assert!(used_ips[2..].iter().all(|ip| ip == "10.0.0.4"));
+    assert!(!used_ips.iter().any(|ip| ip == "10.0.0.5"));
+
+    // Progress is visible through the registry
+    let mut items = admin.registry_get_all::<MtaIpWarmUp>().await;
+    items.sort_by(|(_, a), (_, b)| a.source_ip.to_string().cmp(&b.source_ip.to_string()));
+    assert_eq!(items.len(), 4);
+    let (_, item) = &items[0];
+    assert_eq!(item.source_ip.to_string(), "10.0.0.1");
+    assert_eq!(item.connection_strategy_id, spill_id);
+    assert_eq!(item.status, MtaIpWarmUpStatus::WarmingUp);
+    assert_eq!(item.day, 1);
+    assert_eq!(item.warm_up_days, 30);
+    assert_eq!(item.daily_limit, 2);
+    assert_eq!(item.sent_today, 3);
+    assert_eq!(items[1].1.status, MtaIpWarmUpStatus::WarmingUp);
+    assert_eq!(items[1].1.sent_today, 2);
+    assert_eq!(items[2].1.status, MtaIpWarmUpStatus::Complete);
+    assert_eq!(items[2].1.day, 30);
+    assert_eq!(items[3].1.status, MtaIpWarmUpStatus::Scheduled);
+    assert_eq!(items[3].1.sent_today, 0);
+    let (id, item) = &items[0];
+    assert_eq!(admin.registry_get::<MtaIpWarmUp>(*id).await, *item);
+
+    // Deliveries are rate limited until the next warm-up day when no IP is available
+    let only_warming = MtaConnectionStrategy {
+        name: "only-warming".into(),
+        source_ips: List::from_iter([warm_up_ip("10.0.0.6", started_at.into())]),
+        ..Default::default()
+    };
+    admin.registry_create_object(only_warming).await;
+    admin.reload_settings().await;
+    local.reload_core();
+    let core = local.server.core.clone();
+    let only_warming = core
+        .smtp
+        .queue
+        .connection_strategy
+        .get("only-warming")
+        .unwrap();
+    for _ in 0..2 {
+        assert_eq!(
+            warm_up_deliver(&local.server, only_warming, "mx.example.com").await,
+            "10.0.0.6"
+        );
+    }
+    assert_eq!(
+        local
+            .server
+            .warm_up_source_ip(only_warming, remote_ip, "mx.example.com", 0)
+            .await
+            .unwrap_err(),
+        started_at.timestamp() as u64 + 86400
+    );
+}
+
+async fn warm_up_deliver(server: &Server, strategy: &ConnectionStrategy, mx: &str) -> String {
+    let ip_host = server
+        .warm_up_source_ip(strategy, IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)), mx, 0)
+        .await
+        .unwrap()
+        .unwrap();
+    server.warm_up_delivered(ip_host, mx, 0).await;
+    ip_host.ip.to_string()
+}
+
 pub trait TestQueueEnvelope<'x> {
     fn test(message: &'x Message, rcpt: &'x Recipient, mx: &'x str) -> Self;
 }
@@ -6,9 +6,13 @@
 
 use crate::utils::server::{TestServer, TestServerBuilder};
 use common::config::smtp::queue::{QueueExpiry, QueueName};
+use mail_parser::MessageParser;
 use registry::schema::{
-    enums::CompressionAlgo,
-    structs::{DsnReportSettings, Expression, ReportSettings},
+    enums::{CompressionAlgo, Locale},
+    structs::{
+        CertificateManagement, DkimManagement, DnsManagement, Domain, DsnReportSettings,
+        Expression, ReportSettings,
+    },
 };
 use smtp::queue::{
     Error, ErrorDetails, HostResponse, Message, MessageWrapper, Recipient, Schedule, Status,
@@ -194,6 +198,50 @@ async fn generate_dsn() {
     // Load queue
     let queue = local.read_queued_messages().await;
     assert_eq!(queue.len(), 4);
+
+    // Localized DSN using the default locale of the sender's domain
+    local_admin
+        .registry_create_object(Domain {
+            name: "example.es".to_string(),
+            is_enabled: true,
+            locale: Locale::EsES,
+            certificate_management: CertificateManagement::Manual,
+            dns_management: DnsManagement::Manual,
+            dkim_management: DkimManagement::Manual,
+            ..Default::default()
+        })
+        .await;
+    message.message.return_path = "sender@example.es".into();
+    message.message.recipients.truncate(1);
+    message.message.recipients[0].flags = flags;
+    local.server.send_dsn(&mut message).await;
+    let dsn_message = local.expect_message().await;
+    let bytes = local
+        .server
+        .blob_store()
+        .get_blob(dsn_message.message.blob_hash.as_slice(), 0..usize::MAX)
+        .await
+        .unwrap()
+        .unwrap();
+    let dsn = MessageParser::new().parse(&bytes).unwrap();
+    assert_eq!(dsn.subject(), Some("No se pudo entregar el mensaje"));
+    let text = dsn.body_text(0).unwrap();
+    assert!(
+        text.contains("No se pudo entregar su mensaje a los siguientes destinatarios:"),
+        "{text}"
+    );
+    assert!(
+        text.contains("el servidor 'mx.example.org' rechazó el comando"),
+        "{text}"
+    );
+    let html = dsn.body_html(0).unwrap();
+    assert!(html.contains("Informe de Entrega de Correo"), "{html}");
+    assert!(html.contains("foobar@example.org"), "{html}");
+    assert!(
+        std::str::from_utf8(&bytes)
+            .unwrap()
+            .contains("Action: failed\r\nStatus: 5.1.2\r\n"),
+    );
 }
 
 impl TestServer {
@@ -233,8 +281,9 @@ fn remove_ids(message: Vec<u8>) -> String {
     let mut message = String::with_capacity(old_message.len());
     let mut found_dkim = 0;
     let mut skip = false;
+    let mut skip_html = false;
 
-    let mut boundary = "";
+    let mut boundaries: Vec<String> = Vec::new();
     for line in old_message.split("\r\n") {
         if skip {
             if line.chars().next().unwrap().is_ascii_whitespace() {
@@ -243,6 +292,13 @@ fn remove_ids(message: Vec<u8>) -> String {
                 skip = false;
             }
         }
+        if skip_html {
+            if line.starts_with("--") {
+                skip_html = false;
+            } else {
+                continue;
+            }
+        }
         if line.starts_with("Date:") || line.starts_with("Message-ID:") {
             continue;
         } else if found_dkim < 2 && line.starts_with("DKIM-Signature:") {
@@ -250,10 +306,28 @@ fn remove_ids(message: Vec<u8>) -> String {
             skip = true;
             continue;
         } else if line.starts_with("--") {
-            message.push_str(&line.replace(boundary, "mime_boundary"));
+            let mut line = line.to_string();
+            for boundary in &boundaries {
+                line = line.replace(boundary.as_str(), "mime_boundary");
+            }
+            message.push_str(&line);
         } else if let Some((_, boundary_)) = line.split_once("boundary=\"") {
-            boundary = boundary_.split_once('"').unwrap().0;
-            message.push_str(&line.replace(boundary, "mime_boundary"));
+            let boundary = boundary_.split_once('"').unwrap().0.to_string();
+            let line = line.replace(&boundary, "mime_boundary");
+            boundaries.push(boundary);
+            if line.starts_with('\t') {
+                // Unfold the boundary parameter
+                message.truncate(message.trim_end().len());
+                message.push(' ');
+                message.push_str(line.trim_start());
+            } else {
+                message.push_str(&line);
+            }
+        } else if line.starts_with("Content-Type: text/html") {
+            // The rendered HTML part is not compared
+            message.push_str(line);
+            message.push_str("\r\n<html part goes here>");
+            skip_html = true;
         } else if line.starts_with("Arrival-Date:") {
             message.push_str("Arrival-Date: <date goes here>");
         } else if line.starts_with("Will-Retry-Until:") {
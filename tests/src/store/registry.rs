@@ -10,7 +10,7 @@ use registry::{
     jmap::{IntoValue, JmapValue, JsonPointerPatch, MaybeUnpatched, RegistryJsonPatch},
     pickle::{Pickle, PickledStream},
     schema::{
-        enums::{AccountType, Locale, Permission, StorageQuota},
+        enums::{AccountType, BookingPolicy, Locale, Permission, PrincipalKind, StorageQuota},
         prelude::{Object, ObjectType, Property},
         structs::{
             Account, CertificateManagement, Credential, CredentialPermissions,
@@ -21,8 +21,8 @@ use registry::{
         },
     },
     types::{
-        EnumImpl, ObjectImpl, datetime::UTCDateTime, id::ObjectId, ipmask::IpAddrOrMask,
-        list::List, map::Map,
+        EnumImpl, ObjectImpl, datetime::UTCDateTime, duration::Duration, id::ObjectId,
+        ipmask::IpAddrOrMask, list::List, map::Map,
     },
 };
 use std::str::FromStr;
@@ -104,6 +104,12 @@ pub async fn test(test: &TestServer) {
             role_ids: Map::new(vec![5000u64.into()]),
         }),
         time_zone: None,
+        principal_kind: PrincipalKind::Location,
+        booking_policy: BookingPolicy::Delegate,
+        booking_max_duration: Some(Duration::from_millis(4 * 3600 * 1000)),
+        booking_window: None,
+        booking_allowed_requesters: Map::new(vec!["example.org".to_string()]),
+        booking_delegate_ids: Map::new(vec![3000u64.into()]),
     });
     let account_pickle = account.to_pickled_vec();
     assert_eq!(
@@ -4,15 +4,18 @@
  * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
  */
 
-use crate::utils::{jmap::JmapUtils, server::TestServer, smtp::SmtpConnection};
+use crate::utils::{account::Account, jmap::JmapUtils, server::TestServer, smtp::SmtpConnection};
+use calcard::vcard::VCardProperty;
 use common::{
     auth::{
         ACCOUNT_FLAG_ENCRYPT_ALGO_AES128, ACCOUNT_FLAG_ENCRYPT_ALGO_AES256,
         ACCOUNT_FLAG_ENCRYPT_METHOD_PGP, ACCOUNT_FLAG_ENCRYPT_METHOD_SMIME,
     },
-    storage::encryption::{EncryptionMethod, parse_public_key},
+    network::wkd::wkd_hash,
+    storage::encryption::{AutocryptKey, EncryptionMethod, KeyDiscovery, parse_public_key},
 };
 use email::message::crypto::EncryptMessage;
+use groupware::contact::{ContactCard, autocrypt::AutocryptIngest};
 use mail_parser::{MessageParser, MimeHeaders};
 use registry::schema::{
     prelude::{ObjectType, Property},
@@ -20,7 +23,11 @@ use registry::schema::{
 };
 use serde_json::json;
 use std::path::PathBuf;
-use types::id::Id;
+use store::{
+    ValueKey,
+    write::{AlignedBytes, Archive},
+};
+use types::{collection::Collection, field::ContactField, id::Id};
 
 pub async fn test(test: &mut TestServer) {
     println!("Running Encryption-at-rest tests...");
@@ -102,6 +109,9 @@ pub async fn test(test: &mut TestServer) {
         &certs_parsed[1]
     );
 
+    // Publish keys through WKD and Autocrypt
+    key_discovery(test, &account, cert_ids[1]).await;
+
     // Send a new message, which should be encrypted
     let mut lmtp = SmtpConnection::connect().await;
     lmtp.ingest(
@@ -204,6 +214,210 @@ pub async fn test(test: &mut TestServer) {
     test.cleanup().await;
 }
 
+async fn key_discovery(test: &TestServer, account: &Account, cert_id: Id) {
+    let account_id = account.id().document_id();
+    let hash = wkd_hash("jdoe");
+
+    // WKD publishing is opt-in
+    assert!(
+        test.server
+            .discoverable_public_key("jdoe@example.org", KeyDiscovery::Wkd)
+            .await
+            .unwrap()
+            .is_none()
+    );
+    assert_eq!(
+        wkd_get(&format!("hu/{hash}?l=jdoe"), "example.org").await.0,
+        404
+    );
+    account
+        .registry_update_object(
+            ObjectType::PublicKey,
+            cert_id,
+            json!({
+                Property::PublishWkd: true
+            }),
+        )
+        .await;
+    let expected = test
+        .server
+        .discoverable_public_key("jdoe@example.org", KeyDiscovery::Wkd)
+        .await
+        .unwrap()
+        .expect("OpenPGP key not published");
+    assert_eq!(expected.account_id, account_id);
+    assert!(!expected.cert.is_empty());
+
+    // Direct and advanced WKD lookups
+    for (path, host) in [
+        (format!("hu/{hash}?l=jdoe"), "example.org"),
+        (
+            format!("example.org/hu/{hash}?l=jdoe"),
+            "openpgpkey.example.org",
+        ),
+    ] {
+        let (status, body) = wkd_get(&path, host).await;
+        assert_eq!(status, 200, "{path}");
+        assert_eq!(body, expected.cert, "{path}");
+    }
+    for (path, host) in [
+        ("policy".to_string(), "example.org"),
+        ("example.org/policy".to_string(), "openpgpkey.example.org"),
+    ] {
+        assert_eq!(wkd_get(&path, host).await.0, 200, "{path}");
+    }
+
+    // Mismatched hashes, unknown addresses and domains are not found
+    for (path, host) in [
+        (format!("hu/{}?l=jdoe", wkd_hash("john")), "example.org"),
+        (format!("hu/{hash}"), "example.org"),
+        (
+            format!("hu/{}?l=unknown", wkd_hash("unknown")),
+            "example.org",
+        ),
+        (format!("hu/{hash}?l=jdoe"), "example.com"),
+        ("example.com/policy".to_string(), "openpgpkey.example.com"),
+    ] {
+        assert_eq!(wkd_get(&path, host).await.0, 404, "{path}");
+    }
+
+    // Autocrypt headers are opt-in
+    let mut session = test.new_mta_session();
+    session.data.authenticated_as = Some(
+        test.server
+            .build_account_info(test.server.account(account_id).await.unwrap())
+            .await
+            .unwrap(),
+    );
+    let mut headers = Vec::new();
+    session
+        .write_autocrypt_header("jdoe@example.org", &mut headers)
+        .await;
+    assert!(headers.is_empty());
+    account
+        .registry_update_object(
+            ObjectType::PublicKey,
+            cert_id,
+            json!({
+                Property::Autocrypt: true
+            }),
+        )
+        .await;
+    session
+        .write_autocrypt_header("jdoe@example.org", &mut headers)
+        .await;
+    let header = String::from_utf8(headers).unwrap();
+    let key = AutocryptKey::parse(
+        header
+            .strip_prefix("Autocrypt: ")
+            .unwrap_or_else(|| panic!("Unexpected header {header}")),
+    )
+    .unwrap();
+    assert_eq!(key.addr, "jdoe@example.org");
+    assert_eq!(key.keydata, expected.cert);
+
+    // Unpublished keys are no longer served through WKD
+    account
+        .registry_update_object(
+            ObjectType::PublicKey,
+            cert_id,
+            json!({
+                Property::PublishWkd: false
+            }),
+        )
+        .await;
+    assert_eq!(
+        wkd_get(&format!("hu/{hash}?l=jdoe"), "example.org").await.0,
+        404
+    );
+
+    // Inbound Autocrypt keys are collected into the recipient's contacts
+    let bill = test
+        .create_user_account(
+            "admin@example.org",
+            "bill@example.org",
+            "this is a very strong password",
+            &[],
+            "bill@example.org",
+        )
+        .await;
+    let bill_id = bill.id().document_id();
+    let bill_info = test
+        .server
+        .build_account_info(test.server.account(bill_id).await.unwrap())
+        .await
+        .unwrap();
+    assert!(
+        test.server
+            .autocrypt_ingest(&bill_info, &key)
+            .await
+            .unwrap()
+    );
+    assert!(
+        !test
+            .server
+            .autocrypt_ingest(&bill_info, &key)
+            .await
+            .unwrap()
+    );
+    let card_ids = test
+        .server
+        .document_ids_matching(
+            bill_id,
+            Collection::ContactCard,
+            ContactField::Email,
+            "jdoe@example.org",
+        )
+        .await
+        .unwrap();
+    assert_eq!(card_ids.len(), 1);
+    let card = test
+        .server
+        .store()
+        .get_value::<Archive<AlignedBytes>>(ValueKey::archive(
+            bill_id,
+            Collection::ContactCard,
+            card_ids.min().unwrap(),
+        ))
+        .await
+        .unwrap()
+        .unwrap()
+        .deserialize::<ContactCard>()
+        .unwrap();
+    assert_eq!(card.card.properties(&VCardProperty::Key).count(), 1);
+    assert!(
+        card.card
+            .to_string()
+            .contains("KEY:data:application/pgp-keys;base64,"),
+        "{}",
+        card.card
+    );
+
+    test.account("admin@example.org")
+        .destroy_account(bill)
+        .await;
+}
+
+async fn wkd_get(path: &str, host: &str) -> (u16, Vec<u8>) {
+    let response = reqwest::Client::builder()
+        .danger_accept_invalid_certs(true)
+        .http1_only()
+        .build()
+        .unwrap()
+        .get(format!(
+            "https://127.0.0.1:8899/.well-known/openpgpkey/{path}"
+        ))
+        .header("Host", host)
+        .send()
+        .await
+        .unwrap();
+
+    (
+        response.status().as_u16(),
+        response.bytes().await.unwrap().to_vec(),
+    )
+}
+
 pub async fn import_certs_and_encrypt() {
     for (name, method) in [
         ("cert_pgp.pem", EncryptionMethod::PGP),
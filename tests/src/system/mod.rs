@@ -13,6 +13,7 @@ pub mod delivery;
 pub mod directory;
 pub mod oidc;
 pub mod purge;
+pub mod quarantine;
 pub mod quota;
 pub mod security;
 pub mod task;
@@ -73,6 +74,7 @@ fn system_tests() {
             quota::test(&mut test).await;
             purge::test(&mut test).await;
             delivery::test(&mut test).await;
+            quarantine::test(&mut test).await;
             crypto::test(&mut test).await;
             antispam::test(&mut test).await;
             archiving::test(&mut test).await;
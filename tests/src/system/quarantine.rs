@@ -0,0 +1,196 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::utils::{server::TestServer, smtp::SmtpConnection};
+use email::cache::MessageCacheFetch;
+use jmap_proto::error::set::SetErrorType;
+use mail_parser::MessageParser;
+use registry::schema::{
+    enums::TaskAccountMaintenanceType,
+    prelude::{ObjectType, Property},
+    structs::{
+        QuarantinedMessage, SpamTag, SpamTagAction, Task, TaskAccountMaintenance, TaskStatus,
+    },
+};
+use serde_json::json;
+use std::time::Duration;
+
+const GTUBE_MESSAGE: &str = concat!(
+    "From: bill@example.org\r\n",
+    "To: jdoe@example.org\r\n",
+    "Subject: XJS*C4JDBQADN1.NSBN3*2IDNEN*GTUBE-STANDARD-ANTI-UBE-TEST-EMAIL*C.34X\r\n",
+    "\r\n",
+    "I'm going to need those TPS reports ASAP. ",
+    "So, if you could do that, that'd be great."
+);
+
+pub async fn test(test: &mut TestServer) {
+    println!("Running Quarantine tests...");
+    let admin = test.account("admin@example.org");
+
+    // Quarantine messages matching the GTUBE tag
+    admin
+        .registry_create_object(SpamTag::Quarantine(SpamTagAction {
+            tag: "GTUBE_TEST".to_string(),
+        }))
+        .await;
+    admin.reload_settings().await;
+    let john = test
+        .create_user_account(
+            "admin@example.org",
+            "jdoe@example.org",
+            "this is a very strong password",
+            &[],
+            "jdoe@example.org",
+        )
+        .await;
+    let account_id = john.id().document_id();
+
+    // Messages matching a quarantine tag are held instead of delivered
+    let mut lmtp = SmtpConnection::connect().await;
+    lmtp.ingest("bill@example.org", &["jdoe@example.org"], GTUBE_MESSAGE)
+        .await;
+    assert_eq!(wait_for_messages(test, account_id, 0).await.len(), 0);
+    let items = john.registry_get_all::<QuarantinedMessage>().await;
+    assert_eq!(items.len(), 1);
+    let (item_id, item) = &items[0];
+    assert_eq!(item.reason, "GTUBE_TEST");
+    assert_eq!(item.from, "bill@example.org");
+    assert_eq!(
+        item.recipients.as_slice(),
+        &["jdoe@example.org".to_string()]
+    );
+
+    // Only release requests are accepted as updates
+    john.registry_update_object_expect_err(
+        ObjectType::QuarantinedMessage,
+        *item_id,
+        json!({
+            Property::Status: "held"
+        }),
+    )
+    .await
+    .assert_type(SetErrorType::InvalidProperties)
+    .assert_properties(&["status"]);
+
+    // The digest lists the held message with a release link
+    admin
+        .registry_create_object(Task::AccountMaintenance(TaskAccountMaintenance {
+            account_id: john.id(),
+            maintenance_type: TaskAccountMaintenanceType::QuarantineDigest,
+            status: TaskStatus::now(),
+        }))
+        .await;
+    test.wait_for_tasks().await;
+    let document_ids = wait_for_messages(test, account_id, 1).await;
+    assert_eq!(document_ids.len(), 1);
+    let digest = test.fetch_email(account_id, document_ids[0]).await;
+    let digest = MessageParser::new().parse(&digest).unwrap();
+    assert_eq!(
+        digest.subject(),
+        Some("You have 1 new messages in quarantine")
+    );
+    let html = digest.body_html(0).unwrap();
+    let token = html
+        .split_once("/quarantine/release?i=")
+        .map(|(_, token)| {
+            token
+                .chars()
+                .take_while(|ch| ch.is_ascii_alphanumeric() || "%+-._*".contains(*ch))
+                .collect::<String>()
+        })
+        .expect("Missing release link");
+
+    // Opening the release link only asks for confirmation
+    let client = reqwest::Client::builder()
+        .danger_accept_invalid_certs(true)
+        .build()
+        .unwrap();
+    let response = client
+        .get(format!(
+            "https://127.0.0.1:8899/quarantine/release?i={token}"
+        ))
+        .send()
+        .await
+        .unwrap();
+    assert!(response.status().is_success());
+    let page = response.text().await.unwrap();
+    assert!(page.contains("Release this message?"), "{page}");
+    assert!(page.contains("bill@example.org"), "{page}");
+    assert_eq!(john.registry_get_all::<QuarantinedMessage>().await.len(), 1);
+
+    // Confirming the release delivers the message
+    let response = client
+        .post("https://127.0.0.1:8899/quarantine/release")
+        .header("Content-Type", "application/x-www-form-urlencoded")
+        .body(format!("i={token}"))
+        .send()
+        .await
+        .unwrap();
+    assert!(response.status().is_success());
+    let page = response.text().await.unwrap();
+    assert!(page.contains("Message released"), "{page}");
+    assert_eq!(wait_for_messages(test, account_id, 2).await.len(), 2);
+    assert_eq!(john.registry_get_all::<QuarantinedMessage>().await, vec![]);
+
+    // The link can only be used once
+    let page = client
+        .post("https://127.0.0.1:8899/quarantine/release")
+        .header("Content-Type", "application/x-www-form-urlencoded")
+        .body(format!("i={token}"))
+        .send()
+        .await
+        .unwrap()
+        .text()
+        .await
+        .unwrap();
+    assert!(page.contains("no longer in quarantine"), "{page}");
+
+    // Release using JMAP
+    lmtp.ingest("bill@example.org", &["jdoe@example.org"], GTUBE_MESSAGE)
+        .await;
+    let items = john.registry_get_all::<QuarantinedMessage>().await;
+    assert_eq!(items.len(), 1);
+    john.registry_update_object(
+        ObjectType::QuarantinedMessage,
+        items[0].0,
+        json!({
+            Property::Status: "requestRelease"
+        }),
+    )
+    .await;
+    assert_eq!(wait_for_messages(test, account_id, 3).await.len(), 3);
+    assert_eq!(john.registry_get_all::<QuarantinedMessage>().await, vec![]);
+
+    // Remove test data
+    test.destroy_all_mailboxes(&john).await;
+    admin.registry_destroy_all(ObjectType::SpamTag).await;
+    admin.reload_settings().await;
+    test.assert_is_empty().await;
+    admin.destroy_account(john).await;
+    test.cleanup().await;
+}
+
+async fn wait_for_messages(test: &TestServer, account_id: u32, expected: usize) -> Vec<u32> {
+    let mut document_ids = Vec::new();
+    for _ in 0..50 {
+        document_ids = test
+            .server
+            .get_cached_messages(account_id)
+            .await
+            .unwrap()
+            .emails
+            .items
+            .iter()
+            .map(|e| e.document_id)
+            .collect::<Vec<_>>();
+        if document_ids.len() == expected {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    document_ids
+}
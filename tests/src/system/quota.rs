@@ -5,6 +5,7 @@
  */
 
 use crate::utils::{account::Account, jmap::JmapUtils, server::TestServer, smtp::SmtpConnection};
+use common::KV_QUOTA_WARNING;
 use email::{cache::MessageCacheFetch, mailbox::INBOX_ID};
 use jmap::blob::upload::DISABLE_UPLOAD_QUOTA;
 use jmap_client::{
@@ -23,6 +24,7 @@ use registry::{
     types::{EnumImpl, list::List, map::Map},
 };
 use serde_json::json;
+use store::dispatch::lookup::KeyValue;
 use types::id::Id;
 use utils::map::vec_map::VecMap;
 
@@ -407,12 +409,138 @@ pub async fn test(test: &mut TestServer) {
         1,
     );
 
+    // Test quota warnings
+    admin
+        .registry_update_setting(
+            structs::Email {
+                quota_warn_thresholds: Map::new(vec![50, 90]),
+                ..Default::default()
+            },
+            &[Property::QuotaWarnThresholds],
+        )
+        .await;
+    admin.reload_settings().await;
+    assert_eq!(test.server.quota_warning_level(49_999, 100_000), 0);
+    assert_eq!(test.server.quota_warning_level(50_000, 100_000), 50);
+    assert_eq!(test.server.quota_warning_level(95_000, 100_000), 90);
+    let warn_account_id = admin
+        .registry_create_object(structs::Account::User(UserAccount {
+            name: "user3".to_string(),
+            domain_id,
+            credentials: List::from_iter([Credential::Password(PasswordCredential {
+                secret: "this is a very strong password3".to_string(),
+                ..Default::default()
+            })]),
+            quotas: VecMap::from_iter([(StorageQuota::MaxDiskQuota, 100_000)]),
+            ..Default::default()
+        }))
+        .await;
+    let warn_account = Account::new(
+        "user3@example.org",
+        "this is a very strong password3",
+        &[],
+        "User3",
+        warn_account_id,
+    );
+    let warn_key = KeyValue::<()>::build_key(
+        KV_QUOTA_WARNING,
+        [
+            &[0u8][..],
+            &warn_account.id().document_id().to_be_bytes()[..],
+        ]
+        .concat(),
+    );
+
+    let response = warn_account
+        .jmap_method_call("Quota/get", json!({"ids": null}))
+        .await
+        .to_string();
+    assert!(response.contains("\"warnLimit\":50000"), "{}", response);
+
+    // Usage below the first threshold does not trigger a warning
+    lmtp.ingest(
+        "jane@example.org",
+        &["user3@example.org"],
+        &String::from_utf8(create_message_with_size(
+            "jane@example.org",
+            "user3@example.org",
+            "Warning test 1",
+            30_000,
+        ))
+        .unwrap(),
+    )
+    .await;
+    test.wait_for_tasks().await;
+    assert_eq!(warned_level(test, &warn_key).await, None);
+
+    // Crossing the threshold delivers a single warning
+    lmtp.ingest(
+        "jane@example.org",
+        &["user3@example.org"],
+        &String::from_utf8(create_message_with_size(
+            "jane@example.org",
+            "user3@example.org",
+            "Warning test 2",
+            30_000,
+        ))
+        .unwrap(),
+    )
+    .await;
+    test.wait_for_tasks().await;
+    assert_eq!(warned_level(test, &warn_key).await.as_deref(), Some("50"));
+    let mut num_messages = 0;
+    for _ in 0..50 {
+        num_messages = test
+            .server
+            .get_cached_messages(warn_account.id().document_id())
+            .await
+            .unwrap()
+            .emails
+            .items
+            .len();
+        if num_messages == 3 {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+    }
+    assert_eq!(num_messages, 3);
+    test.wait_for_tasks().await;
+    let warn_client = warn_account.jmap_client().await;
+    assert_eq!(
+        warn_client
+            .email_query(
+                jmap_client::email::query::Filter::subject("storage quota warning").into(),
+                None::<Vec<_>>,
+            )
+            .await
+            .unwrap()
+            .ids()
+            .len(),
+        1
+    );
+
+    // Purging the account keeps the level while usage stays above it
+    admin
+        .registry_create_object(Task::AccountMaintenance(TaskAccountMaintenance {
+            account_id: warn_account_id,
+            maintenance_type: TaskAccountMaintenanceType::Purge,
+            status: TaskStatus::now(),
+        }))
+        .await;
+    test.wait_for_tasks().await;
+    assert_eq!(warned_level(test, &warn_key).await.as_deref(), Some("50"));
+    admin
+        .registry_update_setting(structs::Email::default(), &[Property::QuotaWarnThresholds])
+        .await;
+    admin.reload_settings().await;
+
     DISABLE_UPLOAD_QUOTA.store(true, std::sync::atomic::Ordering::Relaxed);
 
     // Remove test data
     test.wait_for_tasks().await;
     test.destroy_all_mailboxes(&account).await;
     test.destroy_all_mailboxes(&other_account).await;
+    test.destroy_all_mailboxes(&warn_account).await;
     admin.registry_destroy_all(ObjectType::QueuedMessage).await;
     admin
         .registry_destroy_all(ObjectType::SpamTrainingSample)
@@ -420,12 +548,23 @@ pub async fn test(test: &mut TestServer) {
     test.assert_is_empty().await;
 
     admin
-        .registry_destroy(ObjectType::Account, [account_id, other_account_id])
+        .registry_destroy(
+            ObjectType::Account,
+            [account_id, other_account_id, warn_account_id],
+        )
         .await
-        .assert_destroyed(&[account_id, other_account_id]);
+        .assert_destroyed(&[account_id, other_account_id, warn_account_id]);
     test.cleanup().await;
 }
 
+async fn warned_level(test: &TestServer, key: &[u8]) -> Option<String> {
+    test.server
+        .in_memory_store()
+        .key_get::<String>(key.to_vec())
+        .await
+        .unwrap()
+}
+
 fn assert_over_quota<T: std::fmt::Debug>(result: Result<T, jmap_client::Error>) {
     match result {
         Ok(result) => panic!("Expected error, got {:?}", result),
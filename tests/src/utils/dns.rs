@@ -6,7 +6,10 @@
 
 use common::{
     Server,
-    config::{mailstore::spamfilter::IpResolver, smtp::resolver::Tlsa},
+    config::{
+        mailstore::spamfilter::IpResolver,
+        smtp::resolver::{BimiRecord, Tlsa},
+    },
 };
 use mail_auth::{MX, Txt, common::resolver::ToFqdn};
 use std::{
@@ -22,6 +25,7 @@ pub trait DnsCache {
     fn ptr_add(&self, name: IpAddr, value: Vec<String>, valid_until: std::time::Instant);
     fn mx_add(&self, name: impl ToFqdn, value: Vec<MX>, valid_until: std::time::Instant);
     fn tlsa_add(&self, name: impl ToFqdn, value: Arc<Tlsa>, valid_until: std::time::Instant);
+    fn bimi_add(&self, name: impl ToFqdn, value: BimiRecord, valid_until: std::time::Instant);
 }
 
 impl DnsCache for Server {
@@ -82,4 +86,12 @@ impl DnsCache for Server {
             .dns_tlsa
             .insert_with_expiry(name.to_fqdn(), value, valid_until);
     }
+
+    fn bimi_add(&self, name: impl ToFqdn, value: BimiRecord, valid_until: std::time::Instant) {
+        self.inner.cache.dns_bimi.insert_with_expiry(
+            name.to_fqdn(),
+            Some(Arc::new(value)),
+            valid_until,
+        );
+    }
 }
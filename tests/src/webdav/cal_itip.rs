@@ -7,14 +7,19 @@
 use ahash::AHashMap;
 use calcard::{
     common::{IanaString, PartialDateTime},
-    icalendar::{ICalendar, ICalendarProperty, ICalendarValue},
+    icalendar::{
+        ICalendar, ICalendarMethod, ICalendarParticipationStatus, ICalendarProperty, ICalendarValue,
+    },
 };
 use groupware::scheduling::{
     ItipMessage, ItipSummary,
     event_cancel::itip_cancel,
     event_create::itip_create,
     event_update::itip_update,
-    inbound::{MergeResult, itip_import_message, itip_merge_changes, itip_process_message},
+    inbound::{
+        BookingDecision, MergeResult, itip_apply_booking, itip_import_message, itip_merge_changes,
+        itip_process_message,
+    },
     snapshot::itip_snapshot,
 };
 use std::{collections::hash_map::Entry, path::PathBuf};
@@ -360,6 +365,111 @@ pub fn test() {
     }
 }
 
+pub fn test_booking() {
+    println!("Running iTIP booking tests...");
+    let room = vec!["room@example.com".to_string()];
+
+    for (decision, expected_part_stat, expected_messages) in [
+        (
+            BookingDecision::Accept,
+            ICalendarParticipationStatus::Accepted,
+            vec![(ICalendarMethod::Reply, vec!["a@example.com"])],
+        ),
+        (
+            BookingDecision::Decline,
+            ICalendarParticipationStatus::Declined,
+            vec![(ICalendarMethod::Reply, vec!["a@example.com"])],
+        ),
+        (
+            BookingDecision::Delegate(vec!["b@example.com".to_string()]),
+            ICalendarParticipationStatus::Delegated,
+            vec![
+                (ICalendarMethod::Reply, vec!["a@example.com"]),
+                (ICalendarMethod::Request, vec!["b@example.com"]),
+            ],
+        ),
+    ] {
+        let old_ical = ICalendar::parse(TEST_BOOKING).unwrap();
+        let mut ical = old_ical.clone();
+        itip_apply_booking(&mut ical, &room, &decision);
+
+        // Validate the participation status of the room
+        let snapshots = itip_snapshot(&ical, &room, false).unwrap();
+        let attendees = &snapshots.components.values().next().unwrap().attendees;
+        let local = attendees.iter().find(|a| a.email.is_local).unwrap();
+        assert_eq!(local.part_stat, Some(&expected_part_stat), "{decision:?}");
+        if let BookingDecision::Delegate(delegates) = &decision {
+            assert_eq!(
+                local
+                    .delegated_to
+                    .iter()
+                    .map(|e| e.email.as_str())
+                    .collect::<Vec<_>>(),
+                delegates.iter().map(String::as_str).collect::<Vec<_>>()
+            );
+            let delegate = attendees
+                .iter()
+                .find(|a| a.email.email == "b@example.com")
+                .unwrap();
+            assert!(delegate.is_delegated_from(local));
+            assert_eq!(
+                delegate.part_stat,
+                Some(&ICalendarParticipationStatus::NeedsAction)
+            );
+        }
+
+        // Validate the messages sent on behalf of the room
+        let mut messages = itip_update(&mut ical, &old_ical, &room)
+            .unwrap()
+            .into_iter()
+            .map(|message| {
+                let method = message.message.components[0]
+                    .property(&ICalendarProperty::Method)
+                    .and_then(|entry| entry.values.first())
+                    .and_then(|value| {
+                        if let ICalendarValue::Method(method) = value {
+                            Some(method.clone())
+                        } else {
+                            None
+                        }
+                    })
+                    .unwrap();
+                assert_eq!(message.from, "room@example.com");
+                let mut to = message.to;
+                to.sort_unstable();
+                (method, to)
+            })
+            .collect::<Vec<_>>();
+        messages.sort_unstable_by_key(|(method, _)| method.as_str().to_string());
+        assert_eq!(
+            messages,
+            expected_messages
+                .into_iter()
+                .map(|(method, to)| (method, to.into_iter().map(String::from).collect()))
+                .collect::<Vec<_>>(),
+            "{decision:?}"
+        );
+    }
+}
+
+const TEST_BOOKING: &str = r#"BEGIN:VCALENDAR
+VERSION:2.0
+PRODID:-//Example/ExampleCalendarClient//EN
+METHOD:REQUEST
+BEGIN:VEVENT
+UID:booking-test@example.com
+DTSTAMP:20250101T090000Z
+DTSTART:20250110T100000Z
+DTEND:20250110T110000Z
+SUMMARY:Planning
+SEQUENCE:0
+ORGANIZER:mailto:a@example.com
+ATTENDEE;PARTSTAT=ACCEPTED:mailto:a@example.com
+ATTENDEE;CUTYPE=ROOM;PARTSTAT=NEEDS-ACTION;RSVP=TRUE:mailto:room@example.com
+END:VEVENT
+END:VCALENDAR
+"#;
+
 trait ItipMessageExt {
     fn to_string(&self, map: &mut AHashMap<PartialDateTime, usize>) -> String;
 }
@@ -199,6 +199,7 @@ fn webdav_tests() {
             cal_query::test(&test).await;
             cal_alarm::test(&test).await;
             cal_itip::test();
+            cal_itip::test_booking();
             cal_scheduling::test(&test).await;
 
             // Print elapsed time